@@ -0,0 +1,116 @@
+//! Async Fetch Demo
+//!
+//! Fetches a URL on a tokio runtime running on background threads, while the UI side is an
+//! `async` block spawned with `wxdragon::async_support::spawn_local`. The block awaits the
+//! result through a oneshot channel on the main thread and updates the widgets directly,
+//! without `call_after` or idle polling. Errors are shown with an awaited message dialog.
+//!
+//! Only plain `http://` URLs are supported, to keep the demo free of TLS dependencies.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use wxdragon::async_support::spawn_local;
+use wxdragon::prelude::*;
+
+fn main() {
+    SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
+
+    // The runtime's worker threads do the networking; the handle is cheap to clone
+    let runtime = tokio::runtime::Runtime::new().expect("failed to start the tokio runtime");
+    let handle = runtime.handle().clone();
+
+    let _ = wxdragon::main(move |_| {
+        let frame = Frame::builder()
+            .with_title("Async Fetch Demo")
+            .with_size(Size::new(640, 480))
+            .build();
+        let panel = Panel::builder(&frame).build();
+
+        let url_ctrl = TextCtrl::builder(&panel).with_value("http://example.com/").build();
+        let fetch_btn = Button::builder(&panel).with_label("Fetch").build();
+        let status = StaticText::builder(&panel).with_label("Enter an http:// URL").build();
+        let body_ctrl = TextCtrl::builder(&panel)
+            .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .build();
+
+        let url_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+        url_sizer.add(&url_ctrl, 1, SizerFlag::Expand | SizerFlag::Right, 8);
+        url_sizer.add(&fetch_btn, 0, SizerFlag::AlignCenterVertical, 0);
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add_sizer(&url_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+        sizer.add(&status, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+        sizer.add(&body_ctrl, 1, SizerFlag::Expand | SizerFlag::All, 8);
+        panel.set_sizer(sizer, true);
+
+        fetch_btn.on_click(move |_| {
+            let url = url_ctrl.get_value();
+            let handle = handle.clone();
+            fetch_btn.enable(false);
+            status.set_label(&format!("Fetching {url}..."));
+
+            spawn_local(async move {
+                let (sender, receiver) = tokio::sync::oneshot::channel();
+                let fetch_url = url.clone();
+                handle.spawn(async move {
+                    let _ = sender.send(fetch(&fetch_url).await);
+                });
+
+                // Resumes here, on the main thread, once the runtime sent the result
+                match receiver.await {
+                    Ok(Ok(body)) => {
+                        status.set_label(&format!("Fetched {} bytes from {url}", body.len()));
+                        body_ctrl.set_value(&body);
+                    }
+                    Ok(Err(error)) => {
+                        status.set_label("Fetch failed");
+                        MessageDialog::builder(&frame, &error, "Fetch failed")
+                            .with_style(MessageDialogStyle::OK | MessageDialogStyle::IconError)
+                            .build()
+                            .show_modal_async()
+                            .await;
+                    }
+                    Err(_) => status.set_label("The fetch was dropped"),
+                }
+                fetch_btn.enable(true);
+            });
+        });
+
+        frame.show(true);
+        frame.centre();
+    });
+
+    drop(runtime);
+}
+
+/// Fetches `url` with a minimal HTTP/1.0 GET and returns the response, headers included.
+async fn fetch(url: &str) -> Result<String, String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("Only http:// URLs are supported, got {url:?}"))?;
+    let (host_port, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+    let host = host_port.split(':').next().unwrap_or(host_port);
+    let address = if host_port.contains(':') {
+        host_port.to_string()
+    } else {
+        format!("{host_port}:80")
+    };
+
+    let mut stream = TcpStream::connect(&address)
+        .await
+        .map_err(|e| format!("Couldn't connect to {address}: {e}"))?;
+    let request = format!("GET {path} HTTP/1.0\r\nHost: {host}\r\nConnection: close\r\n\r\n");
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(|e| format!("Couldn't send the request: {e}"))?;
+
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .await
+        .map_err(|e| format!("Couldn't read the response: {e}"))?;
+    Ok(String::from_utf8_lossy(&response).into_owned())
+}
@@ -0,0 +1,218 @@
+use wxdragon::config::{Config, ConfigStyle};
+use wxdragon::prelude::*;
+
+const ID_NEW: i32 = ID_HIGHEST + 1;
+const ID_OPEN: i32 = ID_HIGHEST + 2;
+const ID_SAVE: i32 = ID_HIGHEST + 3;
+const ID_BUILD: i32 = ID_HIGHEST + 4;
+const ID_RUN: i32 = ID_HIGHEST + 5;
+const ID_VERBOSE: i32 = ID_HIGHEST + 6;
+const ID_RECENT_FIRST: i32 = ID_HIGHEST + 10;
+
+const ID_VIEW_PROJECT: i32 = ID_HIGHEST + 20;
+const ID_VIEW_OUTPUT: i32 = ID_HIGHEST + 21;
+const ID_VIEW_FLOAT_OUTPUT: i32 = ID_HIGHEST + 22;
+const ID_VIEW_RESET: i32 = ID_HIGHEST + 23;
+
+const RECENT_FILES: [&str; 3] = ["main.rs", "lib.rs", "Cargo.toml"];
+
+fn tool_icon(id: ArtId) -> BitmapBundle {
+    ArtProvider::get_bitmap_bundle(id, ArtClient::Toolbar, None).unwrap_or_default()
+}
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("AUI IDE Demo - drag panes and toolbars, the layout is kept")
+            .with_size(Size::new(1000, 700))
+            .build();
+
+        let view_menu = Menu::builder()
+            .append_item(ID_VIEW_PROJECT, "Show/Hide &Project", "Toggle the project pane")
+            .append_item(ID_VIEW_OUTPUT, "Show/Hide &Output", "Toggle the output pane")
+            .append_item(ID_VIEW_FLOAT_OUTPUT, "&Float/Dock Output", "Float or dock the output pane")
+            .append_separator()
+            .append_item(ID_VIEW_RESET, "&Reset Layout", "Restore the default layout")
+            .build();
+        let menu_bar = MenuBar::builder().append(view_menu, "&View").build();
+        frame.set_menu_bar(menu_bar);
+
+        let manager = AuiManager::new(&frame);
+
+        // Two toolbars in the top toolbar row; drag their grippers to reorder, float or
+        // dock them elsewhere.
+        let file_tools = AuiToolBar::builder(&frame)
+            .with_style(AuiToolBarStyle::Default | AuiToolBarStyle::HorzLayout)
+            .build();
+        file_tools.add_tool(ID_NEW, "New", tool_icon(ArtId::New), WidgetItemKind::Normal);
+        file_tools.add_tool(ID_OPEN, "Open", tool_icon(ArtId::FileOpen), WidgetItemKind::Normal);
+        file_tools.set_tool_drop_down(ID_OPEN, true);
+        file_tools.set_tool_short_help(ID_OPEN, "Open a file, or pick a recent one from the arrow");
+        file_tools.add_tool(ID_SAVE, "Save", tool_icon(ArtId::FileSave), WidgetItemKind::Normal);
+        file_tools.realize();
+
+        let build_tools = AuiToolBar::builder(&frame)
+            .with_style(AuiToolBarStyle::Default | AuiToolBarStyle::HorzLayout)
+            .build();
+        build_tools.add_tool(ID_BUILD, "Build", tool_icon(ArtId::ExecutableFile), WidgetItemKind::Normal);
+        build_tools.add_tool(ID_RUN, "Run", tool_icon(ArtId::GoForward), WidgetItemKind::Normal);
+        build_tools.add_separator();
+        build_tools.add_tool(ID_VERBOSE, "Verbose", tool_icon(ArtId::Tip), WidgetItemKind::Check);
+        let target = Choice::builder(&build_tools)
+            .with_choices(vec!["debug".to_string(), "release".to_string()])
+            .build();
+        target.set_selection(0);
+        build_tools.add_control(&target, "Target");
+        build_tools.realize();
+
+        let project = ListBox::builder(&frame)
+            .with_choices(RECENT_FILES.iter().map(|file| file.to_string()).collect())
+            .build();
+        let output = TextCtrl::builder(&frame)
+            .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .build();
+        let editor = TextCtrl::builder(&frame)
+            .with_style(TextCtrlStyle::MultiLine)
+            .with_value("fn main() {\n    println!(\"Hello from wxDragon\");\n}\n")
+            .build();
+
+        manager.add_pane(
+            &file_tools,
+            AuiPaneInfo::new()
+                .with_name("file_tools")
+                .with_caption("File")
+                .toolbar_pane()
+                .top()
+                .row(0)
+                .position(0),
+        );
+        manager.add_pane(
+            &build_tools,
+            AuiPaneInfo::new()
+                .with_name("build_tools")
+                .with_caption("Build")
+                .toolbar_pane()
+                .top()
+                .row(0)
+                .position(1),
+        );
+        manager.add_pane(
+            &project,
+            AuiPaneInfo::new()
+                .with_name("project")
+                .with_caption("Project")
+                .direction(DockDirection::Left)
+                .layer(1)
+                .best_size(220, 400)
+                .floatable(true)
+                .close_button(true)
+                .minimize_button(true),
+        );
+        manager.add_pane(
+            &output,
+            AuiPaneInfo::new()
+                .with_name("output")
+                .with_caption("Output")
+                .direction(DockDirection::Bottom)
+                .best_size(600, 160)
+                .floatable(true)
+                .close_button(true),
+        );
+        manager.add_pane(&editor, AuiPaneInfo::new().with_name("editor").center_pane());
+        manager.update();
+
+        // Remember the layout as built for "Reset Layout", then restore the one saved by
+        // the last run, which is written back when the frame closes.
+        let default_layout = manager.save_perspective();
+        let config = Config::new("AuiIdeDemo", Some("wxDragon"), None, None, ConfigStyle::empty());
+        persist_perspective(manager, config, "/Layout/Perspective");
+
+        // The editor holds unsaved work, so its pane can't be closed; the others can.
+        manager.on_pane_close(move |event| {
+            let name = event.pane_name().unwrap_or_default();
+            if name == "editor" {
+                event.veto();
+            } else {
+                output.append_text(&format!("Closed the {name} pane (View menu brings it back)\n"));
+            }
+        });
+
+        manager.on_pane_float(move |event| {
+            if let Some(pane) = event.pane() {
+                output.append_text(&format!("Floated the {} pane\n", pane.name()));
+            }
+        });
+        manager.on_pane_dock(move |event| {
+            if let Some(pane) = event.pane() {
+                output.append_text(&format!("Docked the {} pane\n", pane.name()));
+            }
+        });
+
+        file_tools.on_tool_dropdown(move |event| {
+            if !event.is_drop_down_clicked() {
+                event.skip(true);
+                return;
+            }
+            let mut recent = Menu::builder().build();
+            for (index, file) in RECENT_FILES.iter().enumerate() {
+                recent.append(ID_RECENT_FIRST + index as i32, file, "", ItemKind::Normal);
+            }
+            let rect = event.get_item_rect();
+            let below = file_tools.client_to_screen(Point::new(rect.x, rect.y + rect.height));
+            file_tools.set_tool_sticky(ID_OPEN, true);
+            file_tools.popup_menu(&mut recent, Some(below));
+            file_tools.set_tool_sticky(ID_OPEN, false);
+        });
+
+        let on_tool = move |event: AuiToolBarEventData| match event.get_id() {
+            ID_NEW => output.append_text("New file\n"),
+            ID_OPEN => output.append_text("Open...\n"),
+            ID_SAVE => output.append_text("Saved\n"),
+            ID_BUILD => {
+                let verbose = if build_tools.get_tool_toggled(ID_VERBOSE) { " -v" } else { "" };
+                let mode = if target.get_selection() == Some(1) { " --release" } else { "" };
+                output.append_text(&format!("cargo build{mode}{verbose}\n"));
+            }
+            ID_RUN => output.append_text("Running...\n"),
+            id if (ID_RECENT_FIRST..ID_RECENT_FIRST + RECENT_FILES.len() as i32).contains(&id) => {
+                let file = RECENT_FILES[(id - ID_RECENT_FIRST) as usize];
+                output.append_text(&format!("Opened {file}\n"));
+            }
+            _ => event.skip(true),
+        };
+        file_tools.on_tool_clicked(on_tool);
+        build_tools.on_tool_clicked(on_tool);
+
+        frame.on_menu(move |event| {
+            let toggle = |name: &str| {
+                if let Some(pane) = manager.get_pane(name) {
+                    pane.show(!pane.is_shown());
+                    manager.update();
+                }
+            };
+            match event.get_id() {
+                ID_VIEW_PROJECT => toggle("project"),
+                ID_VIEW_OUTPUT => toggle("output"),
+                ID_VIEW_FLOAT_OUTPUT => {
+                    if let Some(pane) = manager.get_pane("output") {
+                        if pane.is_floating() {
+                            pane.dock();
+                        } else {
+                            pane.float();
+                        }
+                        pane.show(true);
+                        manager.update();
+                    }
+                }
+                ID_VIEW_RESET => {
+                    manager.load_perspective(&default_layout, true);
+                }
+                _ => event.skip(true),
+            }
+        });
+
+        frame.show(true);
+        frame.centre();
+    });
+}
@@ -0,0 +1,94 @@
+//! ComboCtrl Demo - a combo box whose popup is a tree to pick a category from.
+
+use wxdragon::prelude::*;
+
+const CATEGORIES: [(&str, &[&str]); 3] = [
+    ("Fruit", &["Apples", "Pears", "Cherries"]),
+    ("Vegetables", &["Carrots", "Leeks", "Potatoes"]),
+    ("Dairy", &["Butter", "Cheese", "Milk"]),
+];
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("ComboCtrl Demo - tree picker")
+            .with_size(Size::new(420, 320))
+            .build();
+        let panel = Panel::builder(&frame).build();
+
+        let label = StaticText::builder(&panel).with_label("Category:").build();
+        let picker = ComboCtrl::builder(&panel)
+            .with_style(ComboCtrlStyle::ReadOnly)
+            .with_value("Pick a product")
+            .build();
+
+        // The popup is as wide as the combo and as high as the tree wants, at most 220
+        // pixels; only products, the leaves of the tree, end up in the text field.
+        picker.set_popup_size(Size::new(-1, 220));
+        let tree = picker
+            .set_popup_content(
+                |popup| {
+                    TreeCtrl::builder(popup)
+                        .with_style(TreeCtrlStyle::HideRoot | TreeCtrlStyle::HasButtons | TreeCtrlStyle::LinesAtRoot)
+                        .build()
+                },
+                |tree| {
+                    let item = tree.get_selection()?;
+                    if tree.item_has_children(&item) {
+                        None
+                    } else {
+                        tree.get_item_text(&item)
+                    }
+                },
+            )
+            .expect("the combo was just created");
+
+        let root = tree.add_root("Products", None, None).expect("the tree was just created");
+        for (category, products) in CATEGORIES {
+            let Some(parent) = tree.append_item(&root, category, None, None) else {
+                continue;
+            };
+            for product in products {
+                tree.append_item(&parent, product, None, None);
+            }
+            tree.expand(&parent);
+        }
+
+        // A click on a product picks it; Enter picks the selected one
+        tree.on_mouse_left_up(move |event| {
+            if let WindowEventData::MouseButton(ref button) = event
+                && let Some(pos) = button.get_position()
+                && let (Some(item), flags) = tree.hit_test(pos)
+                && flags.intersects(TreeHitTestFlags::ON_ITEM)
+                && !tree.item_has_children(&item)
+            {
+                tree.select_item(&item);
+                picker.dismiss_popup();
+            }
+            event.skip(true);
+        });
+        tree.on_item_activated(move |_| picker.dismiss_popup());
+
+        let log = TextCtrl::builder(&panel)
+            .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .build();
+        picker.on_popup_shown(move |_| log.append_text("Popup shown\n"));
+        picker.on_popup_dismissed(move |_| log.append_text(&format!("Popup dismissed, value: {}\n", picker.get_value())));
+
+        let row = BoxSizer::builder(Orientation::Horizontal).build();
+        row.add(&label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+        row.add(&picker, 1, SizerFlag::Expand, 0);
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add_sizer(&row, 0, SizerFlag::Expand | SizerFlag::All, 10);
+        sizer.add(
+            &log,
+            1,
+            SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+            10,
+        );
+        panel.set_sizer(sizer, true);
+
+        frame.show(true);
+        frame.centre();
+    });
+}
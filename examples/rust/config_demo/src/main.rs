@@ -9,6 +9,7 @@
 //! - Using paths/groups to organize settings
 //! - Enumerating entries and groups
 //! - Deleting entries and groups
+//! - Exporting the current group to a folder picked with DirPickerCtrl
 
 use wxdragon::prelude::*;
 
@@ -74,6 +75,20 @@ fn main() {
         path_sizer.add(&path_input, 1, SizerFlag::Expand, 0);
         path_sizer.add(&btn_set_path, 0, SizerFlag::Left, 5);
 
+        // Export controls: pick a destination folder inline with a DirPickerCtrl
+        let export_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+        let export_label = StaticText::builder(&panel).with_label("Export to:").build();
+        let export_dir_picker = DirPickerCtrl::builder(&panel)
+            .with_message("Choose where exported settings go")
+            .with_path(&std::env::temp_dir().to_string_lossy())
+            .with_style(DirPickerCtrlStyle::Default | DirPickerCtrlStyle::UseTextCtrl)
+            .build();
+        let btn_export = Button::builder(&panel).with_label("Export").build();
+
+        export_sizer.add(&export_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 5);
+        export_sizer.add(&export_dir_picker, 1, SizerFlag::Expand, 0);
+        export_sizer.add(&btn_export, 0, SizerFlag::Left, 5);
+
         // Status label
         let status_label = StaticText::builder(&panel).with_label("Status: Ready").build();
 
@@ -220,6 +235,38 @@ fn main() {
                 });
             }
 
+            // Export folder changed
+            export_dir_picker.on_dir_changed(move |event| {
+                status_label.set_label(&format!("Status: Export folder set to '{}'", event.get_path()));
+            });
+
+            // Export current group as key=value lines
+            {
+                let config = config_clone.clone();
+                btn_export.on_click(move |_| {
+                    let dir = export_dir_picker.get_path();
+                    if dir.is_empty() {
+                        status_label.set_label("Status: Choose an export folder first");
+                        return;
+                    }
+                    let mut contents = format!("[{}]\n", config.get_path());
+                    for entry in config.get_entries() {
+                        contents.push_str(&format!("{}={}\n", entry, config.read_string(&entry, "")));
+                    }
+                    let file = std::path::Path::new(&dir).join("config_demo_export.ini");
+                    match std::fs::write(&file, contents) {
+                        Ok(()) => {
+                            status_label.set_label(&format!("Status: Exported to '{}'", file.display()));
+                            log::info!("Exported settings to {}", file.display());
+                        }
+                        Err(e) => {
+                            status_label.set_label(&format!("Status: Export failed: {e}"));
+                            log::error!("Export failed: {e}");
+                        }
+                    }
+                });
+            }
+
             // Set Path
             {
                 let config = config_clone.clone();
@@ -247,6 +294,7 @@ fn main() {
 
         main_sizer.add_sizer(&button_sizer, 0, SizerFlag::AlignCenterHorizontal, 0);
         main_sizer.add_sizer(&path_sizer, 0, SizerFlag::Expand | SizerFlag::All, 10);
+        main_sizer.add_sizer(&export_sizer, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 10);
         main_sizer.add(&status_label, 0, SizerFlag::All | SizerFlag::AlignCenterHorizontal, 10);
 
         panel.set_sizer(main_sizer, true);
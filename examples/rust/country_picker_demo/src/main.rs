@@ -0,0 +1,106 @@
+//! Country Picker Demo - a BitmapComboBox listing countries with their flags.
+
+use wxdragon::prelude::*;
+
+type Rgb = (u8, u8, u8);
+
+const BLACK: Rgb = (0, 0, 0);
+const BLUE: Rgb = (0, 85, 164);
+const GREEN: Rgb = (0, 146, 70);
+const ORANGE: Rgb = (255, 130, 0);
+const RED: Rgb = (206, 17, 38);
+const WHITE: Rgb = (255, 255, 255);
+const YELLOW: Rgb = (255, 206, 0);
+
+// Name, stripes from the top or left, and whether the stripes are vertical
+const COUNTRIES: [(&str, [Rgb; 3], bool); 6] = [
+    ("Austria", [RED, WHITE, RED], false),
+    ("Belgium", [BLACK, YELLOW, RED], true),
+    ("France", [BLUE, WHITE, RED], true),
+    ("Germany", [BLACK, RED, YELLOW], false),
+    ("Ireland", [GREEN, WHITE, ORANGE], true),
+    ("Italy", [GREEN, WHITE, RED], true),
+];
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("Country Picker Demo")
+            .with_size(Size::new(380, 220))
+            .build();
+        let panel = Panel::builder(&frame).build();
+
+        let label = StaticText::builder(&panel).with_label("Country:").build();
+        let picker = BitmapComboBox::builder(&panel).with_style(ComboBoxStyle::ReadOnly).build();
+        // The first flag fixes the size; the larger ones are scaled down to it
+        for (index, (name, stripes, vertical)) in COUNTRIES.iter().enumerate() {
+            let (width, height) = if index % 2 == 0 { (24, 16) } else { (48, 32) };
+            picker.append(name, flag(stripes, *vertical, width, height).as_ref());
+        }
+        picker.set_selection(0);
+
+        let status = StaticText::builder(&panel).build();
+        let show_selection = move || match picker.get_string_selection() {
+            Some(name) => status.set_label(&format!(
+                "Selected: {name}, {} of {} countries",
+                picker.get_selection() + 1,
+                picker.get_count()
+            )),
+            None => status.set_label("No country selected"),
+        };
+        show_selection();
+        picker.on_selection_changed(move |_| show_selection());
+
+        // Insert keeps the list sorted; the flag has two stripes only
+        let add_button = Button::builder(&panel).with_label("Add Ukraine").build();
+        add_button.on_click(move |_| {
+            let pos = (0..picker.get_count())
+                .find(|&n| picker.get_string(n).as_str() > "Ukraine")
+                .unwrap_or(picker.get_count());
+            picker.insert("Ukraine", flag(&[BLUE, YELLOW], false, 30, 20).as_ref(), pos as usize);
+            add_button.enable(false);
+        });
+
+        let remove_button = Button::builder(&panel).with_label("Remove").build();
+        remove_button.on_click(move |_| {
+            let selection = picker.get_selection();
+            if selection >= 0 {
+                picker.delete(selection as u32);
+                picker.set_selection(selection.min(picker.get_count() as i32 - 1));
+                show_selection();
+            }
+        });
+
+        let row = BoxSizer::builder(Orientation::Horizontal).build();
+        row.add(&label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+        row.add(&picker, 1, SizerFlag::Expand, 0);
+        let buttons = BoxSizer::builder(Orientation::Horizontal).build();
+        buttons.add(&add_button, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+        buttons.add(&remove_button, 0, SizerFlag::AlignCenterVertical, 0);
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add_sizer(&row, 0, SizerFlag::Expand | SizerFlag::All, 10);
+        sizer.add(&status, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 10);
+        sizer.add_sizer(&buttons, 0, SizerFlag::All, 10);
+        panel.set_sizer(sizer, true);
+
+        frame.show(true);
+        frame.centre();
+    });
+}
+
+// Draws a flag of equal stripes
+fn flag(stripes: &[Rgb], vertical: bool, width: u32, height: u32) -> Option<Bitmap> {
+    let mut data = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let stripe = if vertical {
+                x * stripes.len() as u32 / width
+            } else {
+                y * stripes.len() as u32 / height
+            };
+            let (r, g, b) = stripes[stripe as usize];
+            data.extend_from_slice(&[r, g, b, 255]);
+        }
+    }
+    Bitmap::from_rgba(&data, width, height)
+}
@@ -1,3 +1,4 @@
+use wxdragon::text::{EllipsizeMode, ellipsize};
 use wxdragon::*;
 
 fn main() {
@@ -68,9 +69,9 @@ fn main() {
                     // Draw progress text
                     ctx.set_text_foreground(Colour::rgb(0, 0, 0));
                     let text = format!("{progress}%");
-                    let (text_width, text_height) = ctx.get_text_extent(&text);
-                    let text_x = rect.x + (rect.width - text_width) / 2;
-                    let text_y = rect.y + (rect.height - text_height) / 2;
+                    let text_size = ctx.get_text_extent(&text);
+                    let text_x = rect.x + (rect.width - text_size.width) / 2;
+                    let text_y = rect.y + (rect.height - text_size.height) / 2;
                     ctx.draw_text(&text, text_x, text_y);
                 }
                 true
@@ -105,10 +106,12 @@ fn main() {
 
                     // Draw text
                     ctx.set_text_foreground(text_color);
-                    let (text_width, text_height) = ctx.get_text_extent(&status);
-                    let text_x = rect.x + (rect.width - text_width) / 2;
-                    let text_y = rect.y + (rect.height - text_height) / 2;
-                    ctx.draw_text(&status, text_x, text_y);
+                    // Narrow columns show "Almost D..." instead of clipping the text
+                    let label = ellipsize(ctx, &status, rect.width - 4, EllipsizeMode::End);
+                    let text_size = ctx.get_text_extent(&label);
+                    let text_x = rect.x + (rect.width - text_size.width) / 2;
+                    let text_y = rect.y + (rect.height - text_size.height) / 2;
+                    ctx.draw_text(&label, text_x, text_y);
                 }
                 true
             })
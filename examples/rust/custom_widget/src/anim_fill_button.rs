@@ -194,9 +194,9 @@ impl AniFillButton {
             dc.set_brush(config.fill_background_color, BrushStyle::Solid);
 
             // 设置裁剪区域来限制填充范围
-            dc.set_clipping_region(0, 0, fill_width, height);
-            dc.draw_rounded_rectangle(0, 0, width, height, radius);
-            dc.destroy_clipping_region();
+            dc.clipped(Rect::new(0, 0, fill_width, height), |dc| {
+                dc.draw_rounded_rectangle(0, 0, width, height, radius);
+            });
         }
 
         // 3. 如果处于按下状态，对整个按钮区域应用darken效果
@@ -228,8 +228,8 @@ impl AniFillButton {
         };
         dc.set_text_foreground(text_color);
         let text_size = dc.get_text_extent(&config.text);
-        let text_x = (width - text_size.0) / 2;
-        let text_y = (height - text_size.1) / 2;
+        let text_x = (width - text_size.width) / 2;
+        let text_y = (height - text_size.height) / 2;
         dc.draw_text(&config.text, text_x, text_y);
     }
 
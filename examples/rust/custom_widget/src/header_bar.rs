@@ -0,0 +1,90 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use wxdragon::dc::renderer::header_button_height;
+use wxdragon::dc::{AutoBufferedPaintDC, GradientDirection};
+use wxdragon::event::WindowEventData;
+use wxdragon::prelude::*;
+
+// A header bar drawn with the native renderer, so it matches the platform's list headers
+custom_widget!(
+    name: HeaderBar,
+    fields: {
+        columns: Vec<String> = vec!["Name".to_string(), "Size".to_string(), "Modified".to_string()],
+        top_colour: Colour = Colour::new(250, 250, 250, 255),
+        bottom_colour: Colour = Colour::new(225, 228, 235, 255),
+    },
+    setup_impl: |config, panel| {
+        panel.set_background_style(BackgroundStyle::Paint);
+
+        // Column under the mouse, drawn in the "current" (hot) state
+        let hovered = Rc::new(Cell::new(None::<usize>));
+
+        let hovered_paint = hovered.clone();
+        let config_paint = config.clone();
+        panel.on_paint(move |_event| {
+            HeaderBar::draw(&panel, &config_paint, hovered_paint.get());
+        });
+
+        let hovered_motion = hovered.clone();
+        let column_count = config.columns.len();
+        panel.on_mouse_motion(move |event| {
+            if let WindowEventData::MouseMotion(ref motion) = event
+                && let Some(pos) = motion.get_position()
+            {
+                let column = HeaderBar::column_at(&panel, column_count, pos.x, pos.y);
+                if hovered_motion.replace(column) != column {
+                    panel.refresh(false, None);
+                }
+            }
+            event.skip(true);
+        });
+
+        let hovered_leave = hovered.clone();
+        panel.on_mouse_leave(move |event| {
+            if hovered_leave.take().is_some() {
+                panel.refresh(false, None);
+            }
+            event.skip(true);
+        });
+    }
+);
+
+impl HeaderBar {
+    fn column_rect(panel: &Panel, column_count: usize, index: usize) -> Rect {
+        let width = panel.get_size().width;
+        let count = column_count.max(1) as i32;
+        let x = width * index as i32 / count;
+        let next_x = width * (index as i32 + 1) / count;
+        Rect::new(x, 0, next_x - x, header_button_height(panel))
+    }
+
+    fn column_at(panel: &Panel, column_count: usize, x: i32, y: i32) -> Option<usize> {
+        (0..column_count).find(|&i| {
+            let rect = Self::column_rect(panel, column_count, i);
+            x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+        })
+    }
+
+    fn draw(panel: &Panel, config: &HeaderBarConfig, hovered: Option<usize>) {
+        let dc = AutoBufferedPaintDC::new(panel);
+        let size = panel.get_size();
+
+        // Themed backdrop below the header row
+        dc.gradient_fill_linear(
+            Rect::new(0, 0, size.width, size.height),
+            config.top_colour,
+            config.bottom_colour,
+            GradientDirection::South,
+        );
+
+        for (i, label) in config.columns.iter().enumerate() {
+            let rect = Self::column_rect(panel, config.columns.len(), i);
+            let flags = if hovered == Some(i) {
+                RendererFlags::Current
+            } else {
+                RendererFlags::Normal
+            };
+            dc.draw_header_button(panel, rect, flags, label);
+        }
+    }
+}
@@ -3,6 +3,9 @@ use wxdragon::prelude::*;
 mod anim_fill_button;
 use anim_fill_button::AniFillButton;
 
+mod header_bar;
+use header_bar::HeaderBar;
+
 mod pie_chart;
 use pie_chart::PieChart;
 
@@ -49,6 +52,18 @@ fn main() {
             .build();
         main_sizer.add(&title, 0, SizerFlag::AlignCenterHorizontal | SizerFlag::All, 10);
 
+        // Themed header bar drawn with the native renderer
+        let header_bar = HeaderBar::builder(&panel)
+            .with_columns(vec![
+                "Name".to_string(),
+                "Type".to_string(),
+                "Size".to_string(),
+                "Modified".to_string(),
+            ])
+            .with_size(Size::new(-1, 40))
+            .build();
+        main_sizer.add(&header_bar, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 10);
+
         main_sizer.add_spacer(20);
 
         // Create horizontal sizer for content
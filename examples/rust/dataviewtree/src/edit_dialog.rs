@@ -63,27 +63,18 @@ impl NodeEditDialog {
 
         vbox.add_sizer(&grid, 1, SizerFlag::Expand | SizerFlag::All, 10);
 
-        // Buttons row
-        let hbox = BoxSizer::builder(Orientation::Horizontal).build();
-        hbox.add_stretch_spacer(1);
-        let ok_btn = Button::builder(&dlg).with_id(ID_OK).with_label("OK").build();
-        let cancel_btn = Button::builder(&dlg).with_id(ID_CANCEL).with_label("Cancel").build();
-        hbox.add(&ok_btn, 0, SizerFlag::All, 4);
-        hbox.add(&cancel_btn, 0, SizerFlag::All, 4);
-        vbox.add_sizer(
-            &hbox,
-            0,
-            SizerFlag::AlignRight | SizerFlag::Right | SizerFlag::Bottom | SizerFlag::All,
-            10,
-        );
-
-        // Wire default behaviors
-        ok_btn.on_click(move |_| {
-            dlg.end_modal(ID_OK);
-        });
-        cancel_btn.on_click(move |_| {
-            dlg.end_modal(ID_CANCEL);
-        });
+        // Standard OK/Cancel buttons: stock labels, native order, and Enter/Esc handling.
+        // They carry ID_OK/ID_CANCEL, so the dialog ends the modal loop by itself.
+        if let Some(buttons) = dlg.create_std_dialog_button_sizer(StdDialogButtons::Ok | StdDialogButtons::Cancel) {
+            vbox.add_sizer(
+                &buttons,
+                0,
+                SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+                10,
+            );
+        }
+        dlg.set_affirmative_id(ID_OK);
+        dlg.set_escape_id(ID_CANCEL);
 
         // Disable non-applicable fields for branch nodes
         if is_branch {
@@ -9,6 +9,9 @@ mod edit_dialog;
 mod music_tree;
 mod mymodels;
 
+const ID_RECENT_SEARCH: i32 = ID_HIGHEST + 200;
+const RECENT_SEARCH_COUNT: usize = 5;
+
 fn main() {
     SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("trace")).init();
@@ -33,7 +36,8 @@ fn main() {
     data.filepath = Some(data_path.clone());
 
     let data_rc: Rc<RefCell<music_tree::MusicTree>> = Rc::new(RefCell::new(data));
-    let model = mymodels::create_music_tree_model(data_rc.clone());
+    let filter = Rc::new(RefCell::new(String::new()));
+    let model = mymodels::create_music_tree_model(data_rc.clone(), filter.clone());
     let _ = wxdragon::main(move |_| {
         let frame = Frame::builder()
             .with_title("wxDragon DataView Example")
@@ -42,6 +46,11 @@ fn main() {
 
         let panel = Panel::builder(&frame).build();
 
+        let search = SearchCtrl::builder(&panel)
+            .with_descriptive_text("Filter…")
+            .with_cancel_button(true)
+            .build();
+
         // Create the generic DataView control (works with custom models), it behaves like a tree view
         let dataview = DataViewCtrl::builder(&panel)
             .with_size(Size::new(860, 520))
@@ -97,9 +106,49 @@ fn main() {
         }
 
         let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add(
+            &search,
+            0,
+            SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top,
+            8,
+        );
         sizer.add(&dataview, 1, SizerFlag::Expand | SizerFlag::All, 8);
         panel.set_sizer(sizer, true);
 
+        // Filter the tree as the user types, showing every match
+        let model_for_filter = model.clone();
+        search.on_text_changed(move |event| {
+            *filter.borrow_mut() = event.get_string().unwrap_or_default();
+            model_for_filter.cleared();
+            expand_all(&dataview, &DataViewItem::default());
+        });
+        search.on_cancel(move |_| search.set_value(""));
+
+        // Searches confirmed with Enter or the magnifier are offered again from its menu
+        let recent_searches: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+        let recent_for_search = recent_searches.clone();
+        search.on_search(move |event| {
+            let text = event.get_string().unwrap_or_default();
+            if text.is_empty() {
+                return;
+            }
+            let mut recent = recent_for_search.borrow_mut();
+            recent.retain(|previous| *previous != text);
+            recent.insert(0, text);
+            recent.truncate(RECENT_SEARCH_COUNT);
+            let menu = Menu::builder().build();
+            for (index, previous) in recent.iter().enumerate() {
+                let _ = menu.append(ID_RECENT_SEARCH + index as i32, previous, "", ItemKind::Normal);
+            }
+            search.set_menu(Some(menu));
+        });
+        search.on_menu_selected(move |event| {
+            let index = (event.get_id() - ID_RECENT_SEARCH) as usize;
+            if let Some(previous) = recent_searches.borrow().get(index) {
+                search.set_value(previous);
+            }
+        });
+
         // Right-click item context menu with an "Edit" entry
         // Widgets are Copy, so use them directly
         let dataview_for_menu = dataview;
@@ -374,6 +423,22 @@ fn main() {
     });
 }
 
+/// Expands `item` and all its descendants.
+fn expand_all(dataview: &DataViewCtrl, item: &DataViewItem) {
+    if item.is_ok() {
+        dataview.expand(item);
+    }
+    let mut index = 0;
+    loop {
+        let child = dataview.get_nth_child(item, index);
+        if !child.is_ok() {
+            break;
+        }
+        expand_all(dataview, &child);
+        index += 1;
+    }
+}
+
 fn edit_item_with_dialog(parent: &dyn WxWidget, model: &CustomDataViewTreeModel, item: &DataViewItem) {
     if let Some(ptr) = item.get_id::<MusicNode>() {
         // SAFETY: ptr is an opaque model ID set by us to a MusicNode address
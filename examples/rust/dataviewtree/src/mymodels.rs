@@ -2,7 +2,9 @@ use crate::music_tree::{MusicNode, MusicTree, NodeType};
 use std::{cell::RefCell, rc::Rc};
 use wxdragon::prelude::*;
 
-pub fn create_music_tree_model(data: Rc<RefCell<MusicTree>>) -> CustomDataViewTreeModel {
+/// Creates the model of the music tree, showing only the nodes that match `filter` (see
+/// [`node_matches`]). Call `cleared()` on the model after changing the filter.
+pub fn create_music_tree_model(data: Rc<RefCell<MusicTree>>, filter: Rc<RefCell<String>>) -> CustomDataViewTreeModel {
     CustomDataViewTreeModel::new(
         data,
         move |data: &Rc<RefCell<MusicTree>>, item: Option<&MusicNode>| match item {
@@ -23,6 +25,7 @@ pub fn create_music_tree_model(data: Rc<RefCell<MusicTree>>) -> CustomDataViewTr
                 .as_ref()
                 .into_iter()
                 .flat_map(|v| v.iter())
+                .filter(|b| node_matches(&b.borrow(), &filter.borrow()))
                 .map(|b| &*b.borrow() as *const MusicNode as *mut MusicNode)
                 .collect(),
         },
@@ -33,6 +36,21 @@ pub fn create_music_tree_model(data: Rc<RefCell<MusicTree>>) -> CustomDataViewTr
     )
 }
 
+/// Returns true if `filter` is empty or found, ignoring case, in the title or artist of
+/// `node` or one of its descendants.
+pub fn node_matches(node: &MusicNode, filter: &str) -> bool {
+    let filter = filter.to_lowercase();
+    let contains = |text: &str| text.to_lowercase().contains(&filter);
+    filter.is_empty()
+        || contains(&node.title)
+        || node.artist.as_deref().is_some_and(contains)
+        || node
+            .children
+            .iter()
+            .flatten()
+            .any(|child| node_matches(&child.borrow(), &filter))
+}
+
 fn get_value_cb(data: &Rc<RefCell<MusicTree>>, item: Option<&MusicNode>, col: u32) -> Variant {
     fn _get_value(r: &MusicNode, col: u32) -> Variant {
         match col {
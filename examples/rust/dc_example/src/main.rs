@@ -1,5 +1,14 @@
+use std::cell::Cell;
+use std::rc::Rc;
 use wxdragon::prelude::*;
 
+/// Rectangle (x, y, width, height) spanned by two corners
+type Selection = (i32, i32, i32, i32);
+
+fn span(a: Point, b: Point) -> Selection {
+    (a.x.min(b.x), a.y.min(b.y), (a.x - b.x).abs(), (a.y - b.y).abs())
+}
+
 struct DrawingPanel {
     panel: Panel,
 }
@@ -9,7 +18,14 @@ impl DrawingPanel {
         let panel = PanelBuilder::new(parent).build();
         panel.set_background_style(BackgroundStyle::Paint);
 
+        // Drag-to-select state: the rubber band is drawn on an overlay while dragging,
+        // the final selection by the paint handler
+        let drag_start: Rc<Cell<Option<Point>>> = Rc::new(Cell::new(None));
+        let selection: Rc<Cell<Option<Selection>>> = Rc::new(Cell::new(None));
+        let overlay = Rc::new(Overlay::new());
+
         // Register the paint handler with a move closure
+        let painted_selection = selection.clone();
         panel.on_paint(move |_event| {
             // Create a PaintDC when handling paint events
             let dc = AutoBufferedPaintDC::new(&panel);
@@ -44,12 +60,86 @@ impl DrawingPanel {
             dc.set_pen(Colour::rgb(150, 150, 0), 1, PenStyle::Solid);
             dc.set_brush(Colour::rgb(255, 255, 0), BrushStyle::CrossHatch);
             dc.draw_ellipse(150, 120, 100, 60);
+
+            // Draw the last selection made by dragging
+            if let Some((x, y, w, h)) = painted_selection.get() {
+                dc.set_pen(Colour::rgb(0, 120, 215), 1, PenStyle::Solid);
+                dc.set_brush(Colour::rgb(0, 0, 0), BrushStyle::Transparent);
+                dc.draw_rectangle(x, y, w, h);
+                dc.draw_text(&format!("Selection: {w} x {h}"), x, y + h + 4);
+            }
+        });
+
+        panel.on_mouse_left_down({
+            let drag_start = drag_start.clone();
+            move |event| {
+                if let WindowEventData::MouseButton(ref button) = event
+                    && let Some(pos) = button.get_position()
+                {
+                    drag_start.set(Some(pos));
+                    panel.capture_mouse();
+                }
+            }
+        });
+
+        // Draw the rubber band immediately instead of waiting for a paint event. The
+        // overlay erases the previous rectangle, which also works where inverting
+        // (LogicalFunction::Invert) is not supported.
+        panel.on_mouse_motion({
+            let drag_start = drag_start.clone();
+            let overlay = overlay.clone();
+            move |event| {
+                let (Some(start), WindowEventData::MouseMotion(motion)) = (drag_start.get(), &event) else {
+                    return;
+                };
+                let Some(pos) = motion.get_position() else {
+                    return;
+                };
+                let (x, y, w, h) = span(start, pos);
+
+                let dc = ClientDC::new(&panel);
+                let overlay_dc = DCOverlay::new(&overlay, &dc);
+                overlay_dc.clear();
+                dc.set_pen(Colour::rgb(0, 120, 215), 1, PenStyle::Dot);
+                dc.set_brush(Colour::rgb(0, 0, 0), BrushStyle::Transparent);
+                dc.draw_rectangle(x, y, w, h);
+            }
+        });
+
+        panel.on_mouse_left_up(move |event| {
+            let Some(start) = drag_start.take() else {
+                return;
+            };
+            if panel.has_capture() {
+                panel.release_mouse();
+            }
+
+            // Erase the rubber band and discard the overlay
+            {
+                let dc = ClientDC::new(&panel);
+                DCOverlay::new(&overlay, &dc).clear();
+            }
+            overlay.reset();
+
+            if let WindowEventData::MouseButton(ref button) = event
+                && let Some(pos) = button.get_position()
+            {
+                selection.set(Some(span(start, pos)));
+            }
+            panel.refresh(false, None);
+        });
+
+        // The paint handler covers the whole client area, so swallow the erase
+        // event instead of letting the default handler clear the background
+        // first; this is what keeps resizing flicker-free.
+        panel.on_erase_background(|event| {
+            event.skip(false);
         });
 
         // Also handle SIZE events to refresh when the window size changes
         panel.on_size(move |_event| {
-            // Force a repaint when window size changes
-            panel.refresh(true, None);
+            // Force a repaint when window size changes, without erasing
+            panel.refresh(false, None);
         });
 
         Self { panel }
@@ -75,7 +165,7 @@ fn main() {
         let drawing_panel = DrawingPanel::new(&frame);
 
         // Initial paint
-        drawing_panel.refresh(true, None);
+        drawing_panel.refresh(false, None);
 
         frame.show(true);
     });
@@ -23,9 +23,13 @@ fn main() {
         // Button 3: Add event binding
         let button3 = Button::builder(&panel).with_label("Button 3 (Add Event Binding)").build();
 
+        // Button 4: Remove every binding added through Button 3
+        let button4 = Button::builder(&panel).with_label("Button 4 (Remove All Bindings)").build();
+
         sizer.add(&button1, 0, SizerFlag::Expand, 8);
         sizer.add(&button2, 0, SizerFlag::Expand, 8);
         sizer.add(&button3, 0, SizerFlag::Expand, 8);
+        sizer.add(&button4, 0, SizerFlag::Expand, 8);
 
         // Simple multiline log area using a StaticText (supports '\n').
         // If you need scrolling, consider adding a TextCtrl wrapper later.
@@ -35,8 +39,8 @@ fn main() {
 
         panel.set_sizer(sizer, true);
 
-        // Shared state: Store tokens and next binding index
-        let tokens = Rc::new(RefCell::new(Vec::new()));
+        // Shared state: an EventScope tracking the bindings on Button 1, and the next binding index
+        let scope = Rc::new(RefCell::new(EventScope::new()));
         let next_index = Rc::new(RefCell::new(1));
 
         // Helper to append a line to the log
@@ -50,20 +54,20 @@ fn main() {
 
         // Button 2: Remove the first event binding from Button 1
         {
-            let tokens_clone = tokens.clone();
+            let scope_clone = scope.clone();
             let log_buffer_clone = log_buffer.clone();
             button2.on_click(move |_| {
-                let mut tokens_vec = tokens_clone.borrow_mut();
-                if tokens_vec.is_empty() {
-                    append_line(&log_buffer_clone, &log_view, "No event bindings to remove!");
-                } else {
-                    // Remove the first token
-                    let token = tokens_vec.remove(0);
-                    if button1.unbind(token) {
-                        let line = format!("Removed event binding! {} binding(s) remaining.", tokens_vec.len());
-                        append_line(&log_buffer_clone, &log_view, &line);
-                    } else {
-                        append_line(&log_buffer_clone, &log_view, "Failed to unbind event!");
+                let mut scope = scope_clone.borrow_mut();
+                match scope.tokens().next() {
+                    None => append_line(&log_buffer_clone, &log_view, "No event bindings to remove!"),
+                    // Remove the oldest binding
+                    Some(token) => {
+                        if scope.unbind(token) {
+                            let line = format!("Removed event binding! {} binding(s) remaining.", scope.len());
+                            append_line(&log_buffer_clone, &log_view, &line);
+                        } else {
+                            append_line(&log_buffer_clone, &log_view, "Failed to unbind event!");
+                        }
                     }
                 }
             });
@@ -71,7 +75,7 @@ fn main() {
 
         // Button 3: Add a new event binding to Button 1
         {
-            let tokens_clone = tokens.clone();
+            let scope_clone = scope.clone();
             let next_index_clone = next_index.clone();
             let log_buffer_clone = log_buffer.clone();
 
@@ -80,20 +84,28 @@ fn main() {
                 let current_index = *index_val;
                 *index_val += 1;
 
-                // Add a new event binding to button1
+                // Add a new event binding to button1, tracked by the scope
                 let log_buffer_clicked = log_buffer_clone.clone();
-                let token = button1.on_click(move |_| {
-                    let line = format!("On click callback #{current_index}");
-                    append_line(&log_buffer_clicked, &log_view, &line);
+                let mut scope = scope_clone.borrow_mut();
+                scope.bind(&button1, |button| {
+                    button.on_click(move |_| {
+                        let line = format!("On click callback #{current_index}");
+                        append_line(&log_buffer_clicked, &log_view, &line);
+                    })
                 });
 
-                // Store the token
-                tokens_clone.borrow_mut().push(token);
+                let line = format!("Added event binding #{current_index}! Total bindings: {}", scope.len());
+                append_line(&log_buffer_clone, &log_view, &line);
+            });
+        }
 
-                let line = format!(
-                    "Added event binding #{current_index}! Total bindings: {}",
-                    tokens_clone.borrow().len()
-                );
+        // Button 4: Clear the whole scope at once
+        {
+            let scope_clone = scope.clone();
+            let log_buffer_clone = log_buffer.clone();
+            button4.on_click(move |_| {
+                let removed = scope_clone.borrow_mut().clear();
+                let line = format!("Removed all event bindings ({removed} removed).");
                 append_line(&log_buffer_clone, &log_view, &line);
             });
         }
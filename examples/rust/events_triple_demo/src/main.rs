@@ -1,9 +1,9 @@
 use std::cell::RefCell;
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use wxdragon::event::{IdleEvent, IdleMode, WindowEvents};
 use wxdragon::prelude::*;
+use wxdragon::task::TaskHandle;
 
 fn main() {
     SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
@@ -26,49 +26,69 @@ fn main() {
 
         let status = StaticText::builder(&frame).with_label("Status: Ready").build();
 
-        let toggle_unsaved_btn = Button::builder(&frame).with_label("Toggle Unsaved").build();
+        // The close veto keys off the editor's own modified flag
+        let editor = TextCtrl::builder(&frame)
+            .with_style(TextCtrlStyle::MultiLine)
+            .with_value("Edit this text, then try to close the window.")
+            .build();
+
+        let save_btn = Button::builder(&frame).with_label("Save").build();
 
         let bg_task_btn = Button::builder(&frame).with_label("Start Background Task").build();
 
         let idle_btn = Button::builder(&frame).with_label("Start Idle Work").build();
 
         // State
-        let unsaved = Rc::new(RefCell::new(false));
         let idle_remaining = Rc::new(RefCell::new(0_i32));
-        let bg_msg: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
 
-        // Toggle unsaved
+        // Only user edits arrive here, setting the value doesn't send text events
+        editor.on_text_updated(move |_| status.set_label("Status: unsaved changes"));
+
+        // Saving clears the modified flag
         // Widgets are Copy, Rc/Arc need .clone()
-        let unsaved_toggle = unsaved.clone();
-        toggle_unsaved_btn.on_click(move |_| {
-            let mut u = unsaved_toggle.borrow_mut();
-            *u = !*u;
-            status.set_label(&format!("Status: unsaved = {}", *u));
+        save_btn.on_click(move |_| {
+            let path = std::env::temp_dir().join("events_triple_demo.txt");
+            if editor.save_file(&path.to_string_lossy()) {
+                status.set_label(&format!("Status: saved to {}", path.display()));
+            } else {
+                status.set_label("Status: saving failed");
+            }
         });
 
-        // Background task: simulate work, notify start and completion via status updates
-        let bg_msg_btn = bg_msg.clone();
+        // Background task: simulate work on a worker thread. Progress and the result are
+        // delivered to the callbacks below on the GUI thread; clicking again cancels.
+        let running_task: Rc<RefCell<Option<TaskHandle<u32, bool>>>> = Rc::new(RefCell::new(None));
         bg_task_btn.on_click(move |_| {
-            {
-                if let Ok(mut m) = bg_msg_btn.lock() {
-                    *m = Some("Status: background task started...".to_string());
-                }
+            if let Some(task) = running_task.borrow().as_ref() {
+                task.cancel();
+                status.set_label("Status: cancelling background task...");
+                return;
             }
-            wxdragon::call_after(Box::new(|| {
-                // Wake the GUI event loop so the Idle handler runs promptly
-                // and applies the pending "started" status update.
-            }));
-            let bg_msg = bg_msg_btn.clone();
-            std::thread::spawn(move || {
-                std::thread::sleep(Duration::from_millis(1000));
-                // Mark completion; Idle handler will pick this up and update the label
-                if let Ok(mut m) = bg_msg.lock() {
-                    *m = Some("Status: background task finished.".to_string());
+
+            status.set_label("Status: background task started...");
+            bg_task_btn.set_label("Cancel Background Task");
+            let running_done = running_task.clone();
+            let task = wxdragon::task::spawn(|progress| {
+                for step in 1..=10 {
+                    if progress.is_cancelled() {
+                        return false;
+                    }
+                    std::thread::sleep(Duration::from_millis(100));
+                    progress.report(step * 10);
+                }
+                true
+            })
+            .on_progress(move |percent| status.set_label(&format!("Status: background task {percent}%")))
+            .on_complete(move |finished| {
+                running_done.borrow_mut().take();
+                bg_task_btn.set_label("Start Background Task");
+                if finished {
+                    status.set_label("Status: background task finished.");
+                } else {
+                    status.set_label("Status: background task cancelled.");
                 }
-                wxdragon::call_after(Box::new(move || {
-                    // Second tick to ensure GUI wakes up; idle will apply message if any.
-                }));
             });
+            *running_task.borrow_mut() = Some(task);
         });
 
         // Idle work: start a chunked job processed in idle handler
@@ -78,16 +98,9 @@ fn main() {
             status.set_label("Status: idle work scheduled (100000 units)");
         });
 
-        // Idle handler: process a few units each idle; also apply background messages
+        // Idle handler: process a few units each idle
         let idle_remaining_handler = idle_remaining.clone();
-        let bg_msg_handler = bg_msg.clone();
         frame.on_idle(move |e| {
-            // Apply background message if any
-            if let Ok(mut m) = bg_msg_handler.lock()
-                && let Some(text) = m.take()
-            {
-                status.set_label(&text);
-            }
             let mut left = idle_remaining_handler.borrow_mut();
             if *left > 0 {
                 let step = 7;
@@ -100,11 +113,10 @@ fn main() {
         });
 
         // Veto close if unsaved
-        let unsaved_close = unsaved.clone();
         frame.on_close(move |e| {
             if let WindowEventData::General(ev) = e
                 && ev.can_veto()
-                && *unsaved_close.borrow()
+                && editor.is_modified()
             {
                 ev.veto();
                 status.set_label("Status: close vetoed (unsaved)");
@@ -113,14 +125,14 @@ fn main() {
 
         // Layout
         sizer.add(&status, 0, SizerFlag::All | SizerFlag::Expand, 8);
+        sizer.add(&editor, 1, SizerFlag::All | SizerFlag::Expand, 8);
 
         let row = BoxSizer::builder(Orientation::Horizontal).build();
-        row.add(&toggle_unsaved_btn, 0, SizerFlag::Right | SizerFlag::All, 4);
+        row.add(&save_btn, 0, SizerFlag::Right | SizerFlag::All, 4);
         row.add(&bg_task_btn, 0, SizerFlag::Right | SizerFlag::All, 4);
         row.add(&idle_btn, 0, SizerFlag::Right | SizerFlag::All, 4);
 
         sizer.add_sizer(&row, 0, SizerFlag::AlignCenterHorizontal | SizerFlag::All, 4);
-        sizer.add_stretch_spacer(1);
 
         frame.set_sizer(sizer, true);
         frame.show(true);
@@ -0,0 +1,101 @@
+//! Export Columns Demo - a dialog picking the columns to export with a CheckListBox.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use wxdragon::prelude::*;
+
+const COLUMNS: [&str; 8] = ["Id", "Name", "Email", "Phone", "Company", "City", "Country", "Created"];
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("Export Columns Demo")
+            .with_size(Size::new(420, 300))
+            .build();
+        let panel = Panel::builder(&frame).build();
+
+        let export_button = Button::builder(&panel).with_label("Export...").build();
+        let log = TextCtrl::builder(&panel)
+            .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .build();
+
+        // The columns picked last time are checked again when the dialog reopens
+        let selected: Rc<RefCell<Vec<u32>>> = Rc::new(RefCell::new(vec![0, 1, 2]));
+        export_button.on_click(move |_| {
+            let previous = selected.borrow().clone();
+            if let Some(columns) = choose_columns(&frame, &previous) {
+                let names: Vec<&str> = columns.iter().map(|&index| COLUMNS[index as usize]).collect();
+                log.append_text(&format!("Exporting {}\n", names.join(", ")));
+                *selected.borrow_mut() = columns;
+            }
+        });
+
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add(&export_button, 0, SizerFlag::All, 10);
+        sizer.add(
+            &log,
+            1,
+            SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+            10,
+        );
+        panel.set_sizer(sizer, true);
+
+        frame.show(true);
+        frame.centre();
+    });
+}
+
+// Asks for the columns to export, starting with `checked`; returns `None` if cancelled
+fn choose_columns(parent: &Frame, checked: &[u32]) -> Option<Vec<u32>> {
+    let dialog = Dialog::builder(parent, "Export Columns")
+        .with_style(DialogStyle::DefaultDialogStyle | DialogStyle::ResizeBorder)
+        .build();
+
+    let columns = CheckListBox::builder(&dialog)
+        .with_choices(COLUMNS.iter().map(|column| column.to_string()).collect())
+        .build();
+    columns.set_checked_items(checked);
+
+    let summary = StaticText::builder(&dialog).build();
+    let update_summary = move || {
+        summary.set_label(&format!("{} of {} columns", columns.get_checked_items().len(), COLUMNS.len()));
+    };
+    update_summary();
+
+    columns.on_item_toggled(move |event| {
+        if let (Some(index), Some(checked)) = (event.get_selection(), event.is_checked()) {
+            let state = if checked { "checked" } else { "unchecked" };
+            println!("{} {state}", COLUMNS[index as usize]);
+        }
+        update_summary();
+    });
+
+    // Checking all items at once sends no toggle events, so the summary is updated here
+    let all_button = Button::builder(&dialog).with_label("All").build();
+    all_button.on_click(move |_| {
+        columns.check_all(true);
+        update_summary();
+    });
+    let none_button = Button::builder(&dialog).with_label("None").build();
+    none_button.on_click(move |_| {
+        columns.check_all(false);
+        update_summary();
+    });
+
+    let row = BoxSizer::builder(Orientation::Horizontal).build();
+    row.add(&all_button, 0, SizerFlag::Right, 5);
+    row.add(&none_button, 0, SizerFlag::Right, 10);
+    row.add(&summary, 1, SizerFlag::AlignCenterVertical, 0);
+
+    let sizer = BoxSizer::builder(Orientation::Vertical).build();
+    sizer.add(&columns, 1, SizerFlag::Expand | SizerFlag::All, 10);
+    sizer.add_sizer(&row, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 10);
+    if let Some(buttons) = dialog.create_std_dialog_button_sizer(StdDialogButtons::Ok | StdDialogButtons::Cancel) {
+        sizer.add_sizer(&buttons, 0, SizerFlag::Expand | SizerFlag::All, 10);
+    }
+    dialog.set_sizer_and_fit(sizer, true);
+
+    let result = (dialog.show_modal() == ID_OK).then(|| columns.get_checked_items());
+    dialog.destroy();
+    result
+}
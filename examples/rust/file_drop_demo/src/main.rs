@@ -0,0 +1,82 @@
+use std::path::Path;
+use wxdragon::prelude::*;
+
+/// Returns true if the file has one of the (lowercase) extensions
+fn has_extension(file: &str, extensions: &[&str]) -> bool {
+    Path::new(file)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| extensions.contains(&ext.to_lowercase().as_str()))
+}
+
+/// A titled list that only accepts dropped files with the given extensions
+fn create_file_list(parent: &Panel, title: &str, extensions: &'static [&'static str], log: TextCtrl) -> BoxSizer {
+    let sizer = BoxSizer::builder(Orientation::Vertical).build();
+    let label = StaticText::builder(parent)
+        .with_label(&format!("{title} ({})", extensions.join(", ")))
+        .build();
+    sizer.add(&label, 0, SizerFlag::All, 5);
+
+    let list = ListBox::builder(parent).build();
+    sizer.add(&list, 1, SizerFlag::Expand | SizerFlag::All, 5);
+
+    let title = title.to_string();
+    list.set_file_drop_target(move |pos, files| {
+        let (accepted, rejected): (Vec<String>, Vec<String>) =
+            files.into_iter().partition(|file| has_extension(file, extensions));
+        for file in &accepted {
+            list.append(file);
+        }
+        log.append_text(&format!(
+            "{title}: {} added, {} rejected at ({}, {})\n",
+            accepted.len(),
+            rejected.len(),
+            pos.x,
+            pos.y
+        ));
+        // Returning false tells the drag source nothing was dropped
+        !accepted.is_empty()
+    });
+
+    sizer
+}
+
+fn main() {
+    SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("File Drop Targets")
+            .with_size(Size::new(700, 500))
+            .build();
+
+        let panel = Panel::builder(&frame).build();
+        let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+
+        let hint = StaticText::builder(&panel)
+            .with_label("Drop images on the left list, documents on the right one. The log refuses all drops.")
+            .build();
+        main_sizer.add(&hint, 0, SizerFlag::All, 10);
+
+        let log = TextCtrl::builder(&panel)
+            .with_size(Size::new(-1, 150))
+            .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .build();
+
+        // Each list has its own drop target with its own rules
+        let lists_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+        let images = create_file_list(&panel, "Images", &["png", "jpg", "jpeg", "gif", "svg"], log);
+        let documents = create_file_list(&panel, "Documents", &["txt", "md", "pdf"], log);
+        lists_sizer.add_sizer(&images, 1, SizerFlag::Expand, 0);
+        lists_sizer.add_sizer(&documents, 1, SizerFlag::Expand, 0);
+        main_sizer.add_sizer(&lists_sizer, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 5);
+
+        // The log shows the "no entry" cursor for anything dragged over it
+        log.set_file_drop_target_with_feedback(|_pos, _files| false, |_pos| DragResult::None);
+        main_sizer.add(&log, 0, SizerFlag::Expand | SizerFlag::All, 10);
+
+        panel.set_sizer(main_sizer, true);
+        frame.show(true);
+        frame.centre();
+    });
+}
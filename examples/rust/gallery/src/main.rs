@@ -22,6 +22,22 @@ const ID_TOOL_NEW: Id = ID_HIGHEST + 1;
 const ID_TOOL_OPEN: Id = ID_HIGHEST + 2;
 const ID_TOOL_SAVE: Id = ID_HIGHEST + 3;
 
+// Toolbar icons
+const TOOL_NEW_SVG: &[u8] = include_bytes!("../asset/toolbar/new.svg");
+const TOOL_OPEN_SVG: &[u8] = include_bytes!("../asset/toolbar/open.svg");
+const TOOL_SAVE_SVG: &[u8] = include_bytes!("../asset/toolbar/save.svg");
+const TOOL_EXIT_SVG: &[u8] = include_bytes!("../asset/toolbar/exit.svg");
+
+/// Adds a tool with an SVG icon, which stays sharp at any display scaling
+fn add_svg_tool(toolbar: &ToolBar, id: Id, label: &str, svg: &[u8], icon_size: Size, short_help: &str) {
+    match BitmapBundle::from_svg_bytes(svg, icon_size) {
+        Some(bundle) => {
+            toolbar.add_tool(id, label, bundle, short_help);
+        }
+        None => log::warn!("Failed to load the SVG icon for the {label} tool"),
+    }
+}
+
 // --- Main Application Logic ---
 
 fn main() {
@@ -37,16 +53,34 @@ fn main() {
         frame.centre();
 
         // --- Menu Bar Setup ---
-        let file_menu = Menu::builder()
-            .append_item(ID_EXIT, "E&xit\tAlt-X", "Quit this program")
-            .build();
-        let help_menu = Menu::builder()
-            .append_item(ID_ABOUT, "&About...", "Show about dialog")
-            .build();
-        let menubar = MenuBar::builder()
-            .append(file_menu, "&File")
-            .append(help_menu, "&Help")
-            .build();
+        // The frame is built in code, the menu bar comes from XRC. Stock names such as
+        // wxID_EXIT keep their stock ids, the other items are matched with xrc_id().
+        let resource = XmlResource::get();
+        resource.init_all_handlers();
+        resource
+            .load_from_string(include_str!("../asset/menus.xrc"))
+            .expect("Failed to load menus.xrc");
+        let menubar = resource
+            .load_menu_bar("gallery_menubar")
+            .expect("Failed to load the gallery menu bar");
+        let id_previous_tab = xrc_id("menu_previous_tab");
+        let id_next_tab = xrc_id("menu_next_tab");
+
+        // Stock icons for the menu items, from the platform theme where it has one
+        let menu_art = [
+            (ID_EXIT, ArtId::Quit),
+            (id_previous_tab, ArtId::GoBack),
+            (id_next_tab, ArtId::GoForward),
+            (ID_ABOUT, ArtId::Information),
+        ];
+        for (id, art_id) in menu_art {
+            if let (Some(item), Some(bundle)) = (
+                menubar.find_item(id),
+                ArtProvider::get_bitmap_bundle(art_id, ArtClient::Menu, None),
+            ) {
+                item.set_bitmap(bundle);
+            }
+        }
         frame.set_menu_bar(menubar);
 
         // --- Status Bar Setup ---
@@ -125,41 +159,31 @@ fn main() {
         let tb_style = ToolBarStyle::Text | ToolBarStyle::Default;
         if let Some(toolbar) = frame.create_tool_bar(Some(tb_style), ID_ANY as i32) {
             // Get sizes for the toolbar icons (platform-dependent)
-            let icon_size = ArtProvider::get_native_dip_size_hint(ArtClient::Toolbar);
-            log::info!("Native toolbar icon size: {}x{}", icon_size.width, icon_size.height);
-
-            // New Tool
-            if let Some(new_bundle) = ArtProvider::get_bitmap_bundle(ArtId::New, ArtClient::Toolbar, None) {
-                toolbar.add_tool_bundle(ID_TOOL_NEW, "New", &new_bundle, "Create a new file");
-                log::debug!("Using BitmapBundle for New tool");
-            } else if let Some(new_icon) = ArtProvider::get_bitmap(ArtId::New, ArtClient::Toolbar, None) {
-                toolbar.add_tool(ID_TOOL_NEW, "New", &new_icon, "Create a new file");
-                log::debug!("Fallback to Bitmap for New tool");
-            } else {
-                log::warn!("Failed to get ArtId::New for toolbar");
-            }
-
-            // Open Tool
-            if let Some(open_bundle) = ArtProvider::get_bitmap_bundle(ArtId::FileOpen, ArtClient::Toolbar, None) {
-                toolbar.add_tool_bundle(ID_TOOL_OPEN, "Open", &open_bundle, "Open an existing file");
-                log::debug!("Using BitmapBundle for Open tool");
-            } else if let Some(open_icon) = ArtProvider::get_bitmap(ArtId::FileOpen, ArtClient::Toolbar, None) {
-                toolbar.add_tool(ID_TOOL_OPEN, "Open", &open_icon, "Open an existing file");
-                log::debug!("Fallback to Bitmap for Open tool");
-            } else {
-                log::warn!("Failed to get ArtId::FileOpen for toolbar");
-            }
-
-            // Save Tool
-            if let Some(save_bundle) = ArtProvider::get_bitmap_bundle(ArtId::FileSave, ArtClient::Toolbar, None) {
-                toolbar.add_tool_bundle(ID_TOOL_SAVE, "Save", &save_bundle, "Save the current file");
-                log::debug!("Using BitmapBundle for Save tool");
-            } else if let Some(save_icon) = ArtProvider::get_bitmap(ArtId::FileSave, ArtClient::Toolbar, None) {
-                toolbar.add_tool(ID_TOOL_SAVE, "Save", &save_icon, "Save the current file");
-                log::debug!("Fallback to Bitmap for Save tool");
-            } else {
-                log::warn!("Failed to get ArtId::FileSave for toolbar");
+            let mut icon_size = ArtProvider::get_native_dip_size_hint(ArtClient::Toolbar);
+            if icon_size.width <= 0 || icon_size.height <= 0 {
+                icon_size = Size::new(24, 24);
             }
+            log::info!("Native toolbar icon size: {}x{}", icon_size.width, icon_size.height);
+            toolbar.set_tool_bitmap_size(icon_size);
+
+            // The icons are SVGs, rendered at the exact pixel size for the display scaling
+            add_svg_tool(&toolbar, ID_TOOL_NEW, "New", TOOL_NEW_SVG, icon_size, "Create a new file");
+            add_svg_tool(
+                &toolbar,
+                ID_TOOL_OPEN,
+                "Open",
+                TOOL_OPEN_SVG,
+                icon_size,
+                "Open an existing file",
+            );
+            add_svg_tool(
+                &toolbar,
+                ID_TOOL_SAVE,
+                "Save",
+                TOOL_SAVE_SVG,
+                icon_size,
+                "Save the current file",
+            );
 
             // separator before exit, and `toolbar.add_separator();` doesn't work for some reason,
             // so add a StaticLine as a workaround
@@ -172,19 +196,45 @@ fn main() {
             sep.set_foreground_color(color);
             toolbar.add_control(&sep);
 
-            // Exit Tool
-            if let Some(exit_bundle) = ArtProvider::get_bitmap_bundle(ArtId::Quit, ArtClient::Toolbar, None) {
-                toolbar.add_tool_bundle(ID_EXIT, "Exit", &exit_bundle, "Quit this program");
-                log::debug!("Using BitmapBundle for Exit tool");
-            } else if let Some(exit_icon) = ArtProvider::get_bitmap(ArtId::Quit, ArtClient::Toolbar, None) {
-                toolbar.add_tool(ID_EXIT, "Exit", &exit_icon, "Quit this program");
-                log::debug!("Fallback to Bitmap for Exit tool");
-            } else {
-                // no bitmap available, add text-only tool using the null bitmap
-                toolbar.add_tool(ID_EXIT, "Exit", &Bitmap::null_bitmap(), "Quit this program");
-                log::warn!("Failed to get ArtId::Quit for toolbar, added text-only Exit tool");
+            // The View menu's commands, with the menu items' ids so on_menu handles both
+            let tab_tools = [
+                (id_previous_tab, "Previous", ArtId::GoBack, "Show the previous tab"),
+                (id_next_tab, "Next", ArtId::GoForward, "Show the next tab"),
+            ];
+            for (id, label, art_id, short_help) in tab_tools {
+                match ArtProvider::get_bitmap_bundle(art_id, ArtClient::Toolbar, Some(icon_size)) {
+                    Some(bundle) => {
+                        toolbar.add_tool(id, label, bundle, short_help);
+                    }
+                    None => log::warn!("Failed to get the icon for the {label} tool"),
+                }
             }
 
+            add_svg_tool(&toolbar, ID_EXIT, "Exit", TOOL_EXIT_SVG, icon_size, "Quit this program");
+
+            // A search field at the end of the toolbar jumps to the first tab whose title
+            // contains the text
+            toolbar.add_stretchable_space();
+            let tab_search = SearchCtrl::builder(&toolbar)
+                .with_descriptive_text("Find tab")
+                .with_size(Size::new(160, -1))
+                .build();
+            toolbar.add_control(&tab_search);
+            tab_search.on_search(move |_| {
+                let query = tab_search.get_value().to_lowercase();
+                let found = (0..notebook.get_page_count()).find(|&index| {
+                    notebook
+                        .get_page_text(index)
+                        .is_some_and(|text| text.to_lowercase().contains(&query))
+                });
+                match found {
+                    Some(index) => {
+                        notebook.set_selection(index);
+                    }
+                    None => frame.set_status_text(&format!("No tab matches '{query}'"), 0),
+                }
+            });
+
             toolbar.realize();
         }
 
@@ -230,6 +280,8 @@ fn main() {
             id if id == ID_ABOUT => {
                 log::info!("Menu: About clicked!");
             }
+            id if id == id_previous_tab => notebook.advance_selection(false),
+            id if id == id_next_tab => notebook.advance_selection(true),
             id if id == ID_TOOL_NEW => {
                 log::info!("Toolbar: New clicked!");
             }
@@ -285,6 +337,16 @@ fn main() {
             );
         });
 
+        // Tabs can be dragged into another order
+        notebook.enable_tab_drag_reorder(true);
+        notebook.on_pages_reordered(move |event_data| {
+            let (Some(new_index), Some(old_index)) = (event_data.get_selection(), event_data.get_old_selection()) else {
+                return;
+            };
+            let page_text = notebook.get_page_text(new_index as usize).unwrap_or_default();
+            frame.set_status_text(&format!("Moved tab '{page_text}' from {old_index} to {new_index}"), 0);
+        });
+
         // Bind tab-specific events
         basic_controls.bind_events();
         advanced_controls.bind_events();
@@ -100,7 +100,11 @@ pub fn create_advanced_tab(notebook: &Notebook) -> (SplitterWindow, AdvancedTabC
         .with_min_value(0)
         .with_max_value(100)
         .with_size(Size::new(200, -1))
+        .with_style(SliderStyle::AutoTicks)
         .build();
+    slider.set_tick_freq(10);
+    slider.set_page_size(10);
+    slider.set_line_size(1);
     slider_sizer.add(&slider, 0, SizerFlag::Expand | SizerFlag::All, 5);
     slider_panel.set_sizer(slider_sizer, true);
 
@@ -210,6 +214,26 @@ impl AdvancedTabControls {
             gauge_status_label.set_label(&format!("Gauge Value: {value}"));
         });
 
+        // Log which scroll sub-event fired, e.g. live thumb tracking vs. the final release
+        let log_scroll = |event: ScrollEvent| {
+            println!(
+                "SLIDER Scroll Event (Advanced Tab): {:?}, Position: {:?}, Vertical: {}",
+                event.get_scroll_type(),
+                event.get_position(),
+                event.is_vertical()
+            );
+            event.event.skip(true);
+        };
+        self.slider.on_scroll_top(log_scroll);
+        self.slider.on_scroll_bottom(log_scroll);
+        self.slider.on_line_up(log_scroll);
+        self.slider.on_line_down(log_scroll);
+        self.slider.on_page_up(log_scroll);
+        self.slider.on_page_down(log_scroll);
+        self.slider.on_thumb_track(log_scroll);
+        self.slider.on_thumb_release(log_scroll);
+        self.slider.on_scroll_changed(log_scroll);
+
         // Timer for Gauge Pulse Demo (simulate pulse by cycling value)
         let timer = Timer::new(&self.gauge); // Use any widget as owner; here use gauge
         timer.start(200, false); // 200ms interval, repeating
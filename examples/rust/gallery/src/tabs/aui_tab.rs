@@ -46,9 +46,13 @@ pub fn create_aui_tab(parent: &Notebook) -> AuiTabControls {
         .build();
 
     // Add tools with IDs so we can identify them in the event handler
-    toolbar.add_tool(1001, "Save Perspective", "Save the current layout", WidgetItemKind::Normal);
+    let save_icon = ArtProvider::get_bitmap_bundle(ArtId::FileSave, ArtClient::Toolbar, None).unwrap_or_default();
+    let load_icon = ArtProvider::get_bitmap_bundle(ArtId::FileOpen, ArtClient::Toolbar, None).unwrap_or_default();
+    toolbar.add_tool(1001, "Save Perspective", &save_icon, WidgetItemKind::Normal);
+    toolbar.set_tool_short_help(1001, "Save the current layout");
     toolbar.add_separator();
-    toolbar.add_tool(1002, "Load Perspective", "Restore a saved layout", WidgetItemKind::Normal);
+    toolbar.add_tool(1002, "Load Perspective", &load_icon, WidgetItemKind::Normal);
+    toolbar.set_tool_short_help(1002, "Restore a saved layout");
     toolbar.add_stretch_spacer(1); // Add stretch space to push tools to the left
 
     // Realize the toolbar to finalize its layout
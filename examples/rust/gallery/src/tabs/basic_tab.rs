@@ -38,6 +38,24 @@ pub struct BasicTabControls {
     pub collapsible_pane: CollapsiblePane,
 }
 
+/// Every three-syllable combination of 22 syllables, sorted.
+fn made_up_words() -> Vec<String> {
+    const SYLLABLES: [&str; 22] = [
+        "ba", "ko", "ri", "ta", "mu", "ne", "sa", "lo", "vi", "da", "pe", "zu", "ka", "mi", "to", "ra", "fe", "no", "gu", "li",
+        "so", "we",
+    ];
+    let mut words = Vec::with_capacity(SYLLABLES.len().pow(3));
+    for first in SYLLABLES {
+        for second in SYLLABLES {
+            for third in SYLLABLES {
+                words.push(format!("{first}{second}{third}"));
+            }
+        }
+    }
+    words.sort();
+    words
+}
+
 pub fn create_basic_tab(notebook: &Notebook, _frame: &Frame) -> BasicTabControls {
     let basic_panel = Panel::builder(notebook).with_style(PanelStyle::TabTraversal).build();
 
@@ -48,6 +66,23 @@ pub fn create_basic_tab(notebook: &Notebook, _frame: &Frame) -> BasicTabControls
         .with_style(TextCtrlStyle::ProcessEnter)
         .build();
     text_ctrl.set_tooltip("Enter text here.");
+
+    // Completion against a large list, computed for each prefix as the user types
+    let autocomplete_label = StaticText::builder(&basic_panel).with_label("Autocomplete:").build();
+    let autocomplete_ctrl = TextCtrl::builder(&basic_panel).build();
+    autocomplete_ctrl.set_tooltip("Type a few letters, e.g. \"kara\", to pick one of 10,648 made-up words.");
+    let words = made_up_words();
+    autocomplete_ctrl.auto_complete_with(move |prefix| {
+        // The list is sorted, so the matches start where the prefix would be inserted
+        let prefix = prefix.to_lowercase();
+        let first = words.partition_point(|word| word.as_str() < prefix.as_str());
+        words[first..]
+            .iter()
+            .take_while(|word| word.starts_with(&prefix))
+            .take(100)
+            .cloned()
+            .collect()
+    });
     let spin_button_label = StaticText::builder(&basic_panel).with_label("Spin Button:").build();
     let spin_button = SpinButton::builder(&basic_panel).build();
     spin_button.set_tooltip("Click arrows or use keys to change the value (wraps around).");
@@ -131,16 +166,14 @@ pub fn create_basic_tab(notebook: &Notebook, _frame: &Frame) -> BasicTabControls
     }
     let red_bitmap = Bitmap::from_rgba(&bmp_data, BMP_WIDTH, BMP_HEIGHT).expect("Failed to create test bitmap");
     let bitmap_button_label = StaticText::builder(&basic_panel).with_label("Bitmap Button:").build();
-    let bitmap_button = BitmapButton::builder(&basic_panel).with_bitmap(Some(red_bitmap)).build();
+    let bitmap_button = BitmapButton::builder(&basic_panel).with_bitmap(red_bitmap).build();
     bitmap_button.set_tooltip("A button with a custom red square bitmap.");
 
     let open_icon_bitmap = ArtProvider::get_bitmap(ArtId::FileOpen, ArtClient::Button, None)
         .or_else(|| ArtProvider::get_bitmap(ArtId::Error, ArtClient::Button, None))
         .expect("Failed to get ART_FILE_OPEN or ART_ERROR icon");
     let art_button_label = StaticText::builder(&basic_panel).with_label("Art Button:").build();
-    let art_button = BitmapButton::builder(&basic_panel)
-        .with_bitmap(Some(open_icon_bitmap))
-        .build();
+    let art_button = BitmapButton::builder(&basic_panel).with_bitmap(open_icon_bitmap).build();
     art_button.set_tooltip("A button using an icon from the ArtProvider.");
 
     let activity_label = StaticText::builder(&basic_panel).with_label("Activity:").build();
@@ -264,6 +297,9 @@ pub fn create_basic_tab(notebook: &Notebook, _frame: &Frame) -> BasicTabControls
     grid.add(&static_text_label, 0, label_flags, 0);
     grid.add(&text_ctrl, 1, control_flags, 0);
 
+    grid.add(&autocomplete_label, 0, label_flags, 0);
+    grid.add(&autocomplete_ctrl, 1, control_flags, 0);
+
     grid.add(&spin_button_label, 0, label_flags, 0);
     grid.add(&spin_button, 0, SizerFlag::AlignLeft | SizerFlag::AlignCenterVertical, 0);
 
@@ -482,6 +518,16 @@ impl BasicTabControls {
             // Example: log text changes if needed
         });
 
+        // Caret and selection moves (sent only when they changed)
+        self.text_ctrl.on_caret_or_selection_changed(move |_event| {
+            let (from, to) = text_ctrl.get_selection();
+            if from != to {
+                println!("TextCtrl selection: {from}..{to}");
+            } else {
+                println!("TextCtrl caret at {from}");
+            }
+        });
+
         // TextCtrl Enter key event
         self.text_ctrl.on_text_enter(move |event| {
             if let Some(text) = event.get_string() {
@@ -584,7 +630,7 @@ impl BasicTabControls {
         });
 
         // SearchCtrl Event Handlers
-        self.search_ctrl.on_search_button_clicked(move |event| {
+        self.search_ctrl.on_search(move |event| {
             let event_text = event.get_string().unwrap_or_default();
             let widget_text = search_ctrl.get_value();
             println!(
@@ -595,7 +641,7 @@ impl BasicTabControls {
             );
         });
 
-        self.search_ctrl.on_cancel_button_clicked(move |event| {
+        self.search_ctrl.on_cancel(move |event| {
             let value_before_clear = search_ctrl.get_value();
             search_ctrl.set_value("");
             println!(
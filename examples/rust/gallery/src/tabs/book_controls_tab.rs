@@ -1,8 +1,18 @@
 use wxdragon::prelude::*;
 
+const STEPS: [&str; 3] = [
+    "Step 1: a SimpleBook has no tabs, the buttons below switch its pages.",
+    "Step 2: tick the box to be allowed to go on.",
+    "Step 3: done! Pages slide in the direction you navigate where supported.",
+];
+
 pub struct BookControlsTab {
     pub tab_panel: Panel,
     pub treebook: Treebook,
+    pub simplebook: SimpleBook,
+    pub back_button: Button,
+    pub next_button: Button,
+    pub agree_checkbox: CheckBox,
 }
 
 pub fn create_book_controls_tab(notebook: &Notebook) -> BookControlsTab {
@@ -60,9 +70,41 @@ pub fn create_book_controls_tab(notebook: &Notebook) -> BookControlsTab {
     advanced_settings_panel.set_sizer(advanced_sizer, true);
     advanced_settings_panel.fit();
 
+    // SimpleBook: a wizard-like page switcher driven by the Back/Next buttons
+    let simplebook_box = StaticBox::builder(&tab_panel).with_label("SimpleBook").build();
+    let simplebook = SimpleBook::builder(&simplebook_box).build();
+    let mut agree_checkbox = None;
+    for (index, text) in STEPS.iter().enumerate() {
+        let step_panel = Panel::builder(&simplebook).build();
+        simplebook.add_page(&step_panel, &format!("Step {}", index + 1), index == 0, None);
+        let step_sizer = BoxSizer::builder(Orientation::Vertical).build();
+        let step_label = StaticText::builder(&step_panel).with_label(text).build();
+        step_sizer.add(&step_label, 0, SizerFlag::All | SizerFlag::Expand, 10);
+        if index == 1 {
+            let checkbox = CheckBox::builder(&step_panel).with_label("I agree").build();
+            step_sizer.add(&checkbox, 0, SizerFlag::Left | SizerFlag::Right, 10);
+            agree_checkbox = Some(checkbox);
+        }
+        step_panel.set_sizer(step_sizer, true);
+    }
+    let agree_checkbox = agree_checkbox.expect("step 2 has a checkbox");
+
+    let back_button = Button::builder(&simplebook_box).with_label("< Back").build();
+    let next_button = Button::builder(&simplebook_box).with_label("Next >").build();
+    back_button.enable(false);
+    let nav_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+    nav_sizer.add_stretch_spacer(1);
+    nav_sizer.add(&back_button, 0, SizerFlag::Right, 5);
+    nav_sizer.add(&next_button, 0, SizerFlag::AlignCenterVertical, 0);
+
+    let simplebook_sizer = StaticBoxSizerBuilder::new_with_box(&simplebook_box, Orientation::Vertical).build();
+    simplebook_sizer.add(&simplebook, 1, SizerFlag::Expand | SizerFlag::All, 5);
+    simplebook_sizer.add_sizer(&nav_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
+
     // Sizer for the main tab panel, to make the Treebook expand
     let main_tab_sizer = BoxSizer::builder(Orientation::Vertical).build();
     main_tab_sizer.add(&treebook, 1, SizerFlag::Expand | SizerFlag::All, 5);
+    main_tab_sizer.add_sizer(&simplebook_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
 
     treebook.on_node_expanded(|event| {
         let id = event.get_id();
@@ -76,7 +118,14 @@ pub fn create_book_controls_tab(notebook: &Notebook) -> BookControlsTab {
     tab_panel.set_sizer(main_tab_sizer, true);
     tab_panel.fit();
 
-    BookControlsTab { tab_panel, treebook }
+    BookControlsTab {
+        tab_panel,
+        treebook,
+        simplebook,
+        back_button,
+        next_button,
+        agree_checkbox,
+    }
 }
 
 impl BookControlsTab {
@@ -90,5 +139,43 @@ impl BookControlsTab {
                 event
             );
         });
+
+        let simplebook = self.simplebook;
+        self.back_button.on_click(move |_| {
+            let current = simplebook.selection();
+            if current > 0 {
+                simplebook.set_effects(ShowEffect::SlideToRight, ShowEffect::SlideToRight, 200);
+                simplebook.set_selection(current as usize - 1);
+            }
+        });
+        self.next_button.on_click(move |_| {
+            let current = simplebook.selection();
+            if current >= 0 && (current as usize) + 1 < simplebook.get_page_count() {
+                simplebook.set_effects(ShowEffect::SlideToLeft, ShowEffect::SlideToLeft, 200);
+                simplebook.set_selection(current as usize + 1);
+            }
+        });
+
+        // Leaving step 2 forward needs the box ticked
+        let agree_checkbox = self.agree_checkbox;
+        simplebook.on_page_changing(move |event| {
+            if event.get_old_selection() == Some(1) && event.get_selection() == Some(2) && !agree_checkbox.is_checked() {
+                println!("SIMPLEBOOK_PAGE_CHANGING vetoed: tick \"I agree\" first");
+                event.veto();
+            }
+        });
+
+        let back_button = self.back_button;
+        let next_button = self.next_button;
+        simplebook.on_page_changed(move |event| {
+            let selection = event.get_selection().unwrap_or(0);
+            println!(
+                "SIMPLEBOOK_PAGE_CHANGED Event: OldSel={}, NewSel={}",
+                event.get_old_selection().unwrap_or(-2),
+                selection
+            );
+            back_button.enable(selection > 0);
+            next_button.enable((selection as usize) + 1 < simplebook.get_page_count());
+        });
     }
 }
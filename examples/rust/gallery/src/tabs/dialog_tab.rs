@@ -1,10 +1,8 @@
 use wxdragon::prelude::*;
 
-use std::time::Duration;
-use std::{
-    sync::{Arc, Mutex},
-    thread,
-};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 #[allow(dead_code)]
 pub struct DialogTabControls {
@@ -206,6 +204,7 @@ pub fn create_dialog_tab(notebook: &Notebook, _frame: &Frame) -> DialogTabContro
     let font_picker_ctrl = FontPickerCtrl::builder(&dialog_panel)
         .with_initial_font(font_for_picker)
         .build();
+    font_picker_ctrl.set_max_point_size(48);
     let font_picker_status_label = StaticText::builder(&dialog_panel).with_label("Font: Default").build();
 
     // Event binding for font picker control is handled in bind_events()
@@ -399,7 +398,7 @@ impl DialogTabControls {
                 .with_message("Choose a file to save to")
                 .with_style(FileDialogStyle::Save | FileDialogStyle::OverwritePrompt)
                 .with_default_dir(".")
-                .with_default_file("my_document.txt")
+                .with_default_filename("my_document.txt")
                 .with_wildcard("Text files (*.txt)|*.txt|All files (*.*)|*.*")
                 .build();
             if dialog.show_modal() == wxdragon::id::ID_OK {
@@ -479,7 +478,9 @@ impl DialogTabControls {
 
         // Font Dialog Button
         self.font_button.on_click(move |_event| {
-            let font_dialog = FontDialog::builder(&frame).build();
+            // Start from the sample's current font
+            let current_font = font_sample_text.get_font();
+            let font_dialog = FontDialog::new(&frame, current_font.as_ref());
             if font_dialog.show_modal() == wxdragon::id::ID_OK {
                 if let Some(font) = font_dialog.get_font() {
                     // Create a copy of the font before setting it on the text control
@@ -494,6 +495,13 @@ impl DialogTabControls {
                         font_copy.get_style(),
                         font_copy.get_weight()
                     );
+                    // The native description is what an app would store in Config
+                    let desc = font_copy.native_description();
+                    let restored = Font::from_native_description(&desc);
+                    println!(
+                        "Font Dialog: Native description {desc:?} round-trips: {}",
+                        restored.is_some_and(|f| f.get_face_name() == font_copy.get_face_name())
+                    );
                 } else {
                     println!("Font Dialog: No font chosen or error retrieving font.");
                 }
@@ -502,28 +510,63 @@ impl DialogTabControls {
             }
         });
 
-        // Progress Dialog button
-        self.progress_button.on_click(move |_event| {
-            let dialog = ProgressDialog::builder(&frame, "Processing...", "Please wait", 100)
-                .with_style(ProgressDialogStyle::CanAbort | ProgressDialogStyle::Smooth | ProgressDialogStyle::AutoHide)
-                .build();
-
-            let mut keep_going = true;
-            for i in 0..=100 {
-                thread::sleep(Duration::from_millis(50));
-                if i == 50 {
-                    keep_going = dialog.update(i, Some("Halfway there!"));
-                } else {
-                    keep_going = dialog.update(i, None);
-                }
-                if !keep_going {
-                    println!("Progress Dialog: Aborted by user at {i}%");
-                    break;
-                }
-            }
-            if keep_going {
+        // Progress Dialog button: a fake 10 second job driven by a timer, so the
+        // UI keeps running between steps and Cancel/Skip are handled on each tick
+        const PROGRESS_STEPS: i32 = 100;
+        let progress_job: Rc<RefCell<Option<(ProgressDialog, i32)>>> = Rc::new(RefCell::new(None));
+        let progress_timer = Rc::new(Timer::new(&self.panel));
+
+        let job_for_tick = progress_job.clone();
+        let timer_for_tick = progress_timer.clone();
+        progress_timer.on_tick(move |_event| {
+            // Skip re-entrant ticks while the dialog is yielding
+            let Ok(mut job) = job_for_tick.try_borrow_mut() else {
+                return;
+            };
+            let Some((dialog, step)) = job.as_mut() else {
+                timer_for_tick.stop();
+                return;
+            };
+
+            *step += 1;
+            let msg = format!("Step {} of {PROGRESS_STEPS}", *step);
+            let (keep_going, skipped) = dialog.update(*step, Some(&msg));
+            if !keep_going {
+                println!("Progress Dialog: Cancelled at step {}", *step);
+            } else if skipped {
+                // Jump ahead a chunk and re-enable the buttons
+                *step = (*step + 10).min(PROGRESS_STEPS - 1);
+                dialog.resume();
+                println!("Progress Dialog: Skipped ahead to step {}", *step);
+                return;
+            } else if *step < PROGRESS_STEPS {
+                return;
+            } else {
                 println!("Progress Dialog: Completed.");
             }
+
+            timer_for_tick.stop();
+            // Dropping the wrapper destroys the dialog
+            *job = None;
+        });
+
+        self.progress_button.on_click(move |_event| {
+            if progress_job.borrow().is_some() {
+                return;
+            }
+            let dialog = ProgressDialog::builder(&frame, "Processing...", "Working on a 10 second job", PROGRESS_STEPS)
+                .with_style(
+                    ProgressDialogStyle::AppModal
+                        | ProgressDialogStyle::CanAbort
+                        | ProgressDialogStyle::CanSkip
+                        | ProgressDialogStyle::ElapsedTime
+                        | ProgressDialogStyle::RemainingTime
+                        | ProgressDialogStyle::Smooth
+                        | ProgressDialogStyle::AutoHide,
+                )
+                .build();
+            *progress_job.borrow_mut() = Some((dialog, 0));
+            progress_timer.start(100, false);
         });
 
         // NotificationMessage button
@@ -538,7 +581,7 @@ impl DialogTabControls {
 
             match notification_result {
                 Ok(notification) => {
-                    notification.show(wxdragon::widgets::notification_message::TIMEOUT_AUTO);
+                    notification.show(Timeout::Auto);
                     notification_status_label.set_label("Notification shown.");
                     println!("NotificationMessage: Shown.");
                     notif_store.lock().unwrap().replace(notification);
@@ -644,8 +687,9 @@ impl DialogTabControls {
 
         // Dir Dialog Button
         self.dlg_dir_button.on_click(move |_| {
-            let dialog = DirDialog::builder(&frame, "Choose a directory", "")
-                .with_style(DirDialogStyle::default().bits() | DirDialogStyle::MustExist.bits())
+            let dialog = DirDialog::builder(&frame)
+                .with_message("Choose a directory")
+                .with_style(DirDialogStyle::default() | DirDialogStyle::MustExist)
                 .build();
 
             if dialog.show_modal() == wxdragon::id::ID_OK {
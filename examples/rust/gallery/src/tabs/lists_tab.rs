@@ -55,6 +55,18 @@ pub fn create_lists_tab(notebook: &Notebook, _frame: &Frame) -> ListsTabControls
         // .with_style(ListBoxStyle::Default | ListBoxStyle::Sort | ListBoxStyle::AlwaysScrollbar | ListBoxStyle::HorizontalScrollbar) // Old complex
         .with_style(ListBoxStyle::Sort) // Simplified to test
         .build();
+    // The sorted listbox may reorder items, but their data stays with them
+    for (fruit, calories) in [
+        ("Apple", 52u32),
+        ("Banana", 89),
+        ("Cherry", 50),
+        ("Date", 282),
+        ("Elderberry", 73),
+    ] {
+        if let Some(index) = list_box.find_string(fruit, false) {
+            list_box.set_item_data(index, calories);
+        }
+    }
     let listbox_status_label = StaticText::builder(&panel).with_label("List Selection: None").build();
     let checklistbox = CheckListBox::builder(&panel)
         .with_id(109)
@@ -181,12 +193,12 @@ pub fn create_lists_tab(notebook: &Notebook, _frame: &Frame) -> ListsTabControls
     list_ctrl.set_item_text_colour(1, &red_text);
     list_ctrl.set_item_text_colour(3, &green_text);
 
-    // Set item data - allows storing arbitrary integer data with each row
-    list_ctrl.set_custom_data(0u64, 1001);
-    list_ctrl.set_custom_data(1u64, 2002);
-    list_ctrl.set_custom_data(2u64, 3003);
-    list_ctrl.set_custom_data(3u64, 4004);
-    list_ctrl.set_custom_data(4u64, 5005);
+    // Set item data - a number kept with each row, also when the list is sorted
+    list_ctrl.set_item_data(0, 1001);
+    list_ctrl.set_item_data(1, 2002);
+    list_ctrl.set_item_data(2, 3003);
+    list_ctrl.set_item_data(3, 4004);
+    list_ctrl.set_item_data(4, 5005);
 
     // Set up selection
     list_ctrl.set_item_state(0, ListItemState::Selected, ListItemState::Selected);
@@ -373,8 +385,9 @@ pub fn create_lists_tab(notebook: &Notebook, _frame: &Frame) -> ListsTabControls
 
     // ListBox Event Binding (Refactored)
     list_box.on_selection_changed(move |event_data| {
-        if let Some(selection_str) = event_data.get_string() {
-            listbox_status.set_label(&format!("List Selection: {selection_str}"));
+        if let (Some(selection_str), Some(index)) = (event_data.get_string(), event_data.get_selection()) {
+            let calories = list_box.get_item_data::<u32>(index as u32).map_or(0, |calories| *calories);
+            listbox_status.set_label(&format!("List Selection: {selection_str} ({calories} kcal)"));
         }
         if let Some(index) = event_data.get_selection() {
             println!(
@@ -522,12 +535,49 @@ pub fn create_lists_tab(notebook: &Notebook, _frame: &Frame) -> ListsTabControls
         if cancelled {
             list_ctrl_status.set_label("Label edit cancelled");
         } else {
-            let _item_index = event_data.get_item_index();
-            let label = event_data.get_label().unwrap_or_else(|| String::from("<no label>"));
-            list_ctrl_status.set_label(&format!("Label changed to: {label}"));
+            let label = event_data.get_label().unwrap_or_default();
+            if label.trim().is_empty() {
+                // Keep the old label rather than an empty one
+                event_data.veto();
+                list_ctrl_status.set_label("Empty labels are not allowed");
+            } else {
+                list_ctrl_status.set_label(&format!("Label changed to: {label}"));
+            }
         }
     });
 
+    list_ctrl.on_item_selected(move |event_data| {
+        let item = event_data.get_item_index() as i64;
+        let data = list_ctrl
+            .get_item_data(item)
+            .map_or("none".to_string(), |data| data.to_string());
+        list_ctrl_status.set_label(&format!(
+            "Selected {} (item data: {data}), {} selected",
+            list_ctrl.get_item_text(item, 0),
+            list_ctrl.get_selected_items().len()
+        ));
+    });
+
+    list_ctrl.on_item_right_click(move |event_data| {
+        let item = event_data.get_item_index();
+        list_ctrl_status.set_label(&format!("Right-clicked {}", list_ctrl.get_item_text(item as i64, 0)));
+    });
+
+    // Clicking a header sorts by that column, numerically where both cells are numbers
+    list_ctrl.on_column_click(move |event_data| {
+        let Some(col) = event_data.get_column() else {
+            return;
+        };
+        list_ctrl.sort_items_by(|a, b| {
+            let (a, b) = (list_ctrl.get_item_text(a, col), list_ctrl.get_item_text(b, col));
+            match (a.parse::<f64>(), b.parse::<f64>()) {
+                (Ok(a), Ok(b)) => a.total_cmp(&b),
+                _ => a.cmp(&b),
+            }
+        });
+        list_ctrl_status.set_label(&format!("Sorted by column {col}"));
+    });
+
     // Cleanup button handler
     cleanup_button.on_click(move |_| {
         println!("Cleanup button clicked - calling explicit cleanup");
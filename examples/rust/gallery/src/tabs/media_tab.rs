@@ -126,7 +126,7 @@ pub fn create_media_tab(notebook: &Notebook) -> MediaControls {
     // Row 3: SVG image (left) and label (right)
     let svg_info_text = StaticText::builder(&panel).with_label("SVG icon").build();
     let svg_icon_bytes = include_bytes!("../../asset/icon_baby.svg");
-    let svg_icon_bundle = BitmapBundle::from_svg_data(svg_icon_bytes, Size::new(64, 64)).unwrap();
+    let svg_icon_bundle = BitmapBundle::from_svg_bytes(svg_icon_bytes, Size::new(64, 64)).unwrap();
     let static_bitmap_ctrl = StaticBitmap::builder(&panel)
         .with_bitmap_bundle(Some(svg_icon_bundle))
         .with_size(Size::new(24, 24))
@@ -4,9 +4,15 @@ use wxdragon::scrollable::WxScrollable;
 pub struct RichTextTabControls {
     pub panel: Panel,
     pub rich_text_ctrl: RichTextCtrl,
-    pub bold_btn: Button,
-    pub italic_btn: Button,
-    pub underline_btn: Button,
+    pub bold_btn: ToggleButton,
+    pub italic_btn: ToggleButton,
+    pub underline_btn: ToggleButton,
+    pub align_left_btn: Button,
+    pub align_center_btn: Button,
+    pub align_right_btn: Button,
+    pub bullets_btn: ToggleButton,
+    pub insert_link_btn: Button,
+    pub insert_image_btn: Button,
     pub font_size_spin: SpinCtrl,
     #[allow(dead_code)]
     pub font_size_label: StaticText,
@@ -40,14 +46,33 @@ pub fn create_richtext_tab(notebook: &Notebook, _frame: &Frame) -> RichTextTabCo
     rich_text_ctrl.set_tooltip("Rich text editor with formatting capabilities");
 
     // Create formatting buttons
-    let bold_btn = Button::builder(&panel).with_label("Bold").build();
-    bold_btn.set_tooltip("Make selected text bold");
+    let bold_btn = ToggleButton::builder(&panel).with_label("Bold").build();
+    bold_btn.set_tooltip("Toggle bold for the selected text");
 
-    let italic_btn = Button::builder(&panel).with_label("Italic").build();
-    italic_btn.set_tooltip("Make selected text italic");
+    let italic_btn = ToggleButton::builder(&panel).with_label("Italic").build();
+    italic_btn.set_tooltip("Toggle italic for the selected text");
 
-    let underline_btn = Button::builder(&panel).with_label("Underline").build();
-    underline_btn.set_tooltip("Underline selected text");
+    let underline_btn = ToggleButton::builder(&panel).with_label("Underline").build();
+    underline_btn.set_tooltip("Toggle underline for the selected text");
+
+    // Paragraph and insertion controls
+    let align_left_btn = Button::builder(&panel).with_label("Left").build();
+    align_left_btn.set_tooltip("Align the selected paragraphs to the left");
+
+    let align_center_btn = Button::builder(&panel).with_label("Center").build();
+    align_center_btn.set_tooltip("Center the selected paragraphs");
+
+    let align_right_btn = Button::builder(&panel).with_label("Right").build();
+    align_right_btn.set_tooltip("Align the selected paragraphs to the right");
+
+    let bullets_btn = ToggleButton::builder(&panel).with_label("Bullets").build();
+    bullets_btn.set_tooltip("Toggle a bulleted list for the selected paragraphs");
+
+    let insert_link_btn = Button::builder(&panel).with_label("Insert Link").build();
+    insert_link_btn.set_tooltip("Insert a link at the cursor");
+
+    let insert_image_btn = Button::builder(&panel).with_label("Insert Image").build();
+    insert_image_btn.set_tooltip("Insert an image at the cursor");
 
     // Font size controls
     let font_size_label = StaticText::builder(&panel).with_label("Font Size:").build();
@@ -64,10 +89,10 @@ pub fn create_richtext_tab(notebook: &Notebook, _frame: &Frame) -> RichTextTabCo
     clear_btn.set_tooltip("Clear all content");
 
     let save_btn = Button::builder(&panel).with_label("Save File...").build();
-    save_btn.set_tooltip("Save content to file (RTF or TXT)");
+    save_btn.set_tooltip("Save content to file (XML, HTML or TXT)");
 
     let load_btn = Button::builder(&panel).with_label("Open File...").build();
-    load_btn.set_tooltip("Open and load file (RTF or TXT)");
+    load_btn.set_tooltip("Open and load file (XML or TXT)");
 
     // Edit operation buttons
     let undo_btn = Button::builder(&panel).with_label("Undo").build();
@@ -133,7 +158,17 @@ pub fn create_richtext_tab(notebook: &Notebook, _frame: &Frame) -> RichTextTabCo
     );
     format_sizer.add(&font_size_spin, 0, SizerFlag::All, 5);
 
-    // Button row 2: Edit operations
+    // Button row 2: Paragraph and insertion controls
+    let paragraph_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+    paragraph_sizer.add(&align_left_btn, 0, SizerFlag::All, 5);
+    paragraph_sizer.add(&align_center_btn, 0, SizerFlag::All, 5);
+    paragraph_sizer.add(&align_right_btn, 0, SizerFlag::All, 5);
+    paragraph_sizer.add(&bullets_btn, 0, SizerFlag::All, 5);
+    paragraph_sizer.add_spacer(10);
+    paragraph_sizer.add(&insert_link_btn, 0, SizerFlag::All, 5);
+    paragraph_sizer.add(&insert_image_btn, 0, SizerFlag::All, 5);
+
+    // Button row 3: Edit operations
     let edit_sizer = BoxSizer::builder(Orientation::Horizontal).build();
     edit_sizer.add(&undo_btn, 0, SizerFlag::All, 5);
     edit_sizer.add(&redo_btn, 0, SizerFlag::All, 5);
@@ -143,19 +178,19 @@ pub fn create_richtext_tab(notebook: &Notebook, _frame: &Frame) -> RichTextTabCo
     edit_sizer.add(&paste_btn, 0, SizerFlag::All, 5);
     edit_sizer.add(&select_all_btn, 0, SizerFlag::All, 5);
 
-    // Button row 3: File operations
+    // Button row 4: File operations
     let file_sizer = BoxSizer::builder(Orientation::Horizontal).build();
     file_sizer.add(&clear_btn, 0, SizerFlag::All, 5);
     file_sizer.add(&load_btn, 0, SizerFlag::All, 5);
     file_sizer.add(&save_btn, 0, SizerFlag::All, 5);
 
-    // Button row 4: Color operations
+    // Button row 5: Color operations
     let color_sizer = BoxSizer::builder(Orientation::Horizontal).build();
     color_sizer.add(&text_color_btn, 0, SizerFlag::All, 5);
     color_sizer.add(&bg_color_btn, 0, SizerFlag::All, 5);
     color_sizer.add(&reset_color_btn, 0, SizerFlag::All, 5);
 
-    // Button row 5: Scrolling operations
+    // Button row 6: Scrolling operations
     let scroll_sizer = BoxSizer::builder(Orientation::Horizontal).build();
     scroll_sizer.add(&add_content_btn, 0, SizerFlag::All, 5);
     scroll_sizer.add(&scroll_to_end_btn, 0, SizerFlag::All, 5);
@@ -164,6 +199,7 @@ pub fn create_richtext_tab(notebook: &Notebook, _frame: &Frame) -> RichTextTabCo
 
     // Add everything to main sizer
     main_sizer.add_sizer(&format_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
+    main_sizer.add_sizer(&paragraph_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
     main_sizer.add_sizer(&edit_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
     main_sizer.add_sizer(&file_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
     main_sizer.add_sizer(&color_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
@@ -179,6 +215,12 @@ pub fn create_richtext_tab(notebook: &Notebook, _frame: &Frame) -> RichTextTabCo
         bold_btn,
         italic_btn,
         underline_btn,
+        align_left_btn,
+        align_center_btn,
+        align_right_btn,
+        bullets_btn,
+        insert_link_btn,
+        insert_image_btn,
         font_size_spin,
         font_size_label,
         clear_btn,
@@ -209,40 +251,85 @@ impl RichTextTabControls {
         let panel = self.panel;
         let font_size_spin = self.font_size_spin;
 
-        // Bold button
-        self.bold_btn.on_click(move |_event| {
-            let (from, to) = rich_text_ctrl.get_selection();
-            if from != to {
-                rich_text_ctrl.set_style_range(from, to, true, false, false);
-                status_label.set_label("Applied bold formatting to selection");
-                log::info!("Applied bold to range {from}-{to}");
-            } else {
-                status_label.set_label("Please select text first");
-            }
+        let bold_btn = self.bold_btn;
+        let italic_btn = self.italic_btn;
+        let underline_btn = self.underline_btn;
+        let bullets_btn = self.bullets_btn;
+
+        // Bring the toggle buttons in line with the formatting at the selection
+        let sync_toggles = move || {
+            bold_btn.set_value(rich_text_ctrl.is_selection_bold());
+            italic_btn.set_value(rich_text_ctrl.is_selection_italic());
+            underline_btn.set_value(rich_text_ctrl.is_selection_underlined());
+            bullets_btn.set_value(rich_text_ctrl.is_selection_bulleted());
+        };
+
+        // Bold, italic and underline toggle the style of the selection
+        self.bold_btn.on_toggle(move |_event| {
+            rich_text_ctrl.apply_bold_to_selection();
+            sync_toggles();
+            status_label.set_label("Toggled bold");
         });
 
-        // Italic button
-        self.italic_btn.on_click(move |_event| {
-            let (from, to) = rich_text_ctrl.get_selection();
-            if from != to {
-                rich_text_ctrl.set_style_range(from, to, false, true, false);
-                status_label.set_label("Applied italic formatting to selection");
-                log::info!("Applied italic to range {from}-{to}");
-            } else {
-                status_label.set_label("Please select text first");
-            }
+        self.italic_btn.on_toggle(move |_event| {
+            rich_text_ctrl.apply_italic_to_selection();
+            sync_toggles();
+            status_label.set_label("Toggled italic");
         });
 
-        // Underline button
-        self.underline_btn.on_click(move |_event| {
-            let (from, to) = rich_text_ctrl.get_selection();
-            if from != to {
-                rich_text_ctrl.set_style_range(from, to, false, false, true);
-                status_label.set_label("Applied underline formatting to selection");
-                log::info!("Applied underline to range {from}-{to}");
+        self.underline_btn.on_toggle(move |_event| {
+            rich_text_ctrl.apply_underline_to_selection();
+            sync_toggles();
+            status_label.set_label("Toggled underline");
+        });
+
+        // Paragraph alignment
+        for (button, alignment, name) in [
+            (self.align_left_btn, RichTextAlignment::Left, "left"),
+            (self.align_center_btn, RichTextAlignment::Centre, "center"),
+            (self.align_right_btn, RichTextAlignment::Right, "right"),
+        ] {
+            button.on_click(move |_event| {
+                rich_text_ctrl.apply_alignment(alignment);
+                rich_text_ctrl.set_focus();
+                status_label.set_label(&format!("Aligned paragraphs {name}"));
+            });
+        }
+
+        // Bulleted list
+        self.bullets_btn.on_toggle(move |_event| {
+            let bullets = bullets_btn.get_value();
+            rich_text_ctrl.apply_bullets_to_selection(bullets);
+            rich_text_ctrl.set_focus();
+            status_label.set_label(if bullets {
+                "Started a bulleted list"
             } else {
-                status_label.set_label("Please select text first");
+                "Ended the bulleted list"
+            });
+        });
+
+        // Link insertion
+        self.insert_link_btn.on_click(move |_event| {
+            rich_text_ctrl.write_url("https://github.com/AllenDang/wxDragon", "wxDragon on GitHub");
+            rich_text_ctrl.set_focus();
+            status_label.set_label("Inserted a link - click it to see the URL");
+        });
+
+        self.rich_text_ctrl.on_url_clicked(move |event| {
+            let url = event.get_url().unwrap_or_default();
+            status_label.set_label(&format!("Link clicked: {url}"));
+            log::info!("Link clicked: {url}");
+        });
+
+        // Image insertion
+        self.insert_image_btn.on_click(move |_event| {
+            match ArtProvider::get_bitmap(ArtId::Information, ArtClient::MessageBox, None) {
+                Some(bitmap) if rich_text_ctrl.write_image(&bitmap) => {
+                    status_label.set_label("Inserted an image");
+                }
+                _ => status_label.set_label("Failed to insert an image"),
             }
+            rich_text_ctrl.set_focus();
         });
 
         // Font size spinner
@@ -250,7 +337,7 @@ impl RichTextTabControls {
             let size = font_size_spin.value();
             let (from, to) = rich_text_ctrl.get_selection();
             if from != to {
-                rich_text_ctrl.set_font_size(from, to, size);
+                rich_text_ctrl.set_font_size_selection(size);
                 status_label.set_label(&format!("Changed font size to {size}pt"));
                 log::info!("Applied font size {size} to range {from}-{to}");
             } else {
@@ -333,16 +420,19 @@ impl RichTextTabControls {
             let save_dialog = FileDialog::builder(&panel)
                 .with_message("Save RichText Content")
                 .with_default_dir(".")
-                .with_default_file("document.rtf")
-                .with_wildcard("RTF files (*.rtf)|*.rtf|Text files (*.txt)|*.txt|All files (*.*)|*.*")
+                .with_default_filename("document.xml")
+                .with_wildcard("RichText XML (*.xml)|*.xml|HTML files (*.html)|*.html|Text files (*.txt)|*.txt")
                 .with_style(FileDialogStyle::Save | FileDialogStyle::OverwritePrompt)
                 .build();
 
             if save_dialog.show_modal() == ID_OK {
                 if let Some(file_path) = save_dialog.get_path() {
                     // Determine file type based on extension
-                    let file_type = if file_path.to_lowercase().ends_with(".rtf") {
-                        RichTextFileType::Rtf
+                    let lower = file_path.to_lowercase();
+                    let file_type = if lower.ends_with(".xml") {
+                        RichTextFileType::Xml
+                    } else if lower.ends_with(".html") || lower.ends_with(".htm") {
+                        RichTextFileType::Html
                     } else {
                         RichTextFileType::Text
                     };
@@ -375,7 +465,7 @@ impl RichTextTabControls {
                 .with_message("Open Text File")
                 .with_default_dir(".")
                 .with_wildcard(
-                    "All supported files|*.rtf;*.txt|RTF files (*.rtf)|*.rtf|Text files (*.txt)|*.txt|All files (*.*)|*.*",
+                    "All supported files|*.xml;*.txt|RichText XML (*.xml)|*.xml|Text files (*.txt)|*.txt|All files (*.*)|*.*",
                 )
                 .with_style(FileDialogStyle::Open | FileDialogStyle::FileMustExist)
                 .build();
@@ -383,8 +473,8 @@ impl RichTextTabControls {
             if open_dialog.show_modal() == ID_OK {
                 if let Some(file_path) = open_dialog.get_path() {
                     // Determine file type based on extension
-                    let file_type = if file_path.to_lowercase().ends_with(".rtf") {
-                        RichTextFileType::Rtf
+                    let file_type = if file_path.to_lowercase().ends_with(".xml") {
+                        RichTextFileType::Xml
                     } else {
                         RichTextFileType::Text
                     };
@@ -456,6 +546,7 @@ impl RichTextTabControls {
 
         // RichTextCtrl selection changed event
         self.rich_text_ctrl.on_selection_changed(move |_event| {
+            sync_toggles();
             let (from, to) = rich_text_ctrl.get_selection();
             if from != to {
                 status_label.set_label(&format!("Selection: {} characters ({from}-{to})", to - from));
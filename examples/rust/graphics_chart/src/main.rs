@@ -0,0 +1,158 @@
+//! Draws the same chart with plain DC primitives (left) and with a
+//! `GraphicsContext` (right) to show anti-aliasing, alpha and gradients.
+
+use std::f64::consts::FRAC_PI_2;
+use wxdragon::dc::PolygonFillMode;
+use wxdragon::prelude::*;
+
+/// Monthly values plotted by both panels
+const VALUES: [f64; 12] = [12.0, 19.0, 15.0, 27.0, 31.0, 24.0, 38.0, 35.0, 44.0, 40.0, 52.0, 49.0];
+const MAX_VALUE: f64 = 60.0;
+const MARGIN: f64 = 40.0;
+
+const LINE_COLOUR: Colour = Colour::rgb(30, 120, 220);
+const GRID_COLOUR: Colour = Colour::rgb(220, 220, 220);
+
+/// Chart coordinates of each value for a panel of the given size
+fn chart_points(width: f64, height: f64) -> Vec<(f64, f64)> {
+    let plot_width = width - 2.0 * MARGIN;
+    let plot_height = height - 2.0 * MARGIN;
+    let step = plot_width / (VALUES.len() - 1) as f64;
+    VALUES
+        .iter()
+        .enumerate()
+        .map(|(i, value)| (MARGIN + i as f64 * step, height - MARGIN - value / MAX_VALUE * plot_height))
+        .collect()
+}
+
+fn draw_with_dc(panel: &Panel) {
+    let dc = AutoBufferedPaintDC::new(panel);
+    dc.set_background(Colour::rgb(255, 255, 255));
+    dc.clear();
+
+    let (width, height) = dc.get_size();
+    let bottom = height - MARGIN as i32;
+
+    dc.set_pen(GRID_COLOUR, 1, PenStyle::Solid);
+    for i in 0..=6 {
+        let y = MARGIN as i32 + (bottom - MARGIN as i32) * i / 6;
+        dc.draw_line(MARGIN as i32, y, width - MARGIN as i32, y);
+    }
+
+    // The DC has no alpha, so the area under the line is a flat, opaque colour
+    let points: Vec<Point> = chart_points(width as f64, height as f64)
+        .into_iter()
+        .map(|(x, y)| Point::new(x as i32, y as i32))
+        .collect();
+    let mut area = points.clone();
+    area.push(Point::new(points[points.len() - 1].x, bottom));
+    area.push(Point::new(points[0].x, bottom));
+    dc.set_pen(Colour::rgb(200, 220, 245), 1, PenStyle::Solid);
+    dc.set_brush(Colour::rgb(200, 220, 245), BrushStyle::Solid);
+    dc.draw_polygon(&area, 0, 0, PolygonFillMode::OddEven);
+
+    dc.set_pen(LINE_COLOUR, 3, PenStyle::Solid);
+    dc.draw_lines(&points, 0, 0);
+    dc.set_brush(Colour::rgb(255, 255, 255), BrushStyle::Solid);
+    for point in &points {
+        dc.draw_circle(point.x, point.y, 4);
+    }
+
+    dc.set_text_foreground(Colour::rgb(0, 0, 0));
+    dc.draw_text("DeviceContext", MARGIN as i32, 12);
+    dc.draw_rotated_text("Sales", 12, height / 2 + 15, 90.0);
+}
+
+fn draw_with_graphics_context(panel: &Panel) {
+    let dc = AutoBufferedPaintDC::new(panel);
+    dc.set_background(Colour::rgb(255, 255, 255));
+    dc.clear();
+
+    let Some(gc) = GraphicsContext::from_dc(&dc) else {
+        return;
+    };
+    let (width, height) = gc.get_size();
+    let bottom = height - MARGIN;
+
+    gc.set_pen(&GraphicsPen::new(GRID_COLOUR, 1.0));
+    for i in 0..=6 {
+        let y = MARGIN + (bottom - MARGIN) * i as f64 / 6.0;
+        gc.stroke_line(MARGIN, y, width - MARGIN, y);
+    }
+
+    // Smooth curve through the points, filled with a gradient fading to transparent
+    let points = chart_points(width, height);
+    let curve = gc.create_path();
+    curve.move_to(points[0].0, points[0].1);
+    for pair in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        let mid = (x0 + x1) / 2.0;
+        curve.add_curve_to_point(mid, y0, mid, y1, x1, y1);
+    }
+
+    let area = gc.create_path();
+    area.move_to(points[0].0, bottom);
+    area.line_to(points[0].0, points[0].1);
+    for pair in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        let mid = (x0 + x1) / 2.0;
+        area.add_curve_to_point(mid, y0, mid, y1, x1, y1);
+    }
+    area.line_to(points[points.len() - 1].0, bottom).close();
+
+    let top = area.get_box().1;
+    gc.set_pen(&GraphicsPen::transparent());
+    gc.set_brush(&GraphicsBrush::linear_gradient(
+        0.0,
+        top,
+        0.0,
+        bottom,
+        GradientStops::new(Colour::new(30, 120, 220, 140), Colour::new(30, 120, 220, 0)),
+    ));
+    gc.fill_path(&area, PolygonFillMode::OddEven);
+
+    gc.set_pen(&GraphicsPen::new(LINE_COLOUR, 3.0));
+    gc.stroke_path(&curve);
+
+    // Semi-transparent markers let the line show through
+    gc.set_pen(&GraphicsPen::new(LINE_COLOUR, 1.5));
+    gc.set_brush(&GraphicsBrush::Solid(Colour::new(255, 255, 255, 180)));
+    for (x, y) in &points {
+        gc.draw_ellipse(x - 4.0, y - 4.0, 8.0, 8.0);
+    }
+
+    gc.draw_text("GraphicsContext", MARGIN, 12.0);
+    let (label_width, _) = gc.get_text_extent("Sales");
+    gc.push_state();
+    gc.translate(12.0, (height + label_width) / 2.0);
+    gc.rotate(-FRAC_PI_2);
+    gc.draw_text("Sales", 0.0, 0.0);
+    gc.pop_state();
+}
+
+fn chart_panel(parent: &Frame, draw: fn(&Panel)) -> Panel {
+    let panel = Panel::builder(parent).build();
+    panel.set_background_style(BackgroundStyle::Paint);
+    panel.on_paint(move |_| draw(&panel));
+    panel.on_size(move |_| panel.refresh(false, None));
+    panel
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("DC vs GraphicsContext")
+            .with_size(Size::new(900, 420))
+            .build();
+
+        let sizer = BoxSizer::builder(Orientation::Horizontal).build();
+        let dc_panel = chart_panel(&frame, draw_with_dc);
+        let gc_panel = chart_panel(&frame, draw_with_graphics_context);
+        sizer.add(&dc_panel, 1, SizerFlag::Expand | SizerFlag::All, 5);
+        sizer.add(&gc_panel, 1, SizerFlag::Expand | SizerFlag::All, 5);
+
+        frame.set_sizer(sizer, true);
+        frame.show(true);
+        frame.centre();
+    });
+}
@@ -3,18 +3,24 @@
 //! This demo shows:
 //! - Server: Listens for connections and receives messages
 //! - Client: Connects to server and sends messages
+//! - Broadcast: The server pushes a line to every subscribed client via Advise
+//! - Query: The client asks for the server's status via Request
+//! - Kick: The server lists its connected clients and disconnects one of them
 //!
 //! Run two separate instances:
 //! - First instance: `cargo run -p ipc_demo` (server mode - click "Start Server")
 //! - Second instance: `cargo run -p ipc_demo` (client mode - click "Connect as Client")
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
-use wxdragon::ipc::{IPCClient, IPCConnection, IPCConnectionBuilder, IPCFormat, IPCServer};
+use std::time::Instant;
+use wxdragon::ipc::{IPCClient, IPCConnection, IPCConnectionBuilder, IPCFormat, IPCServer, IpcError};
 use wxdragon::prelude::*;
 
-const SERVICE_PORT: &str = "4242";
+const DEFAULT_PORT: i64 = 4242;
 const TOPIC: &str = "wxdragon_ipc_demo";
+const BROADCAST_ITEM: &str = "broadcast";
+const STATUS_ITEM: &str = "status";
 
 fn main() {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
@@ -26,7 +32,7 @@ fn main() {
 }
 
 fn create_ui() {
-    let frame = Frame::builder().with_title("IPC Demo").with_size(Size::new(500, 400)).build();
+    let frame = Frame::builder().with_title("IPC Demo").with_size(Size::new(720, 400)).build();
 
     let panel = Panel::builder(&frame).build();
     let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
@@ -50,10 +56,14 @@ fn create_ui() {
     let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
     let server_button = Button::builder(&panel).with_label("Start Server").build();
     let client_button = Button::builder(&panel).with_label("Connect as Client").build();
+    let query_button = Button::builder(&panel).with_label("Query Server Status").build();
+    let kick_button = Button::builder(&panel).with_label("Kick Client").build();
     let disconnect_button = Button::builder(&panel).with_label("Disconnect").build();
 
     button_sizer.add(&server_button, 0, SizerFlag::All, 5);
     button_sizer.add(&client_button, 0, SizerFlag::All, 5);
+    button_sizer.add(&query_button, 0, SizerFlag::All, 5);
+    button_sizer.add(&kick_button, 0, SizerFlag::All, 5);
     button_sizer.add(&disconnect_button, 0, SizerFlag::All, 5);
 
     // Layout
@@ -70,6 +80,8 @@ fn create_ui() {
     let client: Rc<RefCell<Option<IPCClient>>> = Rc::new(RefCell::new(None));
     // Client connection - only valid for client mode
     let client_connection: Rc<RefCell<Option<IPCConnection>>> = Rc::new(RefCell::new(None));
+    // Last port entered, offered again the next time
+    let port: Rc<Cell<i64>> = Rc::new(Cell::new(DEFAULT_PORT));
 
     // Helper to append to log
     let log_text_clone = log_text;
@@ -87,6 +99,7 @@ fn create_ui() {
     {
         let server = server.clone();
         let is_server = is_server.clone();
+        let port = port.clone();
         let status_label_server = status_label;
         let append_log = append_log.clone();
 
@@ -96,41 +109,81 @@ fn create_ui() {
                 return;
             }
 
+            let Some(service_port) = ask_port(&frame, "Port to listen on:", port.get()) else {
+                return;
+            };
+            port.set(service_port);
+
             let append_log_clone = append_log.clone();
+            let started = Instant::now();
+            let received = Rc::new(Cell::new(0usize));
+            let next_client = Rc::new(Cell::new(0u32));
 
             // Create server - the callback creates connections for incoming clients
             let ipc_server = IPCServer::new(move |topic| {
-                append_log_clone(&format!("Client connected to topic: {}", topic));
+                let number = next_client.get() + 1;
+                next_client.set(number);
+                append_log_clone(&format!("[Server] Client #{} connected to topic: {}", number, topic));
 
                 // Create a connection for this client with callbacks to handle incoming data
                 let append_log_exec = append_log_clone.clone();
+                let received_exec = received.clone();
+                let received_status = received.clone();
                 let conn = IPCConnection::builder()
                     .on_execute(move |_topic, data, _format| {
                         let msg = String::from_utf8_lossy(data);
                         append_log_exec(&format!("[Server] Received: {}", msg));
+                        received_exec.set(received_exec.get() + 1);
                         true
                     })
+                    .on_request(move |_topic, item, _format| {
+                        // Unknown items answer None, which the client sees as a failed request
+                        (item == STATUS_ITEM).then(|| {
+                            format!(
+                                "up {}s, {} message(s) received",
+                                started.elapsed().as_secs(),
+                                received_status.get()
+                            )
+                            .into_bytes()
+                        })
+                    })
+                    .on_start_advise({
+                        let append_log_advise = append_log_clone.clone();
+                        move |_topic, item| {
+                            let accepted = item == BROADCAST_ITEM;
+                            if accepted {
+                                append_log_advise(&format!("[Server] Client subscribed to '{}'", item));
+                            }
+                            accepted
+                        }
+                    })
                     .on_disconnect({
                         let append_log_disc = append_log_clone.clone();
                         move || {
-                            append_log_disc("[Server] Client disconnected");
+                            append_log_disc(&format!("[Server] Client #{} disconnected", number));
                             true
                         }
                     })
                     .build();
+                // Lets the Kick button tell the connections apart
+                conn.set_user_data(number);
 
                 Some(conn)
             });
 
-            if ipc_server.create(SERVICE_PORT) {
-                append_log(&format!("Server started on port {}", SERVICE_PORT));
-                append_log("Waiting for client connections...");
-                append_log("(Run another instance and click 'Connect as Client')");
-                status_label_server.set_label("Status: Server running");
-                *server.borrow_mut() = Some(ipc_server);
-                *is_server.borrow_mut() = true;
-            } else {
-                append_log("Failed to start server - port may be in use");
+            match ipc_server.try_create(&service_port.to_string()) {
+                Ok(()) => {
+                    append_log(&format!("Server started on port {}", service_port));
+                    append_log("Waiting for client connections...");
+                    append_log("(Run another instance and click 'Connect as Client')");
+                    status_label_server.set_label("Status: Server running");
+                    *server.borrow_mut() = Some(ipc_server);
+                    *is_server.borrow_mut() = true;
+                }
+                Err(IpcError::AddressInUse(_)) => {
+                    append_log(&format!("Port {} is in use - is a server already running?", service_port));
+                }
+                Err(err) => append_log(&format!("Failed to start server: {}", err)),
             }
         });
     }
@@ -140,6 +193,7 @@ fn create_ui() {
         let client = client.clone();
         let client_connection = client_connection.clone();
         let is_server = is_server.clone();
+        let port = port.clone();
         let status_label_client = status_label;
         let append_log = append_log.clone();
 
@@ -149,11 +203,16 @@ fn create_ui() {
                 return;
             }
 
-            if client_connection.borrow().is_some() {
+            if client_connection.borrow().as_ref().is_some_and(IPCConnection::is_alive) {
                 append_log("Already connected as client");
                 return;
             }
 
+            let Some(service_port) = ask_port(&frame, "Port of the server:", port.get()) else {
+                return;
+            };
+            port.set(service_port);
+
             let ipc_client = IPCClient::new();
             let append_log_clone = append_log.clone();
 
@@ -172,18 +231,75 @@ fn create_ui() {
                     }
                 });
 
-            match ipc_client.make_connection_with_callbacks("localhost", SERVICE_PORT, TOPIC, conn_builder) {
-                Some(conn) => {
-                    append_log(&format!("Connected to server at localhost:{}", SERVICE_PORT));
+            match ipc_client.try_make_connection_with_callbacks("localhost", &service_port.to_string(), TOPIC, conn_builder) {
+                Ok(conn) => {
+                    append_log(&format!("Connected to server at localhost:{}", service_port));
+                    if !conn.start_advise(BROADCAST_ITEM) {
+                        append_log("Server refused the broadcast subscription");
+                    }
                     status_label_client.set_label("Status: Connected as client");
                     *client_connection.borrow_mut() = Some(conn);
                     *client.borrow_mut() = Some(ipc_client);
                 }
-                None => {
-                    append_log("Failed to connect - is the server running?");
+                Err(IpcError::ConnectionRefused(_)) => {
+                    append_log(&format!("No server on port {} - is it running?", service_port));
                     append_log("Start another instance and click 'Start Server' first");
                 }
+                Err(err) => append_log(&format!("Failed to connect: {}", err)),
+            }
+        });
+    }
+
+    // Query Server Status button (client only)
+    {
+        let client_connection = client_connection.clone();
+        let append_log = append_log.clone();
+
+        query_button.on_click(move |_| {
+            let Some(ref conn) = *client_connection.borrow() else {
+                append_log("Not connected - click 'Connect as Client' first");
+                return;
+            };
+
+            // Fails after the connection's timeout rather than hanging if the server is gone
+            match conn.try_request(STATUS_ITEM, IPCFormat::Utf8Text) {
+                Ok(data) => append_log(&format!("[Client] Server status: {}", String::from_utf8_lossy(&data))),
+                Err(err) => append_log(&format!("[Client] Status request failed: {}", err)),
+            }
+        });
+    }
+
+    // Kick Client button (server only)
+    {
+        let server = server.clone();
+        let append_log = append_log.clone();
+
+        kick_button.on_click(move |_| {
+            let connections = match *server.borrow() {
+                Some(ref ipc_server) => ipc_server.connections(),
+                None => {
+                    append_log("Not running as server - click 'Start Server' first");
+                    return;
+                }
+            };
+            if connections.is_empty() {
+                append_log("[Server] No clients connected");
+                return;
+            }
+
+            let names: Vec<String> = connections.iter().map(describe_client).collect();
+            append_log(&format!("[Server] Connected clients: {}", names.join(", ")));
+
+            let choices: Vec<&str> = names.iter().map(String::as_str).collect();
+            let dialog = SingleChoiceDialog::builder(&frame, "Client to disconnect:", "Kick Client", &choices).build();
+            if dialog.show_modal() == wxdragon::id::ID_OK {
+                let selection = dialog.get_selection();
+                if let Some(conn) = usize::try_from(selection).ok().and_then(|i| connections.get(i)) {
+                    // The client's OnDisconnect callback reports it in the log
+                    conn.disconnect();
+                }
             }
+            dialog.destroy();
         });
     }
 
@@ -197,10 +313,10 @@ fn create_ui() {
         let append_log = append_log.clone();
 
         disconnect_button.on_click(move |_| {
-            // For client: disconnect and drop connection
-            if let Some(conn) = client_connection.borrow_mut().take() {
+            // For client: disconnect and drop connection; its OnDisconnect callback logs it
+            let conn = client_connection.borrow_mut().take();
+            if let Some(conn) = conn {
                 conn.disconnect();
-                append_log("Disconnected from server");
             }
             *client.borrow_mut() = None;
 
@@ -215,8 +331,9 @@ fn create_ui() {
         });
     }
 
-    // Send button (client only)
+    // Send button (client: execute on server, server: broadcast to clients)
     {
+        let server = server.clone();
         let client_connection = client_connection.clone();
         let is_server = is_server.clone();
         let append_log = append_log.clone();
@@ -228,17 +345,21 @@ fn create_ui() {
             }
 
             if *is_server.borrow() {
-                append_log("Server mode - clients send data TO server, not the other way");
-                append_log("(Use Advise for server->client push notifications)");
+                if let Some(ref ipc_server) = *server.borrow() {
+                    let reached = ipc_server.advise(BROADCAST_ITEM, msg.as_bytes(), IPCFormat::Utf8Text);
+                    append_log(&format!("[Server] Broadcast to {} client(s): {}", reached, msg));
+                    input_text.set_value("");
+                }
                 return;
             }
 
             if let Some(ref conn) = *client_connection.borrow() {
-                if conn.execute(msg.as_bytes(), IPCFormat::Utf8Text) {
-                    append_log(&format!("[Client] Sent: {}", msg));
-                    input_text.set_value("");
-                } else {
-                    append_log("Failed to send message");
+                match conn.try_execute(msg.as_bytes(), IPCFormat::Utf8Text) {
+                    Ok(()) => {
+                        append_log(&format!("[Client] Sent: {}", msg));
+                        input_text.set_value("");
+                    }
+                    Err(err) => append_log(&format!("Failed to send message: {}", err)),
                 }
             } else {
                 append_log("Not connected - click 'Connect as Client' first");
@@ -254,9 +375,30 @@ fn create_ui() {
         append_log("1. In first instance: Click 'Start Server'");
         append_log("2. In second instance: Click 'Connect as Client'");
         append_log("3. Type messages in client and click 'Send'");
+        append_log("4. Type messages in server and click 'Send' to broadcast to all clients");
+        append_log("5. Click 'Query Server Status' in a client to request the server's status");
+        append_log("6. Click 'Kick Client' in the server to disconnect one of its clients");
         append_log("");
     }
 
     frame.show(true);
     frame.centre();
 }
+
+/// Describes a server-side connection by the client number stored in its user data.
+fn describe_client(conn: &IPCConnection) -> String {
+    let number = conn.with_user_data(|number: &mut u32| *number).unwrap_or_default();
+    format!("#{} ({})", number, conn.topic().unwrap_or_default())
+}
+
+/// Asks for the TCP port to use; returns `None` if the user cancelled.
+fn ask_port(parent: &Frame, prompt: &str, current: i64) -> Option<i64> {
+    let dialog = NumberEntryDialog::builder(parent, "Choose the TCP port used by the IPC service.", prompt, "IPC Port")
+        .with_range(1024, 65535)
+        .with_value(current)
+        .build();
+    dialog.show_modal();
+    let value = dialog.get_value();
+    dialog.destroy();
+    value
+}
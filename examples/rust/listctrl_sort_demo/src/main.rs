@@ -0,0 +1,244 @@
+//! ListCtrl Sort Demo - a file browser whose columns sort by name, size or date when their
+//! header is clicked, keeping folders first. The columns shown and their order are picked
+//! in a dialog with a RearrangeCtrl.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::UNIX_EPOCH;
+use wxdragon::prelude::*;
+
+const NAME_COLUMN: i32 = 0;
+const SIZE_COLUMN: i32 = 1;
+const DATE_COLUMN: i32 = 2;
+const COLUMN_TITLES: [&str; 3] = ["Name", "Size", "Modified"];
+
+struct Entry {
+    name: String,
+    is_dir: bool,
+    size: u64,
+    // Seconds since the Unix epoch
+    modified: u64,
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("ListCtrl Sort Demo - file browser")
+            .with_size(Size::new(640, 480))
+            .build();
+        let panel = Panel::builder(&frame).build();
+
+        let path_label = StaticText::builder(&panel).build();
+        let list = ListCtrl::builder(&panel).with_style(ListCtrlStyle::Report).build();
+        list.insert_column(NAME_COLUMN as i64, "Name", ListColumnFormat::Left, 300);
+        list.insert_column(SIZE_COLUMN as i64, "Size", ListColumnFormat::Right, 100);
+        list.insert_column(DATE_COLUMN as i64, "Modified", ListColumnFormat::Left, 160);
+        let status = StaticText::builder(&panel).build();
+
+        // The item data of each row is its index in `entries`; it moves with the row when
+        // the list is sorted, so the comparison and the selection always find the right entry
+        let entries: Rc<RefCell<Vec<Entry>>> = Rc::new(RefCell::new(Vec::new()));
+        let current_dir = Rc::new(RefCell::new(std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))));
+
+        let sort_entries = entries.clone();
+        list.enable_auto_sort(move |a, b, col, ascending| {
+            let entries = sort_entries.borrow();
+            let (Some(a), Some(b)) = (entry_of(list, &entries, a), entry_of(list, &entries, b)) else {
+                return Ordering::Equal;
+            };
+            let order = match col {
+                SIZE_COLUMN => a.size.cmp(&b.size),
+                DATE_COLUMN => a.modified.cmp(&b.modified),
+                _ => Ordering::Equal,
+            }
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+            // Folders stay on top in both directions
+            b.is_dir.cmp(&a.is_dir).then(if ascending { order } else { order.reverse() })
+        });
+
+        // Fills the list with the content of a folder, folders first, unsorted by any column
+        let show: Rc<dyn Fn(PathBuf)> = {
+            let entries = entries.clone();
+            let current_dir = current_dir.clone();
+            Rc::new(move |dir: PathBuf| {
+                let mut loaded = read_entries(&dir);
+                loaded.sort_by(|a, b| {
+                    b.is_dir
+                        .cmp(&a.is_dir)
+                        .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+                });
+                list.delete_all_items();
+                list.remove_sort_indicator();
+                for (index, entry) in loaded.iter().enumerate() {
+                    let row = index as i64;
+                    let name = if entry.is_dir {
+                        format!("{}/", entry.name)
+                    } else {
+                        entry.name.clone()
+                    };
+                    list.insert_item(row, &name, None);
+                    if !entry.is_dir {
+                        list.set_item(row, SIZE_COLUMN, &format_size(entry.size));
+                    }
+                    list.set_item(row, DATE_COLUMN, &format_timestamp(entry.modified));
+                    list.set_item_data(row, index as i64);
+                }
+                path_label.set_label(&dir.display().to_string());
+                status.set_label(&format!("{} entries", loaded.len()));
+                *entries.borrow_mut() = loaded;
+                *current_dir.borrow_mut() = dir;
+            })
+        };
+        let start_dir = current_dir.borrow().clone();
+        show(start_dir);
+
+        let selected_entries = entries.clone();
+        list.on_item_selected(move |event| {
+            let row = event.get_item_index() as i64;
+            if let Some(entry) = entry_of(list, &selected_entries.borrow(), row) {
+                status.set_label(&format!("Selected {} in row {row}", entry.name));
+            }
+        });
+
+        // Double-clicking a folder opens it, once the event is done with the current rows
+        let open_dir = current_dir.clone();
+        let open = show.clone();
+        list.on_item_activated(move |event| {
+            let dir = match entry_of(list, &entries.borrow(), event.get_item_index() as i64) {
+                Some(entry) if entry.is_dir => open_dir.borrow().join(&entry.name),
+                _ => return,
+            };
+            let open = open.clone();
+            wxdragon::call_later(0, move || open(dir));
+        });
+
+        let up_button = Button::builder(&panel).with_label("Up").build();
+        up_button.on_click(move |_| {
+            let parent = current_dir.borrow().parent().map(Path::to_path_buf);
+            if let Some(parent) = parent {
+                show(parent);
+            }
+        });
+
+        // The layout picked last time, in wxWidgets' encoding: all columns shown in order
+        let column_order = Rc::new(RefCell::new(vec![NAME_COLUMN, SIZE_COLUMN, DATE_COLUMN]));
+        let columns_button = Button::builder(&panel).with_label("Columns...").build();
+        columns_button.on_click(move |_| {
+            let previous = column_order.borrow().clone();
+            if let Some(order) = choose_columns(&frame, list, &previous) {
+                *column_order.borrow_mut() = order;
+            }
+        });
+
+        let top = BoxSizer::builder(Orientation::Horizontal).build();
+        top.add(&up_button, 0, SizerFlag::Right, 8);
+        top.add(&path_label, 1, SizerFlag::AlignCenterVertical, 0);
+        top.add(&columns_button, 0, SizerFlag::Left, 8);
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add_sizer(&top, 0, SizerFlag::Expand | SizerFlag::All, 8);
+        sizer.add(&list, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+        sizer.add(&status, 0, SizerFlag::Expand | SizerFlag::All, 8);
+        panel.set_sizer(sizer, true);
+
+        frame.show(true);
+        frame.centre();
+    });
+}
+
+// Asks for the columns to show and their order, starting with `order`, and applies them
+// to `list`; returns the new order, or `None` if cancelled
+fn choose_columns(parent: &Frame, list: ListCtrl, order: &[i32]) -> Option<Vec<i32>> {
+    let dialog = Dialog::builder(parent, "Columns")
+        .with_style(DialogStyle::DefaultDialogStyle | DialogStyle::ResizeBorder)
+        .with_size(Size::new(320, 280))
+        .build();
+
+    // The items stay in column order; the saved order puts them where the user left them
+    let columns = RearrangeCtrl::builder(&dialog)
+        .with_items(COLUMN_TITLES.iter().map(|title| (true, title.to_string())).collect())
+        .with_order(order.to_vec())
+        .build();
+    let hint = StaticText::builder(&dialog).build();
+    let update_hint = move || {
+        let shown = columns.get_items().iter().filter(|(checked, _)| *checked).count();
+        hint.set_label(&format!("{shown} of {} columns shown", COLUMN_TITLES.len()));
+    };
+    update_hint();
+    columns.on_check_changed(move |_| update_hint());
+
+    let sizer = BoxSizer::builder(Orientation::Vertical).build();
+    sizer.add(&columns, 1, SizerFlag::Expand | SizerFlag::All, 10);
+    sizer.add(&hint, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 10);
+    if let Some(buttons) = dialog.create_std_dialog_button_sizer(StdDialogButtons::Ok | StdDialogButtons::Cancel) {
+        sizer.add_sizer(&buttons, 0, SizerFlag::Expand | SizerFlag::All, 10);
+    }
+    dialog.set_sizer(sizer, true);
+
+    let result = (dialog.show_modal() == ID_OK).then(|| {
+        columns.apply_to_list_ctrl(&list);
+        columns.get_order()
+    });
+    dialog.destroy();
+    result
+}
+
+// The entry shown in `row`, found through the row's item data
+fn entry_of<'a>(list: ListCtrl, entries: &'a [Entry], row: i64) -> Option<&'a Entry> {
+    entries.get(list.get_item_data(row)? as usize)
+}
+
+fn read_entries(dir: &Path) -> Vec<Entry> {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    read_dir
+        .flatten()
+        .filter_map(|dir_entry| {
+            let metadata = dir_entry.metadata().ok()?;
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |duration| duration.as_secs());
+            Some(Entry {
+                name: dir_entry.file_name().to_string_lossy().into_owned(),
+                is_dir: metadata.is_dir(),
+                size: metadata.len(),
+                modified,
+            })
+        })
+        .collect()
+}
+
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if size < 1024 {
+        return format!("{size} B");
+    }
+    let mut value = size as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+// Formats seconds since the Unix epoch as a UTC date and time
+fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let (hour, minute) = (secs % 86_400 / 3600, secs % 3600 / 60);
+    // Days to a civil date, after Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}")
+}
@@ -44,6 +44,8 @@ impl MenuEventsApp {
             .build();
 
         frame.set_existing_status_bar(Some(&status_bar));
+        // The demo shows its own help text for highlighted items, so turn off the automatic one
+        frame.set_status_bar_pane(-1);
 
         Self {
             frame,
@@ -97,6 +99,8 @@ impl MenuEventsApp {
         let status_bar = self.status_bar;
         let menu_count = self.menu_open_count.clone();
         let frame = self.frame;
+        // Whether help text was pushed over the instructions in field 0
+        let help_shown = Rc::new(std::cell::Cell::new(false));
 
         // Menu opened events with full functionality
         self.frame.on_menu_opened(move |event: MenuEventData| {
@@ -116,6 +120,7 @@ impl MenuEventsApp {
         });
 
         // Menu closed events
+        let help_for_close = help_shown.clone();
         self.frame.on_menu_closed(move |event: MenuEventData| {
             let menu_info = if event.is_popup() {
                 "Menu Status: Popup Closed"
@@ -125,6 +130,11 @@ impl MenuEventsApp {
 
             status_bar.set_status_text(menu_info, 1);
 
+            // Bring the instructions back
+            if help_for_close.replace(false) {
+                frame.pop_status_text(0);
+            }
+
             log::trace!("📁 {}", event.format_for_logging());
         });
 
@@ -139,10 +149,16 @@ impl MenuEventsApp {
                 ID_COPY => "Copy selected text to clipboard",
                 ID_PASTE => "Paste text from clipboard",
                 ID_ABOUT => "Show application information",
-                _ => "Ready - Right-click for context menu",
+                _ => "",
             };
 
-            status_bar.set_status_text(help_text, 0);
+            // Highlight events may come before the menu opened event, so the instructions are
+            // saved with the first help text rather than when the menu opens
+            if help_shown.replace(true) {
+                frame.set_status_text(help_text, 0);
+            } else {
+                frame.push_status_text(help_text, 0);
+            }
 
             log::trace!("✨ Menu Highlighted - ID: {}, Help: {}", event.get_id(), help_text);
         });
@@ -1,4 +1,5 @@
 use crate::MenuId;
+use crate::menu_actions::add_node;
 use crate::model::{NodeFields, ServerList, find_node_via_raw_ptr};
 use crate::selection_ctx;
 use crate::server_node::ServerNode;
@@ -108,6 +109,37 @@ pub fn create_data_view_panel(parent: &dyn WxWidget, model: &CustomDataViewTreeM
         selection_ctx::set_pending_details(weak_opt);
     });
 
+    // Dragging a node out, e.g. into a text editor, offers its server URL
+    dataview.enable_drag_source(None);
+    let model_for_drag = model.clone();
+    dataview.on_item_begin_drag(move |event: DataViewEvent| {
+        let Some(needle_ptr) = event.get_item().and_then(|item| item.get_id::<ServerNode>()) else {
+            return;
+        };
+        let url = model_for_drag
+            .with_userdata_mut::<Rc<RefCell<ServerList>>, Option<String>>(|list_rc| {
+                find_node_via_raw_ptr(&*list_rc, needle_ptr).map(|rc| rc.borrow().to_url())
+            })
+            .flatten();
+        if let Some(url) = url {
+            let mut source = DragSource::new(&dataview);
+            source.set_text(&url);
+            event.set_drag_source(source, false);
+        }
+    });
+
+    // Dropping a server URL, e.g. dragged from a browser's address bar, adds a node for it
+    let model_for_drop = model.clone();
+    dataview.set_text_drop_target(move |_pos, text| {
+        let Some(node) = ServerNode::from_url(&text) else {
+            log::info!("Ignoring dropped text that is not a server URL: {text:?}");
+            return false;
+        };
+        log::info!("Adding node for dropped URL {}", text.trim());
+        add_node(&model_for_drop, node);
+        true
+    });
+
     // Layout
     let sizer = BoxSizer::builder(Orientation::Vertical).build();
     sizer.add(&dataview, 1, SizerFlag::Expand | SizerFlag::All, WIDGET_MARGIN);
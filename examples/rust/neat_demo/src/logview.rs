@@ -1,9 +1,76 @@
+use log::{Level, Log, Metadata, Record};
+use std::sync::OnceLock;
+use wxdragon::clipboard;
 use wxdragon::prelude::*;
 
+/// The control log lines are shown in, once the panel exists.
+static LOG_VIEW: OnceLock<TextCtrl> = OnceLock::new();
+
+/// Logs to the console like env_logger, and shows info and more severe lines in the
+/// log panel.
+struct PanelLogger {
+    console: env_logger::Logger,
+}
+
+impl Log for PanelLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.console.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.console.matches(record) {
+            return;
+        }
+        self.console.log(record);
+        if record.level() > Level::Info {
+            return;
+        }
+        if let Some(&text_ctrl) = LOG_VIEW.get() {
+            let level = record.level();
+            let line = format!("[{level}] {}\n", record.args());
+            // Logging may happen on any thread; the control is only touched on the main one
+            let _ = wxdragon::call_after(move || append_line(text_ctrl, level, &line));
+        }
+    }
+
+    fn flush(&self) {
+        self.console.flush();
+    }
+}
+
+/// Installs the logger, showing records allowed by `RUST_LOG` or else `default_filter`.
+pub fn init_logging(default_filter: &str) {
+    let console = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(default_filter)).build();
+    let max_level = console.filter();
+    if log::set_logger(Box::leak(Box::new(PanelLogger { console }))).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+/// Appends `line` in the colour of its severity.
+fn append_line(text_ctrl: TextCtrl, level: Level, line: &str) {
+    let mut style = TextAttr::new();
+    match level {
+        Level::Error => {
+            style.set_text_colour(Colour::rgb(200, 0, 0));
+            style.set_font_weight(FontWeight::Bold);
+        }
+        Level::Warn => {
+            style.set_text_colour(Colour::rgb(200, 120, 0));
+            style.set_font_weight(FontWeight::Normal);
+        }
+        // An empty style goes back to the control's own colours
+        _ => {}
+    }
+    text_ctrl.set_default_style(&style);
+    text_ctrl.append_text(line);
+}
+
 #[allow(dead_code)]
 pub struct LogViewPanel {
     pub panel: Panel,
     pub text_ctrl: TextCtrl,
+    pub copy_button: Button,
 }
 
 impl LogViewPanel {
@@ -13,14 +80,38 @@ impl LogViewPanel {
         let text_ctrl = TextCtrl::builder(&panel)
             .with_size(Size::new(-1, 200))
             .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .with_rich_text()
             .build();
+        let _ = LOG_VIEW.set(text_ctrl);
         sizer.add(
             &text_ctrl,
             1,
             SizerFlag::Expand | SizerFlag::All,
             crate::settings::WIDGET_MARGIN,
         );
+
+        let copy_button = Button::builder(&panel).with_label("Copy Log").build();
+        copy_button.set_tooltip("Copy the whole log to the clipboard");
+        copy_button.on_click(move |_| {
+            // Flush so the log can still be pasted after the demo has been closed
+            if clipboard::set_text(&text_ctrl.get_value()) {
+                clipboard::flush();
+            } else {
+                log::warn!("Failed to copy the log to the clipboard");
+            }
+        });
+        sizer.add(
+            &copy_button,
+            0,
+            SizerFlag::AlignRight | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+            crate::settings::WIDGET_MARGIN,
+        );
+
         panel.set_sizer(sizer, true);
-        Self { panel, text_ctrl }
+        Self {
+            panel,
+            text_ctrl,
+            copy_button,
+        }
     }
 }
@@ -32,6 +32,7 @@ mod details_dlg;
 mod logview;
 mod menu_actions;
 mod model;
+mod notify;
 mod selection_ctx;
 mod server_node;
 mod settings;
@@ -44,7 +45,7 @@ use std::{cell::RefCell, rc::Rc};
 use wxdragon::prelude::*;
 
 fn main() {
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("trace")).init();
+    logview::init_logging("trace");
     let cfg = Rc::new(RefCell::new(settings::load_settings()));
     let cfg_clone = cfg.clone();
     let _ = wxdragon::main(move |_| {
@@ -98,13 +99,28 @@ fn main() {
         frame.set_icon(&icon_bitmap);
 
         // --- Status Bar Setup ---
-        StatusBar::builder(&frame)
+        let status_bar = StatusBar::builder(&frame)
             .with_fields_count(3)
             .with_status_widths(vec![-1, 150, 100])
             .add_initial_text(0, "Ready")
             .add_initial_text(1, "Center Field")
-            .add_initial_text(2, "Right Field")
             .build();
+        status_bar.set_status_styles(&[
+            StatusBarFieldStyle::Normal,
+            StatusBarFieldStyle::Sunken,
+            StatusBarFieldStyle::Flat,
+        ]);
+
+        // The right field hosts a gauge, kept in place by the status bar on resize
+        let traffic = Gauge::builder(&status_bar).with_range(100).build();
+        traffic.set_tooltip("Traffic");
+        status_bar.set_field_widget(2, &traffic);
+
+        // Nodes aren't actually run yet, so the traffic is simulated
+        let traffic_timer = Timer::new(&traffic);
+        traffic_timer.on_tick(move |_| traffic.set_value((traffic.get_value() + 5) % 105));
+        traffic_timer.start(200, false);
+        traffic.on_destroy(move |_| traffic_timer.stop());
 
         // Create popup menu for taskbar icon
         let mut tray_icon_menu = Menu::builder()
@@ -119,6 +135,8 @@ fn main() {
             .with_icon_type(TaskBarIconType::CustomStatusItem)
             .build();
         taskbar.set_popup_menu(&mut tray_icon_menu);
+        // Let native notifications on Windows come from our tray icon
+        NotificationMessage::use_taskbar_icon(Some(&taskbar));
         taskbar.on_menu(move |event| {
             let menu_id = event.get_id();
             match menu_id {
@@ -260,6 +278,7 @@ fn main() {
 
             // Clean up the TaskBarIcon, it's important to call destroy() to remove the icon from the system tray,
             // or we can't exit the application main loop.
+            notify::clear();
             taskbar.destroy();
 
             // Clean up the tray icon menu to release rust closures attached to menu items
@@ -270,6 +289,12 @@ fn main() {
         let main_panel = Panel::builder(&frame).build();
         let sizer = BoxSizer::builder(Orientation::Vertical).build();
 
+        // Info bar for confirmations (top, hidden until a message is shown)
+        let info_bar = InfoBar::new(&main_panel);
+        info_bar.set_show_hide_effects(ShowEffect::SlideToBottom, ShowEffect::SlideToTop, 200);
+        sizer.add(&info_bar, 0, SizerFlag::Expand, 0);
+        notify::set_info_bar(info_bar);
+
         // Integrate DataView module (top, expands)
         let dataview_panel = dataview::create_data_view_panel(&main_panel, &model, &frame);
         sizer.add(
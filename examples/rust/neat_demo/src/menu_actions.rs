@@ -1,8 +1,13 @@
+use wxdragon::clipboard;
 use wxdragon::prelude::*;
 
 use crate::selection_ctx;
-use crate::{MenuId, about_dlg, details_dlg, model::ServerList, server_node, settings_dlg, show_qrcode_dlg};
-use std::{cell::RefCell, rc::Rc};
+use crate::{MenuId, about_dlg, details_dlg, model::ServerList, notify, server_node, settings_dlg, show_qrcode_dlg};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
 
 /// Dispatch a menu command ID to the same logic used by Frame::on_menu.
 /// This allows other UI elements (e.g., double-click on DataView) to reuse menu actions.
@@ -41,6 +46,7 @@ pub fn handle_menu_command(frame: &Frame, model: &CustomDataViewTreeModel, id: i
                             &*b as *const _
                         };
                         model.item_changed::<server_node::ServerNode>(ptr);
+                        notify::confirm(&format!("Node \"{}\" saved", node_name(&rc.borrow())));
                     }
                 } else {
                     // Node no longer exists; open dialog without prefill (no commit target)
@@ -54,25 +60,22 @@ pub fn handle_menu_command(frame: &Frame, model: &CustomDataViewTreeModel, id: i
         x if x == i32::from(MenuId::New) => {
             log::info!("Menu/Toolbar: New clicked!");
             if let Some(node) = details_dlg::details_dlg(frame, None) {
-                let added =
-                    model.with_userdata_mut::<Rc<RefCell<ServerList>>, Option<*const server_node::ServerNode>>(|list_rc| {
-                        let rc = Rc::new(RefCell::new(node));
-                        let ptr: *const server_node::ServerNode = {
-                            let b = rc.borrow();
-                            &*b as *const _
-                        };
-                        list_rc.borrow_mut().nodes.push(rc);
-                        Some(ptr)
-                    });
-                if let Some(Some(ptr)) = added {
-                    model.item_added::<server_node::ServerNode>(None, ptr);
-                }
+                let name = node_name(&node).to_string();
+                add_node(model, node);
+                notify::confirm(&format!("Node \"{name}\" saved"));
             }
         }
+        x if x == i32::from(MenuId::ImportNodeFile) => {
+            log::info!("Menu/Toolbar: Import Node File clicked!");
+            import_node_file(frame, model);
+        }
         x if x == i32::from(MenuId::Delete) => {
             log::info!("Menu/Toolbar: Delete clicked!");
             if let Some(weak) = selection_ctx::get_pending_details() {
                 if let Some(rc) = weak.upgrade() {
+                    if !confirm_delete(frame, &rc.borrow()) {
+                        return;
+                    }
                     // Capture raw pointer for model notification before removal
                     let child_ptr: *const server_node::ServerNode = {
                         let b = rc.borrow();
@@ -105,6 +108,36 @@ pub fn handle_menu_command(frame: &Frame, model: &CustomDataViewTreeModel, id: i
                 log::info!("No selection to delete.");
             }
         }
+        x if x == i32::from(MenuId::Run) => {
+            log::info!("Menu/Toolbar: Run clicked!");
+            match selection_ctx::get_pending_details().and_then(|weak| weak.upgrade()) {
+                Some(rc) => connect_node(frame, &rc.borrow()),
+                None => log::info!("No selection to run."),
+            }
+        }
+        x if x == i32::from(MenuId::Stop) => {
+            log::info!("Menu/Toolbar: Stop clicked!");
+            // Nodes aren't actually run yet, so stopping one stands in for a server going down
+            match selection_ctx::get_pending_details().and_then(|weak| weak.upgrade()) {
+                Some(rc) => notify::notify_server_down(frame, &rc.borrow()),
+                None => log::info!("No selection to stop."),
+            }
+        }
+        x if x == i32::from(MenuId::Copy) => {
+            log::info!("Menu/Toolbar: Copy clicked!");
+            match selection_ctx::get_pending_details().and_then(|weak| weak.upgrade()) {
+                Some(rc) => copy_node(&rc.borrow()),
+                None => log::info!("No selection to copy."),
+            }
+        }
+        x if x == i32::from(MenuId::Paste) => {
+            log::info!("Menu/Toolbar: Paste clicked!");
+            paste_node(model);
+        }
+        x if x == i32::from(MenuId::ScanQrCode) => {
+            log::info!("Menu/Toolbar: Scan QR Code clicked!");
+            scan_qr_code(frame);
+        }
         x if x == i32::from(MenuId::ShowQrCode) => {
             log::info!("Menu/Toolbar: Show QR Code clicked!");
             show_qrcode_dlg::show_qrcode_dlg(frame);
@@ -114,3 +147,185 @@ pub fn handle_menu_command(frame: &Frame, model: &CustomDataViewTreeModel, id: i
         }
     }
 }
+
+/// Grab the screen for QR code detection. Decoding isn't part of the demo, so this only
+/// reports what was captured.
+fn scan_qr_code(frame: &Frame) {
+    let (message, style) = match capture_screen(None) {
+        Some(shot) => (
+            format!("Captured {}x{} pixels of the screen.", shot.get_width(), shot.get_height()),
+            MessageDialogStyle::OK | MessageDialogStyle::IconInformation,
+        ),
+        None => (
+            "The screen could not be captured. On macOS, allow Screen Recording for this app in System Settings.".to_string(),
+            MessageDialogStyle::OK | MessageDialogStyle::IconWarning,
+        ),
+    };
+    MessageDialog::builder(frame, &message, "Scan QR Code")
+        .with_style(style)
+        .build()
+        .show_modal();
+}
+
+thread_local! {
+    // User names saved with "Remember me", keyed by "host:port". Passwords are never kept.
+    static REMEMBERED_USERS: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+    // Cleared when the user ticks "Don't ask me again" in the delete confirmation
+    static CONFIRM_DELETE: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Prompt for the credentials used to connect to `node`.
+fn connect_node(frame: &Frame, node: &server_node::ServerNode) {
+    let server = format!("{}:{}", node.server_host, node.server_port);
+    let remembered = REMEMBERED_USERS.with(|users| users.borrow().get(&server).cloned());
+
+    let dialog = CredentialsDialog::builder(frame, "Connect")
+        .with_message(&format!("Log in to {server}"))
+        .with_user(remembered.as_deref().unwrap_or_default())
+        .with_remember_checkbox("&Remember me", remembered.is_some())
+        .build();
+    let Some(credentials) = dialog.show() else {
+        log::info!("Connection to {server} cancelled");
+        return;
+    };
+
+    REMEMBERED_USERS.with(|users| {
+        let mut users = users.borrow_mut();
+        if credentials.remember {
+            users.insert(server.clone(), credentials.user.clone());
+        } else {
+            users.remove(&server);
+        }
+    });
+    // Only the user name is logged; the password stays in `credentials` and is zeroed on drop
+    log::info!("Connecting to {server} as {}", credentials.user);
+}
+
+/// Ask before deleting `node`, unless the user opted out earlier in this session.
+fn confirm_delete(frame: &Frame, node: &server_node::ServerNode) -> bool {
+    if !CONFIRM_DELETE.get() {
+        return true;
+    }
+    let name = node.remarks.as_deref().unwrap_or(&node.server_host);
+    let dialog = RichMessageDialog::builder(frame, &format!("Delete the node \"{name}\"?"), "Delete Node")
+        .with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconQuestion)
+        .with_checkbox("Don't ask me again", false)
+        .with_detailed_text(&format!(
+            "Host: {}:{}\nThe node and its settings are removed from the list.",
+            node.server_host, node.server_port
+        ))
+        .build();
+    let confirmed = dialog.show_modal() == ID_YES;
+    // Only remember the choice when the user actually went ahead
+    if confirmed && dialog.is_checkbox_checked() {
+        CONFIRM_DELETE.set(false);
+    }
+    confirmed
+}
+
+/// Append a node to the model's server list and notify the view.
+/// Name shown for `node`: its remarks, or its host without them.
+fn node_name(node: &server_node::ServerNode) -> &str {
+    node.remarks.as_deref().unwrap_or(&node.server_host)
+}
+
+pub fn add_node(model: &CustomDataViewTreeModel, node: server_node::ServerNode) {
+    let added = model.with_userdata_mut::<Rc<RefCell<ServerList>>, Option<*const server_node::ServerNode>>(|list_rc| {
+        let rc = Rc::new(RefCell::new(node));
+        let ptr: *const server_node::ServerNode = {
+            let b = rc.borrow();
+            &*b as *const _
+        };
+        list_rc.borrow_mut().nodes.push(rc);
+        Some(ptr)
+    });
+    if let Some(Some(ptr)) = added {
+        model.item_added::<server_node::ServerNode>(None, ptr);
+    }
+}
+
+/// Clipboard format of a whole node as JSON, which only this application reads
+const NODE_CLIPBOARD_FORMAT: &str = "application/x-neat-demo-node";
+
+/// Copy a node both as JSON for pasting here and as its URL for any other application.
+fn copy_node(node: &server_node::ServerNode) {
+    let json = match serde_json::to_vec(node) {
+        Ok(json) => json,
+        Err(e) => {
+            log::error!("Failed to serialize node: {e}");
+            return;
+        }
+    };
+    let data = DataObjectComposite::builder()
+        .with_data(NODE_CLIPBOARD_FORMAT, &json)
+        .with_text(&node.to_url())
+        .build();
+    if !clipboard::set_data_object(data) {
+        log::warn!("Failed to copy the node to the clipboard");
+    }
+}
+
+/// Paste a node copied here, with all its settings, or else one from a URL copied elsewhere.
+fn paste_node(model: &CustomDataViewTreeModel) {
+    let formats = [
+        ClipboardFormat::Custom(NODE_CLIPBOARD_FORMAT.to_string()),
+        ClipboardFormat::Text,
+    ];
+    let node = match clipboard::get_preferred(&formats) {
+        Some(ClipboardData::Custom(_, json)) => serde_json::from_slice(&json).ok(),
+        Some(ClipboardData::Text(text)) => server_node::ServerNode::from_url(&text),
+        _ => None,
+    };
+    match node {
+        Some(node) => add_node(model, node),
+        None => log::info!("The clipboard holds no node to paste."),
+    }
+}
+
+/// Import nodes from a JSON file holding an array of nodes, with a cancellable progress dialog.
+fn import_node_file(frame: &Frame, model: &CustomDataViewTreeModel) {
+    let file_dialog = FileDialog::builder(frame)
+        .with_message("Import Node File")
+        .with_wildcard("Node files (*.json)|*.json|All files (*.*)|*.*")
+        .with_style(FileDialogStyle::Open | FileDialogStyle::FileMustExist)
+        .build();
+    let path = if file_dialog.show_modal() == ID_OK {
+        file_dialog.get_path()
+    } else {
+        None
+    };
+    file_dialog.destroy();
+    let Some(path) = path else {
+        return;
+    };
+
+    let nodes = std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str::<Vec<server_node::ServerNode>>(&text).map_err(|e| e.to_string()));
+    let nodes = match nodes {
+        Ok(nodes) => nodes,
+        Err(e) => {
+            log::error!("Failed to import {path}: {e}");
+            return;
+        }
+    };
+
+    let total = nodes.len() as i32;
+    let progress = ProgressDialog::builder(frame, "Import Node File", "Importing nodes...", total)
+        .can_abort()
+        .show_elapsed_time()
+        .build();
+    let mut imported = 0;
+    for node in nodes {
+        let msg = format!("Importing {}:{}", node.server_host, node.server_port);
+        let (keep_going, _) = progress.update(imported, Some(&msg));
+        if !keep_going {
+            log::info!("Import cancelled after {imported} of {total} node(s)");
+            return;
+        }
+        add_node(model, node);
+        imported += 1;
+    }
+    progress.update(total, Some("Done"));
+    log::info!("Imported {imported} node(s) from {path}");
+}
@@ -0,0 +1,76 @@
+use std::cell::{Cell, RefCell};
+
+use wxdragon::prelude::*;
+
+use crate::server_node::ServerNode;
+
+/// Action id for the "Open" button on server notifications.
+const ACTION_OPEN: i32 = 1;
+
+thread_local! {
+    // The notification must outlive `show()` for its click/action events to arrive
+    static CURRENT: RefCell<Option<NotificationMessage>> = const { RefCell::new(None) };
+    // Info bar at the top of the main window for confirmations
+    static INFO_BAR: Cell<Option<InfoBar>> = const { Cell::new(None) };
+}
+
+/// Use `info_bar` for the confirmations shown by [`confirm`].
+pub fn set_info_bar(info_bar: InfoBar) {
+    INFO_BAR.with(|bar| bar.set(Some(info_bar)));
+}
+
+/// Confirm a finished action, like a saved node, in the main window's info bar.
+pub fn confirm(message: &str) {
+    match INFO_BAR.with(Cell::get) {
+        Some(info_bar) => info_bar.show_message(message, InfoBarIcon::Info),
+        None => log::info!("{message}"),
+    }
+}
+
+/// Show a desktop notification telling the user that `node` went down.
+/// Clicking it (or its "Open" action) brings the main window back.
+pub fn notify_server_down(frame: &Frame, node: &ServerNode) {
+    let name = node.remarks.as_deref().unwrap_or(&node.server_host);
+    let message = format!("{name} ({}:{}) is no longer running.", node.server_host, node.server_port);
+    let notification = match NotificationMessage::builder()
+        .with_title("Server down")
+        .with_message(&message)
+        .with_parent(frame)
+        .with_flags(NotificationStyle::Warning)
+        .build()
+    {
+        Ok(notification) => notification,
+        Err(e) => {
+            log::error!("Failed to create notification: {e:?}");
+            return;
+        }
+    };
+
+    // Actions aren't supported everywhere; the click handler covers that case
+    if let Ok(false) = notification.add_action(ACTION_OPEN, "Open") {
+        log::debug!("Notification actions are not supported on this platform");
+    }
+
+    let frame = *frame;
+    let open_frame = move || {
+        frame.show(true);
+        frame.iconize(false);
+        frame.raise();
+    };
+    notification.on_click(move |_| open_frame());
+    notification.on_action(move |event| {
+        if event.get_id() == ACTION_OPEN {
+            open_frame();
+        }
+    });
+    notification.on_dismissed(|_| log::debug!("Server notification dismissed"));
+
+    notification.show(Timeout::Seconds(10));
+    // Replacing the previous notification destroys it
+    CURRENT.with(|current| *current.borrow_mut() = Some(notification));
+}
+
+/// Destroy the current notification; must run before wx shuts down.
+pub fn clear() {
+    CURRENT.with(|current| current.borrow_mut().take());
+}
@@ -37,4 +37,44 @@ impl ServerNode {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// The server URL of this node, the inverse of [`ServerNode::from_url`].
+    pub fn to_url(&self) -> String {
+        let scheme = if self.disable_tls == Some(true) { "ws" } else { "wss" };
+        let path = if self.tunnel_path.starts_with('/') { "" } else { "/" };
+        format!(
+            "{scheme}://{}:{}{path}{}",
+            self.server_host, self.server_port, self.tunnel_path
+        )
+    }
+
+    /// Parse a server URL such as `wss://example.com:8443/tunnel`, e.g. one dragged from a browser.
+    /// Plain `ws://` and `http://` URLs disable TLS; the port defaults to the scheme's one.
+    pub fn from_url(url: &str) -> Option<Self> {
+        let (scheme, rest) = url.trim().split_once("://")?;
+        let secure = match scheme.to_ascii_lowercase().as_str() {
+            "wss" | "https" => true,
+            "ws" | "http" => false,
+            _ => return None,
+        };
+        let (authority, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().ok()?),
+            None => (authority, if secure { 443 } else { 80 }),
+        };
+        if host.is_empty() {
+            return None;
+        }
+        Some(Self {
+            remarks: Some(host.to_string()),
+            tunnel_path: path.to_string(),
+            disable_tls: (!secure).then_some(true),
+            server_host: host.to_string(),
+            server_port: port,
+            ..Self::default()
+        })
+    }
 }
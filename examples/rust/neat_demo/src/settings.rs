@@ -93,22 +93,15 @@ fn retrieve_config_path() -> std::path::PathBuf {
 }
 
 pub fn create_bitmap_from_memory(data: &[u8], target_size: Option<(u32, u32)>) -> std::io::Result<Bitmap> {
-    let img = image::load_from_memory(data).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-
-    if let Some((w, h)) = target_size {
-        use image::imageops::FilterType;
-        let resized = img.resize_exact(w, h, FilterType::Lanczos3);
-        convert_image_to_bitmap(&resized)
-    } else {
-        convert_image_to_bitmap(&img)
-    }
-}
-
-pub fn convert_image_to_bitmap(image: &image::DynamicImage) -> std::io::Result<Bitmap> {
-    let rgba = image.to_rgba8();
-    let (width, height) = rgba.dimensions();
-    let icon_bitmap = Bitmap::from_rgba(&rgba, width, height).ok_or(std::io::Error::other("Failed to create bitmap"))?;
-    Ok(icon_bitmap)
+    let img = Image::from_bytes(data).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+    let img = match target_size {
+        Some((w, h)) => img
+            .scale(w as i32, h as i32, ResizeQuality::High)
+            .ok_or(std::io::Error::other("Failed to scale image"))?,
+        None => img,
+    };
+    Ok(img.to_bitmap())
 }
 
 /// Center a rectangle of size (w, h) within the parent window
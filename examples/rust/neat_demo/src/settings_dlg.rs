@@ -49,7 +49,10 @@ pub fn settings_dlg(frame_clone: &dyn WxWidget) {
     let ok_button = Button::builder(&panel).with_label("OK").build();
     let cancel_button = Button::builder(&panel).with_label("Cancel").with_id(ID_CANCEL).build();
     ok_button.on_click(move |_data| {
-        dialog.end_modal(ID_OK);
+        // Checks the fields on all tabs, switching to the first invalid one
+        if dialog.validate() {
+            dialog.end_modal(ID_OK);
+        }
     });
     cancel_button.on_click(move |_data| {
         dialog.end_modal(ID_CANCEL);
@@ -76,6 +79,24 @@ pub fn settings_dlg(frame_clone: &dyn WxWidget) {
     // Dialog is automatically cleaned up when it goes out of scope
 }
 
+fn validate_ipv4(value: &str) -> Result<(), String> {
+    value
+        .parse::<std::net::Ipv4Addr>()
+        .map(|_| ())
+        .map_err(|_| format!("'{value}' is not an IPv4 address, e.g. 8.8.8.8."))
+}
+
+/// Accepts an empty value or a `host:port` address.
+fn validate_optional_address(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Ok(());
+    }
+    match value.rsplit_once(':') {
+        Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok_and(|port| port > 0) => Ok(()),
+        _ => Err(format!("'{value}' is not an address like 127.0.0.1:8080.")),
+    }
+}
+
 fn create_common_tab(parent: &dyn WxWidget) -> Panel {
     let panel = Panel::builder(parent).build();
 
@@ -88,7 +109,14 @@ fn create_common_tab(parent: &dyn WxWidget) -> Panel {
         .with_style(StaticTextStyle::AlignRight)
         .with_size(label_size)
         .build();
-    let host_input = TextCtrl::builder(&panel).with_size(Size::new(200, -1)).build();
+    let host_input = TextCtrl::builder(&panel)
+        .with_size(Size::new(200, -1))
+        .with_value("127.0.0.1")
+        .build();
+    host_input.set_validator(
+        RegexValidator::new("[A-Za-z0-9]([A-Za-z0-9.-]*[A-Za-z0-9])?")
+            .with_message("Enter a host name or an IPv4 address, e.g. 127.0.0.1."),
+    );
 
     // Listen Port
     let port_label = StaticText::builder(&panel)
@@ -101,6 +129,7 @@ fn create_common_tab(parent: &dyn WxWidget) -> Panel {
         .with_min_value(1)
         .with_max_value(u16::MAX as i32)
         .build();
+    port_input.set_validator(IntValidator::new(1, u16::MAX as i64));
 
     // Listen User
     let user_label = StaticText::builder(&panel)
@@ -133,6 +162,7 @@ fn create_common_tab(parent: &dyn WxWidget) -> Panel {
         .with_max_value(10000)
         .with_size(Size::new(100, -1))
         .build();
+    pool_input.set_validator(IntValidator::new(10, 10000));
 
     // Cache DNS Label + CheckBox
     let cache_dns_label = StaticText::builder(&panel)
@@ -214,6 +244,7 @@ fn create_tun2proxy_tab(parent: &dyn WxWidget) -> Panel {
         .with_size(Size::new(200, -1))
         .with_value("8.8.8.8")
         .build();
+    dns_addr_input.set_validator(CustomValidator::new(validate_ipv4).with_char_filter(|ch| ch.is_ascii_digit() || ch == '.'));
 
     // DNS Strategy (dropdown)
     let dns_strategy_label = StaticText::builder(&panel)
@@ -290,6 +321,7 @@ fn create_httpproxy_tab(parent: &dyn WxWidget) -> Panel {
         .with_size(label_size)
         .build();
     let local_addr_input = TextCtrl::builder(&panel).with_size(Size::new(200, -1)).build();
+    local_addr_input.set_validator(CustomValidator::new(validate_optional_address));
 
     // Server Addr
     let server_addr_label = StaticText::builder(&panel)
@@ -298,6 +330,7 @@ fn create_httpproxy_tab(parent: &dyn WxWidget) -> Panel {
         .with_size(label_size)
         .build();
     let server_addr_input = TextCtrl::builder(&panel).with_size(Size::new(200, -1)).build();
+    server_addr_input.set_validator(CustomValidator::new(validate_optional_address));
 
     // Username
     let username_label = StaticText::builder(&panel)
@@ -347,8 +380,20 @@ fn create_httpproxy_tab(parent: &dyn WxWidget) -> Panel {
     grid.add(&password_label, 0, SizerFlag::AlignRight | SizerFlag::AlignCenterVertical, 0);
     grid.add(&password_input, 0, SizerFlag::Expand, 0);
 
+    // CA files trusted for the server connection, added and edited in place
+    let ca_files_list = EditableListBox::builder(&panel).with_label("CA Files").build();
+    ca_files_list.on_changed(move |_| {
+        log::debug!("CA files: {:?}", ca_files_list.get_strings());
+    });
+
     let sizer = BoxSizer::builder(Orientation::Vertical).build();
     sizer.add_sizer(&grid, 0, SizerFlag::Expand | SizerFlag::All, 16);
+    sizer.add(
+        &ca_files_list,
+        1,
+        SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+        16,
+    );
     panel.set_sizer(sizer, true);
     panel
 }
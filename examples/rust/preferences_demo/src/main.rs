@@ -0,0 +1,181 @@
+//! Preferences Demo - a settings dialog whose categories and their pages form a Treebook.
+//!
+//! - Three top-level categories with nested pages, each with an icon
+//! - The tree is widened so the page titles fit
+//! - Leaving the Proxy page with an invalid port is vetoed
+
+use wxdragon::prelude::*;
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("Preferences Demo")
+            .with_size(Size::new(360, 200))
+            .build();
+        let panel = Panel::builder(&frame).build();
+        let open = Button::builder(&panel).with_label("Preferences...").build();
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add(&open, 0, SizerFlag::AlignCenterHorizontal | SizerFlag::All, 40);
+        panel.set_sizer(sizer, true);
+
+        open.on_click(move |_| show_preferences(&frame));
+
+        frame.show(true);
+        frame.centre();
+    });
+}
+
+fn show_preferences(parent: &Frame) {
+    let dialog = Dialog::builder(parent, "Preferences")
+        .with_style(DialogStyle::DefaultDialogStyle | DialogStyle::ResizeBorder)
+        .with_size(Size::new(560, 380))
+        .build();
+    let treebook = Treebook::builder(&dialog).build();
+
+    let icons = ImageList::new(16, 16, true, 3);
+    for art in [ArtId::ExecutableFile, ArtId::Edit, ArtId::HardDisk] {
+        if let Some(bitmap) = ArtProvider::get_bitmap(art, ArtClient::Menu, Some(Size::new(16, 16))) {
+            icons.add_bitmap(&bitmap);
+        }
+    }
+    treebook.set_image_list(icons);
+
+    // General
+    let general = Panel::builder(&treebook).build();
+    treebook.add_page(&general, "General", true, 0);
+    let sizer = page_sizer();
+    stack(&sizer, &check(&general, "Restore open files on startup", true));
+    general.set_sizer(sizer, true);
+
+    let updates = Panel::builder(&treebook).build();
+    treebook.add_sub_page(&updates, "Updates", false, -1);
+    let sizer = page_sizer();
+    stack(&sizer, &check(&updates, "Check for updates automatically", true));
+    stack(&sizer, &check(&updates, "Include pre-releases", false));
+    updates.set_sizer(sizer, true);
+
+    // Appearance
+    let appearance = Panel::builder(&treebook).build();
+    treebook.add_page(&appearance, "Appearance", false, 1);
+    let sizer = page_sizer();
+    stack(&sizer, &check(&appearance, "Follow the system dark mode", true));
+    appearance.set_sizer(sizer, true);
+
+    let fonts = Panel::builder(&treebook).build();
+    treebook.add_sub_page(&fonts, "Fonts", false, -1);
+    let font_size = SpinCtrl::builder(&fonts).with_range(6, 48).with_initial_value(11).build();
+    let sizer = page_sizer();
+    stack(&sizer, &label(&fonts, "Editor font size:"));
+    stack(&sizer, &font_size);
+    fonts.set_sizer(sizer, true);
+
+    let colours = Panel::builder(&treebook).build();
+    treebook.add_sub_page(&colours, "Colours", false, -1);
+    let sizer = page_sizer();
+    stack(&sizer, &check(&colours, "Highlight the current line", true));
+    colours.set_sizer(sizer, true);
+
+    // Network, with the Proxy page inserted under it once it exists
+    let network = Panel::builder(&treebook).build();
+    treebook.add_page(&network, "Network", false, 2);
+    let sizer = page_sizer();
+    stack(&sizer, &check(&network, "Work offline", false));
+    network.set_sizer(sizer, true);
+
+    let proxy = Panel::builder(&treebook).build();
+    let network_pos = treebook.get_page_count() as usize - 1;
+    treebook.insert_sub_page(network_pos, &proxy, "Proxy", false, -1);
+    let host = TextCtrl::builder(&proxy).with_value("proxy.example.com").build();
+    let port = TextCtrl::builder(&proxy).with_value("8080").build();
+    let port_error = StaticText::builder(&proxy).build();
+    port_error.set_foreground_color(Colour::rgb(200, 0, 0));
+    let sizer = page_sizer();
+    stack(&sizer, &label(&proxy, "Host:"));
+    stack(&sizer, &host);
+    stack(&sizer, &label(&proxy, "Port:"));
+    stack(&sizer, &port);
+    stack(&sizer, &port_error);
+    proxy.set_sizer(sizer, true);
+
+    for pos in 0..treebook.get_page_count() as usize {
+        treebook.expand_node(pos, true);
+    }
+    if let Some(tree) = treebook.get_tree_ctrl() {
+        tree.set_min_size(Size::new(160, -1));
+    }
+
+    // The proxy port must be valid before any other page is shown
+    treebook.on_page_changing(move |event| {
+        let leaving_proxy = event
+            .get_old_selection()
+            .and_then(|old| treebook.get_page(old as usize))
+            .is_some_and(|page| page.handle_ptr() == proxy.handle_ptr());
+        if leaving_proxy && !matches!(port.get_value().trim().parse::<u16>(), Ok(1..)) {
+            port_error.set_label("Enter a port from 1 to 65535.");
+            event.veto();
+        } else {
+            port_error.set_label("");
+        }
+    });
+
+    // The title shows where the page is, e.g. "Preferences - Appearance > Fonts"
+    treebook.on_page_changed(move |event| {
+        let Some(selection) = event.get_selection() else {
+            return;
+        };
+        let selection = selection as usize;
+        let mut path = treebook.get_page_text(selection);
+        let mut pos = selection;
+        while let Some(parent) = treebook.get_page_parent(pos) {
+            path = format!("{} > {path}", treebook.get_page_text(parent));
+            pos = parent;
+        }
+        dialog.set_label(&format!("Preferences - {path}"));
+    });
+
+    let sizer = BoxSizer::builder(Orientation::Vertical).build();
+    sizer.add(&treebook, 1, SizerFlag::Expand | SizerFlag::All, 10);
+    if let Some(buttons) = dialog.create_std_dialog_button_sizer(StdDialogButtons::Ok | StdDialogButtons::Cancel) {
+        sizer.add_sizer(
+            &buttons,
+            0,
+            SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+            10,
+        );
+    }
+    dialog.set_sizer(sizer, true);
+
+    if dialog.show_modal() == ID_OK {
+        println!(
+            "Saved: font size {}, proxy {}:{}",
+            font_size.value(),
+            host.get_value(),
+            port.get_value()
+        );
+    }
+    dialog.destroy();
+}
+
+fn check(page: &Panel, text: &str, checked: bool) -> CheckBox {
+    let checkbox = CheckBox::builder(page).with_label(text).build();
+    checkbox.set_value(checked);
+    checkbox
+}
+
+fn label(page: &Panel, text: &str) -> StaticText {
+    StaticText::builder(page).with_label(text).build()
+}
+
+// Pages stack their controls from the top
+fn page_sizer() -> BoxSizer {
+    BoxSizer::builder(Orientation::Vertical).build()
+}
+
+fn stack<W: WxWidget>(sizer: &BoxSizer, control: &W) {
+    sizer.add(
+        control,
+        0,
+        SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top,
+        10,
+    );
+}
@@ -1,5 +1,11 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use wxdragon::dc::PolygonFillMode;
 use wxdragon::prelude::*;
 
+/// Size of the drawing in logical units; it is scaled to fit within the page margins.
+const DRAWING_SIZE: Size = Size { width: 400, height: 250 };
+
 fn main() {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("trace")).init();
 
@@ -9,24 +15,64 @@ fn main() {
             .with_size(Size::new(400, 300))
             .build();
 
+        // Settings chosen by the user are kept here and reused for the next job
+        let page_setup = Rc::new(RefCell::new(PageSetupDialogData::new()));
+        let print_settings = Rc::new(RefCell::new(PrintDialogData::new()));
+        {
+            let mut page_setup = page_setup.borrow_mut();
+            page_setup.set_margin_top_left(Point::new(20, 20));
+            page_setup.set_margin_bottom_right(Point::new(20, 20));
+        }
+
         let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        let setup_button = Button::builder(&frame).with_label("Page Setup...").build();
         let button = Button::builder(&frame).with_label("Print...").build();
 
+        setup_button.on_click({
+            let frame_ptr = frame;
+            let page_setup = page_setup.clone();
+            move |_| {
+                let mut page_setup = page_setup.borrow_mut();
+                if PageSetupDialog::edit(&frame_ptr, &mut page_setup) {
+                    log::info!(
+                        "Page setup: {:?} {:?}, margins {:?} / {:?} mm",
+                        page_setup.get_paper_id(),
+                        page_setup.get_orientation(),
+                        page_setup.get_margin_top_left(),
+                        page_setup.get_margin_bottom_right()
+                    );
+                }
+            }
+        });
+
         button.on_click({
             let frame_ptr = frame;
             move |_| {
-                let mut printer = Printer::new(None);
-                let my_printout = TestPrintout;
+                // Print with the paper and orientation from the page setup
+                print_settings
+                    .borrow_mut()
+                    .set_print_data(&page_setup.borrow().get_print_data());
+
+                let mut printer = Printer::new(&*print_settings.borrow());
+                let my_printout = TestPrintout {
+                    page_setup: page_setup.borrow().clone(),
+                };
                 if !printer.print(Some(&frame_ptr), "My Print Document", my_printout, true) {
                     log::warn!("Printing failed or was cancelled");
-                } else {
-                    log::info!("Printing successful");
+                    return;
                 }
+                log::info!("Printing successful");
+
+                // Remember copies, page range and any paper change made in the print dialog
+                let chosen = printer.get_print_dialog_data().clone();
+                page_setup.borrow_mut().set_print_data(&chosen.get_print_data());
+                *print_settings.borrow_mut() = chosen;
             }
         });
 
         sizer.add_stretch_spacer(1);
-        sizer.add(&button, 0, SizerFlag::AlignCenterHorizontal | SizerFlag::All, 20);
+        sizer.add(&setup_button, 0, SizerFlag::AlignCenterHorizontal | SizerFlag::All, 10);
+        sizer.add(&button, 0, SizerFlag::AlignCenterHorizontal | SizerFlag::All, 10);
         sizer.add_stretch_spacer(1);
 
         frame.set_sizer(sizer, true);
@@ -35,25 +81,130 @@ fn main() {
     });
 }
 
-struct TestPrintout;
+struct TestPrintout {
+    page_setup: PageSetupDialogData,
+}
 
 impl Printout for TestPrintout {
-    fn on_print_page(&mut self, dc: &GenericDC, _page_num: i32) -> bool {
-        log::info!("on_print_page called for page {}", _page_num);
+    fn on_print_page(&mut self, dc: &GenericDC, page_num: i32) -> bool {
+        log::info!("on_print_page called for page {}", page_num);
 
-        // Draw something on the DC
-        dc.draw_text("Hello from wxDragon Printing!", 50, 50);
-        dc.draw_circle(100, 150, 40);
-        dc.draw_rectangle(200, 120, 100, 60);
+        // Scale the drawing to the printable area so it looks the same at any printer DPI
+        self.fit_this_size_to_page_margins(DRAWING_SIZE, &self.page_setup);
+        log::info!("Page size: {:?} mm", self.get_page_size_mm());
 
+        match page_num {
+            1 => draw_shapes_page(dc),
+            _ => draw_chart_page(dc),
+        }
         true
     }
 
     fn get_page_info(&mut self) -> (i32, i32, i32, i32) {
-        (1, 1, 1, 1) // Min, Max, From, To
+        (1, PAGE_COUNT, 1, PAGE_COUNT) // Min, Max, From, To
     }
 
     fn has_page(&mut self, page_num: i32) -> bool {
-        page_num == 1
+        (1..=PAGE_COUNT).contains(&page_num)
+    }
+}
+
+const PAGE_COUNT: i32 = 2;
+
+fn draw_shapes_page(dc: &GenericDC) {
+    dc.draw_rectangle(0, 0, DRAWING_SIZE.width, DRAWING_SIZE.height);
+    dc.draw_text("Hello from wxDragon Printing!", 20, 20);
+    dc.draw_circle(100, 150, 40);
+    dc.draw_rectangle(200, 120, 100, 60);
+}
+
+/// Monthly values plotted on the chart page
+const SALES: [i32; 8] = [12, 19, 15, 27, 24, 33, 29, 38];
+
+/// A line chart exercising most of the drawing primitives
+fn draw_chart_page(dc: &GenericDC) {
+    let black = Colour::new(0, 0, 0, 255);
+    let blue = Colour::new(30, 90, 200, 255);
+    let grey = Colour::new(150, 150, 150, 255);
+
+    // Frame and title
+    dc.set_pen(grey, 1, PenStyle::Solid);
+    dc.set_brush(Colour::new(255, 255, 255, 255), BrushStyle::Transparent);
+    dc.draw_rounded_rectangle(0, 0, DRAWING_SIZE.width, DRAWING_SIZE.height, 10.0);
+    dc.draw_text("Sales per month", 20, 10);
+
+    // Plot area and axes
+    let (left, top, right, bottom) = (40, 40, 380, 220);
+    let max_value = 40;
+    let to_point = |i: usize, value: i32| {
+        let x = left + (right - left) * i as i32 / (SALES.len() as i32 - 1);
+        let y = bottom - (bottom - top) * value / max_value;
+        Point::new(x, y)
+    };
+    dc.set_pen(black, 1, PenStyle::Solid);
+    dc.draw_lines(
+        &[Point::new(left, top), Point::new(left, bottom), Point::new(right, bottom)],
+        0,
+        0,
+    );
+    for value in (10..=max_value).step_by(10) {
+        let y = to_point(0, value).y;
+        dc.draw_line(left - 4, y, left, y);
+        dc.draw_text(&value.to_string(), left - 25, y - 7);
+    }
+
+    // Hatched area under the line
+    let points: Vec<Point> = SALES.iter().enumerate().map(|(i, &v)| to_point(i, v)).collect();
+    let mut area = points.clone();
+    area.push(Point::new(right, bottom));
+    area.push(Point::new(left, bottom));
+    dc.set_pen(black, 0, PenStyle::Transparent);
+    dc.set_brush(Colour::new(180, 200, 240, 255), BrushStyle::FDiagonalHatch);
+    dc.draw_polygon(&area, 0, 0, PolygonFillMode::OddEven);
+
+    // Data line, smoothed trend and markers
+    dc.set_pen(blue, 2, PenStyle::Solid);
+    dc.draw_lines(&points, 0, 0);
+    dc.set_pen(grey, 1, PenStyle::ShortDash);
+    dc.draw_spline(&points);
+    dc.set_pen(blue, 1, PenStyle::Solid);
+    dc.set_brush(Colour::new(255, 255, 255, 255), BrushStyle::Solid);
+    for point in &points {
+        dc.draw_ellipse(point.x - 3, point.y - 3, 7, 7);
+        dc.draw_point(point.x, point.y);
+    }
+
+    // Legend: two square markers drawn as one poly-polygon
+    let square = |x: i32, y: i32| {
+        [
+            Point::new(x, y),
+            Point::new(x + 8, y),
+            Point::new(x + 8, y + 8),
+            Point::new(x, y + 8),
+        ]
+    };
+    let (actual, trend) = (square(250, 12), square(320, 12));
+    dc.set_pen(black, 1, PenStyle::Solid);
+    dc.set_brush(blue, BrushStyle::Solid);
+    dc.draw_poly_polygon(&[&actual, &trend], 0, 0, PolygonFillMode::OddEven);
+    dc.draw_text("Actual", 262, 8);
+    dc.draw_text("Trend", 332, 8);
+
+    // Growth gauge: a half circle arc with the achieved share as a filled elliptic arc
+    let growth = f64::from(SALES[SALES.len() - 1] - SALES[0]) / f64::from(SALES[0]);
+    dc.set_brush(Colour::new(0, 0, 0, 0), BrushStyle::Transparent);
+    dc.draw_arc(370, 80, 330, 80, 350, 80);
+    dc.set_brush(Colour::new(80, 170, 80, 255), BrushStyle::Solid);
+    let share = (growth / 2.0).clamp(0.0, 1.0); // the gauge ends at +200%
+    dc.draw_elliptic_arc(330, 60, 40, 40, 180.0 - 180.0 * share, 180.0);
+    dc.draw_text(&format!("+{:.0}%", growth * 100.0), 335, 85);
+
+    // Small logo from raw pixels
+    let logo: Vec<u8> = (0..16 * 16)
+        .flat_map(|i| [30, 90 + (i % 16) * 8, 200, 255])
+        .map(|c| c as u8)
+        .collect();
+    if let Some(bitmap) = Bitmap::from_rgba(&logo, 16, 16) {
+        dc.draw_bitmap(&bitmap, DRAWING_SIZE.width - 26, DRAWING_SIZE.height - 26, false);
     }
 }
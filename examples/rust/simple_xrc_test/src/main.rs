@@ -229,7 +229,7 @@ fn main() {
         });
 
         // Search control
-        search_ctrl.on_search_button_clicked(move |_event_data| {
+        search_ctrl.on_search(move |_event_data| {
             log::info!("Search button clicked");
             status_label.set_label("Search performed");
         });
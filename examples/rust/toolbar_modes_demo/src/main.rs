@@ -0,0 +1,152 @@
+//! ToolBar Modes Demo - a toolbar switching between browsing and editing tools.
+//!
+//! The tools of the mode not shown are removed from the toolbar and kept, not destroyed, so
+//! switching creates nothing anew: the search field keeps its handler and the toolbar keeps
+//! its number of tools however often the mode changes.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wxdragon::prelude::*;
+
+const ID_EDIT_MODE: Id = ID_HIGHEST + 1;
+const ID_BACK: Id = ID_HIGHEST + 2;
+const ID_FORWARD: Id = ID_HIGHEST + 3;
+const ID_HOME: Id = ID_HIGHEST + 4;
+const ID_SEARCH: Id = ID_HIGHEST + 5;
+const ID_SAVE: Id = ID_HIGHEST + 6;
+const ID_UNDO: Id = ID_HIGHEST + 7;
+const ID_REDO: Id = ID_HIGHEST + 8;
+const ID_DELETE: Id = ID_HIGHEST + 9;
+
+const BROWSE_TOOLS: [Id; 4] = [ID_BACK, ID_FORWARD, ID_HOME, ID_SEARCH];
+const EDIT_TOOLS: [Id; 4] = [ID_SAVE, ID_UNDO, ID_REDO, ID_DELETE];
+
+// The mode switch and a separator come before the tools of the mode
+const FIRST_MODE_TOOL: usize = 2;
+
+fn add_art_tool(toolbar: &ToolBar, id: Id, label: &str, art_id: ArtId) {
+    match ArtProvider::get_bitmap_bundle(art_id, ArtClient::Toolbar, Some(Size::new(24, 24))) {
+        Some(bundle) => {
+            toolbar.add_tool(id, label, bundle, label);
+        }
+        None => eprintln!("No icon for the {label} tool"),
+    }
+}
+
+/// Shows the tools kept in `stash` in place of the ones in `shown`, which go to `stash`
+fn switch_tools(toolbar: &ToolBar, shown: &[Id], stash: &mut Vec<RemovedTool>) {
+    let hidden = std::mem::take(stash);
+    for &id in shown {
+        stash.extend(toolbar.remove_tool(id));
+    }
+    let mut pos = FIRST_MODE_TOOL;
+    for tool in hidden {
+        match toolbar.insert_removed_tool(pos, tool) {
+            Ok(()) => pos += 1,
+            Err(tool) => eprintln!("Couldn't insert tool {} again", tool.get_tool_id()),
+        }
+    }
+    toolbar.realize();
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("ToolBar Modes Demo")
+            .with_size(Size::new(560, 360))
+            .build();
+        frame.create_status_bar(1, 0, ID_ANY as i32, "statusbar");
+
+        let toolbar = frame
+            .create_tool_bar(Some(ToolBarStyle::Default), ID_ANY as i32)
+            .expect("Failed to create the toolbar");
+        if let Some(bundle) = ArtProvider::get_bitmap_bundle(ArtId::Edit, ArtClient::Toolbar, Some(Size::new(24, 24))) {
+            toolbar.add_check_tool(ID_EDIT_MODE, "Edit", bundle, "Switch to the editing tools");
+        }
+        toolbar.add_separator();
+
+        // The browsing tools, shown first
+        add_art_tool(&toolbar, ID_BACK, "Back", ArtId::GoBack);
+        add_art_tool(&toolbar, ID_FORWARD, "Forward", ArtId::GoForward);
+        add_art_tool(&toolbar, ID_HOME, "Home", ArtId::GoHome);
+        let search = SearchCtrl::builder(&toolbar)
+            .with_id(ID_SEARCH)
+            .with_descriptive_text("Search")
+            .with_size(Size::new(160, -1))
+            .build();
+        toolbar.add_control(&search);
+        search.on_search(move |_| frame.set_status_text(&format!("Searching for '{}'", search.get_value()), 0));
+
+        // The editing tools, removed right away to be shown in editing mode
+        add_art_tool(&toolbar, ID_SAVE, "Save", ArtId::FileSave);
+        add_art_tool(&toolbar, ID_UNDO, "Undo", ArtId::Undo);
+        add_art_tool(&toolbar, ID_REDO, "Redo", ArtId::Redo);
+        add_art_tool(&toolbar, ID_DELETE, "Delete", ArtId::Delete);
+        let stash = Rc::new(RefCell::new(Vec::new()));
+        for id in EDIT_TOOLS {
+            stash.borrow_mut().extend(toolbar.remove_tool(id));
+        }
+        toolbar.realize();
+
+        let editing = Rc::new(Cell::new(false));
+        let set_editing = {
+            let stash = stash.clone();
+            let editing = editing.clone();
+            move |edit: bool| {
+                if edit == editing.get() {
+                    return;
+                }
+                let shown = if edit { &BROWSE_TOOLS } else { &EDIT_TOOLS };
+                switch_tools(&toolbar, shown, &mut stash.borrow_mut());
+                editing.set(edit);
+                toolbar.toggle_tool(ID_EDIT_MODE, edit);
+            }
+        };
+
+        let panel = Panel::builder(&frame).build();
+        let info = StaticText::builder(&panel)
+            .with_label("Click Edit to switch between the browsing and the editing tools.")
+            .build();
+        let stress = Button::builder(&panel).with_label("Switch 1000 times").build();
+        let log = TextCtrl::builder(&panel)
+            .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .build();
+
+        // All tools are handled here once, whichever of them are on the toolbar
+        let set_mode = set_editing.clone();
+        frame.on_menu(move |event| match event.get_id() {
+            ID_EDIT_MODE => set_mode(toolbar.get_tool_state(ID_EDIT_MODE)),
+            id => {
+                if let Some(pos) = toolbar.get_tool_pos(id) {
+                    log.append_text(&format!("Tool {id} clicked at position {pos}\n"));
+                }
+            }
+        });
+
+        stress.on_click(move |_| {
+            let tools_before = toolbar.get_tools_count();
+            let mode_before = editing.get();
+            for _ in 0..1000 {
+                set_editing(!editing.get());
+            }
+            log.append_text(&format!(
+                "1000 switches: {} tools before, {} after, {} kept aside, editing {} -> {}, search field {}\n",
+                tools_before,
+                toolbar.get_tools_count(),
+                stash.borrow().len(),
+                mode_before,
+                editing.get(),
+                if search.is_valid() { "still alive" } else { "destroyed" },
+            ));
+        });
+
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add(&info, 0, SizerFlag::All, 10);
+        sizer.add(&stress, 0, SizerFlag::Left | SizerFlag::Right, 10);
+        sizer.add(&log, 1, SizerFlag::Expand | SizerFlag::All, 10);
+        panel.set_sizer(sizer, true);
+
+        frame.show(true);
+        frame.centre();
+    });
+}
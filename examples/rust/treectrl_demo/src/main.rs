@@ -365,6 +365,29 @@ fn add_styling_buttons(panel: &Panel, sizer: &BoxSizer, tree: TreeCtrl) {
     });
     btn_sizer.add(&btn_get_colors, 0, SizerFlag::All, 2);
 
+    let btn_pick_text = Button::builder(panel).with_label("Pick text colour...").build();
+    let tree4 = tree;
+    let dialog_parent = *panel;
+    btn_pick_text.on_click(move |_| {
+        let Some(item) = tree4.get_selection() else {
+            log::info!("Select an item first");
+            return;
+        };
+        // Custom colours saved in the dialog are remembered for the next time it opens
+        let dialog = ColourDialog::builder(&dialog_parent)
+            .with_title("Pick text colour")
+            .with_initial_colour(tree4.get_item_text_colour(&item))
+            .build();
+        if dialog.show_modal() == ID_OK {
+            if let Some(colour) = dialog.get_colour() {
+                tree4.set_item_text_colour(&item, colour);
+                log::info!("Set text colour to rgba({},{},{},{})", colour.r, colour.g, colour.b, colour.a);
+            }
+        }
+        dialog.destroy();
+    });
+    btn_sizer.add(&btn_pick_text, 0, SizerFlag::All, 2);
+
     sizer.add_sizer(&btn_sizer, 0, SizerFlag::Expand, 0);
 }
 
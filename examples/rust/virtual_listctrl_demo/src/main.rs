@@ -1,12 +1,20 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use wxdragon::prelude::*;
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
 #[derive(Clone)]
 struct Row {
     id: usize,
     title: String,
     category: String,
+    severity: Severity,
 }
 
 fn main() {
@@ -31,17 +39,32 @@ fn main() {
             .build();
 
         let list = ListCtrl::builder(&panel)
-            .with_style(ListCtrlStyle::Report | ListCtrlStyle::Virtual | ListCtrlStyle::VRules | ListCtrlStyle::HRules)
+            .with_style(ListCtrlStyle::VRules | ListCtrlStyle::HRules)
+            .virtual_mode()
             .build();
 
+        // Severity icons, in the order of `Severity`
+        let icons = ImageList::new(16, 16, true, 3);
+        for art in [ArtId::Information, ArtId::Warning, ArtId::Error] {
+            if let Some(bitmap) = ArtProvider::get_bitmap(art, ArtClient::FrameIcon, Some(Size::new(16, 16))) {
+                icons.add_bitmap(&bitmap);
+            }
+        }
+        list.set_image_list(icons, image_list_type::SMALL);
+
         list.insert_column(0, "ID", ListColumnFormat::Right, 90);
         list.insert_column(1, "Title", ListColumnFormat::Left, 420);
         list.insert_column(2, "Category", ListColumnFormat::Left, 180);
         list.set_item_count(rows.len() as i64);
 
+        // Counts the text requests: the list only asks for the rows it shows, so scrolling
+        // through 100k rows costs a few hundred calls per second at most.
+        let requests = Rc::new(Cell::new(0usize));
         let callback_rows = rows.clone();
         let callback_indices = filtered_indices.clone();
-        assert!(list.set_virtual_text_callback(move |item, col| {
+        let text_requests = requests.clone();
+        assert!(list.on_get_item_text(move |item, col| {
+            text_requests.set(text_requests.get() + 1);
             let Some(row_index) = callback_indices.borrow().get(item as usize).copied() else {
                 return String::new();
             };
@@ -57,10 +80,37 @@ fn main() {
             }
         }));
 
+        let severity_at = {
+            let rows = rows.clone();
+            let indices = filtered_indices.clone();
+            move |item: i64| {
+                let row_index = *indices.borrow().get(item as usize)?;
+                rows.get(row_index).map(|row| row.severity)
+            }
+        };
+        let image_severity_at = severity_at.clone();
+        list.on_get_item_image(move |item| image_severity_at(item).map_or(-1, |severity| severity as i32));
+        list.on_get_item_attr(move |item| match severity_at(item)? {
+            Severity::Info => None,
+            Severity::Warning => Some(ListItemAttr::new().with_text_colour(Colour::rgb(160, 100, 0))),
+            Severity::Error => Some(ListItemAttr::new().with_text_colour(Colour::rgb(200, 0, 0)).with_bold(true)),
+        });
+
+        let request_label = StaticText::builder(&panel).with_label("0 text requests/s").build();
+        let timer = Timer::new(&request_label);
+        timer.on_tick(move |_| {
+            request_label.set_label(&format!("{} text requests/s", requests.replace(0)));
+        });
+        timer.start(1000, false);
+        request_label.on_destroy(move |_| {
+            timer.stop();
+        });
+
         let top_sizer = BoxSizer::builder(Orientation::Vertical).build();
         let search_sizer = BoxSizer::builder(Orientation::Horizontal).build();
         search_sizer.add(&search, 1, SizerFlag::Expand | SizerFlag::Right, 8);
-        search_sizer.add(&status, 0, SizerFlag::AlignCenterVertical, 0);
+        search_sizer.add(&status, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+        search_sizer.add(&request_label, 0, SizerFlag::AlignCenterVertical, 0);
         top_sizer.add_sizer(&search_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
         top_sizer.add(
             &list,
@@ -70,36 +120,24 @@ fn main() {
         );
         panel.set_sizer(top_sizer, true);
 
-        let filter_rows = rows.clone();
-        let filter_indices = filtered_indices.clone();
-        let filter_list = list;
-        let filter_status = status;
+        // Filtering 100k rows on every keystroke makes typing lag, so the filter only runs
+        // once typing paused for 300ms: each change cancels the previously scheduled run.
+        let pending_filter: Rc<RefCell<Option<CallLaterHandle>>> = Rc::new(RefCell::new(None));
         search.on_text_updated(move |event| {
             let query = event.get_string().unwrap_or_default().to_lowercase();
-            let mut indices = filter_indices.borrow_mut();
-            indices.clear();
-
-            if query.is_empty() {
-                indices.extend(0..filter_rows.len());
-            } else {
-                indices.extend(filter_rows.iter().enumerate().filter_map(|(index, row)| {
-                    let matches = row.title.to_lowercase().contains(&query)
-                        || row.category.to_lowercase().contains(&query)
-                        || row.id.to_string().contains(&query);
-                    matches.then_some(index)
-                }));
-            }
-
-            let count = indices.len();
-            filter_list.set_item_count(count as i64);
-            if count > 0 {
-                filter_list.refresh_items(0, count as i64 - 1);
+            if let Some(previous) = pending_filter.borrow_mut().take() {
+                previous.cancel();
             }
-            filter_status.set_label(&format!("{count} rows"));
+            let filter_rows = rows.clone();
+            let filter_indices = filtered_indices.clone();
+            let handle = wxdragon::call_later(300, move || {
+                apply_filter(&query, &filter_rows, &filter_indices, list, status);
+            });
+            *pending_filter.borrow_mut() = Some(handle);
         });
 
         let cancel_search = search;
-        search.on_cancel_button_clicked(move |_| {
+        search.on_cancel(move |_| {
             cancel_search.set_value("");
         });
 
@@ -108,6 +146,30 @@ fn main() {
     });
 }
 
+fn apply_filter(query: &str, rows: &[Row], filtered_indices: &RefCell<Vec<usize>>, list: ListCtrl, status: StaticText) {
+    let watch = StopWatch::new();
+    let mut indices = filtered_indices.borrow_mut();
+    indices.clear();
+
+    if query.is_empty() {
+        indices.extend(0..rows.len());
+    } else {
+        indices.extend(rows.iter().enumerate().filter_map(|(index, row)| {
+            let matches = row.title.to_lowercase().contains(query)
+                || row.category.to_lowercase().contains(query)
+                || row.id.to_string().contains(query);
+            matches.then_some(index)
+        }));
+    }
+
+    let count = indices.len();
+    list.set_item_count(count as i64);
+    if count > 0 {
+        list.refresh_items(0, count as i64 - 1);
+    }
+    status.set_label(&format!("{count} rows ({} ms)", watch.time_ms()));
+}
+
 fn build_rows(count: usize) -> Vec<Row> {
     let categories = ["Build", "Runtime", "Docs", "UI", "Testing", "Release"];
     (0..count)
@@ -115,6 +177,11 @@ fn build_rows(count: usize) -> Vec<Row> {
             id,
             title: format!("Issue candidate {id:06}"),
             category: categories[id % categories.len()].to_string(),
+            severity: match id % 10 {
+                0 => Severity::Error,
+                1 | 2 => Severity::Warning,
+                _ => Severity::Info,
+            },
         })
         .collect()
 }
@@ -0,0 +1,197 @@
+//! Renders an HTML report generated from Rust data with `WebView::set_page`.
+//!
+//! The page links to a stylesheet compiled into the executable and served by a
+//! custom scheme handler, so the report works without network or files on disk.
+
+use std::cell::Cell;
+use std::fmt::Write;
+
+use wxdragon::prelude::*;
+use wxdragon::sizers::SizerFlag;
+use wxdragon::widgets::{WebView, WebViewBackend, WebViewHandlerResponse, WebViewReloadFlags};
+
+/// Scheme serving the report's assets.
+const ASSET_SCHEME: &str = "report";
+
+/// Assets embedded at compile time, by path below the base URL.
+const ASSETS: &[(&str, &[u8], &str)] = &[("report.css", include_bytes!("../assets/report.css"), "text/css")];
+
+/// Serves an embedded asset for `report://app/path` (or `https://report.wxsite/path`,
+/// the form Edge requests custom schemes through).
+fn serve_asset(uri: &str) -> Option<WebViewHandlerResponse> {
+    let path = uri
+        .strip_prefix("report://app/")
+        .or_else(|| uri.strip_prefix("https://report.wxsite/"))?
+        .trim_start_matches('/');
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let (_, data, mime) = ASSETS.iter().find(|(name, _, _)| *name == path)?;
+    Some(WebViewHandlerResponse {
+        data: data.to_vec(),
+        mime_type: Some(mime.to_string()),
+    })
+}
+
+/// One line of the sales report.
+struct Sale {
+    region: &'static str,
+    product: &'static str,
+    units: u32,
+    unit_price: f64,
+}
+
+impl Sale {
+    fn revenue(&self) -> f64 {
+        self.units as f64 * self.unit_price
+    }
+}
+
+/// Sales figures for a quarter, varied a little per quarter so regenerating shows new data.
+fn sales_for_quarter(quarter: u32) -> Vec<Sale> {
+    let base = [
+        ("North", "Widgets", 120, 9.5),
+        ("North", "Gadgets", 45, 24.0),
+        ("South", "Widgets", 80, 9.5),
+        ("South", "Gizmos <XL>", 30, 49.9),
+        ("East", "Gadgets", 95, 24.0),
+        ("West", "Gizmos <XL>", 12, 49.9),
+    ];
+    base.iter()
+        .enumerate()
+        .map(|(i, &(region, product, units, unit_price))| Sale {
+            region,
+            product,
+            units: units + (quarter * 7 + i as u32 * 13) % 40,
+            unit_price,
+        })
+        .collect()
+}
+
+/// Escapes `text` for use in HTML content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders the report. The stylesheet is a relative link resolved against the base URL.
+fn render_report(quarter: u32, sales: &[Sale]) -> String {
+    let total: f64 = sales.iter().map(Sale::revenue).sum();
+    let largest = sales.iter().map(Sale::revenue).fold(0.0, f64::max);
+
+    let mut rows = String::new();
+    for sale in sales {
+        let width = if largest > 0.0 {
+            sale.revenue() / largest * 100.0
+        } else {
+            0.0
+        };
+        let _ = writeln!(
+            rows,
+            r#"<tr><td>{}</td><td>{}</td><td class="number">{}</td><td class="number">{:.2}</td><td><div class="bar" style="width: {:.0}%"></div></td></tr>"#,
+            escape_html(sale.region),
+            escape_html(sale.product),
+            sale.units,
+            sale.revenue(),
+            width
+        );
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Sales report Q{quarter}</title>
+<link rel="stylesheet" href="report.css">
+</head>
+<body>
+<h1>Sales report</h1>
+<p class="subtitle">Quarter {quarter}, {count} lines</p>
+<table>
+<thead><tr><th>Region</th><th>Product</th><th>Units</th><th>Revenue</th><th></th></tr></thead>
+<tbody>
+{rows}</tbody>
+<tfoot><tr><td colspan="3">Total</td><td class="number">{total:.2}</td><td></td></tr></tfoot>
+</table>
+</body>
+</html>
+"#,
+        count = sales.len()
+    )
+}
+
+fn main() {
+    wxdragon::main(|_app| {
+        let frame = Frame::builder()
+            .with_title("WebView Report")
+            .with_size(Size::new(900, 650))
+            .build();
+
+        let panel = Panel::builder(&frame).build();
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+
+        let toolbar_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+        let btn_next = Button::builder(&panel).with_label("Next Quarter").build();
+        let btn_reload = Button::builder(&panel).with_label("Reload").build();
+        let btn_text = Button::builder(&panel).with_label("Print Text").build();
+        let btn_source = Button::builder(&panel).with_label("Print Source").build();
+        toolbar_sizer.add(&btn_next, 0, SizerFlag::All, 5);
+        toolbar_sizer.add(&btn_reload, 0, SizerFlag::All, 5);
+        toolbar_sizer.add(&btn_text, 0, SizerFlag::All, 5);
+        toolbar_sizer.add(&btn_source, 0, SizerFlag::All, 5);
+        sizer.add_sizer(&toolbar_sizer, 0, SizerFlag::Expand, 0);
+
+        let backend = if WebView::is_backend_available(WebViewBackend::Edge) {
+            WebViewBackend::Edge
+        } else {
+            WebViewBackend::Default
+        };
+        // Edge serves custom schemes from a virtual https host
+        let base_url = if backend == WebViewBackend::Edge {
+            "https://report.wxsite/"
+        } else {
+            "report://app/"
+        };
+
+        let webview = WebView::builder(&panel)
+            .with_backend(backend)
+            .with_handler(ASSET_SCHEME, serve_asset)
+            .build();
+        sizer.add(&webview, 1, SizerFlag::Expand, 0);
+        panel.set_sizer(sizer, true);
+
+        let quarter = Cell::new(1);
+        webview.set_page(&render_report(quarter.get(), &sales_for_quarter(quarter.get())), base_url);
+
+        btn_next.on_click(move |_| {
+            let q = quarter.get() % 4 + 1;
+            quarter.set(q);
+            webview.set_page(&render_report(q, &sales_for_quarter(q)), base_url);
+        });
+
+        // Shows the same generated report again instead of a blank page
+        btn_reload.on_click(move |_| {
+            webview.reload(WebViewReloadFlags::Default);
+        });
+
+        btn_text.on_click(move |_| {
+            println!("{}", webview.get_page_text());
+        });
+
+        btn_source.on_click(move |_| {
+            println!("{}", webview.get_page_source());
+        });
+
+        frame.show(true);
+        frame.centre();
+    });
+}
@@ -2,9 +2,98 @@ use wxdragon::event::WebViewEvents;
 use wxdragon::prelude::*;
 use wxdragon::sizers::SizerFlag;
 use wxdragon::widgets::{
-    WebView, WebViewBackend, WebViewFindFlags, WebViewReloadFlags, WebViewUserScriptInjectionTime, WebViewZoom,
+    WebView, WebViewBackend, WebViewBrowsingDataTypes, WebViewFindFlags, WebViewHandlerResponse, WebViewReloadFlags,
+    WebViewSnapshotArea, WebViewUserScriptInjectionTime, WebViewZoom,
 };
 
+/// Scheme serving the pages compiled into the executable.
+const ASSET_SCHEME: &str = "app";
+
+/// Assets embedded at compile time, by path below the scheme.
+const ASSETS: &[(&str, &[u8], &str)] = &[
+    ("index.html", include_bytes!("../assets/index.html"), "text/html"),
+    ("style.css", include_bytes!("../assets/style.css"), "text/css"),
+];
+
+/// Serves an embedded asset for `app://path` (or `https://app.wxsite/path`, the
+/// form Edge requests custom schemes through).
+fn serve_asset(uri: &str) -> Option<WebViewHandlerResponse> {
+    let path = uri
+        .strip_prefix("app://")
+        .or_else(|| uri.strip_prefix("https://app.wxsite/"))?
+        .trim_start_matches('/');
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let (_, data, mime) = ASSETS.iter().find(|(name, _, _)| *name == path)?;
+    Some(WebViewHandlerResponse {
+        data: data.to_vec(),
+        mime_type: Some(mime.to_string()),
+    })
+}
+
+/// Name of the script message handler the bridge page posts to.
+const BRIDGE_HANDLER: &str = "wx_msg";
+
+/// Local page exchanging JSON messages with Rust through the script message handler.
+const BRIDGE_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>Rust Bridge</title></head>
+<body style="font-family: sans-serif">
+  <h2>JavaScript to Rust bridge</h2>
+  <p>
+    <input id="name" value="wxDragon">
+    <button onclick="sendGreeting()">Send JSON to Rust</button>
+  </p>
+  <pre id="log"></pre>
+  <script>
+    let count = 0;
+    function log(text) {
+      document.getElementById('log').textContent += text + '\n';
+    }
+    // Called by Rust through run_script
+    function receiveFromRust(reply) {
+      log('Rust replied: ' + reply);
+    }
+    function sendGreeting() {
+      count += 1;
+      const message = { action: 'greet', name: document.getElementById('name').value, count: count };
+      window.wx_msg.postMessage(JSON.stringify(message));
+      log('Sent: ' + JSON.stringify(message));
+    }
+    // Posted while the page is still loading, before Rust sees the loaded event
+    window.wx_msg.postMessage(JSON.stringify({ action: 'ready' }));
+  </script>
+</body>
+</html>
+"#;
+
+/// Whether `url` is one of the embedded pages.
+fn is_asset_url(url: &str) -> bool {
+    url.starts_with("app://") || url.starts_with("https://app.wxsite/")
+}
+
+/// Whether `url` points to a site outside the embedded pages.
+fn is_external_url(url: &str) -> bool {
+    (url.starts_with("http://") || url.starts_with("https://")) && !is_asset_url(url)
+}
+
+/// Quotes `text` as a JavaScript string literal.
+fn js_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            c if (c as u32) < 0x20 || c == '\u{2028}' || c == '\u{2029}' => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 fn main() {
     wxdragon::main(|_app| {
         let frame = Frame::builder()
@@ -26,6 +115,9 @@ fn main() {
         let btn_script = Button::builder(&panel).with_label("Run Script").build();
         let btn_devtools = Button::builder(&panel).with_label("DevTools").build();
         let btn_find = Button::builder(&panel).with_label("Find 'test'").build();
+        let btn_bridge = Button::builder(&panel).with_label("Bridge Page").build();
+        let btn_clear = Button::builder(&panel).with_label("Clear site data").build();
+        let btn_snapshot = Button::builder(&panel).with_label("Snapshot").build();
 
         toolbar_sizer.add(&btn_back, 0, SizerFlag::All, 5);
         toolbar_sizer.add(&btn_forward, 0, SizerFlag::All, 5);
@@ -35,6 +127,9 @@ fn main() {
         toolbar_sizer.add(&btn_script, 0, SizerFlag::All, 5);
         toolbar_sizer.add(&btn_devtools, 0, SizerFlag::All, 5);
         toolbar_sizer.add(&btn_find, 0, SizerFlag::All, 5);
+        toolbar_sizer.add(&btn_bridge, 0, SizerFlag::All, 5);
+        toolbar_sizer.add(&btn_clear, 0, SizerFlag::All, 5);
+        toolbar_sizer.add(&btn_snapshot, 0, SizerFlag::All, 5);
 
         sizer.add_sizer(&toolbar_sizer, 0, SizerFlag::Expand, 0);
 
@@ -66,7 +161,18 @@ fn main() {
             WebViewBackend::Default
         };
 
-        let webview = WebView::builder(&panel).with_backend(backend).build();
+        // Start on the embedded page. The handler is registered before this first
+        // load, which is also what makes it work with WebKit on macOS.
+        let start_url = if backend == WebViewBackend::Edge {
+            "https://app.wxsite/index.html"
+        } else {
+            "app://index.html"
+        };
+        let webview = WebView::builder(&panel)
+            .with_backend(backend)
+            .with_handler(ASSET_SCHEME, serve_asset)
+            .with_url(Some(start_url.to_string()))
+            .build();
 
         // Enable dev tools and context menu
         webview.enable_access_to_dev_tools(true);
@@ -82,15 +188,24 @@ fn main() {
             WebViewUserScriptInjectionTime::AtDocumentStart,
         );
 
+        // Let pages post messages to Rust; added before any page loads so none are missed
+        if !webview.add_script_message_handler(BRIDGE_HANDLER) {
+            println!("Script message handler '{}' not supported by this backend", BRIDGE_HANDLER);
+        }
+
         // Print backend info
         let backend_name = webview.get_backend();
         println!("WebView backend: {}", backend_name);
 
-        // Load initial URL
-        webview.load_url("https://www.google.com");
-
         sizer.add(&webview, 1, SizerFlag::Expand, 0);
 
+        // Results of the "Run Script" button
+        let script_output = TextCtrl::builder(&panel)
+            .with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+            .with_size(Size::new(-1, 100))
+            .build();
+        sizer.add(&script_output, 0, SizerFlag::Expand | SizerFlag::All, 5);
+
         panel.set_sizer(sizer, true);
 
         // Event handling - Navigation
@@ -139,27 +254,16 @@ fn main() {
             println!("Zoom level: {:?} (backend: {:?})", webview.get_zoom(), backend);
         });
 
-        // Advanced script execution
+        // Asynchronous script execution; the result is shown below the page
         btn_script.on_click(move |_| {
-            // Get page title
-            if let Some(title) = webview.run_script("document.title") {
-                println!("Page title: {}", title);
-            }
-
-            // Get page URL
-            if let Some(url) = webview.run_script("window.location.href") {
-                println!("Page URL: {}", url);
-            }
-
-            // Get page text length
-            if let Some(len) = webview.run_script("document.body.innerText.length") {
-                println!("Page text length: {} characters", len);
-            }
-
-            // Show current URL from API
-            println!("Current URL (API): {}", webview.get_current_url());
-            println!("Current title (API): {}", webview.get_current_title());
-            println!("User agent: {}", webview.get_user_agent());
+            let script = "({ title: document.title, url: location.href, textLength: document.body.innerText.length })";
+            webview.run_script_async(script, move |result| {
+                let line = match result {
+                    Ok(value) => format!("Page info: {value}\n"),
+                    Err(message) => format!("Script failed: {message}\n"),
+                };
+                script_output.append_text(&line);
+            });
         });
 
         // Dev tools toggle
@@ -182,6 +286,66 @@ fn main() {
             println!("Found {} occurrences of 'test'", if count >= 0 { count } else { 0 });
         });
 
+        // Local page talking to Rust
+        btn_bridge.on_click(move |_| {
+            webview.set_page(BRIDGE_PAGE, "about:blank");
+        });
+
+        // Log out of the current site, then wipe everything stored by any site
+        btn_clear.on_click(move |_| {
+            let url = webview.get_current_url();
+            if webview.supports_cookies() {
+                println!("Deleted cookies for {}: {}", url, webview.delete_cookies(&url));
+            } else {
+                println!("Cookies cannot be managed with this backend");
+            }
+
+            if webview.supports_browsing_data_clearing() {
+                let types =
+                    WebViewBrowsingDataTypes::COOKIES | WebViewBrowsingDataTypes::CACHE | WebViewBrowsingDataTypes::DOM_STORAGE;
+                println!("Clearing browsing data started: {}", webview.clear_browsing_data(types, None));
+            } else {
+                println!("Browsing data cannot be cleared with this backend");
+            }
+        });
+
+        webview.on_browsing_data_cleared(move |_| {
+            println!("Browsing data cleared");
+        });
+
+        // Capture the whole page, save it next to the other temp files and copy it
+        btn_snapshot.on_click(move |_| {
+            webview.capture_snapshot_async(WebViewSnapshotArea::FullPage, |bitmap| {
+                let Some(bitmap) = bitmap else {
+                    println!("Snapshot not available with this backend");
+                    return;
+                };
+                let path = std::env::temp_dir().join("webview_snapshot.png");
+                match bitmap.save_file(&path, BitmapType::Png) {
+                    Ok(()) => println!("Snapshot saved to {}", path.display()),
+                    Err(err) => println!("Saving snapshot failed: {err}"),
+                }
+                println!("Snapshot copied to clipboard: {}", wxdragon::clipboard::set_bitmap(&bitmap));
+            });
+        });
+
+        // Messages posted by the page through window.wx_msg.postMessage()
+        webview.on_script_message(move |event| {
+            let handler = event.get_message_handler_name().unwrap_or_default();
+            let message = event.get_string().unwrap_or_default();
+            println!("Script message to '{}': {}", handler, message);
+
+            if handler == BRIDGE_HANDLER {
+                let reply = format!("got {} bytes: {}", message.len(), message);
+                if webview
+                    .run_script(&format!("receiveFromRust({})", js_string(&reply)))
+                    .is_none()
+                {
+                    println!("Page has no receiveFromRust() to reply to");
+                }
+            }
+        });
+
         // WebView events
         webview.on_loaded(move |_| {
             println!("Page loaded! URL: {}", webview.get_current_url());
@@ -189,8 +353,82 @@ fn main() {
             println!("Is busy: {}", webview.is_busy());
         });
 
-        webview.on_navigating(move |_| {
-            println!("Navigating...");
+        // Keep the embedded UI in the webview: deep links are handled by Rust and
+        // links to other sites open in the user's browser instead
+        webview.on_navigating(move |event| {
+            let url = event.get_url().unwrap_or_default();
+            println!("Navigating to {} (target '{}')", url, event.get_target().unwrap_or_default());
+
+            if let Some(link) = url.strip_prefix("myapp://") {
+                event.veto();
+                println!("Deep link handled by Rust: {}", link);
+            } else if is_asset_url(&webview.get_current_url()) && is_external_url(&url) {
+                event.veto();
+                if !launch_default_browser(&url, BrowserLaunchFlags::Default) {
+                    println!("Could not open {} in the default browser", url);
+                }
+            }
+        });
+
+        // target="_blank" links and window.open(): embedded pages stay in this view,
+        // everything else goes to the system browser
+        webview.on_new_window(move |event| {
+            let url = event.get_url().unwrap_or_default();
+            println!("New window requested for {}", url);
+
+            if is_asset_url(&url) {
+                event.load_in_current();
+            } else {
+                event.veto();
+                if !launch_default_browser(&url, BrowserLaunchFlags::Default) {
+                    println!("Could not open {} in the default browser", url);
+                }
+            }
+        });
+
+        // Downloads: saved to the suggested location, with progress on the console
+        println!("Download events supported: {}", webview.supports_downloads());
+        webview.on_download_request(move |event| {
+            println!(
+                "Download requested: {} ({}, {}) -> {:?}",
+                event.get_url().unwrap_or_default(),
+                event.get_suggested_filename().unwrap_or_default(),
+                event.get_mime_type().unwrap_or_else(|| "unknown type".to_string()),
+                event.get_download_path().unwrap_or_default()
+            );
+        });
+
+        webview.on_download_progress(move |event| {
+            let received = event.get_bytes_received().unwrap_or(0);
+            match event.get_total_bytes() {
+                Some(total) => println!("Downloaded {} of {} bytes", received, total),
+                None => println!("Downloaded {} bytes", received),
+            }
+        });
+
+        webview.on_download_completed(move |event| {
+            println!(
+                "Download {:?}: {:?}",
+                event.get_download_state(),
+                event.get_download_path().unwrap_or_default()
+            );
+        });
+
+        webview.on_navigated(move |event| {
+            println!("Navigated to {}", event.get_url().unwrap_or_default());
+        });
+
+        webview.on_error(move |event| {
+            println!(
+                "Navigation to {} failed: {:?} ({})",
+                event.get_url().unwrap_or_default(),
+                event.get_navigation_error(),
+                event.get_error_description().unwrap_or_default()
+            );
+        });
+
+        webview.on_title_changed(move |event| {
+            println!("Title changed: {}", event.get_title().unwrap_or_default());
         });
 
         frame.show(true);
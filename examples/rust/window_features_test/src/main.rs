@@ -86,6 +86,43 @@ fn test_cursor_functionality(window: &Frame) {
         }
     }
 
+    // Test a brush shaped cursor built from an image, with the hotspot in its centre
+    println!("\nTesting image cursors...");
+    const BRUSH_SIZE: i32 = 21;
+    let radius = BRUSH_SIZE / 2;
+    if let Some(mut brush) = Image::new(BRUSH_SIZE, BRUSH_SIZE) {
+        brush.set_alpha(0);
+        for y in 0..BRUSH_SIZE {
+            for x in 0..BRUSH_SIZE {
+                let (dx, dy) = (x - radius, y - radius);
+                if dx * dx + dy * dy <= radius * radius {
+                    brush.set_pixel(x, y, Colour::new(30, 90, 200, 140));
+                }
+            }
+        }
+        match Cursor::from_image(&brush, radius, radius) {
+            Some(cursor) => {
+                let hotspot = cursor.get_hotspot();
+                println!("✓ Created brush cursor, hotspot: ({}, {})", hotspot.x, hotspot.y);
+                window.set_cursor(Some(&cursor));
+                std::thread::sleep(std::time::Duration::from_millis(1000));
+            }
+            None => println!("✗ Failed to create brush cursor"),
+        }
+
+        // Oversized images are scaled down where the platform requires it
+        let big_brush = brush.scale(128, 128, ResizeQuality::High).unwrap_or(brush);
+        if let Some(cursor) = Cursor::from_bitmap(&big_brush.to_bitmap(), Point::new(64, 64)) {
+            let hotspot = cursor.get_hotspot();
+            println!(
+                "✓ Created cursor from a 128x128 bitmap, hotspot: ({}, {})",
+                hotspot.x, hotspot.y
+            );
+        } else {
+            println!("✗ Failed to create cursor from a 128x128 bitmap");
+        }
+    }
+
     // Test global cursor functions
     println!("\nTesting global cursor functions...");
 
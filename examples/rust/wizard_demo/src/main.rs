@@ -0,0 +1,153 @@
+//! Wizard Demo - a first-run setup wizard built with wxDragon
+//!
+//! This demo shows:
+//! - Three chained `WizardPage`s whose controls are laid out with sizers
+//! - Validation by vetoing `on_page_changing`
+//! - A confirmation when cancelling, through a vetoable `on_cancel`
+//! - Runtime branching with `WizardPage::set_next`: the proxy page is only shown
+//!   when "Use a proxy server" is checked
+//! - Collecting the values into a `Settings` struct once the wizard is finished
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use wxdragon::prelude::*;
+
+#[derive(Debug, Clone, Default)]
+struct Settings {
+    user_name: String,
+    theme: String,
+    proxy: Option<(String, i32)>,
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = Frame::builder()
+            .with_title("Wizard Demo")
+            .with_size(Size::new(420, 240))
+            .build();
+
+        let panel = Panel::builder(&frame).build();
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        let result = StaticText::builder(&panel).with_label("No settings yet.").build();
+        let run_button = Button::builder(&panel).with_label("Run Setup Wizard...").build();
+        sizer.add(&run_button, 0, SizerFlag::All, 10);
+        sizer.add(&result, 1, SizerFlag::Expand | SizerFlag::All, 10);
+        panel.set_sizer(sizer, true);
+
+        let settings = Rc::new(RefCell::new(Settings::default()));
+        run_button.on_click(move |_| {
+            let mut current = settings.borrow_mut();
+            match run_setup_wizard(&frame, &current) {
+                Some(new_settings) => {
+                    result.set_label(&format!("{new_settings:#?}"));
+                    *current = new_settings;
+                }
+                None => result.set_label("Setup was cancelled."),
+            }
+            panel.layout();
+        });
+
+        frame.show(true);
+        frame.centre();
+    });
+}
+
+/// Runs the wizard pre-filled with `initial`; returns the new settings if it was finished.
+fn run_setup_wizard(parent: &Frame, initial: &Settings) -> Option<Settings> {
+    let wizard = Wizard::builder(parent, "First Run Setup", None).build();
+
+    // Page 1: profile
+    let profile_page = WizardPage::new(&wizard);
+    let profile_sizer = BoxSizer::builder(Orientation::Vertical).build();
+    let intro = StaticText::builder(&profile_page)
+        .with_label("Welcome! This wizard configures the application.\n\nWhat should we call you?")
+        .build();
+    let name = TextCtrl::builder(&profile_page).with_value(&initial.user_name).build();
+    profile_sizer.add(&intro, 0, SizerFlag::Expand | SizerFlag::Bottom, 10);
+    profile_sizer.add(&name, 0, SizerFlag::Expand, 0);
+    profile_page.set_sizer(profile_sizer, true);
+
+    // Page 2: preferences
+    let prefs_page = WizardPage::new(&wizard);
+    let prefs_sizer = BoxSizer::builder(Orientation::Vertical).build();
+    let themes = ["System", "Light", "Dark"];
+    let theme_label = StaticText::builder(&prefs_page).with_label("Theme:").build();
+    let theme = Choice::builder(&prefs_page)
+        .with_choices(themes.iter().map(|t| t.to_string()).collect())
+        .with_selection(Some(themes.iter().position(|t| *t == initial.theme).unwrap_or(0) as u32))
+        .build();
+    let use_proxy = CheckBox::builder(&prefs_page).with_label("Use a proxy server").build();
+    use_proxy.set_value(initial.proxy.is_some());
+    prefs_sizer.add(&theme_label, 0, SizerFlag::Bottom, 5);
+    prefs_sizer.add(&theme, 0, SizerFlag::Expand | SizerFlag::Bottom, 15);
+    prefs_sizer.add(&use_proxy, 0, SizerFlag::Expand, 0);
+    prefs_page.set_sizer(prefs_sizer, true);
+
+    // Page 3: proxy, only reachable when the checkbox is checked
+    let proxy_page = WizardPage::new(&wizard);
+    let proxy_sizer = FlexGridSizer::builder(2, 2).with_gap(Size::new(8, 8)).build();
+    proxy_sizer.add_growable_col(1, 1);
+    let (initial_host, initial_port) = initial.proxy.clone().unwrap_or_else(|| (String::new(), 8080));
+    let host_label = StaticText::builder(&proxy_page).with_label("Host:").build();
+    let host = TextCtrl::builder(&proxy_page).with_value(&initial_host).build();
+    let port_label = StaticText::builder(&proxy_page).with_label("Port:").build();
+    let port = SpinCtrl::builder(&proxy_page)
+        .with_range(1, 65535)
+        .with_initial_value(initial_port)
+        .build();
+    proxy_sizer.add(&host_label, 0, SizerFlag::AlignCenterVertical, 0);
+    proxy_sizer.add(&host, 1, SizerFlag::Expand, 0);
+    proxy_sizer.add(&port_label, 0, SizerFlag::AlignCenterVertical, 0);
+    proxy_sizer.add(&port, 0, SizerFlag::Expand, 0);
+    proxy_page.set_sizer(proxy_sizer, true);
+
+    wizard.chain(&[&profile_page, &prefs_page, &proxy_page]);
+    // Branch at runtime: without a proxy the preferences page is the last one, so
+    // pressing Next there finishes the wizard. The static link still serves "Back"
+    // from the proxy page.
+    prefs_page.set_next(move || use_proxy.is_checked().then_some(proxy_page));
+
+    // Validation: only when moving forward
+    wizard.on_page_changing(move |event| {
+        if !event.get_direction() {
+            return;
+        }
+        let page = event.get_page();
+        let error = if page == Some(profile_page) && name.get_value().trim().is_empty() {
+            Some("Please enter a name.")
+        } else if page == Some(proxy_page) && host.get_value().trim().is_empty() {
+            Some("Please enter the proxy host.")
+        } else {
+            None
+        };
+        if let Some(message) = error {
+            MessageDialog::builder(&wizard, message, "Setup")
+                .with_style(MessageDialogStyle::OK | MessageDialogStyle::IconWarning)
+                .build()
+                .show_modal();
+            event.veto();
+        }
+    });
+
+    wizard.on_cancel(move |event| {
+        let answer = MessageDialog::builder(&wizard, "Really cancel the setup?", "Setup")
+            .with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconQuestion)
+            .build()
+            .show_modal();
+        if answer != ID_YES {
+            event.veto();
+        }
+    });
+
+    wizard.on_finished(|_| println!("Setup wizard finished"));
+
+    let settings = wizard.run_wizard(&profile_page).then(|| Settings {
+        user_name: name.get_value().trim().to_string(),
+        theme: theme.get_string_selection().unwrap_or_default(),
+        proxy: use_proxy
+            .is_checked()
+            .then(|| (host.get_value().trim().to_string(), port.get_value())),
+    });
+    wizard.destroy();
+    settings
+}
@@ -13,21 +13,84 @@ use syn::{Error, Ident, LitStr, Token, parse_macro_input};
 ///
 /// ```ignore
 /// include_xrc!("path/to/file.xrc", StructName);
+/// include_xrc!(["dialogs.xrc", "common.xrc"], StructName, root = "SettingsDialog");
 /// ```
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the XRC file relative to the current crate root
+/// * `path` - Path to the XRC file relative to the current crate root, or a list of paths
 /// * `struct_name` - Name for the generated Rust struct  
+/// * `root = "Name"` - Optional name of the top-level Frame, Dialog or Panel to load;
+///   defaults to the first one found
+///
+/// # Multiple files
+///
+/// All listed files are loaded into the same resource set before the root is created,
+/// so `<object_ref ref="common_buttons"/>` can use objects from any of them. The named
+/// objects inside a referenced object become fields as if they were written in place.
+/// A top-level name defined in more than one file is a compile error, since wxWidgets
+/// would silently pick one of them.
 ///
 /// # Generated Code
 ///
 /// The macro generates a struct with:
 /// - A field for the root object (Frame, Dialog, or Panel) - automatically detected
 /// - Fields for all named child widgets found in the XRC
-/// - A `new()` method that loads the XRC and initializes all fields
+/// - A `new()` method that loads the XRC and initializes all fields, and
+///   `new_with_base_path()` to resolve relative paths (e.g. bitmap files) against
+///   another directory than the XRC file's one
+/// - An accessor returning `Option<Bitmap>` for each named top-level `wxBitmap` or
+///   `wxIcon` object
+/// - An `ID_*` constant for each named `wxMenuItem` and `tool`, e.g. `ID_MENU_OPEN`
+///   for `menu_open`, to match ids in menu and tool event handlers
 /// - An `xrc_id()` helper method for getting XRC IDs
 ///
+/// # Menu and tool ids
+///
+/// XRC normally allocates ids when objects are created, so they are not known at
+/// compile time. Instead, each menu item and tool gets an id derived from its name,
+/// in the range 20000..32000, which `new()` assigns before creating any object:
+///
+/// ```ignore
+/// frame.on_menu(|event| match event.get_id() {
+///     MyFrameUI::ID_MENU_OPEN => open_file(),
+///     MyFrameUI::ID_MENU_QUIT => quit(),
+///     _ => {}
+/// });
+/// ```
+///
+/// The same name gets the same id in every XRC file. `new()` panics if such an id is
+/// already used by another name (or the name already has another id), which in
+/// practice means two names from different files hashed to the same id; renaming one
+/// of them resolves it. Names starting with `wxID_` keep their stock id.
+///
+/// # Errors
+///
+/// Compilation fails if a named object in the XRC has a class without a wxDragon
+/// wrapper; the error lists each such object as `name (class)`. Unnamed objects are
+/// not turned into fields and may use any class.
+///
+/// # Custom widgets
+///
+/// Objects of your own XRC classes, such as `<object class="MyCanvas" name="plot"/>`,
+/// are created by Rust types implementing `wxdragon::xrc::XrcCustomWidget`. Map the
+/// class to the type with the `custom` option and the field gets that type:
+///
+/// ```ignore
+/// include_xrc!("ui.xrc", MyFrameUI, custom = { "MyCanvas" => MyCanvas });
+/// ```
+///
+/// Position, size and the other window attributes declared in the XRC are applied
+/// to the created widget.
+///
+/// To build anyway, pass `fallback = Window`: such objects then become plain
+/// `wxdragon::window::Window` fields (keeping their names, so switching to a dedicated type later is
+/// non-breaking) and a warning lists which objects were degraded:
+///
+/// ```ignore
+/// include_xrc!("ui.xrc", MyFrameUI, fallback = Window);
+/// ```
+///
 /// # Example
 ///
 /// Given an XRC file `dialog.xrc`:
@@ -67,6 +130,8 @@ use syn::{Error, Ident, LitStr, Token, parse_macro_input};
 ///         // Implementation that loads XRC and finds all widgets
 ///     }
 ///     
+///     pub const ID_MENU_OPEN: i32 = ...; // For each named menu item and tool
+///
 ///     pub fn xrc_id(name: &str) -> i32 {
 ///         // Helper to get XRC IDs
 ///     }
@@ -84,24 +149,89 @@ pub fn include_xrc(input: TokenStream) -> TokenStream {
 
 /// Parsed input for the include_xrc macro
 struct XrcMacroInput {
-    xrc_path: String,
+    /// A single path, or the paths from `["a.xrc", "b.xrc"]`
+    xrc_paths: Vec<LitStr>,
     struct_name: Ident,
+    /// From `root = "Name"`: the top-level object to load instead of the first Frame, Dialog or Panel
+    root: Option<LitStr>,
+    /// Set by `fallback = Window`: unsupported named objects become `Window` fields
+    fallback_to_window: bool,
+    /// From `custom = { "Class" => Type, ... }`: XRC classes created by an `XrcCustomWidget`
+    custom_classes: Vec<(String, syn::Type)>,
 }
 
 impl syn::parse::Parse for XrcMacroInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        // Parse "path/to/file.xrc"
-        let xrc_path: LitStr = input.parse()?;
+        // Parse "path/to/file.xrc" or ["a.xrc", "b.xrc"]
+        let xrc_paths = if input.peek(syn::token::Bracket) {
+            let content;
+            let brackets = syn::bracketed!(content in input);
+            let paths: Vec<LitStr> = content
+                .parse_terminated(|p| p.parse::<LitStr>(), Token![,])?
+                .into_iter()
+                .collect();
+            if paths.is_empty() {
+                return Err(Error::new(brackets.span.join(), "expected at least one XRC file"));
+            }
+            paths
+        } else {
+            vec![input.parse::<LitStr>()?]
+        };
         input.parse::<Token![,]>()?;
 
         // Parse StructName
         let struct_name: Ident = input.parse()?;
 
-        // No more root parameter - we'll auto-detect it
+        // Optional ", root = "Name"", ", fallback = Window" and ", custom = { ... }"
+        let mut root = None;
+        let mut fallback_to_window = false;
+        let mut custom_classes = Vec::new();
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if key == "root" {
+                root = Some(input.parse::<LitStr>()?);
+            } else if key == "fallback" {
+                let value: Ident = input.parse()?;
+                if value != "Window" {
+                    return Err(Error::new(value.span(), "only `fallback = Window` is supported"));
+                }
+                fallback_to_window = true;
+            } else if key == "custom" {
+                let content;
+                syn::braced!(content in input);
+                while !content.is_empty() {
+                    // The class may be written as a string or, if it is a valid identifier, bare
+                    let class = if content.peek(LitStr) {
+                        content.parse::<LitStr>()?.value()
+                    } else {
+                        content.parse::<Ident>()?.to_string()
+                    };
+                    content.parse::<Token![=>]>()?;
+                    let widget_type: syn::Type = content.parse()?;
+                    custom_classes.push((class, widget_type));
+                    if !content.is_empty() {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+            } else {
+                return Err(Error::new(
+                    key.span(),
+                    "expected `root = \"Name\"`, `fallback = Window` or `custom = { ... }`",
+                ));
+            }
+        }
 
         Ok(XrcMacroInput {
-            xrc_path: xrc_path.value(),
+            xrc_paths,
             struct_name,
+            root,
+            fallback_to_window,
+            custom_classes,
         })
     }
 }
@@ -111,9 +241,20 @@ impl syn::parse::Parse for XrcMacroInput {
 struct XrcObject {
     name: String,
     class: String,
+    /// For `<object_ref ref="...">`: the name of the referenced object. The class and
+    /// children are filled in by `resolve_object_refs`.
+    reference: Option<String>,
     children: Vec<XrcObject>,
 }
 
+/// An XRC file read during macro expansion
+struct XrcFile {
+    path: LitStr,
+    /// Directory relative paths in the file are resolved against
+    base_dir: String,
+    objects: Vec<XrcObject>,
+}
+
 /// Mapping from XRC class names to wxDragon Rust types
 fn get_class_mapping() -> HashMap<&'static str, &'static str> {
     let mut map = HashMap::new();
@@ -140,6 +281,9 @@ fn get_class_mapping() -> HashMap<&'static str, &'static str> {
     map.insert("wxTreeListCtrl", "wxdragon::widgets::TreeListCtrl");
     map.insert("wxNotebook", "wxdragon::widgets::Notebook");
     map.insert("wxSimplebook", "wxdragon::widgets::SimpleBook");
+    map.insert("wxChoicebook", "wxdragon::widgets::Choicebook");
+    map.insert("wxListbook", "wxdragon::widgets::Listbook");
+    map.insert("wxToolbook", "wxdragon::widgets::Toolbook");
     map.insert("wxRadioButton", "wxdragon::widgets::RadioButton");
     map.insert("wxRadioBox", "wxdragon::widgets::RadioBox");
     map.insert("wxToggleButton", "wxdragon::widgets::ToggleButton");
@@ -162,6 +306,9 @@ fn get_class_mapping() -> HashMap<&'static str, &'static str> {
     map.insert("wxRearrangeList", "wxdragon::widgets::RearrangeList");
     map.insert("wxTreebook", "wxdragon::widgets::Treebook");
     map.insert("wxListCtrl", "wxdragon::widgets::ListCtrl");
+    map.insert("wxDataViewCtrl", "wxdragon::widgets::DataViewCtrl");
+    map.insert("wxDataViewListCtrl", "wxdragon::widgets::DataViewListCtrl");
+    map.insert("wxDataViewTreeCtrl", "wxdragon::widgets::DataViewTreeCtrl");
     map.insert("wxGrid", "wxdragon::widgets::Grid");
     map.insert("wxHyperlinkCtrl", "wxdragon::widgets::HyperlinkCtrl");
     map.insert("wxSearchCtrl", "wxdragon::widgets::SearchCtrl");
@@ -202,20 +349,79 @@ fn get_class_mapping() -> HashMap<&'static str, &'static str> {
     map.insert("wxMenu", "wxdragon::menus::Menu");
     map.insert("wxMenuItem", "wxdragon::menus::MenuItem");
 
-    // Fallback for unknown types - treat as Window
-    map.insert("unknown", "wxdragon::window::Window");
-
     map
 }
 
+/// Returns true for XRC objects that are not widgets and never get a field:
+/// sizers, spacers, menus (part of their MenuBar) and book pages such as
+/// `notebookpage` or `choicebookpage`, whose content window is the actual widget.
+fn is_non_widget_class(class: &str) -> bool {
+    class.contains("Sizer")
+        || class.contains("sizeritem")
+        || class.contains("spacer")
+        || class == "wxMenu"
+        || (class.ends_with("page") && !class.starts_with("wx"))
+}
+
+/// Collects the named objects whose class has no wxDragon wrapper, as "name (class)".
+fn find_unsupported_objects(objects: &[XrcObject], is_supported: impl Fn(&str) -> bool) -> Vec<String> {
+    objects
+        .iter()
+        .filter(|obj| !is_supported(&obj.class))
+        .map(|obj| format!("{} ({})", obj.name, obj.class))
+        .collect()
+}
+
 /// Generate the complete XRC struct implementation
 fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenStream> {
-    // Read and parse the XRC file for widget analysis
-    let xrc_content = read_xrc_file(&input.xrc_path)?;
-    let xrc_objects = parse_xrc_content(&xrc_content)?;
+    // Read and parse every XRC file for widget analysis
+    let mut xrc_files = Vec::new();
+    for path in &input.xrc_paths {
+        let (xrc_content, xrc_file) = read_xrc_file(&path.value())?;
+        let objects = parse_xrc_content(&xrc_content)?;
+
+        // Relative paths inside the XRC (e.g. bitmap files) are resolved against its directory
+        let base_dir = std::fs::canonicalize(&xrc_file)
+            .unwrap_or(xrc_file)
+            .parent()
+            // canonicalize() returns verbatim `\\?\` paths on Windows, which wxFileName doesn't handle
+            .map(|dir| dir.to_string_lossy().trim_start_matches(r"\\?\").to_string())
+            .unwrap_or_default();
+        xrc_files.push(XrcFile {
+            path: path.clone(),
+            base_dir,
+            objects,
+        });
+    }
+    check_duplicate_names(&xrc_files)?;
 
-    // Find root object
-    let root_object = find_root_object(&xrc_objects)?;
+    // Find root object, then expand the object_refs below it
+    let (root_file, root_object) = find_root_object(&xrc_files, input.root.as_ref())?;
+    let root_object = &resolve_object_refs(root_object, &xrc_files, 0).map_err(|msg| Error::new(root_file.path.span(), msg))?;
+    let xrc_path = root_file.path.value();
+    let xrc_path_span = root_file.path.span();
+
+    // Named top-level bitmaps and icons get accessor methods
+    let bitmap_accessors = xrc_files
+        .iter()
+        .flat_map(|file| &file.objects)
+        .filter(|obj| !obj.name.is_empty() && (obj.class == "wxBitmap" || obj.class == "wxIcon"))
+        .map(|obj| {
+            let method_name = Ident::new(&obj.name, proc_macro2::Span::call_site());
+            let name_lit = &obj.name;
+            let load_method = if obj.class == "wxIcon" {
+                quote! { load_icon }
+            } else {
+                quote! { load_bitmap }
+            };
+            let doc = format!("Loads the `{}` {} resource from the XRC.", obj.name, obj.class);
+            quote! {
+                #[doc = #doc]
+                pub fn #method_name(&self) -> Option<wxdragon::bitmap::Bitmap> {
+                    self._resource.#load_method(#name_lit)
+                }
+            }
+        });
 
     // Collect all named objects for field generation
     let mut all_objects = Vec::new();
@@ -224,13 +430,45 @@ fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenSt
     // Filter out sizers and other non-widget objects that don't support XRC
     let widget_objects: Vec<_> = all_objects
         .into_iter()
-        .filter(|obj| {
-            !obj.class.contains("Sizer") && !obj.class.contains("sizeritem") && !obj.class.contains("spacer")
-            // Skip Menu objects - they're part of MenuBar
-             && obj.class != "wxMenu"
-        })
+        .filter(|obj| !is_non_widget_class(&obj.class))
         .collect();
 
+    // Report every named object without a wrapper at once, pointing at the XRC path
+    let class_mapping = get_class_mapping();
+    let custom_types: HashMap<&str, &syn::Type> = input.custom_classes.iter().map(|(class, ty)| (class.as_str(), ty)).collect();
+    let unsupported = find_unsupported_objects(&widget_objects, |class| {
+        class_mapping.contains_key(class) || custom_types.contains_key(class)
+    });
+    if !unsupported.is_empty() && !input.fallback_to_window {
+        return Err(Error::new(
+            xrc_path_span,
+            format!(
+                "XRC file '{}' contains named objects of classes not supported by include_xrc!: {}",
+                xrc_path,
+                unsupported.join(", ")
+            ),
+        ));
+    }
+
+    // With the fallback, degraded objects are reported through a deprecation warning,
+    // the only kind of warning a proc macro can emit on stable Rust
+    let fallback_warning = if unsupported.is_empty() {
+        quote! {}
+    } else {
+        let note = format!(
+            "include_xrc!: these objects of unsupported classes are exposed as Window: {}",
+            unsupported.join(", ")
+        );
+        quote::quote_spanned! {xrc_path_span=>
+            const _: () = {
+                #[deprecated(note = #note)]
+                #[allow(non_camel_case_types)]
+                struct include_xrc_fallback;
+                let _ = include_xrc_fallback;
+            };
+        }
+    };
+
     // Separate tools and menu items from other widgets for special handling
     let (tool_objects, remaining_objects): (Vec<_>, Vec<_>) = widget_objects.iter().partition(|obj| obj.class == "tool");
 
@@ -240,16 +478,60 @@ fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenSt
     let (menubar_objects, non_special_objects): (Vec<_>, Vec<_>) =
         remaining_objects2.into_iter().partition(|obj| obj.class == "wxMenuBar");
 
+    // Menu items and tools get fixed ids, so event handlers can match on constants
+    let id_names: Vec<&str> = menu_item_objects
+        .iter()
+        .chain(tool_objects.iter())
+        .map(|obj| obj.name.as_str())
+        .filter(|name| !name.starts_with("wxID_"))
+        .collect();
+    let fixed_ids = assign_fixed_ids(&id_names);
+    let id_consts = fixed_ids.iter().map(|(name, id)| {
+        let const_name = quote::format_ident!("ID_{}", name.to_uppercase());
+        let doc = format!("Id of the `{name}` XRC object");
+        quote! {
+            #[doc = #doc]
+            pub const #const_name: i32 = #id;
+        }
+    });
+    let id_table = fixed_ids.iter().map(|(name, id)| quote! { (#name, #id) });
+
     // Generate the struct and implementation
     let struct_name = &input.struct_name;
-    let class_mapping = get_class_mapping();
+
+    // Custom classes use their XrcCustomWidget type, unknown ones (with the fallback) Window
+    let widget_type_for = |class: &str| -> syn::Type {
+        match custom_types.get(class) {
+            Some(ty) => (*ty).clone(),
+            None => syn::parse_str(class_mapping.get(class).copied().unwrap_or("wxdragon::window::Window")).unwrap(),
+        }
+    };
+
+    // Each custom class gets a factory that also records the widgets it creates, so the
+    // fields can hold the created values rather than wrappers rebuilt from a pointer
+    let custom_store = |class: &str| {
+        let index = input.custom_classes.iter().position(|(c, _)| c == class).unwrap();
+        quote::format_ident!("xrc_custom_widgets_{}", index)
+    };
+    let custom_factories = input.custom_classes.iter().map(|(class, ty)| {
+        let store = custom_store(class);
+        quote! {
+            let #store: std::rc::Rc<std::cell::RefCell<Vec<(String, #ty)>>> = Default::default();
+            {
+                let created = #store.clone();
+                resource.register_subclass_factory(#class, move |parent: &wxdragon::window::Window, name: &str| {
+                    let widget = <#ty as wxdragon::xrc::XrcCustomWidget>::create_from_xrc(parent, name);
+                    created.borrow_mut().push((name.to_string(), widget.clone()));
+                    widget
+                });
+            }
+        }
+    });
 
     // Generate struct fields for all named objects
     let struct_fields = widget_objects.iter().map(|obj| {
         let field_name = Ident::new(&obj.name, proc_macro2::Span::call_site());
-        let type_str = class_mapping.get(obj.class.as_str()).unwrap_or(&"wxdragon::window::Window");
-
-        let field_type: syn::Type = syn::parse_str(type_str).unwrap();
+        let field_type = widget_type_for(&obj.class);
         quote! { pub #field_name: #field_type }
     });
 
@@ -262,7 +544,12 @@ fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenSt
     };
 
     let root_field_name = Ident::new(&root_object.name, proc_macro2::Span::call_site());
-    let xrc_path = &input.xrc_path;
+    let root_base_dir = &root_file.base_dir;
+    let xrc_file_entries = xrc_files.iter().map(|file| {
+        let path = &file.path;
+        let base_dir = &file.base_dir;
+        quote! { (include_str!(#path), #base_dir) }
+    });
 
     // Generate initialization for regular widgets first
     let non_special_initializers = non_special_objects.iter().map(|obj| {
@@ -273,12 +560,22 @@ fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenSt
             // Root object is loaded directly
             quote! {
                 let #field_name = resource.#root_load_method(parent, #obj_name_lit)
-                    .unwrap_or_else(|| panic!("Failed to load XRC root object: {}", #obj_name_lit));
+                    .unwrap_or_else(|err| panic!("Failed to load XRC root object {}: {}", #obj_name_lit, err));
+            }
+        } else if custom_types.contains_key(obj.class.as_str()) {
+            // Custom widgets were recorded by their factory while the root was loaded
+            let store = custom_store(&obj.class);
+            quote! {
+                let #field_name = #store
+                    .borrow()
+                    .iter()
+                    .find(|(name, _)| name == #obj_name_lit)
+                    .map(|(_, widget)| widget.clone())
+                    .unwrap_or_else(|| panic!("XRC custom object was not created: {}", #obj_name_lit));
             }
         } else {
             // Regular widgets are found within the root - explicitly specify the widget type
-            let type_str = class_mapping.get(obj.class.as_str()).unwrap_or(&"wxdragon::window::Window");
-            let widget_type: syn::Type = syn::parse_str(type_str).unwrap();
+            let widget_type = widget_type_for(&obj.class);
 
             quote! {
                 let #field_name = #root_field_name
@@ -358,11 +655,40 @@ fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenSt
         }
 
         impl #struct_name {
-            /// The embedded XRC data from the file
+            /// The embedded XRC data from the file defining the root object
             pub const XRC_DATA: &'static str = include_str!(#xrc_path);
 
+            /// Directory of the XRC file at build time; relative paths in the XRC are resolved against it
+            pub const XRC_BASE_DIR: &'static str = #root_base_dir;
+
+            /// Every XRC file passed to the macro with its directory at build time, in load order
+            pub const XRC_FILES: &'static [(&'static str, &'static str)] = &[#(#xrc_file_entries),*];
+
+            /// Fixed ids of the named menu items and tools, also available as `ID_*` constants
+            pub const XRC_IDS: &'static [(&'static str, i32)] = &[#(#id_table),*];
+
+            #(#id_consts)*
+
             /// Create a new instance by loading the embedded XRC
             pub fn new(parent: Option<&dyn wxdragon::window::WxWidget>, auto_destroy_root: bool) -> Self {
+                Self::load_xrc_files(parent, auto_destroy_root, None)
+            }
+
+            /// Create a new instance, resolving relative paths in the XRC (such as bitmap
+            /// files) against `base_dir`, e.g. the resource directory of an installed app
+            pub fn new_with_base_path(
+                parent: Option<&dyn wxdragon::window::WxWidget>,
+                auto_destroy_root: bool,
+                base_dir: impl AsRef<std::path::Path>,
+            ) -> Self {
+                Self::load_xrc_files(parent, auto_destroy_root, Some(base_dir.as_ref()))
+            }
+
+            fn load_xrc_files(
+                parent: Option<&dyn wxdragon::window::WxWidget>,
+                auto_destroy_root: bool,
+                base_dir: Option<&std::path::Path>,
+            ) -> Self {
                 let resource = wxdragon::xrc::XmlResource::get();
 
                 // Initialize platform-aware StaticBitmap handler BEFORE default handlers
@@ -371,8 +697,21 @@ fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenSt
 
                 resource.init_all_handlers();
 
-                resource.load_from_string(Self::XRC_DATA)
-                    .unwrap_or_else(|err| panic!("Failed to load XRC data: {}", err));
+                // The ids must be assigned before objects using these names are created
+                for (name, id) in Self::XRC_IDS {
+                    wxdragon::xrc::XmlResource::assign_xrc_id(name, *id)
+                        .unwrap_or_else(|err| panic!("Failed to assign XRC id: {}", err));
+                }
+
+                // Load all files before creating the root, so object_refs can refer to any of them
+                for (data, build_dir) in Self::XRC_FILES {
+                    let dir = base_dir.unwrap_or_else(|| std::path::Path::new(build_dir));
+                    resource.load_from_string_with_base_path(data, dir)
+                        .unwrap_or_else(|err| panic!("Failed to load XRC data: {}", err));
+                }
+
+                // Register factories for custom classes before any object is created
+                #(#custom_factories)*
 
                 #(#non_special_initializers)*
 
@@ -396,16 +735,47 @@ fn generate_xrc_struct(input: XrcMacroInput) -> syn::Result<proc_macro2::TokenSt
             pub fn xrc_id(name: &str) -> i32 {
                 wxdragon::xrc::XmlResource::get_xrc_id(name)
             }
+
+            #(#bitmap_accessors)*
         }
 
         #drop_impl
+
+        #fallback_warning
     };
 
     Ok(generated)
 }
 
+/// First id given to menu items and tools. Ids must fit in 16 bits on Windows; this
+/// range stays clear of the stock ids and the ids wxWidgets allocates itself.
+const FIXED_ID_FIRST: i32 = 20000;
+const FIXED_ID_COUNT: u32 = 12000;
+
+/// Derives an id from each name, so the same name gets the same id in every XRC file.
+/// Names whose ids collide within the file are moved to the next free id.
+fn assign_fixed_ids(names: &[&str]) -> Vec<(String, i32)> {
+    let mut used = std::collections::HashSet::new();
+    let mut ids: Vec<(String, i32)> = Vec::new();
+    for name in names {
+        if ids.iter().any(|(n, _)| n == name) {
+            continue;
+        }
+        // FNV-1a, stable across compiler versions unlike std's hasher
+        let hash = name
+            .bytes()
+            .fold(0x811c9dc5u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193));
+        let mut offset = hash % FIXED_ID_COUNT;
+        while !used.insert(offset) {
+            offset = (offset + 1) % FIXED_ID_COUNT;
+        }
+        ids.push((name.to_string(), FIXED_ID_FIRST + offset as i32));
+    }
+    ids
+}
+
 /// Read XRC file content from the filesystem during macro expansion
-fn read_xrc_file(path: &str) -> syn::Result<String> {
+fn read_xrc_file(path: &str) -> syn::Result<(String, std::path::PathBuf)> {
     // Try to resolve the file the same way include_str! would
     // include_str! looks for files relative to the current source file
 
@@ -445,7 +815,7 @@ fn read_xrc_file(path: &str) -> syn::Result<String> {
 
     for full_path in &possible_paths {
         if let Ok(content) = std::fs::read_to_string(full_path) {
-            return Ok(content);
+            return Ok((content, full_path.clone()));
         }
     }
 
@@ -477,27 +847,19 @@ fn parse_xrc_content(content: &str) -> syn::Result<Vec<XrcObject>> {
 
     loop {
         match reader.read_event() {
-            Ok(Event::Start(ref e)) if e.name().as_ref() == b"object" => {
-                let mut obj = XrcObject {
-                    name: String::new(),
-                    class: String::new(),
-                    children: Vec::new(),
-                };
-
-                // Parse attributes
-                for attr in e.attributes() {
-                    let attr = attr.map_err(|e| Error::new(proc_macro2::Span::call_site(), format!("XML parsing error: {e}")))?;
-
-                    match attr.key.as_ref() {
-                        b"name" => obj.name = String::from_utf8_lossy(&attr.value).into_owned(),
-                        b"class" => obj.class = String::from_utf8_lossy(&attr.value).into_owned(),
-                        _ => {}
-                    }
+            Ok(Event::Start(ref e)) if is_object_element(e.name().as_ref()) => {
+                stack.push(parse_object_attributes(e)?);
+            }
+            // Self-closing <object .../> has no children and no End event
+            Ok(Event::Empty(ref e)) if is_object_element(e.name().as_ref()) => {
+                let obj = parse_object_attributes(e)?;
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(obj);
+                } else if !obj.name.is_empty() {
+                    objects.push(obj);
                 }
-
-                stack.push(obj);
             }
-            Ok(Event::End(ref e)) if e.name().as_ref() == b"object" => {
+            Ok(Event::End(ref e)) if is_object_element(e.name().as_ref()) => {
                 if let Some(obj) = stack.pop() {
                     if let Some(parent) = stack.last_mut() {
                         // Always add to parent, even if object doesn't have a name
@@ -522,20 +884,152 @@ fn parse_xrc_content(content: &str) -> syn::Result<Vec<XrcObject>> {
     Ok(objects)
 }
 
-/// Find the root object to load (automatically detect Frame, Dialog, or Panel)
-fn find_root_object(objects: &[XrcObject]) -> syn::Result<&XrcObject> {
-    // Look for the first Frame, Dialog, or Panel object
-    objects
-        .iter()
-        .find(|obj| {
-            obj.class == "wxFrame" || obj.class == "wxDialog" || obj.class == "wxPanel"
-        })
-        .ok_or_else(|| {
+fn is_object_element(name: &[u8]) -> bool {
+    name == b"object" || name == b"object_ref"
+}
+
+/// Create an XrcObject from the name and class attributes of an <object> element, or
+/// the name and ref attributes of an <object_ref> element
+fn parse_object_attributes(e: &quick_xml::events::BytesStart) -> syn::Result<XrcObject> {
+    let mut obj = XrcObject {
+        name: String::new(),
+        class: String::new(),
+        reference: None,
+        children: Vec::new(),
+    };
+    let is_ref = e.name().as_ref() == b"object_ref";
+
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| Error::new(proc_macro2::Span::call_site(), format!("XML parsing error: {e}")))?;
+
+        match attr.key.as_ref() {
+            b"name" => obj.name = String::from_utf8_lossy(&attr.value).into_owned(),
+            b"class" => obj.class = String::from_utf8_lossy(&attr.value).into_owned(),
+            b"ref" if is_ref => obj.reference = Some(String::from_utf8_lossy(&attr.value).into_owned()),
+            _ => {}
+        }
+    }
+
+    Ok(obj)
+}
+
+fn is_root_class(class: &str) -> bool {
+    class == "wxFrame" || class == "wxDialog" || class == "wxPanel"
+}
+
+/// Find the root object to load: the top-level object named by `root = "..."`, or
+/// the first Frame, Dialog, or Panel
+fn find_root_object<'a>(files: &'a [XrcFile], root: Option<&LitStr>) -> syn::Result<(&'a XrcFile, &'a XrcObject)> {
+    let mut top_level = files.iter().flat_map(|file| file.objects.iter().map(move |obj| (file, obj)));
+
+    let Some(root) = root else {
+        return top_level.find(|(_, obj)| is_root_class(&obj.class)).ok_or_else(|| {
             Error::new(
                 proc_macro2::Span::call_site(),
                 "No root Frame, Dialog, or Panel object found in XRC. Make sure your XRC file contains a top-level wxFrame, wxDialog, or wxPanel object.",
             )
-        })
+        });
+    };
+
+    let (file, obj) = top_level
+        .find(|(_, obj)| obj.name == root.value())
+        .ok_or_else(|| Error::new(root.span(), format!("No top-level XRC object named '{}'", root.value())))?;
+    if !is_root_class(&obj.class) {
+        return Err(Error::new(
+            root.span(),
+            format!(
+                "XRC object '{}' is a {}, not a wxFrame, wxDialog, or wxPanel",
+                obj.name, obj.class
+            ),
+        ));
+    }
+    Ok((file, obj))
+}
+
+/// Reports top-level names defined in more than one file. wxWidgets would silently use
+/// whichever comes first, both for loading and for object_ref.
+fn check_duplicate_names(files: &[XrcFile]) -> syn::Result<()> {
+    let mut defined_in: Vec<(&str, Vec<String>)> = Vec::new();
+    for file in files {
+        for obj in file.objects.iter().filter(|obj| !obj.name.is_empty()) {
+            match defined_in.iter_mut().find(|(name, _)| *name == obj.name) {
+                Some((_, paths)) => paths.push(file.path.value()),
+                None => defined_in.push((&obj.name, vec![file.path.value()])),
+            }
+        }
+    }
+
+    let duplicates: Vec<String> = defined_in
+        .iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .map(|(name, paths)| format!("'{}' ({})", name, paths.join(", ")))
+        .collect();
+    if duplicates.is_empty() {
+        return Ok(());
+    }
+    Err(Error::new(
+        files[0].path.span(),
+        format!("XRC objects defined in more than one file: {}", duplicates.join("; ")),
+    ))
+}
+
+/// Find a named object for an object_ref: top-level objects first, like wxWidgets
+fn find_named_object<'a>(files: &'a [XrcFile], name: &str) -> Option<&'a XrcObject> {
+    fn find_in<'a>(obj: &'a XrcObject, name: &str) -> Option<&'a XrcObject> {
+        if obj.name == name && obj.reference.is_none() {
+            return Some(obj);
+        }
+        obj.children.iter().find_map(|child| find_in(child, name))
+    }
+
+    let top_level = || files.iter().flat_map(|file| &file.objects);
+    top_level()
+        .find(|obj| obj.name == name && obj.reference.is_none())
+        .or_else(|| top_level().find_map(|obj| find_in(obj, name)))
+}
+
+/// Replaces each object_ref below `obj` with the object it refers to. As in wxWidgets,
+/// the reference keeps its own name if it has one, and its children override the
+/// referenced children of the same name.
+fn resolve_object_refs(obj: &XrcObject, files: &[XrcFile], depth: usize) -> Result<XrcObject, String> {
+    let mut resolved = XrcObject {
+        name: obj.name.clone(),
+        class: obj.class.clone(),
+        reference: None,
+        children: Vec::new(),
+    };
+
+    if let Some(reference) = &obj.reference {
+        if depth > 32 {
+            return Err(format!("object_ref '{reference}' refers back to itself"));
+        }
+        let target = find_named_object(files, reference).ok_or_else(|| {
+            let paths: Vec<String> = files.iter().map(|file| file.path.value()).collect();
+            format!(
+                "object_ref '{reference}' does not match a named object in {}",
+                paths.join(", ")
+            )
+        })?;
+        let target = resolve_object_refs(target, files, depth + 1)?;
+        resolved.class = target.class;
+        if resolved.name.is_empty() {
+            resolved.name = target.name;
+        }
+        resolved.children = target.children;
+    }
+
+    for child in &obj.children {
+        let child = resolve_object_refs(child, files, depth)?;
+        match resolved
+            .children
+            .iter_mut()
+            .find(|c| !child.name.is_empty() && c.name == child.name)
+        {
+            Some(existing) => *existing = child,
+            None => resolved.children.push(child),
+        }
+    }
+    Ok(resolved)
 }
 
 /// Recursively collect all named objects from the hierarchy
@@ -565,3 +1059,179 @@ fn find_toolbar_parent_for_tool<'a>(obj: &'a XrcObject, tool_name: &str) -> Opti
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xrc_file(path: &str, xrc: &str) -> XrcFile {
+        XrcFile {
+            path: LitStr::new(path, proc_macro2::Span::call_site()),
+            base_dir: String::new(),
+            objects: parse_xrc_content(xrc).unwrap(),
+        }
+    }
+
+    fn root_name(files: &[XrcFile], root: Option<&str>) -> syn::Result<String> {
+        let root = root.map(|root| LitStr::new(root, proc_macro2::Span::call_site()));
+        find_root_object(files, root.as_ref()).map(|(_, obj)| obj.name.clone())
+    }
+
+    fn unsupported_in(xrc: &str) -> Vec<String> {
+        let files = [xrc_file("test.xrc", xrc)];
+        let mut named = Vec::new();
+        collect_named_objects(find_root_object(&files, None).unwrap().1, &mut named);
+        named.retain(|obj| !is_non_widget_class(&obj.class));
+        let class_mapping = get_class_mapping();
+        find_unsupported_objects(&named, |class| class_mapping.contains_key(class))
+    }
+
+    #[test]
+    fn book_controls_are_supported() {
+        assert!(unsupported_in(include_str!("../../wxdragon/tests/xrc/book_controls.xrc")).is_empty());
+        assert!(unsupported_in(include_str!("../../wxdragon/tests/xrc/aui_notebook.xrc")).is_empty());
+    }
+
+    #[test]
+    fn dataview_controls_are_supported() {
+        assert!(unsupported_in(include_str!("../../wxdragon/tests/xrc/dataview_controls.xrc")).is_empty());
+    }
+
+    #[test]
+    fn unsupported_classes_are_listed_by_name() {
+        let xrc = r#"<resource>
+              <object class="wxFrame" name="frame">
+                <object class="wxPanel" name="panel">
+                  <object class="wxHtmlWindow" name="help_view"/>
+                  <object class="wxButton" name="ok_button"/>
+                </object>
+              </object>
+            </resource>"#;
+        assert_eq!(unsupported_in(xrc), vec!["help_view (wxHtmlWindow)".to_string()]);
+    }
+
+    #[test]
+    fn top_level_bitmaps_are_not_widget_fields() {
+        let objects = parse_xrc_content(include_str!("../../wxdragon/tests/xrc/bitmaps.xrc")).unwrap();
+        let classes: Vec<_> = objects.iter().map(|obj| (obj.name.as_str(), obj.class.as_str())).collect();
+        assert_eq!(
+            classes,
+            vec![("logo", "wxBitmap"), ("app_icon", "wxIcon"), ("bitmap_frame", "wxFrame")]
+        );
+        let files = [xrc_file("bitmaps.xrc", include_str!("../../wxdragon/tests/xrc/bitmaps.xrc"))];
+        assert_eq!(root_name(&files, None).unwrap(), "bitmap_frame");
+        assert!(unsupported_in(include_str!("../../wxdragon/tests/xrc/bitmaps.xrc")).is_empty());
+    }
+
+    #[test]
+    fn custom_classes_are_parsed() {
+        let input: XrcMacroInput =
+            syn::parse_str(r#""ui.xrc", MyUI, custom = { "MyCanvas" => crate::MyCanvas, Plot => Plot }, fallback = Window"#)
+                .unwrap();
+        let classes: Vec<_> = input.custom_classes.iter().map(|(class, _)| class.as_str()).collect();
+        assert_eq!(classes, vec!["MyCanvas", "Plot"]);
+        assert!(input.fallback_to_window);
+        assert!(syn::parse_str::<XrcMacroInput>(r#""ui.xrc", MyUI, custom = { "MyCanvas" }"#).is_err());
+    }
+
+    #[test]
+    fn fallback_option_is_parsed() {
+        let input: XrcMacroInput = syn::parse_str(r#""ui.xrc", MyUI"#).unwrap();
+        assert!(!input.fallback_to_window);
+        let input: XrcMacroInput = syn::parse_str(r#""ui.xrc", MyUI, fallback = Window"#).unwrap();
+        assert!(input.fallback_to_window);
+        assert!(syn::parse_str::<XrcMacroInput>(r#""ui.xrc", MyUI, fallback = Panel"#).is_err());
+    }
+
+    #[test]
+    fn fixed_ids_depend_only_on_the_name() {
+        let ids = assign_fixed_ids(&["menu_open", "menu_quit"]);
+        let again = assign_fixed_ids(&["tool_save", "menu_open"]);
+        assert_eq!(ids[0], again[1]);
+        assert!(ids.iter().all(|(_, id)| (FIXED_ID_FIRST..32000).contains(id)));
+    }
+
+    #[test]
+    fn colliding_fixed_ids_are_moved() {
+        // "costarring" and "liquid" collide under 32-bit FNV-1a
+        let ids = assign_fixed_ids(&["costarring", "liquid", "costarring"]);
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0].1, ids[1].1);
+    }
+
+    #[test]
+    fn file_lists_and_root_are_parsed() {
+        let input: XrcMacroInput = syn::parse_str(r#"["dialogs.xrc", "common.xrc"], MyUI, root = "SettingsDialog""#).unwrap();
+        let paths: Vec<String> = input.xrc_paths.iter().map(|path| path.value()).collect();
+        assert_eq!(paths, vec!["dialogs.xrc", "common.xrc"]);
+        assert_eq!(input.root.unwrap().value(), "SettingsDialog");
+        assert!(syn::parse_str::<XrcMacroInput>(r#"[], MyUI"#).is_err());
+    }
+
+    fn settings_files() -> [XrcFile; 2] {
+        [
+            xrc_file(
+                "settings_dialog.xrc",
+                include_str!("../../wxdragon/tests/xrc/settings_dialog.xrc"),
+            ),
+            xrc_file("common.xrc", include_str!("../../wxdragon/tests/xrc/common.xrc")),
+        ]
+    }
+
+    #[test]
+    fn root_is_selected_by_name_across_files() {
+        let files = settings_files();
+        assert_eq!(root_name(&files, None).unwrap(), "SettingsDialog");
+        assert_eq!(root_name(&files, Some("common_buttons")).unwrap(), "common_buttons");
+        assert!(root_name(&files, Some("missing")).is_err());
+        assert!(root_name(&files, Some("shared_icon")).is_err());
+    }
+
+    #[test]
+    fn object_refs_resolve_across_files() {
+        let files = settings_files();
+        let (_, root) = find_root_object(&files, Some(&LitStr::new("SettingsDialog", proc_macro2::Span::call_site()))).unwrap();
+        let root = resolve_object_refs(root, &files, 0).unwrap();
+        let mut named = Vec::new();
+        collect_named_objects(&root, &mut named);
+        let named: Vec<_> = named.iter().map(|obj| (obj.name.as_str(), obj.class.as_str())).collect();
+        assert_eq!(
+            named,
+            vec![
+                ("SettingsDialog", "wxDialog"),
+                ("autosave_check", "wxCheckBox"),
+                ("settings_buttons", "wxPanel"),
+                ("ok_button", "wxButton"),
+                ("cancel_button", "wxButton"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_and_cyclic_object_refs_are_errors() {
+        let files = [xrc_file(
+            "refs.xrc",
+            r#"<resource>
+              <object class="wxPanel" name="panel"><object_ref ref="nowhere"/></object>
+              <object class="wxPanel" name="loop"><object_ref ref="loop"/></object>
+            </resource>"#,
+        )];
+        assert!(
+            resolve_object_refs(&files[0].objects[0], &files, 0)
+                .unwrap_err()
+                .contains("nowhere")
+        );
+        assert!(resolve_object_refs(&files[0].objects[1], &files, 0).is_err());
+    }
+
+    #[test]
+    fn duplicate_names_across_files_are_errors() {
+        let files = [
+            xrc_file("a.xrc", r#"<resource><object class="wxPanel" name="shared"/></resource>"#),
+            xrc_file("b.xrc", r#"<resource><object class="wxPanel" name="shared"/></resource>"#),
+        ];
+        let err = check_duplicate_names(&files).unwrap_err().to_string();
+        assert!(err.contains("'shared' (a.xrc, b.xrc)"), "{err}");
+        assert!(check_duplicate_names(&settings_files()).is_ok());
+    }
+}
@@ -18,6 +18,12 @@ WXD_EXPORTED void
 wxd_App_ExitMainLoop(wxd_App_t* app);
 WXD_EXPORTED void
 wxd_WakeUpIdle();
+
+// Event loop helpers
+WXD_EXPORTED bool
+wxd_EventLoop_IsRunning();
+WXD_EXPORTED bool
+wxd_EventLoop_Yield(bool onlyIfNeeded);
 WXD_EXPORTED bool
 wxd_App_GetExitOnFrameDelete(wxd_App_t* app);
 WXD_EXPORTED void
@@ -28,9 +28,15 @@ WXD_EXPORTED void
 wxd_Clipboard_UsePrimarySelection(wxd_Clipboard_t* clipboard, bool use_primary);
 
 // --- Convenience Functions ---
+// These open the clipboard unless it is already open, and fail outside the main thread
+// like wxd_Clipboard_Open.
 WXD_EXPORTED bool
 wxd_Clipboard_SetText(wxd_Clipboard_t* clipboard, const char* text);
 
+// True if the clipboard holds text in a format wxd_Clipboard_GetText can read
+WXD_EXPORTED bool
+wxd_Clipboard_HasText(wxd_Clipboard_t* clipboard);
+
 /**
  * Gets text from the clipboard.
  * Returns the length of the text retrieved (not including the null terminator), or -1 on error.
@@ -40,4 +46,32 @@ wxd_Clipboard_SetText(wxd_Clipboard_t* clipboard, const char* text);
 WXD_EXPORTED int
 wxd_Clipboard_GetText(const wxd_Clipboard_t* clipboard, char* buffer, size_t buffer_len);
 
+// format is one of the DataFormat ids (1 text, 2 bitmap, 4 files) or a wxDataFormatId
+WXD_EXPORTED bool
+wxd_Clipboard_IsFormatAvailable(wxd_Clipboard_t* clipboard, int format);
+
+WXD_EXPORTED bool
+wxd_Clipboard_SetBitmap(wxd_Clipboard_t* clipboard, const wxd_Bitmap_t* bitmap);
+
+// Returns a new bitmap owned by the caller, or NULL if the clipboard holds none
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Clipboard_GetBitmap(wxd_Clipboard_t* clipboard);
+
+// Puts a list of file paths on the clipboard, as copied in a file manager
+WXD_EXPORTED bool
+wxd_Clipboard_SetFiles(wxd_Clipboard_t* clipboard, const wxd_ArrayString_t* files);
+
+// Replaces the contents of files with the paths on the clipboard; false if it holds none
+WXD_EXPORTED bool
+wxd_Clipboard_GetFiles(wxd_Clipboard_t* clipboard, wxd_ArrayString_t* files);
+
+// True if the clipboard holds data of the custom format named format
+WXD_EXPORTED bool
+wxd_Clipboard_IsCustomFormatAvailable(wxd_Clipboard_t* clipboard, const char* format);
+
+// Returns a new data object with the clipboard's data of the custom format named format,
+// owned by the caller (destroy with wxd_DataObject_Destroy), or NULL if it holds none
+WXD_EXPORTED wxd_CustomDataObject_t*
+wxd_Clipboard_GetCustomData(wxd_Clipboard_t* clipboard, const char* format);
+
 #endif // WXD_CLIPBOARD_H
\ No newline at end of file
@@ -22,9 +22,14 @@ WXD_EXPORTED wxd_Cursor_t*
 wxd_Cursor_CreateFromData(const unsigned char* bits, int width, int height, int hotspot_x,
                           int hotspot_y, const unsigned char* mask_bits);
 
-/// Creates a cursor from an image
+/// Creates a cursor from an image, keeping its alpha channel where the platform supports it.
+/// Images larger than the platform allows are scaled down, moving the hotspot with them.
 WXD_EXPORTED wxd_Cursor_t*
-wxd_Cursor_CreateFromImage(const wxd_Bitmap_t* image);
+wxd_Cursor_CreateFromImage(const wxd_Image_t* image, int hotspot_x, int hotspot_y);
+
+/// Creates a cursor from a bitmap, as wxd_Cursor_CreateFromImage
+WXD_EXPORTED wxd_Cursor_t*
+wxd_Cursor_CreateFromBitmap(const wxd_Bitmap_t* bitmap, int hotspot_x, int hotspot_y);
 
 /// Creates a copy of a cursor
 WXD_EXPORTED wxd_Cursor_t*
@@ -47,4 +47,25 @@ wxd_BitmapDataObject_Create(const wxd_Bitmap_t* bitmap);
 WXD_EXPORTED wxd_Bitmap_t*
 wxd_BitmapDataObject_GetBitmap(wxd_BitmapDataObject_t* data_object);
 
+// --- DataObjectComposite Functions ---
+// Offers several formats at once; destroy with wxd_DataObject_Destroy
+WXD_EXPORTED wxd_DataObjectComposite_t*
+wxd_DataObjectComposite_Create();
+
+// Takes ownership of data_object
+WXD_EXPORTED void
+wxd_DataObjectComposite_Add(wxd_DataObjectComposite_t* composite, wxd_DataObject_t* data_object,
+                            bool preferred);
+
+// --- CustomDataObject Functions ---
+// Raw bytes of an application defined format, e.g. "application/x-myapp-node", which wx
+// registers with the platform by name. Destroy with wxd_DataObject_Destroy
+WXD_EXPORTED wxd_CustomDataObject_t*
+wxd_CustomDataObject_Create(const char* format, const unsigned char* data, size_t len);
+
+// Returns the size of the data; copies up to buffer_len bytes into buffer if it is not null
+WXD_EXPORTED size_t
+wxd_CustomDataObject_GetData(const wxd_CustomDataObject_t* data_object, unsigned char* buffer,
+                             size_t buffer_len);
+
 #endif // WXD_DATAOBJECT_H
\ No newline at end of file
@@ -22,6 +22,16 @@ typedef enum {
     WXD_IPC_PRIVATE = 20        // Private/binary data
 } wxd_IPCFormat;
 
+// --- IPC Errors ---
+typedef enum {
+    WXD_IPC_ERROR_NONE = 0,
+    WXD_IPC_ERROR_FAILED = 1,             // Any other failure, see wxd_IPC_GetLastError
+    WXD_IPC_ERROR_ADDRESS_IN_USE = 2,     // Another server already listens on the service
+    WXD_IPC_ERROR_CONNECTION_REFUSED = 3, // No server listens on the service
+    WXD_IPC_ERROR_TIMEOUT = 4,            // The peer did not answer in time
+    WXD_IPC_ERROR_DISCONNECTED = 5        // The connection is closed
+} wxd_IPCError;
+
 // --- Connection Callbacks (Server-side: called when client sends data) ---
 
 // Called when client executes a command via Execute()
@@ -87,8 +97,8 @@ typedef bool (*wxd_IPC_OnAdvise_Callback)(
 
 // --- Connection Callbacks (Both sides) ---
 
-// Called when connection is terminated
-// Return true to allow default cleanup (delete connection)
+// Called when connection is terminated by the other side
+// Return true to allow default cleanup (delete connection, freeing its user data)
 typedef bool (*wxd_IPC_OnDisconnect_Callback)(void* user_data);
 
 // Cleanup callback to free user data
@@ -144,7 +154,7 @@ wxd_IPCConnection_ExecuteString(
 
 // Request data from the server
 // Returns pointer to data, sets out_size. Caller must copy data before next call.
-// Returns NULL on failure.
+// Returns NULL on failure, when the peer is gone or when it does not answer in time.
 WXD_EXPORTED const void*
 wxd_IPCConnection_Request(
     wxd_IPCConnection_t* conn,
@@ -173,7 +183,8 @@ wxd_IPCConnection_StopAdvise(wxd_IPCConnection_t* conn, const char* item);
 
 // --- Server-side Connection Methods ---
 
-// Send advised data to the client (server calls this)
+// Send advised data to the client (server calls this). Clients get it for the items
+// they started an advise loop for; with DDE only for those.
 WXD_EXPORTED bool
 wxd_IPCConnection_Advise(
     wxd_IPCConnection_t* conn,
@@ -185,11 +196,19 @@ wxd_IPCConnection_Advise(
 
 // --- Both-side Connection Methods ---
 
-// Disconnect the connection
+// Disconnect the connection. The peer and this side both get OnDisconnect; unless this
+// side's callback returns false the connection is deleted, so it must not be called from
+// one of the connection's own callbacks.
 WXD_EXPORTED bool
 wxd_IPCConnection_Disconnect(wxd_IPCConnection_t* conn);
 
-// Get the topic of the connection
+// Set how many seconds blocking operations (Request, Poke, Execute) wait for the peer.
+// Returns false if the transport does not support it (DDE) or the connection is not made.
+WXD_EXPORTED bool
+wxd_IPCConnection_SetTimeout(wxd_IPCConnection_t* conn, long seconds);
+
+// Get the topic the connection was made for. Returns the needed length (excluding the
+// null terminator); pass a NULL buffer to query it.
 WXD_EXPORTED size_t
 wxd_IPCConnection_GetTopic(wxd_IPCConnection_t* conn, char* buffer, size_t buffer_size);
 
@@ -240,10 +259,25 @@ wxd_IPCClient_MakeConnection(
     wxd_IPC_FreeUserData_Callback free_user_data
 );
 
+// Limit how long MakeConnection waits for a TCP server to accept the connection.
+// 0 (the default) waits as long as the platform does.
+WXD_EXPORTED void
+wxd_IPCClient_SetConnectTimeout(wxd_IPCClient_t* client, long milliseconds);
+
 // Destroy the client
 WXD_EXPORTED void
 wxd_IPCClient_Destroy(wxd_IPCClient_t* client);
 
+// --- Errors ---
+
+// Kind of error of the last failed IPC operation; operations that succeed reset it
+WXD_EXPORTED wxd_IPCError
+wxd_IPC_GetLastErrorKind(void);
+
+// Message of the last failed IPC operation, including what wxWidgets logged (empty if none)
+WXD_EXPORTED size_t
+wxd_IPC_GetLastError(char* buffer, size_t buffer_len);
+
 // Destroy all remaining IPC server/client objects.
 // Called during app shutdown to ensure DDE objects are cleaned up
 // before wxDDECleanUp() runs (Windows DDE assertion fix).
@@ -7,18 +7,51 @@
 WXD_EXPORTED wxd_PrintData_t* wxd_PrintData_Create();
 WXD_EXPORTED void wxd_PrintData_Destroy(wxd_PrintData_t* self);
 WXD_EXPORTED bool wxd_PrintData_IsOk(wxd_PrintData_t* self);
+WXD_EXPORTED int wxd_PrintData_GetOrientation(wxd_PrintData_t* self);
+WXD_EXPORTED void wxd_PrintData_SetOrientation(wxd_PrintData_t* self, int orientation);
+WXD_EXPORTED int wxd_PrintData_GetPaperId(wxd_PrintData_t* self);
+WXD_EXPORTED void wxd_PrintData_SetPaperId(wxd_PrintData_t* self, int paper_id);
 
 // --- PrintDialogData ---
 WXD_EXPORTED wxd_PrintDialogData_t* wxd_PrintDialogData_Create();
 WXD_EXPORTED wxd_PrintDialogData_t* wxd_PrintDialogData_CreateFromData(wxd_PrintData_t* data);
 WXD_EXPORTED void wxd_PrintDialogData_Destroy(wxd_PrintDialogData_t* self);
+WXD_EXPORTED wxd_PrintDialogData_t* wxd_PrintDialogData_Copy(wxd_PrintDialogData_t* self);
 WXD_EXPORTED wxd_PrintData_t* wxd_PrintDialogData_GetPrintData(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetPrintData(wxd_PrintDialogData_t* self, wxd_PrintData_t* data);
+WXD_EXPORTED int wxd_PrintDialogData_GetFromPage(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetFromPage(wxd_PrintDialogData_t* self, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetToPage(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetToPage(wxd_PrintDialogData_t* self, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetMinPage(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetMinPage(wxd_PrintDialogData_t* self, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetMaxPage(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetMaxPage(wxd_PrintDialogData_t* self, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetNoCopies(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetNoCopies(wxd_PrintDialogData_t* self, int copies);
+WXD_EXPORTED bool wxd_PrintDialogData_GetCollate(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetCollate(wxd_PrintDialogData_t* self, bool collate);
+WXD_EXPORTED bool wxd_PrintDialogData_GetPrintToFile(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetPrintToFile(wxd_PrintDialogData_t* self, bool print_to_file);
+WXD_EXPORTED bool wxd_PrintDialogData_GetAllPages(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetAllPages(wxd_PrintDialogData_t* self, bool all_pages);
+WXD_EXPORTED bool wxd_PrintDialogData_GetSelection(wxd_PrintDialogData_t* self);
+WXD_EXPORTED void wxd_PrintDialogData_SetSelection(wxd_PrintDialogData_t* self, bool selection);
 
 // --- PageSetupDialogData ---
 WXD_EXPORTED wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Create();
 WXD_EXPORTED wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_CreateFromData(wxd_PrintData_t* data);
 WXD_EXPORTED void wxd_PageSetupDialogData_Destroy(wxd_PageSetupDialogData_t* self);
+WXD_EXPORTED wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Copy(wxd_PageSetupDialogData_t* self);
 WXD_EXPORTED wxd_PrintData_t* wxd_PageSetupDialogData_GetPrintData(wxd_PageSetupDialogData_t* self);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetPrintData(wxd_PageSetupDialogData_t* self, wxd_PrintData_t* data);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetPaperId(wxd_PageSetupDialogData_t* self, int paper_id);
+WXD_EXPORTED wxd_Size wxd_PageSetupDialogData_GetPaperSize(wxd_PageSetupDialogData_t* self);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetPaperSize(wxd_PageSetupDialogData_t* self, wxd_Size size);
+WXD_EXPORTED wxd_Point wxd_PageSetupDialogData_GetMarginTopLeft(wxd_PageSetupDialogData_t* self);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetMarginTopLeft(wxd_PageSetupDialogData_t* self, wxd_Point pt);
+WXD_EXPORTED wxd_Point wxd_PageSetupDialogData_GetMarginBottomRight(wxd_PageSetupDialogData_t* self);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetMarginBottomRight(wxd_PageSetupDialogData_t* self, wxd_Point pt);
 
 // --- Printout ---
 WXD_EXPORTED wxd_Printout_t* wxd_Printout_CreateWithCallbacks(
@@ -40,6 +73,9 @@ WXD_EXPORTED void wxd_Printout_GetPageSizeMM(wxd_Printout_t* self, int* w, int*
 WXD_EXPORTED void wxd_Printout_GetPPIScreen(wxd_Printout_t* self, int* x, int* y);
 WXD_EXPORTED void wxd_Printout_GetPPIPrinter(wxd_Printout_t* self, int* x, int* y);
 WXD_EXPORTED bool wxd_Printout_IsPreview(wxd_Printout_t* self);
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPage(wxd_Printout_t* self, wxd_Size image_size);
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPageMargins(wxd_Printout_t* self, wxd_Size image_size, wxd_PageSetupDialogData_t* page_setup);
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToPage(wxd_Printout_t* self);
 
 // --- Printer ---
 WXD_EXPORTED wxd_Printer_t* wxd_Printer_Create(wxd_PrintDialogData_t* data);
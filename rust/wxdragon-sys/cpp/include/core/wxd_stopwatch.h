@@ -0,0 +1,42 @@
+#ifndef WXD_STOPWATCH_H
+#define WXD_STOPWATCH_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Create a new wxStopWatch; it starts running immediately
+WXD_EXPORTED wxd_StopWatch_t*
+wxd_StopWatch_Create(void);
+
+// Destroy a wxStopWatch
+WXD_EXPORTED void
+wxd_StopWatch_Destroy(wxd_StopWatch_t* self);
+
+// (Re)start the stopwatch at time0_ms milliseconds
+WXD_EXPORTED void
+wxd_StopWatch_Start(wxd_StopWatch_t* self, int64_t time0_ms);
+
+// Pause the stopwatch; pauses nest, each needs a matching resume
+WXD_EXPORTED void
+wxd_StopWatch_Pause(wxd_StopWatch_t* self);
+
+// Resume the stopwatch after a pause
+WXD_EXPORTED void
+wxd_StopWatch_Resume(wxd_StopWatch_t* self);
+
+// Elapsed time in milliseconds
+WXD_EXPORTED int64_t
+wxd_StopWatch_Time(const wxd_StopWatch_t* self);
+
+// Elapsed time in microseconds
+WXD_EXPORTED int64_t
+wxd_StopWatch_TimeInMicro(const wxd_StopWatch_t* self);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_STOPWATCH_H
@@ -47,6 +47,30 @@ wxd_Timer_GetInterval(wxd_Timer_t* self);
 WXD_EXPORTED void
 wxd_Timer_SetInterval(wxd_Timer_t* self, int milliseconds);
 
+// --- Delayed calls ---
+
+// Called once when a delayed call fires
+typedef void (*wxd_CallLaterCallback)(void* user_data);
+// Frees the user data of a delayed call, whether it fired or not
+typedef void (*wxd_CallLaterFreeCallback)(void* user_data);
+
+// Calls callback(user_data) once on the main thread after milliseconds, using a one-shot
+// timer owned by the library. free_fn(user_data) is always called exactly once: after the
+// call, when the call can't be scheduled (returns null), when it is cancelled, or when the
+// application exits before it fired. Must be called on the main thread.
+WXD_EXPORTED wxd_CallLater_t*
+wxd_CallLater(int milliseconds, wxd_CallLaterCallback callback, void* user_data, wxd_CallLaterFreeCallback free_fn);
+
+// Stops a delayed call that hasn't fired yet and frees it and its user data without calling
+// it. Returns false, doing nothing, if the call already fired or was freed.
+WXD_EXPORTED bool
+wxd_CallLater_Cancel(wxd_CallLater_t* call);
+
+// Stops all delayed calls that haven't fired and frees their user data without calling them.
+// Called when the application exits.
+WXD_EXPORTED void
+wxd_CallLater_CleanupAll(void);
+
 #ifdef __cplusplus
 }
 #endif
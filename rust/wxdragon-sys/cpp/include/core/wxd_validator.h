@@ -0,0 +1,52 @@
+#ifndef WXD_VALIDATOR_H
+#define WXD_VALIDATOR_H
+
+#include "../wxd_types.h"
+
+// --- Custom (Rust-backed) validators ---
+
+// Sink handed to validate for the message shown to the user when the value is
+// rejected; the message is copied, so it need only outlive the call.
+typedef void (*wxd_ValidatorMessageSink)(void* sink, const char* message);
+
+typedef struct wxd_Validator_vtable {
+    // Check `value`, the text of the validated control. Return false to reject
+    // it after passing a message for the user to emit(sink, message).
+    bool (*validate)(void* user_data, const char* value, void* sink, wxd_ValidatorMessageSink emit);
+
+    // Return false to reject the character `ch` (a Unicode code point) typed
+    // into the control. Editing and navigation keys aren't passed.
+    bool (*accepts_char)(void* user_data, uint32_t ch);
+
+    // Take the value of the control when its parent transfers data from its
+    // children. May be null.
+    void (*transfer_from_window)(void* user_data, const char* value);
+
+    // Release user_data. Called once the window and all copies of the
+    // validator are gone.
+    void (*destroy)(void* user_data);
+} wxd_Validator_vtable;
+
+// Attach a validator to a text control, combo box or spin control, replacing
+// any validator set before. The vtable is copied; `user_data` is owned by the
+// validator and released via vtable.destroy, also if this call fails.
+WXD_EXPORTED bool
+wxd_Window_SetValidator(wxd_Window_t* window, const wxd_Validator_vtable* vtable, void* user_data);
+
+// Validate all descendants of `window` that have a validator, stopping at the
+// first failure. The failing control is brought into view and focused, and its
+// message is shown. Returns true if every value is accepted.
+WXD_EXPORTED bool
+wxd_Window_Validate(wxd_Window_t* window);
+
+// Let the validators of all descendants of `window` take their control's value.
+// Returns false if one of them fails.
+WXD_EXPORTED bool
+wxd_Window_TransferDataFromWindow(wxd_Window_t* window);
+
+// Returns 1 if `pattern` (extended regular expression syntax) matches all of
+// `text`, 0 if it doesn't and -1 if the pattern is invalid.
+WXD_EXPORTED int
+wxd_RegEx_MatchesAll(const char* pattern, const char* text);
+
+#endif // WXD_VALIDATOR_H
@@ -28,6 +28,9 @@ WXD_EXPORTED void
 wxd_Window_Refresh(wxd_Window_t* window, int eraseBackground, const wxd_Rect* rect);
 WXD_EXPORTED void
 wxd_Window_Update(wxd_Window_t* window);
+// Sends an idle event to the window and its children; returns true if more idle time was requested
+WXD_EXPORTED bool
+wxd_Window_SendIdleEvents(wxd_Window_t* window);
 WXD_EXPORTED void
 wxd_Window_SetToolTip(wxd_Window_t* window, const char* tipString);
 
@@ -95,6 +98,12 @@ wxd_Window_SetBackgroundStyle(wxd_Window_t* window, int style);
 WXD_EXPORTED int
 wxd_Window_GetBackgroundStyle(wxd_Window_t* window);
 
+// Native double buffering; SetDoubleBuffered does nothing where it isn't supported
+WXD_EXPORTED void
+wxd_Window_SetDoubleBuffered(wxd_Window_t* window, bool on);
+WXD_EXPORTED bool
+wxd_Window_IsDoubleBuffered(wxd_Window_t* window);
+
 // Extra window style functions
 WXD_EXPORTED void
 wxd_Window_SetExtraStyle(wxd_Window_t* window, int64_t exStyle);
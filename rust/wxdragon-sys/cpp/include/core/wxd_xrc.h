@@ -7,6 +7,11 @@
 extern "C" {
 #endif
 
+// Creates the window for an object of a custom XRC class, or returns NULL on failure.
+// name is the XRC object name (UTF-8, may be empty).
+typedef wxd_Window_t* (*wxd_XmlResource_SubclassFactory)(void* user_data, wxd_Window_t* parent,
+                                                         const char* name);
+
 // Get the global wxXmlResource instance
 WXD_EXPORTED wxd_XmlResource_t*
 wxd_XmlResource_Get(void);
@@ -23,6 +28,24 @@ wxd_XmlResource_LoadFromFile(wxd_XmlResource_t* self, const char* filemask);
 WXD_EXPORTED bool
 wxd_XmlResource_LoadFromString(wxd_XmlResource_t* self, const char* xrc_data);
 
+// Load XRC from string data; relative paths in it are resolved against base_dir
+WXD_EXPORTED bool
+wxd_XmlResource_LoadFromStringWithBasePath(wxd_XmlResource_t* self, const char* xrc_data,
+                                           const char* base_dir);
+
+// Load XRC from a UTF-8 byte buffer; name identifies the document in error messages
+WXD_EXPORTED bool
+wxd_XmlResource_LoadFromBytes(wxd_XmlResource_t* self, const unsigned char* data, size_t len,
+                              const char* name);
+
+// Load a named wxBitmap resource (caller owns the result, null if not found)
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_XmlResource_LoadBitmap(wxd_XmlResource_t* self, const char* name);
+
+// Load a named wxIcon resource as a bitmap (caller owns the result, null if not found)
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_XmlResource_LoadIcon(wxd_XmlResource_t* self, const char* name);
+
 // Load a dialog from XRC
 WXD_EXPORTED wxd_Dialog_t*
 wxd_XmlResource_LoadDialog(wxd_XmlResource_t* self, wxd_Window_t* parent, const char* name);
@@ -39,19 +62,58 @@ wxd_XmlResource_LoadPanel(wxd_XmlResource_t* self, wxd_Window_t* parent, const c
 WXD_EXPORTED wxd_MenuBar_t*
 wxd_XmlResource_LoadMenuBar(wxd_XmlResource_t* self, wxd_Window_t* parent, const char* name);
 
+// Load a menu from XRC; the caller owns the returned menu
+WXD_EXPORTED wxd_Menu_t*
+wxd_XmlResource_LoadMenu(wxd_XmlResource_t* self, const char* name);
+
+// Load a toolbar from XRC; if parent is a frame, the toolbar becomes its toolbar
+WXD_EXPORTED wxd_ToolBar_t*
+wxd_XmlResource_LoadToolBar(wxd_XmlResource_t* self, wxd_Window_t* parent, const char* name);
+
 // Load a generic object from XRC
 WXD_EXPORTED wxd_Window_t*
 wxd_XmlResource_LoadObject(wxd_XmlResource_t* self, wxd_Window_t* parent, const char* name,
                            const char* classname);
 
+// Get the class of a top-level resource; returns -1 if there is no resource with this name
+WXD_EXPORTED int
+wxd_XmlResource_GetResourceClass(wxd_XmlResource_t* self, const char* name, char* buffer,
+                                 size_t buffer_len);
+
+// Get the errors logged by the last load call (empty if none)
+WXD_EXPORTED int
+wxd_XmlResource_GetLastError(char* buffer, size_t buffer_len);
+
 // Get XRC ID for a named control
 WXD_EXPORTED int
 wxd_XmlResource_GetXRCID(const char* name);
 
+// Get XRC ID for a name used in a loaded document, or given an id before; returns wxID_NONE
+// instead of creating an id for unknown names
+WXD_EXPORTED int
+wxd_XmlResource_TryGetXRCID(const char* name);
+
+// Give name the XRC ID id. Returns the id name ends up with: id itself, the id name already
+// had, or wxID_NONE if id belongs to another name.
+WXD_EXPORTED int
+wxd_XmlResource_AssignXRCID(const char* name, int id);
+
+// Get the XRC name an id was created for; returns -1 if the id does not come from XRC
+WXD_EXPORTED int
+wxd_XmlResource_GetXRCName(int id, char* buffer, size_t buffer_len);
+
 // Find a child window by XRC name
 WXD_EXPORTED wxd_Window_t*
 wxd_Window_FindWindowByXRCName(wxd_Window_t* parent, const char* xrc_name);
 
+// Register a factory creating the objects of XRC class class_name. The id, name, style, position,
+// size and common window attributes from the XRC are applied to the created window. Registering
+// the same class again replaces the factory. user_data is released with
+// wxd_Drop_Rust_XrcSubclassFactory when no longer needed.
+WXD_EXPORTED bool
+wxd_XmlResource_RegisterSubclassFactory(wxd_XmlResource_t* self, const char* class_name,
+                                        wxd_XmlResource_SubclassFactory factory, void* user_data);
+
 // Initialize platform-aware StaticBitmap handler for XRC files
 WXD_EXPORTED void
 wxd_XmlResource_InitPlatformAwareStaticBitmapHandler(wxd_XmlResource_t* resource);
@@ -40,11 +40,30 @@ wxd_Dialog_SetReturnCode(wxd_Dialog_t* self, int retCode);
 WXD_EXPORTED int
 wxd_Dialog_GetReturnCode(wxd_Dialog_t* self);
 
+// Standard buttons (wxOK, wxCANCEL, wxYES, wxNO, wxAPPLY, wxCLOSE, wxHELP, wxNO_DEFAULT)
+// laid out in a realized wxStdDialogButtonSizer. Returns NULL if no button was requested.
+WXD_EXPORTED wxd_StdDialogButtonSizer_t*
+wxd_Dialog_CreateStdDialogButtonSizer(wxd_Dialog_t* self, wxd_Style_t flags);
+
 // --- MessageDialog ---
 WXD_EXPORTED wxd_MessageDialog_t*
 wxd_MessageDialog_Create(wxd_Window_t* parent, const char* message, const char* caption,
                          wxd_Style_t style);
 
+// --- RichMessageDialog ---
+WXD_EXPORTED wxd_RichMessageDialog_t*
+wxd_RichMessageDialog_Create(wxd_Window_t* parent, const char* message, const char* caption,
+                             wxd_Style_t style);
+
+WXD_EXPORTED void
+wxd_RichMessageDialog_ShowCheckBox(wxd_RichMessageDialog_t* self, const char* label, bool checked);
+
+WXD_EXPORTED bool
+wxd_RichMessageDialog_IsCheckBoxChecked(wxd_RichMessageDialog_t* self);
+
+WXD_EXPORTED void
+wxd_RichMessageDialog_ShowDetailedText(wxd_RichMessageDialog_t* self, const char* text);
+
 // --- FileDialog ---
 
 WXD_EXPORTED wxd_FileDialog_t*
@@ -119,6 +138,9 @@ wxd_ColourData_SetCustomColour(wxd_ColourData_t* self, int i, wxd_Colour_t colou
 WXD_EXPORTED wxd_Colour_t
 wxd_ColourData_GetCustomColour(wxd_ColourData_t* self, int i);
 
+WXD_EXPORTED bool
+wxd_ColourData_HasCustomColour(wxd_ColourData_t* self, int i);
+
 WXD_EXPORTED void
 wxd_ColourData_Destroy(wxd_ColourData_t* self);
 
@@ -217,6 +239,14 @@ wxd_Font_GetFaceName(wxd_Font_t* self, char* buffer, size_t buffer_len);
 WXD_EXPORTED bool
 wxd_Font_IsOk(wxd_Font_t* self);
 
+// Returns the length of the full description (excluding the null terminator).
+WXD_EXPORTED int
+wxd_Font_GetNativeFontInfoDesc(wxd_Font_t* self, char* buffer, size_t buffer_len);
+
+// Returns NULL if the description cannot be parsed.
+WXD_EXPORTED wxd_Font_t*
+wxd_Font_CreateFromNativeFontInfoDesc(const char* desc);
+
 WXD_EXPORTED wxd_FontDialog_t*
 wxd_FontDialog_Create(wxd_Window_t* parent, const char* title, wxd_FontData_t* data);
 
@@ -235,11 +265,36 @@ wxd_TextEntryDialog_Create(wxd_Window_t* parent, const char* message, const char
 WXD_EXPORTED int
 wxd_TextEntryDialog_GetValue(wxd_TextEntryDialog_t* self, char* buffer, size_t bufLen);
 
+// 0 means no limit.
+WXD_EXPORTED void
+wxd_TextEntryDialog_SetMaxLength(wxd_TextEntryDialog_t* self, unsigned long len);
+
+// --- PasswordEntryDialog ---
+// A wxTextEntryDialog with a masked field; use the TextEntryDialog functions on the
+// returned pointer to read the value or limit its length.
+WXD_EXPORTED wxd_TextEntryDialog_t*
+wxd_PasswordEntryDialog_Create(wxd_Window_t* parent, const char* message, const char* caption,
+                               wxd_Style_t style, int x, int y);
+
+// --- NumberEntryDialog ---
+// min/max are swapped if reversed and value is clamped into the range.
+WXD_EXPORTED wxd_NumberEntryDialog_t*
+wxd_NumberEntryDialog_Create(wxd_Window_t* parent, const char* message, const char* prompt,
+                             const char* caption, int64_t value, int64_t min, int64_t max);
+
+WXD_EXPORTED int64_t
+wxd_NumberEntryDialog_GetValue(wxd_NumberEntryDialog_t* self);
+
 // --- ProgressDialog ---
 WXD_EXPORTED wxd_ProgressDialog_t*
 wxd_ProgressDialog_Create(wxd_Window_t* parent, const char* title, const char* message, int maximum,
                           wxd_Style_t style);
 
+// Always uses wxGenericProgressDialog, even where a native dialog exists (Windows).
+WXD_EXPORTED wxd_ProgressDialog_t*
+wxd_ProgressDialog_CreateGeneric(wxd_Window_t* parent, const char* title, const char* message,
+                                 int maximum, wxd_Style_t style);
+
 WXD_EXPORTED bool
 wxd_ProgressDialog_Update(wxd_ProgressDialog_t* self, int value, const char* newmsg, bool* skip);
 
@@ -356,4 +411,67 @@ wxd_DirDialog_GetMessage(const wxd_DirDialog_t* self, char* buffer, size_t bufLe
 WXD_EXPORTED void
 wxd_DirDialog_SetMessage(wxd_DirDialog_t* self, const char* message);
 
+// --- Wizard ---
+// Returns the page to show next (or previous), or NULL for none.
+typedef wxd_WizardPage_t* (*wxd_WizardPage_LinkCallback)(void* user_data);
+
+WXD_EXPORTED wxd_Wizard_t*
+wxd_Wizard_Create(wxd_Window_t* parent, int id, const char* title, const wxd_Bitmap_t* bitmap,
+                  int x, int y, wxd_Style_t style);
+
+// Adds the page to the page area sizer so the wizard is sized to fit it.
+WXD_EXPORTED void
+wxd_Wizard_AddPageToSizer(wxd_Wizard_t* self, wxd_WizardPage_t* page);
+
+WXD_EXPORTED bool
+wxd_Wizard_RunWizard(wxd_Wizard_t* self, wxd_WizardPage_t* firstPage);
+
+WXD_EXPORTED wxd_WizardPage_t*
+wxd_Wizard_GetCurrentPage(const wxd_Wizard_t* self);
+
+WXD_EXPORTED bool
+wxd_Wizard_HasNextPage(wxd_Wizard_t* self, wxd_WizardPage_t* page);
+
+WXD_EXPORTED bool
+wxd_Wizard_HasPrevPage(wxd_Wizard_t* self, wxd_WizardPage_t* page);
+
+WXD_EXPORTED void
+wxd_Wizard_SetPageSize(wxd_Wizard_t* self, int width, int height);
+
+WXD_EXPORTED void
+wxd_Wizard_SetBorder(wxd_Wizard_t* self, int border);
+
+WXD_EXPORTED wxd_WizardPage_t*
+wxd_WizardPage_Create(wxd_Wizard_t* wizard);
+
+WXD_EXPORTED void
+wxd_WizardPage_SetPrev(wxd_WizardPage_t* self, wxd_WizardPage_t* prev);
+
+WXD_EXPORTED void
+wxd_WizardPage_SetNext(wxd_WizardPage_t* self, wxd_WizardPage_t* next);
+
+WXD_EXPORTED wxd_WizardPage_t*
+wxd_WizardPage_GetPrev(const wxd_WizardPage_t* self);
+
+WXD_EXPORTED wxd_WizardPage_t*
+wxd_WizardPage_GetNext(const wxd_WizardPage_t* self);
+
+// Installs a callback deciding the next/previous page at runtime, replacing (and
+// freeing) any previous one. Pass a NULL callback to fall back to the static link.
+// user_data is released with wxd_Drop_Rust_WizardPageLink when no longer needed.
+WXD_EXPORTED void
+wxd_WizardPage_SetNextCallback(wxd_WizardPage_t* self, wxd_WizardPage_LinkCallback callback,
+                               void* user_data);
+
+WXD_EXPORTED void
+wxd_WizardPage_SetPrevCallback(wxd_WizardPage_t* self, wxd_WizardPage_LinkCallback callback,
+                               void* user_data);
+
+// Wizard event accessors
+WXD_EXPORTED bool
+wxd_WizardEvent_GetDirection(wxd_Event_t* event);
+
+WXD_EXPORTED wxd_WizardPage_t*
+wxd_WizardEvent_GetPage(wxd_Event_t* event);
+
 #endif // WXD_DIALOGS_H
\ No newline at end of file
@@ -54,6 +54,35 @@ wxd_TextDropTarget_Destroy(wxd_TextDropTarget_t* dropTarget);
 WXD_EXPORTED void
 wxd_FileDropTarget_Destroy(wxd_FileDropTarget_t* dropTarget);
 
+// --- Window drop handlers ---
+// A window accepts any mix of files, text and custom formats registered with these
+// functions through one composite drop target, replacing a drop target set otherwise.
+// Registering a kind (or custom format) again replaces its handler, freeing the old
+// userData; a NULL callback unregisters it. The target and all userData are freed with
+// the window. Return false (freeing userData) if the window is NULL.
+typedef bool (*wxd_OnDropData_Callback)(const unsigned char* data, size_t len, int x, int y,
+                                        void* userData);
+
+WXD_EXPORTED bool
+wxd_Window_SetDropFilesHandler(wxd_Window_t* window, wxd_OnDropFiles_Callback onDropFiles,
+                               void* userData, wxd_FreeUserData_Callback freeUserData);
+
+WXD_EXPORTED bool
+wxd_Window_SetDropTextHandler(wxd_Window_t* window, wxd_OnDropText_Callback onDropText,
+                              void* userData, wxd_FreeUserData_Callback freeUserData);
+
+// format is the name of a custom wxDataFormat, e.g. "application/x-myapp-node"
+WXD_EXPORTED bool
+wxd_Window_SetDropDataHandler(wxd_Window_t* window, const char* format,
+                              wxd_OnDropData_Callback onDropData, void* userData,
+                              wxd_FreeUserData_Callback freeUserData);
+
+// Decides the drag result (and so the cursor) while data is dragged over the window; a
+// drop where it returns WXD_DRAG_NONE is refused. Without one every position accepts.
+WXD_EXPORTED bool
+wxd_Window_SetDragOverHandler(wxd_Window_t* window, wxd_OnDragOver_Callback onDragOver,
+                              void* userData, wxd_FreeUserData_Callback freeUserData);
+
 #ifdef __cplusplus
 }
 #endif
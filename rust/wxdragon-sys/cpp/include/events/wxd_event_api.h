@@ -37,6 +37,17 @@ WXD_EXPORTED void
 wxd_Event_Skip(wxd_Event_t* event, bool skip);
 WXD_EXPORTED WXDEventTypeCEnum
 wxd_Event_GetEventType(wxd_Event_t* event);
+WXD_EXPORTED int64_t
+wxd_Event_GetTimestamp(wxd_Event_t* event);
+
+// Propagation control: StopPropagation returns the previous propagation level,
+// which can be handed back to ResumePropagation.
+WXD_EXPORTED int
+wxd_Event_StopPropagation(wxd_Event_t* event);
+WXD_EXPORTED void
+wxd_Event_ResumePropagation(wxd_Event_t* event, int propagation_level);
+WXD_EXPORTED bool
+wxd_Event_ShouldPropagate(wxd_Event_t* event);
 
 /**
  * Get string from wxCommandEvent.
@@ -75,6 +86,9 @@ wxd_ScrollEvent_GetPosition(wxd_Event_t* event);
 WXD_EXPORTED int
 wxd_ScrollEvent_GetOrientation(wxd_Event_t* event);
 
+WXD_EXPORTED double
+wxd_SpinDoubleEvent_GetValue(wxd_Event_t* event);
+
 WXD_EXPORTED int
 wxd_NotebookEvent_GetSelection(wxd_Event_t* event);
 WXD_EXPORTED int
@@ -144,6 +158,10 @@ wxd_DataViewEvent_GetPosition(const wxd_Event_t* event);
 WXD_EXPORTED bool
 wxd_DataViewEvent_GetSortOrder(const wxd_Event_t* event, bool* ascending);
 
+// Sets the data dragged for wxEVT_DATAVIEW_ITEM_BEGIN_DRAG, taking ownership of data_object
+WXD_EXPORTED bool
+wxd_DataViewEvent_SetDragData(wxd_Event_t* event, wxd_DataObject_t* data_object, bool allow_move);
+
 // TreeListCtrl event accessors
 WXD_EXPORTED wxd_Long_t
 wxd_TreeListEvent_GetItem(wxd_Event_t* event);
@@ -15,6 +15,10 @@ typedef struct wxd_PaintDC_t wxd_PaintDC_t;
 typedef struct wxd_MemoryDC_t wxd_MemoryDC_t;
 typedef struct wxd_ScreenDC_t wxd_ScreenDC_t;
 typedef struct wxd_AutoBufferedPaintDC_t wxd_AutoBufferedPaintDC_t;
+typedef struct wxd_BufferedDC_t wxd_BufferedDC_t;
+typedef struct wxd_Overlay_t wxd_Overlay_t;
+typedef struct wxd_DCOverlay_t wxd_DCOverlay_t;
+typedef struct wxd_DCState_t wxd_DCState_t;
 
 // DC Creation/Destruction
 WXD_EXPORTED wxd_WindowDC_t*
@@ -53,10 +57,22 @@ wxd_AutoBufferedPaintDC_Create(wxd_Window_t* window);
 WXD_EXPORTED void
 wxd_AutoBufferedPaintDC_Destroy(wxd_AutoBufferedPaintDC_t* dc);
 
+// Buffers drawing for `dc` in a bitmap of the DC's size, copied to `dc` on destruction.
+// The BufferedDC must be destroyed before `dc`.
+WXD_EXPORTED wxd_BufferedDC_t*
+wxd_BufferedDC_Create(wxd_DC_t* dc);
+
+WXD_EXPORTED void
+wxd_BufferedDC_Destroy(wxd_BufferedDC_t* dc);
+
 // Common DC operations (operates on the base type)
 WXD_EXPORTED void
 wxd_DC_Destroy(wxd_DC_t* dc);
 
+// False if the DC could not be created, e.g. for a destroyed window
+WXD_EXPORTED bool
+wxd_DC_IsOk(wxd_DC_t* dc);
+
 WXD_EXPORTED void
 wxd_DC_Clear(wxd_DC_t* dc);
 
@@ -111,6 +127,11 @@ WXD_EXPORTED void
 wxd_DC_DrawPolygon(wxd_DC_t* dc, int n, wxd_Point* points, int xoffset, int yoffset,
                    int fill_style);
 
+// `counts` holds the number of points of each of the `n` polygons stored one after another in `points`
+WXD_EXPORTED void
+wxd_DC_DrawPolyPolygon(wxd_DC_t* dc, int n, const int* counts, wxd_Point* points, int xoffset, int yoffset,
+                       int fill_style);
+
 WXD_EXPORTED void
 wxd_DC_DrawLines(wxd_DC_t* dc, int n, wxd_Point* points, int xoffset, int yoffset);
 
@@ -153,6 +174,18 @@ wxd_DC_DestroyClippingRegion(wxd_DC_t* dc);
 WXD_EXPORTED void
 wxd_DC_GetClippingBox(wxd_DC_t* dc, int* x, int* y, int* width, int* height);
 
+// Returns false (and leaves rect alone) if no clipping region is set
+WXD_EXPORTED bool
+wxd_DC_GetClippingRect(wxd_DC_t* dc, wxd_Rect* rect);
+
+// Drawing state: pen, brush, font, colours, modes, origins, user scale and clipping box
+WXD_EXPORTED wxd_DCState_t*
+wxd_DC_SaveState(wxd_DC_t* dc);
+
+// Restores the state and destroys it
+WXD_EXPORTED void
+wxd_DC_RestoreState(wxd_DC_t* dc, wxd_DCState_t* state);
+
 // Coordinate transformation
 WXD_EXPORTED void
 wxd_DC_SetDeviceOrigin(wxd_DC_t* dc, int x, int y);
@@ -222,6 +255,21 @@ wxd_DC_GetCharHeight(wxd_DC_t* dc);
 WXD_EXPORTED int
 wxd_DC_GetCharWidth(wxd_DC_t* dc);
 
+// Width of each prefix of the text: widths[i] is the extent of the first i + 1 characters.
+// Returns the number of characters (call with widths = NULL to query it), or -1 on error.
+WXD_EXPORTED int
+wxd_DC_GetPartialTextExtents(wxd_DC_t* dc, const char* string, int* widths, size_t count);
+
+// Metrics of the DC's current font
+WXD_EXPORTED bool
+wxd_DC_GetFontMetrics(wxd_DC_t* dc, wxd_FontMetrics* metrics);
+
+// Shortens the text with an ellipsis to fit max_width using the DC's current font, as
+// wxControl::Ellipsize does. mode is one of the WXD_ELLIPSIZE_* constants.
+// Returns the length of the result in bytes, or -1 on error.
+WXD_EXPORTED int
+wxd_DC_Ellipsize(wxd_DC_t* dc, const char* string, int mode, int max_width, char* buffer, size_t buffer_len);
+
 // Drawing state queries
 WXD_EXPORTED wxd_Colour_t
 wxd_DC_GetBackground(wxd_DC_t* dc);
@@ -251,6 +299,29 @@ WXD_EXPORTED void
 wxd_DC_GradientFillConcentric(wxd_DC_t* dc, wxd_Rect rect, wxd_Colour_t initialColour,
                               wxd_Colour_t destColour, wxd_Point circleCenter);
 
+// Native renderer (wxRendererNative); flags are WXD_CONTROL_* bits and `window` is the
+// window the DC draws on, used for theming
+WXD_EXPORTED void
+wxd_DC_DrawHeaderButton(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags, const char* label);
+
+WXD_EXPORTED void
+wxd_DC_DrawPushButton(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags);
+
+WXD_EXPORTED void
+wxd_DC_DrawCheckBox(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags);
+
+WXD_EXPORTED void
+wxd_DC_DrawItemSelectionRect(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags);
+
+WXD_EXPORTED void
+wxd_DC_DrawFocusRect(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags);
+
+WXD_EXPORTED int
+wxd_Renderer_GetHeaderButtonHeight(wxd_Window_t* window);
+
+WXD_EXPORTED wxd_Size
+wxd_Renderer_GetCheckBoxSize(wxd_Window_t* window, int flags);
+
 // Flood fill
 WXD_EXPORTED bool
 wxd_DC_FloodFill(wxd_DC_t* dc, int x, int y, wxd_Colour_t colour, int style);
@@ -262,13 +333,54 @@ wxd_DC_SetLogicalFunction(wxd_DC_t* dc, int function);
 WXD_EXPORTED int
 wxd_DC_GetLogicalFunction(wxd_DC_t* dc);
 
-// MemoryDC specific operations
+// MemoryDC specific operations; a NULL bitmap deselects the current one
 WXD_EXPORTED void
 wxd_MemoryDC_SelectObject(wxd_MemoryDC_t* dc, const wxd_Bitmap_t* bitmap);
 
 WXD_EXPORTED void
 wxd_MemoryDC_SelectObjectAsSource(wxd_MemoryDC_t* dc, const wxd_Bitmap_t* bitmap);
 
+// Overlay for drawing temporary shapes (e.g. rubber bands) on top of a window
+WXD_EXPORTED wxd_Overlay_t*
+wxd_Overlay_Create(void);
+
+WXD_EXPORTED void
+wxd_Overlay_Destroy(wxd_Overlay_t* overlay);
+
+// Removes the overlay; call when the temporary drawing is finished
+WXD_EXPORTED void
+wxd_Overlay_Reset(wxd_Overlay_t* overlay);
+
+// Connects a DC to an overlay; must be destroyed before the DC
+WXD_EXPORTED wxd_DCOverlay_t*
+wxd_DCOverlay_Create(wxd_Overlay_t* overlay, wxd_DC_t* dc);
+
+WXD_EXPORTED void
+wxd_DCOverlay_Destroy(wxd_DCOverlay_t* dc_overlay);
+
+// Erases what was drawn on the overlay before
+WXD_EXPORTED void
+wxd_DCOverlay_Clear(wxd_DCOverlay_t* dc_overlay);
+
+// --- Screen capture ---
+// Reasons reported by the capture functions
+#define WXD_CAPTURE_OK 0
+#define WXD_CAPTURE_PERMISSION_DENIED 1
+#define WXD_CAPTURE_OUTSIDE_SCREEN 2
+#define WXD_CAPTURE_NOT_VISIBLE 3
+#define WXD_CAPTURE_NOT_SUPPORTED 4
+#define WXD_CAPTURE_FAILED 5
+
+// Copies a screen area into a new bitmap. Coordinates are screen coordinates, negative for
+// displays left of or above the primary one; a non-positive width or height captures all
+// displays. The area is clipped to the displays. Returns NULL and sets *error on failure.
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_CaptureScreen(int x, int y, int width, int height, int* error);
+
+// Copies the client area of a shown window as it appears on screen
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Window_CaptureClientArea(wxd_Window_t* window, int* error);
+
 // Type casting functions (for safely using base DC functions with derived types)
 WXD_EXPORTED wxd_DC_t*
 wxd_WindowDC_AsDC(wxd_WindowDC_t* dc);
@@ -288,6 +400,15 @@ wxd_ScreenDC_AsDC(wxd_ScreenDC_t* dc);
 WXD_EXPORTED wxd_DC_t*
 wxd_AutoBufferedPaintDC_AsDC(wxd_AutoBufferedPaintDC_t* dc);
 
+WXD_EXPORTED wxd_DC_t*
+wxd_BufferedDC_AsDC(wxd_BufferedDC_t* dc);
+
+// Erase background event support
+// Returns the DC owned by a wxEraseEvent. The DC is borrowed and only valid
+// for the duration of the event handler; returns NULL for other event types.
+WXD_EXPORTED wxd_DC_t*
+wxd_EraseEvent_GetDC(wxd_Event_t* event);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif
@@ -0,0 +1,192 @@
+#ifndef WXD_GRAPHICS_H
+#define WXD_GRAPHICS_H
+
+#include "../wxd_types.h"
+#include "wxd_dc.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Opaque graphics context types
+typedef struct wxd_GraphicsContext_t wxd_GraphicsContext_t;
+typedef struct wxd_GraphicsPath_t wxd_GraphicsPath_t;
+
+// Gradient kinds for wxd_GraphicsGradient_t
+#define WXD_GRAPHICS_GRADIENT_LINEAR 1
+#define WXD_GRAPHICS_GRADIENT_RADIAL 2
+
+// Describes a linear (x1,y1 -> x2,y2) or radial gradient (from x1,y1 to the circle of
+// `radius` around x2,y2) with `count` colour stops at positions between 0.0 and 1.0
+typedef struct {
+    int kind;
+    double x1;
+    double y1;
+    double x2;
+    double y2;
+    double radius;
+    const wxd_Colour_t* colours;
+    const double* positions;
+    int count;
+} wxd_GraphicsGradient_t;
+
+// Creation/destruction; NULL if no graphics renderer is available.
+// Drawing is flushed to the DC or window when the context is destroyed.
+WXD_EXPORTED wxd_GraphicsContext_t*
+wxd_GraphicsContext_CreateFromDC(wxd_DC_t* dc);
+
+WXD_EXPORTED wxd_GraphicsContext_t*
+wxd_GraphicsContext_CreateFromWindow(wxd_Window_t* window);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_Destroy(wxd_GraphicsContext_t* gc);
+
+// Pens and brushes; a NULL gradient means a plain colour
+WXD_EXPORTED void
+wxd_GraphicsContext_SetPen(wxd_GraphicsContext_t* gc, wxd_Colour_t colour, double width, int style,
+                           const wxd_GraphicsGradient_t* gradient);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_SetBrush(wxd_GraphicsContext_t* gc, wxd_Colour_t colour, int style,
+                             const wxd_GraphicsGradient_t* gradient);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_SetFont(wxd_GraphicsContext_t* gc, const wxd_Font_t* font, wxd_Colour_t colour);
+
+// Quality
+WXD_EXPORTED bool
+wxd_GraphicsContext_SetAntialiasMode(wxd_GraphicsContext_t* gc, int mode);
+
+WXD_EXPORTED int
+wxd_GraphicsContext_GetAntialiasMode(wxd_GraphicsContext_t* gc);
+
+WXD_EXPORTED bool
+wxd_GraphicsContext_SetInterpolationQuality(wxd_GraphicsContext_t* gc, int quality);
+
+WXD_EXPORTED int
+wxd_GraphicsContext_GetInterpolationQuality(wxd_GraphicsContext_t* gc);
+
+// Drawing
+WXD_EXPORTED void
+wxd_GraphicsContext_StrokePath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_FillPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_StrokeLine(wxd_GraphicsContext_t* gc, double x1, double y1, double x2, double y2);
+
+// `xy` holds `count` x/y pairs
+WXD_EXPORTED void
+wxd_GraphicsContext_StrokeLines(wxd_GraphicsContext_t* gc, const double* xy, int count);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawRoundedRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h,
+                                         double radius);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawEllipse(wxd_GraphicsContext_t* gc, double x, double y, double w, double h);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawBitmap(wxd_GraphicsContext_t* gc, const wxd_Bitmap_t* bitmap, double x, double y,
+                               double w, double h);
+
+// Text
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawText(wxd_GraphicsContext_t* gc, const char* text, double x, double y, double angle);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_GetTextExtent(wxd_GraphicsContext_t* gc, const char* text, double* width, double* height,
+                                  double* descent, double* external_leading);
+
+// Transforms and state
+WXD_EXPORTED void
+wxd_GraphicsContext_Translate(wxd_GraphicsContext_t* gc, double dx, double dy);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_Scale(wxd_GraphicsContext_t* gc, double x_scale, double y_scale);
+
+// Angle in radians, clockwise
+WXD_EXPORTED void
+wxd_GraphicsContext_Rotate(wxd_GraphicsContext_t* gc, double angle);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_PushState(wxd_GraphicsContext_t* gc);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_PopState(wxd_GraphicsContext_t* gc);
+
+// Draws everything until EndLayer with the given opacity (0.0 - 1.0)
+WXD_EXPORTED void
+wxd_GraphicsContext_BeginLayer(wxd_GraphicsContext_t* gc, double opacity);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_EndLayer(wxd_GraphicsContext_t* gc);
+
+// Clipping; Clip intersects with the current clipping region
+WXD_EXPORTED void
+wxd_GraphicsContext_Clip(wxd_GraphicsContext_t* gc, double x, double y, double w, double h);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_ResetClip(wxd_GraphicsContext_t* gc);
+
+WXD_EXPORTED void
+wxd_GraphicsContext_GetSize(wxd_GraphicsContext_t* gc, double* width, double* height);
+
+// Paths are created by a context but may outlive it
+WXD_EXPORTED wxd_GraphicsPath_t*
+wxd_GraphicsContext_CreatePath(wxd_GraphicsContext_t* gc);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_Destroy(wxd_GraphicsPath_t* path);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_MoveToPoint(wxd_GraphicsPath_t* path, double x, double y);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddLineToPoint(wxd_GraphicsPath_t* path, double x, double y);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddCurveToPoint(wxd_GraphicsPath_t* path, double cx1, double cy1, double cx2, double cy2, double x,
+                                 double y);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddQuadCurveToPoint(wxd_GraphicsPath_t* path, double cx, double cy, double x, double y);
+
+// Angles in radians
+WXD_EXPORTED void
+wxd_GraphicsPath_AddArc(wxd_GraphicsPath_t* path, double x, double y, double r, double start_angle, double end_angle,
+                        bool clockwise);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddRoundedRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h, double radius);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddCircle(wxd_GraphicsPath_t* path, double x, double y, double r);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddEllipse(wxd_GraphicsPath_t* path, double x, double y, double w, double h);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_CloseSubpath(wxd_GraphicsPath_t* path);
+
+WXD_EXPORTED void
+wxd_GraphicsPath_GetBox(const wxd_GraphicsPath_t* path, double* x, double* y, double* w, double* h);
+
+WXD_EXPORTED bool
+wxd_GraphicsPath_Contains(const wxd_GraphicsPath_t* path, double x, double y, int fill_style);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_GRAPHICS_H
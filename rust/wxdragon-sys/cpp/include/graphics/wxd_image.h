@@ -0,0 +1,103 @@
+#ifndef WXD_IMAGE_H
+#define WXD_IMAGE_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// --- Image Functions ---
+// wxImage is device independent and can be used before a wxApp exists.
+// Functions returning wxd_Image_t* return a new image to destroy with
+// wxd_Image_Destroy, or NULL on failure.
+
+// Creates a black image without alpha
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Create(int width, int height);
+
+// Creates an image with alpha from 4 bytes per pixel (R, G, B, A)
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_CreateFromRGBA(const unsigned char* data, int width, int height);
+
+// Loads an image file, detecting its format
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_CreateFromFile(const char* path);
+
+// Decodes an encoded image (PNG, JPEG, ...), detecting its format
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_CreateFromBytes(const unsigned char* data, size_t len);
+
+WXD_EXPORTED void
+wxd_Image_Destroy(wxd_Image_t* image);
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Copy(const wxd_Image_t* image);
+
+WXD_EXPORTED bool
+wxd_Image_IsOk(const wxd_Image_t* image);
+
+WXD_EXPORTED int
+wxd_Image_GetWidth(const wxd_Image_t* image);
+
+WXD_EXPORTED int
+wxd_Image_GetHeight(const wxd_Image_t* image);
+
+WXD_EXPORTED bool
+wxd_Image_HasAlpha(const wxd_Image_t* image);
+
+// Copies the pixels as RGBA (opaque if the image has no alpha) into `buffer`,
+// which must hold width * height * 4 bytes
+WXD_EXPORTED bool
+wxd_Image_GetRGBAData(const wxd_Image_t* image, unsigned char* buffer, size_t buffer_len);
+
+// quality is one of the WXD_IMAGE_QUALITY_* constants
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Scale(const wxd_Image_t* image, int width, int height, int quality);
+
+// Places the image at (x, y) on a new width x height canvas. The uncovered area
+// is filled with (r, g, b), or made transparent if any of them is negative.
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Resize(const wxd_Image_t* image, int width, int height, int x, int y, int r, int g, int b);
+
+// Angle in radians, counter-clockwise as in wxImage::Rotate; the corners uncovered by the
+// rotation are transparent
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Rotate(const wxd_Image_t* image, double angle, int center_x, int center_y);
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Rotate90(const wxd_Image_t* image, bool clockwise);
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Rotate180(const wxd_Image_t* image);
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Mirror(const wxd_Image_t* image, bool horizontally);
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_ConvertToGreyscale(const wxd_Image_t* image);
+
+// Sets the alpha of every pixel, adding an alpha channel if needed
+WXD_EXPORTED void
+wxd_Image_SetAlpha(wxd_Image_t* image, unsigned char alpha);
+
+// Returns false if the pixel is outside the image
+WXD_EXPORTED bool
+wxd_Image_GetPixel(const wxd_Image_t* image, int x, int y, wxd_Colour_t* colour);
+
+// Adds an alpha channel if the colour isn't opaque; false if the pixel is outside the image
+WXD_EXPORTED bool
+wxd_Image_SetPixel(wxd_Image_t* image, int x, int y, wxd_Colour_t colour);
+
+// Conversion to and from bitmaps
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Image_ToBitmap(const wxd_Image_t* image);
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Bitmap_ToImage(const wxd_Bitmap_t* bitmap);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_IMAGE_H
@@ -35,6 +35,17 @@ WXD_EXPORTED bool
 wxd_NotificationMessage_AddAction(wxd_NotificationMessage_t* self, wxd_Id actionid,
                                   const char* label);
 
+// Makes native notifications on Windows come from this tray icon (NULL to stop).
+// Returns false on other platforms, where it has no effect.
+WXD_EXPORTED bool
+wxd_NotificationMessage_UseTaskBarIcon(wxd_TaskBarIcon_t* icon);
+
+// Enables toast notifications on Windows, creating or updating the Start menu shortcut
+// (NULL for the default one) and setting the AppUserModelID (NULL for the default).
+// Returns false if toasts are unavailable or on other platforms.
+WXD_EXPORTED bool
+wxd_NotificationMessage_UseToasts(const char* shortcut_path, const char* app_id);
+
 // Event binding for wxNotificationMessage events (wxEVT_NOTIFICATION_MESSAGE_CLICK, etc.)
 // is handled by binding to a parent wxEvtHandler (e.g., the parent window or frame)
 // using the standard wxd_EvtHandler_Bind and the WXDEventTypeCEnum values for these events.
@@ -7,6 +7,12 @@
 extern "C" {
 #endif
 
+// Creates the bitmap for a custom provider, or returns NULL to let the next provider
+// handle the request. id and client are the wxART_* strings. The returned bitmap is
+// owned by the caller.
+typedef wxd_Bitmap_t* (*wxd_ArtProvider_CreateBitmapCallback)(void* user_data, const char* id,
+                                                               const char* client, wxd_Size size);
+
 // --- ArtProvider Functions ---
 // Getting bitmaps and bundles
 WXD_EXPORTED wxd_Bitmap_t*
@@ -32,6 +38,17 @@ wxd_ArtProvider_GetNativeSizeHint(const char* client, wxd_Window_t* window);
 WXD_EXPORTED bool
 wxd_ArtProvider_HasNativeProvider(void);
 
+// Custom providers
+// Pushes a provider on top of the stack, so it is asked first. user_data is freed with
+// wxd_Drop_Rust_ArtProviderCallback when the provider is removed or at shutdown.
+WXD_EXPORTED wxd_ArtProvider_t*
+wxd_ArtProvider_Push(wxd_ArtProvider_CreateBitmapCallback callback, void* user_data);
+
+// Removes and destroys a provider added with wxd_ArtProvider_Push; false if it was
+// already removed
+WXD_EXPORTED bool
+wxd_ArtProvider_Delete(wxd_ArtProvider_t* provider);
+
 #ifdef __cplusplus
 }
 #endif
@@ -20,6 +20,16 @@ wxd_AuiManager_GetManagedWindow(wxd_AuiManager_t* self);
 WXD_EXPORTED void
 wxd_AuiManager_UnInit(wxd_AuiManager_t* self);
 
+WXD_EXPORTED wxd_EvtHandler_t*
+wxd_AuiManager_GetEvtHandler(wxd_AuiManager_t* self);
+
+/**
+ * UnInit the manager if it is still attached to its managed window and free it once the
+ * event being processed is done. Meant for the managed window's destroy event.
+ */
+WXD_EXPORTED void
+wxd_AuiManager_DestroyLater(wxd_AuiManager_t* self);
+
 WXD_EXPORTED bool
 wxd_AuiManager_AddPane(wxd_AuiManager_t* self, wxd_Window_t* window, int direction,
                        const char* caption);
@@ -45,12 +55,66 @@ wxd_AuiManager_Delete(wxd_AuiManager_t* self);
 WXD_EXPORTED int
 wxd_AuiManager_SavePerspective(const wxd_AuiManager_t* self, char* out, size_t out_len);
 
+/**
+ * Restore a layout saved with wxd_AuiManager_SavePerspective. Entries for panes that no
+ * longer exist are ignored, and panes missing from the perspective keep their settings.
+ */
 WXD_EXPORTED bool
 wxd_AuiManager_LoadPerspective(wxd_AuiManager_t* self, const char* perspective, bool update);
 
+/**
+ * Serialize the settings of the pane named `name`, with the same buffer contract as
+ * wxd_AuiManager_SavePerspective. Returns -1 if there is no such pane.
+ */
+WXD_EXPORTED int
+wxd_AuiManager_SavePaneInfo(wxd_AuiManager_t* self, const char* name, char* out, size_t out_len);
+
+WXD_EXPORTED bool
+wxd_AuiManager_LoadPaneInfo(wxd_AuiManager_t* self, const char* name, const char* info);
+
 WXD_EXPORTED bool
 wxd_AuiManager_DetachPane(wxd_AuiManager_t* self, wxd_Window_t* window);
 
+// Panes by name. Changes take effect on the next wxd_AuiManager_Update.
+WXD_EXPORTED bool
+wxd_AuiManager_HasPane(wxd_AuiManager_t* self, const char* name);
+
+WXD_EXPORTED wxd_Window_t*
+wxd_AuiManager_GetPaneWindow(wxd_AuiManager_t* self, const char* name);
+
+WXD_EXPORTED bool
+wxd_AuiManager_ShowPane(wxd_AuiManager_t* self, const char* name, bool show);
+
+WXD_EXPORTED bool
+wxd_AuiManager_IsPaneShown(wxd_AuiManager_t* self, const char* name);
+
+WXD_EXPORTED bool
+wxd_AuiManager_FloatPane(wxd_AuiManager_t* self, const char* name);
+
+WXD_EXPORTED bool
+wxd_AuiManager_DockPane(wxd_AuiManager_t* self, const char* name);
+
+WXD_EXPORTED bool
+wxd_AuiManager_IsPaneFloating(wxd_AuiManager_t* self, const char* name);
+
+WXD_EXPORTED bool
+wxd_AuiManager_SetPaneCaption(wxd_AuiManager_t* self, const char* name, const char* caption);
+
+// --- wxAuiManagerEvent ---
+/**
+ * Copies the name of the event's pane, with the same buffer contract as
+ * wxd_AuiManager_SavePerspective. Returns -1 if the event has no pane.
+ */
+WXD_EXPORTED int
+wxd_AuiManagerEvent_GetPaneName(wxd_Event_t* event, char* out, size_t out_len);
+
+WXD_EXPORTED wxd_Window_t*
+wxd_AuiManagerEvent_GetPaneWindow(wxd_Event_t* event);
+
+/** The DC a render event paints the dock area with, or NULL for other events. */
+WXD_EXPORTED wxd_DC_t*
+wxd_AuiManagerEvent_GetDC(wxd_Event_t* event);
+
 // --- wxAuiPaneInfo ---
 WXD_EXPORTED wxd_AuiPaneInfo_t*
 wxd_AuiPaneInfo_Create();
@@ -206,10 +270,9 @@ WXD_EXPORTED wxd_AuiToolBar_t*
 wxd_AuiToolBar_Create(wxd_Window_t* parent, int id, wxd_Point pos, wxd_Size size, int64_t style);
 
 WXD_EXPORTED void
-wxd_AuiToolBar_AddTool(
-    wxd_AuiToolBar_t* self, int tool_id, const char* label,
-    /*wxd_Bitmap_t* bitmap, wxd_Bitmap_t* disabled_bitmap,*/ const char* short_help_string,
-    WXDItemKindCEnum kind);
+wxd_AuiToolBar_AddTool(wxd_AuiToolBar_t* self, int tool_id, const char* label,
+                       const wxd_BitmapBundle_t* bitmap, const char* short_help_string,
+                       WXDItemKindCEnum kind);
 
 WXD_EXPORTED void
 wxd_AuiToolBar_AddLabel(wxd_AuiToolBar_t* self, int tool_id, const char* label, int width);
@@ -268,6 +331,28 @@ wxd_AuiToolBar_ClearTools(wxd_AuiToolBar_t* self);
 WXD_EXPORTED bool
 wxd_AuiToolBar_DeleteTool(wxd_AuiToolBar_t* self, int tool_id);
 
+WXD_EXPORTED void
+wxd_AuiToolBar_ToggleTool(wxd_AuiToolBar_t* self, int tool_id, bool state);
+
+WXD_EXPORTED bool
+wxd_AuiToolBar_GetToolToggled(wxd_AuiToolBar_t* self, int tool_id);
+
+WXD_EXPORTED void
+wxd_AuiToolBar_SetToolShortHelp(wxd_AuiToolBar_t* self, int tool_id, const char* help_string);
+
+WXD_EXPORTED void
+wxd_AuiToolBar_SetToolSticky(wxd_AuiToolBar_t* self, int tool_id, bool sticky);
+
+// --- wxAuiToolBarEvent ---
+WXD_EXPORTED bool
+wxd_AuiToolBarEvent_IsDropDownClicked(wxd_Event_t* event);
+
+WXD_EXPORTED wxd_Rect
+wxd_AuiToolBarEvent_GetItemRect(wxd_Event_t* event);
+
+WXD_EXPORTED wxd_Point
+wxd_AuiToolBarEvent_GetClickPoint(wxd_Event_t* event);
+
 #ifdef __cplusplus
 }
 #endif
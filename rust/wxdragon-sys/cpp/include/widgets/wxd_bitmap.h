@@ -7,6 +7,10 @@
 WXD_EXPORTED wxd_Bitmap_t*
 wxd_Bitmap_CreateFromRGBA(const unsigned char* data, int width, int height);
 
+// Creates an opaque bitmap from 3 bytes per pixel (R, G, B)
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_CreateFromRGB(const unsigned char* data, int width, int height);
+
 WXD_EXPORTED void
 wxd_Bitmap_Destroy(wxd_Bitmap_t* bitmap);
 
@@ -22,6 +26,13 @@ wxd_Bitmap_IsOk(const wxd_Bitmap_t* bitmap);
 WXD_EXPORTED wxd_Bitmap_t*
 wxd_Bitmap_Clone(const wxd_Bitmap_t* bitmap);
 
+WXD_EXPORTED bool
+wxd_Bitmap_HasAlpha(const wxd_Bitmap_t* bitmap);
+
+// Copies the given rectangle into a new bitmap; NULL if it is outside the bitmap
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_GetSubBitmap(const wxd_Bitmap_t* bitmap, int x, int y, int width, int height);
+
 // Extract RGBA data from bitmap
 WXD_EXPORTED unsigned char*
 wxd_Bitmap_GetRGBAData(const wxd_Bitmap_t* bitmap, size_t* width, size_t* height);
@@ -29,6 +40,32 @@ wxd_Bitmap_GetRGBAData(const wxd_Bitmap_t* bitmap, size_t* width, size_t* height
 WXD_EXPORTED void
 wxd_Bitmap_FreeRGBAData(unsigned char* data);
 
+// --- Loading and saving ---
+// Failures are returned without showing wxWidgets error popups.
+
+// True if an image handler for the type is available (always true for ANY)
+WXD_EXPORTED bool
+wxd_Bitmap_IsTypeSupported(wxd_BitmapType type);
+
+// Loads an image file, detecting its format
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_CreateFromFile(const char* path);
+
+// Decodes an encoded image (PNG, JPEG, ...), detecting its format
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_CreateFromBytes(const unsigned char* data, size_t len);
+
+// quality is the JPEG quality (0-100); negative for the default
+WXD_EXPORTED bool
+wxd_Bitmap_SaveFile(const wxd_Bitmap_t* bitmap, const char* path, wxd_BitmapType type, int quality);
+
+// Returns the encoded image to free with wxd_Bitmap_FreeEncodedData, NULL on failure
+WXD_EXPORTED unsigned char*
+wxd_Bitmap_Encode(const wxd_Bitmap_t* bitmap, wxd_BitmapType type, int quality, size_t* len);
+
+WXD_EXPORTED void
+wxd_Bitmap_FreeEncodedData(unsigned char* data);
+
 // Get a pointer to wxNullBitmap
 WXD_EXPORTED const wxd_Bitmap_t*
 wxd_Bitmap_GetNull(void);
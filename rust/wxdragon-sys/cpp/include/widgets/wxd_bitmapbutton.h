@@ -6,11 +6,11 @@
 // --- BitmapButton Functions ---
 WXD_EXPORTED wxd_BitmapButton_t*
 wxd_BitmapButton_Create(wxd_Window_t* parent, wxd_Id id,
-                        const wxd_Bitmap_t* bitmap, // Main bitmap (normal state)
+                        const wxd_BitmapBundle_t* bitmap, // Main bitmap (normal state)
                         wxd_Point pos, wxd_Size size, wxd_Style_t style, const char* name,
-                        const wxd_Bitmap_t* bitmap_disabled, // Disabled state bitmap (can be NULL)
-                        const wxd_Bitmap_t* bitmap_focus,    // Focus state bitmap (can be NULL)
-                        const wxd_Bitmap_t* bitmap_hover     // Hover state bitmap (can be NULL)
+                        const wxd_BitmapBundle_t* bitmap_disabled, // Disabled state bitmap (can be NULL)
+                        const wxd_BitmapBundle_t* bitmap_focus,    // Focus state bitmap (can be NULL)
+                        const wxd_BitmapBundle_t* bitmap_hover     // Hover state bitmap (can be NULL)
 );
 
 // --- Setters for individual bitmaps after creation ---
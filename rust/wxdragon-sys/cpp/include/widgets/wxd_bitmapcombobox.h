@@ -14,6 +14,9 @@ wxd_BitmapComboBox_Append(wxd_BitmapComboBox_t* self, const char* item, const wx
 WXD_EXPORTED void
 wxd_BitmapComboBox_Insert(wxd_BitmapComboBox_t* self, const char* item, const wxd_Bitmap_t* bitmap, unsigned int pos);
 
+WXD_EXPORTED void
+wxd_BitmapComboBox_Delete(wxd_BitmapComboBox_t* self, unsigned int pos);
+
 WXD_EXPORTED void
 wxd_BitmapComboBox_Clear(wxd_BitmapComboBox_t* self);
 
@@ -43,4 +46,8 @@ WXD_EXPORTED void
 wxd_BitmapComboBox_SetItemBitmap(wxd_BitmapComboBox_t* self, unsigned int n,
                                  const wxd_Bitmap_t* bitmap);
 
+// Size all item bitmaps are scaled to, fixed by the first one added; -1 x -1 before that.
+WXD_EXPORTED wxd_Size
+wxd_BitmapComboBox_GetBitmapSize(wxd_BitmapComboBox_t* self);
+
 #endif // WXD_BITMAPCOMBOBOX_H
\ No newline at end of file
@@ -0,0 +1,69 @@
+#ifndef WXD_BOOKCTRL_H
+#define WXD_BOOKCTRL_H
+
+#include "../wxd_types.h"
+
+// --- Choicebook, Listbook and Toolbook ---
+// These share the wxBookCtrlBase functions below, which take the book control as a window.
+WXD_EXPORTED wxd_Window_t*
+wxd_Choicebook_new(wxd_Window_t* parent, int id, int x, int y, int width, int height,
+                   wxd_Style_t style);
+
+WXD_EXPORTED wxd_Window_t*
+wxd_Listbook_new(wxd_Window_t* parent, int id, int x, int y, int width, int height,
+                 wxd_Style_t style);
+
+WXD_EXPORTED wxd_Window_t*
+wxd_Toolbook_new(wxd_Window_t* parent, int id, int x, int y, int width, int height,
+                 wxd_Style_t style);
+
+// --- wxBookCtrlBase Functions ---
+WXD_EXPORTED bool
+wxd_BookCtrl_AddPage(wxd_Window_t* self, wxd_Window_t* page, const char* text, bool select,
+                     int imageId);
+
+WXD_EXPORTED bool
+wxd_BookCtrl_InsertPage(wxd_Window_t* self, size_t n, wxd_Window_t* page, const char* text,
+                        bool select, int imageId);
+
+// Removes the page and destroys its window
+WXD_EXPORTED bool
+wxd_BookCtrl_DeletePage(wxd_Window_t* self, size_t n);
+
+// Removes the page without destroying its window
+WXD_EXPORTED bool
+wxd_BookCtrl_RemovePage(wxd_Window_t* self, size_t n);
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetPageCount(wxd_Window_t* self);
+
+WXD_EXPORTED wxd_Window_t*
+wxd_BookCtrl_GetPage(wxd_Window_t* self, size_t n);
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetSelection(wxd_Window_t* self);
+
+WXD_EXPORTED int
+wxd_BookCtrl_SetSelection(wxd_Window_t* self, size_t n);
+
+// Like SetSelection, but without page changing and changed events
+WXD_EXPORTED int
+wxd_BookCtrl_ChangeSelection(wxd_Window_t* self, size_t n);
+
+WXD_EXPORTED void
+wxd_BookCtrl_SetPageText(wxd_Window_t* self, size_t n, const char* text);
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetPageText(wxd_Window_t* self, size_t n, char* buffer, size_t bufferLen);
+
+// Takes ownership of the image list
+WXD_EXPORTED void
+wxd_BookCtrl_AssignImageList(wxd_Window_t* self, wxd_ImageList_t* imageList);
+
+WXD_EXPORTED bool
+wxd_BookCtrl_SetPageImage(wxd_Window_t* self, size_t n, int imageId);
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetPageImage(wxd_Window_t* self, size_t n);
+
+#endif // WXD_BOOKCTRL_H
@@ -48,4 +48,15 @@ wxd_CheckListBox_IsChecked(wxd_CheckListBox_t* clbox, unsigned int index);
 WXD_EXPORTED void
 wxd_CheckListBox_Check(wxd_CheckListBox_t* clbox, unsigned int index, bool check);
 
+// Fills `items` with up to max_items indices of checked items and returns the
+// number of checked items, which may be more than max_items.
+WXD_EXPORTED size_t
+wxd_CheckListBox_GetCheckedItems(wxd_CheckListBox_t* clbox, unsigned int* items, size_t max_items);
+// Checks the given items and unchecks all others. Like Check, sends no events.
+WXD_EXPORTED void
+wxd_CheckListBox_SetCheckedItems(wxd_CheckListBox_t* clbox, const unsigned int* items, size_t count);
+// Checks or unchecks all items. Like Check, sends no events.
+WXD_EXPORTED void
+wxd_CheckListBox_CheckAll(wxd_CheckListBox_t* clbox, bool check);
+
 #endif // WXD_CHECKLISTBOX_H
\ No newline at end of file
@@ -0,0 +1,58 @@
+#ifndef WXD_COMBOCTRL_H
+#define WXD_COMBOCTRL_H
+
+#include "../wxd_types.h"
+
+// --- ComboCtrl Functions ---
+WXD_EXPORTED wxd_ComboCtrl_t*
+wxd_ComboCtrl_Create(wxd_Window_t* parent, wxd_Id id, const char* value, wxd_Point pos,
+                     wxd_Size size, wxd_Style_t style);
+
+// Empty the popup panel, destroying the previous content and callbacks, and
+// return it for the caller to create the popup content in. A combo not created
+// by wxd_ComboCtrl_Create, e.g. loaded from XRC, gets such a popup here.
+WXD_EXPORTED wxd_Window_t*
+wxd_ComboCtrl_SetPopupContent(wxd_ComboCtrl_t* combo);
+
+// Sink handed to get_string_value for the text shown in the combo; the text is
+// copied, so it need only outlive the call.
+typedef void (*wxd_ComboPopupStringSink)(void* sink, const char* value);
+
+typedef struct wxd_ComboPopup_vtable {
+    // Pass the text to show in the combo when the popup is dismissed to
+    // emit(sink, value). Not passing anything keeps the current text.
+    void (*get_string_value)(void* user_data, void* sink, wxd_ComboPopupStringSink emit);
+
+    // Release user_data. Called when the popup is destroyed or gets other callbacks.
+    void (*destroy)(void* user_data);
+} wxd_ComboPopup_vtable;
+
+// Set the callbacks of the popup, replacing the ones set before. The vtable is
+// copied; `user_data` is owned by the popup and released via vtable.destroy,
+// also if this call fails because the combo has no wxDragon popup.
+WXD_EXPORTED bool
+wxd_ComboCtrl_SetPopupCallbacks(wxd_ComboCtrl_t* combo, const wxd_ComboPopup_vtable* vtable,
+                                void* user_data);
+
+// Size of the popup; a width or height of -1 keeps the default, the combo's
+// width and the content's best height. Returns false if the combo has no
+// wxDragon popup yet, see wxd_ComboCtrl_SetPopupContent.
+WXD_EXPORTED bool
+wxd_ComboCtrl_SetPopupSize(wxd_ComboCtrl_t* combo, wxd_Size size);
+
+WXD_EXPORTED void
+wxd_ComboCtrl_SetValue(wxd_ComboCtrl_t* combo, const char* value);
+
+WXD_EXPORTED int
+wxd_ComboCtrl_GetValue(wxd_ComboCtrl_t* combo, char* buffer, size_t buffer_len);
+
+WXD_EXPORTED void
+wxd_ComboCtrl_ShowPopup(wxd_ComboCtrl_t* combo);
+
+WXD_EXPORTED void
+wxd_ComboCtrl_DismissPopup(wxd_ComboCtrl_t* combo);
+
+WXD_EXPORTED bool
+wxd_ComboCtrl_IsPopupShown(wxd_ComboCtrl_t* combo);
+
+#endif // WXD_COMBOCTRL_H
@@ -195,6 +195,11 @@ wxd_DataViewCtrl_GetSelectedRow(wxd_Window_t* self);
 WXD_EXPORTED void
 wxd_DataViewCtrl_UnselectAll(wxd_Window_t* self);
 
+// Drag and drop: lets items be dragged out of the control, offering the given format to
+// the drop target; a NULL or empty format offers text. Data is set on the begin-drag event
+WXD_EXPORTED bool
+wxd_DataViewCtrl_EnableDragSource(wxd_Window_t* self, const char* format);
+
 // DataViewVirtualListModel functions
 WXD_EXPORTED wxd_DataViewModel_t*
 wxd_DataViewVirtualListModel_Create(uint64_t initial_size);
@@ -348,7 +353,11 @@ WXD_EXPORTED void
 wxd_DataViewColumn_SetSortable(wxd_DataViewColumn_t* self, bool sortable);
 WXD_EXPORTED bool
 wxd_DataViewColumn_IsSortable(wxd_DataViewColumn_t* self);
-// TODO: Add other properties like Reorderable, Hidden, Alignment, Width etc. as needed
+WXD_EXPORTED void
+wxd_DataViewColumn_SetHidden(wxd_DataViewColumn_t* self, bool hidden);
+WXD_EXPORTED bool
+wxd_DataViewColumn_IsHidden(wxd_DataViewColumn_t* self);
+// TODO: Add other properties like Reorderable, Alignment, Width etc. as needed
 
 // Custom Renderer Callbacks
 typedef struct {
@@ -22,6 +22,18 @@ wxd_Window_t*
 wxd_EditableListBox_New(wxd_Window_t* parent, int id, const char* label, int x, int y, int width,
                         int height, int64_t style);
 
+/**
+ * @brief Send changed events for a listbox not created by wxd_EditableListBox_New
+ *
+ * Listboxes created by wxd_EditableListBox_New are tracked already. The event is
+ * sent after the user added, edited, deleted or moved an item, not for changes
+ * made with wxd_EditableListBox_SetStrings or wxd_EditableListBox_AddString.
+ *
+ * @param self Pointer to wxEditableListBox
+ */
+void
+wxd_EditableListBox_TrackChanges(wxd_Window_t* self);
+
 /**
  * @brief Set the string items in the listbox
  * 
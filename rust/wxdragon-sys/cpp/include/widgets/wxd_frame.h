@@ -47,6 +47,19 @@ wxd_Frame_CenterOnScreen(wxd_Frame_t* frame);
 WXD_EXPORTED void
 wxd_Frame_SetStatusText(wxd_Frame_t* frame, const char* text, int number);
 
+WXD_EXPORTED void
+wxd_Frame_PushStatusText(wxd_Frame_t* frame, const char* text, int number);
+
+WXD_EXPORTED void
+wxd_Frame_PopStatusText(wxd_Frame_t* frame, int number);
+
+// Field showing the help text of highlighted menu items and tools; -1 for none.
+WXD_EXPORTED void
+wxd_Frame_SetStatusBarPane(wxd_Frame_t* frame, int pane);
+
+WXD_EXPORTED int
+wxd_Frame_GetStatusBarPane(wxd_Frame_t* frame);
+
 WXD_EXPORTED char*
 wxd_Frame_GetTitle(wxd_Frame_t* frame);
 
@@ -65,6 +78,11 @@ wxd_Frame_IsMaximized(wxd_Frame_t* frame);
 WXD_EXPORTED void
 wxd_Frame_SetIconFromBitmap(wxd_Frame_t* frame, const wxd_Bitmap_t* bitmap);
 
+// Sets the frame icons from a bundle, rendered at the standard icon sizes so the
+// title bar, taskbar and task switcher each get a sharp image
+WXD_EXPORTED void
+wxd_Frame_SetIcons(wxd_Frame_t* frame, const wxd_BitmapBundle_t* bundle);
+
 WXD_EXPORTED void
 wxd_Frame_RequestUserAttention(wxd_Frame_t* frame, int flags);
 
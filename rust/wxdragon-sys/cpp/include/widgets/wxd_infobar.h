@@ -0,0 +1,42 @@
+#ifndef WXD_INFOBAR_H
+#define WXD_INFOBAR_H
+
+#include "../wxd_types.h"
+
+// --- InfoBar Functions ---
+// The info bar starts hidden; it shows itself in ShowMessage.
+WXD_EXPORTED wxd_InfoBar_t*
+wxd_InfoBar_Create(wxd_Window_t* parent, wxd_Id id);
+
+// `flags` is one of the WXD_ICON_* constants, or 0 for no icon.
+WXD_EXPORTED void
+wxd_InfoBar_ShowMessage(wxd_InfoBar_t* self, const char* msg, int flags);
+
+WXD_EXPORTED void
+wxd_InfoBar_Dismiss(wxd_InfoBar_t* self);
+
+// An empty label uses the stock label of a stock id. Clicking a button sends
+// wxEVT_BUTTON with its id and dismisses the bar unless the event is handled.
+WXD_EXPORTED void
+wxd_InfoBar_AddButton(wxd_InfoBar_t* self, wxd_Id btnid, const char* label);
+
+WXD_EXPORTED void
+wxd_InfoBar_RemoveButton(wxd_InfoBar_t* self, wxd_Id btnid);
+
+WXD_EXPORTED size_t
+wxd_InfoBar_GetButtonCount(wxd_InfoBar_t* self);
+
+WXD_EXPORTED bool
+wxd_InfoBar_HasButtonId(wxd_InfoBar_t* self, wxd_Id btnid);
+
+// Effects are wxd_ShowEffect values; they only apply where the info bar isn't native (not GTK).
+WXD_EXPORTED void
+wxd_InfoBar_SetShowHideEffects(wxd_InfoBar_t* self, int showEffect, int hideEffect);
+
+WXD_EXPORTED void
+wxd_InfoBar_SetEffectDuration(wxd_InfoBar_t* self, int duration);
+
+WXD_EXPORTED int
+wxd_InfoBar_GetEffectDuration(wxd_InfoBar_t* self);
+
+#endif // WXD_INFOBAR_H
@@ -38,4 +38,24 @@ wxd_ListBox_EnsureVisible(wxd_ListBox_t* listbox, int index);
 WXD_EXPORTED bool
 wxd_ListBox_PopupMenu(wxd_ListBox_t* listbox, wxd_Menu_t* menu, wxd_Point pos);
 
+// Fills `selections` with up to max_items selected indices and returns the
+// number of selected items, which may be more than max_items.
+WXD_EXPORTED size_t
+wxd_ListBox_GetSelections(wxd_ListBox_t* listbox, int* selections, size_t max_items);
+// Index of the item with the given label, or -1 if there is none.
+WXD_EXPORTED int
+wxd_ListBox_FindString(wxd_ListBox_t* listbox, const char* text, bool case_sensitive);
+WXD_EXPORTED void
+wxd_ListBox_SetFirstItem(wxd_ListBox_t* listbox, int index);
+// Index of the item at `point`, in client coordinates, or -1 if there is none.
+WXD_EXPORTED int
+wxd_ListBox_HitTest(wxd_ListBox_t* listbox, wxd_Point point);
+
+// Item client data: an opaque number, 0 meaning none. wxDragon stores the id
+// of the Rust data attached to the item.
+WXD_EXPORTED void
+wxd_ListBox_SetClientData(wxd_ListBox_t* listbox, unsigned int n, uint64_t data);
+WXD_EXPORTED uint64_t
+wxd_ListBox_GetClientData(wxd_ListBox_t* listbox, unsigned int n);
+
 #endif // WXD_LISTBOX_H
\ No newline at end of file
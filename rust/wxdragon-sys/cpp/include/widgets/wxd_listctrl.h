@@ -6,6 +6,22 @@
 typedef char* (*wxd_listctrl_virtual_text_callback)(void* userdata, int64_t item, int32_t col);
 typedef void (*wxd_listctrl_free_string_callback)(char* text);
 typedef void (*wxd_listctrl_free_userdata_callback)(void* userdata);
+typedef int32_t (*wxd_listctrl_virtual_image_callback)(void* userdata, int64_t item);
+
+// Appearance of a row of a virtual list; colours are used only when their
+// has_ flag is set, and bold/italic apply to the control's font.
+typedef struct wxd_ListItemAttr {
+    bool has_text_colour;
+    wxd_Colour_t text_colour;
+    bool has_background_colour;
+    wxd_Colour_t background_colour;
+    bool bold;
+    bool italic;
+} wxd_ListItemAttr;
+
+// Fill `attr` for `item` and return true, or return false for the default look.
+typedef bool (*wxd_listctrl_virtual_attr_callback)(void* userdata, int64_t item,
+                                                   wxd_ListItemAttr* attr);
 
 // --- ListCtrl Functions ---
 WXD_EXPORTED wxd_ListCtrl_t*
@@ -98,12 +114,27 @@ wxd_ListCtrl_SetVirtualTextCallback(wxd_ListCtrl_t* self, void* userdata,
                                     wxd_listctrl_free_userdata_callback free_userdata);
 WXD_EXPORTED void
 wxd_ListCtrl_ClearVirtualTextCallback(wxd_ListCtrl_t* self);
+// Like the text callback, the image and attribute callbacks own `userdata` once
+// set and release it via free_userdata when replaced or the control is destroyed.
+WXD_EXPORTED bool
+wxd_ListCtrl_SetVirtualImageCallback(wxd_ListCtrl_t* self, void* userdata,
+                                     wxd_listctrl_virtual_image_callback callback,
+                                     wxd_listctrl_free_userdata_callback free_userdata);
+WXD_EXPORTED bool
+wxd_ListCtrl_SetVirtualAttrCallback(wxd_ListCtrl_t* self, void* userdata,
+                                    wxd_listctrl_virtual_attr_callback callback,
+                                    wxd_listctrl_free_userdata_callback free_userdata);
 
 // Sorting
 WXD_EXPORTED bool
 wxd_ListCtrl_SortItems(wxd_ListCtrl_t* self, int (*cmpFunc)(void*, void*, void*), void* data);
 WXD_EXPORTED void
 wxd_ListCtrl_ShowSortIndicator(wxd_ListCtrl_t* self, int col, bool ascending);
+WXD_EXPORTED void
+wxd_ListCtrl_RemoveSortIndicator(wxd_ListCtrl_t* self);
+// Column showing the sort indicator, or -1 if none; `ascending` may be null.
+WXD_EXPORTED int
+wxd_ListCtrl_GetSortIndicator(wxd_ListCtrl_t* self, bool* ascending);
 
 // Image List Support
 WXD_EXPORTED void
@@ -311,7 +311,7 @@ wxd_MenuItem_GetSubMenu(const wxd_MenuItem_t* item);
  * @brief Set the bitmap for the menu item.
  */
 WXD_EXPORTED void
-wxd_MenuItem_SetBitmap(wxd_MenuItem_t* item, const wxd_Bitmap_t* bitmap);
+wxd_MenuItem_SetBitmap(wxd_MenuItem_t* item, const wxd_BitmapBundle_t* bitmap);
 
 /**
  * @brief Get the bitmap associated with the menu item.
@@ -57,4 +57,40 @@ WXD_EXPORTED bool
 wxd_Notebook_InsertPage(wxd_Notebook_t* self, size_t index, wxd_Window_t* page, const char* text,
                         bool select);
 
+// Keep the old tab selected after a vetoed page change, see notebook.cpp. Done by
+// wxd_Notebook_Create; call it for notebooks created otherwise, e.g. from XRC.
+WXD_EXPORTED void
+wxd_Notebook_GuardVetoes(wxd_Notebook_t* self);
+
+WXD_EXPORTED bool
+wxd_Notebook_DeletePage(wxd_Notebook_t* self, size_t n);
+WXD_EXPORTED bool
+wxd_Notebook_DeleteAllPages(wxd_Notebook_t* self);
+WXD_EXPORTED int
+wxd_Notebook_FindPage(wxd_Notebook_t* self, wxd_Window_t* page);
+
+WXD_EXPORTED bool
+wxd_Notebook_SetPageText(wxd_Notebook_t* self, size_t n, const char* text);
+WXD_EXPORTED int
+wxd_Notebook_GetPageText(wxd_Notebook_t* self, size_t n, char* buffer, size_t buffer_len);
+WXD_EXPORTED bool
+wxd_Notebook_SetPageImage(wxd_Notebook_t* self, size_t n, int imageId);
+WXD_EXPORTED int
+wxd_Notebook_GetPageImage(wxd_Notebook_t* self, size_t n);
+
+// Move the page at `from` to `to`, its index afterwards. The page window and the
+// selected page are kept and no page change events are sent.
+WXD_EXPORTED bool
+wxd_Notebook_MovePage(wxd_Notebook_t* self, size_t from, size_t to);
+
+// Index of the tab at `point`, in notebook coordinates, or -1; `flags` receives
+// the wxBK_HITTEST_* flags.
+WXD_EXPORTED int
+wxd_Notebook_HitTest(wxd_Notebook_t* self, wxd_Point point, int* flags);
+
+// Let the user drag tabs to other positions, which sends
+// WXD_EVENT_TYPE_NOTEBOOK_PAGES_REORDERED after each move.
+WXD_EXPORTED void
+wxd_Notebook_EnableTabDragReorder(wxd_Notebook_t* self, bool enable);
+
 #endif // WXD_NOTEBOOK_H
\ No newline at end of file
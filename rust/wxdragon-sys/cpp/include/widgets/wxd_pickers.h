@@ -87,4 +87,10 @@ wxd_FontPickerCtrl_GetSelectedFont(wxd_FontPickerCtrl_t* self);
 WXD_EXPORTED void
 wxd_FontPickerCtrl_SetSelectedFont(wxd_FontPickerCtrl_t* self, const wxd_Font_t* font);
 
+WXD_EXPORTED void
+wxd_FontPickerCtrl_SetMaxPointSize(wxd_FontPickerCtrl_t* self, unsigned int max);
+
+WXD_EXPORTED unsigned int
+wxd_FontPickerCtrl_GetMaxPointSize(wxd_FontPickerCtrl_t* self);
+
 #endif // WXD_PICKERS_H
\ No newline at end of file
@@ -132,6 +132,30 @@ wxd_RearrangeList_IsChecked(wxd_RearrangeList_t* self, int index);
 void
 wxd_RearrangeList_Check(wxd_RearrangeList_t* self, unsigned int index, bool check);
 
+/**
+ * Creates a wxRearrangeCtrl, a wxRearrangeList with buttons to move the
+ * selected item up and down.
+ *
+ * The parameters are those of wxd_RearrangeList_Create. Moving or checking an
+ * item sends a COMMAND_REARRANGE_LIST or REARRANGE_LIST_CHECKED event from the
+ * list, once its order has been updated.
+ *
+ * @return Pointer to the created control or NULL on failure.
+ */
+wxd_RearrangeCtrl_t*
+wxd_RearrangeCtrl_Create(wxd_Window_t* parent, wxd_Id id, wxd_Point pos, wxd_Size size,
+                         const int* order, int orderCount, const char** items, int itemsCount,
+                         wxd_Style_t style);
+
+/**
+ * Gets the list of a wxRearrangeCtrl.
+ *
+ * @param self The RearrangeCtrl instance.
+ * @return The list, owned by the control, or NULL if self is NULL.
+ */
+wxd_RearrangeList_t*
+wxd_RearrangeCtrl_GetList(wxd_RearrangeCtrl_t* self);
+
 #ifdef __cplusplus
 }
 #endif
@@ -114,6 +114,22 @@ wxd_RichTextCtrl_SetBackgroundColor(wxd_RichTextCtrl_t* self, wxd_Long_t start,
 WXD_EXPORTED bool
 wxd_RichTextCtrl_SetBackgroundColorSelection(wxd_RichTextCtrl_t* self, wxd_Colour_t color);
 
+// Paragraph operations
+WXD_EXPORTED bool
+wxd_RichTextCtrl_ApplyAlignmentToSelection(wxd_RichTextCtrl_t* self, int alignment);
+WXD_EXPORTED bool
+wxd_RichTextCtrl_IsSelectionAligned(wxd_RichTextCtrl_t* self, int alignment);
+WXD_EXPORTED bool
+wxd_RichTextCtrl_ApplyBulletsToSelection(wxd_RichTextCtrl_t* self, bool bullets);
+WXD_EXPORTED bool
+wxd_RichTextCtrl_IsSelectionBulleted(wxd_RichTextCtrl_t* self);
+
+// Content insertion
+WXD_EXPORTED bool
+wxd_RichTextCtrl_WriteImage(wxd_RichTextCtrl_t* self, const wxd_Bitmap_t* bitmap);
+WXD_EXPORTED bool
+wxd_RichTextCtrl_WriteUrl(wxd_RichTextCtrl_t* self, const char* url, const char* text);
+
 // Scroll operations
 WXD_EXPORTED void
 wxd_RichTextCtrl_ShowPosition(wxd_RichTextCtrl_t* self, wxd_Long_t pos);
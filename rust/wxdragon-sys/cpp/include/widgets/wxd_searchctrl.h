@@ -32,9 +32,17 @@ wxd_SearchCtrl_SetValue(const wxd_SearchCtrl_t* self, const char* value);
 WXD_EXPORTED size_t
 wxd_SearchCtrl_GetValue(const wxd_SearchCtrl_t* self, char* buffer, size_t buffer_len);
 
+// Set/Get the hint shown while the control is empty
+WXD_EXPORTED void
+wxd_SearchCtrl_SetDescriptiveText(wxd_SearchCtrl_t* self, const char* text);
+
+WXD_EXPORTED int
+wxd_SearchCtrl_GetDescriptiveText(const wxd_SearchCtrl_t* self, char* buffer, size_t buffer_len);
+
 WXD_EXPORTED wxd_Control_t*
 wxd_SearchCtrl_GetCancelButton(wxd_SearchCtrl_t* self);
 
+// Takes ownership of `menu` (may be null to remove the menu) and deletes the previous one
 WXD_EXPORTED void
 wxd_SearchCtrl_SetMenu(wxd_SearchCtrl_t* self, wxd_Menu_t* menu);
 
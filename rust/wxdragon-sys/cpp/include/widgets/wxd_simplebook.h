@@ -43,4 +43,13 @@ WXD_EXPORTED bool
 wxd_SimpleBook_InsertPageWithImageId(wxd_SimpleBook_t* self, size_t index, wxd_Window_t* page,
                                      const char* text, bool select, int imageId);
 
+// Effects used when pages are shown and hidden, see wxd_ShowEffect; only some are
+// supported natively on each platform, the others show pages without animation
+WXD_EXPORTED void
+wxd_SimpleBook_SetEffects(wxd_SimpleBook_t* self, int showEffect, int hideEffect);
+
+// Duration of the effects in milliseconds, 0 for the platform default
+WXD_EXPORTED void
+wxd_SimpleBook_SetEffectTimeout(wxd_SimpleBook_t* self, unsigned int timeout);
+
 #endif // WXD_SIMPLEBOOK_H
\ No newline at end of file
@@ -23,4 +23,22 @@ wxd_Slider_GetMin(wxd_Slider_t* self);
 WXD_EXPORTED int
 wxd_Slider_GetMax(wxd_Slider_t* self);
 
+WXD_EXPORTED void
+wxd_Slider_SetTickFreq(wxd_Slider_t* self, int freq);
+
+WXD_EXPORTED int
+wxd_Slider_GetTickFreq(wxd_Slider_t* self);
+
+WXD_EXPORTED void
+wxd_Slider_SetPageSize(wxd_Slider_t* self, int pageSize);
+
+WXD_EXPORTED int
+wxd_Slider_GetPageSize(wxd_Slider_t* self);
+
+WXD_EXPORTED void
+wxd_Slider_SetLineSize(wxd_Slider_t* self, int lineSize);
+
+WXD_EXPORTED int
+wxd_Slider_GetLineSize(wxd_Slider_t* self);
+
 #endif // WXD_SLIDER_H
\ No newline at end of file
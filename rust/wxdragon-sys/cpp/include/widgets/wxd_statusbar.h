@@ -22,4 +22,22 @@ wxd_StatusBar_PushStatusText(wxd_StatusBar_t* self, const char* text, int fieldI
 WXD_EXPORTED void
 wxd_StatusBar_PopStatusText(wxd_StatusBar_t* self, int fieldIndex);
 
+// Rectangle of a field relative to the status bar; false if there is no such field.
+WXD_EXPORTED bool
+wxd_StatusBar_GetFieldRect(wxd_StatusBar_t* self, int fieldIndex, wxd_Rect* rect);
+
+// Styles as wxd_StatusBarFieldStyle values, one per field.
+WXD_EXPORTED void
+wxd_StatusBar_SetStatusStyles(wxd_StatusBar_t* self, int count, const int* styles);
+
+WXD_EXPORTED void
+wxd_StatusBar_SetMinHeight(wxd_StatusBar_t* self, int height);
+
+// Keep `widget`, a child of the status bar, covering the field whenever the
+// status bar is resized, replacing a widget set for the field before. A null
+// widget only releases the field. Returns false if there is no such field or
+// the widget isn't a child of the status bar.
+WXD_EXPORTED bool
+wxd_StatusBar_SetFieldWidget(wxd_StatusBar_t* self, int fieldIndex, wxd_Window_t* widget);
+
 #endif // WXD_STATUSBAR_H
\ No newline at end of file
@@ -8,6 +8,10 @@ WXD_EXPORTED wxd_TextCtrl_t*
 wxd_TextCtrl_Create(wxd_Window_t* parent, wxd_Id id, const char* value, wxd_Point pos,
                     wxd_Size size, wxd_Style_t style);
 WXD_EXPORTED void
+wxd_TextCtrl_TrackCaret(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED bool
+wxd_TextCtrl_HitTest(wxd_TextCtrl_t* textCtrl, wxd_Point point, wxd_Long_t* pos);
+WXD_EXPORTED void
 wxd_TextCtrl_SetValue(wxd_TextCtrl_t* textCtrl, const char* value);
 WXD_EXPORTED int
 wxd_TextCtrl_GetValue(wxd_TextCtrl_t* textCtrl, char* buffer, int buffer_len);
@@ -32,6 +36,28 @@ wxd_TextCtrl_IsModified(wxd_TextCtrl_t* textCtrl);
 WXD_EXPORTED void
 wxd_TextCtrl_SetModified(wxd_TextCtrl_t* textCtrl, bool modified);
 WXD_EXPORTED void
+wxd_TextCtrl_MarkDirty(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED void
+wxd_TextCtrl_DiscardEdits(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED bool
+wxd_TextCtrl_LoadFile(wxd_TextCtrl_t* textCtrl, const char* path);
+WXD_EXPORTED bool
+wxd_TextCtrl_SaveFile(wxd_TextCtrl_t* textCtrl, const char* path);
+WXD_EXPORTED bool
+wxd_TextCtrl_CanUndo(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED void
+wxd_TextCtrl_Undo(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED bool
+wxd_TextCtrl_CanRedo(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED void
+wxd_TextCtrl_Redo(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED void
+wxd_TextCtrl_Cut(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED void
+wxd_TextCtrl_Copy(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED void
+wxd_TextCtrl_Paste(wxd_TextCtrl_t* textCtrl);
+WXD_EXPORTED void
 wxd_TextCtrl_SetEditable(wxd_TextCtrl_t* textCtrl, bool editable);
 WXD_EXPORTED bool
 wxd_TextCtrl_IsEditable(wxd_TextCtrl_t* textCtrl);
@@ -146,6 +172,10 @@ WXD_EXPORTED int wxd_TextAttr_GetBulletStyle(wxd_TextAttr_t* attr);
 WXD_EXPORTED void
 wxd_TextCtrl_SetStyle(wxd_TextCtrl_t* textCtrl, wxd_Long_t start, wxd_Long_t end, const wxd_TextAttr_t* style);
 
+/** Returns a new wxTextAttr the caller must delete, or NULL if the style can't be read. */
+WXD_EXPORTED wxd_TextAttr_t*
+wxd_TextCtrl_GetStyle(wxd_TextCtrl_t* textCtrl, wxd_Long_t position);
+
 WXD_EXPORTED wxd_TextAttr_t*
 wxd_TextCtrl_GetDefaultStyle(wxd_TextCtrl_t* textCtrl);
 
@@ -0,0 +1,36 @@
+#ifndef WXD_TEXTENTRY_H
+#define WXD_TEXTENTRY_H
+
+#include "../wxd_types.h"
+
+// --- wxTextEntry functions ---
+// These take any window deriving from wxTextEntry (wxTextCtrl, wxComboBox, ...)
+// and return false for other windows.
+
+WXD_EXPORTED bool
+wxd_TextEntry_AutoComplete(wxd_Window_t* entry, const wxd_ArrayString_t* choices);
+
+WXD_EXPORTED bool
+wxd_TextEntry_AutoCompleteFileNames(wxd_Window_t* entry);
+
+WXD_EXPORTED bool
+wxd_TextEntry_AutoCompleteDirectories(wxd_Window_t* entry);
+
+typedef struct wxd_TextCompleter_vtable {
+    // Append the completions of `prefix` to `out` (a wxArrayString) via
+    // wxd_ArrayString_Add.
+    void (*get_completions)(void* user_data, const char* prefix, wxd_ArrayString_t* out);
+
+    // Release user_data. Called from the completer's destructor.
+    void (*destroy)(void* user_data);
+} wxd_TextCompleter_vtable;
+
+// Complete the entry with candidates computed for each prefix by `vtable`.
+// The vtable is copied; `user_data` is owned by the completer and released
+// via vtable.destroy when the entry drops it, also if this call fails.
+WXD_EXPORTED bool
+wxd_TextEntry_AutoCompleteCustom(wxd_Window_t* entry,
+                                 const wxd_TextCompleter_vtable* vtable,
+                                 void* user_data);
+
+#endif // WXD_TEXTENTRY_H
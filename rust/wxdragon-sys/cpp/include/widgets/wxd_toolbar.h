@@ -10,12 +10,16 @@ wxd_ToolBar_Create(wxd_Window_t* parent, wxd_Id id, wxd_Point pos, wxd_Size size
 
 WXD_EXPORTED void*
 wxd_ToolBar_AddTool(wxd_ToolBar_t* self, wxd_Id toolId, const char* label,
-                    const wxd_Bitmap_t* bitmap, const wxd_Bitmap_t* bitmapDisabled, int kind,
+                    const wxd_BitmapBundle_t* bitmap, const wxd_BitmapBundle_t* bitmapDisabled, int kind,
                     const char* shortHelp, const char* longHelp);
 
 WXD_EXPORTED void
 wxd_ToolBar_AddSeparator(wxd_ToolBar_t* self);
 
+// Space taking up what is left of the toolbar, e.g. to push the following tools to its end
+WXD_EXPORTED void
+wxd_ToolBar_AddStretchableSpace(wxd_ToolBar_t* self);
+
 WXD_EXPORTED void
 wxd_ToolBar_AddControl(wxd_ToolBar_t* self, wxd_Window_t* control);
 
@@ -47,4 +51,57 @@ wxd_ToolBar_AddToolWithBundles(wxd_ToolBar_t* toolbar, wxd_Id id, const char* la
                                wxd_BitmapBundle_t* bitmap, wxd_BitmapBundle_t* bitmapDisabled,
                                const char* shortHelp, const char* longHelp);
 
+// Menu shown by the dropdown arrow of a tool added with kind WXD_ITEM_DROPDOWN,
+// replacing the one set before. Takes ownership of `menu`, deleting it if the
+// tool isn't a dropdown tool.
+WXD_EXPORTED bool
+wxd_ToolBar_SetDropdownMenu(wxd_ToolBar_t* self, wxd_Id toolId, wxd_Menu_t* menu);
+
+// Size of the tool bitmaps in DIPs; set it before adding the tools
+WXD_EXPORTED void
+wxd_ToolBar_SetToolBitmapSize(wxd_ToolBar_t* self, wxd_Size size);
+
+WXD_EXPORTED wxd_Size
+wxd_ToolBar_GetToolBitmapSize(wxd_ToolBar_t* self);
+
+// --- Changing the tools at runtime; call wxd_ToolBar_Realize afterwards ---
+WXD_EXPORTED bool
+wxd_ToolBar_InsertTool(wxd_ToolBar_t* self, size_t pos, wxd_Id toolId, const char* label,
+                       const wxd_BitmapBundle_t* bitmap, const wxd_BitmapBundle_t* bitmapDisabled,
+                       int kind, const char* shortHelp, const char* longHelp);
+
+WXD_EXPORTED bool
+wxd_ToolBar_InsertSeparator(wxd_ToolBar_t* self, size_t pos);
+
+// Remove a tool without destroying it or its control. The toolbar keeps owning
+// the returned tool until it is passed to wxd_ToolBar_InsertRemovedTool or
+// wxd_ToolBar_DestroyRemovedTool, and deletes it when destroyed itself.
+WXD_EXPORTED wxd_ToolBarTool_t*
+wxd_ToolBar_RemoveTool(wxd_ToolBar_t* self, wxd_Id toolId);
+
+WXD_EXPORTED bool
+wxd_ToolBar_InsertRemovedTool(wxd_ToolBar_t* self, size_t pos, wxd_ToolBarTool_t* tool);
+
+WXD_EXPORTED void
+wxd_ToolBar_DestroyRemovedTool(wxd_ToolBar_t* self, wxd_ToolBarTool_t* tool);
+
+WXD_EXPORTED wxd_Id
+wxd_ToolBarTool_GetId(const wxd_ToolBarTool_t* tool);
+
+// Delete a tool, destroying its control if it has one
+WXD_EXPORTED bool
+wxd_ToolBar_DeleteTool(wxd_ToolBar_t* self, wxd_Id toolId);
+
+WXD_EXPORTED bool
+wxd_ToolBar_DeleteToolByPos(wxd_ToolBar_t* self, size_t pos);
+
+WXD_EXPORTED size_t
+wxd_ToolBar_GetToolsCount(wxd_ToolBar_t* self);
+
+WXD_EXPORTED bool
+wxd_ToolBar_HasTool(wxd_ToolBar_t* self, wxd_Id toolId);
+
+WXD_EXPORTED int
+wxd_ToolBar_GetToolPos(wxd_ToolBar_t* self, wxd_Id toolId);
+
 #endif // WXD_TOOLBAR_H
\ No newline at end of file
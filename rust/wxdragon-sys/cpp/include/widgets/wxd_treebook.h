@@ -16,15 +16,50 @@ WXD_EXPORTED int
 wxd_Treebook_AddSubPage(wxd_Treebook_t* self, wxd_Window_t* page, const char* text, int select,
                         int imageId);
 
+WXD_EXPORTED int
+wxd_Treebook_InsertPage(wxd_Treebook_t* self, size_t pos, wxd_Window_t* page, const char* text,
+                        int select, int imageId);
+
+WXD_EXPORTED int
+wxd_Treebook_InsertSubPage(wxd_Treebook_t* self, size_t pos, wxd_Window_t* page, const char* text,
+                           int select, int imageId);
+
 WXD_EXPORTED int
 wxd_Treebook_GetPageCount(wxd_Treebook_t* self);
 
+WXD_EXPORTED wxd_Window_t*
+wxd_Treebook_GetPage(wxd_Treebook_t* self, size_t n);
+
 WXD_EXPORTED int
 wxd_Treebook_GetSelection(wxd_Treebook_t* self);
 
 WXD_EXPORTED int
 wxd_Treebook_SetSelection(wxd_Treebook_t* self, size_t n);
 
+WXD_EXPORTED int
+wxd_Treebook_ChangeSelection(wxd_Treebook_t* self, size_t n);
+
+WXD_EXPORTED bool
+wxd_Treebook_DeletePage(wxd_Treebook_t* self, size_t n);
+
+WXD_EXPORTED bool
+wxd_Treebook_ExpandNode(wxd_Treebook_t* self, size_t n, bool expand);
+
+WXD_EXPORTED bool
+wxd_Treebook_IsNodeExpanded(wxd_Treebook_t* self, size_t n);
+
+WXD_EXPORTED int
+wxd_Treebook_GetPageParent(wxd_Treebook_t* self, size_t n);
+
+WXD_EXPORTED wxd_Window_t*
+wxd_Treebook_GetTreeCtrl(wxd_Treebook_t* self);
+
+WXD_EXPORTED void
+wxd_Treebook_AssignImageList(wxd_Treebook_t* self, wxd_ImageList_t* imageList);
+
+WXD_EXPORTED bool
+wxd_Treebook_SetPageImage(wxd_Treebook_t* self, size_t n, int imageId);
+
 WXD_EXPORTED void
 wxd_Treebook_SetPageText(wxd_Treebook_t* self, size_t n, const char* strText);
 
@@ -10,10 +10,6 @@ extern "C" {
 // Opaque type for wxWebView
 typedef struct wxd_WebView wxd_WebView_t;
 
-// Creation
-WXD_EXPORTED wxd_WebView_t* wxd_WebView_Create(wxd_Window_t* parent, wxd_Id id, const char* url,
-                                               wxd_Point pos, wxd_Size size, long style,
-                                               const char* name, const char* backend);
 
 // Navigation
 WXD_EXPORTED void wxd_WebView_LoadURL(wxd_WebView_t* self, const char* url);
@@ -42,6 +38,14 @@ WXD_EXPORTED void wxd_WebView_SetZoomType(wxd_WebView_t* self, int zoomType);
 // Scripting
 WXD_EXPORTED int wxd_WebView_RunScript(wxd_WebView_t* self, const char* javascript, char* output, int output_len);
 
+// Called once with the script's result, or with success false and the error message.
+typedef void (*wxd_WebViewScriptCallback)(bool success, const char* result, void* userdata);
+
+// Runs the script without waiting for it; the callback is called from the event loop,
+// or with an error when the webview is destroyed first.
+WXD_EXPORTED void wxd_WebView_RunScriptAsync(wxd_WebView_t* self, const char* javascript,
+                                             wxd_WebViewScriptCallback callback, void* userdata);
+
 // Clipboard
 WXD_EXPORTED bool wxd_WebView_CanCut(wxd_WebView_t* self);
 WXD_EXPORTED bool wxd_WebView_CanCopy(wxd_WebView_t* self);
@@ -69,6 +73,20 @@ WXD_EXPORTED void wxd_WebView_SetEditable(wxd_WebView_t* self, bool enable);
 // Printing
 WXD_EXPORTED void wxd_WebView_Print(wxd_WebView_t* self);
 
+// Snapshots
+// Receives the snapshot as PNG bytes (valid during the call), or null data and the reason
+// capturing failed.
+typedef void (*wxd_WebViewSnapshotCallback)(const unsigned char* png, size_t len,
+                                            const char* error, void* userdata);
+// Calls `callback` exactly once, possibly before returning. `full_page` captures the whole
+// document instead of the visible viewport.
+WXD_EXPORTED void wxd_WebView_CaptureSnapshot(wxd_WebView_t* self, bool full_page,
+                                              wxd_WebViewSnapshotCallback callback, void* userdata);
+// Same, but waits for the snapshot (dispatching events) and calls `callback` before returning.
+WXD_EXPORTED void wxd_WebView_CaptureSnapshotSync(wxd_WebView_t* self, bool full_page,
+                                                  wxd_WebViewSnapshotCallback callback,
+                                                  void* userdata);
+
 // Context Menu & Dev Tools
 WXD_EXPORTED void wxd_WebView_EnableContextMenu(wxd_WebView_t* self, bool enable);
 WXD_EXPORTED bool wxd_WebView_IsContextMenuEnabled(wxd_WebView_t* self);
@@ -95,8 +113,41 @@ WXD_EXPORTED int wxd_WebView_GetUserAgent(wxd_WebView_t* self, char* buffer, int
 WXD_EXPORTED bool wxd_WebView_SetProxy(wxd_WebView_t* self, const char* proxy);
 
 // Advanced Scripting
+// Handlers added before the backend is ready (Edge) are added once it has been created.
 WXD_EXPORTED bool wxd_WebView_AddScriptMessageHandler(wxd_WebView_t* self, const char* name);
 WXD_EXPORTED bool wxd_WebView_RemoveScriptMessageHandler(wxd_WebView_t* self, const char* name);
+// Name of the handler a wxEVT_WEBVIEW_SCRIPT_MESSAGE_RECEIVED event was posted to.
+// Returns -1 if the event is not a webview event.
+WXD_EXPORTED int wxd_WebViewEvent_GetMessageHandler(wxd_Event_t* event, char* buffer, int len);
+// URL and target frame name of a navigation, load or error event.
+// Return -1 if the event is not a webview event.
+WXD_EXPORTED int wxd_WebViewEvent_GetURL(wxd_Event_t* event, char* buffer, int len);
+WXD_EXPORTED int wxd_WebViewEvent_GetTarget(wxd_Event_t* event, char* buffer, int len);
+// Handles a wxEVT_WEBVIEW_NEWWINDOW event by loading its URL in the originating webview.
+WXD_EXPORTED bool wxd_WebViewEvent_LoadInCurrent(wxd_Event_t* event);
+
+// Downloads
+// WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_* events are only sent where wxDragon hooks the
+// backend's download API (see wxd_WebView_SupportsDownloads). The getters return -1
+// (or false) for other events.
+typedef enum {
+    WXD_WEBVIEW_DOWNLOAD_IN_PROGRESS = 0,
+    WXD_WEBVIEW_DOWNLOAD_COMPLETED = 1,
+    WXD_WEBVIEW_DOWNLOAD_INTERRUPTED = 2
+} wxd_WebViewDownloadState;
+
+WXD_EXPORTED int wxd_WebViewEvent_GetDownloadFilename(wxd_Event_t* event, char* buffer, int len);
+WXD_EXPORTED int wxd_WebViewEvent_GetDownloadPath(wxd_Event_t* event, char* buffer, int len);
+WXD_EXPORTED int wxd_WebViewEvent_GetDownloadMimeType(wxd_Event_t* event, char* buffer, int len);
+// Only for download request events: saves the file to `path` instead.
+WXD_EXPORTED bool wxd_WebViewEvent_SetDownloadPath(wxd_Event_t* event, const char* path);
+// `total` is -1 when the size is unknown.
+WXD_EXPORTED bool wxd_WebViewEvent_GetDownloadBytes(wxd_Event_t* event, int64_t* received,
+                                                    int64_t* total);
+WXD_EXPORTED int wxd_WebViewEvent_GetDownloadState(wxd_Event_t* event);
+// Installs the download hooks (done by wxd_WebView_Create); for webviews made elsewhere, e.g. XRC.
+WXD_EXPORTED bool wxd_WebView_EnableDownloadEvents(wxd_WebView_t* self);
+WXD_EXPORTED bool wxd_WebView_SupportsDownloads(wxd_WebView_t* self);
 WXD_EXPORTED bool wxd_WebView_AddUserScript(wxd_WebView_t* self, const char* javascript, int injectionTime);
 WXD_EXPORTED void wxd_WebView_RemoveAllUserScripts(wxd_WebView_t* self);
 
@@ -121,11 +172,61 @@ WXD_EXPORTED void wxd_WebView_RegisterHandler(wxd_WebView_t* self, const char* s
                                               wxd_WebViewHandler_FreeData free_data,
                                               wxd_WebViewHandler_DropUserdata drop_userdata,
                                               void* userdata);
+// Built-in handlers: wxWebViewArchiveHandler serves files from inside archives
+// (scheme:///path/file.zip;protocol=zip/page.htm) and wxWebViewFSHandler serves
+// scheme: URLs through wxFileSystem.
+WXD_EXPORTED void wxd_WebView_RegisterArchiveHandler(wxd_WebView_t* self, const char* scheme);
+WXD_EXPORTED void wxd_WebView_RegisterFSHandler(wxd_WebView_t* self, const char* scheme);
+
+typedef enum {
+    WXD_WEBVIEW_HANDLER_CALLBACK = 0,
+    WXD_WEBVIEW_HANDLER_ARCHIVE = 1,
+    WXD_WEBVIEW_HANDLER_FILESYSTEM = 2
+} wxd_WebViewHandlerKind;
+
+// A handler to register while the webview is created, before the first page loads.
+// The callback fields are only used for WXD_WEBVIEW_HANDLER_CALLBACK.
+typedef struct {
+    const char* scheme;
+    wxd_WebViewHandlerKind kind;
+    wxd_WebViewHandler_Callback callback;
+    wxd_WebViewHandler_FreeData free_data;
+    wxd_WebViewHandler_DropUserdata drop_userdata;
+    void* userdata;
+} wxd_WebViewHandlerDesc;
+
+// Creation
+// `handlers` are registered before `url` is loaded. On macOS the webview is created in
+// two steps so WebKit sees them. Userdata of callback handlers is always consumed.
+WXD_EXPORTED wxd_WebView_t* wxd_WebView_Create(wxd_Window_t* parent, wxd_Id id, const char* url,
+                                               wxd_Point pos, wxd_Size size, long style,
+                                               const char* name, const char* backend,
+                                               const wxd_WebViewHandlerDesc* handlers,
+                                               int handler_count);
 
 // Native Backend
 WXD_EXPORTED void* wxd_WebView_GetNativeBackend(wxd_WebView_t* self);
 WXD_EXPORTED int wxd_WebView_GetBackend(wxd_WebView_t* self, char* buffer, int len);
 
+// Browsing Data
+// `types` is a combination of wxWebViewBrowsingDataTypes. Data from before `since`
+// (seconds since the Unix epoch) is kept; pass -1 to clear data of any age. Returns
+// false on backends that cannot clear browsing data. Completion is reported by
+// WXD_EVENT_TYPE_WEBVIEW_BROWSING_DATA_CLEARED.
+WXD_EXPORTED bool wxd_WebView_SupportsBrowsingDataClearing(wxd_WebView_t* self);
+WXD_EXPORTED bool wxd_WebView_ClearBrowsingData(wxd_WebView_t* self, int types, int64_t since);
+
+// Cookies (Edge only). GetCookie returns -1 if there is no such cookie or cookies are
+// unsupported. `expires` is in seconds since the Unix epoch, or -1 for a session cookie.
+WXD_EXPORTED bool wxd_WebView_SupportsCookies(wxd_WebView_t* self);
+WXD_EXPORTED int wxd_WebView_GetCookie(wxd_WebView_t* self, const char* name, const char* url,
+                                       char* buffer, int len);
+WXD_EXPORTED bool wxd_WebView_SetCookie(wxd_WebView_t* self, const char* name, const char* value,
+                                        const char* domain, const char* path, double expires,
+                                        bool http_only, bool secure);
+// Deletes every cookie that would be sent to `url`.
+WXD_EXPORTED bool wxd_WebView_DeleteCookies(wxd_WebView_t* self, const char* url);
+
 // Static utility functions
 WXD_EXPORTED bool wxd_WebView_IsBackendAvailable(const char* backend);
 
@@ -48,6 +48,16 @@ typedef struct {
     unsigned char a;
 } wxd_Colour_t;
 
+// Font metrics as reported by wxDC::GetFontMetrics
+typedef struct {
+    int height;
+    int ascent;
+    int descent;
+    int internal_leading;
+    int external_leading;
+    int average_width;
+} wxd_FontMetrics;
+
 // DateTime opaque type (pointer to wxDateTime instance)
 typedef struct wxd_DateTime_t wxd_DateTime_t;
 
@@ -60,6 +70,9 @@ typedef enum {
     WXD_EVENT_TYPE_CHECKBOX = 3,
     WXD_EVENT_TYPE_TEXT = 4,
     WXD_EVENT_TYPE_TEXT_ENTER = 5,
+    WXD_EVENT_TYPE_TEXT_MAXLEN = 418,
+    WXD_EVENT_TYPE_TEXT_URL = 419,
+    WXD_EVENT_TYPE_TEXT_CARET_OR_SELECTION_CHANGED = 420, // Caret or selection moved (sent by wxDragon)
     WXD_EVENT_TYPE_SIZE = 6,
     WXD_EVENT_TYPE_MENU = 7,
     WXD_EVENT_TYPE_LEFT_DOWN = 8,
@@ -78,6 +91,8 @@ typedef enum {
     WXD_EVENT_TYPE_COMMAND_LISTBOX_SELECTED = 17,
     WXD_EVENT_TYPE_COMMAND_CHOICE_SELECTED = 18,
     WXD_EVENT_TYPE_COMMAND_COMBOBOX_SELECTED = 19,
+    WXD_EVENT_TYPE_COMBOBOX_DROPDOWN = 421, // wxEVT_COMBOBOX_DROPDOWN
+    WXD_EVENT_TYPE_COMBOBOX_CLOSEUP = 422,  // wxEVT_COMBOBOX_CLOSEUP
     WXD_EVENT_TYPE_COMMAND_CHECKLISTBOX_SELECTED = 20,
     WXD_EVENT_TYPE_COMMAND_TOGGLEBUTTON_CLICKED = 21,
     WXD_EVENT_TYPE_TREE_BEGIN_LABEL_EDIT = 22,
@@ -176,6 +191,7 @@ typedef enum {
     WXD_EVENT_TYPE_DATAVIEW_COLUMN_SORTED = 103,
     WXD_EVENT_TYPE_DATAVIEW_COLUMN_REORDERED = 104,
     WXD_EVENT_TYPE_DATAVIEW_ITEM_CONTEXT_MENU = 220, // wxEVT_DATAVIEW_ITEM_CONTEXT_MENU
+    WXD_EVENT_TYPE_DATAVIEW_ITEM_BEGIN_DRAG = 221,   // wxEVT_DATAVIEW_ITEM_BEGIN_DRAG
 
     // New TreeCtrl Event Types
     WXD_EVENT_TYPE_TREE_SEL_CHANGING = 105,             // wxEVT_TREE_SEL_CHANGING
@@ -193,6 +209,7 @@ typedef enum {
     WXD_EVENT_TYPE_TREE_END_DRAG = 117,                 // wxEVT_TREE_END_DRAG
     WXD_EVENT_TYPE_TREE_STATE_IMAGE_CLICK = 118,        // wxEVT_TREE_STATE_IMAGE_CLICK
     WXD_EVENT_TYPE_COMMAND_LISTBOX_DOUBLECLICKED = 119, // wxEVT_LISTBOX_DCLICK
+    WXD_EVENT_TYPE_EDITABLELISTBOX_CHANGED = 423, // EditableListBox strings edited (sent by wxDragon)
 
     // ADDED: Additional event types missing from enum
     WXD_EVENT_TYPE_TOOL = 123,                 // ToolBar Tool clicked event (wxEVT_TOOL)
@@ -215,11 +232,17 @@ typedef enum {
     WXD_EVENT_TYPE_AUI_PANE_RESTORE = 138,   // wxEVT_AUI_PANE_RESTORE
     WXD_EVENT_TYPE_AUI_PANE_ACTIVATED = 139, // wxEVT_AUI_PANE_ACTIVATED
     WXD_EVENT_TYPE_AUI_RENDER = 140,         // wxEVT_AUI_RENDER
+    WXD_EVENT_TYPE_AUITOOLBAR_TOOL_DROPDOWN = 414, // wxEVT_AUITOOLBAR_TOOL_DROPDOWN
+    WXD_EVENT_TYPE_AUITOOLBAR_RIGHT_CLICK = 415,   // wxEVT_AUITOOLBAR_RIGHT_CLICK
+    WXD_EVENT_TYPE_AUI_PANE_FLOAT = 416,           // Pane became floating (sent by wxDragon)
+    WXD_EVENT_TYPE_AUI_PANE_DOCK = 417,            // Pane was docked (sent by wxDragon)
 #endif
 
     // ADDED: RearrangeList event
     WXD_EVENT_TYPE_COMMAND_REARRANGE_LIST =
-        141, // Event for RearrangeList when items are rearranged
+        141, // RearrangeList items moved by the user (sent by wxDragon)
+    WXD_EVENT_TYPE_REARRANGE_LIST_CHECKED =
+        424, // RearrangeList item checked by the user (sent by wxDragon)
 
     // ADDED: CollapsiblePane event
     WXD_EVENT_TYPE_COLLAPSIBLEPANE_CHANGED =
@@ -318,6 +341,9 @@ typedef enum {
     WXD_EVENT_TYPE_WEBVIEW_SCRIPT_RESULT = 350,          // wxEVT_WEBVIEW_SCRIPT_RESULT
     WXD_EVENT_TYPE_WEBVIEW_WINDOW_CLOSE_REQUESTED = 351, // wxEVT_WEBVIEW_WINDOW_CLOSE_REQUESTED
     WXD_EVENT_TYPE_WEBVIEW_BROWSING_DATA_CLEARED = 352,  // wxEVT_WEBVIEW_BROWSING_DATA_CLEARED
+    WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_REQUEST = 353,       // Download about to start (sent by wxDragon)
+    WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_PROGRESS = 354,      // Download received data (sent by wxDragon)
+    WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_COMPLETED = 355,     // Download finished or failed (sent by wxDragon)
 #endif
 
     // NEW: Menu event types
@@ -346,6 +372,13 @@ typedef enum {
     WXD_EVENT_TYPE_GRID_RANGE_SELECTED = 386,       // wxEVT_GRID_RANGE_SELECTED
     WXD_EVENT_TYPE_GRID_TABBING = 387,              // wxEVT_GRID_TABBING
     WXD_EVENT_TYPE_NOTEBOOK_PAGE_CHANGING = 389,    // wxEVT_NOTEBOOK_PAGE_CHANGING
+    WXD_EVENT_TYPE_BOOKCTRL_PAGE_CHANGED = 425,     // wxEVT_BOOKCTRL_PAGE_CHANGED (wxSimplebook)
+    WXD_EVENT_TYPE_BOOKCTRL_PAGE_CHANGING = 426,    // wxEVT_BOOKCTRL_PAGE_CHANGING (wxSimplebook)
+    WXD_EVENT_TYPE_CHOICEBOOK_PAGE_CHANGED = 427,   // wxEVT_CHOICEBOOK_PAGE_CHANGED
+    WXD_EVENT_TYPE_CHOICEBOOK_PAGE_CHANGING = 428,  // wxEVT_CHOICEBOOK_PAGE_CHANGING
+    WXD_EVENT_TYPE_LISTBOOK_PAGE_CHANGED = 429,     // wxEVT_LISTBOOK_PAGE_CHANGED
+    WXD_EVENT_TYPE_LISTBOOK_PAGE_CHANGING = 430,    // wxEVT_LISTBOOK_PAGE_CHANGING
+    WXD_EVENT_TYPE_NOTEBOOK_PAGES_REORDERED = 431,  // Tab dragged to another position (sent by wxDragon)
 
     // PropertyGrid event types
     WXD_EVENT_TYPE_PG_SELECTED = 390,          // wxEVT_PG_SELECTED
@@ -363,7 +396,18 @@ typedef enum {
     WXD_EVENT_TYPE_PG_COL_DRAGGING = 402,      // wxEVT_PG_COL_DRAGGING
     WXD_EVENT_TYPE_PG_COL_END_DRAG = 403,      // wxEVT_PG_COL_END_DRAG
 
-    WXD_EVENT_TYPE_MAX // Keep this last for count if needed, or remove if not used for iteration
+    // Wizard event types
+    WXD_EVENT_TYPE_WIZARD_PAGE_CHANGING = 410, // wxEVT_WIZARD_PAGE_CHANGING
+    WXD_EVENT_TYPE_WIZARD_PAGE_CHANGED = 411,  // wxEVT_WIZARD_PAGE_CHANGED
+    WXD_EVENT_TYPE_WIZARD_CANCEL = 412,        // wxEVT_WIZARD_CANCEL
+    WXD_EVENT_TYPE_WIZARD_FINISHED = 413,      // wxEVT_WIZARD_FINISHED
+
+    // ToolBar event types added after the ones above
+    WXD_EVENT_TYPE_TOOL_DROPDOWN = 432, // Dropdown arrow of a ToolBar tool clicked
+
+    // Highest value above plus one: the reverse lookup in event.cpp only tries values below
+    // it, so keep it up to date when adding event types
+    WXD_EVENT_TYPE_MAX = 433
 } WXDEventTypeCEnum;
 
 typedef int64_t wxd_Style_t;
@@ -388,6 +432,7 @@ typedef struct wxd_RadioButton_t wxd_RadioButton_t;
 typedef struct wxd_ListBox_t wxd_ListBox_t;
 typedef struct wxd_Choice_t wxd_Choice_t;
 typedef struct wxd_ComboBox_t wxd_ComboBox_t;
+typedef struct wxd_ComboCtrl_t wxd_ComboCtrl_t;
 typedef struct wxd_CheckListBox_t wxd_CheckListBox_t;
 typedef struct wxd_ToggleButton_t wxd_ToggleButton_t;
 typedef struct wxd_BitmapToggleButton_t wxd_BitmapToggleButton_t;
@@ -408,7 +453,9 @@ typedef struct wxd_Bitmap_t wxd_Bitmap_t;
 typedef struct wxd_BitmapButton_t wxd_BitmapButton_t;
 typedef struct wxd_ScrolledWindow_t wxd_ScrolledWindow_t;
 typedef struct wxd_StatusBar_t wxd_StatusBar_t;
+typedef struct wxd_InfoBar_t wxd_InfoBar_t;
 typedef struct wxd_ToolBar_t wxd_ToolBar_t;
+typedef struct wxd_ToolBarTool_t wxd_ToolBarTool_t; // A tool removed from its wxToolBar
 typedef struct wxd_MenuBar_t wxd_MenuBar_t;
 typedef struct wxd_Menu_t wxd_Menu_t;
 typedef struct wxd_MenuItem_t wxd_MenuItem_t;
@@ -435,6 +482,10 @@ typedef struct wxd_ScrollBar_t wxd_ScrollBar_t;
 typedef struct wxd_CommandLinkButton_t wxd_CommandLinkButton_t;
 typedef struct wxd_Dialog wxd_Dialog_t;
 typedef struct wxd_MessageDialog wxd_MessageDialog_t;
+typedef struct wxd_RichMessageDialog wxd_RichMessageDialog_t;
+typedef struct wxd_NumberEntryDialog wxd_NumberEntryDialog_t;
+typedef struct wxd_Wizard wxd_Wizard_t;
+typedef struct wxd_WizardPage wxd_WizardPage_t;
 typedef struct wxd_ArrayString_t wxd_ArrayString_t;
 typedef struct wxd_FileDialog wxd_FileDialog_t;
 typedef struct wxd_ColourData wxd_ColourData_t;
@@ -456,6 +507,7 @@ typedef struct wxd_NotificationMessage_t wxd_NotificationMessage_t;
 typedef struct wxd_FileCtrl_t wxd_FileCtrl_t;
 typedef struct wxd_MediaCtrl_t wxd_MediaCtrl_t;
 typedef struct wxd_RearrangeList_t wxd_RearrangeList_t;
+typedef struct wxd_RearrangeCtrl_t wxd_RearrangeCtrl_t;
 typedef struct wxd_EditableListBox_t wxd_EditableListBox_t;
 typedef struct wxd_Clipboard_t wxd_Clipboard_t;
 typedef struct wxd_Accessible_t wxd_Accessible_t;
@@ -494,6 +546,29 @@ typedef enum {
     WXD_MOD_ALL = 0x000f
 } wxd_KeyModifier;
 
+// --- Show Effect Constants (wxShowEffect, for wxSimplebook page transitions) ---
+typedef enum {
+    WXD_SHOW_EFFECT_NONE = 0,
+    WXD_SHOW_EFFECT_ROLL_TO_LEFT = 1,
+    WXD_SHOW_EFFECT_ROLL_TO_RIGHT = 2,
+    WXD_SHOW_EFFECT_ROLL_TO_TOP = 3,
+    WXD_SHOW_EFFECT_ROLL_TO_BOTTOM = 4,
+    WXD_SHOW_EFFECT_SLIDE_TO_LEFT = 5,
+    WXD_SHOW_EFFECT_SLIDE_TO_RIGHT = 6,
+    WXD_SHOW_EFFECT_SLIDE_TO_TOP = 7,
+    WXD_SHOW_EFFECT_SLIDE_TO_BOTTOM = 8,
+    WXD_SHOW_EFFECT_BLEND = 9,
+    WXD_SHOW_EFFECT_EXPAND = 10
+} wxd_ShowEffect;
+
+// --- Status Bar Field Styles (wxSB_*, for wxStatusBar::SetStatusStyles) ---
+typedef enum {
+    WXD_SB_NORMAL = 0,
+    WXD_SB_FLAT = 1,
+    WXD_SB_RAISED = 2,
+    WXD_SB_SUNKEN = 3
+} wxd_StatusBarFieldStyle;
+
 // ConfigBase opaque type
 typedef struct wxd_ConfigBase_t wxd_ConfigBase_t;
 
@@ -517,6 +592,7 @@ typedef enum {
 
 // XRC-related opaque types
 typedef struct wxd_XmlResource_t wxd_XmlResource_t;
+typedef struct wxd_ArtProvider_t wxd_ArtProvider_t;
 
 typedef struct wxd_AuiMDIParentFrame_t wxd_AuiMDIParentFrame_t;
 typedef struct wxd_AuiMDIChildFrame_t wxd_AuiMDIChildFrame_t;
@@ -534,6 +610,8 @@ typedef struct wxd_DataObject_t wxd_DataObject_t;
 typedef struct wxd_TextDataObject_t wxd_TextDataObject_t;
 typedef struct wxd_FileDataObject_t wxd_FileDataObject_t;
 typedef struct wxd_BitmapDataObject_t wxd_BitmapDataObject_t;
+typedef struct wxd_DataObjectComposite_t wxd_DataObjectComposite_t;
+typedef struct wxd_CustomDataObject_t wxd_CustomDataObject_t;
 typedef struct wxd_DropSource_t wxd_DropSource_t;
 typedef struct wxd_DropTarget_t wxd_DropTarget_t;
 typedef struct wxd_TextDropTarget_t wxd_TextDropTarget_t;
@@ -554,7 +632,7 @@ typedef enum {
     WXD_ITEM_CHECK = 1,     // wxITEM_CHECK
     WXD_ITEM_RADIO = 2,     // wxITEM_RADIO
     WXD_ITEM_SEPARATOR = 3, // wxITEM_SEPARATOR
-    // wxITEM_DROPDOWN (specific to wxToolBar and wxRibbonBar, might add later if needed)
+    WXD_ITEM_DROPDOWN = 4,  // wxITEM_DROPDOWN, tools with a dropdown arrow (wxToolBar)
     // wxITEM_MAX is not typically used directly as a kind
 } WXDItemKindCEnum;
 
@@ -607,7 +685,7 @@ typedef enum {
 } wxd_DataViewCellModeCEnum;
 
 // Image related opaque types
-typedef void wxd_Image_t;             // Added if not present
+typedef struct wxd_Image_t wxd_Image_t;
 typedef void wxd_ImageList_t;         // Added
 typedef struct wxd_Icon_t wxd_Icon_t; // Assuming this pattern from Bitmap_t
 
@@ -685,6 +763,10 @@ typedef struct wxd_Window_t wxd_Window_t;
 
 /// Opaque pointer to wxTimer
 typedef struct wxd_Timer_t wxd_Timer_t;
+typedef struct wxd_CallLater_t wxd_CallLater_t;
+
+/// Opaque pointer to wxStopWatch
+typedef struct wxd_StopWatch_t wxd_StopWatch_t;
 
 /// Window ID type (must match wxWidgets window ID type)
 typedef int wxd_Id;
@@ -24,6 +24,7 @@ extern "C" {
 // Include API categories
 #include "core/wxd_app.h"
 #include "core/wxd_window_base.h"
+#include "core/wxd_validator.h"
 #include "core/wxd_accessible.h"
 #include "core/wxd_cursor.h"
 #if wxdUSE_XRC
@@ -36,6 +37,7 @@ extern "C" {
 #include "widgets/wxd_button.h"
 #include "widgets/wxd_statictext.h"
 #include "widgets/wxd_textctrl.h"
+#include "widgets/wxd_textentry.h"
 #if wxdUSE_RICHTEXT
 #include "widgets/wxd_richtextctrl.h"
 #endif
@@ -69,6 +71,7 @@ extern "C" {
 #include "widgets/wxd_listbox.h"
 #include "widgets/wxd_choice.h"
 #include "widgets/wxd_combobox.h"
+#include "widgets/wxd_comboctrl.h"
 #include "widgets/wxd_checklistbox.h"
 #include "widgets/wxd_radiobox.h"
 #include "widgets/wxd_bitmapcombobox.h"
@@ -91,11 +94,13 @@ extern "C" {
 #include "widgets/wxd_treebook.h"
 #include "widgets/wxd_notebook.h"
 #include "widgets/wxd_simplebook.h"
+#include "widgets/wxd_bookctrl.h"
 
 // Window and UI elements
 #include "widgets/wxd_frame.h"
 #include "widgets/wxd_mdi.h"
 #include "widgets/wxd_statusbar.h"
+#include "widgets/wxd_infobar.h"
 #include "widgets/wxd_toolbar.h"
 #include "widgets/wxd_menu.h"
 #include "widgets/wxd_bitmap.h"
@@ -105,6 +110,7 @@ extern "C" {
 
 // Graphics
 #include "graphics/wxd_bitmapbundle.h"
+#include "graphics/wxd_image.h"
 
 // Other widgets
 #include "widgets/wxd_pickers.h"
@@ -115,6 +121,7 @@ extern "C" {
 
 // Timer
 #include "core/wxd_timer.h"
+#include "core/wxd_stopwatch.h"
 
 // Application progress indicator
 #include "core/wxd_appprogress.h"
@@ -134,6 +141,7 @@ extern "C" {
 #include "dialogs/wxd_about.h"
 #include "dnd/wxd_dnd.h"     // Drag and drop functionality
 #include "graphics/wxd_dc.h" // Device context functionality
+#include "graphics/wxd_graphics.h" // wxGraphicsContext
 
 // DataView related includes.
 // wxd_dataview.h provides main FFI for DataViewCtrl, ListCtrl, TreeCtrl (creation),
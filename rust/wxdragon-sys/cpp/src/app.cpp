@@ -4,6 +4,7 @@
 #include "../include/wxdragon.h"
 #include <wx/app.h>
 #include <wx/image.h>
+#include <wx/evtloop.h>
 #include <cstdlib>
 #include <wx/private/safecall.h>
 #include <wx/scopeguard.h>
@@ -38,7 +39,7 @@ public:
     void
     OnIdle(wxIdleEvent& event);
 
-    // Override OnExit to clean up IPC/DDE objects before module cleanup
+    // Override OnExit to clean up IPC/DDE objects and delayed calls before module cleanup
     virtual int OnExit() override;
 
 #ifdef __WXOSX__
@@ -113,7 +114,7 @@ WxdApp::OnIdle(wxIdleEvent& event)
     }
 }
 
-// Clean up IPC/DDE objects before wxWidgets module cleanup.
+// Clean up IPC/DDE objects and pending delayed calls before wxWidgets module cleanup.
 // On Windows, wxDDECleanUp() asserts all DDE objects are gone.
 // Rust-side Drop impls may not run until after that point, so
 // we proactively destroy any remaining IPC objects here.
@@ -121,6 +122,7 @@ int
 WxdApp::OnExit()
 {
     wxd_IPC_CleanupAll();
+    wxd_CallLater_CleanupAll();
     return wxApp::OnExit();
 }
 
@@ -266,9 +268,28 @@ wxd_App_ExitMainLoop(wxd_App_t* app)
 void
 wxd_WakeUpIdle()
 {
+    // Thread-safe: posts a wake-up to the main event loop
     wxWakeUpIdle();
 }
 
+bool
+wxd_EventLoop_IsRunning()
+{
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    return loop && loop->IsRunning();
+}
+
+bool
+wxd_EventLoop_Yield(bool onlyIfNeeded)
+{
+    if (!wxIsMainThread())
+        return false;
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    if (!loop)
+        return false;
+    return loop->Yield(onlyIfNeeded);
+}
+
 bool
 wxd_App_GetExitOnFrameDelete(wxd_App_t* app)
 {
@@ -5,6 +5,45 @@
 #include <wx/bitmap.h>
 #include <wx/bmpbndl.h>
 
+// Implemented in Rust: frees the closure passed as user_data to wxd_ArtProvider_Push.
+extern "C" void
+wxd_Drop_Rust_ArtProviderCallback(void* user_data);
+
+namespace {
+
+// Art provider backed by a Rust closure
+class RustArtProvider : public wxArtProvider {
+public:
+    RustArtProvider(wxd_ArtProvider_CreateBitmapCallback callback, void* userData)
+        : m_callback(callback), m_userData(userData)
+    {
+    }
+
+    ~RustArtProvider() override
+    {
+        wxd_Drop_Rust_ArtProviderCallback(m_userData);
+    }
+
+protected:
+    wxBitmap CreateBitmap(const wxArtID& id, const wxArtClient& client, const wxSize& size) override
+    {
+        wxd_Size wxd_size = { size.GetWidth(), size.GetHeight() };
+        wxBitmap* bitmap = reinterpret_cast<wxBitmap*>(
+            m_callback(m_userData, id.utf8_str(), client.utf8_str(), wxd_size));
+        if (!bitmap)
+            return wxNullBitmap;
+        wxBitmap result(*bitmap);
+        delete bitmap;
+        return result;
+    }
+
+private:
+    wxd_ArtProvider_CreateBitmapCallback m_callback;
+    void* m_userData;
+};
+
+} // namespace
+
 // We add extern "C" for all the function definitions to make sure they use C linkage
 extern "C" {
 
@@ -124,4 +163,27 @@ wxd_ArtProvider_HasNativeProvider(void)
     return wxArtProvider::HasNativeProvider();
 }
 
+WXD_EXPORTED wxd_ArtProvider_t*
+wxd_ArtProvider_Push(wxd_ArtProvider_CreateBitmapCallback callback, void* user_data)
+{
+    if (!callback) {
+        wxd_Drop_Rust_ArtProviderCallback(user_data);
+        return nullptr;
+    }
+    RustArtProvider* provider = new RustArtProvider(callback, user_data);
+    wxArtProvider::Push(provider);
+    return reinterpret_cast<wxd_ArtProvider_t*>(provider);
+}
+
+WXD_EXPORTED bool
+wxd_ArtProvider_Delete(wxd_ArtProvider_t* provider)
+{
+    wxArtProvider* wx_provider = reinterpret_cast<wxArtProvider*>(provider);
+    // Remove only compares pointers, so this is safe after the providers were cleaned up
+    if (!wx_provider || !wxArtProvider::Remove(wx_provider))
+        return false;
+    delete wx_provider;
+    return true;
+}
+
 } // extern "C"
\ No newline at end of file
@@ -6,6 +6,75 @@
 #include <wx/aui/framemanager.h>
 #include <wx/aui/auibook.h>
 
+#include <set>
+#include <unordered_map>
+#include <vector>
+
+// wxAUI has no events for a pane being floated or docked, so wxDragon sends its own.
+wxDEFINE_EVENT(wxdEVT_AUI_PANE_FLOAT, wxAuiManagerEvent);
+wxDEFINE_EVENT(wxdEVT_AUI_PANE_DOCK, wxAuiManagerEvent);
+
+// wxAuiManager that sends wxdEVT_AUI_PANE_FLOAT/DOCK when a pane's floating state changed.
+// Every layout change repaints the dock area, so the state is compared after each render
+// event; this is done here rather than in a bound handler so user render handlers that
+// don't skip the event can't starve it.
+class WxdAuiManager : public wxAuiManager {
+public:
+    bool ProcessEvent(wxEvent& event) override
+    {
+        bool processed = wxAuiManager::ProcessEvent(event);
+        if (event.GetEventType() == wxEVT_AUI_RENDER) {
+            CheckFloatingState();
+        }
+        return processed;
+    }
+
+private:
+    void CheckFloatingState()
+    {
+        std::unordered_map<wxWindow*, bool> current;
+        std::vector<wxWindow*> floated;
+        std::vector<wxWindow*> docked;
+        wxAuiPaneInfoArray& panes = GetAllPanes();
+        for (size_t i = 0; i < panes.GetCount(); ++i) {
+            const wxAuiPaneInfo& pane = panes.Item(i);
+            if (!pane.window)
+                continue;
+            bool is_floating = pane.IsFloating();
+            current[pane.window] = is_floating;
+            auto known = m_floating.find(pane.window);
+            if (known != m_floating.end() && known->second != is_floating) {
+                (is_floating ? floated : docked).push_back(pane.window);
+            }
+        }
+        m_floating.swap(current);
+
+        if (floated.empty() && docked.empty())
+            return;
+
+        // Handlers may change the layout, which mustn't happen while it is being painted
+        CallAfter([this, floated, docked]() {
+            SendPaneEvents(wxdEVT_AUI_PANE_FLOAT, floated);
+            SendPaneEvents(wxdEVT_AUI_PANE_DOCK, docked);
+        });
+    }
+
+    void SendPaneEvents(wxEventType type, const std::vector<wxWindow*>& windows)
+    {
+        for (wxWindow* window : windows) {
+            wxAuiPaneInfo& pane = GetPane(window);
+            if (!pane.IsOk())
+                continue;
+            wxAuiManagerEvent event(type);
+            event.SetManager(this);
+            event.SetPane(&pane);
+            wxAuiManager::ProcessEvent(event);
+        }
+    }
+
+    std::unordered_map<wxWindow*, bool> m_floating;
+};
+
 // Direction constants for AddPane function (matching wxAUI constants)
 #define WXD_AUI_DOCK_LEFT   (0)
 #define WXD_AUI_DOCK_RIGHT  (1)
@@ -37,6 +106,57 @@ struct wxd_AuiPaneInfo_t {
     }
 };
 
+// True while the manager is still pushed onto its managed window's handler chain, i.e.
+// UnInit() hasn't run yet. wxAuiManager::UnInit() itself doesn't track this and removing
+// the handler a second time asserts.
+static bool
+IsManagerPushed(wxAuiManager* manager)
+{
+    wxWindow* frame = manager->GetManagedWindow();
+    if (!frame)
+        return false;
+    for (wxEvtHandler* handler = frame->GetEventHandler(); handler && handler != frame;
+         handler = handler->GetNextHandler()) {
+        if (handler == manager)
+            return true;
+    }
+    return false;
+}
+
+// Looks up a pane by name, returning nullptr if the manager has none with that name.
+static wxAuiPaneInfo*
+FindPane(wxd_AuiManager_t* self, const char* name)
+{
+    if (!self || !self->manager || !name)
+        return nullptr;
+    wxAuiPaneInfo& pane = self->manager->GetPane(wxString::FromUTF8(name));
+    return pane.IsOk() ? &pane : nullptr;
+}
+
+// Collects the names of the panes stored in a perspective string, splitting it the way
+// wxAuiManager::LoadPerspective does (escaped separators are kept within a part).
+static std::set<wxString>
+PerspectivePaneNames(wxAuiManager* manager, const wxString& perspective)
+{
+    std::set<wxString> names;
+    wxString input = perspective;
+    input.Replace(wxT("\\|"), wxT("\a"));
+    input.Replace(wxT("\\;"), wxT("\b"));
+
+    wxArrayString parts = wxSplit(input, '|', '\0');
+    for (wxString part : parts) {
+        if (!part.StartsWith(wxT("name="))) {
+            continue;
+        }
+        part.Replace(wxT("\a"), wxT("\\|"));
+        part.Replace(wxT("\b"), wxT("\\;"));
+        wxAuiPaneInfo pane;
+        manager->LoadPaneInfo(part, pane);
+        names.insert(pane.name);
+    }
+    return names;
+}
+
 // --- wxAuiManager implementation ---
 
 extern "C" {
@@ -44,7 +164,7 @@ extern "C" {
 wxd_AuiManager_t*
 wxd_AuiManager_Create()
 {
-    wxAuiManager* manager = new wxAuiManager();
+    wxAuiManager* manager = new WxdAuiManager();
     return new wxd_AuiManager_t(manager);
 }
 
@@ -71,11 +191,42 @@ wxd_AuiManager_GetManagedWindow(wxd_AuiManager_t* self)
 void
 wxd_AuiManager_UnInit(wxd_AuiManager_t* self)
 {
-    if (!self || !self->manager)
+    if (!self || !self->manager || !IsManagerPushed(self->manager))
         return;
     self->manager->UnInit();
 }
 
+wxd_EvtHandler_t*
+wxd_AuiManager_GetEvtHandler(wxd_AuiManager_t* self)
+{
+    if (!self || !self->manager)
+        return nullptr;
+    return reinterpret_cast<wxd_EvtHandler_t*>(static_cast<wxEvtHandler*>(self->manager));
+}
+
+void
+wxd_AuiManager_DestroyLater(wxd_AuiManager_t* self)
+{
+    if (!self)
+        return;
+
+    if (self->manager) {
+        if (IsManagerPushed(self->manager)) {
+            self->manager->UnInit();
+        }
+        // This usually runs while the managed window's destroy event is still being
+        // processed through the manager, so it can't be deleted right away.
+        if (wxTheApp) {
+            wxTheApp->ScheduleForDestruction(self->manager);
+        }
+        else {
+            delete self->manager;
+        }
+    }
+
+    delete self;
+}
+
 bool
 wxd_AuiManager_AddPane(wxd_AuiManager_t* self, wxd_Window_t* window, int direction,
                        const char* caption)
@@ -146,7 +297,7 @@ wxd_AuiManager_Delete(wxd_AuiManager_t* self)
 
     if (self->manager) {
         // UnInit the manager first to ensure proper cleanup
-        if (self->manager->GetManagedWindow()) {
+        if (IsManagerPushed(self->manager)) {
             self->manager->UnInit();
         }
         delete self->manager;
@@ -172,8 +323,59 @@ wxd_AuiManager_LoadPerspective(wxd_AuiManager_t* self, const char* perspective,
         return false;
 
     wxString wx_perspective = wxString::FromUTF8(perspective);
-    bool result = self->manager->LoadPerspective(wx_perspective, update);
-    return result;
+
+    // LoadPerspective hides every pane it finds no entry for, so remember panes added since
+    // the perspective was saved and give them back their own settings afterwards. Entries
+    // for panes that no longer exist are skipped by wxAUI itself.
+    std::set<wxString> saved_names = PerspectivePaneNames(self->manager, wx_perspective);
+    std::vector<wxAuiPaneInfo> new_panes;
+    wxAuiPaneInfoArray& panes = self->manager->GetAllPanes();
+    for (size_t i = 0; i < panes.GetCount(); ++i) {
+        if (saved_names.find(panes.Item(i).name) == saved_names.end()) {
+            new_panes.push_back(panes.Item(i));
+        }
+    }
+
+    if (!self->manager->LoadPerspective(wx_perspective, false))
+        return false;
+
+    for (const wxAuiPaneInfo& saved : new_panes) {
+        wxAuiPaneInfo& pane = self->manager->GetPane(saved.window);
+        if (pane.IsOk()) {
+            pane = saved;
+        }
+    }
+
+    if (update)
+        self->manager->Update();
+    return true;
+}
+
+int
+wxd_AuiManager_SavePaneInfo(wxd_AuiManager_t* self, const char* name, char* out, size_t out_len)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    if (!pane)
+        return -1;
+    return (int)wxd_cpp_utils::copy_wxstring_to_buffer(self->manager->SavePaneInfo(*pane), out,
+                                                       out_len);
+}
+
+bool
+wxd_AuiManager_LoadPaneInfo(wxd_AuiManager_t* self, const char* name, const char* info)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    if (!pane || !info)
+        return false;
+
+    // Load into a copy so the pane keeps its name, window and frame whatever the string says
+    wxAuiPaneInfo loaded = *pane;
+    self->manager->LoadPaneInfo(wxString::FromUTF8(info), loaded);
+    loaded.name = pane->name;
+    loaded.window = pane->window;
+    loaded.frame = pane->frame;
+    *pane = loaded;
+    return true;
 }
 
 bool
@@ -186,6 +388,110 @@ wxd_AuiManager_DetachPane(wxd_AuiManager_t* self, wxd_Window_t* window)
     return self->manager->DetachPane(wx_window);
 }
 
+bool
+wxd_AuiManager_HasPane(wxd_AuiManager_t* self, const char* name)
+{
+    return FindPane(self, name) != nullptr;
+}
+
+wxd_Window_t*
+wxd_AuiManager_GetPaneWindow(wxd_AuiManager_t* self, const char* name)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    if (!pane || !pane->window)
+        return nullptr;
+    return reinterpret_cast<wxd_Window_t*>(pane->window);
+}
+
+bool
+wxd_AuiManager_ShowPane(wxd_AuiManager_t* self, const char* name, bool show)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    if (!pane)
+        return false;
+    pane->Show(show);
+    return true;
+}
+
+bool
+wxd_AuiManager_IsPaneShown(wxd_AuiManager_t* self, const char* name)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    return pane && pane->IsShown();
+}
+
+bool
+wxd_AuiManager_FloatPane(wxd_AuiManager_t* self, const char* name)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    if (!pane)
+        return false;
+    pane->Float();
+    return true;
+}
+
+bool
+wxd_AuiManager_DockPane(wxd_AuiManager_t* self, const char* name)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    if (!pane)
+        return false;
+    pane->Dock();
+    return true;
+}
+
+bool
+wxd_AuiManager_IsPaneFloating(wxd_AuiManager_t* self, const char* name)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    return pane && pane->IsFloating();
+}
+
+bool
+wxd_AuiManager_SetPaneCaption(wxd_AuiManager_t* self, const char* name, const char* caption)
+{
+    wxAuiPaneInfo* pane = FindPane(self, name);
+    if (!pane || !caption)
+        return false;
+    pane->Caption(wxString::FromUTF8(caption));
+    return true;
+}
+
+// --- wxAuiManagerEvent implementation ---
+
+int
+wxd_AuiManagerEvent_GetPaneName(wxd_Event_t* event, char* out, size_t out_len)
+{
+    if (!event)
+        return -1;
+    wxAuiManagerEvent* aui_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxAuiManagerEvent);
+    if (!aui_event || !aui_event->GetPane())
+        return -1;
+    return (int)wxd_cpp_utils::copy_wxstring_to_buffer(aui_event->GetPane()->name, out, out_len);
+}
+
+wxd_Window_t*
+wxd_AuiManagerEvent_GetPaneWindow(wxd_Event_t* event)
+{
+    if (!event)
+        return nullptr;
+    wxAuiManagerEvent* aui_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxAuiManagerEvent);
+    if (!aui_event || !aui_event->GetPane() || !aui_event->GetPane()->window)
+        return nullptr;
+    return reinterpret_cast<wxd_Window_t*>(aui_event->GetPane()->window);
+}
+
+wxd_DC_t*
+wxd_AuiManagerEvent_GetDC(wxd_Event_t* event)
+{
+    if (!event)
+        return nullptr;
+    wxAuiManagerEvent* aui_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxAuiManagerEvent);
+    if (!aui_event)
+        return nullptr;
+    return reinterpret_cast<wxd_DC_t*>(aui_event->GetDC());
+}
+
 // --- wxAuiPaneInfo implementation ---
 
 wxd_AuiPaneInfo_t*
@@ -26,10 +26,9 @@ wxd_AuiToolBar_Create(wxd_Window_t* parent, int id, wxd_Point pos, wxd_Size size
 }
 
 WXD_EXPORTED void
-wxd_AuiToolBar_AddTool(
-    wxd_AuiToolBar_t* self, int tool_id, const char* label,
-    /*wxd_Bitmap_t* bitmap, wxd_Bitmap_t* disabled_bitmap,*/ const char* short_help_string,
-    WXDItemKindCEnum kind)
+wxd_AuiToolBar_AddTool(wxd_AuiToolBar_t* self, int tool_id, const char* label,
+                       const wxd_BitmapBundle_t* bitmap, const char* short_help_string,
+                       WXDItemKindCEnum kind)
 {
     if (!self)
         return;
@@ -56,11 +55,8 @@ wxd_AuiToolBar_AddTool(
         wx_kind = wxITEM_NORMAL;
     }
 
-    // wxWidgets wxAuiToolBar::AddTool requires a bitmap.
-    // Using wxNullBitmap for now.
-    /* wxAuiToolBarItem* item = */ toolbar->AddTool(tool_id, wx_label, wxNullBitmap, wx_short_help,
-                                                    wx_kind);
-    // if (item) {} // item is wxAuiToolBarItem*, not used for void return
+    const wxBitmapBundle* bundle = reinterpret_cast<const wxBitmapBundle*>(bitmap);
+    toolbar->AddTool(tool_id, wx_label, bundle ? *bundle : wxBitmapBundle(), wx_short_help, wx_kind);
 }
 
 WXD_EXPORTED void
@@ -238,4 +234,78 @@ wxd_AuiToolBar_DeleteTool(wxd_AuiToolBar_t* self, int tool_id)
     return toolbar->DeleteTool(tool_id);
 }
 
+WXD_EXPORTED void
+wxd_AuiToolBar_ToggleTool(wxd_AuiToolBar_t* self, int tool_id, bool state)
+{
+    if (!self)
+        return;
+    wxAuiToolBar* toolbar = (wxAuiToolBar*)self;
+    toolbar->ToggleTool(tool_id, state);
+    toolbar->Refresh(false);
+}
+
+WXD_EXPORTED bool
+wxd_AuiToolBar_GetToolToggled(wxd_AuiToolBar_t* self, int tool_id)
+{
+    if (!self)
+        return false;
+    wxAuiToolBar* toolbar = (wxAuiToolBar*)self;
+    return toolbar->GetToolToggled(tool_id);
+}
+
+WXD_EXPORTED void
+wxd_AuiToolBar_SetToolShortHelp(wxd_AuiToolBar_t* self, int tool_id, const char* help_string)
+{
+    if (!self)
+        return;
+    wxAuiToolBar* toolbar = (wxAuiToolBar*)self;
+    toolbar->SetToolShortHelp(tool_id, WXD_STR_TO_WX_STRING_UTF8_NULL_OK(help_string));
+}
+
+WXD_EXPORTED void
+wxd_AuiToolBar_SetToolSticky(wxd_AuiToolBar_t* self, int tool_id, bool sticky)
+{
+    if (!self)
+        return;
+    wxAuiToolBar* toolbar = (wxAuiToolBar*)self;
+    toolbar->SetToolSticky(tool_id, sticky);
+}
+
+// --- wxAuiToolBarEvent ---
+
+static wxAuiToolBarEvent*
+AsAuiToolBarEvent(wxd_Event_t* event)
+{
+    if (!event)
+        return nullptr;
+    return wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxAuiToolBarEvent);
+}
+
+WXD_EXPORTED bool
+wxd_AuiToolBarEvent_IsDropDownClicked(wxd_Event_t* event)
+{
+    wxAuiToolBarEvent* tb_event = AsAuiToolBarEvent(event);
+    return tb_event && tb_event->IsDropDownClicked();
+}
+
+WXD_EXPORTED wxd_Rect
+wxd_AuiToolBarEvent_GetItemRect(wxd_Event_t* event)
+{
+    wxAuiToolBarEvent* tb_event = AsAuiToolBarEvent(event);
+    if (!tb_event)
+        return wxd_Rect{ 0, 0, 0, 0 };
+    wxRect rect = tb_event->GetItemRect();
+    return wxd_Rect{ rect.x, rect.y, rect.width, rect.height };
+}
+
+WXD_EXPORTED wxd_Point
+wxd_AuiToolBarEvent_GetClickPoint(wxd_Event_t* event)
+{
+    wxAuiToolBarEvent* tb_event = AsAuiToolBarEvent(event);
+    if (!tb_event)
+        return wxd_Point{ -1, -1 };
+    wxPoint point = tb_event->GetClickPoint();
+    return wxd_Point{ point.x, point.y };
+}
+
 } // extern "C"
\ No newline at end of file
@@ -3,6 +3,8 @@
 #include "../include/wxdragon.h"
 #include <wx/image.h>  // For wxImage
 #include <wx/bitmap.h> // For wxBitmap
+#include <wx/mstream.h> // For wxMemoryInputStream/wxMemoryOutputStream
+#include "wxd_utils.h"
 #include <cstdlib>     // For malloc, free
 #include <cstring>     // For memcpy
 
@@ -66,6 +68,29 @@ wxd_Bitmap_CreateFromRGBA(const unsigned char* data, int width, int height)
     return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
 }
 
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_CreateFromRGB(const unsigned char* data, int width, int height)
+{
+    if (!data || width <= 0 || height <= 0) {
+        return nullptr;
+    }
+
+    // Static data is copied by wxImage, so the Rust buffer can be used directly
+    wxImage image(width, height, const_cast<unsigned char*>(data), true);
+    if (!image.IsOk()) {
+        WXD_LOG_ERROR("Failed to create wxImage from RGB data.");
+        return nullptr;
+    }
+
+    wxBitmap* bitmap = new (std::nothrow) wxBitmap(image.Copy(), -1);
+    if (!bitmap || !bitmap->IsOk()) {
+        WXD_LOG_ERROR("Failed to create wxBitmap from wxImage.");
+        delete bitmap;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
+}
+
 // Implementation for wxd_Bitmap_Destroy
 WXD_EXPORTED void
 wxd_Bitmap_Destroy(wxd_Bitmap_t* bitmap)
@@ -128,6 +153,30 @@ wxd_Bitmap_Clone(const wxd_Bitmap_t* bitmap)
     return reinterpret_cast<wxd_Bitmap_t*>(cloned_bmp);
 }
 
+WXD_EXPORTED bool
+wxd_Bitmap_HasAlpha(const wxd_Bitmap_t* bitmap)
+{
+    const wxBitmap* bmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    return bmp && bmp->IsOk() && bmp->HasAlpha();
+}
+
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_GetSubBitmap(const wxd_Bitmap_t* bitmap, int x, int y, int width, int height)
+{
+    const wxBitmap* bmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!bmp || !bmp->IsOk() || x < 0 || y < 0 || width <= 0 || height <= 0 ||
+        x + width > bmp->GetWidth() || y + height > bmp->GetHeight()) {
+        return nullptr;
+    }
+
+    wxBitmap* sub = new (std::nothrow) wxBitmap(bmp->GetSubBitmap(wxRect(x, y, width, height)));
+    if (!sub || !sub->IsOk()) {
+        delete sub;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(sub);
+}
+
 // Extract RGBA data from bitmap
 WXD_EXPORTED unsigned char*
 wxd_Bitmap_GetRGBAData(const wxd_Bitmap_t* bitmap, size_t* width, size_t* height)
@@ -197,6 +246,113 @@ wxd_Bitmap_FreeRGBAData(unsigned char* data)
     }
 }
 
+// --- Loading and saving ---
+
+static wxd_Bitmap_t*
+bitmap_from_image(const wxImage& image)
+{
+    if (!image.IsOk())
+        return nullptr;
+    wxBitmap* bitmap = new (std::nothrow) wxBitmap(image, -1);
+    if (!bitmap || !bitmap->IsOk()) {
+        delete bitmap;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
+}
+
+static bool
+image_for_saving(const wxd_Bitmap_t* bitmap, int quality, wxImage& image)
+{
+    wxd_cpp_utils::ensure_image_handlers();
+    const wxBitmap* bmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!bmp || !bmp->IsOk())
+        return false;
+    image = bmp->ConvertToImage();
+    if (!image.IsOk())
+        return false;
+    if (quality >= 0)
+        image.SetOption(wxIMAGE_OPTION_QUALITY, quality > 100 ? 100 : quality);
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_Bitmap_IsTypeSupported(wxd_BitmapType type)
+{
+    wxd_cpp_utils::ensure_image_handlers();
+    wxBitmapType wx_type = wxd_cpp_utils::to_wx_bitmap_type(type);
+    return wx_type == wxBITMAP_TYPE_ANY || wxImage::FindHandler(wx_type) != nullptr;
+}
+
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_CreateFromFile(const char* path)
+{
+    if (!path)
+        return nullptr;
+    wxd_cpp_utils::ensure_image_handlers();
+    wxLogNull no_log;
+    wxImage image;
+    if (!image.LoadFile(wxString::FromUTF8(path), wxBITMAP_TYPE_ANY))
+        return nullptr;
+    return bitmap_from_image(image);
+}
+
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Bitmap_CreateFromBytes(const unsigned char* data, size_t len)
+{
+    if (!data || len == 0)
+        return nullptr;
+    wxd_cpp_utils::ensure_image_handlers();
+    wxLogNull no_log;
+    wxMemoryInputStream stream(data, len);
+    wxImage image;
+    if (!image.LoadFile(stream, wxBITMAP_TYPE_ANY))
+        return nullptr;
+    return bitmap_from_image(image);
+}
+
+WXD_EXPORTED bool
+wxd_Bitmap_SaveFile(const wxd_Bitmap_t* bitmap, const char* path, wxd_BitmapType type, int quality)
+{
+    if (!path)
+        return false;
+    wxLogNull no_log;
+    wxImage image;
+    if (!image_for_saving(bitmap, quality, image))
+        return false;
+    return image.SaveFile(wxString::FromUTF8(path), wxd_cpp_utils::to_wx_bitmap_type(type));
+}
+
+WXD_EXPORTED unsigned char*
+wxd_Bitmap_Encode(const wxd_Bitmap_t* bitmap, wxd_BitmapType type, int quality, size_t* len)
+{
+    if (!len)
+        return nullptr;
+    *len = 0;
+    wxLogNull no_log;
+    wxImage image;
+    if (!image_for_saving(bitmap, quality, image))
+        return nullptr;
+
+    wxMemoryOutputStream stream;
+    if (!image.SaveFile(stream, wxd_cpp_utils::to_wx_bitmap_type(type)))
+        return nullptr;
+
+    size_t size = stream.GetSize();
+    unsigned char* data = static_cast<unsigned char*>(malloc(size > 0 ? size : 1));
+    if (!data)
+        return nullptr;
+    stream.CopyTo(data, size);
+    *len = size;
+    return data;
+}
+
+WXD_EXPORTED void
+wxd_Bitmap_FreeEncodedData(unsigned char* data)
+{
+    free(data);
+}
+
 // Get a pointer to wxNullBitmap
 WXD_EXPORTED const wxd_Bitmap_t*
 wxd_Bitmap_GetNull(void)
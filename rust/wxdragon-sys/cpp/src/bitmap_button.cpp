@@ -26,15 +26,15 @@ wxd_to_wx_size(const wxd_Size& s)
 WXD_EXPORTED wxd_BitmapButton_t*
 wxd_BitmapButton_Create(
     wxd_Window_t* parent, wxd_Id id,
-    const wxd_Bitmap_t* bitmap, // Main bitmap (normal state)
+    const wxd_BitmapBundle_t* bitmap, // Main bitmap (normal state)
     wxd_Point pos, wxd_Size size, wxd_Style_t style, const char* name_str,
-    const wxd_Bitmap_t* bitmap_disabled_wxd, // Disabled state bitmap (can be NULL)
-    const wxd_Bitmap_t* bitmap_focus_wxd,    // Focus state bitmap (can be NULL)
-    const wxd_Bitmap_t* bitmap_hover_wxd     // Hover state bitmap (can be NULL)
+    const wxd_BitmapBundle_t* bitmap_disabled_wxd, // Disabled state bitmap (can be NULL)
+    const wxd_BitmapBundle_t* bitmap_focus_wxd,    // Focus state bitmap (can be NULL)
+    const wxd_BitmapBundle_t* bitmap_hover_wxd     // Hover state bitmap (can be NULL)
 )
 {
     wxWindow* parentWin = reinterpret_cast<wxWindow*>(parent);
-    const wxBitmap* bmp_main = reinterpret_cast<const wxBitmap*>(bitmap);
+    const wxBitmapBundle* bmp_main = reinterpret_cast<const wxBitmapBundle*>(bitmap);
 
     if (!parentWin) {
         return nullptr;
@@ -44,7 +44,7 @@ wxd_BitmapButton_Create(
     wxBitmapButton* btn = nullptr;
     try {
         btn = new wxBitmapButton(parentWin, id,
-                                 bmp_main ? *bmp_main : wxNullBitmap, // Main bitmap
+                                 bmp_main ? *bmp_main : wxBitmapBundle(), // Main bitmap
                                  wxd_to_wx_point(pos), wxd_to_wx_size(size), style,
                                  wxDefaultValidator, WXD_STR_TO_WX_STRING_UTF8_NULL_OK(name_str));
     }
@@ -64,19 +64,19 @@ wxd_BitmapButton_Create(
 
     // Set other state bitmaps if provided
     if (bitmap_disabled_wxd) {
-        const wxBitmap* bmp_disabled = reinterpret_cast<const wxBitmap*>(bitmap_disabled_wxd);
+        const wxBitmapBundle* bmp_disabled = reinterpret_cast<const wxBitmapBundle*>(bitmap_disabled_wxd);
         if (bmp_disabled && bmp_disabled->IsOk()) {
             btn->SetBitmapDisabled(*bmp_disabled);
         }
     }
     if (bitmap_focus_wxd) {
-        const wxBitmap* bmp_focus = reinterpret_cast<const wxBitmap*>(bitmap_focus_wxd);
+        const wxBitmapBundle* bmp_focus = reinterpret_cast<const wxBitmapBundle*>(bitmap_focus_wxd);
         if (bmp_focus && bmp_focus->IsOk()) {
             btn->SetBitmapFocus(*bmp_focus);
         }
     }
     if (bitmap_hover_wxd) {
-        const wxBitmap* bmp_hover = reinterpret_cast<const wxBitmap*>(bitmap_hover_wxd);
+        const wxBitmapBundle* bmp_hover = reinterpret_cast<const wxBitmapBundle*>(bitmap_hover_wxd);
         if (bmp_hover && bmp_hover->IsOk()) {
             btn->SetBitmapHover(*bmp_hover);
         }
@@ -6,6 +6,22 @@
 #include <wx/bitmap.h>  // For wxBitmap
 #include <wx/arrstr.h>  // For GetStrings
 
+// The first bitmap fixes the size of all of them; others would break the layout
+// or be cut off, so they are scaled to it.
+static wxBitmap
+FitBitmap(wxBitmapComboBox* cb, const wxd_Bitmap_t* bitmap)
+{
+    const wxBitmap* wxBmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!wxBmp || !wxBmp->IsOk())
+        return wxNullBitmap;
+    wxSize size = cb->GetBitmapSize();
+    if (size.x <= 0 || size.y <= 0 || wxBmp->GetSize() == size)
+        return *wxBmp;
+    wxImage image = wxBmp->ConvertToImage();
+    image.Rescale(size.x, size.y, wxIMAGE_QUALITY_HIGH);
+    return wxBitmap(image);
+}
+
 extern "C" {
 
 WXD_EXPORTED wxd_BitmapComboBox_t*
@@ -35,29 +51,25 @@ wxd_BitmapComboBox_Append(wxd_BitmapComboBox_t* self, const char* item,
     if (!cb)
         return;
     wxString wxItem = wxString::FromUTF8(item ? item : "");
-    const wxBitmap* wxBmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    // Without a valid bitmap the item gets none
+    cb->Append(wxItem, FitBitmap(cb, bitmap));
+}
 
-    if (wxBmp && wxBmp->IsOk()) {
-        cb->Append(wxItem, *wxBmp);
-    }
-    else {
-        // Append without bitmap if bitmap is null or invalid
-        cb->Append(wxItem);
-        // Alternatively, use wxNullBitmap explicitly:
-        // cb->Append(wxItem, wxNullBitmap);
+WXD_EXPORTED void
+wxd_BitmapComboBox_Insert(wxd_BitmapComboBox_t* self, const char* item, const wxd_Bitmap_t* bitmap, unsigned int pos)
+{
+    wxBitmapComboBox* cb = (wxBitmapComboBox*)self;
+    if (cb && item && pos <= cb->GetCount()) {
+        cb->Insert(wxString::FromUTF8(item), FitBitmap(cb, bitmap), pos);
     }
 }
 
 WXD_EXPORTED void
-wxd_BitmapComboBox_Insert(wxd_BitmapComboBox_t* self, const char* item, const wxd_Bitmap_t* bitmap, unsigned int pos)
+wxd_BitmapComboBox_Delete(wxd_BitmapComboBox_t* self, unsigned int pos)
 {
     wxBitmapComboBox* cb = (wxBitmapComboBox*)self;
-    if (cb && item) {
-        if (bitmap) {
-            cb->Insert(wxString::FromUTF8(item), *(const wxBitmap*)bitmap, pos);
-        } else {
-            cb->Insert(wxString::FromUTF8(item), wxNullBitmap, pos);
-        }
+    if (cb && pos < cb->GetCount()) {
+        cb->Delete(pos);
     }
 }
 
@@ -151,9 +163,18 @@ wxd_BitmapComboBox_SetItemBitmap(wxd_BitmapComboBox_t* self, unsigned int n,
     wxBitmapComboBox* cb = (wxBitmapComboBox*)self;
     if (!cb || n >= cb->GetCount())
         return;
-    const wxBitmap* wxBmp = reinterpret_cast<const wxBitmap*>(bitmap);
     // Use wxNullBitmap if the provided bitmap pointer is null or invalid
-    cb->SetItemBitmap(n, (wxBmp && wxBmp->IsOk()) ? *wxBmp : wxNullBitmap);
+    cb->SetItemBitmap(n, FitBitmap(cb, bitmap));
+}
+
+WXD_EXPORTED wxd_Size
+wxd_BitmapComboBox_GetBitmapSize(wxd_BitmapComboBox_t* self)
+{
+    wxBitmapComboBox* cb = (wxBitmapComboBox*)self;
+    if (!cb)
+        return wxd_Size{ -1, -1 };
+    wxSize size = cb->GetBitmapSize();
+    return wxd_Size{ size.x, size.y };
 }
 
 // Destroy handled by parent window
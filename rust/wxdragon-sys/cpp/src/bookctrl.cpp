@@ -0,0 +1,177 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include "wx/bookctrl.h"
+#include "wx/choicebk.h"
+#include "wx/listbook.h"
+#include "wx/toolbook.h"
+#include "wx/imaglist.h"
+#include "wxd_utils.h"
+
+namespace {
+
+wxBookCtrlBase*
+AsBookCtrl(wxd_Window_t* self)
+{
+    return wxDynamicCast(reinterpret_cast<wxWindow*>(self), wxBookCtrlBase);
+}
+
+} // namespace
+
+extern "C" {
+
+WXD_EXPORTED wxd_Window_t*
+wxd_Choicebook_new(wxd_Window_t* parent, int id, int x, int y, int width, int height,
+                   wxd_Style_t style)
+{
+    wxChoicebook* book = new wxChoicebook(reinterpret_cast<wxWindow*>(parent), id, wxPoint(x, y),
+                                          wxSize(width, height), style);
+    return reinterpret_cast<wxd_Window_t*>(book);
+}
+
+WXD_EXPORTED wxd_Window_t*
+wxd_Listbook_new(wxd_Window_t* parent, int id, int x, int y, int width, int height,
+                 wxd_Style_t style)
+{
+    wxListbook* book = new wxListbook(reinterpret_cast<wxWindow*>(parent), id, wxPoint(x, y),
+                                      wxSize(width, height), style);
+    return reinterpret_cast<wxd_Window_t*>(book);
+}
+
+WXD_EXPORTED wxd_Window_t*
+wxd_Toolbook_new(wxd_Window_t* parent, int id, int x, int y, int width, int height,
+                 wxd_Style_t style)
+{
+    wxToolbook* book = new wxToolbook(reinterpret_cast<wxWindow*>(parent), id, wxPoint(x, y),
+                                      wxSize(width, height), style);
+    return reinterpret_cast<wxd_Window_t*>(book);
+}
+
+WXD_EXPORTED bool
+wxd_BookCtrl_AddPage(wxd_Window_t* self, wxd_Window_t* page, const char* text, bool select,
+                     int imageId)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || !page)
+        return false;
+    return book->AddPage(reinterpret_cast<wxWindow*>(page), wxString::FromUTF8(text ? text : ""),
+                         select, imageId);
+}
+
+WXD_EXPORTED bool
+wxd_BookCtrl_InsertPage(wxd_Window_t* self, size_t n, wxd_Window_t* page, const char* text,
+                        bool select, int imageId)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || !page || n > book->GetPageCount())
+        return false;
+    return book->InsertPage(n, reinterpret_cast<wxWindow*>(page),
+                            wxString::FromUTF8(text ? text : ""), select, imageId);
+}
+
+WXD_EXPORTED bool
+wxd_BookCtrl_DeletePage(wxd_Window_t* self, size_t n)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return false;
+    return book->DeletePage(n);
+}
+
+WXD_EXPORTED bool
+wxd_BookCtrl_RemovePage(wxd_Window_t* self, size_t n)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return false;
+    return book->RemovePage(n);
+}
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetPageCount(wxd_Window_t* self)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    return book ? static_cast<int>(book->GetPageCount()) : 0;
+}
+
+WXD_EXPORTED wxd_Window_t*
+wxd_BookCtrl_GetPage(wxd_Window_t* self, size_t n)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return nullptr;
+    return reinterpret_cast<wxd_Window_t*>(book->GetPage(n));
+}
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetSelection(wxd_Window_t* self)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    return book ? book->GetSelection() : wxNOT_FOUND;
+}
+
+WXD_EXPORTED int
+wxd_BookCtrl_SetSelection(wxd_Window_t* self, size_t n)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return wxNOT_FOUND;
+    return book->SetSelection(n);
+}
+
+WXD_EXPORTED int
+wxd_BookCtrl_ChangeSelection(wxd_Window_t* self, size_t n)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return wxNOT_FOUND;
+    return book->ChangeSelection(n);
+}
+
+WXD_EXPORTED void
+wxd_BookCtrl_SetPageText(wxd_Window_t* self, size_t n, const char* text)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return;
+    book->SetPageText(n, wxString::FromUTF8(text ? text : ""));
+}
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetPageText(wxd_Window_t* self, size_t n, char* buffer, size_t bufferLen)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return -1;
+    return static_cast<int>(
+        wxd_cpp_utils::copy_wxstring_to_buffer(book->GetPageText(n), buffer, bufferLen));
+}
+
+WXD_EXPORTED void
+wxd_BookCtrl_AssignImageList(wxd_Window_t* self, wxd_ImageList_t* imageList)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book)
+        return;
+    book->AssignImageList(reinterpret_cast<wxImageList*>(imageList));
+}
+
+WXD_EXPORTED bool
+wxd_BookCtrl_SetPageImage(wxd_Window_t* self, size_t n, int imageId)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return false;
+    return book->SetPageImage(n, imageId);
+}
+
+WXD_EXPORTED int
+wxd_BookCtrl_GetPageImage(wxd_Window_t* self, size_t n)
+{
+    wxBookCtrlBase* book = AsBookCtrl(self);
+    if (!book || n >= book->GetPageCount())
+        return wxNOT_FOUND;
+    return book->GetPageImage(n);
+}
+
+} // extern "C"
@@ -3,8 +3,10 @@
 #include "../include/wxdragon.h"
 #include <wx/checklst.h> // Include the correct header
 #include <wx/window.h>
+#include <wx/wupdlock.h>
 #include <wx/defs.h>   // For wxID_ANY, wxNOT_FOUND, wxDefaultPosition, wxDefaultSize
 #include "wxd_utils.h" // For wxd_cpp_utils::copy_wxstring_to_buffer
+#include <vector>
 
 // Helper function (already defined in event.cpp, consider moving to a common utils file later)
 // REMOVED: static int copy_wxstring_to_buffer(...) - Now using wxd_cpp_utils version
@@ -134,4 +136,49 @@ wxd_CheckListBox_Check(wxd_CheckListBox_t* clbox, unsigned int index, bool check
     }
 }
 
+WXD_EXPORTED size_t
+wxd_CheckListBox_GetCheckedItems(wxd_CheckListBox_t* clbox, unsigned int* items, size_t max_items)
+{
+    wxCheckListBox* wxClbox = reinterpret_cast<wxCheckListBox*>(clbox);
+    if (!wxClbox)
+        return 0;
+    wxArrayInt checked;
+    size_t count = (size_t)wxClbox->GetCheckedItems(checked);
+    for (size_t i = 0; items && i < count && i < max_items; ++i)
+        items[i] = (unsigned int)checked[i];
+    return count;
+}
+
+WXD_EXPORTED void
+wxd_CheckListBox_SetCheckedItems(wxd_CheckListBox_t* clbox, const unsigned int* items, size_t count)
+{
+    wxCheckListBox* wxClbox = reinterpret_cast<wxCheckListBox*>(clbox);
+    if (!wxClbox)
+        return;
+    std::vector<bool> checked(wxClbox->GetCount(), false);
+    for (size_t i = 0; items && i < count; ++i) {
+        if (items[i] < checked.size())
+            checked[items[i]] = true;
+    }
+    // Redraw once rather than for every item
+    wxWindowUpdateLocker noUpdates(wxClbox);
+    for (unsigned int index = 0; index < checked.size(); ++index) {
+        if (wxClbox->IsChecked(index) != checked[index])
+            wxClbox->Check(index, checked[index]);
+    }
+}
+
+WXD_EXPORTED void
+wxd_CheckListBox_CheckAll(wxd_CheckListBox_t* clbox, bool check)
+{
+    wxCheckListBox* wxClbox = reinterpret_cast<wxCheckListBox*>(clbox);
+    if (!wxClbox)
+        return;
+    wxWindowUpdateLocker noUpdates(wxClbox);
+    for (unsigned int index = 0; index < wxClbox->GetCount(); ++index) {
+        if (wxClbox->IsChecked(index) != check)
+            wxClbox->Check(index, check);
+    }
+}
+
 } // extern "C"
\ No newline at end of file
@@ -3,6 +3,60 @@
 #include "../include/wxdragon.h"
 #include <wx/clipbrd.h>
 #include <wx/dataobj.h>
+#include <wx/thread.h>
+
+namespace {
+
+// wxClipboard may only be used from the main thread, so opening it (which every
+// operation needs) fails elsewhere. The text helpers open the clipboard only if the
+// caller hasn't, so they can also be used while it is held open.
+class ScopedClipboardOpen {
+public:
+    explicit ScopedClipboardOpen(wxClipboard* clipboard)
+        : m_clipboard(clipboard), m_openedHere(false), m_ok(clipboard->IsOpened())
+    {
+        if (!m_ok) {
+            m_ok = m_openedHere = clipboard->Open();
+        }
+    }
+
+    ~ScopedClipboardOpen()
+    {
+        if (m_openedHere)
+            m_clipboard->Close();
+    }
+
+    bool IsOk() const { return m_ok; }
+
+private:
+    wxClipboard* m_clipboard;
+    bool m_openedHere;
+    bool m_ok;
+};
+
+} // namespace
+
+// Maps the format ids used by the Rust DataFormat constants
+static wxDataFormat
+to_data_format(int format)
+{
+    switch (format) {
+    case 1: // wxDF_TEXT
+        return wxDataFormat(wxDF_TEXT);
+    case 2: // wxDF_BITMAP
+        return wxDataFormat(wxDF_BITMAP);
+    case 4: // wxDF_FILENAME
+        return wxDataFormat(wxDF_FILENAME);
+    default:
+        return wxDataFormat((wxDataFormatId)format);
+    }
+}
+
+static bool
+has_text(wxClipboard* clipboard)
+{
+    return clipboard->IsSupported(wxDF_UNICODETEXT) || clipboard->IsSupported(wxDF_TEXT);
+}
 
 extern "C" {
 
@@ -15,7 +69,7 @@ wxd_Clipboard_Get()
 bool
 wxd_Clipboard_Open(wxd_Clipboard_t* clipboard)
 {
-    if (!clipboard)
+    if (!clipboard || !wxIsMainThread())
         return false;
     wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
     return wx_clipboard->Open();
@@ -65,26 +119,7 @@ wxd_Clipboard_IsSupported(wxd_Clipboard_t* clipboard, int format)
     if (!clipboard)
         return false;
     wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
-
-    wxDataFormat dataFormat;
-
-    // Special handling for different formats
-    switch (format) {
-    case 1: // wxDF_TEXT
-        dataFormat = wxDF_TEXT;
-        break;
-    case 2: // wxDF_BITMAP
-        dataFormat = wxDF_BITMAP;
-        break;
-    case 4: // wxDF_FILENAME
-        dataFormat = wxDF_FILENAME;
-        break;
-    default:
-        dataFormat = (wxDataFormatId)format;
-        break;
-    }
-
-    return wx_clipboard->IsSupported(dataFormat);
+    return wx_clipboard->IsSupported(to_data_format(format));
 }
 
 bool
@@ -100,7 +135,7 @@ wxd_Clipboard_GetData(wxd_Clipboard_t* clipboard, wxd_DataObject_t* data)
 void
 wxd_Clipboard_Clear(wxd_Clipboard_t* clipboard)
 {
-    if (!clipboard)
+    if (!clipboard || !wxIsMainThread())
         return;
     wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
     wx_clipboard->Clear();
@@ -109,7 +144,7 @@ wxd_Clipboard_Clear(wxd_Clipboard_t* clipboard)
 bool
 wxd_Clipboard_Flush(wxd_Clipboard_t* clipboard)
 {
-    if (!clipboard)
+    if (!clipboard || !wxIsMainThread())
         return false;
     wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
     return wx_clipboard->Flush();
@@ -128,43 +163,157 @@ wxd_Clipboard_UsePrimarySelection(wxd_Clipboard_t* clipboard, bool use_primary)
 bool
 wxd_Clipboard_SetText(wxd_Clipboard_t* clipboard, const char* text)
 {
-    if (!clipboard || !text)
+    if (!clipboard || !text || !wxIsMainThread())
         return false;
     wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
 
-    if (!wx_clipboard->Open()) {
+    ScopedClipboardOpen open(wx_clipboard);
+    if (!open.IsOk())
         return false;
-    }
-
-    wxTextDataObject* data = new wxTextDataObject(wxString::FromUTF8(text));
-    bool success = wx_clipboard->SetData(data);
-    wx_clipboard->Close();
-
-    return success;
+    return wx_clipboard->SetData(new wxTextDataObject(wxString::FromUTF8(text)));
 }
 
 WXD_EXPORTED int
 wxd_Clipboard_GetText(const wxd_Clipboard_t* clipboard, char* buffer, size_t buffer_len)
 {
-    if (!clipboard)
+    if (!clipboard || !wxIsMainThread())
         return -1;
     wxClipboard* cb = const_cast<wxClipboard*>(reinterpret_cast<const wxClipboard*>(clipboard));
 
-    if (!cb->Open()) {
+    ScopedClipboardOpen open(cb);
+    if (!open.IsOk())
         return -1;
-    }
 
-    int len = -1;
-    if (cb->IsSupported(wxDF_TEXT) || cb->IsSupported(wxDF_UNICODETEXT)) {
-        wxTextDataObject data;
-        if (cb->GetData(data)) {
-            wxString text = data.GetText();
-            len = (int)wxd_cpp_utils::copy_wxstring_to_buffer(text, buffer, buffer_len);
-        }
-    }
+    wxTextDataObject data;
+    if (!has_text(cb) || !cb->GetData(data))
+        return -1;
+    return (int)wxd_cpp_utils::copy_wxstring_to_buffer(data.GetText(), buffer, buffer_len);
+}
+
+WXD_EXPORTED bool
+wxd_Clipboard_HasText(wxd_Clipboard_t* clipboard)
+{
+    if (!clipboard || !wxIsMainThread())
+        return false;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+
+    ScopedClipboardOpen open(wx_clipboard);
+    return open.IsOk() && has_text(wx_clipboard);
+}
 
-    cb->Close();
-    return len;
+WXD_EXPORTED bool
+wxd_Clipboard_IsFormatAvailable(wxd_Clipboard_t* clipboard, int format)
+{
+    if (!clipboard || !wxIsMainThread())
+        return false;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+
+    ScopedClipboardOpen open(wx_clipboard);
+    if (!open.IsOk())
+        return false;
+    if (format == 1)
+        return has_text(wx_clipboard);
+    return wx_clipboard->IsSupported(to_data_format(format));
+}
+
+WXD_EXPORTED bool
+wxd_Clipboard_SetBitmap(wxd_Clipboard_t* clipboard, const wxd_Bitmap_t* bitmap)
+{
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!clipboard || !wx_bitmap || !wx_bitmap->IsOk() || !wxIsMainThread())
+        return false;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+
+    ScopedClipboardOpen open(wx_clipboard);
+    if (!open.IsOk())
+        return false;
+    return wx_clipboard->SetData(new wxBitmapDataObject(*wx_bitmap));
+}
+
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Clipboard_GetBitmap(wxd_Clipboard_t* clipboard)
+{
+    if (!clipboard || !wxIsMainThread())
+        return nullptr;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+
+    ScopedClipboardOpen open(wx_clipboard);
+    if (!open.IsOk() || !wx_clipboard->IsSupported(wxDF_BITMAP))
+        return nullptr;
+
+    wxBitmapDataObject data;
+    if (!wx_clipboard->GetData(data) || !data.GetBitmap().IsOk())
+        return nullptr;
+    return reinterpret_cast<wxd_Bitmap_t*>(new wxBitmap(data.GetBitmap()));
+}
+
+WXD_EXPORTED bool
+wxd_Clipboard_SetFiles(wxd_Clipboard_t* clipboard, const wxd_ArrayString_t* files)
+{
+    const wxArrayString* wx_files = reinterpret_cast<const wxArrayString*>(files);
+    if (!clipboard || !wx_files || wx_files->IsEmpty() || !wxIsMainThread())
+        return false;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+
+    ScopedClipboardOpen open(wx_clipboard);
+    if (!open.IsOk())
+        return false;
+
+    wxFileDataObject* data = new wxFileDataObject();
+    for (const wxString& file : *wx_files)
+        data->AddFile(file);
+    return wx_clipboard->SetData(data);
+}
+
+WXD_EXPORTED bool
+wxd_Clipboard_GetFiles(wxd_Clipboard_t* clipboard, wxd_ArrayString_t* files)
+{
+    wxArrayString* wx_files = reinterpret_cast<wxArrayString*>(files);
+    if (!clipboard || !wx_files || !wxIsMainThread())
+        return false;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+
+    ScopedClipboardOpen open(wx_clipboard);
+    if (!open.IsOk() || !wx_clipboard->IsSupported(wxDF_FILENAME))
+        return false;
+
+    wxFileDataObject data;
+    if (!wx_clipboard->GetData(data))
+        return false;
+    *wx_files = data.GetFilenames();
+    return true;
+}
+
+bool
+wxd_Clipboard_IsCustomFormatAvailable(wxd_Clipboard_t* clipboard, const char* format)
+{
+    if (!clipboard || !format || !*format || !wxIsMainThread())
+        return false;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+
+    ScopedClipboardOpen open(wx_clipboard);
+    return open.IsOk() &&
+           wx_clipboard->IsSupported(wxd_cpp_utils::custom_data_format(wxString::FromUTF8(format)));
+}
+
+wxd_CustomDataObject_t*
+wxd_Clipboard_GetCustomData(wxd_Clipboard_t* clipboard, const char* format)
+{
+    if (!clipboard || !format || !*format || !wxIsMainThread())
+        return nullptr;
+    wxClipboard* wx_clipboard = reinterpret_cast<wxClipboard*>(clipboard);
+    const wxDataFormat data_format = wxd_cpp_utils::custom_data_format(wxString::FromUTF8(format));
+
+    ScopedClipboardOpen open(wx_clipboard);
+    if (!open.IsOk() || !wx_clipboard->IsSupported(data_format))
+        return nullptr;
+
+    wxCustomDataObject* data = new wxCustomDataObject(data_format);
+    if (!wx_clipboard->GetData(*data)) {
+        delete data;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_CustomDataObject_t*>(data);
 }
 
 } // extern "C"
\ No newline at end of file
@@ -83,9 +83,18 @@ wxd_ColourData_GetCustomColour(wxd_ColourData_t* self, int i)
     wxColourData* data = reinterpret_cast<wxColourData*>(self);
     if (!data) return wxd_Colour_t{0, 0, 0, 0};
     wxColour c = data->GetCustomColour(i);
+    if (!c.IsOk()) return wxd_Colour_t{0, 0, 0, 0};
     return to_wxd(c);
 }
 
+WXD_EXPORTED bool
+wxd_ColourData_HasCustomColour(wxd_ColourData_t* self, int i)
+{
+    wxColourData* data = reinterpret_cast<wxColourData*>(self);
+    if (!data || i < 0 || i >= wxColourData::NUM_CUSTOM) return false;
+    return data->GetCustomColour(i).IsOk();
+}
+
 void
 wxd_ColourData_Destroy(wxd_ColourData_t* self)
 {
@@ -101,15 +110,9 @@ wxd_ColourDialog_t*
 wxd_ColourDialog_Create(wxd_Window_t* parent, const char* title, wxd_ColourData_t* data)
 {
     wxWindow* parentWin = reinterpret_cast<wxWindow*>(parent);
-    wxColourData* colourData = nullptr;
-
-    if (data) {
-        colourData = reinterpret_cast<wxColourData*>(data);
-    }
-    else {
-        // If no data provided, create a default one
-        colourData = new wxColourData();
-    }
+    // wxColourDialog copies the data, so a default on the stack is enough when none is given
+    wxColourData defaultData;
+    wxColourData* colourData = data ? reinterpret_cast<wxColourData*>(data) : &defaultData;
 
     wxColourDialog* dialog = new wxColourDialog(parentWin, colourData);
 
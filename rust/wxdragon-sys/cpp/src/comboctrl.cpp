@@ -0,0 +1,203 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include <wx/combo.h>
+#include "../include/wxdragon.h"
+#include "wxd_utils.h"
+
+static void
+SetPopupStringValue(void* sink, const char* value)
+{
+    *static_cast<wxString*>(sink) = wxString::FromUTF8(value ? value : "");
+}
+
+// A wxComboPopup holding a panel whose content is created in Rust, with the
+// text shown in the combo computed by Rust callbacks.
+class WxdComboPopup : public wxComboPopup {
+public:
+    ~WxdComboPopup() override
+    {
+        ReleaseCallbacks();
+    }
+
+    bool Create(wxWindow* parent) override
+    {
+        m_panel = new wxPanel(parent, wxID_ANY, wxDefaultPosition, wxDefaultSize, wxBORDER_NONE);
+        return true;
+    }
+
+    wxWindow* GetControl() override
+    {
+        return m_panel;
+    }
+
+    wxString GetStringValue() const override
+    {
+        // Without a value from Rust the text stays as it is
+        wxString value = GetComboCtrl()->GetValue();
+        if (m_vtable.get_string_value)
+            m_vtable.get_string_value(m_user_data, &value, SetPopupStringValue);
+        return value;
+    }
+
+    wxSize GetAdjustedSize(int minWidth, int prefHeight, int maxHeight) override
+    {
+        int width = m_size.x > 0 ? m_size.x : minWidth;
+        int height = m_size.y > 0 ? m_size.y : prefHeight;
+        if (height <= 0 && m_panel)
+            height = m_panel->GetBestSize().y;
+        if (maxHeight > 0 && height > maxHeight)
+            height = maxHeight;
+        return wxSize(width, height);
+    }
+
+    // Destroys the content and the callbacks, keeping the size
+    void Clear()
+    {
+        ReleaseCallbacks();
+        if (m_panel) {
+            m_panel->SetSizer(nullptr);
+            m_panel->DestroyChildren();
+        }
+    }
+
+    void SetCallbacks(const wxd_ComboPopup_vtable* vtable, void* user_data)
+    {
+        ReleaseCallbacks();
+        m_vtable = *vtable;
+        m_user_data = user_data;
+    }
+
+    void SetSize(const wxSize& size)
+    {
+        m_size = size;
+    }
+
+private:
+    void ReleaseCallbacks()
+    {
+        if (m_vtable.destroy)
+            m_vtable.destroy(m_user_data);
+        m_vtable = wxd_ComboPopup_vtable();
+        m_user_data = nullptr;
+    }
+
+    wxPanel* m_panel = nullptr;
+    wxd_ComboPopup_vtable m_vtable = wxd_ComboPopup_vtable();
+    void* m_user_data = nullptr;
+    wxSize m_size = wxDefaultSize;
+};
+
+static WxdComboPopup*
+GetWxdPopup(wxd_ComboCtrl_t* combo)
+{
+    wxComboCtrl* ctrl = reinterpret_cast<wxComboCtrl*>(combo);
+    if (!ctrl)
+        return nullptr;
+    return dynamic_cast<WxdComboPopup*>(ctrl->GetPopupControl());
+}
+
+extern "C" {
+
+WXD_EXPORTED wxd_ComboCtrl_t*
+wxd_ComboCtrl_Create(wxd_Window_t* parent, wxd_Id id, const char* value, wxd_Point pos,
+                     wxd_Size size, wxd_Style_t style)
+{
+    wxWindow* parentWin = reinterpret_cast<wxWindow*>(parent);
+    if (!parentWin)
+        return nullptr;
+
+    wxComboCtrl* ctrl = new wxComboCtrl(parentWin, id, wxString::FromUTF8(value ? value : ""),
+                                        wxd_cpp_utils::to_wx(pos), wxd_cpp_utils::to_wx(size),
+                                        style);
+    // The combo owns the popup. It isn't created lazily, so its panel exists
+    // from here on and is reused for all content.
+    ctrl->SetPopupControl(new WxdComboPopup());
+    return reinterpret_cast<wxd_ComboCtrl_t*>(ctrl);
+}
+
+WXD_EXPORTED wxd_Window_t*
+wxd_ComboCtrl_SetPopupContent(wxd_ComboCtrl_t* combo)
+{
+    wxComboCtrl* ctrl = reinterpret_cast<wxComboCtrl*>(combo);
+    if (!ctrl)
+        return nullptr;
+    WxdComboPopup* popup = GetWxdPopup(combo);
+    if (popup) {
+        popup->Clear();
+    }
+    else {
+        // A combo loaded from XRC starts without one
+        popup = new WxdComboPopup();
+        ctrl->SetPopupControl(popup);
+    }
+    return reinterpret_cast<wxd_Window_t*>(popup->GetControl());
+}
+
+WXD_EXPORTED bool
+wxd_ComboCtrl_SetPopupCallbacks(wxd_ComboCtrl_t* combo, const wxd_ComboPopup_vtable* vtable,
+                                void* user_data)
+{
+    if (!vtable)
+        return false;
+    WxdComboPopup* popup = GetWxdPopup(combo);
+    if (!popup) {
+        // Owns user_data, so it is released on failure too
+        if (vtable->destroy)
+            vtable->destroy(user_data);
+        return false;
+    }
+    popup->SetCallbacks(vtable, user_data);
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_ComboCtrl_SetPopupSize(wxd_ComboCtrl_t* combo, wxd_Size size)
+{
+    WxdComboPopup* popup = GetWxdPopup(combo);
+    if (!popup)
+        return false;
+    popup->SetSize(wxd_cpp_utils::to_wx(size));
+    return true;
+}
+
+WXD_EXPORTED void
+wxd_ComboCtrl_SetValue(wxd_ComboCtrl_t* combo, const char* value)
+{
+    wxComboCtrl* ctrl = reinterpret_cast<wxComboCtrl*>(combo);
+    if (ctrl)
+        ctrl->SetValue(wxString::FromUTF8(value ? value : ""));
+}
+
+WXD_EXPORTED int
+wxd_ComboCtrl_GetValue(wxd_ComboCtrl_t* combo, char* buffer, size_t buffer_len)
+{
+    wxComboCtrl* ctrl = reinterpret_cast<wxComboCtrl*>(combo);
+    if (!ctrl)
+        return -1;
+    return (int)wxd_cpp_utils::copy_wxstring_to_buffer(ctrl->GetValue(), buffer, buffer_len);
+}
+
+WXD_EXPORTED void
+wxd_ComboCtrl_ShowPopup(wxd_ComboCtrl_t* combo)
+{
+    wxComboCtrl* ctrl = reinterpret_cast<wxComboCtrl*>(combo);
+    if (ctrl)
+        ctrl->Popup();
+}
+
+WXD_EXPORTED void
+wxd_ComboCtrl_DismissPopup(wxd_ComboCtrl_t* combo)
+{
+    wxComboCtrl* ctrl = reinterpret_cast<wxComboCtrl*>(combo);
+    if (ctrl)
+        ctrl->Dismiss();
+}
+
+WXD_EXPORTED bool
+wxd_ComboCtrl_IsPopupShown(wxd_ComboCtrl_t* combo)
+{
+    wxComboCtrl* ctrl = reinterpret_cast<wxComboCtrl*>(combo);
+    return ctrl && ctrl->IsPopupShown();
+}
+
+} // extern "C"
@@ -0,0 +1,70 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_stopwatch.h"
+#include <wx/stopwatch.h>
+
+extern "C" {
+
+// Create a new running wxStopWatch
+WXD_EXPORTED wxd_StopWatch_t*
+wxd_StopWatch_Create(void)
+{
+    return reinterpret_cast<wxd_StopWatch_t*>(new wxStopWatch());
+}
+
+// Destroy a wxStopWatch
+WXD_EXPORTED void
+wxd_StopWatch_Destroy(wxd_StopWatch_t* self)
+{
+    if (!self)
+        return;
+    delete reinterpret_cast<wxStopWatch*>(self);
+}
+
+// (Re)start the stopwatch
+WXD_EXPORTED void
+wxd_StopWatch_Start(wxd_StopWatch_t* self, int64_t time0_ms)
+{
+    if (!self)
+        return;
+    reinterpret_cast<wxStopWatch*>(self)->Start(static_cast<long>(time0_ms));
+}
+
+// Pause the stopwatch
+WXD_EXPORTED void
+wxd_StopWatch_Pause(wxd_StopWatch_t* self)
+{
+    if (!self)
+        return;
+    reinterpret_cast<wxStopWatch*>(self)->Pause();
+}
+
+// Resume the stopwatch
+WXD_EXPORTED void
+wxd_StopWatch_Resume(wxd_StopWatch_t* self)
+{
+    if (!self)
+        return;
+    reinterpret_cast<wxStopWatch*>(self)->Resume();
+}
+
+// Elapsed time in milliseconds
+WXD_EXPORTED int64_t
+wxd_StopWatch_Time(const wxd_StopWatch_t* self)
+{
+    if (!self)
+        return 0;
+    return reinterpret_cast<const wxStopWatch*>(self)->Time();
+}
+
+// Elapsed time in microseconds
+WXD_EXPORTED int64_t
+wxd_StopWatch_TimeInMicro(const wxd_StopWatch_t* self)
+{
+    if (!self)
+        return 0;
+    return reinterpret_cast<const wxStopWatch*>(self)->TimeInMicro().GetValue();
+}
+
+} // extern "C"
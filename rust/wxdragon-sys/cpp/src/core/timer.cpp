@@ -3,6 +3,65 @@
 #include "../../include/wxdragon.h"
 #include "../../include/core/wxd_timer.h"
 #include <wx/timer.h>
+#include <set>
+
+namespace {
+
+// One-shot timer running a delayed call. It is owned by the set of pending calls below and
+// deleted after it fired, from the event loop rather than from inside Notify().
+class WxdCallLaterTimer : public wxTimer {
+public:
+    WxdCallLaterTimer(wxd_CallLaterCallback callback, void* user_data, wxd_CallLaterFreeCallback free_fn)
+        : m_callback(callback), m_userData(user_data), m_freeFn(free_fn)
+    {
+    }
+
+    ~WxdCallLaterTimer()
+    {
+        if (m_freeFn && m_userData) {
+            m_freeFn(m_userData);
+        }
+    }
+
+    virtual void
+    Notify() override;
+
+private:
+    wxd_CallLaterCallback m_callback;
+    void* m_userData;
+    wxd_CallLaterFreeCallback m_freeFn;
+};
+
+std::set<WxdCallLaterTimer*>&
+PendingCallLaterTimers()
+{
+    static std::set<WxdCallLaterTimer*> timers;
+    return timers;
+}
+
+void
+WxdCallLaterTimer::Notify()
+{
+    if (PendingCallLaterTimers().erase(this) == 0) {
+        return;
+    }
+    if (m_callback) {
+        m_callback(m_userData);
+    }
+    // Free the user data right away; only the timer itself waits for the event loop.
+    if (m_freeFn && m_userData) {
+        m_freeFn(m_userData);
+    }
+    m_userData = nullptr;
+    if (wxTheApp) {
+        wxTheApp->CallAfter([this]() { delete this; });
+    }
+    else {
+        delete this;
+    }
+}
+
+} // namespace
 
 extern "C" {
 
@@ -88,4 +147,50 @@ wxd_Timer_SetInterval(wxd_Timer_t* self, int milliseconds)
     // If not running, the interval will be used on the next Start() call
 }
 
-} // extern "C"
\ No newline at end of file
+// Schedule a one-shot delayed call owned by the library
+WXD_EXPORTED wxd_CallLater_t*
+wxd_CallLater(int milliseconds, wxd_CallLaterCallback callback, void* user_data, wxd_CallLaterFreeCallback free_fn)
+{
+    if (!callback || !wxTheApp || !wxIsMainThread()) {
+        if (free_fn && user_data) {
+            free_fn(user_data);
+        }
+        return nullptr;
+    }
+
+    WxdCallLaterTimer* timer = new WxdCallLaterTimer(callback, user_data, free_fn);
+    if (!timer->StartOnce(milliseconds < 0 ? 0 : milliseconds)) {
+        delete timer;
+        return nullptr;
+    }
+    PendingCallLaterTimers().insert(timer);
+    return reinterpret_cast<wxd_CallLater_t*>(timer);
+}
+
+// Stop and free a delayed call that hasn't fired yet
+WXD_EXPORTED bool
+wxd_CallLater_Cancel(wxd_CallLater_t* call)
+{
+    WxdCallLaterTimer* timer = reinterpret_cast<WxdCallLaterTimer*>(call);
+    // Fired calls have left the set and may already be gone, so only touch pending ones
+    if (!timer || PendingCallLaterTimers().erase(timer) == 0) {
+        return false;
+    }
+    timer->Stop();
+    delete timer;
+    return true;
+}
+
+// Stop and free all delayed calls that haven't fired yet
+WXD_EXPORTED void
+wxd_CallLater_CleanupAll(void)
+{
+    std::set<WxdCallLaterTimer*> pending;
+    pending.swap(PendingCallLaterTimers());
+    for (WxdCallLaterTimer* timer : pending) {
+        timer->Stop();
+        delete timer;
+    }
+}
+
+} // extern "C"
@@ -1,11 +1,66 @@
 #include <wx/wxprec.h>
 #include <wx/wx.h>
 #include "../include/wxdragon.h"
+#include "wxd_utils.h"
 #include <wx/cursor.h>
 #include <wx/bitmap.h>
 #include <wx/utils.h>
+#include <wx/image.h>
+#include <wx/settings.h>
+#include <algorithm>
 #include <cstring>
 
+// Windows shows cursors at the system cursor size (32x32 at 100% scaling) and
+// stretches other sizes, so larger images are scaled down to it first. The other
+// ports accept any reasonable size.
+static wxSize
+max_cursor_size()
+{
+#ifdef __WXMSW__
+    const int width = wxSystemSettings::GetMetric(wxSYS_CURSOR_X);
+    const int height = wxSystemSettings::GetMetric(wxSYS_CURSOR_Y);
+    return wxSize(width > 0 ? width : 32, height > 0 ? height : 32);
+#else
+    return wxSize(-1, -1);
+#endif
+}
+
+static wxd_Cursor_t*
+cursor_from_image(const wxImage& source, int hotspot_x, int hotspot_y)
+{
+    if (!source.IsOk())
+        return nullptr;
+
+    wxImage image = source;
+    const wxSize max_size = max_cursor_size();
+    if (max_size.x > 0 && (image.GetWidth() > max_size.x || image.GetHeight() > max_size.y)) {
+        // Keep the aspect ratio and move the hotspot along with the pixels
+        const double scale = std::min(static_cast<double>(max_size.x) / image.GetWidth(),
+                                      static_cast<double>(max_size.y) / image.GetHeight());
+        const int width = std::max(1, static_cast<int>(image.GetWidth() * scale));
+        const int height = std::max(1, static_cast<int>(image.GetHeight() * scale));
+        hotspot_x = static_cast<int>(hotspot_x * scale);
+        hotspot_y = static_cast<int>(hotspot_y * scale);
+        image = image.Scale(width, height, wxIMAGE_QUALITY_HIGH);
+    }
+    else {
+        // The hotspot options are set below, don't touch the caller's image
+        image = image.Copy();
+    }
+
+    hotspot_x = std::max(0, std::min(hotspot_x, image.GetWidth() - 1));
+    hotspot_y = std::max(0, std::min(hotspot_y, image.GetHeight() - 1));
+    image.SetOption(wxIMAGE_OPTION_CUR_HOTSPOT_X, hotspot_x);
+    image.SetOption(wxIMAGE_OPTION_CUR_HOTSPOT_Y, hotspot_y);
+
+    wxCursor* cursor = new (std::nothrow) wxCursor(image);
+    if (!cursor || !cursor->IsOk()) {
+        delete cursor;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_Cursor_t*>(cursor);
+}
+
 extern "C" {
 
 // Mapping function to convert our enum to wxWidgets stock cursor IDs
@@ -74,35 +129,6 @@ map_stock_cursor(wxd_StockCursor cursor_id)
     }
 }
 
-static wxBitmapType
-map_bitmap_type(wxd_BitmapType type)
-{
-    switch (type) {
-    case WXD_BITMAP_TYPE_BMP:
-        return wxBITMAP_TYPE_BMP;
-    case WXD_BITMAP_TYPE_ICO:
-        return wxBITMAP_TYPE_ICO;
-    case WXD_BITMAP_TYPE_CUR:
-        return wxBITMAP_TYPE_CUR;
-    case WXD_BITMAP_TYPE_XBM:
-        return wxBITMAP_TYPE_XBM;
-    case WXD_BITMAP_TYPE_XPM:
-        return wxBITMAP_TYPE_XPM;
-    case WXD_BITMAP_TYPE_PNG:
-        return wxBITMAP_TYPE_PNG;
-    case WXD_BITMAP_TYPE_JPEG:
-        return wxBITMAP_TYPE_JPEG;
-    case WXD_BITMAP_TYPE_GIF:
-        return wxBITMAP_TYPE_GIF;
-    case WXD_BITMAP_TYPE_ANI:
-        return wxBITMAP_TYPE_ANI;
-    case WXD_BITMAP_TYPE_ANY:
-        return wxBITMAP_TYPE_ANY;
-    default:
-        return wxBITMAP_TYPE_ANY;
-    }
-}
-
 // --- Cursor Creation Functions ---
 
 WXD_EXPORTED wxd_Cursor_t*
@@ -131,7 +157,7 @@ wxd_Cursor_CreateFromFile(const char* filename, wxd_BitmapType type, int hotspot
 
     try {
         wxString wx_filename = wxString::FromUTF8(filename);
-        wxBitmapType wx_type = map_bitmap_type(type);
+        wxBitmapType wx_type = wxd_cpp_utils::to_wx_bitmap_type(type);
         wxCursor* cursor = new wxCursor(wx_filename, wx_type, hotspot_x, hotspot_y);
 
         if (cursor && cursor->IsOk()) {
@@ -238,24 +264,30 @@ wxd_Cursor_CreateFromData(const unsigned char* bits, int width, int height, int
 }
 
 WXD_EXPORTED wxd_Cursor_t*
-wxd_Cursor_CreateFromImage(const wxd_Bitmap_t* image)
+wxd_Cursor_CreateFromImage(const wxd_Image_t* image, int hotspot_x, int hotspot_y)
 {
     if (!image) {
         return nullptr;
     }
 
     try {
-        const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(image);
-        wxImage wx_image = wx_bitmap->ConvertToImage();
-        wxCursor* cursor = new wxCursor(wx_image);
+        return cursor_from_image(*reinterpret_cast<const wxImage*>(image), hotspot_x, hotspot_y);
+    }
+    catch (...) {
+        return nullptr;
+    }
+}
 
-        if (cursor && cursor->IsOk()) {
-            return reinterpret_cast<wxd_Cursor_t*>(cursor);
-        }
-        else {
-            delete cursor;
-            return nullptr;
-        }
+WXD_EXPORTED wxd_Cursor_t*
+wxd_Cursor_CreateFromBitmap(const wxd_Bitmap_t* bitmap, int hotspot_x, int hotspot_y)
+{
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!wx_bitmap || !wx_bitmap->IsOk()) {
+        return nullptr;
+    }
+
+    try {
+        return cursor_from_image(wx_bitmap->ConvertToImage(), hotspot_x, hotspot_y);
     }
     catch (...) {
         return nullptr;
@@ -155,4 +155,48 @@ wxd_BitmapDataObject_GetBitmap(wxd_BitmapDataObject_t* data_object)
     return reinterpret_cast<wxd_Bitmap_t*>(new_bitmap);
 }
 
-} // extern "C"
\ No newline at end of file
+// --- DataObjectComposite Functions ---
+
+wxd_DataObjectComposite_t*
+wxd_DataObjectComposite_Create()
+{
+    return reinterpret_cast<wxd_DataObjectComposite_t*>(new wxDataObjectComposite());
+}
+
+void
+wxd_DataObjectComposite_Add(wxd_DataObjectComposite_t* composite, wxd_DataObject_t* data_object,
+                            bool preferred)
+{
+    if (!composite || !data_object)
+        return;
+    wxDataObjectComposite* wx_composite = reinterpret_cast<wxDataObjectComposite*>(composite);
+    wx_composite->Add(reinterpret_cast<wxDataObjectSimple*>(data_object), preferred);
+}
+
+// --- CustomDataObject Functions ---
+
+wxd_CustomDataObject_t*
+wxd_CustomDataObject_Create(const char* format, const unsigned char* data, size_t len)
+{
+    if (!format || !*format)
+        return nullptr;
+    wxCustomDataObject* data_object = new wxCustomDataObject(wxd_cpp_utils::custom_data_format(wxString::FromUTF8(format)));
+    if (data && len > 0)
+        data_object->SetData(len, data);
+    return reinterpret_cast<wxd_CustomDataObject_t*>(data_object);
+}
+
+size_t
+wxd_CustomDataObject_GetData(const wxd_CustomDataObject_t* data_object, unsigned char* buffer,
+                             size_t buffer_len)
+{
+    if (!data_object)
+        return 0;
+    const wxCustomDataObject* wx_data_object = reinterpret_cast<const wxCustomDataObject*>(data_object);
+    const size_t size = wx_data_object->GetSize();
+    if (buffer && buffer_len > 0 && size > 0)
+        memcpy(buffer, wx_data_object->GetData(), wxMin(size, buffer_len));
+    return size;
+}
+
+} // extern "C"
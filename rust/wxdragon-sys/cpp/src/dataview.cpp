@@ -740,6 +740,18 @@ wxd_DataViewCtrl_UnselectAll(wxd_Window_t* self)
     ctrl->UnselectAll();
 }
 
+WXD_EXPORTED bool
+wxd_DataViewCtrl_EnableDragSource(wxd_Window_t* self, const char* format)
+{
+    if (!self)
+        return false;
+
+    wxDataViewCtrl* ctrl = reinterpret_cast<wxDataViewCtrl*>(self);
+    if (!format || !*format)
+        return ctrl->EnableDragSource(wxDataFormat(wxDF_UNICODETEXT));
+    return ctrl->EnableDragSource(wxd_cpp_utils::custom_data_format(wxString::FromUTF8(format)));
+}
+
 // Standard DataViewListModel implementation
 class WxDDataViewListModel : public wxDataViewListStore {
 private:
@@ -1233,6 +1245,25 @@ wxd_DataViewColumn_IsSortable(wxd_DataViewColumn_t* self)
     return false; // Default if col is null
 }
 
+WXD_EXPORTED void
+wxd_DataViewColumn_SetHidden(wxd_DataViewColumn_t* self, bool hidden)
+{
+    wxDataViewColumn* col = reinterpret_cast<wxDataViewColumn*>(self);
+    if (col) {
+        col->SetHidden(hidden);
+    }
+}
+
+WXD_EXPORTED bool
+wxd_DataViewColumn_IsHidden(wxd_DataViewColumn_t* self)
+{
+    wxDataViewColumn* col = reinterpret_cast<wxDataViewColumn*>(self);
+    if (col) {
+        return col->IsHidden();
+    }
+    return false; // Default if col is null
+}
+
 // =============================================================================
 // DataViewListModel (DataViewListStore) - CRUD Operations
 // =============================================================================
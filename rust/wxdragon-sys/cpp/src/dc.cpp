@@ -4,6 +4,15 @@
 #include <wx/dcmemory.h>
 #include <wx/dcscreen.h>
 #include <wx/dcbuffer.h>
+#include <wx/overlay.h>
+#include <wx/display.h>
+#include <wx/renderer.h>
+#include <wx/control.h>
+#include "wxd_utils.h"
+#include <algorithm>
+#ifdef __WXOSX__
+#include <CoreGraphics/CoreGraphics.h>
+#endif
 
 // Type aliases for easier reference
 using wxd_DC_t = struct wxd_DC_t;
@@ -13,6 +22,7 @@ using wxd_PaintDC_t = struct wxd_PaintDC_t;
 using wxd_MemoryDC_t = struct wxd_MemoryDC_t;
 using wxd_ScreenDC_t = struct wxd_ScreenDC_t;
 using wxd_AutoBufferedPaintDC_t = struct wxd_AutoBufferedPaintDC_t;
+using wxd_BufferedDC_t = struct wxd_BufferedDC_t;
 
 // The wxWidgets DC hierarchy provides some challenges for C FFI
 // We need to use the opaque type pattern and typecasts:
@@ -34,6 +44,12 @@ wxd_DC_Destroy(wxd_DC_t* dc)
     }
 }
 
+bool
+wxd_DC_IsOk(wxd_DC_t* dc)
+{
+    return dc && reinterpret_cast<wxDC*>(dc)->IsOk();
+}
+
 wxd_WindowDC_t*
 wxd_WindowDC_Create(wxd_Window_t* window)
 {
@@ -130,6 +146,24 @@ wxd_AutoBufferedPaintDC_Destroy(wxd_AutoBufferedPaintDC_t* dc)
     }
 }
 
+wxd_BufferedDC_t*
+wxd_BufferedDC_Create(wxd_DC_t* dc)
+{
+    if (!dc)
+        return nullptr;
+    wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    return reinterpret_cast<wxd_BufferedDC_t*>(new wxBufferedDC(wx_dc, wx_dc->GetSize()));
+}
+
+void
+wxd_BufferedDC_Destroy(wxd_BufferedDC_t* dc)
+{
+    if (dc) {
+        // Copies the buffer to the target DC
+        delete reinterpret_cast<wxBufferedDC*>(dc);
+    }
+}
+
 // Type casting functions
 wxd_DC_t*
 wxd_WindowDC_AsDC(wxd_WindowDC_t* dc)
@@ -168,6 +202,24 @@ wxd_AutoBufferedPaintDC_AsDC(wxd_AutoBufferedPaintDC_t* dc)
         static_cast<wxDC*>(reinterpret_cast<wxAutoBufferedPaintDC*>(dc)));
 }
 
+wxd_DC_t*
+wxd_BufferedDC_AsDC(wxd_BufferedDC_t* dc)
+{
+    return reinterpret_cast<wxd_DC_t*>(static_cast<wxDC*>(reinterpret_cast<wxBufferedDC*>(dc)));
+}
+
+wxd_DC_t*
+wxd_EraseEvent_GetDC(wxd_Event_t* event)
+{
+    if (!event)
+        return nullptr;
+    wxEvent* evt = reinterpret_cast<wxEvent*>(event);
+    wxEraseEvent* erase = wxDynamicCast(evt, wxEraseEvent);
+    if (!erase)
+        return nullptr;
+    return reinterpret_cast<wxd_DC_t*>(erase->GetDC());
+}
+
 // Common DC operations
 void
 wxd_DC_Clear(wxd_DC_t* dc)
@@ -293,6 +345,18 @@ wxd_DC_DrawPolygon(wxd_DC_t* dc, int n, wxd_Point* points, int xoffset, int yoff
     }
 }
 
+void
+wxd_DC_DrawPolyPolygon(wxd_DC_t* dc, int n, const int* counts, wxd_Point* points, int xoffset, int yoffset,
+                       int fill_style)
+{
+    if (dc && counts && points && n > 0) {
+        wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+        wxPoint* wx_points = reinterpret_cast<wxPoint*>(points);
+        wxPolygonFillMode wx_fill_style = static_cast<wxPolygonFillMode>(fill_style);
+        wx_dc->DrawPolyPolygon(n, counts, wx_points, xoffset, yoffset, wx_fill_style);
+    }
+}
+
 void
 wxd_DC_DrawLines(wxd_DC_t* dc, int n, wxd_Point* points, int xoffset, int yoffset)
 {
@@ -422,6 +486,86 @@ wxd_DC_GetClippingBox(wxd_DC_t* dc, int* x, int* y, int* width, int* height)
     }
 }
 
+bool
+wxd_DC_GetClippingRect(wxd_DC_t* dc, wxd_Rect* rect)
+{
+    if (!dc || !rect)
+        return false;
+    wxRect box;
+    if (!reinterpret_cast<wxDC*>(dc)->GetClippingBox(box))
+        return false;
+    rect->x = box.x;
+    rect->y = box.y;
+    rect->width = box.width;
+    rect->height = box.height;
+    return true;
+}
+
+// Everything a drawing routine may change through the DC
+struct WxdDCState {
+    wxPen pen;
+    wxBrush brush;
+    wxFont font;
+    wxColour text_foreground;
+    wxColour text_background;
+    wxBrush background;
+    int background_mode;
+    wxRasterOperationMode logical_function;
+    wxPoint device_origin;
+    wxPoint logical_origin;
+    double user_scale_x;
+    double user_scale_y;
+    bool clipped;
+    wxRect clip_box;
+};
+
+wxd_DCState_t*
+wxd_DC_SaveState(wxd_DC_t* dc)
+{
+    if (!dc)
+        return nullptr;
+    const wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    WxdDCState* state = new WxdDCState();
+    state->pen = wx_dc->GetPen();
+    state->brush = wx_dc->GetBrush();
+    state->font = wx_dc->GetFont();
+    state->text_foreground = wx_dc->GetTextForeground();
+    state->text_background = wx_dc->GetTextBackground();
+    state->background = wx_dc->GetBackground();
+    state->background_mode = wx_dc->GetBackgroundMode();
+    state->logical_function = wx_dc->GetLogicalFunction();
+    state->device_origin = wx_dc->GetDeviceOrigin();
+    state->logical_origin = wx_dc->GetLogicalOrigin();
+    wx_dc->GetUserScale(&state->user_scale_x, &state->user_scale_y);
+    state->clipped = wx_dc->GetClippingBox(state->clip_box);
+    return reinterpret_cast<wxd_DCState_t*>(state);
+}
+
+void
+wxd_DC_RestoreState(wxd_DC_t* dc, wxd_DCState_t* state)
+{
+    WxdDCState* saved = reinterpret_cast<WxdDCState*>(state);
+    if (dc && saved) {
+        wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+        wx_dc->SetPen(saved->pen);
+        wx_dc->SetBrush(saved->brush);
+        wx_dc->SetFont(saved->font);
+        wx_dc->SetTextForeground(saved->text_foreground);
+        wx_dc->SetTextBackground(saved->text_background);
+        wx_dc->SetBackground(saved->background);
+        wx_dc->SetBackgroundMode(saved->background_mode);
+        wx_dc->SetLogicalFunction(saved->logical_function);
+        // The clipping box is in the logical coordinates it was saved with
+        wx_dc->SetDeviceOrigin(saved->device_origin.x, saved->device_origin.y);
+        wx_dc->SetLogicalOrigin(saved->logical_origin.x, saved->logical_origin.y);
+        wx_dc->SetUserScale(saved->user_scale_x, saved->user_scale_y);
+        wx_dc->DestroyClippingRegion();
+        if (saved->clipped)
+            wx_dc->SetClippingRegion(saved->clip_box);
+    }
+    delete saved;
+}
+
 // Coordinate transformation
 void
 wxd_DC_SetDeviceOrigin(wxd_DC_t* dc, int x, int y)
@@ -566,12 +710,16 @@ wxd_DC_GetTextExtent(wxd_DC_t* dc, const char* string, int* w, int* h)
 void
 wxd_MemoryDC_SelectObject(wxd_MemoryDC_t* dc, const wxd_Bitmap_t* bitmap)
 {
-    if (dc && bitmap) {
-        wxMemoryDC* wx_dc = reinterpret_cast<wxMemoryDC*>(dc);
-        const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
-        // Note: wxMemoryDC::SelectObject takes a non-const wxBitmap*, strange.
-        wx_dc->SelectObject(*(const_cast<wxBitmap*>(wx_bitmap)));
+    if (!dc)
+        return;
+    wxMemoryDC* wx_dc = reinterpret_cast<wxMemoryDC*>(dc);
+    if (!bitmap) {
+        wx_dc->SelectObject(wxNullBitmap);
+        return;
     }
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    // Note: wxMemoryDC::SelectObject takes a non-const wxBitmap*, strange.
+    wx_dc->SelectObject(*(const_cast<wxBitmap*>(wx_bitmap)));
 }
 
 void
@@ -778,6 +926,56 @@ wxd_DC_GetCharWidth(wxd_DC_t* dc)
     return 0;
 }
 
+int
+wxd_DC_GetPartialTextExtents(wxd_DC_t* dc, const char* string, int* widths, size_t count)
+{
+    if (!dc || !string)
+        return -1;
+    wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    wxString wx_text = wxString::FromUTF8(string);
+    if (wx_text.empty())
+        return 0;
+
+    wxArrayInt wx_widths;
+    if (!wx_dc->GetPartialTextExtents(wx_text, wx_widths))
+        return -1;
+    if (widths) {
+        const size_t n = std::min(count, wx_widths.size());
+        for (size_t i = 0; i < n; ++i)
+            widths[i] = wx_widths[i];
+    }
+    return static_cast<int>(wx_widths.size());
+}
+
+bool
+wxd_DC_GetFontMetrics(wxd_DC_t* dc, wxd_FontMetrics* metrics)
+{
+    if (!dc || !metrics)
+        return false;
+    wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    const wxFontMetrics wx_metrics = wx_dc->GetFontMetrics();
+    metrics->height = wx_metrics.height;
+    metrics->ascent = wx_metrics.ascent;
+    metrics->descent = wx_metrics.descent;
+    metrics->internal_leading = wx_metrics.internalLeading;
+    metrics->external_leading = wx_metrics.externalLeading;
+    metrics->average_width = wx_metrics.averageWidth;
+    return true;
+}
+
+int
+wxd_DC_Ellipsize(wxd_DC_t* dc, const char* string, int mode, int max_width, char* buffer, size_t buffer_len)
+{
+    if (!dc || !string)
+        return -1;
+    wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    // No mnemonic processing: the text is drawn as is, not used as a control label
+    const wxString result = wxControl::Ellipsize(wxString::FromUTF8(string), *wx_dc,
+                                                 static_cast<wxEllipsizeMode>(mode), max_width,
+                                                 wxELLIPSIZE_FLAGS_EXPAND_TAB);
+    return static_cast<int>(wxd_cpp_utils::copy_wxstring_to_buffer(result, buffer, buffer_len));
+}
+
 // Drawing state queries
 wxd_Colour_t
 wxd_DC_GetBackground(wxd_DC_t* dc)
@@ -890,6 +1088,76 @@ wxd_DC_GradientFillConcentric(wxd_DC_t* dc, wxd_Rect rect, wxd_Colour_t initialC
     }
 }
 
+// Native renderer
+void
+wxd_DC_DrawHeaderButton(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags, const char* label)
+{
+    if (dc && window) {
+        wxHeaderButtonParams params;
+        params.m_labelText = wxString::FromUTF8(label ? label : "");
+        wxRendererNative::Get().DrawHeaderButton(reinterpret_cast<wxWindow*>(window), *reinterpret_cast<wxDC*>(dc),
+                                                 wxRect(rect.x, rect.y, rect.width, rect.height), flags,
+                                                 wxHDR_SORT_ICON_NONE, params.m_labelText.empty() ? nullptr : &params);
+    }
+}
+
+void
+wxd_DC_DrawPushButton(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags)
+{
+    if (dc && window) {
+        wxRendererNative::Get().DrawPushButton(reinterpret_cast<wxWindow*>(window), *reinterpret_cast<wxDC*>(dc),
+                                               wxRect(rect.x, rect.y, rect.width, rect.height), flags);
+    }
+}
+
+void
+wxd_DC_DrawCheckBox(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags)
+{
+    if (dc && window) {
+        wxRendererNative::Get().DrawCheckBox(reinterpret_cast<wxWindow*>(window), *reinterpret_cast<wxDC*>(dc),
+                                             wxRect(rect.x, rect.y, rect.width, rect.height), flags);
+    }
+}
+
+void
+wxd_DC_DrawItemSelectionRect(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags)
+{
+    if (dc && window) {
+        wxRendererNative::Get().DrawItemSelectionRect(reinterpret_cast<wxWindow*>(window),
+                                                      *reinterpret_cast<wxDC*>(dc),
+                                                      wxRect(rect.x, rect.y, rect.width, rect.height), flags);
+    }
+}
+
+void
+wxd_DC_DrawFocusRect(wxd_DC_t* dc, wxd_Window_t* window, wxd_Rect rect, int flags)
+{
+    if (dc && window) {
+        wxRendererNative::Get().DrawFocusRect(reinterpret_cast<wxWindow*>(window), *reinterpret_cast<wxDC*>(dc),
+                                              wxRect(rect.x, rect.y, rect.width, rect.height), flags);
+    }
+}
+
+int
+wxd_Renderer_GetHeaderButtonHeight(wxd_Window_t* window)
+{
+    if (!window)
+        return 0;
+    return wxRendererNative::Get().GetHeaderButtonHeight(reinterpret_cast<wxWindow*>(window));
+}
+
+wxd_Size
+wxd_Renderer_GetCheckBoxSize(wxd_Window_t* window, int flags)
+{
+    wxd_Size result = { 0, 0 };
+    if (window) {
+        wxSize size = wxRendererNative::Get().GetCheckBoxSize(reinterpret_cast<wxWindow*>(window), flags);
+        result.width = size.x;
+        result.height = size.y;
+    }
+    return result;
+}
+
 // Flood fill
 bool
 wxd_DC_FloodFill(wxd_DC_t* dc, int x, int y, wxd_Colour_t colour, int style)
@@ -922,4 +1190,176 @@ wxd_DC_GetLogicalFunction(wxd_DC_t* dc)
         return static_cast<int>(wx_dc->GetLogicalFunction());
     }
     return static_cast<int>(wxCOPY);
-}
\ No newline at end of file
+}
+// Overlay
+wxd_Overlay_t*
+wxd_Overlay_Create(void)
+{
+    return reinterpret_cast<wxd_Overlay_t*>(new wxOverlay());
+}
+
+void
+wxd_Overlay_Destroy(wxd_Overlay_t* overlay)
+{
+    if (overlay) {
+        delete reinterpret_cast<wxOverlay*>(overlay);
+    }
+}
+
+void
+wxd_Overlay_Reset(wxd_Overlay_t* overlay)
+{
+    if (overlay) {
+        reinterpret_cast<wxOverlay*>(overlay)->Reset();
+    }
+}
+
+wxd_DCOverlay_t*
+wxd_DCOverlay_Create(wxd_Overlay_t* overlay, wxd_DC_t* dc)
+{
+    if (!overlay || !dc)
+        return nullptr;
+    wxOverlay* wx_overlay = reinterpret_cast<wxOverlay*>(overlay);
+    wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    return reinterpret_cast<wxd_DCOverlay_t*>(new wxDCOverlay(*wx_overlay, wx_dc));
+}
+
+void
+wxd_DCOverlay_Destroy(wxd_DCOverlay_t* dc_overlay)
+{
+    if (dc_overlay) {
+        delete reinterpret_cast<wxDCOverlay*>(dc_overlay);
+    }
+}
+
+void
+wxd_DCOverlay_Clear(wxd_DCOverlay_t* dc_overlay)
+{
+    if (dc_overlay) {
+        reinterpret_cast<wxDCOverlay*>(dc_overlay)->Clear();
+    }
+}
+
+// --- Screen capture ---
+
+#ifdef __WXOSX__
+// Since macOS 10.15 capturing other apps' windows needs the Screen Recording permission;
+// without it the system silently returns only the desktop background
+static bool
+has_screen_capture_permission()
+{
+    if (__builtin_available(macOS 10.15, *)) {
+        if (CGPreflightScreenCaptureAccess())
+            return true;
+        // Shows the system prompt (once); a granted permission applies after restarting the app
+        CGRequestScreenCaptureAccess();
+        return false;
+    }
+    return true;
+}
+#endif
+
+// Bounding box of all displays in screen coordinates
+static wxRect
+all_displays_rect()
+{
+    wxRect bounds;
+    for (unsigned int i = 0; i < wxDisplay::GetCount(); ++i) {
+        bounds.Union(wxDisplay(i).GetGeometry());
+    }
+    return bounds;
+}
+
+static wxBitmap*
+capture_screen_rect(wxRect area, bool whole_screen, int& reason)
+{
+#ifdef __WXGTK__
+    // Wayland doesn't allow reading the screen contents
+    if (wxGetDisplayInfo().type == wxDisplayWayland) {
+        reason = WXD_CAPTURE_NOT_SUPPORTED;
+        return nullptr;
+    }
+#endif
+
+#ifdef __WXOSX__
+    if (!has_screen_capture_permission()) {
+        reason = WXD_CAPTURE_PERMISSION_DENIED;
+        return nullptr;
+    }
+#endif
+    const wxRect screen = all_displays_rect();
+
+    if (whole_screen) {
+        area = screen;
+    } else {
+        area.Intersect(screen);
+    }
+    if (area.IsEmpty()) {
+        reason = WXD_CAPTURE_OUTSIDE_SCREEN;
+        return nullptr;
+    }
+
+#ifdef __WXOSX__
+    // wxScreenDC only covers the main display on macOS and can't be blitted from, so read
+    // the area from all displays at once; CoreGraphics uses the same global coordinates
+#pragma clang diagnostic push
+#pragma clang diagnostic ignored "-Wdeprecated-declarations"
+    CGImageRef image = CGWindowListCreateImage(CGRectMake(area.x, area.y, area.width, area.height),
+                                               kCGWindowListOptionOnScreenOnly, kCGNullWindowID,
+                                               kCGWindowImageDefault);
+#pragma clang diagnostic pop
+    wxBitmap captured;
+    if (image) {
+        // The image has the pixels of the display with the highest resolution in the area
+        captured = wxBitmap(image, (double)CGImageGetWidth(image) / area.width);
+        CGImageRelease(image);
+    }
+#else
+    wxScreenDC screen_dc;
+    wxBitmap captured(area.GetSize(), 24);
+    if (captured.IsOk()) {
+        wxMemoryDC memory_dc(captured);
+        if (!memory_dc.Blit(0, 0, area.width, area.height, &screen_dc, area.x, area.y)) {
+            reason = WXD_CAPTURE_FAILED;
+            return nullptr;
+        }
+    }
+#endif
+    if (!captured.IsOk()) {
+        reason = WXD_CAPTURE_FAILED;
+        return nullptr;
+    }
+    reason = WXD_CAPTURE_OK;
+    return new wxBitmap(captured);
+}
+
+wxd_Bitmap_t*
+wxd_CaptureScreen(int x, int y, int width, int height, int* error)
+{
+    int reason = WXD_CAPTURE_FAILED;
+    wxBitmap* bitmap = capture_screen_rect(wxRect(x, y, width, height), width <= 0 || height <= 0, reason);
+    if (error) {
+        *error = reason;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
+}
+
+wxd_Bitmap_t*
+wxd_Window_CaptureClientArea(wxd_Window_t* window, int* error)
+{
+    wxWindow* win = reinterpret_cast<wxWindow*>(window);
+    int reason = WXD_CAPTURE_NOT_VISIBLE;
+    wxBitmap* bitmap = nullptr;
+    if (win && win->IsShownOnScreen()) {
+        const wxSize size = win->GetClientSize();
+        if (size.x > 0 && size.y > 0) {
+            // Paint pending changes before grabbing the pixels
+            win->Update();
+            bitmap = capture_screen_rect(wxRect(win->ClientToScreen(wxPoint(0, 0)), size), false, reason);
+        }
+    }
+    if (error) {
+        *error = reason;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
+}
@@ -2,6 +2,7 @@
 #include <wx/wx.h>
 #include "wxdragon.h"
 #include "wx/dialog.h"
+#include "wx/sizer.h"
 
 extern "C" {
 
@@ -109,6 +110,18 @@ wxd_Dialog_GetReturnCode(wxd_Dialog* self)
     return ((wxDialog*)self)->GetReturnCode();
 }
 
+wxd_StdDialogButtonSizer_t*
+wxd_Dialog_CreateStdDialogButtonSizer(wxd_Dialog* self, wxd_Style_t flags)
+{
+    if (!self)
+        return nullptr;
+    // Buttons get stock ids and empty labels, so their text comes from the
+    // translated stock labels (wxstd catalog) and OK/Cancel handling is native.
+    wxStdDialogButtonSizer* sizer =
+        ((wxDialog*)self)->CreateStdDialogButtonSizer(static_cast<long>(flags));
+    return reinterpret_cast<wxd_StdDialogButtonSizer_t*>(sizer);
+}
+
 // Note: wxDialog itself is usually not created directly with a simple 'Create' function in this C API.
 // Derived dialogs (like wxMessageDialog) will have their own creation functions that return a wxd_Dialog* or wxd_SpecificDialog* castable to wxd_Dialog*.
 // Destruction is handled by wxd_Window_Destroy, as wxDialog inherits from wxWindow.
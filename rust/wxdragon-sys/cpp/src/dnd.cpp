@@ -85,9 +85,16 @@ wxd_DropSource_SetData(wxd_DropSource_t* source, wxd_DataObject_t* data)
 extern "C" WXDRAGON_API WXDDragResultCEnum
 wxd_DropSource_DoDragDrop(wxd_DropSource_t* source, bool allow_move)
 {
-    if (!source)
+    // The drag runs its own modal loop, which only the main thread may do
+    if (!source || !wxIsMainThread())
         return WXD_DRAG_ERROR;
 
+    // A window still capturing the mouse from the button press that started the drag
+    // would keep getting the mouse events the drag loop waits for
+    wxWindow* captured = wxWindow::GetCapture();
+    if (captured)
+        captured->ReleaseMouse();
+
     wxDropSource* drop_source = reinterpret_cast<wxDropSource*>(source);
     wxDragResult result = drop_source->DoDragDrop(allow_move ? wxDrag_AllowMove : wxDrag_CopyOnly);
 
@@ -3,6 +3,8 @@
 #include "../include/wxdragon.h"
 #include <wx/dnd.h>
 #include <wx/tokenzr.h>
+#include <memory>
+#include <vector>
 
 // Full-featured text drop target implementation
 class WxdTextDropTargetFull : public wxTextDropTarget {
@@ -208,6 +210,201 @@ private:
     wxd_FreeUserData_Callback m_freeUserData;
 };
 
+// Frees Rust user data together with the handler owning it
+class RustUserData {
+public:
+    RustUserData(void* data, wxd_FreeUserData_Callback freeData) : m_data(data), m_free(freeData) {}
+
+    ~RustUserData()
+    {
+        if (m_data && m_free)
+            m_free(m_data);
+    }
+
+    void*
+    Get() const
+    {
+        return m_data;
+    }
+
+private:
+    void* m_data;
+    wxd_FreeUserData_Callback m_free;
+
+    wxDECLARE_NO_COPY_CLASS(RustUserData);
+};
+
+// Drop target behind the wxd_Window_SetDrop*Handler functions. Every registered kind adds
+// a simple data object to a composite, so one window can accept files, text and custom
+// formats; OnData dispatches on the format that was actually received.
+class WxdWindowDropTarget : public wxDropTarget {
+public:
+    template<typename Callback>
+    struct Handler {
+        Callback callback;
+        std::unique_ptr<RustUserData> userData;
+    };
+
+    struct DataHandler {
+        wxString format;
+        Handler<wxd_OnDropData_Callback> handler;
+        wxCustomDataObject* object = nullptr;
+    };
+
+    // Returns the window's target, installing a new one if it has none or another kind
+    static WxdWindowDropTarget*
+    For(wxWindow* window)
+    {
+        WxdWindowDropTarget* target = dynamic_cast<WxdWindowDropTarget*>(window->GetDropTarget());
+        if (!target) {
+            target = new WxdWindowDropTarget();
+            window->SetDropTarget(target);
+        }
+        return target;
+    }
+
+    void
+    SetFiles(wxd_OnDropFiles_Callback callback, std::unique_ptr<RustUserData> userData)
+    {
+        m_files.reset(callback ? new Handler<wxd_OnDropFiles_Callback>{ callback, std::move(userData) }
+                               : nullptr);
+        Rebuild();
+    }
+
+    void
+    SetText(wxd_OnDropText_Callback callback, std::unique_ptr<RustUserData> userData)
+    {
+        m_text.reset(callback ? new Handler<wxd_OnDropText_Callback>{ callback, std::move(userData) }
+                              : nullptr);
+        Rebuild();
+    }
+
+    void
+    SetData(const wxString& format, wxd_OnDropData_Callback callback,
+            std::unique_ptr<RustUserData> userData)
+    {
+        for (auto it = m_data.begin(); it != m_data.end(); ++it) {
+            if ((*it)->format == format) {
+                m_data.erase(it);
+                break;
+            }
+        }
+        if (callback) {
+            std::shared_ptr<DataHandler> data(new DataHandler());
+            data->format = format;
+            data->handler.callback = callback;
+            data->handler.userData = std::move(userData);
+            m_data.push_back(std::move(data));
+        }
+        Rebuild();
+    }
+
+    void
+    SetDragOver(wxd_OnDragOver_Callback callback, std::unique_ptr<RustUserData> userData)
+    {
+        m_dragOver.reset(callback ? new Handler<wxd_OnDragOver_Callback>{ callback, std::move(userData) }
+                                  : nullptr);
+    }
+
+    wxDragResult
+    OnEnter(wxCoord x, wxCoord y, wxDragResult defResult) override
+    {
+        return OnDragOver(x, y, defResult);
+    }
+
+    wxDragResult
+    OnDragOver(wxCoord x, wxCoord y, wxDragResult defResult) override
+    {
+        // Held for the call, as the handler may replace itself and so free its closure
+        const std::shared_ptr<Handler<wxd_OnDragOver_Callback>> handler = m_dragOver;
+        if (!handler)
+            return defResult;
+        return static_cast<wxDragResult>(handler->callback(
+            x, y, static_cast<wxd_DragResult>(defResult), handler->userData->Get()));
+    }
+
+    bool
+    OnDrop(wxCoord x, wxCoord y) override
+    {
+        // Not every platform stops a drop the last OnDragOver refused
+        return OnDragOver(x, y, wxDragCopy) != wxDragNone;
+    }
+
+    wxDragResult
+    OnData(wxCoord x, wxCoord y, wxDragResult defResult) override
+    {
+        if (!m_composite || !GetData())
+            return wxDragNone;
+
+        // A handler may re-register handlers, which frees the replaced closures and
+        // rebuilds the data objects. So the handler is held and the data copied before
+        // calling out.
+        wxDataObjectSimple* received = m_composite->GetObject(m_composite->GetReceivedFormat());
+        bool accepted = false;
+        if (m_files && received == m_filesObject) {
+            const std::shared_ptr<Handler<wxd_OnDropFiles_Callback>> handler = m_files;
+            const wxArrayString files = m_filesObject->GetFilenames();
+            accepted = handler->callback(reinterpret_cast<const wxd_ArrayString_t*>(&files), x, y,
+                                         handler->userData->Get());
+        }
+        else if (m_text && received == m_textObject) {
+            const std::shared_ptr<Handler<wxd_OnDropText_Callback>> handler = m_text;
+            const wxScopedCharBuffer text = m_textObject->GetText().utf8_str();
+            accepted = handler->callback(text.data(), x, y, handler->userData->Get());
+        }
+        else {
+            std::shared_ptr<DataHandler> data;
+            for (const auto& candidate : m_data) {
+                if (received == candidate->object) {
+                    data = candidate;
+                    break;
+                }
+            }
+            if (data) {
+                const unsigned char* bytes = static_cast<const unsigned char*>(data->object->GetData());
+                const std::vector<unsigned char> copy(bytes, bytes + data->object->GetSize());
+                const Handler<wxd_OnDropData_Callback>& handler = data->handler;
+                accepted = handler.callback(copy.data(), copy.size(), x, y, handler.userData->Get());
+            }
+        }
+        return accepted ? defResult : wxDragNone;
+    }
+
+private:
+    // Files are preferred over custom formats, and those over the text most sources also offer
+    void
+    Rebuild()
+    {
+        m_composite = new wxDataObjectComposite();
+        m_filesObject = nullptr;
+        m_textObject = nullptr;
+        if (m_files) {
+            m_filesObject = new wxFileDataObject();
+            m_composite->Add(m_filesObject);
+        }
+        for (const auto& data : m_data) {
+            data->object = new wxCustomDataObject(wxd_cpp_utils::custom_data_format(data->format));
+            m_composite->Add(data->object);
+        }
+        if (m_text) {
+            m_textObject = new wxTextDataObject();
+            m_composite->Add(m_textObject);
+        }
+        SetDataObject(m_composite);
+    }
+
+    // Shared so OnData and OnDragOver can keep a handler alive while it runs
+    std::shared_ptr<Handler<wxd_OnDropFiles_Callback>> m_files;
+    std::shared_ptr<Handler<wxd_OnDropText_Callback>> m_text;
+    std::vector<std::shared_ptr<DataHandler>> m_data;
+    std::shared_ptr<Handler<wxd_OnDragOver_Callback>> m_dragOver;
+
+    // Owned by the drop target through SetDataObject
+    wxDataObjectComposite* m_composite = nullptr;
+    wxFileDataObject* m_filesObject = nullptr;
+    wxTextDataObject* m_textObject = nullptr;
+};
+
 extern "C" {
 
 // Create text drop target with full callback set
@@ -254,4 +451,56 @@ wxd_FileDropTarget_CreateFull(wxd_Window_t* window, wxd_OnEnter_Callback onEnter
     return reinterpret_cast<wxd_FileDropTarget_t*>(drop_target);
 }
 
+// --- Window drop handlers ---
+
+WXD_EXPORTED bool
+wxd_Window_SetDropFilesHandler(wxd_Window_t* window, wxd_OnDropFiles_Callback onDropFiles,
+                               void* userData, wxd_FreeUserData_Callback freeUserData)
+{
+    std::unique_ptr<RustUserData> data(new RustUserData(userData, freeUserData));
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window)
+        return false;
+    WxdWindowDropTarget::For(wx_window)->SetFiles(onDropFiles, std::move(data));
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_Window_SetDropTextHandler(wxd_Window_t* window, wxd_OnDropText_Callback onDropText,
+                              void* userData, wxd_FreeUserData_Callback freeUserData)
+{
+    std::unique_ptr<RustUserData> data(new RustUserData(userData, freeUserData));
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window)
+        return false;
+    WxdWindowDropTarget::For(wx_window)->SetText(onDropText, std::move(data));
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_Window_SetDropDataHandler(wxd_Window_t* window, const char* format,
+                              wxd_OnDropData_Callback onDropData, void* userData,
+                              wxd_FreeUserData_Callback freeUserData)
+{
+    std::unique_ptr<RustUserData> data(new RustUserData(userData, freeUserData));
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window || !format || !*format)
+        return false;
+    WxdWindowDropTarget::For(wx_window)->SetData(wxString::FromUTF8(format), onDropData,
+                                                 std::move(data));
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_Window_SetDragOverHandler(wxd_Window_t* window, wxd_OnDragOver_Callback onDragOver,
+                              void* userData, wxd_FreeUserData_Callback freeUserData)
+{
+    std::unique_ptr<RustUserData> data(new RustUserData(userData, freeUserData));
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window)
+        return false;
+    WxdWindowDropTarget::For(wx_window)->SetDragOver(onDragOver, std::move(data));
+    return true;
+}
+
 } // extern "C"
\ No newline at end of file
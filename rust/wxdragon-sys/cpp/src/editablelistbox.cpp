@@ -3,10 +3,91 @@
 #include "../include/wxdragon.h"
 #include <wx/window.h>
 #include <wx/editlbox.h>
+#include <wx/listctrl.h>
+#include <unordered_map>
 
 // Cast helpers for EditableListBox
 #define TO_WX_EDITABLELB(x) ((wxEditableListBox*)(x))
 
+// wxEditableListBox has no event for its strings changing, so wxDragon sends its
+// own after the buttons or the inline editor changed them.
+wxDEFINE_EVENT(wxdEVT_EDITABLELISTBOX_CHANGED, wxCommandEvent);
+
+// The strings of each tracked listbox as last reported
+static std::unordered_map<wxEditableListBox*, wxArrayString> s_elb_strings;
+
+static void
+SyncStrings(wxEditableListBox* elb)
+{
+    auto state = s_elb_strings.find(elb);
+    if (state != s_elb_strings.end())
+        elb->GetStrings(state->second);
+}
+
+static void
+CheckStrings(wxEditableListBox* elb)
+{
+    auto state = s_elb_strings.find(elb);
+    if (state == s_elb_strings.end())
+        return;
+    wxArrayString strings;
+    elb->GetStrings(strings);
+    if (strings == state->second)
+        return;
+    state->second = strings;
+
+    wxCommandEvent event(wxdEVT_EDITABLELISTBOX_CHANGED, elb->GetId());
+    event.SetEventObject(elb);
+    elb->HandleWindowEvent(event);
+}
+
+// Drops a row other than the last, empty one for new entries when the user
+// empties it; wxWidgets itself only adds an entry from that row if it isn't empty.
+static void
+OnElbEndLabelEdit(wxEditableListBox* elb, wxListEvent& event)
+{
+    event.Skip();
+    long index = event.GetIndex();
+    if (event.IsEditCancelled() || !event.GetLabel().empty() ||
+        index >= elb->GetListCtrl()->GetItemCount() - 1)
+        return;
+
+    event.Veto();
+    elb->CallAfter([elb, index]() {
+        wxListCtrl* list = elb->GetListCtrl();
+        if (index < list->GetItemCount() - 1)
+            list->DeleteItem(index);
+        CheckStrings(elb);
+    });
+}
+
+static void
+OnElbDestroy(wxWindowDestroyEvent& event)
+{
+    if (wxEditableListBox* elb = wxDynamicCast(event.GetEventObject(), wxEditableListBox))
+        s_elb_strings.erase(elb);
+    event.Skip();
+}
+
+static void
+TrackChanges(wxEditableListBox* elb)
+{
+    if (s_elb_strings.count(elb))
+        return;
+    elb->GetStrings(s_elb_strings[elb]);
+
+    // Bound handlers run before the listbox's own ones, so the check runs after them
+    elb->Bind(wxEVT_BUTTON, [elb](wxCommandEvent& event) {
+        event.Skip();
+        elb->CallAfter([elb]() { CheckStrings(elb); });
+    });
+    elb->GetListCtrl()->Bind(wxEVT_LIST_END_LABEL_EDIT, [elb](wxListEvent& event) {
+        OnElbEndLabelEdit(elb, event);
+        elb->CallAfter([elb]() { CheckStrings(elb); });
+    });
+    elb->Bind(wxEVT_DESTROY, &OnElbDestroy);
+}
+
 // Create a new wxEditableListBox
 wxd_Window_t*
 wxd_EditableListBox_New(wxd_Window_t* parent, int id, const char* label, int x, int y, int width,
@@ -17,10 +98,20 @@ wxd_EditableListBox_New(wxd_Window_t* parent, int id, const char* label, int x,
     wxEditableListBox* editablelistbox = new wxEditableListBox((wxWindow*)parent, id, wx_label,
                                                                wxPoint(x, y), wxSize(width, height),
                                                                style);
+    TrackChanges(editablelistbox);
 
     return (wxd_Window_t*)editablelistbox;
 }
 
+// Send changed events for a listbox not created by wxd_EditableListBox_New
+void
+wxd_EditableListBox_TrackChanges(wxd_Window_t* self)
+{
+    wxEditableListBox* elb = wxDynamicCast((wxWindow*)self, wxEditableListBox);
+    if (elb)
+        TrackChanges(elb);
+}
+
 // Set the string items in the wxEditableListBox
 void
 wxd_EditableListBox_SetStrings(wxd_Window_t* self, const char** strings, int count)
@@ -40,6 +131,8 @@ wxd_EditableListBox_SetStrings(wxd_Window_t* self, const char** strings, int cou
     }
 
     TO_WX_EDITABLELB(self)->SetStrings(wx_strings);
+    // Only edits by the user are reported
+    SyncStrings(TO_WX_EDITABLELB(self));
 }
 
 // Add a string item to the wxEditableListBox
@@ -61,6 +154,7 @@ wxd_EditableListBox_AddString(wxd_Window_t* self, const char* string)
 
     // Set the updated strings
     TO_WX_EDITABLELB(self)->SetStrings(strings);
+    SyncStrings(TO_WX_EDITABLELB(self));
 }
 
 // Get the underlying wxListBox from the wxEditableListBox
@@ -4,7 +4,7 @@
 // #include "../include/events/wxd_event_api.h" // No longer needed, wxd_Event_t defined in wxd_types.h (via wxdragon.h)
 #include <unordered_map>
 #include <vector>     // For std::vector used in closureMap
-#include <memory>     // For std::unique_ptr if we want safer memory management
+#include <memory>     // For std::shared_ptr holding each closure list
 #include <tuple>      // For std::pair used in map key
 #include <inttypes.h> // for PRIxPTR to format pointers as 0x...
 #include <wx/event.h>
@@ -22,6 +22,8 @@
 #include <wx/clrpicker.h>  // ADDED: For wxEVT_COLOURPICKER_CHANGED
 #include <wx/dateevt.h>    // ADDED: For wxEVT_DATE_CHANGED
 #include <wx/treebook.h>   // ADDED: For wxEVT_TREEBOOK_*
+#include <wx/choicebk.h>   // For wxEVT_CHOICEBOOK_*
+#include <wx/listbook.h>   // For wxEVT_LISTBOOK_*
 #include <wx/srchctrl.h>   // ADDED: For wxEVT_SEARCHCTRL_SEARCH_BTN, wxEVT_SEARCHCTRL_CANCEL_BTN
 #include <wx/hyperlink.h>  // ADDED: For wxHyperlinkEvent
 #include <wx/calctrl.h>    // ADDED: For wxCalendarCtrl events
@@ -37,6 +39,10 @@
 #endif
 #if wxdUSE_WEBVIEW
 #include <wx/webview.h> // ADDED: For WebView events
+// Download events sent by wxDragon's WebView wrapper (webview.cpp)
+wxDECLARE_EVENT(wxdEVT_WEBVIEW_DOWNLOAD_REQUEST, wxWebViewEvent);
+wxDECLARE_EVENT(wxdEVT_WEBVIEW_DOWNLOAD_PROGRESS, wxWebViewEvent);
+wxDECLARE_EVENT(wxdEVT_WEBVIEW_DOWNLOAD_COMPLETED, wxWebViewEvent);
 #endif
 #include <wx/dataview.h> // ADDED: For DataView events
 #include <wx/grid.h>
@@ -47,12 +53,26 @@
 #include "../src/wxd_utils.h" // For WXD_STR_TO_WX_STRING_UTF8_NULL_OK, etc.
 #if wxdUSE_AUI
 #include <wx/aui/framemanager.h> // ADDED: For wxEVT_AUI_* constants
+#include <wx/aui/auibar.h>       // For wxEVT_AUITOOLBAR_* constants
+// Float/dock events sent by wxDragon's AuiManager wrapper (aui_manager.cpp)
+wxDECLARE_EVENT(wxdEVT_AUI_PANE_FLOAT, wxAuiManagerEvent);
+wxDECLARE_EVENT(wxdEVT_AUI_PANE_DOCK, wxAuiManagerEvent);
 #endif
+// Sent by wxDragon's TextCtrl wrapper (textctrl.cpp)
+wxDECLARE_EVENT(wxdEVT_TEXT_CARET_OR_SELECTION_CHANGED, wxCommandEvent);
+// Sent by wxDragon's EditableListBox wrapper (editablelistbox.cpp)
+wxDECLARE_EVENT(wxdEVT_EDITABLELISTBOX_CHANGED, wxCommandEvent);
+// Sent by wxDragon's RearrangeCtrl wrapper (rearrangelist.cpp)
+wxDECLARE_EVENT(wxdEVT_REARRANGE_LIST, wxCommandEvent);
+wxDECLARE_EVENT(wxdEVT_REARRANGE_LIST_CHECKED, wxCommandEvent);
+// Sent by wxDragon's Notebook wrapper (notebook.cpp)
+wxDECLARE_EVENT(wxdEVT_NOTEBOOK_PAGES_REORDERED, wxBookCtrlEvent);
 #include <wx/dynarray.h> // For wxEVT_REARRANGE_LIST
 #include <wx/log.h>
 #include <wx/utils.h>
 #include <wx/rearrangectrl.h> // ADDED: For wxEVT_REARRANGE_LIST
 #include <wx/collpane.h>      // ADDED: For wxEVT_COLLAPSIBLEPANE_CHANGED
+#include <wx/wizard.h>        // For wxEVT_WIZARD_*
 #if wxdUSE_RICHTEXT
 #include <wx/richtext/richtextctrl.h> // ADDED: For richtext events
 #endif
@@ -92,6 +112,10 @@ struct RustClosureInfo {
     size_t token = 0;                              // NEW: Unique identifier for unbinding
 };
 
+// Closures bound for one (eventType, widgetId) pair. Shared so that a dispatch can walk
+// the list while its handlers bind or unbind; see WxdEventHandler::MutableClosures.
+typedef std::vector<RustClosureInfo> ClosureList;
+
 // Forward declarations
 class WxdEventHandler;
 static wxEventType
@@ -123,7 +147,9 @@ IsVetableEventType(wxEventType eventType)
     }
 
     // Notebook/book events (only *_CHANGING events are vetable)
-    if (eventType == wxEVT_NOTEBOOK_PAGE_CHANGING || eventType == wxEVT_TREEBOOK_PAGE_CHANGING) {
+    if (eventType == wxEVT_NOTEBOOK_PAGE_CHANGING || eventType == wxEVT_TREEBOOK_PAGE_CHANGING ||
+        eventType == wxEVT_BOOKCTRL_PAGE_CHANGING || eventType == wxEVT_CHOICEBOOK_PAGE_CHANGING ||
+        eventType == wxEVT_LISTBOOK_PAGE_CHANGING) {
         return true;
     }
 
@@ -138,9 +164,16 @@ IsVetableEventType(wxEventType eventType)
         return true;
     }
 
+    // Wizard events (wxWizardEvent is a wxNotifyEvent)
+    if (eventType == wxEVT_WIZARD_PAGE_CHANGING || eventType == wxEVT_WIZARD_CANCEL) {
+        return true;
+    }
+
 // AUI events
 #if wxdUSE_AUI
-    if (eventType == wxEVT_AUI_PANE_CLOSE) {
+    // wxAuiManager checks the veto of these before acting on a pane button
+    if (eventType == wxEVT_AUI_PANE_BUTTON || eventType == wxEVT_AUI_PANE_CLOSE ||
+        eventType == wxEVT_AUI_PANE_MAXIMIZE || eventType == wxEVT_AUI_PANE_RESTORE) {
         return true;
     }
 #endif
@@ -164,6 +197,14 @@ IsEventVetoed(wxEvent& event)
         return !notifyEvent->IsAllowed(); // IsAllowed() returns false if vetoed
     }
 
+#if wxdUSE_AUI
+    // wxAuiManagerEvent has its own veto flag rather than deriving from wxNotifyEvent
+    wxAuiManagerEvent* auiEvent = wxDynamicCast(&event, wxAuiManagerEvent);
+    if (auiEvent) {
+        return auiEvent->GetVeto();
+    }
+#endif
+
     // For other event types that might support veto, add specific checks here
     // Note: Most vetable events derive from wxNotifyEvent or wxCloseEvent
 
@@ -189,7 +230,7 @@ public:
 class WxdEventHandler : public wxEvtHandler {
 public:
     // Map (eventType, widgetId) pair to a vector of Rust closure info
-    std::unordered_map<std::pair<wxEventType, wxd_Id>, std::vector<RustClosureInfo>, PairHash>
+    std::unordered_map<std::pair<wxEventType, wxd_Id>, std::shared_ptr<ClosureList>, PairHash>
         closureMap;
 
     // NEW: Fast lookup - token -> location of closure
@@ -201,6 +242,12 @@ public:
     std::unordered_map<std::pair<wxEventType, wxd_Id>, bool, PairHash> wx_bindings_made;
     wxEvtHandler* ownerHandler = nullptr; // Store the actual wxEvtHandler*
 
+    // Closures may be unbound from inside their own handler (e.g. one-shot bindings).
+    // While a dispatch is in progress their Rust boxes are parked here and only
+    // dropped once the outermost DispatchEvent call has returned.
+    int dispatchDepth = 0;
+    std::vector<void*> pendingDrops;
+
     WxdEventHandler(wxEvtHandler* owner) : ownerHandler(owner)
     {
         WXD_LOG_TRACEF("WxdEventHandler 0x%" PRIxPTR " created for wxEvtHandler 0x%p cls=%s",
@@ -218,6 +265,30 @@ public:
     size_t
     UnbindAll();
 
+    bool
+    IsTokenBound(size_t token) const
+    {
+        return tokenMap.find(token) != tokenMap.end();
+    }
+
+    // Returns `list` ready to be modified. A dispatch in progress holds its own reference
+    // to the list it walks, so the list is only copied in that case.
+    static ClosureList&
+    MutableClosures(std::shared_ptr<ClosureList>& list)
+    {
+        if (!list)
+            list = std::make_shared<ClosureList>();
+        else if (list.use_count() > 1)
+            list = std::make_shared<ClosureList>(*list);
+        return *list;
+    }
+
+    // Drops the Rust closure now, or defers it if a dispatch is running
+    void
+    ReleaseClosure(void* closure_ptr);
+    void
+    FlushPendingDrops();
+
     // The new dispatch method that handles multiple closures per event
     void
     DispatchEvent(wxEvent& event);
@@ -249,18 +320,43 @@ WxdEventHandler::~WxdEventHandler()
     WXD_LOG_TRACEF("WxdEventHandler 0x%" PRIxPTR " destroying. cls=%s", (uintptr_t)this,
                    wx_cls(ownerHandler).c_str());
     for (auto const& [key, closure_vector] : closureMap) {
-        for (auto const& info : closure_vector) {
+        for (auto const& info : *closure_vector) {
             if (info.closure_ptr) {
                 // Tell Rust to drop the Box corresponding to this pointer
                 drop_rust_event_closure_box(info.closure_ptr);
             }
         }
     }
+    FlushPendingDrops();
     // Clear the maps (optional, as the handler is being destroyed)
     closureMap.clear();
     wx_bindings_made.clear();
 }
 
+void
+WxdEventHandler::ReleaseClosure(void* closure_ptr)
+{
+    if (!closure_ptr)
+        return;
+    if (dispatchDepth > 0) {
+        pendingDrops.push_back(closure_ptr);
+    }
+    else {
+        drop_rust_event_closure_box(closure_ptr);
+    }
+}
+
+void
+WxdEventHandler::FlushPendingDrops()
+{
+    // Swap out first: dropping a closure may run Rust code that binds/unbinds again
+    std::vector<void*> drops;
+    drops.swap(pendingDrops);
+    for (void* closure_ptr : drops) {
+        drop_rust_event_closure_box(closure_ptr);
+    }
+}
+
 bool
 WxdEventHandler::UnbindClosure(size_t token)
 {
@@ -283,16 +379,14 @@ WxdEventHandler::UnbindClosure(size_t token)
         return false;
     }
 
-    auto& closure_vec = closure_it->second;
+    auto& closure_vec = MutableClosures(closure_it->second);
 
     // Search for the closure by token (tokens are unique)
     bool found = false;
     for (auto vec_it = closure_vec.begin(); vec_it != closure_vec.end(); ++vec_it) {
         if (vec_it->token == token) {
-            // Found it! Drop the Rust closure
-            if (vec_it->closure_ptr) {
-                drop_rust_event_closure_box(vec_it->closure_ptr);
-            }
+            // Found it! Drop the Rust closure (deferred if we are mid-dispatch)
+            ReleaseClosure(vec_it->closure_ptr);
 
             // Remove from vector
             closure_vec.erase(vec_it);
@@ -371,10 +465,18 @@ WxdEventHandler::DispatchEvent(wxEvent& event)
 
     bool event_consumed = false;
 
+    // Handlers may bind or unbind closures while we iterate. Holding a reference to each
+    // list makes those changes copy it (see MutableClosures), so the list walked here
+    // stays intact; entries whose token has been unbound in the meantime are skipped.
+    ++dispatchDepth;
+
     // Process Specific ID Handlers first
     auto it_specific = closureMap.find(key_specific_id);
     if (it_specific != closureMap.end()) {
-        for (auto const& info : it_specific->second) {
+        const std::shared_ptr<const ClosureList> handlers = it_specific->second;
+        for (auto const& info : *handlers) {
+            if (!IsTokenBound(info.token))
+                continue;
             if (info.closure_ptr && info.rust_trampoline) {
                 // Reset skip to true before each handler call
                 event.Skip(true);
@@ -397,7 +499,10 @@ WxdEventHandler::DispatchEvent(wxEvent& event)
     if (!event_consumed && !specific_key_is_any) {
         auto it_any = closureMap.find(key_any_id);
         if (it_any != closureMap.end()) {
-            for (auto const& info : it_any->second) {
+            const std::shared_ptr<const ClosureList> handlers = it_any->second;
+            for (auto const& info : *handlers) {
+                if (!IsTokenBound(info.token))
+                    continue;
                 if (info.closure_ptr && info.rust_trampoline) {
                     // Reset skip to true before each handler call
                     event.Skip(true);
@@ -447,6 +552,11 @@ WxdEventHandler::DispatchEvent(wxEvent& event)
         // how many handlers were unbound; this is a final cleanup step.
         (void)this->UnbindAll();
     }
+
+    --dispatchDepth;
+    if (dispatchDepth == 0) {
+        FlushPendingDrops();
+    }
 }
 
 // Special dispatch method for close events
@@ -501,6 +611,21 @@ GetOrCreateEventHandler(wxEvtHandler* handler)
     return customHandler;
 }
 
+// Whether Rust closures are bound to `handler` itself for events of `type`.
+bool
+wxdHasRustHandlers(wxEvtHandler* handler, wxEventType type)
+{
+    WxdHandlerClientData* clientData =
+        handler ? static_cast<WxdHandlerClientData*>(handler->GetClientObject()) : nullptr;
+    if (!clientData || !clientData->handler)
+        return false;
+    for (const auto& entry : clientData->handler->closureMap) {
+        if (entry.first.first == type && !entry.second->empty())
+            return true;
+    }
+    return false;
+}
+
 // --- C++ Closure Wrapper (Functor) ---
 
 // A simple functor class to wrap the Rust callback and data pointer.
@@ -606,7 +731,7 @@ WxdEventHandler::BindClosure(wxEventType wx_event_type, wxd_Id actual_id, void*
     }
 
     // Add closure to vector
-    this->closureMap[map_key].push_back(new_info);
+    MutableClosures(this->closureMap[map_key]).push_back(new_info);
 
     // Store token mapping for fast lookup
     this->tokenMap[token] = std::make_tuple(wx_event_type, actual_id, rust_closure_ptr);
@@ -785,6 +910,38 @@ wxd_Event_Skip(wxd_Event_t* event, bool skip)
     ((wxEvent*)event)->Skip(skip);
 }
 
+WXD_EXPORTED int64_t
+wxd_Event_GetTimestamp(wxd_Event_t* event)
+{
+    if (!event)
+        return 0;
+    return static_cast<int64_t>(((wxEvent*)event)->GetTimestamp());
+}
+
+WXD_EXPORTED int
+wxd_Event_StopPropagation(wxd_Event_t* event)
+{
+    if (!event)
+        return 0;
+    return ((wxEvent*)event)->StopPropagation();
+}
+
+WXD_EXPORTED void
+wxd_Event_ResumePropagation(wxd_Event_t* event, int propagation_level)
+{
+    if (!event)
+        return;
+    ((wxEvent*)event)->ResumePropagation(propagation_level);
+}
+
+WXD_EXPORTED bool
+wxd_Event_ShouldPropagate(wxd_Event_t* event)
+{
+    if (!event)
+        return false;
+    return ((wxEvent*)event)->ShouldPropagate();
+}
+
 // --- NEW: Event Data Accessors Implementation ---
 
 // Accessors for specific event types
@@ -900,6 +1057,17 @@ wxd_ScrollEvent_GetOrientation(wxd_Event_t* event)
     return scrollEvent->GetOrientation();
 }
 
+WXD_EXPORTED double
+wxd_SpinDoubleEvent_GetValue(wxd_Event_t* event)
+{
+    if (!event)
+        return 0.0;
+    wxSpinDoubleEvent* spinEvent = wxDynamicCast((wxEvent*)event, wxSpinDoubleEvent);
+    if (!spinEvent)
+        return 0.0;
+    return spinEvent->GetValue();
+}
+
 // Forward declaration
 static wxEventType
 get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val);
@@ -989,6 +1157,12 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
         return wxEVT_TEXT;
     case WXD_EVENT_TYPE_TEXT_ENTER:
         return wxEVT_TEXT_ENTER;
+    case WXD_EVENT_TYPE_TEXT_MAXLEN:
+        return wxEVT_TEXT_MAXLEN;
+    case WXD_EVENT_TYPE_TEXT_URL:
+        return wxEVT_TEXT_URL;
+    case WXD_EVENT_TYPE_TEXT_CARET_OR_SELECTION_CHANGED:
+        return wxdEVT_TEXT_CARET_OR_SELECTION_CHANGED;
     case WXD_EVENT_TYPE_SIZE:
         return wxEVT_SIZE;
     case WXD_EVENT_TYPE_MENU:
@@ -1037,6 +1211,10 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
         return wxEVT_CHOICE;
     case WXD_EVENT_TYPE_COMMAND_COMBOBOX_SELECTED:
         return wxEVT_COMBOBOX;
+    case WXD_EVENT_TYPE_COMBOBOX_DROPDOWN:
+        return wxEVT_COMBOBOX_DROPDOWN;
+    case WXD_EVENT_TYPE_COMBOBOX_CLOSEUP:
+        return wxEVT_COMBOBOX_CLOSEUP;
     case WXD_EVENT_TYPE_COMMAND_CHECKLISTBOX_SELECTED:
         return wxEVT_CHECKLISTBOX;
     case WXD_EVENT_TYPE_COMMAND_TOGGLEBUTTON_CLICKED:
@@ -1085,6 +1263,22 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
         return wxEVT_NOTEBOOK_PAGE_CHANGED;
     case WXD_EVENT_TYPE_NOTEBOOK_PAGE_CHANGING:
         return wxEVT_NOTEBOOK_PAGE_CHANGING;
+    case WXD_EVENT_TYPE_NOTEBOOK_PAGES_REORDERED:
+        return wxdEVT_NOTEBOOK_PAGES_REORDERED;
+
+    // Events of the other book controls
+    case WXD_EVENT_TYPE_BOOKCTRL_PAGE_CHANGED:
+        return wxEVT_BOOKCTRL_PAGE_CHANGED;
+    case WXD_EVENT_TYPE_BOOKCTRL_PAGE_CHANGING:
+        return wxEVT_BOOKCTRL_PAGE_CHANGING;
+    case WXD_EVENT_TYPE_CHOICEBOOK_PAGE_CHANGED:
+        return wxEVT_CHOICEBOOK_PAGE_CHANGED;
+    case WXD_EVENT_TYPE_CHOICEBOOK_PAGE_CHANGING:
+        return wxEVT_CHOICEBOOK_PAGE_CHANGING;
+    case WXD_EVENT_TYPE_LISTBOOK_PAGE_CHANGED:
+        return wxEVT_LISTBOOK_PAGE_CHANGED;
+    case WXD_EVENT_TYPE_LISTBOOK_PAGE_CHANGING:
+        return wxEVT_LISTBOOK_PAGE_CHANGING;
 
     // Splitter events
     case WXD_EVENT_TYPE_SPLITTER_SASH_POS_CHANGED:
@@ -1109,6 +1303,8 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
         return wxEVT_LIST_END_LABEL_EDIT;
     case WXD_EVENT_TYPE_COMMAND_LISTBOX_DOUBLECLICKED:
         return wxEVT_LISTBOX_DCLICK;
+    case WXD_EVENT_TYPE_EDITABLELISTBOX_CHANGED:
+        return wxdEVT_EDITABLELISTBOX_CHANGED;
 
     // Picker control events
     case WXD_EVENT_TYPE_COLOURPICKER_CHANGED:
@@ -1280,6 +1476,8 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
         return wxEVT_DATAVIEW_COLUMN_REORDERED;
     case WXD_EVENT_TYPE_DATAVIEW_ITEM_CONTEXT_MENU:
         return wxEVT_DATAVIEW_ITEM_CONTEXT_MENU;
+    case WXD_EVENT_TYPE_DATAVIEW_ITEM_BEGIN_DRAG:
+        return wxEVT_DATAVIEW_ITEM_BEGIN_DRAG;
 
     // Additional TreeCtrl events
     case WXD_EVENT_TYPE_TREE_SEL_CHANGING:
@@ -1317,6 +1515,8 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
     // case WXD_EVENT_TYPE_TOOL: return wxEVT_TOOL;  // Conflicts with WXD_EVENT_TYPE_CALENDAR_WEEKDAY_CLICKED (both = 123)
     case WXD_EVENT_TYPE_TOOL_ENTER:
         return wxEVT_TOOL_ENTER;
+    case WXD_EVENT_TYPE_TOOL_DROPDOWN:
+        return wxEVT_TOOL_DROPDOWN;
 
     // Timer event
     case WXD_EVENT_TYPE_TIMER:
@@ -1340,11 +1540,21 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
         return wxEVT_AUI_PANE_ACTIVATED;
     case WXD_EVENT_TYPE_AUI_RENDER:
         return wxEVT_AUI_RENDER;
+    case WXD_EVENT_TYPE_AUITOOLBAR_TOOL_DROPDOWN:
+        return wxEVT_AUITOOLBAR_TOOL_DROPDOWN;
+    case WXD_EVENT_TYPE_AUITOOLBAR_RIGHT_CLICK:
+        return wxEVT_AUITOOLBAR_RIGHT_CLICK;
+    case WXD_EVENT_TYPE_AUI_PANE_FLOAT:
+        return wxdEVT_AUI_PANE_FLOAT;
+    case WXD_EVENT_TYPE_AUI_PANE_DOCK:
+        return wxdEVT_AUI_PANE_DOCK;
 #endif
 
     // RearrangeList event
     case WXD_EVENT_TYPE_COMMAND_REARRANGE_LIST:
-        return wxEVT_COMMAND_LISTBOX_SELECTED;
+        return wxdEVT_REARRANGE_LIST;
+    case WXD_EVENT_TYPE_REARRANGE_LIST_CHECKED:
+        return wxdEVT_REARRANGE_LIST_CHECKED;
 
     // CollapsiblePane event
     case WXD_EVENT_TYPE_COLLAPSIBLEPANE_CHANGED:
@@ -1497,6 +1707,12 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
         return wxEVT_WEBVIEW_WINDOW_CLOSE_REQUESTED;
     case WXD_EVENT_TYPE_WEBVIEW_BROWSING_DATA_CLEARED:
         return wxEVT_WEBVIEW_BROWSING_DATA_CLEARED;
+    case WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_REQUEST:
+        return wxdEVT_WEBVIEW_DOWNLOAD_REQUEST;
+    case WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_PROGRESS:
+        return wxdEVT_WEBVIEW_DOWNLOAD_PROGRESS;
+    case WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_COMPLETED:
+        return wxdEVT_WEBVIEW_DOWNLOAD_COMPLETED;
 #endif
 
     // NEW: Menu event types - use actual dynamic wxWidgets constants
@@ -1577,6 +1793,16 @@ get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val)
     case WXD_EVENT_TYPE_PG_COL_END_DRAG:
         return wxEVT_PG_COL_END_DRAG;
 
+    // Wizard events
+    case WXD_EVENT_TYPE_WIZARD_PAGE_CHANGING:
+        return wxEVT_WIZARD_PAGE_CHANGING;
+    case WXD_EVENT_TYPE_WIZARD_PAGE_CHANGED:
+        return wxEVT_WIZARD_PAGE_CHANGED;
+    case WXD_EVENT_TYPE_WIZARD_CANCEL:
+        return wxEVT_WIZARD_CANCEL;
+    case WXD_EVENT_TYPE_WIZARD_FINISHED:
+        return wxEVT_WIZARD_FINISHED;
+
     default:
         return wxEVT_NULL;
     }
@@ -1991,6 +2217,14 @@ wxd_Event_CanVeto(wxd_Event_t* event)
         return true; // wxNotifyEvent always supports veto
     }
 
+#if wxdUSE_AUI
+    // Only the pane button events act on the veto of a wxAuiManagerEvent
+    wxAuiManagerEvent* aui_event = wxDynamicCast(wx_event, wxAuiManagerEvent);
+    if (aui_event) {
+        return IsVetableEventType(aui_event->GetEventType());
+    }
+#endif
+
     return false; // Event doesn't support veto
 }
 
@@ -2014,6 +2248,14 @@ wxd_Event_Veto(wxd_Event_t* event)
         notify_event->Veto();
         return;
     }
+
+#if wxdUSE_AUI
+    wxAuiManagerEvent* aui_event = wxDynamicCast(wx_event, wxAuiManagerEvent);
+    if (aui_event) {
+        aui_event->Veto();
+        return;
+    }
+#endif
 }
 
 WXD_EXPORTED bool
@@ -2215,6 +2457,23 @@ wxd_DataViewEvent_GetSortOrder(const wxd_Event_t* event, bool* ascending)
     return true;
 }
 
+WXD_EXPORTED bool
+wxd_DataViewEvent_SetDragData(wxd_Event_t* event, wxd_DataObject_t* data_object, bool allow_move)
+{
+    wxDataObject* data = reinterpret_cast<wxDataObject*>(data_object);
+    wxEvent* wx_event = reinterpret_cast<wxEvent*>(event);
+    wxDataViewEvent* dv_event = wx_event ? wxDynamicCast(wx_event, wxDataViewEvent) : nullptr;
+    if (!dv_event || wx_event->GetEventType() != wxEVT_DATAVIEW_ITEM_BEGIN_DRAG) {
+        delete data;
+        return false;
+    }
+
+    // The control deletes the data object once the drag is over
+    dv_event->SetDataObject(data);
+    dv_event->SetDragFlags(allow_move ? wxDrag_AllowMove : wxDrag_CopyOnly);
+    return data != nullptr;
+}
+
 // --- SizeEvent specific ---
 
 extern "C" wxd_Size
@@ -185,6 +185,31 @@ wxd_Font_IsOk(wxd_Font_t* self)
     return font->IsOk();
 }
 
+WXD_EXPORTED int
+wxd_Font_GetNativeFontInfoDesc(wxd_Font_t* self, char* buffer, size_t buffer_len)
+{
+    if (!self)
+        return -1;
+    wxFont* font = reinterpret_cast<wxFont*>(self);
+    if (!font->IsOk())
+        return -1;
+    return (int)wxd_cpp_utils::copy_wxstring_to_buffer(font->GetNativeFontInfoDesc(), buffer,
+                                                       buffer_len);
+}
+
+WXD_EXPORTED wxd_Font_t*
+wxd_Font_CreateFromNativeFontInfoDesc(const char* desc)
+{
+    if (!desc || !*desc)
+        return NULL;
+    wxFont* font = new wxFont();
+    if (!font->SetNativeFontInfo(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(desc)) || !font->IsOk()) {
+        delete font;
+        return NULL;
+    }
+    return reinterpret_cast<wxd_Font_t*>(font);
+}
+
 WXD_EXPORTED bool
 wxd_Font_AddPrivateFont(const char* font_file_path)
 {
@@ -51,4 +51,20 @@ wxd_FontPickerCtrl_SetSelectedFont(wxd_FontPickerCtrl_t* self, const wxd_Font_t*
         return;
     }
     ((wxFontPickerCtrl*)self)->SetSelectedFont(*((wxFont*)font));
-}
\ No newline at end of file
+}
+
+WXD_EXPORTED void
+wxd_FontPickerCtrl_SetMaxPointSize(wxd_FontPickerCtrl_t* self, unsigned int max)
+{
+    if (!self)
+        return;
+    ((wxFontPickerCtrl*)self)->SetMaxPointSize(max);
+}
+
+WXD_EXPORTED unsigned int
+wxd_FontPickerCtrl_GetMaxPointSize(wxd_FontPickerCtrl_t* self)
+{
+    if (!self)
+        return 0;
+    return ((wxFontPickerCtrl*)self)->GetMaxPointSize();
+}
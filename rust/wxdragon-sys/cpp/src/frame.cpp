@@ -156,6 +156,44 @@ wxd_Frame_SetStatusText(wxd_Frame_t* frame, const char* text, int number)
     }
 }
 
+void
+wxd_Frame_PushStatusText(wxd_Frame_t* frame, const char* text, int number)
+{
+    if (frame && text) {
+        ((wxFrame*)frame)->PushStatusText(wxString::FromUTF8(text), number);
+    }
+}
+
+void
+wxd_Frame_PopStatusText(wxd_Frame_t* frame, int number)
+{
+    if (!frame)
+        return;
+    // Popping a field without pushed text asserts, or worse in release builds
+    wxStatusBar* statusBar = ((wxFrame*)frame)->GetStatusBar();
+    if (statusBar && number >= 0 && number < statusBar->GetFieldsCount() &&
+        !statusBar->GetField(number).GetStack().empty()) {
+        ((wxFrame*)frame)->PopStatusText(number);
+    }
+}
+
+void
+wxd_Frame_SetStatusBarPane(wxd_Frame_t* frame, int pane)
+{
+    if (frame) {
+        ((wxFrame*)frame)->SetStatusBarPane(pane);
+    }
+}
+
+int
+wxd_Frame_GetStatusBarPane(wxd_Frame_t* frame)
+{
+    if (frame) {
+        return ((wxFrame*)frame)->GetStatusBarPane();
+    }
+    return -1;
+}
+
 char*
 wxd_Frame_GetTitle(wxd_Frame_t* frame)
 {
@@ -220,6 +258,40 @@ wxd_Frame_SetIconFromBitmap(wxd_Frame_t* frame, const wxd_Bitmap_t* bitmap)
     }
 }
 
+// wxBitmapBundle::GetIcon only exists since wxWidgets 3.1.6
+static wxIcon
+BundleIcon(const wxBitmapBundle& bundle, const wxSize& size)
+{
+#if wxCHECK_VERSION(3, 1, 6)
+    return bundle.GetIcon(size);
+#else
+    wxIcon icon;
+    icon.CopyFromBitmap(bundle.GetBitmap(size));
+    return icon;
+#endif
+}
+
+void
+wxd_Frame_SetIcons(wxd_Frame_t* frame, const wxd_BitmapBundle_t* bundle)
+{
+    if (!frame || !bundle)
+        return;
+
+    const wxBitmapBundle* wx_bundle = reinterpret_cast<const wxBitmapBundle*>(bundle);
+    if (!wx_bundle->IsOk())
+        return;
+
+    wxIconBundle icons;
+    const wxSize default_size = wx_bundle->GetDefaultSize();
+    icons.AddIcon(BundleIcon(*wx_bundle, default_size));
+    for (int size : { 16, 24, 32, 48, 64, 128, 256 }) {
+        // Sizes sharing a dimension with the default one are covered by the default icon
+        if (size != default_size.x && size != default_size.y)
+            icons.AddIcon(BundleIcon(*wx_bundle, wxSize(size, size)));
+    }
+    reinterpret_cast<wxFrame*>(frame)->SetIcons(icons);
+}
+
 void
 wxd_Frame_RequestUserAttention(wxd_Frame_t* frame, int flags)
 {
@@ -0,0 +1,461 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/graphics.h>
+#include <vector>
+
+// A wxd_GraphicsContext_t is a heap allocated wxGraphicsContext, a wxd_GraphicsPath_t a
+// heap allocated copy of the (reference counted) wxGraphicsPath.
+
+static inline wxGraphicsContext*
+to_gc(wxd_GraphicsContext_t* gc)
+{
+    return reinterpret_cast<wxGraphicsContext*>(gc);
+}
+
+static inline const wxGraphicsPath*
+to_path(const wxd_GraphicsPath_t* path)
+{
+    return reinterpret_cast<const wxGraphicsPath*>(path);
+}
+
+static inline wxGraphicsPath*
+to_path(wxd_GraphicsPath_t* path)
+{
+    return reinterpret_cast<wxGraphicsPath*>(path);
+}
+
+static inline wxColour
+to_wx(wxd_Colour_t c)
+{
+    return wxColour(c.r, c.g, c.b, c.a);
+}
+
+static wxGraphicsGradientStops
+make_stops(const wxd_GraphicsGradient_t* gradient)
+{
+    if (!gradient->colours || gradient->count < 1)
+        return wxGraphicsGradientStops();
+    const int last = gradient->count - 1;
+    wxGraphicsGradientStops stops(to_wx(gradient->colours[0]), to_wx(gradient->colours[last]));
+    for (int i = 1; i < last; ++i) {
+        const double pos = gradient->positions ? gradient->positions[i] : double(i) / last;
+        stops.Add(to_wx(gradient->colours[i]), pos);
+    }
+    return stops;
+}
+
+// Start with the font of the target, otherwise DrawText has nothing to draw with
+static void
+init_font(wxGraphicsContext* gc, const wxFont& font, const wxColour& colour)
+{
+    gc->SetFont(font.IsOk() ? font : *wxNORMAL_FONT, colour.IsOk() ? colour : *wxBLACK);
+}
+
+extern "C" {
+
+// --- Creation/destruction ---
+
+WXD_EXPORTED wxd_GraphicsContext_t*
+wxd_GraphicsContext_CreateFromDC(wxd_DC_t* dc)
+{
+    if (!dc)
+        return nullptr;
+    wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    if (!wx_dc->IsOk())
+        return nullptr;
+    wxGraphicsContext* gc = wxGraphicsContext::CreateFromUnknownDC(*wx_dc);
+    if (!gc)
+        return nullptr;
+    init_font(gc, wx_dc->GetFont(), wx_dc->GetTextForeground());
+    return reinterpret_cast<wxd_GraphicsContext_t*>(gc);
+}
+
+WXD_EXPORTED wxd_GraphicsContext_t*
+wxd_GraphicsContext_CreateFromWindow(wxd_Window_t* window)
+{
+    if (!window)
+        return nullptr;
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    wxGraphicsContext* gc = wxGraphicsContext::Create(wx_window);
+    if (!gc)
+        return nullptr;
+    init_font(gc, wx_window->GetFont(), wx_window->GetForegroundColour());
+    return reinterpret_cast<wxd_GraphicsContext_t*>(gc);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_Destroy(wxd_GraphicsContext_t* gc)
+{
+    delete to_gc(gc);
+}
+
+// --- Pens, brushes and fonts ---
+
+WXD_EXPORTED void
+wxd_GraphicsContext_SetPen(wxd_GraphicsContext_t* gc, wxd_Colour_t colour, double width, int style,
+                           const wxd_GraphicsGradient_t* gradient)
+{
+    if (!gc)
+        return;
+    wxGraphicsPenInfo info(to_wx(colour), width, static_cast<wxPenStyle>(style));
+    if (gradient && gradient->kind == WXD_GRAPHICS_GRADIENT_LINEAR) {
+        info.LinearGradient(gradient->x1, gradient->y1, gradient->x2, gradient->y2, make_stops(gradient));
+    }
+    else if (gradient && gradient->kind == WXD_GRAPHICS_GRADIENT_RADIAL) {
+        info.RadialGradient(gradient->x1, gradient->y1, gradient->x2, gradient->y2, gradient->radius,
+                            make_stops(gradient));
+    }
+    to_gc(gc)->SetPen(to_gc(gc)->CreatePen(info));
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_SetBrush(wxd_GraphicsContext_t* gc, wxd_Colour_t colour, int style,
+                             const wxd_GraphicsGradient_t* gradient)
+{
+    if (!gc)
+        return;
+    wxGraphicsContext* ctx = to_gc(gc);
+    if (gradient && gradient->kind == WXD_GRAPHICS_GRADIENT_LINEAR) {
+        ctx->SetBrush(ctx->CreateLinearGradientBrush(gradient->x1, gradient->y1, gradient->x2, gradient->y2,
+                                                     make_stops(gradient)));
+    }
+    else if (gradient && gradient->kind == WXD_GRAPHICS_GRADIENT_RADIAL) {
+        ctx->SetBrush(ctx->CreateRadialGradientBrush(gradient->x1, gradient->y1, gradient->x2, gradient->y2,
+                                                     gradient->radius, make_stops(gradient)));
+    }
+    else {
+        ctx->SetBrush(wxBrush(to_wx(colour), static_cast<wxBrushStyle>(style)));
+    }
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_SetFont(wxd_GraphicsContext_t* gc, const wxd_Font_t* font, wxd_Colour_t colour)
+{
+    if (!gc || !font)
+        return;
+    to_gc(gc)->SetFont(*reinterpret_cast<const wxFont*>(font), to_wx(colour));
+}
+
+// --- Quality ---
+
+WXD_EXPORTED bool
+wxd_GraphicsContext_SetAntialiasMode(wxd_GraphicsContext_t* gc, int mode)
+{
+    return gc && to_gc(gc)->SetAntialiasMode(static_cast<wxAntialiasMode>(mode));
+}
+
+WXD_EXPORTED int
+wxd_GraphicsContext_GetAntialiasMode(wxd_GraphicsContext_t* gc)
+{
+    return gc ? static_cast<int>(to_gc(gc)->GetAntialiasMode()) : static_cast<int>(wxANTIALIAS_DEFAULT);
+}
+
+WXD_EXPORTED bool
+wxd_GraphicsContext_SetInterpolationQuality(wxd_GraphicsContext_t* gc, int quality)
+{
+    return gc && to_gc(gc)->SetInterpolationQuality(static_cast<wxInterpolationQuality>(quality));
+}
+
+WXD_EXPORTED int
+wxd_GraphicsContext_GetInterpolationQuality(wxd_GraphicsContext_t* gc)
+{
+    return gc ? static_cast<int>(to_gc(gc)->GetInterpolationQuality())
+              : static_cast<int>(wxINTERPOLATION_DEFAULT);
+}
+
+// --- Drawing ---
+
+WXD_EXPORTED void
+wxd_GraphicsContext_StrokePath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path)
+{
+    if (gc && path)
+        to_gc(gc)->StrokePath(*to_path(path));
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_FillPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style)
+{
+    if (gc && path)
+        to_gc(gc)->FillPath(*to_path(path), static_cast<wxPolygonFillMode>(fill_style));
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style)
+{
+    if (gc && path)
+        to_gc(gc)->DrawPath(*to_path(path), static_cast<wxPolygonFillMode>(fill_style));
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_StrokeLine(wxd_GraphicsContext_t* gc, double x1, double y1, double x2, double y2)
+{
+    if (gc)
+        to_gc(gc)->StrokeLine(x1, y1, x2, y2);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_StrokeLines(wxd_GraphicsContext_t* gc, const double* xy, int count)
+{
+    if (!gc || !xy || count < 2)
+        return;
+    std::vector<wxPoint2DDouble> points;
+    points.reserve(count);
+    for (int i = 0; i < count; ++i) {
+        points.emplace_back(xy[2 * i], xy[2 * i + 1]);
+    }
+    to_gc(gc)->StrokeLines(points.size(), points.data());
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h)
+{
+    if (gc)
+        to_gc(gc)->DrawRectangle(x, y, w, h);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawRoundedRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h,
+                                         double radius)
+{
+    if (gc)
+        to_gc(gc)->DrawRoundedRectangle(x, y, w, h, radius);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawEllipse(wxd_GraphicsContext_t* gc, double x, double y, double w, double h)
+{
+    if (gc)
+        to_gc(gc)->DrawEllipse(x, y, w, h);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawBitmap(wxd_GraphicsContext_t* gc, const wxd_Bitmap_t* bitmap, double x, double y,
+                               double w, double h)
+{
+    if (!gc || !bitmap)
+        return;
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (wx_bitmap->IsOk())
+        to_gc(gc)->DrawBitmap(*wx_bitmap, x, y, w, h);
+}
+
+// --- Text ---
+
+WXD_EXPORTED void
+wxd_GraphicsContext_DrawText(wxd_GraphicsContext_t* gc, const char* text, double x, double y, double angle)
+{
+    if (!gc || !text)
+        return;
+    wxString wx_text = wxString::FromUTF8(text);
+    if (angle == 0.0)
+        to_gc(gc)->DrawText(wx_text, x, y);
+    else
+        to_gc(gc)->DrawText(wx_text, x, y, angle);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_GetTextExtent(wxd_GraphicsContext_t* gc, const char* text, double* width, double* height,
+                                  double* descent, double* external_leading)
+{
+    wxDouble w = 0, h = 0, d = 0, e = 0;
+    if (gc && text)
+        to_gc(gc)->GetTextExtent(wxString::FromUTF8(text), &w, &h, &d, &e);
+    if (width)
+        *width = w;
+    if (height)
+        *height = h;
+    if (descent)
+        *descent = d;
+    if (external_leading)
+        *external_leading = e;
+}
+
+// --- Transforms and state ---
+
+WXD_EXPORTED void
+wxd_GraphicsContext_Translate(wxd_GraphicsContext_t* gc, double dx, double dy)
+{
+    if (gc)
+        to_gc(gc)->Translate(dx, dy);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_Scale(wxd_GraphicsContext_t* gc, double x_scale, double y_scale)
+{
+    if (gc)
+        to_gc(gc)->Scale(x_scale, y_scale);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_Rotate(wxd_GraphicsContext_t* gc, double angle)
+{
+    if (gc)
+        to_gc(gc)->Rotate(angle);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_PushState(wxd_GraphicsContext_t* gc)
+{
+    if (gc)
+        to_gc(gc)->PushState();
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_PopState(wxd_GraphicsContext_t* gc)
+{
+    if (gc)
+        to_gc(gc)->PopState();
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_BeginLayer(wxd_GraphicsContext_t* gc, double opacity)
+{
+    if (gc)
+        to_gc(gc)->BeginLayer(opacity);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_EndLayer(wxd_GraphicsContext_t* gc)
+{
+    if (gc)
+        to_gc(gc)->EndLayer();
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_Clip(wxd_GraphicsContext_t* gc, double x, double y, double w, double h)
+{
+    if (gc)
+        to_gc(gc)->Clip(x, y, w, h);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_ResetClip(wxd_GraphicsContext_t* gc)
+{
+    if (gc)
+        to_gc(gc)->ResetClip();
+}
+
+WXD_EXPORTED void
+wxd_GraphicsContext_GetSize(wxd_GraphicsContext_t* gc, double* width, double* height)
+{
+    wxDouble w = 0, h = 0;
+    if (gc)
+        to_gc(gc)->GetSize(&w, &h);
+    if (width)
+        *width = w;
+    if (height)
+        *height = h;
+}
+
+// --- Paths ---
+
+WXD_EXPORTED wxd_GraphicsPath_t*
+wxd_GraphicsContext_CreatePath(wxd_GraphicsContext_t* gc)
+{
+    if (!gc)
+        return nullptr;
+    return reinterpret_cast<wxd_GraphicsPath_t*>(new wxGraphicsPath(to_gc(gc)->CreatePath()));
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_Destroy(wxd_GraphicsPath_t* path)
+{
+    delete to_path(path);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_MoveToPoint(wxd_GraphicsPath_t* path, double x, double y)
+{
+    if (path)
+        to_path(path)->MoveToPoint(x, y);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddLineToPoint(wxd_GraphicsPath_t* path, double x, double y)
+{
+    if (path)
+        to_path(path)->AddLineToPoint(x, y);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddCurveToPoint(wxd_GraphicsPath_t* path, double cx1, double cy1, double cx2, double cy2, double x,
+                                 double y)
+{
+    if (path)
+        to_path(path)->AddCurveToPoint(cx1, cy1, cx2, cy2, x, y);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddQuadCurveToPoint(wxd_GraphicsPath_t* path, double cx, double cy, double x, double y)
+{
+    if (path)
+        to_path(path)->AddQuadCurveToPoint(cx, cy, x, y);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddArc(wxd_GraphicsPath_t* path, double x, double y, double r, double start_angle, double end_angle,
+                        bool clockwise)
+{
+    if (path)
+        to_path(path)->AddArc(x, y, r, start_angle, end_angle, clockwise);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h)
+{
+    if (path)
+        to_path(path)->AddRectangle(x, y, w, h);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddRoundedRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h, double radius)
+{
+    if (path)
+        to_path(path)->AddRoundedRectangle(x, y, w, h, radius);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddCircle(wxd_GraphicsPath_t* path, double x, double y, double r)
+{
+    if (path)
+        to_path(path)->AddCircle(x, y, r);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_AddEllipse(wxd_GraphicsPath_t* path, double x, double y, double w, double h)
+{
+    if (path)
+        to_path(path)->AddEllipse(x, y, w, h);
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_CloseSubpath(wxd_GraphicsPath_t* path)
+{
+    if (path)
+        to_path(path)->CloseSubpath();
+}
+
+WXD_EXPORTED void
+wxd_GraphicsPath_GetBox(const wxd_GraphicsPath_t* path, double* x, double* y, double* w, double* h)
+{
+    wxDouble bx = 0, by = 0, bw = 0, bh = 0;
+    if (path)
+        to_path(path)->GetBox(&bx, &by, &bw, &bh);
+    if (x)
+        *x = bx;
+    if (y)
+        *y = by;
+    if (w)
+        *w = bw;
+    if (h)
+        *h = bh;
+}
+
+WXD_EXPORTED bool
+wxd_GraphicsPath_Contains(const wxd_GraphicsPath_t* path, double x, double y, int fill_style)
+{
+    return path && to_path(path)->Contains(x, y, static_cast<wxPolygonFillMode>(fill_style));
+}
+
+} // extern "C"
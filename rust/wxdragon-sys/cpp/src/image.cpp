@@ -0,0 +1,316 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/image.h>
+#include <wx/mstream.h>
+#include "wxd_utils.h"
+#include <cstring>
+
+// A wxd_Image_t is a heap allocated wxImage. Operations never modify their source
+// in place unless documented otherwise; they return a new image instead.
+
+static inline const wxImage*
+to_image(const wxd_Image_t* image)
+{
+    return reinterpret_cast<const wxImage*>(image);
+}
+
+static inline wxImage*
+to_image(wxd_Image_t* image)
+{
+    return reinterpret_cast<wxImage*>(image);
+}
+
+static wxd_Image_t*
+new_image(const wxImage& image)
+{
+    if (!image.IsOk())
+        return nullptr;
+    wxImage* copy = new (std::nothrow) wxImage(image);
+    return reinterpret_cast<wxd_Image_t*>(copy);
+}
+
+static bool
+is_valid(const wxd_Image_t* image)
+{
+    return image && to_image(image)->IsOk();
+}
+
+// Returns a copy with an alpha channel, converting the mask (if any) to alpha
+static wxImage
+with_alpha(const wxImage& image)
+{
+    if (image.HasAlpha())
+        return image;
+    wxImage copy = image.Copy();
+    copy.InitAlpha();
+    return copy;
+}
+
+extern "C" {
+
+// --- Creation/destruction ---
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Create(int width, int height)
+{
+    if (width <= 0 || height <= 0)
+        return nullptr;
+    return new_image(wxImage(width, height, true));
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_CreateFromRGBA(const unsigned char* data, int width, int height)
+{
+    if (!data || width <= 0 || height <= 0)
+        return nullptr;
+    wxImage image(width, height, false);
+    if (!image.IsOk())
+        return nullptr;
+    image.InitAlpha();
+
+    unsigned char* rgb = image.GetData();
+    unsigned char* alpha = image.GetAlpha();
+    const size_t num_pixels = static_cast<size_t>(width) * static_cast<size_t>(height);
+    for (size_t i = 0; i < num_pixels; ++i) {
+        rgb[i * 3 + 0] = data[i * 4 + 0];
+        rgb[i * 3 + 1] = data[i * 4 + 1];
+        rgb[i * 3 + 2] = data[i * 4 + 2];
+        alpha[i] = data[i * 4 + 3];
+    }
+    return new_image(image);
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_CreateFromFile(const char* path)
+{
+    if (!path)
+        return nullptr;
+    wxd_cpp_utils::ensure_image_handlers();
+    wxLogNull no_log;
+    wxImage image;
+    if (!image.LoadFile(wxString::FromUTF8(path), wxBITMAP_TYPE_ANY))
+        return nullptr;
+    return new_image(image);
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_CreateFromBytes(const unsigned char* data, size_t len)
+{
+    if (!data || len == 0)
+        return nullptr;
+    wxd_cpp_utils::ensure_image_handlers();
+    wxLogNull no_log;
+    wxMemoryInputStream stream(data, len);
+    wxImage image;
+    if (!image.LoadFile(stream, wxBITMAP_TYPE_ANY))
+        return nullptr;
+    return new_image(image);
+}
+
+WXD_EXPORTED void
+wxd_Image_Destroy(wxd_Image_t* image)
+{
+    delete to_image(image);
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Copy(const wxd_Image_t* image)
+{
+    if (!is_valid(image))
+        return nullptr;
+    return new_image(to_image(image)->Copy());
+}
+
+// --- Properties ---
+
+WXD_EXPORTED bool
+wxd_Image_IsOk(const wxd_Image_t* image)
+{
+    return is_valid(image);
+}
+
+WXD_EXPORTED int
+wxd_Image_GetWidth(const wxd_Image_t* image)
+{
+    return is_valid(image) ? to_image(image)->GetWidth() : 0;
+}
+
+WXD_EXPORTED int
+wxd_Image_GetHeight(const wxd_Image_t* image)
+{
+    return is_valid(image) ? to_image(image)->GetHeight() : 0;
+}
+
+WXD_EXPORTED bool
+wxd_Image_HasAlpha(const wxd_Image_t* image)
+{
+    return is_valid(image) && to_image(image)->HasAlpha();
+}
+
+WXD_EXPORTED bool
+wxd_Image_GetRGBAData(const wxd_Image_t* image, unsigned char* buffer, size_t buffer_len)
+{
+    if (!is_valid(image) || !buffer)
+        return false;
+    const wxImage* img = to_image(image);
+    const size_t num_pixels = static_cast<size_t>(img->GetWidth()) * static_cast<size_t>(img->GetHeight());
+    if (buffer_len < num_pixels * 4)
+        return false;
+
+    const unsigned char* rgb = img->GetData();
+    const unsigned char* alpha = img->GetAlpha();
+    for (size_t i = 0; i < num_pixels; ++i) {
+        buffer[i * 4 + 0] = rgb[i * 3 + 0];
+        buffer[i * 4 + 1] = rgb[i * 3 + 1];
+        buffer[i * 4 + 2] = rgb[i * 3 + 2];
+        buffer[i * 4 + 3] = alpha ? alpha[i] : 255;
+    }
+    return true;
+}
+
+// --- Transformations ---
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Scale(const wxd_Image_t* image, int width, int height, int quality)
+{
+    if (!is_valid(image) || width <= 0 || height <= 0)
+        return nullptr;
+    return new_image(to_image(image)->Scale(width, height, static_cast<wxImageResizeQuality>(quality)));
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Resize(const wxd_Image_t* image, int width, int height, int x, int y, int r, int g, int b)
+{
+    if (!is_valid(image) || width <= 0 || height <= 0)
+        return nullptr;
+    const wxImage* src = to_image(image);
+    const bool transparent = r < 0 || g < 0 || b < 0;
+
+    wxImage canvas(width, height, true);
+    if (!canvas.IsOk())
+        return nullptr;
+    if (!transparent)
+        canvas.SetRGB(wxRect(0, 0, width, height), r, g, b);
+
+    if (transparent || src->HasAlpha()) {
+        canvas.InitAlpha();
+        memset(canvas.GetAlpha(), transparent ? 0 : 255, static_cast<size_t>(width) * static_cast<size_t>(height));
+        canvas.Paste(with_alpha(*src), x, y);
+    } else {
+        canvas.Paste(*src, x, y);
+    }
+    return new_image(canvas);
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Rotate(const wxd_Image_t* image, double angle, int center_x, int center_y)
+{
+    if (!is_valid(image))
+        return nullptr;
+    // With an alpha channel wxImage::Rotate leaves the uncovered corners transparent
+    wxImage src = with_alpha(*to_image(image));
+    return new_image(src.Rotate(angle, wxPoint(center_x, center_y), true));
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Rotate90(const wxd_Image_t* image, bool clockwise)
+{
+    if (!is_valid(image))
+        return nullptr;
+    return new_image(to_image(image)->Rotate90(clockwise));
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Rotate180(const wxd_Image_t* image)
+{
+    if (!is_valid(image))
+        return nullptr;
+    return new_image(to_image(image)->Rotate180());
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_Mirror(const wxd_Image_t* image, bool horizontally)
+{
+    if (!is_valid(image))
+        return nullptr;
+    return new_image(to_image(image)->Mirror(horizontally));
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Image_ConvertToGreyscale(const wxd_Image_t* image)
+{
+    if (!is_valid(image))
+        return nullptr;
+    return new_image(to_image(image)->ConvertToGreyscale());
+}
+
+// --- Pixel access ---
+
+WXD_EXPORTED void
+wxd_Image_SetAlpha(wxd_Image_t* image, unsigned char alpha)
+{
+    if (!is_valid(image))
+        return;
+    wxImage* img = to_image(image);
+    if (!img->HasAlpha())
+        img->InitAlpha();
+    memset(img->GetAlpha(), alpha, static_cast<size_t>(img->GetWidth()) * static_cast<size_t>(img->GetHeight()));
+}
+
+WXD_EXPORTED bool
+wxd_Image_GetPixel(const wxd_Image_t* image, int x, int y, wxd_Colour_t* colour)
+{
+    if (!is_valid(image) || !colour)
+        return false;
+    const wxImage* img = to_image(image);
+    if (x < 0 || y < 0 || x >= img->GetWidth() || y >= img->GetHeight())
+        return false;
+    colour->r = img->GetRed(x, y);
+    colour->g = img->GetGreen(x, y);
+    colour->b = img->GetBlue(x, y);
+    colour->a = img->HasAlpha() ? img->GetAlpha(x, y) : 255;
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_Image_SetPixel(wxd_Image_t* image, int x, int y, wxd_Colour_t colour)
+{
+    if (!is_valid(image))
+        return false;
+    wxImage* img = to_image(image);
+    if (x < 0 || y < 0 || x >= img->GetWidth() || y >= img->GetHeight())
+        return false;
+    img->SetRGB(x, y, colour.r, colour.g, colour.b);
+    if (colour.a != 255 && !img->HasAlpha())
+        img->InitAlpha();
+    if (img->HasAlpha())
+        img->SetAlpha(x, y, colour.a);
+    return true;
+}
+
+// --- Conversion ---
+
+WXD_EXPORTED wxd_Bitmap_t*
+wxd_Image_ToBitmap(const wxd_Image_t* image)
+{
+    if (!is_valid(image))
+        return nullptr;
+    wxBitmap* bitmap = new (std::nothrow) wxBitmap(*to_image(image), -1);
+    if (!bitmap || !bitmap->IsOk()) {
+        delete bitmap;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
+}
+
+WXD_EXPORTED wxd_Image_t*
+wxd_Bitmap_ToImage(const wxd_Bitmap_t* bitmap)
+{
+    const wxBitmap* bmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!bmp || !bmp->IsOk())
+        return nullptr;
+    return new_image(bmp->ConvertToImage());
+}
+
+} // extern "C"
@@ -0,0 +1,89 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include <wx/infobar.h>
+#include "../include/wxdragon.h"
+#include "wxd_utils.h"
+
+extern "C" {
+
+WXD_EXPORTED wxd_InfoBar_t*
+wxd_InfoBar_Create(wxd_Window_t* parent, wxd_Id id)
+{
+    wxWindow* parentWin = reinterpret_cast<wxWindow*>(parent);
+    if (!parentWin)
+        return nullptr;
+    return reinterpret_cast<wxd_InfoBar_t*>(new wxInfoBar(parentWin, id));
+}
+
+WXD_EXPORTED void
+wxd_InfoBar_ShowMessage(wxd_InfoBar_t* self, const char* msg, int flags)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    if (bar)
+        bar->ShowMessage(wxString::FromUTF8(msg ? msg : ""), flags);
+}
+
+WXD_EXPORTED void
+wxd_InfoBar_Dismiss(wxd_InfoBar_t* self)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    if (bar)
+        bar->Dismiss();
+}
+
+WXD_EXPORTED void
+wxd_InfoBar_AddButton(wxd_InfoBar_t* self, wxd_Id btnid, const char* label)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    if (bar)
+        bar->AddButton(btnid, wxString::FromUTF8(label ? label : ""));
+}
+
+WXD_EXPORTED void
+wxd_InfoBar_RemoveButton(wxd_InfoBar_t* self, wxd_Id btnid)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    // Removing a button that isn't there asserts
+    if (bar && bar->HasButtonId(btnid))
+        bar->RemoveButton(btnid);
+}
+
+WXD_EXPORTED size_t
+wxd_InfoBar_GetButtonCount(wxd_InfoBar_t* self)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    return bar ? bar->GetButtonCount() : 0;
+}
+
+WXD_EXPORTED bool
+wxd_InfoBar_HasButtonId(wxd_InfoBar_t* self, wxd_Id btnid)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    return bar && bar->HasButtonId(btnid);
+}
+
+WXD_EXPORTED void
+wxd_InfoBar_SetShowHideEffects(wxd_InfoBar_t* self, int showEffect, int hideEffect)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    if (bar)
+        bar->SetShowHideEffects(wxd_cpp_utils::to_wx_show_effect(showEffect),
+                                wxd_cpp_utils::to_wx_show_effect(hideEffect));
+}
+
+WXD_EXPORTED void
+wxd_InfoBar_SetEffectDuration(wxd_InfoBar_t* self, int duration)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    if (bar)
+        bar->SetEffectDuration(duration);
+}
+
+WXD_EXPORTED int
+wxd_InfoBar_GetEffectDuration(wxd_InfoBar_t* self)
+{
+    wxInfoBar* bar = reinterpret_cast<wxInfoBar*>(self);
+    return bar ? bar->GetEffectDuration() : 0;
+}
+
+} // extern "C"
@@ -2,8 +2,15 @@
 #include <wx/wx.h>
 #include "../include/wxdragon.h"
 #include "../include/core/wxd_ipc.h"
+#include "wxd_utils.h"
 #include <wx/ipc.h>
+#if !wxUSE_DDE_FOR_IPC
+#include <wx/socket.h>
+#include <cerrno>
+#endif
+#include <memory>
 #include <unordered_set>
+#include <vector>
 
 // Global registries of live IPC objects.
 // These are used to ensure all DDE objects are destroyed before wxDDECleanUp()
@@ -11,6 +18,91 @@
 static std::unordered_set<void*> g_liveServers;
 static std::unordered_set<void*> g_liveClients;
 
+// Error of the most recent IPC operation, see wxd_IPC_GetLastError
+static wxd_IPCError g_lastIpcErrorKind = WXD_IPC_ERROR_NONE;
+static wxString g_lastIpcError;
+
+// Collects the errors wxWidgets logs during an IPC operation, so they can be returned to the
+// caller instead of being shown in a log message box. The socket transport dispatches events
+// while it waits for the peer, so anything else may log meanwhile: errors are only taken as
+// the IPC error if the operation fails, and otherwise passed on when it is done, like all
+// other messages.
+class IpcErrorCapture : public wxLog {
+public:
+    IpcErrorCapture()
+    {
+        g_lastIpcErrorKind = WXD_IPC_ERROR_NONE;
+        g_lastIpcError.clear();
+        m_previous = wxLog::SetActiveTarget(this);
+    }
+
+    ~IpcErrorCapture() override
+    {
+        wxLog::SetActiveTarget(m_previous);
+        if (!m_failed && m_previous) {
+            for (const Record& record : m_errors)
+                m_previous->LogRecord(record.level, record.msg, record.info);
+        }
+    }
+
+    // Records that the operation failed; `fallback` describes it if wxWidgets logged nothing
+    void Fail(wxd_IPCError kind, const wxString& fallback)
+    {
+        m_failed = true;
+        g_lastIpcErrorKind = kind;
+        for (const Record& record : m_errors) {
+            if (!g_lastIpcError.empty())
+                g_lastIpcError += "\n";
+            g_lastIpcError += record.msg;
+        }
+        m_errors.clear();
+        if (g_lastIpcError.empty())
+            g_lastIpcError = fallback;
+    }
+
+protected:
+    void DoLogRecord(wxLogLevel level, const wxString& msg, const wxLogRecordInfo& info) override
+    {
+        if (level > wxLOG_Warning || m_failed) {
+            if (m_previous)
+                m_previous->LogRecord(level, msg, info);
+            return;
+        }
+        m_errors.push_back(Record{ level, msg, info });
+    }
+
+private:
+    struct Record {
+        wxLogLevel level;
+        wxString msg;
+        wxLogRecordInfo info;
+    };
+
+    wxLog* m_previous;
+    std::vector<Record> m_errors;
+    bool m_failed = false;
+};
+
+#if !wxUSE_DDE_FOR_IPC
+// Classifies the system error left by a failed socket call; wxSocket itself only reports
+// generic errors.
+static wxd_IPCError
+IpcErrorFromErrno(int err)
+{
+    switch (err) {
+    case EADDRINUSE:
+        return WXD_IPC_ERROR_ADDRESS_IN_USE;
+    case ECONNREFUSED:
+    case ENOENT: // Unix domain socket path without a server
+        return WXD_IPC_ERROR_CONNECTION_REFUSED;
+    case ETIMEDOUT:
+        return WXD_IPC_ERROR_TIMEOUT;
+    default:
+        return WXD_IPC_ERROR_FAILED;
+    }
+}
+#endif
+
 // --- WxdConnection: Custom connection class that wraps callbacks ---
 
 class WxdConnection : public wxConnection {
@@ -54,6 +146,7 @@ public:
 
     virtual ~WxdConnection() {
         WXD_LOG_TRACE("WxdConnection destroyed");
+        *m_alive = false;
         if (m_userData && m_freeUserData) {
             m_freeUserData(m_userData);
             m_userData = nullptr;
@@ -87,6 +180,7 @@ public:
     virtual bool OnExecute(const wxString& topic, const void* data, size_t size, wxIPCFormat format) override {
         if (m_onExecute) {
             wxScopedCharBuffer topicUtf8 = topic.utf8_str();
+            CallbackScope scope(this);
             return m_onExecute(m_userData, topicUtf8.data(), data, size, static_cast<wxd_IPCFormat>(format));
         }
         return false;
@@ -96,6 +190,7 @@ public:
         if (m_onRequest) {
             wxScopedCharBuffer topicUtf8 = topic.utf8_str();
             wxScopedCharBuffer itemUtf8 = item.utf8_str();
+            CallbackScope scope(this);
             return m_onRequest(m_userData, topicUtf8.data(), itemUtf8.data(), size, static_cast<wxd_IPCFormat>(format));
         }
         return nullptr;
@@ -105,6 +200,7 @@ public:
         if (m_onPoke) {
             wxScopedCharBuffer topicUtf8 = topic.utf8_str();
             wxScopedCharBuffer itemUtf8 = item.utf8_str();
+            CallbackScope scope(this);
             return m_onPoke(m_userData, topicUtf8.data(), itemUtf8.data(), data, size, static_cast<wxd_IPCFormat>(format));
         }
         return false;
@@ -114,6 +210,7 @@ public:
         if (m_onStartAdvise) {
             wxScopedCharBuffer topicUtf8 = topic.utf8_str();
             wxScopedCharBuffer itemUtf8 = item.utf8_str();
+            CallbackScope scope(this);
             return m_onStartAdvise(m_userData, topicUtf8.data(), itemUtf8.data());
         }
         return false;
@@ -123,6 +220,7 @@ public:
         if (m_onStopAdvise) {
             wxScopedCharBuffer topicUtf8 = topic.utf8_str();
             wxScopedCharBuffer itemUtf8 = item.utf8_str();
+            CallbackScope scope(this);
             return m_onStopAdvise(m_userData, topicUtf8.data(), itemUtf8.data());
         }
         return false;
@@ -133,6 +231,7 @@ public:
         if (m_onAdvise) {
             wxScopedCharBuffer topicUtf8 = topic.utf8_str();
             wxScopedCharBuffer itemUtf8 = item.utf8_str();
+            CallbackScope scope(this);
             return m_onAdvise(m_userData, topicUtf8.data(), itemUtf8.data(), data, size, static_cast<wxd_IPCFormat>(format));
         }
         return false;
@@ -140,14 +239,87 @@ public:
 
     // Both-side callback
     virtual bool OnDisconnect() override {
+        // Default behavior: allow deletion
+        bool allowDelete = true;
         if (m_onDisconnect) {
-            return m_onDisconnect(m_userData);
+            CallbackScope scope(this);
+            allowDelete = m_onDisconnect(m_userData);
         }
-        // Default behavior: allow deletion
+        if (allowDelete) {
+            // Like wxConnectionBase::OnDisconnect(); nothing touches the connection after this
+            // returns, and freeing the user data tells Rust handles that it is gone
+            delete this;
+        }
+        return allowDelete;
+    }
+
+    // Closes the connection from this side. wxWidgets only notifies the peer, so run our own
+    // OnDisconnect too; with the default result it deletes this connection. From inside one
+    // of this connection's callbacks that would free the running callback (and wxWidgets
+    // still uses the connection after it returns), so OnDisconnect is postponed to the
+    // event loop then.
+    bool DisconnectAndNotify() {
+        if (!GetConnected()) return true;
+        bool ok = Disconnect();
+        if (m_callbackDepth > 0 && wxTheApp) {
+            std::shared_ptr<bool> alive = m_alive;
+            wxTheApp->CallAfter([this, alive]() {
+                if (*alive) OnDisconnect();
+            });
+        }
+        else {
+            OnDisconnect();
+        }
+        return ok;
+    }
+
+    // Records why an operation on this connection failed
+    void RecordFailure(IpcErrorCapture& capture, const wxString& what) {
+        if (!GetConnected()) {
+            capture.Fail(WXD_IPC_ERROR_DISCONNECTED, "The connection is closed");
+            return;
+        }
+#if !wxUSE_DDE_FOR_IPC
+        if (m_sock && m_sock->Error() && m_sock->LastError() == wxSOCKET_TIMEDOUT) {
+            capture.Fail(WXD_IPC_ERROR_TIMEOUT, what + ": the peer did not answer in time");
+            return;
+        }
+#endif
+        capture.Fail(WXD_IPC_ERROR_FAILED, what + " failed");
+    }
+
+    // The topic the connection was made for; wxConnection keeps it private to the transport
+    void SetTopicName(const wxString& topic) { m_topicName = topic; }
+    const wxString& GetTopicName() const { return m_topicName; }
+
+    // Bounds how long blocking operations such as Request() wait for the peer. Only the
+    // socket transport can be tuned; DDE transactions use their own fixed timeout.
+    bool SetTimeout(long seconds) {
+#if wxUSE_DDE_FOR_IPC
+        (void)seconds;
+        return false;
+#else
+        // m_sock is only set once the connection has been made
+        if (!m_sock) return false;
+        m_sock->SetTimeout(seconds);
         return true;
+#endif
     }
 
 private:
+    // Counts the callbacks into Rust running for this connection, see DisconnectAndNotify
+    class CallbackScope {
+    public:
+        explicit CallbackScope(WxdConnection* conn) : m_conn(conn) { ++m_conn->m_callbackDepth; }
+        ~CallbackScope() { --m_conn->m_callbackDepth; }
+
+    private:
+        WxdConnection* m_conn;
+    };
+
+    int m_callbackDepth = 0;
+    // Cleared when the connection is destroyed, for work postponed to the event loop
+    std::shared_ptr<bool> m_alive = std::make_shared<bool>(true);
     void* m_userData;
     wxd_IPC_OnExecute_Callback m_onExecute;
     wxd_IPC_OnRequest_Callback m_onRequest;
@@ -157,6 +329,7 @@ private:
     wxd_IPC_OnAdvise_Callback m_onAdvise;
     wxd_IPC_OnDisconnect_Callback m_onDisconnect;
     wxd_IPC_FreeUserData_Callback m_freeUserData;
+    wxString m_topicName;
 };
 
 // --- WxdServer: Custom server class that wraps callbacks ---
@@ -186,6 +359,9 @@ public:
         if (m_onAcceptConnection) {
             wxScopedCharBuffer topicUtf8 = topic.utf8_str();
             wxd_IPCConnection_t* conn = m_onAcceptConnection(m_userData, topicUtf8.data());
+            if (conn) {
+                reinterpret_cast<WxdConnection*>(conn)->SetTopicName(topic);
+            }
             return reinterpret_cast<wxConnectionBase*>(conn);
         }
         return nullptr;
@@ -210,7 +386,8 @@ public:
           m_pendingOnStopAdvise(nullptr),
           m_pendingOnAdvise(nullptr),
           m_pendingOnDisconnect(nullptr),
-          m_pendingFreeUserData(nullptr)
+          m_pendingFreeUserData(nullptr),
+          m_connectTimeoutMs(0)
     {
         WXD_LOG_TRACE("WxdClient created");
     }
@@ -255,6 +432,46 @@ public:
         m_pendingFreeUserData = nullptr;
     }
 
+    void SetConnectTimeout(long milliseconds) { m_connectTimeoutMs = milliseconds; }
+
+    // With a connect timeout, checks that a TCP server answers at host:service before
+    // MakeConnection() does a blocking connect, which waits minutes for an unreachable host.
+    // Returns false and records the error if it doesn't.
+    bool ProbeServer(IpcErrorCapture& capture, const wxString& host, const wxString& service) {
+#if wxUSE_DDE_FOR_IPC
+        (void)capture;
+        (void)host;
+        (void)service;
+        return true;
+#else
+        // Only TCP services are numbers; Unix domain sockets fail immediately anyway
+        if (m_connectTimeoutMs <= 0 || !service.IsNumber()) return true;
+
+        wxIPV4address addr;
+        if (!addr.Hostname(host) || !addr.Service(service)) {
+            capture.Fail(WXD_IPC_ERROR_FAILED, wxString::Format("Unknown host %s", host));
+            return false;
+        }
+
+        wxSocketClient probe;
+        errno = 0;
+        probe.Connect(addr, false);
+        if (!probe.WaitOnConnect(m_connectTimeoutMs / 1000, m_connectTimeoutMs % 1000)) {
+            capture.Fail(WXD_IPC_ERROR_TIMEOUT,
+                         wxString::Format("Timed out connecting to %s:%s", host, service));
+            return false;
+        }
+        if (!probe.IsConnected()) {
+            wxd_IPCError kind = IpcErrorFromErrno(errno);
+            capture.Fail(kind == WXD_IPC_ERROR_FAILED ? WXD_IPC_ERROR_CONNECTION_REFUSED : kind,
+                         wxString::Format("No server is listening on %s:%s", host, service));
+            return false;
+        }
+        probe.Close();
+        return true;
+#endif
+    }
+
     // Override OnMakeConnection to return our custom connection type with callbacks
     virtual wxConnectionBase* OnMakeConnection() override {
         WxdConnection* conn = new WxdConnection(
@@ -283,6 +500,7 @@ private:
     wxd_IPC_OnAdvise_Callback m_pendingOnAdvise;
     wxd_IPC_OnDisconnect_Callback m_pendingOnDisconnect;
     wxd_IPC_FreeUserData_Callback m_pendingFreeUserData;
+    long m_connectTimeoutMs;
 };
 
 // --- C API Implementation ---
@@ -334,7 +552,10 @@ wxd_IPCConnection_Execute(
 {
     if (!conn) return false;
     WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
-    return wx_conn->Execute(data, size, static_cast<wxIPCFormat>(format));
+    IpcErrorCapture capture;
+    if (wx_conn->Execute(data, size, static_cast<wxIPCFormat>(format))) return true;
+    wx_conn->RecordFailure(capture, "Execute");
+    return false;
 }
 
 WXD_EXPORTED bool
@@ -345,7 +566,10 @@ wxd_IPCConnection_ExecuteString(
     if (!conn || !data) return false;
     WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
     wxString str = wxString::FromUTF8(data);
-    return wx_conn->Execute(str);
+    IpcErrorCapture capture;
+    if (wx_conn->Execute(str)) return true;
+    wx_conn->RecordFailure(capture, "Execute");
+    return false;
 }
 
 WXD_EXPORTED const void*
@@ -357,8 +581,16 @@ wxd_IPCConnection_Request(
 {
     if (!conn || !item) return nullptr;
     WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
+    IpcErrorCapture capture;
     wxString itemStr = wxString::FromUTF8(item);
-    return wx_conn->Request(itemStr, out_size, static_cast<wxIPCFormat>(format));
+    const void* data = nullptr;
+    if (wx_conn->GetConnected()) {
+        data = wx_conn->Request(itemStr, out_size, static_cast<wxIPCFormat>(format));
+    }
+    if (!data) {
+        wx_conn->RecordFailure(capture, wxString::Format("Request for '%s'", itemStr));
+    }
+    return data;
 }
 
 WXD_EXPORTED bool
@@ -371,8 +603,14 @@ wxd_IPCConnection_Poke(
 {
     if (!conn || !item) return false;
     WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
+    IpcErrorCapture capture;
     wxString itemStr = wxString::FromUTF8(item);
-    return wx_conn->Poke(itemStr, data, size, static_cast<wxIPCFormat>(format));
+    if (wx_conn->GetConnected() &&
+        wx_conn->Poke(itemStr, data, size, static_cast<wxIPCFormat>(format))) {
+        return true;
+    }
+    wx_conn->RecordFailure(capture, wxString::Format("Poke of '%s'", itemStr));
+    return false;
 }
 
 WXD_EXPORTED bool
@@ -403,8 +641,11 @@ wxd_IPCConnection_Advise(
 {
     if (!conn || !item) return false;
     WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
+    IpcErrorCapture capture;
     wxString itemStr = wxString::FromUTF8(item);
-    return wx_conn->Advise(itemStr, data, size, static_cast<wxIPCFormat>(format));
+    if (wx_conn->Advise(itemStr, data, size, static_cast<wxIPCFormat>(format))) return true;
+    wx_conn->RecordFailure(capture, wxString::Format("Advise of '%s'", itemStr));
+    return false;
 }
 
 WXD_EXPORTED bool
@@ -412,18 +653,23 @@ wxd_IPCConnection_Disconnect(wxd_IPCConnection_t* conn)
 {
     if (!conn) return false;
     WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
-    return wx_conn->Disconnect();
+    return wx_conn->DisconnectAndNotify();
+}
+
+WXD_EXPORTED bool
+wxd_IPCConnection_SetTimeout(wxd_IPCConnection_t* conn, long seconds)
+{
+    if (!conn || seconds <= 0) return false;
+    WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
+    return wx_conn->SetTimeout(seconds);
 }
 
 WXD_EXPORTED size_t
 wxd_IPCConnection_GetTopic(wxd_IPCConnection_t* conn, char* buffer, size_t buffer_size)
 {
     if (!conn) return 0;
-    // wxConnection doesn't expose GetTopic() directly
-    // We would need to track it ourselves or access protected members
-    (void)buffer;
-    (void)buffer_size;
-    return 0;
+    WxdConnection* wx_conn = reinterpret_cast<WxdConnection*>(conn);
+    return wxd_cpp_utils::copy_wxstring_to_buffer(wx_conn->GetTopicName(), buffer, buffer_size);
 }
 
 WXD_EXPORTED bool
@@ -453,7 +699,24 @@ wxd_IPCServer_Create_Service(wxd_IPCServer_t* server, const char* service)
     if (!server || !service) return false;
     WxdServer* wx_server = reinterpret_cast<WxdServer*>(server);
     wxString serviceStr = wxString::FromUTF8(service);
-    return wx_server->Create(serviceStr);
+    IpcErrorCapture capture;
+#if wxUSE_DDE_FOR_IPC
+    if (wx_server->Create(serviceStr)) return true;
+    capture.Fail(WXD_IPC_ERROR_FAILED,
+                 wxString::Format("Failed to register the DDE service %s", serviceStr));
+#else
+    errno = 0;
+    if (wx_server->Create(serviceStr)) return true;
+    int err = errno;
+    wxd_IPCError kind = IpcErrorFromErrno(err);
+    if (kind == WXD_IPC_ERROR_ADDRESS_IN_USE) {
+        capture.Fail(kind, wxString::Format("Service %s is already in use", serviceStr));
+    } else {
+        wxString reason = err ? wxString(wxSysErrorMsgStr(err)) : wxString("unknown error");
+        capture.Fail(kind, wxString::Format("Failed to listen on %s: %s", serviceStr, reason));
+    }
+#endif
+    return false;
 }
 
 WXD_EXPORTED void
@@ -514,9 +777,34 @@ wxd_IPCClient_MakeConnection(
     wxString serviceStr = wxString::FromUTF8(service);
     wxString topicStr = wxString::FromUTF8(topic);
 
-    // MakeConnection will call OnMakeConnection() internally,
-    // which creates our WxdConnection with the pending callbacks
-    wxConnectionBase* conn = wx_client->MakeConnection(hostStr, serviceStr, topicStr);
+    IpcErrorCapture capture;
+    wxConnectionBase* conn = nullptr;
+    if (wx_client->ProbeServer(capture, hostStr, serviceStr)) {
+        // MakeConnection will call OnMakeConnection() internally,
+        // which creates our WxdConnection with the pending callbacks
+#if wxUSE_DDE_FOR_IPC
+        conn = wx_client->MakeConnection(hostStr, serviceStr, topicStr);
+        if (!conn) {
+            capture.Fail(WXD_IPC_ERROR_CONNECTION_REFUSED,
+                         wxString::Format("No DDE server %s accepts the topic %s", serviceStr,
+                                          topicStr));
+        }
+#else
+        errno = 0;
+        conn = wx_client->MakeConnection(hostStr, serviceStr, topicStr);
+        if (!conn) {
+            wxd_IPCError kind = IpcErrorFromErrno(errno);
+            if (kind == WXD_IPC_ERROR_FAILED) {
+                // Connected, but the server's OnAcceptConnection refused the topic
+                capture.Fail(kind, wxString::Format("The server at %s:%s refused the topic %s",
+                                                    hostStr, serviceStr, topicStr));
+            } else {
+                capture.Fail(kind, wxString::Format("Failed to connect to %s:%s", hostStr,
+                                                    serviceStr));
+            }
+        }
+#endif
+    }
 
     if (!conn) {
         // Connection failed, clear pending callbacks
@@ -530,9 +818,17 @@ wxd_IPCClient_MakeConnection(
         return nullptr;
     }
 
+    static_cast<WxdConnection*>(conn)->SetTopicName(topicStr);
     return reinterpret_cast<wxd_IPCConnection_t*>(conn);
 }
 
+WXD_EXPORTED void
+wxd_IPCClient_SetConnectTimeout(wxd_IPCClient_t* client, long milliseconds)
+{
+    if (!client) return;
+    reinterpret_cast<WxdClient*>(client)->SetConnectTimeout(milliseconds);
+}
+
 WXD_EXPORTED void
 wxd_IPCClient_Destroy(wxd_IPCClient_t* client)
 {
@@ -543,6 +839,18 @@ wxd_IPCClient_Destroy(wxd_IPCClient_t* client)
     delete wx_client;
 }
 
+WXD_EXPORTED wxd_IPCError
+wxd_IPC_GetLastErrorKind(void)
+{
+    return g_lastIpcErrorKind;
+}
+
+WXD_EXPORTED size_t
+wxd_IPC_GetLastError(char* buffer, size_t buffer_len)
+{
+    return wxd_cpp_utils::copy_wxstring_to_buffer(g_lastIpcError, buffer, buffer_len);
+}
+
 // Destroy all remaining IPC server/client objects.
 // Called from WxdApp::OnExit() to ensure DDE objects are cleaned up
 // before wxDDECleanUp() asserts they're gone.
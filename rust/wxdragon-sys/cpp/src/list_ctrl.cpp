@@ -20,6 +20,8 @@ public:
     ~WxdListCtrl() override
     {
         ClearVirtualTextCallback();
+        m_image.Clear();
+        m_attr.Clear();
     }
 
     void SetVirtualTextCallback(void* userdata, wxd_listctrl_virtual_text_callback callback,
@@ -44,7 +46,57 @@ public:
         m_freeUserdata = nullptr;
     }
 
+    void SetVirtualImageCallback(void* userdata, wxd_listctrl_virtual_image_callback callback,
+                                 wxd_listctrl_free_userdata_callback freeUserdata)
+    {
+        m_image.Set(userdata, callback, freeUserdata);
+    }
+
+    void SetVirtualAttrCallback(void* userdata, wxd_listctrl_virtual_attr_callback callback,
+                                wxd_listctrl_free_userdata_callback freeUserdata)
+    {
+        m_attr.Set(userdata, callback, freeUserdata);
+    }
+
 protected:
+    int OnGetItemImage(long item) const override
+    {
+        if (!m_image.callback)
+            return wxListCtrl::OnGetItemImage(item);
+        return m_image.callback(m_image.userdata, static_cast<int64_t>(item));
+    }
+
+    wxItemAttr* OnGetItemAttr(long item) const override
+    {
+        if (!m_attr.callback)
+            return wxListCtrl::OnGetItemAttr(item);
+
+        wxd_ListItemAttr attr = {};
+        if (!m_attr.callback(m_attr.userdata, static_cast<int64_t>(item), &attr))
+            return nullptr;
+
+        // wxWidgets uses the returned attribute right away, so one is enough
+        m_itemAttr = wxItemAttr();
+        if (attr.has_text_colour) {
+            m_itemAttr.SetTextColour(wxColour(attr.text_colour.r, attr.text_colour.g,
+                                              attr.text_colour.b, attr.text_colour.a));
+        }
+        if (attr.has_background_colour) {
+            m_itemAttr.SetBackgroundColour(
+                wxColour(attr.background_colour.r, attr.background_colour.g,
+                         attr.background_colour.b, attr.background_colour.a));
+        }
+        if (attr.bold || attr.italic) {
+            wxFont font = GetFont();
+            if (attr.bold)
+                font.MakeBold();
+            if (attr.italic)
+                font.MakeItalic();
+            m_itemAttr.SetFont(font);
+        }
+        return &m_itemAttr;
+    }
+
     wxString OnGetItemText(long item, long column) const override
     {
         if (!m_textCallback) {
@@ -65,10 +117,39 @@ protected:
     }
 
 private:
+    // A Rust callback together with the data it owns
+    template <typename Callback>
+    struct VirtualCallback {
+        void* userdata = nullptr;
+        Callback callback = nullptr;
+        wxd_listctrl_free_userdata_callback freeUserdata = nullptr;
+
+        void Set(void* newUserdata, Callback newCallback,
+                 wxd_listctrl_free_userdata_callback newFreeUserdata)
+        {
+            Clear();
+            userdata = newUserdata;
+            callback = newCallback;
+            freeUserdata = newFreeUserdata;
+        }
+
+        void Clear()
+        {
+            if (userdata && freeUserdata)
+                freeUserdata(userdata);
+            userdata = nullptr;
+            callback = nullptr;
+            freeUserdata = nullptr;
+        }
+    };
+
     void* m_userdata;
     wxd_listctrl_virtual_text_callback m_textCallback;
     wxd_listctrl_free_string_callback m_freeString;
     wxd_listctrl_free_userdata_callback m_freeUserdata;
+    VirtualCallback<wxd_listctrl_virtual_image_callback> m_image;
+    VirtualCallback<wxd_listctrl_virtual_attr_callback> m_attr;
+    mutable wxItemAttr m_itemAttr;
 };
 
 static WxdListCtrl*
@@ -488,6 +569,32 @@ wxd_ListCtrl_ClearVirtualTextCallback(wxd_ListCtrl_t* self)
     listCtrl->ClearVirtualTextCallback();
 }
 
+WXD_EXPORTED bool
+wxd_ListCtrl_SetVirtualImageCallback(wxd_ListCtrl_t* self, void* userdata,
+                                     wxd_listctrl_virtual_image_callback callback,
+                                     wxd_listctrl_free_userdata_callback freeUserdata)
+{
+    WxdListCtrl* listCtrl = wxd_as_custom_list_ctrl(self);
+    if (!listCtrl || !userdata || !callback)
+        return false;
+
+    listCtrl->SetVirtualImageCallback(userdata, callback, freeUserdata);
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_ListCtrl_SetVirtualAttrCallback(wxd_ListCtrl_t* self, void* userdata,
+                                    wxd_listctrl_virtual_attr_callback callback,
+                                    wxd_listctrl_free_userdata_callback freeUserdata)
+{
+    WxdListCtrl* listCtrl = wxd_as_custom_list_ctrl(self);
+    if (!listCtrl || !userdata || !callback)
+        return false;
+
+    listCtrl->SetVirtualAttrCallback(userdata, callback, freeUserdata);
+    return true;
+}
+
 // Sorting - This is a bit tricky because of the callback
 // We'll need a mapping system or to adapt this for Rust usage
 struct SortCallbackData {
@@ -533,6 +640,25 @@ wxd_ListCtrl_ShowSortIndicator(wxd_ListCtrl_t* self, int col, bool ascending)
     reinterpret_cast<wxListCtrl*>(self)->ShowSortIndicator(col, ascending);
 }
 
+WXD_EXPORTED void
+wxd_ListCtrl_RemoveSortIndicator(wxd_ListCtrl_t* self)
+{
+    if (!self)
+        return;
+    reinterpret_cast<wxListCtrl*>(self)->RemoveSortIndicator();
+}
+
+WXD_EXPORTED int
+wxd_ListCtrl_GetSortIndicator(wxd_ListCtrl_t* self, bool* ascending)
+{
+    if (!self)
+        return -1;
+    wxListCtrl* listCtrl = reinterpret_cast<wxListCtrl*>(self);
+    if (ascending)
+        *ascending = listCtrl->IsAscendingSortIndicator();
+    return listCtrl->GetSortIndicator();
+}
+
 // Image List Support
 WXD_EXPORTED void
 wxd_ListCtrl_SetImageList(wxd_ListCtrl_t* self, wxd_ImageList_t* imageList, int which)
@@ -160,4 +160,60 @@ wxd_ListBox_PopupMenu(wxd_ListBox_t* listbox, wxd_Menu_t* menu, wxd_Point pos)
     return lb->PopupMenu(wx_menu, wxd_to_wx_point_sb(pos));
 }
 
+WXD_EXPORTED size_t
+wxd_ListBox_GetSelections(wxd_ListBox_t* listbox, int* selections, size_t max_items)
+{
+    wxListBox* lb = (wxListBox*)listbox;
+    if (!lb)
+        return 0;
+    wxArrayInt indices;
+    size_t count = (size_t)lb->GetSelections(indices);
+    for (size_t i = 0; selections && i < count && i < max_items; ++i)
+        selections[i] = indices[i];
+    return count;
+}
+
+WXD_EXPORTED int
+wxd_ListBox_FindString(wxd_ListBox_t* listbox, const char* text, bool case_sensitive)
+{
+    wxListBox* lb = (wxListBox*)listbox;
+    if (!lb || !text)
+        return wxNOT_FOUND;
+    return lb->FindString(wxString::FromUTF8(text), case_sensitive);
+}
+
+WXD_EXPORTED void
+wxd_ListBox_SetFirstItem(wxd_ListBox_t* listbox, int index)
+{
+    wxListBox* lb = (wxListBox*)listbox;
+    if (lb && index >= 0 && (unsigned int)index < lb->GetCount())
+        lb->SetFirstItem(index);
+}
+
+WXD_EXPORTED int
+wxd_ListBox_HitTest(wxd_ListBox_t* listbox, wxd_Point point)
+{
+    wxListBox* lb = (wxListBox*)listbox;
+    if (!lb)
+        return wxNOT_FOUND;
+    return lb->HitTest(wxPoint(point.x, point.y));
+}
+
+WXD_EXPORTED void
+wxd_ListBox_SetClientData(wxd_ListBox_t* listbox, unsigned int n, uint64_t data)
+{
+    wxListBox* lb = (wxListBox*)listbox;
+    if (lb && n < lb->GetCount())
+        lb->SetClientData(n, reinterpret_cast<void*>(static_cast<uintptr_t>(data)));
+}
+
+WXD_EXPORTED uint64_t
+wxd_ListBox_GetClientData(wxd_ListBox_t* listbox, unsigned int n)
+{
+    wxListBox* lb = (wxListBox*)listbox;
+    if (!lb || n >= lb->GetCount() || !lb->HasClientUntypedData())
+        return 0;
+    return static_cast<uint64_t>(reinterpret_cast<uintptr_t>(lb->GetClientData(n)));
+}
+
 } // extern "C"
\ No newline at end of file
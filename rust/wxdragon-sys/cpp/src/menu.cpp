@@ -566,13 +566,13 @@ wxd_MenuItem_GetSubMenu(const wxd_MenuItem_t* item)
 }
 
 WXD_EXPORTED void
-wxd_MenuItem_SetBitmap(wxd_MenuItem_t* item, const wxd_Bitmap_t* bitmap)
+wxd_MenuItem_SetBitmap(wxd_MenuItem_t* item, const wxd_BitmapBundle_t* bitmap)
 {
     if (!item)
         return;
     wxMenuItem* wx_item = reinterpret_cast<wxMenuItem*>(item);
-    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
-    wx_item->SetBitmap(wx_bitmap ? *wx_bitmap : wxNullBitmap);
+    const wxBitmapBundle* bundle = reinterpret_cast<const wxBitmapBundle*>(bitmap);
+    wx_item->SetBitmap(bundle ? *bundle : wxBitmapBundle());
 }
 
 WXD_EXPORTED wxd_Bitmap_t*
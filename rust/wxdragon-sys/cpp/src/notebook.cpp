@@ -1,7 +1,249 @@
 #include <wx/wxprec.h>
 #include <wx/wx.h>
 #include "wxdragon.h"
+#include "wxd_utils.h"
 #include <wx/notebook.h>
+#include <unordered_map>
+#ifdef __WXMSW__
+#include <wx/msw/wrapcctl.h> // For TabCtrl_GetCurSel
+#endif
+
+// Whether the page change last asked for went through, per tracked notebook
+static std::unordered_map<wxNotebook*, bool> s_page_changed;
+
+// A vetoed change must leave the old tab selected. On MSW the native tab control
+// can still show the tab that was clicked, e.g. when the handler showed a message
+// box, while the old page stays visible; so once the change is over the tabs are
+// made to match the selection again, without events.
+static void
+RestoreVetoedTab(wxNotebook* notebook, int old_page, int new_page)
+{
+    auto state = s_page_changed.find(notebook);
+    if (state == s_page_changed.end() || state->second)
+        return;
+#ifdef __WXMSW__
+    wxUnusedVar(old_page);
+    wxUnusedVar(new_page);
+    HWND hwnd = (HWND)notebook->GetHWND();
+    int selection = notebook->GetSelection();
+    if (selection != wxNOT_FOUND && TabCtrl_GetCurSel(hwnd) != selection)
+        TabCtrl_SetCurSel(hwnd, selection);
+#else
+    if (old_page != wxNOT_FOUND && old_page < (int)notebook->GetPageCount() &&
+        notebook->GetSelection() == new_page)
+        notebook->ChangeSelection(old_page);
+#endif
+}
+
+static void
+OnGuardedPageChanging(wxBookCtrlEvent& event)
+{
+    event.Skip();
+    wxNotebook* notebook = wxDynamicCast(event.GetEventObject(), wxNotebook);
+    if (!notebook || !s_page_changed.count(notebook))
+        return;
+    s_page_changed[notebook] = false;
+    // Bound handlers run before the notebook's own ones, so whether the change
+    // was vetoed is only known afterwards
+    int old_page = event.GetOldSelection();
+    int new_page = event.GetSelection();
+    notebook->CallAfter([notebook, old_page, new_page]() { RestoreVetoedTab(notebook, old_page, new_page); });
+}
+
+static void
+OnGuardedPageChanged(wxBookCtrlEvent& event)
+{
+    event.Skip();
+    wxNotebook* notebook = wxDynamicCast(event.GetEventObject(), wxNotebook);
+    if (notebook && s_page_changed.count(notebook))
+        s_page_changed[notebook] = true;
+}
+
+static void
+OnGuardedNotebookDestroy(wxWindowDestroyEvent& event)
+{
+    if (wxNotebook* notebook = wxDynamicCast(event.GetEventObject(), wxNotebook))
+        s_page_changed.erase(notebook);
+    event.Skip();
+}
+
+static void
+GuardVetoes(wxNotebook* notebook)
+{
+    if (s_page_changed.count(notebook))
+        return;
+    s_page_changed[notebook] = true;
+    notebook->Bind(wxEVT_NOTEBOOK_PAGE_CHANGING, &OnGuardedPageChanging);
+    notebook->Bind(wxEVT_NOTEBOOK_PAGE_CHANGED, &OnGuardedPageChanged);
+    notebook->Bind(wxEVT_DESTROY, &OnGuardedNotebookDestroy);
+}
+
+// Sent after the user dragged a tab to another position, with the new position
+// as the selection and the old one as the old selection
+wxDEFINE_EVENT(wxdEVT_NOTEBOOK_PAGES_REORDERED, wxBookCtrlEvent);
+
+// Swallows the page change events a notebook sends while one of its pages is
+// moved, before any handler bound to the notebook sees them
+class PageMoveEventBlocker : public wxEvtHandler {
+public:
+    bool ProcessEvent(wxEvent& event) override
+    {
+        wxEventType type = event.GetEventType();
+        if (type == wxEVT_NOTEBOOK_PAGE_CHANGING || type == wxEVT_NOTEBOOK_PAGE_CHANGED)
+            return true;
+        return wxEvtHandler::ProcessEvent(event);
+    }
+};
+
+// Moves a page by removing and inserting it again. The page window itself is
+// kept, with its children, sizer and bound handlers, as is the selected page.
+static bool
+MovePage(wxNotebook* notebook, size_t from, size_t to)
+{
+    size_t count = notebook->GetPageCount();
+    if (from >= count || to >= count)
+        return false;
+    if (from == to)
+        return true;
+
+    wxWindow* page = notebook->GetPage(from);
+    wxString text = notebook->GetPageText(from);
+    int image = notebook->GetPageImage(from);
+    int selection = notebook->GetSelection();
+    wxWindow* selected = selection != wxNOT_FOUND ? notebook->GetPage(selection) : nullptr;
+
+    PageMoveEventBlocker blocker;
+    notebook->PushEventHandler(&blocker);
+    notebook->Freeze();
+    bool moved = false;
+    if (notebook->RemovePage(from)) {
+        moved = notebook->InsertPage(to, page, text, false, image);
+        if (!moved)
+            notebook->InsertPage(from, page, text, false, image);
+    }
+    if (selected) {
+        int index = notebook->FindPage(selected);
+        if (index != wxNOT_FOUND && notebook->GetSelection() != index)
+            notebook->ChangeSelection(index);
+    }
+    notebook->Thaw();
+    notebook->PopEventHandler();
+    return moved;
+}
+
+// Dragging tabs to reorder them, per notebook it was enabled for
+struct TabDrag {
+    bool enabled = false;
+    int page = wxNOT_FOUND; // Tab the left button was pressed on
+    wxPoint start;
+    bool dragging = false;
+};
+
+static std::unordered_map<wxNotebook*, TabDrag> s_tab_drags;
+
+static TabDrag*
+FindTabDrag(wxEvent& event, wxNotebook** notebook)
+{
+    *notebook = wxDynamicCast(event.GetEventObject(), wxNotebook);
+    if (!*notebook)
+        return nullptr;
+    auto drag = s_tab_drags.find(*notebook);
+    if (drag == s_tab_drags.end() || !drag->second.enabled)
+        return nullptr;
+    return &drag->second;
+}
+
+static void
+EndTabDrag(wxNotebook* notebook, TabDrag& drag)
+{
+    if (drag.dragging) {
+        if (notebook->HasCapture())
+            notebook->ReleaseMouse();
+        notebook->SetCursor(wxNullCursor);
+    }
+    drag.page = wxNOT_FOUND;
+    drag.dragging = false;
+}
+
+static void
+OnTabDragLeftDown(wxMouseEvent& event)
+{
+    event.Skip();
+    wxNotebook* notebook;
+    TabDrag* drag = FindTabDrag(event, &notebook);
+    if (!drag)
+        return;
+    EndTabDrag(notebook, *drag);
+    long flags = wxBK_HITTEST_NOWHERE;
+    int page = notebook->HitTest(event.GetPosition(), &flags);
+    if (page != wxNOT_FOUND && (flags & wxBK_HITTEST_ONITEM)) {
+        drag->page = page;
+        drag->start = event.GetPosition();
+    }
+}
+
+static void
+OnTabDragMotion(wxMouseEvent& event)
+{
+    event.Skip();
+    wxNotebook* notebook;
+    TabDrag* drag = FindTabDrag(event, &notebook);
+    if (!drag || drag->page == wxNOT_FOUND || drag->dragging)
+        return;
+    if (!event.LeftIsDown()) {
+        EndTabDrag(notebook, *drag);
+        return;
+    }
+    // Only start dragging once the mouse moved far enough for it not to be a click
+    wxPoint moved = event.GetPosition() - drag->start;
+    int threshold_x = wxMax(wxSystemSettings::GetMetric(wxSYS_DRAG_X, notebook), 3);
+    int threshold_y = wxMax(wxSystemSettings::GetMetric(wxSYS_DRAG_Y, notebook), 3);
+    if (abs(moved.x) < threshold_x && abs(moved.y) < threshold_y)
+        return;
+    drag->dragging = true;
+    if (!notebook->HasCapture())
+        notebook->CaptureMouse();
+    notebook->SetCursor(wxCursor(wxCURSOR_HAND));
+}
+
+static void
+OnTabDragLeftUp(wxMouseEvent& event)
+{
+    event.Skip();
+    wxNotebook* notebook;
+    TabDrag* drag = FindTabDrag(event, &notebook);
+    if (!drag)
+        return;
+    bool dragging = drag->dragging;
+    int old_page = drag->page;
+    EndTabDrag(notebook, *drag);
+    if (!dragging)
+        return;
+
+    int new_page = notebook->HitTest(event.GetPosition());
+    if (new_page == wxNOT_FOUND || new_page == old_page || !MovePage(notebook, old_page, new_page))
+        return;
+    wxBookCtrlEvent reordered(wxdEVT_NOTEBOOK_PAGES_REORDERED, notebook->GetId(), new_page,
+                              old_page);
+    reordered.SetEventObject(notebook);
+    notebook->HandleWindowEvent(reordered);
+}
+
+static void
+OnTabDragCaptureLost(wxMouseCaptureLostEvent& event)
+{
+    wxNotebook* notebook;
+    if (TabDrag* drag = FindTabDrag(event, &notebook))
+        EndTabDrag(notebook, *drag);
+}
+
+static void
+OnTabDragNotebookDestroy(wxWindowDestroyEvent& event)
+{
+    if (wxNotebook* notebook = wxDynamicCast(event.GetEventObject(), wxNotebook))
+        s_tab_drags.erase(notebook);
+    event.Skip();
+}
 
 // Implementation for wxd_Notebook_Create
 WXD_EXPORTED wxd_Notebook_t*
@@ -11,10 +253,21 @@ wxd_Notebook_Create(wxd_Window_t* parent, wxd_Id id, wxd_Point pos, wxd_Size siz
     wxWindow* parentWin = reinterpret_cast<wxWindow*>(parent);
     wxNotebook* notebook = new wxNotebook(parentWin, id, wxPoint(pos.x, pos.y),
                                           wxSize(size.width, size.height), style);
+    GuardVetoes(notebook);
 
     return reinterpret_cast<wxd_Notebook_t*>(notebook);
 }
 
+// Keep the old tab selected after vetoed changes for a notebook not created by
+// wxd_Notebook_Create
+WXD_EXPORTED void
+wxd_Notebook_GuardVetoes(wxd_Notebook_t* self)
+{
+    wxNotebook* notebook = wxDynamicCast(reinterpret_cast<wxWindow*>(self), wxNotebook);
+    if (notebook)
+        GuardVetoes(notebook);
+}
+
 // Implementation for wxd_Notebook_AddPage
 WXD_EXPORTED bool
 wxd_Notebook_AddPage(wxd_Notebook_t* self, wxd_Window_t* page, const char* text, bool select)
@@ -189,3 +442,108 @@ wxd_Notebook_RemovePage(wxd_Notebook_t* self, size_t n)
         return false;
     return notebook->RemovePage(n);
 }
+
+WXD_EXPORTED bool
+wxd_Notebook_DeletePage(wxd_Notebook_t* self, size_t n)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook || n >= notebook->GetPageCount())
+        return false;
+    return notebook->DeletePage(n);
+}
+
+WXD_EXPORTED bool
+wxd_Notebook_DeleteAllPages(wxd_Notebook_t* self)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook)
+        return false;
+    return notebook->DeleteAllPages();
+}
+
+WXD_EXPORTED int
+wxd_Notebook_FindPage(wxd_Notebook_t* self, wxd_Window_t* page)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    wxWindow* pageWin = reinterpret_cast<wxWindow*>(page);
+    if (!notebook || !pageWin)
+        return wxNOT_FOUND;
+    return notebook->FindPage(pageWin);
+}
+
+WXD_EXPORTED bool
+wxd_Notebook_SetPageText(wxd_Notebook_t* self, size_t n, const char* text)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook || n >= notebook->GetPageCount())
+        return false;
+    return notebook->SetPageText(n, wxString::FromUTF8(text ? text : ""));
+}
+
+WXD_EXPORTED int
+wxd_Notebook_GetPageText(wxd_Notebook_t* self, size_t n, char* buffer, size_t buffer_len)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook || n >= notebook->GetPageCount())
+        return -1;
+    return (int)wxd_cpp_utils::copy_wxstring_to_buffer(notebook->GetPageText(n), buffer, buffer_len);
+}
+
+WXD_EXPORTED bool
+wxd_Notebook_SetPageImage(wxd_Notebook_t* self, size_t n, int imageId)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook || n >= notebook->GetPageCount())
+        return false;
+    return notebook->SetPageImage(n, imageId);
+}
+
+WXD_EXPORTED int
+wxd_Notebook_GetPageImage(wxd_Notebook_t* self, size_t n)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook || n >= notebook->GetPageCount())
+        return wxNOT_FOUND;
+    return notebook->GetPageImage(n);
+}
+
+WXD_EXPORTED bool
+wxd_Notebook_MovePage(wxd_Notebook_t* self, size_t from, size_t to)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook)
+        return false;
+    return MovePage(notebook, from, to);
+}
+
+WXD_EXPORTED int
+wxd_Notebook_HitTest(wxd_Notebook_t* self, wxd_Point point, int* flags)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    long hitFlags = wxBK_HITTEST_NOWHERE;
+    int page = notebook ? notebook->HitTest(wxPoint(point.x, point.y), &hitFlags) : wxNOT_FOUND;
+    if (flags)
+        *flags = (int)hitFlags;
+    return page;
+}
+
+WXD_EXPORTED void
+wxd_Notebook_EnableTabDragReorder(wxd_Notebook_t* self, bool enable)
+{
+    wxNotebook* notebook = reinterpret_cast<wxNotebook*>(self);
+    if (!notebook)
+        return;
+    auto drag = s_tab_drags.find(notebook);
+    if (drag == s_tab_drags.end()) {
+        if (!enable)
+            return;
+        notebook->Bind(wxEVT_LEFT_DOWN, &OnTabDragLeftDown);
+        notebook->Bind(wxEVT_MOTION, &OnTabDragMotion);
+        notebook->Bind(wxEVT_LEFT_UP, &OnTabDragLeftUp);
+        notebook->Bind(wxEVT_MOUSE_CAPTURE_LOST, &OnTabDragCaptureLost);
+        notebook->Bind(wxEVT_DESTROY, &OnTabDragNotebookDestroy);
+        drag = s_tab_drags.emplace(notebook, TabDrag()).first;
+    }
+    EndTabDrag(notebook, drag->second);
+    drag->second.enabled = enable;
+}
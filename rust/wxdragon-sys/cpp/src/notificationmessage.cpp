@@ -4,8 +4,52 @@
 #include <wx/notifmsg.h>         // Required for wxNotificationMessage
 #include <wx/generic/notifmsg.h> // Required for wxGenericNotificationMessage
 
+#if defined(__WXMSW__) && wxUSE_TASKBARICON
+#include <wx/taskbar.h>
+#endif
+
 // --- wxNotificationMessage ---
 
+// Native and generic notifications only share wxNotificationMessageBase, so every
+// accessor below works through that type.
+static inline wxNotificationMessageBase*
+to_notification(wxd_NotificationMessage_t* self)
+{
+    return reinterpret_cast<wxNotificationMessageBase*>(self);
+}
+
+#ifdef __WXMSW__
+#if wxUSE_TASKBARICON
+// Tray icon registered through wxd_NotificationMessage_UseTaskBarIcon
+static wxTaskBarIcon* s_notificationTaskBarIcon = nullptr;
+
+// Called by wxd_TaskBarIcon_Destroy so notifications never use a deleted icon
+void
+wxd_NotificationMessage_ForgetTaskBarIcon(wxTaskBarIcon* icon)
+{
+    if (icon && icon == s_notificationTaskBarIcon) {
+        wxNotificationMessage::UseTaskBarIcon(nullptr);
+        s_notificationTaskBarIcon = nullptr;
+    }
+}
+#endif
+
+// Whether wxd_NotificationMessage_UseToasts enabled toast notifications
+static bool s_notificationToasts = false;
+
+// Native notifications without a tray icon or toast support make wx create its
+// own hidden tray icon, which can keep the app alive; use the generic ones then.
+static bool
+can_use_native_notifications()
+{
+#if wxUSE_TASKBARICON
+    if (s_notificationTaskBarIcon)
+        return true;
+#endif
+    return s_notificationToasts;
+}
+#endif
+
 WXD_EXPORTED wxd_NotificationMessage_t*
 wxd_NotificationMessage_Create(const char* title, const char* message, wxd_Window_t* parent,
                                int flags)
@@ -14,27 +58,56 @@ wxd_NotificationMessage_Create(const char* title, const char* message, wxd_Windo
     wxString wxMessage = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message);
     wxWindow* wxParent = parent ? reinterpret_cast<wxWindow*>(parent) : nullptr;
 
+    wxNotificationMessageBase* instance = nullptr;
 #ifdef __WXMSW__
-    // Use generic notifications on Windows to avoid taskbar icon issues
-    // that cause app hanging and persistent icons
-    wxGenericNotificationMessage* instance =
-        new wxGenericNotificationMessage(wxTitle, wxMessage, wxParent, flags);
-#else
-    // Use native notifications on other platforms
-    wxNotificationMessage* instance =
-        new wxNotificationMessage(wxTitle, wxMessage, wxParent, flags);
+    if (!can_use_native_notifications()) {
+        instance = new wxGenericNotificationMessage(wxTitle, wxMessage, wxParent, flags);
+    }
 #endif
+    if (!instance) {
+        instance = new wxNotificationMessage(wxTitle, wxMessage, wxParent, flags);
+    }
 
     return reinterpret_cast<wxd_NotificationMessage_t*>(instance);
 }
 
+WXD_EXPORTED bool
+wxd_NotificationMessage_UseTaskBarIcon(wxd_TaskBarIcon_t* icon)
+{
+#if defined(__WXMSW__) && wxUSE_TASKBARICON
+    wxTaskBarIcon* wxIcon = reinterpret_cast<wxTaskBarIcon*>(icon);
+    wxNotificationMessage::UseTaskBarIcon(wxIcon);
+    s_notificationTaskBarIcon = wxIcon;
+    return true;
+#else
+    wxUnusedVar(icon);
+    return false;
+#endif
+}
+
+WXD_EXPORTED bool
+wxd_NotificationMessage_UseToasts(const char* shortcut_path, const char* app_id)
+{
+#ifdef __WXMSW__
+    // Creates or updates the Start menu shortcut and sets the AppUserModelID
+    wxString shortcutPath = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(shortcut_path);
+    wxString appId = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(app_id);
+    s_notificationToasts = wxNotificationMessage::MSWUseToasts(shortcutPath, appId);
+    return s_notificationToasts;
+#else
+    wxUnusedVar(shortcut_path);
+    wxUnusedVar(app_id);
+    return false;
+#endif
+}
+
 WXD_EXPORTED void
 wxd_NotificationMessage_Destroy(wxd_NotificationMessage_t* self)
 {
     if (self) {
         // wxNotificationMessage is not a wxWindow, so it needs to be deleted directly.
         // It does not have a Destroy() method that wxWindow objects use for deferred deletion.
-        delete reinterpret_cast<wxNotificationMessage*>(self);
+        delete to_notification(self);
     }
 }
 
@@ -45,7 +118,7 @@ wxd_NotificationMessage_Show(wxd_NotificationMessage_t* self, int timeout)
         return false;
     // wxNotificationMessage::Show can take wxNotificationMessage::Timeout_Auto or wxNotificationMessage::Timeout_Never
     // or a duration in seconds.
-    return reinterpret_cast<wxNotificationMessage*>(self)->Show(timeout);
+    return to_notification(self)->Show(timeout);
 }
 
 WXD_EXPORTED bool
@@ -53,7 +126,7 @@ wxd_NotificationMessage_Close(wxd_NotificationMessage_t* self)
 {
     if (!self)
         return false;
-    return reinterpret_cast<wxNotificationMessage*>(self)->Close();
+    return to_notification(self)->Close();
 }
 
 WXD_EXPORTED void
@@ -62,7 +135,7 @@ wxd_NotificationMessage_SetTitle(wxd_NotificationMessage_t* self, const char* ti
     if (!self)
         return;
     wxString wxTitle = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(title);
-    reinterpret_cast<wxNotificationMessage*>(self)->SetTitle(wxTitle);
+    to_notification(self)->SetTitle(wxTitle);
 }
 
 WXD_EXPORTED void
@@ -71,7 +144,7 @@ wxd_NotificationMessage_SetMessage(wxd_NotificationMessage_t* self, const char*
     if (!self)
         return;
     wxString wxMessage = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message);
-    reinterpret_cast<wxNotificationMessage*>(self)->SetMessage(wxMessage);
+    to_notification(self)->SetMessage(wxMessage);
 }
 
 WXD_EXPORTED void
@@ -79,7 +152,7 @@ wxd_NotificationMessage_SetFlags(wxd_NotificationMessage_t* self, int flags)
 {
     if (!self)
         return;
-    reinterpret_cast<wxNotificationMessage*>(self)->SetFlags(flags);
+    to_notification(self)->SetFlags(flags);
 }
 
 WXD_EXPORTED void
@@ -88,7 +161,7 @@ wxd_NotificationMessage_SetParent(wxd_NotificationMessage_t* self, wxd_Window_t*
     if (!self)
         return;
     wxWindow* wxParent = parent ? reinterpret_cast<wxWindow*>(parent) : nullptr;
-    reinterpret_cast<wxNotificationMessage*>(self)->SetParent(wxParent);
+    to_notification(self)->SetParent(wxParent);
 }
 
 WXD_EXPORTED bool
@@ -98,7 +171,7 @@ wxd_NotificationMessage_AddAction(wxd_NotificationMessage_t* self, wxd_Id action
     if (!self)
         return false;
     wxString wxLabel = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(label);
-    return reinterpret_cast<wxNotificationMessage*>(self)->AddAction(actionid, wxLabel);
+    return to_notification(self)->AddAction(actionid, wxLabel);
 }
 
 // Event binding for wxNotificationMessage: Events like wxEVT_NOTIFICATION_MESSAGE_CLICK,
@@ -0,0 +1,43 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "wx/numdlg.h"
+#include "../include/wxdragon.h"
+
+#include <algorithm>
+#include <climits>
+
+// wxNumberEntryDialog works with long, which is 32-bit on Windows
+static long
+to_long(int64_t value)
+{
+    return (long)std::max<int64_t>(LONG_MIN, std::min<int64_t>(LONG_MAX, value));
+}
+
+WXD_EXPORTED wxd_NumberEntryDialog_t*
+wxd_NumberEntryDialog_Create(wxd_Window_t* parent, const char* message, const char* prompt,
+                             const char* caption, int64_t value, int64_t min, int64_t max)
+{
+    long wx_min = to_long(min);
+    long wx_max = to_long(max);
+    if (wx_min > wx_max)
+        std::swap(wx_min, wx_max);
+    // wxNumberEntryDialog asserts on an out-of-range initial value
+    long wx_value = std::max(wx_min, std::min(wx_max, to_long(value)));
+
+    wxNumberEntryDialog* dlg = new wxNumberEntryDialog(
+        (wxWindow*)parent, WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message),
+        WXD_STR_TO_WX_STRING_UTF8_NULL_OK(prompt), WXD_STR_TO_WX_STRING_UTF8_NULL_OK(caption),
+        wx_value, wx_min, wx_max);
+
+    return (wxd_NumberEntryDialog_t*)dlg;
+}
+
+// ShowModal is inherited from wxd_Dialog_ShowModal
+
+WXD_EXPORTED int64_t
+wxd_NumberEntryDialog_GetValue(wxd_NumberEntryDialog_t* self)
+{
+    if (!self)
+        return -1;
+    return ((wxNumberEntryDialog*)self)->GetValue();
+}
@@ -99,6 +99,22 @@ extern "C" bool wxd_PrintData_IsOk(wxd_PrintData_t* self) {
     return reinterpret_cast<wxPrintData*>(self)->IsOk();
 }
 
+extern "C" int wxd_PrintData_GetOrientation(wxd_PrintData_t* self) {
+    return static_cast<int>(reinterpret_cast<wxPrintData*>(self)->GetOrientation());
+}
+
+extern "C" void wxd_PrintData_SetOrientation(wxd_PrintData_t* self, int orientation) {
+    reinterpret_cast<wxPrintData*>(self)->SetOrientation(static_cast<wxPrintOrientation>(orientation));
+}
+
+extern "C" int wxd_PrintData_GetPaperId(wxd_PrintData_t* self) {
+    return static_cast<int>(reinterpret_cast<wxPrintData*>(self)->GetPaperId());
+}
+
+extern "C" void wxd_PrintData_SetPaperId(wxd_PrintData_t* self, int paper_id) {
+    reinterpret_cast<wxPrintData*>(self)->SetPaperId(static_cast<wxPaperSize>(paper_id));
+}
+
 // PrintDialogData
 extern "C" wxd_PrintDialogData_t* wxd_PrintDialogData_Create() {
     return reinterpret_cast<wxd_PrintDialogData_t*>(new wxPrintDialogData());
@@ -112,10 +128,90 @@ extern "C" void wxd_PrintDialogData_Destroy(wxd_PrintDialogData_t* self) {
     delete reinterpret_cast<wxPrintDialogData*>(self);
 }
 
+extern "C" wxd_PrintDialogData_t* wxd_PrintDialogData_Copy(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxd_PrintDialogData_t*>(new wxPrintDialogData(*reinterpret_cast<wxPrintDialogData*>(self)));
+}
+
 extern "C" wxd_PrintData_t* wxd_PrintDialogData_GetPrintData(wxd_PrintDialogData_t* self) {
     return reinterpret_cast<wxd_PrintData_t*>(&reinterpret_cast<wxPrintDialogData*>(self)->GetPrintData());
 }
 
+extern "C" void wxd_PrintDialogData_SetPrintData(wxd_PrintDialogData_t* self, wxd_PrintData_t* data) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetPrintData(*reinterpret_cast<wxPrintData*>(data));
+}
+
+extern "C" int wxd_PrintDialogData_GetFromPage(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetFromPage();
+}
+
+extern "C" void wxd_PrintDialogData_SetFromPage(wxd_PrintDialogData_t* self, int page) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetFromPage(page);
+}
+
+extern "C" int wxd_PrintDialogData_GetToPage(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetToPage();
+}
+
+extern "C" void wxd_PrintDialogData_SetToPage(wxd_PrintDialogData_t* self, int page) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetToPage(page);
+}
+
+extern "C" int wxd_PrintDialogData_GetMinPage(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetMinPage();
+}
+
+extern "C" void wxd_PrintDialogData_SetMinPage(wxd_PrintDialogData_t* self, int page) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetMinPage(page);
+}
+
+extern "C" int wxd_PrintDialogData_GetMaxPage(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetMaxPage();
+}
+
+extern "C" void wxd_PrintDialogData_SetMaxPage(wxd_PrintDialogData_t* self, int page) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetMaxPage(page);
+}
+
+extern "C" int wxd_PrintDialogData_GetNoCopies(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetNoCopies();
+}
+
+extern "C" void wxd_PrintDialogData_SetNoCopies(wxd_PrintDialogData_t* self, int copies) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetNoCopies(copies);
+}
+
+extern "C" bool wxd_PrintDialogData_GetCollate(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetCollate();
+}
+
+extern "C" void wxd_PrintDialogData_SetCollate(wxd_PrintDialogData_t* self, bool collate) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetCollate(collate);
+}
+
+extern "C" bool wxd_PrintDialogData_GetPrintToFile(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetPrintToFile();
+}
+
+extern "C" void wxd_PrintDialogData_SetPrintToFile(wxd_PrintDialogData_t* self, bool print_to_file) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetPrintToFile(print_to_file);
+}
+
+extern "C" bool wxd_PrintDialogData_GetAllPages(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetAllPages();
+}
+
+extern "C" void wxd_PrintDialogData_SetAllPages(wxd_PrintDialogData_t* self, bool all_pages) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetAllPages(all_pages);
+}
+
+extern "C" bool wxd_PrintDialogData_GetSelection(wxd_PrintDialogData_t* self) {
+    return reinterpret_cast<wxPrintDialogData*>(self)->GetSelection();
+}
+
+extern "C" void wxd_PrintDialogData_SetSelection(wxd_PrintDialogData_t* self, bool selection) {
+    reinterpret_cast<wxPrintDialogData*>(self)->SetSelection(selection);
+}
+
 // PageSetupDialogData
 extern "C" wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Create() {
     return reinterpret_cast<wxd_PageSetupDialogData_t*>(new wxPageSetupDialogData());
@@ -129,10 +225,50 @@ extern "C" void wxd_PageSetupDialogData_Destroy(wxd_PageSetupDialogData_t* self)
     delete reinterpret_cast<wxPageSetupDialogData*>(self);
 }
 
+extern "C" wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Copy(wxd_PageSetupDialogData_t* self) {
+    return reinterpret_cast<wxd_PageSetupDialogData_t*>(new wxPageSetupDialogData(*reinterpret_cast<wxPageSetupDialogData*>(self)));
+}
+
 extern "C" wxd_PrintData_t* wxd_PageSetupDialogData_GetPrintData(wxd_PageSetupDialogData_t* self) {
     return reinterpret_cast<wxd_PrintData_t*>(&reinterpret_cast<wxPageSetupDialogData*>(self)->GetPrintData());
 }
 
+extern "C" void wxd_PageSetupDialogData_SetPrintData(wxd_PageSetupDialogData_t* self, wxd_PrintData_t* data) {
+    reinterpret_cast<wxPageSetupDialogData*>(self)->SetPrintData(*reinterpret_cast<wxPrintData*>(data));
+}
+
+extern "C" wxd_Size wxd_PageSetupDialogData_GetPaperSize(wxd_PageSetupDialogData_t* self) {
+    wxSize size = reinterpret_cast<wxPageSetupDialogData*>(self)->GetPaperSize();
+    return wxd_Size{ size.x, size.y };
+}
+
+// Unlike setting the id on the print data, this also updates the paper size
+extern "C" void wxd_PageSetupDialogData_SetPaperId(wxd_PageSetupDialogData_t* self, int paper_id) {
+    reinterpret_cast<wxPageSetupDialogData*>(self)->SetPaperId(static_cast<wxPaperSize>(paper_id));
+}
+
+extern "C" void wxd_PageSetupDialogData_SetPaperSize(wxd_PageSetupDialogData_t* self, wxd_Size size) {
+    reinterpret_cast<wxPageSetupDialogData*>(self)->SetPaperSize(wxSize(size.width, size.height));
+}
+
+extern "C" wxd_Point wxd_PageSetupDialogData_GetMarginTopLeft(wxd_PageSetupDialogData_t* self) {
+    wxPoint pt = reinterpret_cast<wxPageSetupDialogData*>(self)->GetMarginTopLeft();
+    return wxd_Point{ pt.x, pt.y };
+}
+
+extern "C" void wxd_PageSetupDialogData_SetMarginTopLeft(wxd_PageSetupDialogData_t* self, wxd_Point pt) {
+    reinterpret_cast<wxPageSetupDialogData*>(self)->SetMarginTopLeft(wxPoint(pt.x, pt.y));
+}
+
+extern "C" wxd_Point wxd_PageSetupDialogData_GetMarginBottomRight(wxd_PageSetupDialogData_t* self) {
+    wxPoint pt = reinterpret_cast<wxPageSetupDialogData*>(self)->GetMarginBottomRight();
+    return wxd_Point{ pt.x, pt.y };
+}
+
+extern "C" void wxd_PageSetupDialogData_SetMarginBottomRight(wxd_PageSetupDialogData_t* self, wxd_Point pt) {
+    reinterpret_cast<wxPageSetupDialogData*>(self)->SetMarginBottomRight(wxPoint(pt.x, pt.y));
+}
+
 // Printout
 extern "C" wxd_Printout_t* wxd_Printout_CreateWithCallbacks(
     const char* title,
@@ -181,6 +317,21 @@ extern "C" bool wxd_Printout_IsPreview(wxd_Printout_t* self) {
     return reinterpret_cast<wxPrintout*>(self)->IsPreview();
 }
 
+extern "C" void wxd_Printout_FitThisSizeToPage(wxd_Printout_t* self, wxd_Size image_size) {
+    reinterpret_cast<wxPrintout*>(self)->FitThisSizeToPage(wxSize(image_size.width, image_size.height));
+}
+
+extern "C" void wxd_Printout_FitThisSizeToPageMargins(wxd_Printout_t* self, wxd_Size image_size, wxd_PageSetupDialogData_t* page_setup) {
+    reinterpret_cast<wxPrintout*>(self)->FitThisSizeToPageMargins(
+        wxSize(image_size.width, image_size.height),
+        *reinterpret_cast<wxPageSetupDialogData*>(page_setup)
+    );
+}
+
+extern "C" void wxd_Printout_MapScreenSizeToPage(wxd_Printout_t* self) {
+    reinterpret_cast<wxPrintout*>(self)->MapScreenSizeToPage();
+}
+
 // Printer
 extern "C" wxd_Printer_t* wxd_Printer_Create(wxd_PrintDialogData_t* data) {
     if (data) {
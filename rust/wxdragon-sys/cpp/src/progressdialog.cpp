@@ -13,7 +13,23 @@ wxd_ProgressDialog_Create(wxd_Window_t* parent, const char* title, const char* m
     wxString wxTitle = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(title);
     wxString wxMessage = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message);
 
-    wxProgressDialog* dialog = new wxProgressDialog(wxTitle, wxMessage, maximum, wxParent, style);
+    // Stored as the generic base so native and generic dialogs share the accessors below
+    wxGenericProgressDialog* dialog =
+        new wxProgressDialog(wxTitle, wxMessage, maximum, wxParent, style);
+
+    return reinterpret_cast<wxd_ProgressDialog_t*>(dialog);
+}
+
+wxd_ProgressDialog_t*
+wxd_ProgressDialog_CreateGeneric(wxd_Window_t* parent, const char* title, const char* message,
+                                 int maximum, wxd_Style_t style)
+{
+    wxWindow* wxParent = reinterpret_cast<wxWindow*>(parent);
+    wxString wxTitle = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(title);
+    wxString wxMessage = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message);
+
+    wxGenericProgressDialog* dialog =
+        new wxGenericProgressDialog(wxTitle, wxMessage, maximum, wxParent, style);
 
     return reinterpret_cast<wxd_ProgressDialog_t*>(dialog);
 }
@@ -21,7 +37,7 @@ wxd_ProgressDialog_Create(wxd_Window_t* parent, const char* title, const char* m
 bool
 wxd_ProgressDialog_Update(wxd_ProgressDialog_t* self, int value, const char* newmsg, bool* skip)
 {
-    wxProgressDialog* dialog = reinterpret_cast<wxProgressDialog*>(self);
+    wxGenericProgressDialog* dialog = reinterpret_cast<wxGenericProgressDialog*>(self);
     wxString wxNewMsg;
     if (newmsg) {
         wxNewMsg = wxString::FromUTF8(newmsg);
@@ -40,7 +56,7 @@ wxd_ProgressDialog_Update(wxd_ProgressDialog_t* self, int value, const char* new
 bool
 wxd_ProgressDialog_Pulse(wxd_ProgressDialog_t* self, const char* newmsg, bool* skip)
 {
-    wxProgressDialog* dialog = reinterpret_cast<wxProgressDialog*>(self);
+    wxGenericProgressDialog* dialog = reinterpret_cast<wxGenericProgressDialog*>(self);
     wxString wxNewMsg;
     if (newmsg) {
         wxNewMsg = wxString::FromUTF8(newmsg);
@@ -59,35 +75,35 @@ wxd_ProgressDialog_Pulse(wxd_ProgressDialog_t* self, const char* newmsg, bool* s
 void
 wxd_ProgressDialog_Resume(wxd_ProgressDialog_t* self)
 {
-    wxProgressDialog* dialog = reinterpret_cast<wxProgressDialog*>(self);
+    wxGenericProgressDialog* dialog = reinterpret_cast<wxGenericProgressDialog*>(self);
     dialog->Resume();
 }
 
 int
 wxd_ProgressDialog_GetValue(wxd_ProgressDialog_t* self)
 {
-    wxProgressDialog* dialog = reinterpret_cast<wxProgressDialog*>(self);
+    wxGenericProgressDialog* dialog = reinterpret_cast<wxGenericProgressDialog*>(self);
     return dialog->GetValue();
 }
 
 int
 wxd_ProgressDialog_GetRange(wxd_ProgressDialog_t* self)
 {
-    wxProgressDialog* dialog = reinterpret_cast<wxProgressDialog*>(self);
+    wxGenericProgressDialog* dialog = reinterpret_cast<wxGenericProgressDialog*>(self);
     return dialog->GetRange();
 }
 
 bool
 wxd_ProgressDialog_WasCancelled(wxd_ProgressDialog_t* self)
 {
-    wxProgressDialog* dialog = reinterpret_cast<wxProgressDialog*>(self);
+    wxGenericProgressDialog* dialog = reinterpret_cast<wxGenericProgressDialog*>(self);
     return dialog->WasCancelled();
 }
 
 bool
 wxd_ProgressDialog_WasSkipped(wxd_ProgressDialog_t* self)
 {
-    wxProgressDialog* dialog = reinterpret_cast<wxProgressDialog*>(self);
+    wxGenericProgressDialog* dialog = reinterpret_cast<wxGenericProgressDialog*>(self);
     return dialog->WasSkipped();
 }
 
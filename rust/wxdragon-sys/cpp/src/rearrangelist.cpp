@@ -29,6 +29,59 @@ CArrayToWxArrayInt(const int* cArray, int arraySize)
     return wxArray;
 }
 
+// wxRearrangeList has no events for the user reordering or checking items that
+// arrive after its order is updated, so wxDragon sends its own.
+wxDEFINE_EVENT(wxdEVT_REARRANGE_LIST, wxCommandEvent);
+wxDEFINE_EVENT(wxdEVT_REARRANGE_LIST_CHECKED, wxCommandEvent);
+
+static bool
+SameOrder(const wxArrayInt& a, const wxArrayInt& b)
+{
+    if (a.GetCount() != b.GetCount())
+        return false;
+    for (size_t i = 0; i < a.GetCount(); ++i) {
+        if (a[i] != b[i])
+            return false;
+    }
+    return true;
+}
+
+static void
+SendRearrangeEvent(wxRearrangeList* list, wxEventType type, int pos)
+{
+    wxCommandEvent event(type, list->GetId());
+    event.SetEventObject(list);
+    event.SetInt(pos);
+    event.SetString(list->GetString(pos));
+    list->HandleWindowEvent(event);
+}
+
+// Bound handlers run before the control's own ones, so the events are sent after them
+static void
+TrackChanges(wxRearrangeCtrl* ctrl)
+{
+    wxRearrangeList* list = ctrl->GetList();
+    ctrl->Bind(wxEVT_BUTTON, [list](wxCommandEvent& event) {
+        event.Skip();
+        if (event.GetId() != wxID_UP && event.GetId() != wxID_DOWN)
+            return;
+        const wxArrayInt order = list->GetCurrentOrder();
+        list->CallAfter([list, order]() {
+            int pos = list->GetSelection();
+            if (pos != wxNOT_FOUND && !SameOrder(list->GetCurrentOrder(), order))
+                SendRearrangeEvent(list, wxdEVT_REARRANGE_LIST, pos);
+        });
+    });
+    list->Bind(wxEVT_CHECKLISTBOX, [list](wxCommandEvent& event) {
+        event.Skip();
+        int pos = event.GetInt();
+        list->CallAfter([list, pos]() {
+            if (pos >= 0 && pos < static_cast<int>(list->GetCount()))
+                SendRearrangeEvent(list, wxdEVT_REARRANGE_LIST_CHECKED, pos);
+        });
+    });
+}
+
 extern "C" {
 
 wxd_RearrangeList_t*
@@ -199,4 +252,39 @@ wxd_RearrangeList_Check(wxd_RearrangeList_t* self, unsigned int index, bool chec
     list->Check(index, check);
 }
 
-} // extern "C"
\ No newline at end of file
+wxd_RearrangeCtrl_t*
+wxd_RearrangeCtrl_Create(wxd_Window_t* parent, wxd_Id id, wxd_Point pos, wxd_Size size,
+                         const int* order, int orderCount, const char** items, int itemsCount,
+                         wxd_Style_t style)
+{
+    wxWindow* wxParent = (wxWindow*)parent;
+    if (!wxParent)
+        return nullptr;
+
+    wxArrayInt wxOrder = CArrayToWxArrayInt(order, orderCount);
+    wxArrayString wxItems;
+    for (int i = 0; i < itemsCount; ++i) {
+        if (items[i]) {
+            wxItems.Add(wxString::FromUTF8(items[i]));
+        }
+    }
+
+    wxRearrangeCtrl* ctrl = new wxRearrangeCtrl(wxParent, id, wxPoint(pos.x, pos.y),
+                                                wxSize(size.width, size.height), wxOrder, wxItems,
+                                                style);
+    TrackChanges(ctrl);
+
+    return (wxd_RearrangeCtrl_t*)ctrl;
+}
+
+wxd_RearrangeList_t*
+wxd_RearrangeCtrl_GetList(wxd_RearrangeCtrl_t* self)
+{
+    wxRearrangeCtrl* ctrl = (wxRearrangeCtrl*)self;
+    if (!ctrl)
+        return nullptr;
+
+    return (wxd_RearrangeList_t*)ctrl->GetList();
+}
+
+} // extern "C"
@@ -0,0 +1,49 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "wxdragon.h"
+#include <wx/richmsgdlg.h>
+
+extern "C" {
+
+wxd_RichMessageDialog_t*
+wxd_RichMessageDialog_Create(wxd_Window_t* parent, const char* message, const char* caption,
+                             int64_t style)
+{
+    wxWindow* wx_parent = (wxWindow*)parent;
+    wxString wx_message = wxString::FromUTF8(message ? message : "");
+    wxString wx_caption = wxString::FromUTF8(caption ? caption : "");
+
+    // wxRichMessageDialog uses the native task dialog where available (Windows)
+    // and falls back to the generic implementation elsewhere.
+    wxRichMessageDialog* dlg = new wxRichMessageDialog(wx_parent, wx_message, wx_caption, style);
+    return (wxd_RichMessageDialog_t*)dlg;
+}
+
+void
+wxd_RichMessageDialog_ShowCheckBox(wxd_RichMessageDialog_t* self, const char* label, bool checked)
+{
+    if (!self)
+        return;
+    ((wxRichMessageDialog*)self)->ShowCheckBox(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(label), checked);
+}
+
+bool
+wxd_RichMessageDialog_IsCheckBoxChecked(wxd_RichMessageDialog_t* self)
+{
+    if (!self)
+        return false;
+    return ((wxRichMessageDialog*)self)->IsCheckBoxChecked();
+}
+
+void
+wxd_RichMessageDialog_ShowDetailedText(wxd_RichMessageDialog_t* self, const char* text)
+{
+    if (!self)
+        return;
+    ((wxRichMessageDialog*)self)->ShowDetailedText(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(text));
+}
+
+// ShowModal is handled by wxd_Dialog_ShowModal((wxd_Dialog*)dlg_ptr)
+// Destroy is handled by wxd_Window_Destroy((wxd_Window_t*)dlg_ptr)
+
+} // extern "C"
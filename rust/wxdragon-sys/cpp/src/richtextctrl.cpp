@@ -5,9 +5,36 @@
 #endif
 
 #include "wx/richtext/richtextctrl.h"
+#include "wx/richtext/richtextxml.h"
+#include "wx/richtext/richtexthtml.h"
 #include "wxdragon.h"
 #include "wxd_utils.h"
 
+// Makes the RichText XML and HTML file types available; wxWidgets only
+// registers the plain text handler itself.
+static void
+EnsureFileHandlers()
+{
+    if (!wxRichTextBuffer::FindHandler(wxRICHTEXT_TYPE_XML))
+        wxRichTextBuffer::AddHandler(new wxRichTextXMLHandler);
+    if (!wxRichTextBuffer::FindHandler(wxRICHTEXT_TYPE_HTML)) {
+        wxRichTextHTMLHandler* html = new wxRichTextHTMLHandler;
+        // Keep saved documents self-contained instead of referring to temporary files
+        html->SetFlags(wxRICHTEXT_HANDLER_SAVE_IMAGES_TO_BASE64);
+        wxRichTextBuffer::AddHandler(html);
+    }
+}
+
+// The selection, or the paragraph holding the caret when nothing is selected
+static wxRichTextRange
+GetParagraphsRange(wxRichTextCtrl* ctrl)
+{
+    if (ctrl->HasSelection())
+        return ctrl->GetSelectionRange();
+    long pos = ctrl->GetAdjustedCaretPosition(ctrl->GetCaretPosition());
+    return wxRichTextRange(pos, pos);
+}
+
 extern "C" {
 
 // Create a new wxRichTextCtrl
@@ -15,6 +42,7 @@ WXD_EXPORTED wxd_RichTextCtrl_t*
 wxd_RichTextCtrl_Create(wxd_Window_t* parent, wxd_Id id, const char* value, wxd_Point pos,
                         wxd_Size size, wxd_Style_t style)
 {
+    EnsureFileHandlers();
     wxWindow* parentWin = (wxWindow*)parent;
     wxRichTextCtrl* ctrl = new wxRichTextCtrl(parentWin, id, wxString::FromUTF8(value ? value : ""),
                                               wxd_cpp_utils::to_wx(pos), wxd_cpp_utils::to_wx(size),
@@ -504,4 +532,102 @@ wxd_RichTextCtrl_SetBackgroundColorSelection(wxd_RichTextCtrl_t* self, wxd_Colou
     return ctrl->SetStyle(wxRichTextRange(from, to), attr);
 }
 
+// Paragraph operations
+
+// Align the selected paragraphs
+WXD_EXPORTED bool
+wxd_RichTextCtrl_ApplyAlignmentToSelection(wxd_RichTextCtrl_t* self, int alignment)
+{
+    wxRichTextCtrl* ctrl = (wxRichTextCtrl*)self;
+    if (!ctrl)
+        return false;
+    return ctrl->ApplyAlignmentToSelection(static_cast<wxTextAttrAlignment>(alignment));
+}
+
+// Check the alignment of the selected paragraphs
+WXD_EXPORTED bool
+wxd_RichTextCtrl_IsSelectionAligned(wxd_RichTextCtrl_t* self, int alignment)
+{
+    wxRichTextCtrl* ctrl = (wxRichTextCtrl*)self;
+    if (!ctrl)
+        return false;
+    return ctrl->IsSelectionAligned(static_cast<wxTextAttrAlignment>(alignment));
+}
+
+// Turn the selected paragraphs into a bulleted list, or back into plain paragraphs
+WXD_EXPORTED bool
+wxd_RichTextCtrl_ApplyBulletsToSelection(wxd_RichTextCtrl_t* self, bool bullets)
+{
+    wxRichTextCtrl* ctrl = (wxRichTextCtrl*)self;
+    if (!ctrl)
+        return false;
+
+    wxRichTextAttr attr;
+    attr.SetFlags(wxTEXT_ATTR_BULLET_STYLE | wxTEXT_ATTR_BULLET_NAME | wxTEXT_ATTR_LEFT_INDENT);
+    if (bullets) {
+        attr.SetBulletStyle(wxTEXT_ATTR_BULLET_STYLE_STANDARD);
+        attr.SetBulletName(wxT("standard/circle"));
+        // In tenths of a millimetre, like the wxRichTextCtrl sample
+        attr.SetLeftIndent(100, 60);
+    }
+    else {
+        attr.SetBulletStyle(wxTEXT_ATTR_BULLET_STYLE_NONE);
+        attr.SetLeftIndent(0, 0);
+    }
+
+    return ctrl->SetStyleEx(GetParagraphsRange(ctrl), attr,
+                            wxRICHTEXT_SETSTYLE_WITH_UNDO | wxRICHTEXT_SETSTYLE_PARAGRAPHS_ONLY);
+}
+
+// Check whether the paragraph holding the caret is a list item
+WXD_EXPORTED bool
+wxd_RichTextCtrl_IsSelectionBulleted(wxd_RichTextCtrl_t* self)
+{
+    wxRichTextCtrl* ctrl = (wxRichTextCtrl*)self;
+    if (!ctrl)
+        return false;
+
+    wxRichTextAttr attr;
+    if (!ctrl->GetStyle(GetParagraphsRange(ctrl).GetStart(), attr))
+        return false;
+    return attr.HasBulletStyle() && attr.GetBulletStyle() != wxTEXT_ATTR_BULLET_STYLE_NONE;
+}
+
+// Content insertion
+
+// Insert an image at the insertion point
+WXD_EXPORTED bool
+wxd_RichTextCtrl_WriteImage(wxd_RichTextCtrl_t* self, const wxd_Bitmap_t* bitmap)
+{
+    wxRichTextCtrl* ctrl = (wxRichTextCtrl*)self;
+    const wxBitmap* bmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!ctrl || !bmp || !bmp->IsOk())
+        return false;
+    // PNG keeps transparency and is what the XML and HTML handlers save
+    return ctrl->WriteImage(*bmp, wxBITMAP_TYPE_PNG);
+}
+
+// Insert a link showing `text` at the insertion point
+WXD_EXPORTED bool
+wxd_RichTextCtrl_WriteUrl(wxd_RichTextCtrl_t* self, const char* url, const char* text)
+{
+    wxRichTextCtrl* ctrl = (wxRichTextCtrl*)self;
+    if (!ctrl || !url)
+        return false;
+
+    wxString wx_url = wxString::FromUTF8(url);
+    wxString wx_text = text && *text ? wxString::FromUTF8(text) : wx_url;
+
+    wxRichTextAttr link_style;
+    link_style.SetTextColour(wxSystemSettings::GetColour(wxSYS_COLOUR_HOTLIGHT));
+    link_style.SetFontUnderlined(true);
+
+    ctrl->BeginStyle(link_style);
+    ctrl->BeginURL(wx_url);
+    ctrl->WriteText(wx_text);
+    ctrl->EndURL();
+    ctrl->EndStyle();
+    return true;
+}
+
 } // extern "C"
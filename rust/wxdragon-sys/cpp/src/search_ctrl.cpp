@@ -91,4 +91,44 @@ wxd_SearchCtrl_GetValue(const wxd_SearchCtrl_t* searchCtrl, char* buffer, size_t
     return actual_len;
 }
 
+WXD_EXPORTED void
+wxd_SearchCtrl_SetDescriptiveText(wxd_SearchCtrl_t* searchCtrl, const char* text)
+{
+    wxSearchCtrl* ctrl = reinterpret_cast<wxSearchCtrl*>(searchCtrl);
+    if (ctrl) {
+        ctrl->SetDescriptiveText(wxString::FromUTF8(text ? text : ""));
+    }
+}
+
+WXD_EXPORTED int
+wxd_SearchCtrl_GetDescriptiveText(const wxd_SearchCtrl_t* searchCtrl, char* buffer,
+                                  size_t buffer_len)
+{
+    const wxSearchCtrl* ctrl = reinterpret_cast<const wxSearchCtrl*>(searchCtrl);
+    if (!ctrl) {
+        return -1;
+    }
+    return (int)wxd_cpp_utils::copy_wxstring_to_buffer(ctrl->GetDescriptiveText(), buffer,
+                                                       buffer_len);
+}
+
+WXD_EXPORTED void
+wxd_SearchCtrl_SetMenu(wxd_SearchCtrl_t* searchCtrl, wxd_Menu_t* menu)
+{
+    wxSearchCtrl* ctrl = reinterpret_cast<wxSearchCtrl*>(searchCtrl);
+    if (ctrl) {
+        ctrl->SetMenu(reinterpret_cast<wxMenu*>(menu));
+    }
+    else {
+        delete reinterpret_cast<wxMenu*>(menu);
+    }
+}
+
+WXD_EXPORTED wxd_Menu_t*
+wxd_SearchCtrl_GetMenu(wxd_SearchCtrl_t* searchCtrl)
+{
+    wxSearchCtrl* ctrl = reinterpret_cast<wxSearchCtrl*>(searchCtrl);
+    return ctrl ? reinterpret_cast<wxd_Menu_t*>(ctrl->GetMenu()) : nullptr;
+}
+
 } // extern "C"
\ No newline at end of file
@@ -1,6 +1,7 @@
 #include <wx/wxprec.h>
 #include <wx/wx.h>
 #include "wxdragon.h"
+#include "wxd_utils.h"
 #include <wx/simplebook.h>
 
 // Implementation for wxd_SimpleBook_Create
@@ -129,4 +130,25 @@ wxd_SimpleBook_InsertPageWithImageId(wxd_SimpleBook_t* self, size_t index, wxd_W
     // wxSimplebook doesn't use images visually, but we support the API for consistency
     // The imageId parameter is ignored as SimpleBook has no visual tabs
     return simplebook->InsertPage(index, pageWin, wxString::FromUTF8(text ? text : ""), select);
-}
\ No newline at end of file
+}
+
+// Implementation for wxd_SimpleBook_SetEffects
+WXD_EXPORTED void
+wxd_SimpleBook_SetEffects(wxd_SimpleBook_t* self, int showEffect, int hideEffect)
+{
+    wxSimplebook* simplebook = reinterpret_cast<wxSimplebook*>(self);
+    if (!simplebook)
+        return;
+    simplebook->SetEffects(wxd_cpp_utils::to_wx_show_effect(showEffect),
+                           wxd_cpp_utils::to_wx_show_effect(hideEffect));
+}
+
+// Implementation for wxd_SimpleBook_SetEffectTimeout
+WXD_EXPORTED void
+wxd_SimpleBook_SetEffectTimeout(wxd_SimpleBook_t* self, unsigned int timeout)
+{
+    wxSimplebook* simplebook = reinterpret_cast<wxSimplebook*>(self);
+    if (!simplebook)
+        return;
+    simplebook->SetEffectTimeout(timeout);
+}
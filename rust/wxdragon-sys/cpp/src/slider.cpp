@@ -76,4 +76,64 @@ wxd_Slider_GetMax(wxd_Slider_t* self)
     if (!slider)
         return 0; // Or error?
     return slider->GetMax();
-}
\ No newline at end of file
+}
+
+// Implementation for wxd_Slider_SetTickFreq
+WXD_EXPORTED void
+wxd_Slider_SetTickFreq(wxd_Slider_t* self, int freq)
+{
+    wxSlider* slider = reinterpret_cast<wxSlider*>(self);
+    if (slider) {
+        slider->SetTickFreq(freq);
+    }
+}
+
+// Implementation for wxd_Slider_GetTickFreq
+WXD_EXPORTED int
+wxd_Slider_GetTickFreq(wxd_Slider_t* self)
+{
+    wxSlider* slider = reinterpret_cast<wxSlider*>(self);
+    if (!slider)
+        return 0;
+    return slider->GetTickFreq();
+}
+
+// Implementation for wxd_Slider_SetPageSize
+WXD_EXPORTED void
+wxd_Slider_SetPageSize(wxd_Slider_t* self, int pageSize)
+{
+    wxSlider* slider = reinterpret_cast<wxSlider*>(self);
+    if (slider) {
+        slider->SetPageSize(pageSize);
+    }
+}
+
+// Implementation for wxd_Slider_GetPageSize
+WXD_EXPORTED int
+wxd_Slider_GetPageSize(wxd_Slider_t* self)
+{
+    wxSlider* slider = reinterpret_cast<wxSlider*>(self);
+    if (!slider)
+        return 0;
+    return slider->GetPageSize();
+}
+
+// Implementation for wxd_Slider_SetLineSize
+WXD_EXPORTED void
+wxd_Slider_SetLineSize(wxd_Slider_t* self, int lineSize)
+{
+    wxSlider* slider = reinterpret_cast<wxSlider*>(self);
+    if (slider) {
+        slider->SetLineSize(lineSize);
+    }
+}
+
+// Implementation for wxd_Slider_GetLineSize
+WXD_EXPORTED int
+wxd_Slider_GetLineSize(wxd_Slider_t* self)
+{
+    wxSlider* slider = reinterpret_cast<wxSlider*>(self);
+    if (!slider)
+        return 0;
+    return slider->GetLineSize();
+}
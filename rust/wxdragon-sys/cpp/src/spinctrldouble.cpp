@@ -5,6 +5,40 @@
 #include <wx/string.h>   // For wxString
 #include <wx/gdicmn.h>   // For wxPoint, wxSize
 #include <wx/window.h>   // For wxWindow parent type
+#include <wx/numformatter.h>
+#include <algorithm>
+
+// Parses a number typed into the control. The locale's decimal separator is
+// accepted, and so is '.' when the locale uses another one.
+static bool
+ParseTypedValue(wxString text, double* value)
+{
+    text.Trim(true).Trim(false);
+    const wxString separator(wxNumberFormatter::GetDecimalSeparator());
+    if (separator != "." && !text.Contains(separator) && text.Freq('.') == 1)
+        text.Replace(".", separator);
+    return wxNumberFormatter::FromString(text, value);
+}
+
+// Applies the typed text when it is committed, clamped into the range, so all
+// platforms take the same values and report a change the same way.
+static void
+CommitTypedValue(wxSpinCtrlDouble* ctrl)
+{
+    // Read the text first, GetValue() may already parse it natively
+    double value;
+    if (!ParseTypedValue(ctrl->GetTextValue(), &value))
+        value = ctrl->GetValue(); // Not a number: show the last value again
+    value = std::min(std::max(value, ctrl->GetMin()), ctrl->GetMax());
+
+    bool changed = value != ctrl->GetValue();
+    ctrl->SetValue(value); // Also reformats the text
+    if (changed) {
+        wxSpinDoubleEvent event(wxEVT_SPINCTRLDOUBLE, ctrl->GetId(), value);
+        event.SetEventObject(ctrl);
+        ctrl->HandleWindowEvent(event);
+    }
+}
 
 WXD_EXPORTED wxd_SpinCtrlDouble_t*
 wxd_SpinCtrlDouble_Create(wxd_Window_t* parent, int id, const char* value_str, int x, int y, int w,
@@ -35,6 +69,15 @@ wxd_SpinCtrlDouble_Create(wxd_Window_t* parent, int id, const char* value_str, i
     // should handle this: if value_wxString is empty, initial_double is used. Otherwise, value_wxString is parsed.
     // We will rely on this wxWidgets behavior.
 
+    ctrl->Bind(wxEVT_KILL_FOCUS, [ctrl](wxFocusEvent& event) {
+        CommitTypedValue(ctrl);
+        event.Skip();
+    });
+    ctrl->Bind(wxEVT_TEXT_ENTER, [ctrl](wxCommandEvent& event) {
+        CommitTypedValue(ctrl);
+        event.Skip();
+    });
+
     return (wxd_SpinCtrlDouble_t*)ctrl;
 }
 
@@ -3,7 +3,57 @@
 #include "wx/statusbr.h"
 #include "wx/window.h" // Base class
 #include "wx/string.h" // For wxString conversions
+#include "wx/weakref.h"
 #include "wxdragon.h"
+#include <map>
+#include <unordered_map>
+#include <vector>
+
+// Widgets kept covering a field of their status bar, by field. The size handler
+// is bound once per status bar; the entry goes when the status bar is destroyed.
+static std::unordered_map<wxStatusBar*, std::map<int, wxWeakRef<wxWindow>>> s_field_widgets;
+
+static void
+PlaceFieldWidgets(wxStatusBar* statusBar)
+{
+    auto widgets = s_field_widgets.find(statusBar);
+    if (widgets == s_field_widgets.end())
+        return;
+    for (auto it = widgets->second.begin(); it != widgets->second.end();) {
+        wxWindow* widget = it->second;
+        if (it->first >= statusBar->GetFieldsCount()) {
+            // The field was removed; its widget would cover nothing
+            if (widget)
+                widget->Hide();
+            it = widgets->second.erase(it);
+            continue;
+        }
+        wxRect rect;
+        if (widget && statusBar->GetFieldRect(it->first, rect)) {
+            // Leave the border of the field visible around the widget
+            rect.Deflate(1);
+            widget->SetSize(rect);
+        }
+        ++it;
+    }
+}
+
+static void
+OnStatusBarWithWidgetsSize(wxSizeEvent& event)
+{
+    event.Skip();
+    // Field rects are up to date here even before wxStatusBar handles the event
+    PlaceFieldWidgets(wxDynamicCast(event.GetEventObject(), wxStatusBar));
+}
+
+static void
+OnStatusBarWithWidgetsDestroy(wxWindowDestroyEvent& event)
+{
+    event.Skip();
+    wxStatusBar* statusBar = wxDynamicCast(event.GetEventObject(), wxStatusBar);
+    if (statusBar)
+        s_field_widgets.erase(statusBar);
+}
 
 extern "C" {
 
@@ -24,6 +74,7 @@ wxd_StatusBar_SetFieldsCount(wxd_StatusBar_t* self, int count)
     if (statusBar) {
         // Need to cast count to unsigned
         statusBar->SetFieldsCount(static_cast<unsigned int>(count));
+        PlaceFieldWidgets(statusBar);
     }
 }
 
@@ -43,6 +94,7 @@ wxd_StatusBar_SetStatusWidths(wxd_StatusBar_t* self, int count, const int* width
     if (statusBar && count > 0 && widths) {
         // wxWidgets takes count and a pointer to int array
         statusBar->SetStatusWidths(count, widths);
+        PlaceFieldWidgets(statusBar);
     }
 }
 
@@ -59,11 +111,95 @@ WXD_EXPORTED void
 wxd_StatusBar_PopStatusText(wxd_StatusBar_t* self, int fieldIndex)
 {
     wxStatusBar* statusBar = (wxStatusBar*)self;
-    if (statusBar) {
+    // Popping a field without pushed text asserts, or worse in release builds
+    if (statusBar && fieldIndex >= 0 && fieldIndex < statusBar->GetFieldsCount() &&
+        !statusBar->GetField(fieldIndex).GetStack().empty()) {
         statusBar->PopStatusText(fieldIndex);
     }
 }
 
+WXD_EXPORTED bool
+wxd_StatusBar_GetFieldRect(wxd_StatusBar_t* self, int fieldIndex, wxd_Rect* rect)
+{
+    wxStatusBar* statusBar = (wxStatusBar*)self;
+    if (!statusBar || !rect || fieldIndex < 0 || fieldIndex >= statusBar->GetFieldsCount())
+        return false;
+    wxRect fieldRect;
+    if (!statusBar->GetFieldRect(fieldIndex, fieldRect))
+        return false;
+    rect->x = fieldRect.x;
+    rect->y = fieldRect.y;
+    rect->width = fieldRect.width;
+    rect->height = fieldRect.height;
+    return true;
+}
+
+static int
+ToWxFieldStyle(int style)
+{
+    switch (style) {
+    case WXD_SB_FLAT:
+        return wxSB_FLAT;
+    case WXD_SB_RAISED:
+        return wxSB_RAISED;
+    case WXD_SB_SUNKEN:
+        return wxSB_SUNKEN;
+    default:
+        return wxSB_NORMAL;
+    }
+}
+
+WXD_EXPORTED void
+wxd_StatusBar_SetStatusStyles(wxd_StatusBar_t* self, int count, const int* styles)
+{
+    wxStatusBar* statusBar = (wxStatusBar*)self;
+    if (!statusBar || count <= 0 || !styles)
+        return;
+    std::vector<int> wxStyles(styles, styles + count);
+    for (int& style : wxStyles)
+        style = ToWxFieldStyle(style);
+    statusBar->SetStatusStyles(count, wxStyles.data());
+}
+
+WXD_EXPORTED void
+wxd_StatusBar_SetMinHeight(wxd_StatusBar_t* self, int height)
+{
+    wxStatusBar* statusBar = (wxStatusBar*)self;
+    if (!statusBar)
+        return;
+    statusBar->SetMinHeight(height);
+    // The frame only takes the new height into account when laying out again
+    wxFrame* frame = wxDynamicCast(statusBar->GetParent(), wxFrame);
+    if (frame)
+        frame->SendSizeEvent();
+}
+
+WXD_EXPORTED bool
+wxd_StatusBar_SetFieldWidget(wxd_StatusBar_t* self, int fieldIndex, wxd_Window_t* widget)
+{
+    wxStatusBar* statusBar = (wxStatusBar*)self;
+    wxWindow* win = (wxWindow*)widget;
+    if (!statusBar || fieldIndex < 0 || fieldIndex >= statusBar->GetFieldsCount())
+        return false;
+    if (win && win->GetParent() != statusBar)
+        return false;
+
+    auto widgets = s_field_widgets.find(statusBar);
+    if (!win) {
+        if (widgets != s_field_widgets.end())
+            widgets->second.erase(fieldIndex);
+        return true;
+    }
+    if (widgets == s_field_widgets.end()) {
+        widgets = s_field_widgets.emplace(statusBar, std::map<int, wxWeakRef<wxWindow>>()).first;
+        statusBar->Bind(wxEVT_SIZE, OnStatusBarWithWidgetsSize);
+        statusBar->Bind(wxEVT_DESTROY, OnStatusBarWithWidgetsDestroy);
+    }
+    widgets->second[fieldIndex] = win;
+    PlaceFieldWidgets(statusBar);
+    return true;
+}
+
 // No wxd_StatusBar_Destroy needed, frame manages lifetime when SetStatusBar is called.
 
 } // extern "C"
\ No newline at end of file
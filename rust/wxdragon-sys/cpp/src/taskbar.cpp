@@ -16,6 +16,12 @@
 #include "wx/osx/private.h"
 #endif
 
+#ifdef __WXMSW__
+// Defined in notificationmessage.cpp
+void
+wxd_NotificationMessage_ForgetTaskBarIcon(wxTaskBarIcon* icon);
+#endif
+
 // Custom TaskBarIcon class that exposes events properly and supports auto popup menus
 class wxdTaskBarIcon : public wxTaskBarIcon {
 public:
@@ -124,6 +130,9 @@ wxd_TaskBarIcon_Destroy(wxd_TaskBarIcon_t* taskbar)
 {
     if (taskbar) {
         wxdTaskBarIcon* wx_taskbar = reinterpret_cast<wxdTaskBarIcon*>(taskbar);
+#ifdef __WXMSW__
+        wxd_NotificationMessage_ForgetTaskBarIcon(wx_taskbar);
+#endif
         delete wx_taskbar;
     }
 }
@@ -0,0 +1,98 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/textentry.h>
+#include <wx/textcompleter.h>
+#include <wx/arrstr.h>
+
+#ifdef __WXOSX__
+// Implemented in window_osx.mm
+bool
+wxdOSXHasMarkedText(wxWindow* window);
+#endif
+
+// wxTextEntryBase rather than wxTextEntry, which the generic wxSearchCtrl doesn't derive from.
+static wxTextEntryBase*
+AsTextEntry(wxd_Window_t* entry)
+{
+    return dynamic_cast<wxTextEntryBase*>(reinterpret_cast<wxWindow*>(entry));
+}
+
+// A wxTextCompleterSimple that asks Rust for the completions of each prefix.
+class WxdRustTextCompleter : public wxTextCompleterSimple {
+public:
+    WxdRustTextCompleter(wxWindow* window, const wxd_TextCompleter_vtable* vtable, void* user_data)
+        : m_window(window), m_vtable(*vtable), m_user_data(user_data)
+    {
+    }
+
+    ~WxdRustTextCompleter() override
+    {
+        if (m_vtable.destroy)
+            m_vtable.destroy(m_user_data);
+    }
+
+    void GetCompletions(const wxString& prefix, wxArrayString& res) override
+    {
+#ifdef __WXOSX__
+        // Offering completions while an input method composes text replaces the
+        // marked text and breaks the composition, so wait until it is committed.
+        if (wxdOSXHasMarkedText(m_window))
+            return;
+#endif
+        if (m_vtable.get_completions) {
+            m_vtable.get_completions(m_user_data, prefix.utf8_str(),
+                                     reinterpret_cast<wxd_ArrayString_t*>(&res));
+        }
+    }
+
+private:
+    wxWindow* m_window;
+    wxd_TextCompleter_vtable m_vtable;
+    void* m_user_data;
+};
+
+extern "C" {
+
+WXD_EXPORTED bool
+wxd_TextEntry_AutoComplete(wxd_Window_t* entry, const wxd_ArrayString_t* choices)
+{
+    wxTextEntryBase* text_entry = AsTextEntry(entry);
+    if (!text_entry || !choices)
+        return false;
+    return text_entry->AutoComplete(*reinterpret_cast<const wxArrayString*>(choices));
+}
+
+WXD_EXPORTED bool
+wxd_TextEntry_AutoCompleteFileNames(wxd_Window_t* entry)
+{
+    wxTextEntryBase* text_entry = AsTextEntry(entry);
+    return text_entry && text_entry->AutoCompleteFileNames();
+}
+
+WXD_EXPORTED bool
+wxd_TextEntry_AutoCompleteDirectories(wxd_Window_t* entry)
+{
+    wxTextEntryBase* text_entry = AsTextEntry(entry);
+    return text_entry && text_entry->AutoCompleteDirectories();
+}
+
+WXD_EXPORTED bool
+wxd_TextEntry_AutoCompleteCustom(wxd_Window_t* entry,
+                                 const wxd_TextCompleter_vtable* vtable,
+                                 void* user_data)
+{
+    if (!vtable)
+        return false;
+    wxTextEntryBase* text_entry = AsTextEntry(entry);
+    if (!text_entry) {
+        if (vtable->destroy)
+            vtable->destroy(user_data);
+        return false;
+    }
+    // AutoComplete takes ownership of the completer, also when it fails
+    return text_entry->AutoComplete(
+        new WxdRustTextCompleter(reinterpret_cast<wxWindow*>(entry), vtable, user_data));
+}
+
+} // extern "C"
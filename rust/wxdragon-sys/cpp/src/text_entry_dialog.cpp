@@ -43,6 +43,28 @@ wxd_TextEntryDialog_GetValue(wxd_TextEntryDialog_t* self, char* buffer, size_t b
     return (int)wxd_cpp_utils::copy_wxstring_to_buffer(val, buffer, bufLen);
 }
 
+WXD_EXPORTED void
+wxd_TextEntryDialog_SetMaxLength(wxd_TextEntryDialog_t* self, unsigned long len)
+{
+    if (!self)
+        return;
+    ((wxTextEntryDialog*)self)->SetMaxLength(len);
+}
+
+WXD_EXPORTED wxd_TextEntryDialog_t*
+wxd_PasswordEntryDialog_Create(wxd_Window_t* parent, const char* message, const char* caption,
+                               wxd_Style_t style, int x, int y)
+{
+    wxPoint pos = (x == -1 && y == -1) ? wxDefaultPosition : wxPoint(x, y);
+    // No default value: a password is never pre-filled. wxPasswordEntryDialog adds
+    // wxTE_PASSWORD itself.
+    wxPasswordEntryDialog* dlg =
+        new wxPasswordEntryDialog((wxWindow*)parent, WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message),
+                                  WXD_STR_TO_WX_STRING_UTF8_NULL_OK(caption), wxEmptyString,
+                                  style, pos);
+    return (wxd_TextEntryDialog_t*)dlg;
+}
+
 // Optional: Setter implementation if uncommented in header
 /*
 WXD_EXPORTED void wxd_TextEntryDialog_SetValue(wxd_TextEntryDialog_t* self, const char* value) {
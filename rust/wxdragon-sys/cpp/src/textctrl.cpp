@@ -5,9 +5,155 @@
 #endif
 
 #include "wx/textctrl.h"
+#include "wx/clipbrd.h"
 #include "wxdragon.h"
 #include "wxd_utils.h"
 
+#include <unordered_map>
+
+// Length limits set with wxd_TextCtrl_SetMaxLength. Ports differ in what a paste
+// over the limit does (some truncate it, some drop all of it), so pastes into
+// limited controls are handled here and always truncated.
+static std::unordered_map<wxTextCtrl*, long> s_max_lengths;
+
+static void
+OnLimitedCtrlDestroy(wxWindowDestroyEvent& event)
+{
+    s_max_lengths.erase(static_cast<wxTextCtrl*>(event.GetEventObject()));
+    event.Skip();
+}
+
+static void
+OnPasteIntoLimitedCtrl(wxClipboardTextEvent& event)
+{
+    wxTextCtrl* ctrl = wxDynamicCast(event.GetEventObject(), wxTextCtrl);
+    auto limit = ctrl ? s_max_lengths.find(ctrl) : s_max_lengths.end();
+    if (limit == s_max_lengths.end()) {
+        event.Skip();
+        return;
+    }
+
+    wxString text;
+    if (wxTheClipboard->Open()) {
+        wxTextDataObject data;
+        if (wxTheClipboard->GetData(data)) {
+            text = data.GetText();
+        }
+        wxTheClipboard->Close();
+    }
+
+    long from, to;
+    ctrl->GetSelection(&from, &to);
+    long selected = static_cast<long>(ctrl->GetRange(from, to).length());
+    long room = limit->second - (static_cast<long>(ctrl->GetValue().length()) - selected);
+    if (static_cast<long>(text.length()) <= room) {
+        // Fits, let the control paste it as usual
+        event.Skip();
+        return;
+    }
+
+    if (room > 0) {
+        ctrl->Replace(from, to, text.Left(room));
+    }
+    wxCommandEvent maxlen(wxEVT_TEXT_MAXLEN, ctrl->GetId());
+    maxlen.SetEventObject(ctrl);
+    maxlen.SetString(ctrl->GetValue());
+    ctrl->HandleWindowEvent(maxlen);
+}
+
+// wxWidgets has no event for the caret or selection moving, so wxDragon sends its
+// own after input that may have moved them, and only if they actually did.
+wxDEFINE_EVENT(wxdEVT_TEXT_CARET_OR_SELECTION_CHANGED, wxCommandEvent);
+
+struct CaretState {
+    long insertion_point;
+    long from;
+    long to;
+    bool check_pending;
+};
+
+static std::unordered_map<wxTextCtrl*, CaretState> s_caret_states;
+
+static void
+CheckCaretState(wxTextCtrl* ctrl)
+{
+    auto state = s_caret_states.find(ctrl);
+    if (state == s_caret_states.end())
+        return;
+    state->second.check_pending = false;
+
+    long from, to;
+    ctrl->GetSelection(&from, &to);
+    long insertion_point = ctrl->GetInsertionPoint();
+    const CaretState& last = state->second;
+    if (insertion_point == last.insertion_point && from == last.from && to == last.to)
+        return;
+    state->second = { insertion_point, from, to, false };
+
+    wxCommandEvent event(wxdEVT_TEXT_CARET_OR_SELECTION_CHANGED, ctrl->GetId());
+    event.SetEventObject(ctrl);
+    event.SetInt(static_cast<int>(insertion_point));
+    ctrl->HandleWindowEvent(event);
+}
+
+// The native control may only move the caret once the event has been handled,
+// so the check runs after that, at most once per batch of events.
+static void
+ScheduleCaretCheck(wxEvent& event)
+{
+    event.Skip();
+    wxTextCtrl* ctrl = wxDynamicCast(event.GetEventObject(), wxTextCtrl);
+    auto state = ctrl ? s_caret_states.find(ctrl) : s_caret_states.end();
+    if (state == s_caret_states.end() || state->second.check_pending)
+        return;
+    state->second.check_pending = true;
+    ctrl->CallAfter([ctrl]() { CheckCaretState(ctrl); });
+}
+
+static void
+OnCaretMouseMotion(wxMouseEvent& event)
+{
+    // Only a drag extends the selection
+    if (event.LeftIsDown())
+        ScheduleCaretCheck(event);
+    else
+        event.Skip();
+}
+
+// Catches what input events don't, such as programmatic changes and text
+// committed by an input method, whenever idle events reach the control
+static void
+OnCaretIdle(wxIdleEvent& event)
+{
+    event.Skip();
+    if (wxTextCtrl* ctrl = wxDynamicCast(event.GetEventObject(), wxTextCtrl))
+        CheckCaretState(ctrl);
+}
+
+static void
+OnCaretTrackedCtrlDestroy(wxWindowDestroyEvent& event)
+{
+    s_caret_states.erase(static_cast<wxTextCtrl*>(event.GetEventObject()));
+    event.Skip();
+}
+
+static void
+TrackCaret(wxTextCtrl* ctrl)
+{
+    if (s_caret_states.count(ctrl))
+        return;
+    long from, to;
+    ctrl->GetSelection(&from, &to);
+    s_caret_states[ctrl] = { ctrl->GetInsertionPoint(), from, to, false };
+
+    ctrl->Bind(wxEVT_KEY_UP, &ScheduleCaretCheck);
+    ctrl->Bind(wxEVT_LEFT_UP, &ScheduleCaretCheck);
+    ctrl->Bind(wxEVT_MOTION, &OnCaretMouseMotion);
+    ctrl->Bind(wxEVT_TEXT, &ScheduleCaretCheck);
+    ctrl->Bind(wxEVT_IDLE, &OnCaretIdle);
+    ctrl->Bind(wxEVT_DESTROY, &OnCaretTrackedCtrlDestroy);
+}
+
 extern "C" {
 
 // Create a new wxTextCtrl
@@ -18,9 +164,35 @@ wxd_TextCtrl_Create(wxd_Window_t* parent, wxd_Id id, const char* value, wxd_Poin
     wxWindow* parentWin = (wxWindow*)parent;
     wxTextCtrl* ctrl = new wxTextCtrl(parentWin, id, wxString::FromUTF8(value ? value : ""),
                                       wxd_cpp_utils::to_wx(pos), wxd_cpp_utils::to_wx(size), style);
+    TrackCaret(ctrl);
     return (wxd_TextCtrl_t*)ctrl;
 }
 
+// Send caret or selection changed events for a control not created by wxd_TextCtrl_Create
+WXD_EXPORTED void
+wxd_TextCtrl_TrackCaret(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        TrackCaret(ctrl);
+    }
+}
+
+// Map a point in client coordinates to a character position
+WXD_EXPORTED bool
+wxd_TextCtrl_HitTest(wxd_TextCtrl_t* textCtrl, wxd_Point point, wxd_Long_t* pos)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (!ctrl || !pos)
+        return false;
+    long wx_pos = 0;
+    // Points before or past the text map to its nearest position
+    if (ctrl->HitTest(wxd_cpp_utils::to_wx(point), &wx_pos) == wxTE_HT_UNKNOWN)
+        return false;
+    *pos = static_cast<wxd_Long_t>(wx_pos);
+    return true;
+}
+
 // Set the value of the wxTextCtrl
 WXD_EXPORTED void
 wxd_TextCtrl_SetValue(wxd_TextCtrl_t* textCtrl, const char* value)
@@ -48,7 +220,13 @@ wxd_TextCtrl_AppendText(wxd_TextCtrl_t* textCtrl, const char* text)
 {
     wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
     if (ctrl && text) {
+        // Not every port applies the default style to appended text, so apply it here
+        const wxTextAttr& style = ctrl->GetDefaultStyle();
+        long start = ctrl->GetLastPosition();
         ctrl->AppendText(wxString::FromUTF8(text));
+        if (!style.IsDefault() && ctrl->IsMultiLine()) {
+            ctrl->SetStyle(start, ctrl->GetLastPosition(), style);
+        }
     }
 }
 
@@ -136,6 +314,116 @@ wxd_TextCtrl_SetModified(wxd_TextCtrl_t* textCtrl, bool modified)
     }
 }
 
+// Mark the wxTextCtrl as modified
+WXD_EXPORTED void
+wxd_TextCtrl_MarkDirty(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        ctrl->MarkDirty();
+    }
+}
+
+// Mark the wxTextCtrl as unmodified
+WXD_EXPORTED void
+wxd_TextCtrl_DiscardEdits(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        ctrl->DiscardEdits();
+    }
+}
+
+// Load a file into the wxTextCtrl, clearing the modified state
+WXD_EXPORTED bool
+wxd_TextCtrl_LoadFile(wxd_TextCtrl_t* textCtrl, const char* path)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (!ctrl || !path)
+        return false;
+    return ctrl->LoadFile(wxString::FromUTF8(path));
+}
+
+// Save the contents of the wxTextCtrl, clearing the modified state on success
+WXD_EXPORTED bool
+wxd_TextCtrl_SaveFile(wxd_TextCtrl_t* textCtrl, const char* path)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (!ctrl || !path)
+        return false;
+    return ctrl->SaveFile(wxString::FromUTF8(path));
+}
+
+// Check if the last edit can be undone
+WXD_EXPORTED bool
+wxd_TextCtrl_CanUndo(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (!ctrl)
+        return false;
+    return ctrl->CanUndo();
+}
+
+// Undo the last edit
+WXD_EXPORTED void
+wxd_TextCtrl_Undo(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        ctrl->Undo();
+    }
+}
+
+// Check if the last undone edit can be redone
+WXD_EXPORTED bool
+wxd_TextCtrl_CanRedo(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (!ctrl)
+        return false;
+    return ctrl->CanRedo();
+}
+
+// Redo the last undone edit
+WXD_EXPORTED void
+wxd_TextCtrl_Redo(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        ctrl->Redo();
+    }
+}
+
+// Cut the selection to the clipboard
+WXD_EXPORTED void
+wxd_TextCtrl_Cut(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        ctrl->Cut();
+    }
+}
+
+// Copy the selection to the clipboard
+WXD_EXPORTED void
+wxd_TextCtrl_Copy(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        ctrl->Copy();
+    }
+}
+
+// Paste the clipboard text
+WXD_EXPORTED void
+wxd_TextCtrl_Paste(wxd_TextCtrl_t* textCtrl)
+{
+    wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
+    if (ctrl) {
+        ctrl->Paste();
+    }
+}
+
 // Make the wxTextCtrl editable or read-only
 WXD_EXPORTED void
 wxd_TextCtrl_SetEditable(wxd_TextCtrl_t* textCtrl, bool editable)
@@ -183,6 +471,20 @@ wxd_TextCtrl_SetMaxLength(wxd_TextCtrl_t* textCtrl, wxd_Long_t len)
     wxTextCtrl* ctrl = (wxTextCtrl*)textCtrl;
     if (ctrl) {
         ctrl->SetMaxLength(len);
+
+        bool was_limited = s_max_lengths.count(ctrl) > 0;
+        if (len > 0) {
+            s_max_lengths[ctrl] = static_cast<long>(len);
+            if (!was_limited) {
+                ctrl->Bind(wxEVT_TEXT_PASTE, &OnPasteIntoLimitedCtrl);
+                ctrl->Bind(wxEVT_DESTROY, &OnLimitedCtrlDestroy);
+            }
+        }
+        else if (was_limited) {
+            s_max_lengths.erase(ctrl);
+            ctrl->Unbind(wxEVT_TEXT_PASTE, &OnPasteIntoLimitedCtrl);
+            ctrl->Unbind(wxEVT_DESTROY, &OnLimitedCtrlDestroy);
+        }
     }
 }
 
@@ -423,6 +725,16 @@ wxd_TextCtrl_SetStyle(wxd_TextCtrl_t* textCtrl, wxd_Long_t start, wxd_Long_t end
     }
 }
 
+WXD_EXPORTED wxd_TextAttr_t*
+wxd_TextCtrl_GetStyle(wxd_TextCtrl_t* textCtrl, wxd_Long_t position)
+{
+    wxTextCtrl* ctrl = reinterpret_cast<wxTextCtrl*>(textCtrl);
+    if (!ctrl) return nullptr;
+    wxTextAttr style;
+    if (!ctrl->GetStyle(static_cast<long>(position), style)) return nullptr;
+    return reinterpret_cast<wxd_TextAttr_t*>(new wxTextAttr(style));
+}
+
 WXD_EXPORTED wxd_TextAttr_t*
 wxd_TextCtrl_GetDefaultStyle(wxd_TextCtrl_t* textCtrl)
 {
@@ -456,7 +768,7 @@ WXD_EXPORTED wxd_Long_t
 wxd_TextCtrl_XYToPosition(wxd_TextCtrl_t* textCtrl, wxd_Long_t x, wxd_Long_t y)
 {
     wxTextCtrl* ctrl = reinterpret_cast<wxTextCtrl*>(textCtrl);
-    if (!ctrl) return 0;
+    if (!ctrl) return -1;
     return static_cast<wxd_Long_t>(ctrl->XYToPosition(static_cast<long>(x), static_cast<long>(y)));
 }
 
@@ -464,7 +776,7 @@ WXD_EXPORTED int
 wxd_TextCtrl_GetLineLength(wxd_TextCtrl_t* textCtrl, wxd_Long_t lineNo)
 {
     wxTextCtrl* ctrl = reinterpret_cast<wxTextCtrl*>(textCtrl);
-    if (!ctrl) return 0;
+    if (!ctrl) return -1;
     return ctrl->GetLineLength(static_cast<long>(lineNo));
 }
 
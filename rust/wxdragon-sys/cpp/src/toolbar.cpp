@@ -3,26 +3,33 @@
 #include "../include/wxdragon.h"
 #include <wx/toolbar.h>
 #include <wx/bmpbndl.h>
+#include <unordered_map>
+#include <unordered_set>
 
-// Helper to create a wxBitmapBundle from optional wxd_Bitmap_t pointers
-wxBitmapBundle
-CreateBundle(const wxd_Bitmap_t* bmpNormal, const wxd_Bitmap_t* bmpDisabled)
-{
-    const wxBitmap* normalPtr = reinterpret_cast<const wxBitmap*>(bmpNormal);
-    // const wxBitmap* disabledPtr = reinterpret_cast<const wxBitmap*>(bmpDisabled);
+// Tools removed from a toolbar, which owns them until they are inserted again or
+// destroyed. They are deleted when the toolbar is, before its children, as a
+// tool's destructor destroys its control.
+static std::unordered_map<wxToolBar*, std::unordered_set<wxToolBarToolBase*>> s_removed_tools;
 
-    if (!normalPtr || !normalPtr->IsOk()) {
-        // Must have at least a normal bitmap
-        return wxBitmapBundle(); // Return invalid bundle
-    }
-    // wxToolBar::AddTool takes bundle by value, expect copy/ref-counting
-    return wxBitmapBundle::FromBitmap(*normalPtr);
+static void
+OnToolBarWithRemovedToolsDestroy(wxWindowDestroyEvent& event)
+{
+    event.Skip();
+    wxToolBar* tb = wxDynamicCast(event.GetEventObject(), wxToolBar);
+    auto removed = tb ? s_removed_tools.find(tb) : s_removed_tools.end();
+    if (removed == s_removed_tools.end())
+        return;
+    for (wxToolBarToolBase* tool : removed->second)
+        delete tool;
+    s_removed_tools.erase(removed);
+}
 
-    // TODO: wxWidgets allows multiple bitmaps in a bundle for different states (hover, pressed)
-    // and different resolutions. For now, just use the normal one.
-    // If disabledPtr is valid, we could potentially add it, but wxBitmapBundle::FromBitmaps
-    // isn't standard, and AddTool might handle wxNullBitmap for disabled state implicitly.
-    // For now, returning bundle from just the normal bitmap is simplest.
+// Takes `tool` out of the removed tools of `tb`; false if it isn't one of them
+static bool
+TakeRemovedTool(wxToolBar* tb, wxToolBarToolBase* tool)
+{
+    auto removed = s_removed_tools.find(tb);
+    return removed != s_removed_tools.end() && removed->second.erase(tool) > 0;
 }
 
 WXD_EXPORTED wxd_ToolBar_t*
@@ -40,28 +47,27 @@ wxd_ToolBar_Create(wxd_Window_t* parent, wxd_Id id, wxd_Point pos, wxd_Size size
 
 WXD_EXPORTED void*
 wxd_ToolBar_AddTool(wxd_ToolBar_t* self, wxd_Id toolId, const char* label,
-                    const wxd_Bitmap_t* bitmap, const wxd_Bitmap_t* bitmapDisabled, int kind,
+                    const wxd_BitmapBundle_t* bitmap, const wxd_BitmapBundle_t* bitmapDisabled, int kind,
                     const char* shortHelp, const char* longHelp)
 {
     wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
     if (!tb)
         return nullptr;
 
+    const wxBitmapBundle* bundle = reinterpret_cast<const wxBitmapBundle*>(bitmap);
+    if (!bundle || !bundle->IsOk()) {
+        WXD_LOG_ERROR("wxd_ToolBar_AddTool: Invalid bitmap provided.");
+        return nullptr;
+    }
+    const wxBitmapBundle* disabled = reinterpret_cast<const wxBitmapBundle*>(bitmapDisabled);
+
     wxString wxLabel = wxString::FromUTF8(label ? label : "");
     wxString wxShortHelp = wxString::FromUTF8(shortHelp ? shortHelp : "");
     wxString wxLongHelp = wxString::FromUTF8(longHelp ? longHelp : "");
     wxItemKind wxKind = static_cast<wxItemKind>(kind);
 
-    wxBitmapBundle bundle = CreateBundle(bitmap, bitmapDisabled);
-    if (!bundle.IsOk()) {
-        WXD_LOG_ERROR("wxd_ToolBar_AddTool: Invalid bitmap provided.");
-        return nullptr;
-    }
-
-    wxToolBarToolBase* tool = tb->AddTool(toolId, wxLabel, bundle, wxShortHelp, wxKind);
-    if (tool && !wxLongHelp.IsEmpty()) {
-        tb->SetToolLongHelp(toolId, wxLongHelp);
-    }
+    wxToolBarToolBase* tool = tb->AddTool(toolId, wxLabel, *bundle, disabled ? *disabled : wxBitmapBundle(), wxKind,
+                                          wxShortHelp, wxLongHelp);
     // Return the tool pointer, though it's opaque for now in C API.
     return reinterpret_cast<void*>(tool);
 }
@@ -75,6 +81,15 @@ wxd_ToolBar_AddSeparator(wxd_ToolBar_t* self)
     tb->AddSeparator();
 }
 
+WXD_EXPORTED void
+wxd_ToolBar_AddStretchableSpace(wxd_ToolBar_t* self)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb)
+        return;
+    tb->AddStretchableSpace();
+}
+
 WXD_EXPORTED void
 wxd_ToolBar_AddControl(wxd_ToolBar_t* self, wxd_Window_t* control)
 {
@@ -185,4 +200,156 @@ wxd_ToolBar_AddToolWithBundles(wxd_ToolBar_t* toolbar, wxd_Id id, const char* la
                                           wxITEM_NORMAL, wx_shortHelp, wx_longHelp);
 
     return tool != nullptr;
-}
\ No newline at end of file
+}
+
+WXD_EXPORTED bool
+wxd_ToolBar_SetDropdownMenu(wxd_ToolBar_t* self, wxd_Id toolId, wxd_Menu_t* menu)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    wxMenu* wxMenuPtr = reinterpret_cast<wxMenu*>(menu);
+    if (!wxMenuPtr)
+        return false;
+    // The toolbar only takes the menu for a dropdown tool; otherwise it is ours to delete
+    if (!tb || !tb->SetDropdownMenu(toolId, wxMenuPtr)) {
+        delete wxMenuPtr;
+        return false;
+    }
+    return true;
+}
+
+WXD_EXPORTED void
+wxd_ToolBar_SetToolBitmapSize(wxd_ToolBar_t* self, wxd_Size size)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb)
+        return;
+    tb->SetToolBitmapSize(wxSize(size.width, size.height));
+}
+
+WXD_EXPORTED wxd_Size
+wxd_ToolBar_GetToolBitmapSize(wxd_ToolBar_t* self)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb)
+        return wxd_Size{ -1, -1 };
+    wxSize size = tb->GetToolBitmapSize();
+    return wxd_Size{ size.x, size.y };
+}
+
+WXD_EXPORTED bool
+wxd_ToolBar_InsertTool(wxd_ToolBar_t* self, size_t pos, wxd_Id toolId, const char* label,
+                       const wxd_BitmapBundle_t* bitmap, const wxd_BitmapBundle_t* bitmapDisabled,
+                       int kind, const char* shortHelp, const char* longHelp)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    const wxBitmapBundle* bundle = reinterpret_cast<const wxBitmapBundle*>(bitmap);
+    if (!tb || pos > tb->GetToolsCount() || !bundle || !bundle->IsOk())
+        return false;
+    const wxBitmapBundle* disabled = reinterpret_cast<const wxBitmapBundle*>(bitmapDisabled);
+
+    wxToolBarToolBase* tool =
+        tb->InsertTool(pos, toolId, wxString::FromUTF8(label ? label : ""), *bundle,
+                       disabled ? *disabled : wxBitmapBundle(), static_cast<wxItemKind>(kind),
+                       wxString::FromUTF8(shortHelp ? shortHelp : ""),
+                       wxString::FromUTF8(longHelp ? longHelp : ""));
+    return tool != nullptr;
+}
+
+WXD_EXPORTED bool
+wxd_ToolBar_InsertSeparator(wxd_ToolBar_t* self, size_t pos)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb || pos > tb->GetToolsCount())
+        return false;
+    return tb->InsertSeparator(pos) != nullptr;
+}
+
+WXD_EXPORTED wxd_ToolBarTool_t*
+wxd_ToolBar_RemoveTool(wxd_ToolBar_t* self, wxd_Id toolId)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb)
+        return nullptr;
+    wxToolBarToolBase* tool = tb->RemoveTool(toolId);
+    if (!tool)
+        return nullptr;
+    auto removed = s_removed_tools.find(tb);
+    if (removed == s_removed_tools.end()) {
+        tb->Bind(wxEVT_DESTROY, &OnToolBarWithRemovedToolsDestroy);
+        removed = s_removed_tools.emplace(tb, std::unordered_set<wxToolBarToolBase*>()).first;
+    }
+    removed->second.insert(tool);
+    return reinterpret_cast<wxd_ToolBarTool_t*>(tool);
+}
+
+WXD_EXPORTED bool
+wxd_ToolBar_InsertRemovedTool(wxd_ToolBar_t* self, size_t pos, wxd_ToolBarTool_t* tool)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    wxToolBarToolBase* wxTool = reinterpret_cast<wxToolBarToolBase*>(tool);
+    if (!tb || !wxTool || pos > tb->GetToolsCount() || !TakeRemovedTool(tb, wxTool))
+        return false;
+    if (tb->InsertTool(pos, wxTool))
+        return true;
+    s_removed_tools[tb].insert(wxTool);
+    return false;
+}
+
+WXD_EXPORTED void
+wxd_ToolBar_DestroyRemovedTool(wxd_ToolBar_t* self, wxd_ToolBarTool_t* tool)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    wxToolBarToolBase* wxTool = reinterpret_cast<wxToolBarToolBase*>(tool);
+    if (tb && wxTool && TakeRemovedTool(tb, wxTool))
+        delete wxTool;
+}
+
+WXD_EXPORTED wxd_Id
+wxd_ToolBarTool_GetId(const wxd_ToolBarTool_t* tool)
+{
+    const wxToolBarToolBase* wxTool = reinterpret_cast<const wxToolBarToolBase*>(tool);
+    return wxTool ? wxTool->GetId() : wxID_NONE;
+}
+
+WXD_EXPORTED bool
+wxd_ToolBar_DeleteTool(wxd_ToolBar_t* self, wxd_Id toolId)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb)
+        return false;
+    return tb->DeleteTool(toolId);
+}
+
+WXD_EXPORTED bool
+wxd_ToolBar_DeleteToolByPos(wxd_ToolBar_t* self, size_t pos)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb || pos >= tb->GetToolsCount())
+        return false;
+    return tb->DeleteToolByPos(pos);
+}
+
+WXD_EXPORTED size_t
+wxd_ToolBar_GetToolsCount(wxd_ToolBar_t* self)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb)
+        return 0;
+    return tb->GetToolsCount();
+}
+
+WXD_EXPORTED bool
+wxd_ToolBar_HasTool(wxd_ToolBar_t* self, wxd_Id toolId)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    return tb && tb->FindById(toolId) != nullptr;
+}
+
+WXD_EXPORTED int
+wxd_ToolBar_GetToolPos(wxd_ToolBar_t* self, wxd_Id toolId)
+{
+    wxToolBar* tb = reinterpret_cast<wxToolBar*>(self);
+    if (!tb)
+        return wxNOT_FOUND;
+    return tb->GetToolPos(toolId);
+}
@@ -64,10 +64,38 @@ wxd_Treebook_AddSubPage(wxd_Treebook_t* self, wxd_Window_t* page, const char* te
         ->AddSubPage((wxWindow*)page, wxString::FromUTF8(text ? text : ""), (bool)bSelect, imageId);
 }
 
+// Wrapper for wxTreebook::InsertPage(size_t, wxWindow*, wxString const&, bool, int)
+// Inserts a top-level page, or a sibling of the page at pos if that is a sub-page
+WXD_EXPORTED int
+wxd_Treebook_InsertPage(wxd_Treebook_t* self, size_t pos, wxd_Window_t* page, const char* text,
+                        int bSelect, int imageId)
+{
+    if (!self || !page || pos > ((wxTreebook*)self)->GetPageCount())
+        return 0;
+    return ((wxTreebook*)self)
+        ->InsertPage(pos, (wxWindow*)page, wxString::FromUTF8(text ? text : ""), (bool)bSelect,
+                     imageId);
+}
+
+// Wrapper for wxTreebook::InsertSubPage(size_t, wxWindow*, wxString const&, bool, int)
+// Inserts the page as the last child of the page at pos
+WXD_EXPORTED int
+wxd_Treebook_InsertSubPage(wxd_Treebook_t* self, size_t pos, wxd_Window_t* page, const char* text,
+                           int bSelect, int imageId)
+{
+    if (!self || !page || pos >= ((wxTreebook*)self)->GetPageCount())
+        return 0;
+    return ((wxTreebook*)self)
+        ->InsertSubPage(pos, (wxWindow*)page, wxString::FromUTF8(text ? text : ""), (bool)bSelect,
+                        imageId);
+}
+
 // Wrapper for wxBookCtrlBase::GetPageCount()
 WXD_EXPORTED int
 wxd_Treebook_GetPageCount(wxd_Treebook_t* self)
 {
+    if (!self)
+        return 0;
     return ((wxTreebook*)self)->GetPageCount();
 }
 
@@ -75,6 +103,8 @@ wxd_Treebook_GetPageCount(wxd_Treebook_t* self)
 WXD_EXPORTED wxd_Window_t*
 wxd_Treebook_GetPage(wxd_Treebook_t* self, size_t n)
 {
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return nullptr;
     return (wxd_Window_t*)((wxTreebook*)self)->GetPage(n);
 }
 
@@ -82,6 +112,8 @@ wxd_Treebook_GetPage(wxd_Treebook_t* self, size_t n)
 WXD_EXPORTED int
 wxd_Treebook_GetSelection(wxd_Treebook_t* self)
 {
+    if (!self)
+        return wxNOT_FOUND;
     return ((wxTreebook*)self)->GetSelection();
 }
 
@@ -89,14 +121,90 @@ wxd_Treebook_GetSelection(wxd_Treebook_t* self)
 WXD_EXPORTED int
 wxd_Treebook_SetSelection(wxd_Treebook_t* self, size_t n)
 {
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return wxNOT_FOUND;
     return ((wxTreebook*)self)->SetSelection(n);
 }
 
+// Wrapper for wxBookCtrlBase::ChangeSelection(size_t), which sends no events
+WXD_EXPORTED int
+wxd_Treebook_ChangeSelection(wxd_Treebook_t* self, size_t n)
+{
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return wxNOT_FOUND;
+    return ((wxTreebook*)self)->ChangeSelection(n);
+}
+
+// Wrapper for wxTreebook::DeletePage(size_t), which deletes the sub-pages too
+WXD_EXPORTED bool
+wxd_Treebook_DeletePage(wxd_Treebook_t* self, size_t n)
+{
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return false;
+    return ((wxTreebook*)self)->DeletePage(n);
+}
+
+// Wrapper for wxTreebook::ExpandNode(size_t, bool)
+WXD_EXPORTED bool
+wxd_Treebook_ExpandNode(wxd_Treebook_t* self, size_t n, bool expand)
+{
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return false;
+    return ((wxTreebook*)self)->ExpandNode(n, expand);
+}
+
+// Wrapper for wxTreebook::IsNodeExpanded(size_t)
+WXD_EXPORTED bool
+wxd_Treebook_IsNodeExpanded(wxd_Treebook_t* self, size_t n)
+{
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return false;
+    return ((wxTreebook*)self)->IsNodeExpanded(n);
+}
+
+// Wrapper for wxTreebook::GetPageParent(size_t)
+WXD_EXPORTED int
+wxd_Treebook_GetPageParent(wxd_Treebook_t* self, size_t n)
+{
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return wxNOT_FOUND;
+    return ((wxTreebook*)self)->GetPageParent(n);
+}
+
+// Wrapper for wxTreebook::GetTreeCtrl()
+WXD_EXPORTED wxd_Window_t*
+wxd_Treebook_GetTreeCtrl(wxd_Treebook_t* self)
+{
+    if (!self)
+        return nullptr;
+    return (wxd_Window_t*)((wxTreebook*)self)->GetTreeCtrl();
+}
+
+// Wrapper for wxBookCtrlBase::AssignImageList(wxImageList*), the treebook takes ownership
+WXD_EXPORTED void
+wxd_Treebook_AssignImageList(wxd_Treebook_t* self, wxd_ImageList_t* imageList)
+{
+    if (!self)
+        return;
+    ((wxTreebook*)self)->AssignImageList((wxImageList*)imageList);
+}
+
+// Wrapper for wxBookCtrlBase::SetPageImage(size_t, int)
+WXD_EXPORTED bool
+wxd_Treebook_SetPageImage(wxd_Treebook_t* self, size_t n, int imageId)
+{
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return false;
+    return ((wxTreebook*)self)->SetPageImage(n, imageId);
+}
+
 // Wrapper for wxBookCtrlBase::SetPageText(size_t, wxString const&)
 WXD_EXPORTED void
 wxd_Treebook_SetPageText(wxd_Treebook_t* self, size_t n, const char* strText)
 {
-    ((wxTreebook*)self)->SetPageText(n, wxString(strText));
+    if (!self || n >= ((wxTreebook*)self)->GetPageCount())
+        return;
+    ((wxTreebook*)self)->SetPageText(n, wxString::FromUTF8(strText ? strText : ""));
 }
 
 // Wrapper for wxBookCtrlBase::GetPageText(size_t)
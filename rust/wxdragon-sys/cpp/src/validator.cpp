@@ -0,0 +1,208 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/validate.h>
+#include <wx/textentry.h>
+#include <wx/spinctrl.h>
+#include <wx/bookctrl.h>
+#include <wx/regex.h>
+#include <wx/log.h>
+#include <memory>
+
+// The Rust side of a validator, shared by the validator set on a window and
+// the copies wxWidgets makes of it.
+struct WxdValidatorCallbacks {
+    WxdValidatorCallbacks(const wxd_Validator_vtable* vtable, void* user_data)
+        : vtable(*vtable), user_data(user_data)
+    {
+    }
+
+    ~WxdValidatorCallbacks()
+    {
+        if (vtable.destroy)
+            vtable.destroy(user_data);
+    }
+
+    wxd_Validator_vtable vtable;
+    void* user_data;
+};
+
+static void
+SetValidatorMessage(void* sink, const char* message)
+{
+    *static_cast<wxString*>(sink) = wxString::FromUTF8(message ? message : "");
+}
+
+// The text the user entered into a validated control.
+static wxString
+GetValidatedValue(wxWindow* window)
+{
+    // wxTextEntryBase also covers the generic wxSearchCtrl, which isn't a wxTextEntry
+    if (wxTextEntryBase* entry = dynamic_cast<wxTextEntryBase*>(window))
+        return entry->GetValue();
+    if (wxSpinCtrlBase* spin = dynamic_cast<wxSpinCtrlBase*>(window))
+        return spin->GetTextValue();
+    return window->GetLabel();
+}
+
+// Selects the book pages containing `window`, so it can be seen and focused.
+static void
+RevealWindow(wxWindow* window)
+{
+    for (wxWindow* page = window; page && !page->IsTopLevel(); page = page->GetParent()) {
+        wxBookCtrlBase* book = wxDynamicCast(page->GetParent(), wxBookCtrlBase);
+        if (!book)
+            continue;
+        int index = book->FindPage(page);
+        if (index != wxNOT_FOUND && index != book->GetSelection())
+            book->SetSelection(index);
+    }
+}
+
+// A wxValidator whose checks and key filter are implemented in Rust.
+class WxdRustValidator : public wxValidator {
+public:
+    explicit WxdRustValidator(std::shared_ptr<WxdValidatorCallbacks> callbacks)
+        : m_callbacks(std::move(callbacks))
+    {
+        Bind(wxEVT_CHAR, &WxdRustValidator::OnChar, this);
+    }
+
+    wxObject* Clone() const override
+    {
+        return new WxdRustValidator(m_callbacks);
+    }
+
+    bool Validate(wxWindow* parent) override
+    {
+        wxWindow* window = GetWindow();
+        // Like the wxWidgets validators, a disabled control is never in the way
+        if (!window || !window->IsEnabled() || !m_callbacks->vtable.validate)
+            return true;
+
+        wxString message;
+        if (m_callbacks->vtable.validate(m_callbacks->user_data,
+                                         GetValidatedValue(window).utf8_str(),
+                                         &message,
+                                         SetValidatorMessage))
+            return true;
+
+        if (message.empty())
+            message = _("The value is not valid.");
+        RevealWindow(window);
+        window->SetFocus();
+        wxMessageBox(message, _("Validation conflict"), wxOK | wxICON_EXCLAMATION, parent);
+        return false;
+    }
+
+    bool TransferToWindow() override
+    {
+        return true;
+    }
+
+    bool TransferFromWindow() override
+    {
+        wxWindow* window = GetWindow();
+        if (window && m_callbacks->vtable.transfer_from_window) {
+            m_callbacks->vtable.transfer_from_window(m_callbacks->user_data,
+                                                     GetValidatedValue(window).utf8_str());
+        }
+        return true;
+    }
+
+private:
+    void OnChar(wxKeyEvent& event)
+    {
+        int key_code = event.GetKeyCode();
+        wxChar ch = event.GetUnicodeKey();
+        // Control characters, editing and navigation keys and shortcuts pass
+        if (!m_callbacks->vtable.accepts_char || key_code < WXK_SPACE || key_code == WXK_DELETE ||
+            key_code >= WXK_START || ch == WXK_NONE || event.HasModifiers()) {
+            event.Skip();
+            return;
+        }
+
+        if (m_callbacks->vtable.accepts_char(m_callbacks->user_data, static_cast<uint32_t>(ch))) {
+            event.Skip();
+        }
+        else if (!wxValidator::IsSilent()) {
+            wxBell();
+        }
+    }
+
+    std::shared_ptr<WxdValidatorCallbacks> m_callbacks;
+};
+
+// Calls `visit` with the validator of each descendant of `window`, depth first,
+// until it returns false. Top-level children such as dialogs are left out.
+template <typename Visit>
+static bool
+ForEachValidator(wxWindow* window, Visit visit)
+{
+    wxWindowList& children = window->GetChildren();
+    for (wxWindowList::iterator it = children.begin(); it != children.end(); ++it) {
+        wxWindow* child = *it;
+        if (child->IsTopLevel())
+            continue;
+        wxValidator* validator = child->GetValidator();
+        if (validator && !visit(validator))
+            return false;
+        if (!ForEachValidator(child, visit))
+            return false;
+    }
+    return true;
+}
+
+extern "C" {
+
+WXD_EXPORTED bool
+wxd_Window_SetValidator(wxd_Window_t* window, const wxd_Validator_vtable* vtable, void* user_data)
+{
+    if (!vtable)
+        return false;
+    // Owns user_data from here on, so it is released on failure too
+    auto callbacks = std::make_shared<WxdValidatorCallbacks>(vtable, user_data);
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window)
+        return false;
+    // SetValidator stores a copy
+    wx_window->SetValidator(WxdRustValidator(callbacks));
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_Window_Validate(wxd_Window_t* window)
+{
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window)
+        return false;
+    return ForEachValidator(wx_window, [wx_window](wxValidator* validator) {
+        return validator->Validate(wx_window);
+    });
+}
+
+WXD_EXPORTED bool
+wxd_Window_TransferDataFromWindow(wxd_Window_t* window)
+{
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window)
+        return false;
+    return ForEachValidator(wx_window,
+                            [](wxValidator* validator) { return validator->TransferFromWindow(); });
+}
+
+WXD_EXPORTED int
+wxd_RegEx_MatchesAll(const char* pattern, const char* text)
+{
+    if (!pattern)
+        return -1;
+    // An invalid pattern is reported to the caller, not logged
+    wxLogNull no_log;
+    // Anchored, so the pattern has to match the whole text
+    wxRegEx regex("^(" + wxString::FromUTF8(pattern) + ")$", wxRE_EXTENDED);
+    if (!regex.IsValid())
+        return -1;
+    return regex.Matches(wxString::FromUTF8(text ? text : "")) ? 1 : 0;
+}
+
+} // extern "C"
@@ -10,8 +10,57 @@
 #if wxdUSE_WEBVIEW
 
 #include "wx/webview.h"
+#include "wx/webviewarchivehandler.h"
+#include "wx/webviewfshandler.h"
 #include "wx/mstream.h"
 #include "wx/filesys.h"
+#include "wx/fs_arc.h"
+#include "wx/fs_mem.h"
+#include "wx/base64.h"
+#include "wx/filename.h"
+#include "wx/stopwatch.h"
+#include "wx/evtloop.h"
+#include "wx/weakref.h"
+
+#include <algorithm>
+#include <cmath>
+#include <functional>
+#include <memory>
+#include <unordered_map>
+#include <unordered_set>
+#include <vector>
+
+// Downloads and cookies, which wxWebView doesn't wrap, use the backend's own API where
+// wxDragon hooks it: WebView2 on MSVC builds (the SDK's COM helpers need WRL).
+#if defined(__WXMSW__) && wxUSE_WEBVIEW_EDGE && defined(_MSC_VER)
+#define WXD_WEBVIEW_EDGE_COM 1
+#include "wx/msw/webview_edge.h"
+#include <WebView2.h>
+#include <wrl.h>
+#else
+#define WXD_WEBVIEW_EDGE_COM 0
+#endif
+
+// Snapshots on WebKit go through WKWebView (webview_osx.mm) on macOS and through
+// WebKitGTK, when CMake found its headers, on GTK.
+#if defined(__WXGTK__) && defined(WXD_WEBVIEW_WEBKITGTK)
+#include <webkit2/webkit2.h>
+#endif
+
+// Dispatches events until `done` is set or `timeoutMs` passes, for the backends' APIs
+// that only answer asynchronously. YieldFor() keeps dispatching when called from an
+// event handler that runs inside another yield, where wxYield() returns right away.
+static void
+YieldUntil(const bool& done, long timeoutMs)
+{
+    wxStopWatch timer;
+    while (!done && timer.Time() < timeoutMs) {
+        wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+        if (!loop)
+            return;
+        loop->YieldFor(wxEVT_CATEGORY_ALL);
+    }
+}
 
 // Custom scheme handler that bridges wxWebViewHandler::GetFile to a Rust callback.
 // We override GetFile rather than StartRequest because the base StartRequest
@@ -72,11 +121,666 @@ private:
     void* m_userdata;
 };
 
+// Download events sent by wxDragon's WebView wrapper. GetURL() is the download URL
+// like for other webview events; the download details travel alongside it.
+class wxdWebViewDownloadEvent : public wxWebViewEvent
+{
+public:
+    wxdWebViewDownloadEvent(wxEventType type, int id, const wxString& url)
+        : wxWebViewEvent(type, id, url, wxString())
+    {
+    }
+
+    wxEvent* Clone() const override { return new wxdWebViewDownloadEvent(*this); }
+
+    wxString m_path;       // Where the file is (or will be) saved
+    wxString m_mimeType;
+    wxString m_targetPath; // Set by a download request handler to save elsewhere
+    wxLongLong_t m_bytesReceived = 0;
+    wxLongLong_t m_totalBytes = -1;
+    wxd_WebViewDownloadState m_state = WXD_WEBVIEW_DOWNLOAD_IN_PROGRESS;
+};
+
+wxDEFINE_EVENT(wxdEVT_WEBVIEW_DOWNLOAD_REQUEST, wxWebViewEvent);
+wxDEFINE_EVENT(wxdEVT_WEBVIEW_DOWNLOAD_PROGRESS, wxWebViewEvent);
+wxDEFINE_EVENT(wxdEVT_WEBVIEW_DOWNLOAD_COMPLETED, wxWebViewEvent);
+
+static wxdWebViewDownloadEvent*
+AsDownloadEvent(wxd_Event_t* event)
+{
+    return event ? dynamic_cast<wxdWebViewDownloadEvent*>(reinterpret_cast<wxEvent*>(event))
+                 : nullptr;
+}
+
+// Webviews whose backend download hooks are installed or waiting for the backend.
+static std::unordered_set<wxWebView*> s_download_hooks;
+
+// Receives the PNG bytes of a snapshot, or an empty buffer and the reason it failed.
+typedef std::function<void(const wxMemoryBuffer& png, const wxString& error)> SnapshotDone;
+
+#ifdef __WXOSX__
+// Implemented in webview_osx.mm
+void
+wxdOSXCaptureWebViewSnapshot(void* native, SnapshotDone done);
+#endif
+
+// Implemented in event.cpp
+bool
+wxdHasRustHandlers(wxEvtHandler* handler, wxEventType type);
+
+#if WXD_WEBVIEW_EDGE_COM
+using Microsoft::WRL::Callback;
+using Microsoft::WRL::ComPtr;
+
+static wxString
+TakeCoTaskString(LPWSTR str)
+{
+    wxString result(str ? str : L"");
+    if (str)
+        CoTaskMemFree(str);
+    return result;
+}
+
+static void
+FillDownloadEvent(wxdWebViewDownloadEvent& event, ICoreWebView2DownloadOperation* op)
+{
+    LPWSTR str = nullptr;
+    if (SUCCEEDED(op->get_ResultFilePath(&str)))
+        event.m_path = TakeCoTaskString(str);
+    str = nullptr;
+    if (SUCCEEDED(op->get_MimeType(&str)))
+        event.m_mimeType = TakeCoTaskString(str);
+
+    INT64 received = 0;
+    INT64 total = -1;
+    op->get_BytesReceived(&received);
+    op->get_TotalBytesToReceive(&total);
+    event.m_bytesReceived = received;
+    event.m_totalBytes = total;
+
+    COREWEBVIEW2_DOWNLOAD_STATE state = COREWEBVIEW2_DOWNLOAD_STATE_IN_PROGRESS;
+    op->get_State(&state);
+    if (state == COREWEBVIEW2_DOWNLOAD_STATE_COMPLETED)
+        event.m_state = WXD_WEBVIEW_DOWNLOAD_COMPLETED;
+    else if (state == COREWEBVIEW2_DOWNLOAD_STATE_INTERRUPTED)
+        event.m_state = WXD_WEBVIEW_DOWNLOAD_INTERRUPTED;
+}
+
+static void
+SendDownloadEvent(wxWebView* webview, ICoreWebView2DownloadOperation* op, wxEventType type)
+{
+    LPWSTR uri = nullptr;
+    op->get_Uri(&uri);
+    wxdWebViewDownloadEvent event(type, webview->GetId(), TakeCoTaskString(uri));
+    event.SetEventObject(webview);
+    FillDownloadEvent(event, op);
+    webview->HandleWindowEvent(event);
+}
+
+static HRESULT
+OnEdgeDownloadStarting(wxWebView* webview, ICoreWebView2DownloadStartingEventArgs* args)
+{
+    ComPtr<ICoreWebView2DownloadOperation> op;
+    if (FAILED(args->get_DownloadOperation(&op)))
+        return S_OK;
+
+    LPWSTR uri = nullptr;
+    op->get_Uri(&uri);
+    wxdWebViewDownloadEvent event(wxdEVT_WEBVIEW_DOWNLOAD_REQUEST, webview->GetId(),
+                                  TakeCoTaskString(uri));
+    event.SetEventObject(webview);
+    FillDownloadEvent(event, op.Get());
+    // The operation only knows its final path once started; the args hold the suggestion
+    LPWSTR path = nullptr;
+    if (SUCCEEDED(args->get_ResultFilePath(&path)))
+        event.m_path = TakeCoTaskString(path);
+
+    webview->HandleWindowEvent(event);
+    if (!event.IsAllowed()) {
+        args->put_Cancel(TRUE);
+        return S_OK;
+    }
+    if (!event.m_targetPath.empty())
+        args->put_ResultFilePath(event.m_targetPath.wc_str());
+    // An application handling download requests on the webview shows its own progress, so
+    // Edge's download flyout is hidden. Rust handlers skip by default, so the result of
+    // HandleWindowEvent doesn't tell.
+    if (wxdHasRustHandlers(webview, wxdEVT_WEBVIEW_DOWNLOAD_REQUEST))
+        args->put_Handled(TRUE);
+
+    wxWeakRef<wxWebView> weak(webview);
+    EventRegistrationToken token;
+    op->add_BytesReceivedChanged(
+        Callback<ICoreWebView2BytesReceivedChangedEventHandler>(
+            [weak](ICoreWebView2DownloadOperation* sender, IUnknown*) -> HRESULT {
+                if (wxWebView* target = weak.get())
+                    SendDownloadEvent(target, sender, wxdEVT_WEBVIEW_DOWNLOAD_PROGRESS);
+                return S_OK;
+            })
+            .Get(),
+        &token);
+    op->add_StateChanged(
+        Callback<ICoreWebView2StateChangedEventHandler>(
+            [weak](ICoreWebView2DownloadOperation* sender, IUnknown*) -> HRESULT {
+                COREWEBVIEW2_DOWNLOAD_STATE state;
+                if (FAILED(sender->get_State(&state)) ||
+                    state == COREWEBVIEW2_DOWNLOAD_STATE_IN_PROGRESS)
+                    return S_OK;
+                if (wxWebView* target = weak.get())
+                    SendDownloadEvent(target, sender, wxdEVT_WEBVIEW_DOWNLOAD_COMPLETED);
+                return S_OK;
+            })
+            .Get(),
+        &token);
+    return S_OK;
+}
+
+static void
+InstallEdgeDownloadHooks(wxWebView* webview)
+{
+    ICoreWebView2* core = static_cast<ICoreWebView2*>(webview->GetNativeBackend());
+    ComPtr<ICoreWebView2_4> core4;
+    if (!core || FAILED(core->QueryInterface(IID_PPV_ARGS(&core4))))
+        return;
+
+    wxWeakRef<wxWebView> weak(webview);
+    EventRegistrationToken token;
+    core4->add_DownloadStarting(
+        Callback<ICoreWebView2DownloadStartingEventHandler>(
+            [weak](ICoreWebView2*, ICoreWebView2DownloadStartingEventArgs* args) -> HRESULT {
+                wxWebView* target = weak.get();
+                return target ? OnEdgeDownloadStarting(target, args) : S_OK;
+            })
+            .Get(),
+        &token);
+}
+
+static ComPtr<ICoreWebView2CookieManager>
+GetEdgeCookieManager(wxWebView* webview)
+{
+    ComPtr<ICoreWebView2CookieManager> manager;
+    if (!wxDynamicCast(webview, wxWebViewEdge))
+        return manager;
+    ICoreWebView2* core = static_cast<ICoreWebView2*>(webview->GetNativeBackend());
+    ComPtr<ICoreWebView2_2> core2;
+    if (core && SUCCEEDED(core->QueryInterface(IID_PPV_ARGS(&core2))))
+        core2->get_CookieManager(&manager);
+    return manager;
+}
+
+// WebView2 only lists cookies asynchronously; wait for the answer while dispatching
+// events.
+static bool
+GetEdgeCookies(ICoreWebView2CookieManager* manager, const wxString& url,
+               std::vector<ComPtr<ICoreWebView2Cookie>>& cookies)
+{
+    struct Request
+    {
+        bool done = false;
+        bool ok = false;
+        std::vector<ComPtr<ICoreWebView2Cookie>> cookies;
+    };
+    auto request = std::make_shared<Request>();
+
+    HRESULT hr = manager->GetCookies(
+        url.wc_str(),
+        Callback<ICoreWebView2GetCookiesCompletedHandler>(
+            [request](HRESULT result, ICoreWebView2CookieList* list) -> HRESULT {
+                request->done = true;
+                UINT count = 0;
+                if (FAILED(result) || !list || FAILED(list->get_Count(&count)))
+                    return S_OK;
+                for (UINT i = 0; i < count; ++i) {
+                    ComPtr<ICoreWebView2Cookie> cookie;
+                    if (SUCCEEDED(list->GetValueAtIndex(i, &cookie)))
+                        request->cookies.push_back(cookie);
+                }
+                request->ok = true;
+                return S_OK;
+            })
+            .Get());
+    if (FAILED(hr))
+        return false;
+
+    YieldUntil(request->done, 5000);
+
+    cookies = request->cookies;
+    return request->ok;
+}
+
+static void
+CaptureEdgeViewport(ICoreWebView2* core, SnapshotDone done)
+{
+    ComPtr<IStream> stream;
+    if (FAILED(CreateStreamOnHGlobal(nullptr, TRUE, &stream))) {
+        done(wxMemoryBuffer(), "could not allocate the snapshot stream");
+        return;
+    }
+
+    HRESULT hr = core->CapturePreview(
+        COREWEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG, stream.Get(),
+        Callback<ICoreWebView2CapturePreviewCompletedHandler>(
+            [stream, done](HRESULT result) -> HRESULT {
+                HGLOBAL memory = nullptr;
+                STATSTG stat;
+                if (FAILED(result) || FAILED(GetHGlobalFromStream(stream.Get(), &memory)) ||
+                    FAILED(stream->Stat(&stat, STATFLAG_NONAME))) {
+                    done(wxMemoryBuffer(), "WebView2 could not capture the page");
+                    return S_OK;
+                }
+                wxMemoryBuffer png;
+                if (void* data = GlobalLock(memory)) {
+                    png.AppendData(data, (size_t)stat.cbSize.QuadPart);
+                    GlobalUnlock(memory);
+                }
+                done(png, png.IsEmpty() ? "WebView2 returned an empty snapshot" : "");
+                return S_OK;
+            })
+            .Get());
+    if (FAILED(hr))
+        done(wxMemoryBuffer(), "WebView2 could not capture the page");
+}
+
+// Reads the number `key` of the object `object` in a DevTools protocol reply.
+static bool
+GetJsonNumber(const wxString& json, const wxString& object, const wxString& key, double* value)
+{
+    size_t start = json.find("\"" + object + "\":{");
+    if (start == wxString::npos)
+        return false;
+    size_t end = json.find('}', start);
+    size_t pos = json.find("\"" + key + "\":", start);
+    if (pos == wxString::npos || pos > end)
+        return false;
+    pos += key.length() + 3;
+    wxString number = json.Mid(pos, json.find_first_of(",}", pos) - pos);
+    return number.Trim().Trim(false).ToCDouble(value);
+}
+
+static void
+CaptureEdgeArea(ICoreWebView2* core, double width, double height, SnapshotDone done)
+{
+    wxString params = wxString::Format(
+        "{\"format\":\"png\",\"captureBeyondViewport\":true,"
+        "\"clip\":{\"x\":0,\"y\":0,\"width\":%d,\"height\":%d,\"scale\":1}}",
+        (int)std::ceil(width), (int)std::ceil(height));
+
+    HRESULT hr = core->CallDevToolsProtocolMethod(
+        L"Page.captureScreenshot", params.wc_str(),
+        Callback<ICoreWebView2CallDevToolsProtocolMethodCompletedHandler>(
+            [done](HRESULT result, LPCWSTR json) -> HRESULT {
+                // The reply is {"data":"<base64 PNG>"}
+                wxString reply(json ? json : L"");
+                const wxString key = "\"data\":\"";
+                size_t start = reply.find(key);
+                size_t end = wxString::npos;
+                if (start != wxString::npos)
+                    end = reply.find('"', start + key.length());
+                if (FAILED(result) || end == wxString::npos) {
+                    done(wxMemoryBuffer(), "WebView2 could not capture the page");
+                    return S_OK;
+                }
+                start += key.length();
+                wxMemoryBuffer png = wxBase64Decode(reply.Mid(start, end - start));
+                done(png, png.IsEmpty() ? "WebView2 returned an empty snapshot" : "");
+                return S_OK;
+            })
+            .Get());
+    if (FAILED(hr))
+        done(wxMemoryBuffer(), "WebView2 could not capture the page");
+}
+
+// CapturePreview only sees the viewport, so whole pages go through the DevTools
+// protocol with a clip covering the document. The document size comes from the
+// protocol too, rather than from a script that would have to be waited for.
+static void
+CaptureEdgeFullPage(ICoreWebView2* core, SnapshotDone done)
+{
+    ComPtr<ICoreWebView2> coreRef(core);
+    HRESULT hr = core->CallDevToolsProtocolMethod(
+        L"Page.getLayoutMetrics", L"{}",
+        Callback<ICoreWebView2CallDevToolsProtocolMethodCompletedHandler>(
+            [coreRef, done](HRESULT result, LPCWSTR json) -> HRESULT {
+                // cssContentSize is in CSS pixels like the clip; older runtimes only
+                // report contentSize
+                wxString reply(json ? json : L"");
+                double width = 0, height = 0;
+                bool measured = SUCCEEDED(result) &&
+                                ((GetJsonNumber(reply, "cssContentSize", "width", &width) &&
+                                  GetJsonNumber(reply, "cssContentSize", "height", &height)) ||
+                                 (GetJsonNumber(reply, "contentSize", "width", &width) &&
+                                  GetJsonNumber(reply, "contentSize", "height", &height)));
+                if (!measured || width <= 0 || height <= 0)
+                    done(wxMemoryBuffer(), "could not measure the page");
+                else
+                    CaptureEdgeArea(coreRef.Get(), width, height, done);
+                return S_OK;
+            })
+            .Get());
+    if (FAILED(hr))
+        done(wxMemoryBuffer(), "could not measure the page");
+}
+#endif // WXD_WEBVIEW_EDGE_COM
+
+#if defined(__WXGTK__) && defined(WXD_WEBVIEW_WEBKITGTK)
+static cairo_status_t
+AppendPngData(void* closure, const unsigned char* data, unsigned int length)
+{
+    static_cast<wxMemoryBuffer*>(closure)->AppendData(data, length);
+    return CAIRO_STATUS_SUCCESS;
+}
+
+static void
+WebKitGTKSnapshotReady(GObject* source, GAsyncResult* result, gpointer userdata)
+{
+    std::unique_ptr<SnapshotDone> done(static_cast<SnapshotDone*>(userdata));
+    GError* error = nullptr;
+    cairo_surface_t* surface =
+        webkit_web_view_get_snapshot_finish(WEBKIT_WEB_VIEW(source), result, &error);
+    if (!surface) {
+        wxString reason = "WebKitGTK could not capture the page";
+        if (error) {
+            reason = wxString::FromUTF8(error->message);
+            g_error_free(error);
+        }
+        (*done)(wxMemoryBuffer(), reason);
+        return;
+    }
+
+    wxMemoryBuffer png;
+    cairo_status_t status = cairo_surface_write_to_png_stream(surface, AppendPngData, &png);
+    cairo_surface_destroy(surface);
+    if (status != CAIRO_STATUS_SUCCESS || png.IsEmpty())
+        (*done)(wxMemoryBuffer(), "could not encode the snapshot as PNG");
+    else
+        (*done)(png, "");
+}
+
+static void
+CaptureWebKitGTK(WebKitWebView* view, bool fullPage, SnapshotDone done)
+{
+    // Owned by WebKitGTKSnapshotReady, which WebKit always calls, also on failure
+    webkit_web_view_get_snapshot(
+        view, fullPage ? WEBKIT_SNAPSHOT_REGION_FULL_DOCUMENT : WEBKIT_SNAPSHOT_REGION_VISIBLE,
+        WEBKIT_SNAPSHOT_OPTIONS_NONE, nullptr, WebKitGTKSnapshotReady, new SnapshotDone(done));
+}
+#endif
+
+// Calls `done` exactly once, possibly before returning.
+static void
+CaptureSnapshot(wxWebView* webview, bool fullPage, SnapshotDone done)
+{
+#if WXD_WEBVIEW_EDGE_COM
+    if (wxDynamicCast(webview, wxWebViewEdge)) {
+        ICoreWebView2* core = static_cast<ICoreWebView2*>(webview->GetNativeBackend());
+        if (!core)
+            done(wxMemoryBuffer(), "the Edge backend has not been created yet");
+        else if (fullPage)
+            CaptureEdgeFullPage(core, done);
+        else
+            CaptureEdgeViewport(core, done);
+        return;
+    }
+#endif
+#if defined(__WXOSX__) || (defined(__WXGTK__) && defined(WXD_WEBVIEW_WEBKITGTK))
+    if (webview->GetClassInfo()->GetClassName() == wxString("wxWebViewWebKit")) {
+        void* native = webview->GetNativeBackend();
+        if (!native) {
+            done(wxMemoryBuffer(), "the WebKit backend has not been created yet");
+            return;
+        }
+#ifdef __WXOSX__
+        // WKWebView only renders what is on screen into a snapshot
+        if (fullPage) {
+            done(wxMemoryBuffer(), "full-page snapshots are not supported by WKWebView");
+            return;
+        }
+        wxdOSXCaptureWebViewSnapshot(native, done);
+#else
+        CaptureWebKitGTK(WEBKIT_WEB_VIEW(native), fullPage, done);
+#endif
+        return;
+    }
+#endif
+    wxUnusedVar(fullPage);
+    done(wxMemoryBuffer(), wxString::Format("snapshots are not supported by the %s backend",
+                                            webview->GetClassInfo()->GetClassName()));
+}
+
+#if defined(__WXGTK__) && defined(WXD_WEBVIEW_WEBKITGTK)
+// WebKitGTK reports downloads on the web context, which webviews may share, so each
+// download goes to the hooked webview showing the page it came from.
+static wxWebView*
+FindDownloadWebView(WebKitDownload* download)
+{
+    WebKitWebView* view = webkit_download_get_web_view(download);
+    for (wxWebView* webview : s_download_hooks) {
+        if (view && webview->GetNativeBackend() == view)
+            return webview;
+    }
+    return nullptr;
+}
+
+static void
+SendDownloadEvent(WebKitDownload* download, wxEventType type, wxd_WebViewDownloadState state)
+{
+    wxWebView* webview = FindDownloadWebView(download);
+    if (!webview)
+        return;
+
+    WebKitURIRequest* request = webkit_download_get_request(download);
+    wxdWebViewDownloadEvent event(type, webview->GetId(),
+                                  wxString::FromUTF8(webkit_uri_request_get_uri(request)));
+    event.SetEventObject(webview);
+    // WebKitGTK 4.x destinations are file:// URIs
+    if (const gchar* destination = webkit_download_get_destination(download)) {
+        if (gchar* path = g_filename_from_uri(destination, nullptr, nullptr)) {
+            event.m_path = wxString::FromUTF8(path);
+            g_free(path);
+        }
+    }
+    if (WebKitURIResponse* response = webkit_download_get_response(download)) {
+        if (const gchar* mime = webkit_uri_response_get_mime_type(response))
+            event.m_mimeType = wxString::FromUTF8(mime);
+        if (guint64 length = webkit_uri_response_get_content_length(response))
+            event.m_totalBytes = (wxLongLong_t)length;
+    }
+    event.m_bytesReceived = (wxLongLong_t)webkit_download_get_received_data_length(download);
+    event.m_state = state;
+    webview->HandleWindowEvent(event);
+}
+
+static void
+OnWebKitGTKDownloadData(WebKitDownload* download, guint64, gpointer)
+{
+    SendDownloadEvent(download, wxdEVT_WEBVIEW_DOWNLOAD_PROGRESS, WXD_WEBVIEW_DOWNLOAD_IN_PROGRESS);
+}
+
+static void
+OnWebKitGTKDownloadFailed(WebKitDownload* download, GError*, gpointer)
+{
+    // "finished" follows and reports the download as interrupted
+    g_object_set_data(G_OBJECT(download), "wxd-download-failed", GINT_TO_POINTER(1));
+}
+
+static void
+OnWebKitGTKDownloadFinished(WebKitDownload* download, gpointer)
+{
+    bool failed = g_object_get_data(G_OBJECT(download), "wxd-download-failed") != nullptr;
+    SendDownloadEvent(download, wxdEVT_WEBVIEW_DOWNLOAD_COMPLETED,
+                      failed ? WXD_WEBVIEW_DOWNLOAD_INTERRUPTED : WXD_WEBVIEW_DOWNLOAD_COMPLETED);
+}
+
+// The download request event, sent once WebKit knows the file name
+static gboolean
+OnWebKitGTKDecideDestination(WebKitDownload* download, gchar* suggested, gpointer)
+{
+    wxWebView* webview = FindDownloadWebView(download);
+    if (!webview)
+        return FALSE;
+
+    WebKitURIRequest* request = webkit_download_get_request(download);
+    wxdWebViewDownloadEvent event(wxdEVT_WEBVIEW_DOWNLOAD_REQUEST, webview->GetId(),
+                                  wxString::FromUTF8(webkit_uri_request_get_uri(request)));
+    event.SetEventObject(webview);
+    // Where WebKit saves the file unless told otherwise
+    const gchar* dir = g_get_user_special_dir(G_USER_DIRECTORY_DOWNLOAD);
+    wxFileName path(wxString::FromUTF8(dir ? dir : g_get_home_dir()),
+                    wxString::FromUTF8(suggested));
+    event.m_path = path.GetFullPath();
+    if (WebKitURIResponse* response = webkit_download_get_response(download)) {
+        if (const gchar* mime = webkit_uri_response_get_mime_type(response))
+            event.m_mimeType = wxString::FromUTF8(mime);
+        if (guint64 length = webkit_uri_response_get_content_length(response))
+            event.m_totalBytes = (wxLongLong_t)length;
+    }
+
+    webview->HandleWindowEvent(event);
+    if (!event.IsAllowed()) {
+        webkit_download_cancel(download);
+        return TRUE;
+    }
+
+    g_signal_connect(download, "received-data", G_CALLBACK(OnWebKitGTKDownloadData), nullptr);
+    g_signal_connect(download, "failed", G_CALLBACK(OnWebKitGTKDownloadFailed), nullptr);
+    g_signal_connect(download, "finished", G_CALLBACK(OnWebKitGTKDownloadFinished), nullptr);
+    if (event.m_targetPath.empty())
+        return FALSE;
+    if (gchar* uri = g_filename_to_uri(event.m_targetPath.utf8_str(), nullptr, nullptr)) {
+        webkit_download_set_destination(download, uri);
+        g_free(uri);
+    }
+    return TRUE;
+}
+
+static void
+OnWebKitGTKDownloadStarted(WebKitWebContext*, WebKitDownload* download, gpointer)
+{
+    g_signal_connect(download, "decide-destination", G_CALLBACK(OnWebKitGTKDecideDestination),
+                     nullptr);
+}
+
+static void
+InstallWebKitGTKDownloadHooks(wxWebView* webview)
+{
+    WebKitWebView* view = static_cast<WebKitWebView*>(webview->GetNativeBackend());
+    WebKitWebContext* context = view ? webkit_web_view_get_context(view) : nullptr;
+    if (!context || g_object_get_data(G_OBJECT(context), "wxd-download-hooks"))
+        return;
+    g_object_set_data(G_OBJECT(context), "wxd-download-hooks", GINT_TO_POINTER(1));
+    g_signal_connect(context, "download-started", G_CALLBACK(OnWebKitGTKDownloadStarted), nullptr);
+}
+#endif
+
+static bool
+BackendSupportsDownloads(wxWebView* webview)
+{
+#if WXD_WEBVIEW_EDGE_COM
+    return wxDynamicCast(webview, wxWebViewEdge) != nullptr;
+#elif defined(__WXGTK__) && defined(WXD_WEBVIEW_WEBKITGTK)
+    return webview->GetClassInfo()->GetClassName() == wxString("wxWebViewWebKit");
+#else
+    wxUnusedVar(webview);
+    return false;
+#endif
+}
+
+// Hooks the backend's download API once per webview. Edge has no native backend
+// until it sends wxEVT_WEBVIEW_CREATED, so the hooks are installed then; WebKitGTK's
+// are installed once per web context.
+static bool
+EnableDownloadEvents(wxWebView* webview)
+{
+    if (!webview || !BackendSupportsDownloads(webview))
+        return false;
+    if (!s_download_hooks.insert(webview).second)
+        return true;
+
+    webview->Bind(wxEVT_DESTROY, [webview](wxWindowDestroyEvent& event) {
+        event.Skip();
+        if (event.GetEventObject() == webview)
+            s_download_hooks.erase(webview);
+    });
+#if WXD_WEBVIEW_EDGE_COM
+    if (webview->GetNativeBackend()) {
+        InstallEdgeDownloadHooks(webview);
+    }
+    else {
+        webview->Bind(wxEVT_WEBVIEW_CREATED, [webview](wxWebViewEvent& event) {
+            event.Skip();
+            InstallEdgeDownloadHooks(webview);
+        });
+    }
+#elif defined(__WXGTK__) && defined(WXD_WEBVIEW_WEBKITGTK)
+    InstallWebKitGTKDownloadHooks(webview);
+#endif
+    return true;
+}
+
+// The built-in handlers read through wxFileSystem, which needs the archive and
+// memory handlers installed once.
+static void
+EnsureFileSystemHandlers()
+{
+    static bool installed = false;
+    if (installed)
+        return;
+    installed = true;
+    wxFileSystem::AddHandler(new wxArchiveFSHandler);
+    wxFileSystem::AddHandler(new wxMemoryFSHandler);
+}
+
+// Builds the wxWebViewHandler described by `desc`, or returns nullptr (after
+// dropping any Rust userdata) if the description is unusable.
+static wxWebViewHandler*
+MakeHandler(const wxd_WebViewHandlerDesc& desc)
+{
+    if (!desc.scheme) {
+        if (desc.kind == WXD_WEBVIEW_HANDLER_CALLBACK && desc.drop_userdata)
+            desc.drop_userdata(desc.userdata);
+        return nullptr;
+    }
+
+    wxString schemeStr = wxString::FromUTF8(desc.scheme);
+    switch (desc.kind) {
+    case WXD_WEBVIEW_HANDLER_ARCHIVE:
+        EnsureFileSystemHandlers();
+        return new wxWebViewArchiveHandler(schemeStr);
+    case WXD_WEBVIEW_HANDLER_FILESYSTEM:
+        EnsureFileSystemHandlers();
+        return new wxWebViewFSHandler(schemeStr);
+    case WXD_WEBVIEW_HANDLER_CALLBACK:
+    default:
+        if (!desc.callback) {
+            if (desc.drop_userdata)
+                desc.drop_userdata(desc.userdata);
+            return nullptr;
+        }
+        return new WxdRustWebViewHandler(schemeStr, desc.callback, desc.free_data,
+                                         desc.drop_userdata, desc.userdata);
+    }
+}
+
+static void
+RegisterHandlers(wxWebView* webview, const wxd_WebViewHandlerDesc* handlers, int count)
+{
+    for (int i = 0; i < count; ++i) {
+        wxWebViewHandler* handler = MakeHandler(handlers[i]);
+        if (!handler)
+            continue;
+        // Owned by the shared pointer, so a null webview still frees the handler
+        wxSharedPtr<wxWebViewHandler> shared(handler);
+        if (webview)
+            webview->RegisterHandler(shared);
+    }
+}
+
 extern "C" {
 
 WXD_EXPORTED wxd_WebView_t*
 wxd_WebView_Create(wxd_Window_t* parent, wxd_Id id, const char* url, wxd_Point pos, wxd_Size size,
-                   long style, const char* name, const char* backend)
+                   long style, const char* name, const char* backend,
+                   const wxd_WebViewHandlerDesc* handlers, int handler_count)
 {
     wxWindow* parentWin = (wxWindow*)parent;
     wxString urlStr = url ? wxString::FromUTF8(url) : wxString();
@@ -86,18 +790,145 @@ wxd_WebView_Create(wxd_Window_t* parent, wxd_Id id, const char* url, wxd_Point p
     // On macOS/Linux, wxWebViewBackendDefault is "wxWebViewWebKit"
     wxString backendStr = (!backend || backend[0] == '\0') ? wxWebViewBackendDefault : wxString::FromUTF8(backend);
 
-    wxWebView* webview = wxWebView::New(parentWin, id, urlStr, wxd_cpp_utils::to_wx(pos),
-                                        wxd_cpp_utils::to_wx(size), backendStr, style,
-                                        nameStr);
+    if (!handlers || handler_count <= 0) {
+        wxWebView* webview = wxWebView::New(parentWin, id, urlStr, wxd_cpp_utils::to_wx(pos),
+                                            wxd_cpp_utils::to_wx(size), backendStr, style,
+                                            nameStr);
+        EnableDownloadEvents(webview);
+        return (wxd_WebView_t*)webview;
+    }
 
+#ifdef __WXOSX__
+    // WebKit on macOS only reads the handlers when the native view is created,
+    // so register them on a not yet created webview and create it afterwards.
+    wxWebView* webview = wxWebView::New(backendStr);
+    RegisterHandlers(webview, handlers, handler_count);
+    if (webview && !webview->Create(parentWin, id, urlStr, wxd_cpp_utils::to_wx(pos),
+                                    wxd_cpp_utils::to_wx(size), style, nameStr)) {
+        delete webview;
+        webview = nullptr;
+    }
+#else
+    // Edge and WebKit2GTK accept handlers on a live webview, but the initial URL
+    // must not be loaded until they are in place.
+    wxWebView* webview = wxWebView::New(parentWin, id, wxString(), wxd_cpp_utils::to_wx(pos),
+                                        wxd_cpp_utils::to_wx(size), backendStr, style, nameStr);
+    RegisterHandlers(webview, handlers, handler_count);
+    if (webview && !urlStr.empty())
+        webview->LoadURL(urlStr);
+#endif
+
+    EnableDownloadEvents(webview);
     return (wxd_WebView_t*)webview;
 }
 
+// HTML given to wxd_WebView_SetPage. Reloading the page would load about:blank on
+// Edge, so wxd_WebView_Reload sets it again while the webview still shows it.
+struct GeneratedPage {
+    wxString html;
+    wxString baseUrl;
+    wxString url; // Current URL once it has loaded, empty while loading
+    bool shown = false;
+};
+
+static std::unordered_map<wxWebView*, GeneratedPage> s_generated_pages;
+
+// Finds the start tag `<name` in lowercased HTML, skipping longer tag names such as
+// <header> for "head" or <basefont> for "base".
+static int
+FindStartTag(const wxString& lower, const wxString& name)
+{
+    wxString open = "<" + name;
+    size_t pos = lower.find(open);
+    while (pos != wxString::npos) {
+        size_t next = pos + open.length();
+        if (next == lower.length())
+            return wxNOT_FOUND;
+        wxUniChar c = lower[next];
+        if (c == '>' || c == '/' || wxIsspace(c))
+            return (int)pos;
+        pos = lower.find(open, next);
+    }
+    return wxNOT_FOUND;
+}
+
+// Only WebKit resolves relative URLs against the base URL passed to SetPage, so
+// for other backends the base URL is added to the document as a <base> element.
+static wxString
+WithBaseElement(wxWebView* webview, const wxString& html, const wxString& baseUrl)
+{
+    if (baseUrl.empty() || webview->GetClassInfo()->GetClassName() == wxString("wxWebViewWebKit"))
+        return html;
+
+    wxString lower = html.Lower();
+    if (FindStartTag(lower, "base") != wxNOT_FOUND)
+        return html;
+
+    wxString href = baseUrl;
+    href.Replace("&", "&amp;");
+    href.Replace("\"", "&quot;");
+    wxString element = wxString::Format("<base href=\"%s\">", href);
+
+    // Without a <head>, the element goes after the <html> start tag or the doctype: in
+    // front of the doctype it would switch the page into quirks mode
+    int tag = FindStartTag(lower, "head");
+    if (tag == wxNOT_FOUND)
+        tag = FindStartTag(lower, "html");
+    if (tag == wxNOT_FOUND)
+        tag = FindStartTag(lower, "!doctype");
+    if (tag != wxNOT_FOUND) {
+        size_t close = lower.find('>', tag);
+        if (close != wxString::npos)
+            return html.substr(0, close + 1) + element + html.substr(close + 1);
+    }
+    return element + html;
+}
+
+static void
+ShowGeneratedPage(wxWebView* webview, GeneratedPage& page)
+{
+    page.url.clear();
+    page.shown = true;
+    webview->SetPage(WithBaseElement(webview, page.html, page.baseUrl), page.baseUrl);
+}
+
+static void
+SetGeneratedPage(wxWebView* webview, const wxString& html, const wxString& baseUrl)
+{
+    auto it = s_generated_pages.find(webview);
+    if (it == s_generated_pages.end()) {
+        it = s_generated_pages.emplace(webview, GeneratedPage()).first;
+        webview->Bind(wxEVT_WEBVIEW_LOADED, [webview](wxWebViewEvent& event) {
+            event.Skip();
+            auto loaded = s_generated_pages.find(webview);
+            if (loaded == s_generated_pages.end() || !loaded->second.shown)
+                return;
+            GeneratedPage& page = loaded->second;
+            wxString url = webview->GetCurrentURL();
+            if (page.url.empty())
+                page.url = url;
+            else if (page.url != url)
+                page.shown = false; // Navigated away from the generated page
+        });
+        webview->Bind(wxEVT_DESTROY, [webview](wxWindowDestroyEvent& event) {
+            event.Skip();
+            if (event.GetEventObject() == webview)
+                s_generated_pages.erase(webview);
+        });
+    }
+    it->second.html = html;
+    it->second.baseUrl = baseUrl;
+    ShowGeneratedPage(webview, it->second);
+}
+
 WXD_EXPORTED void
 wxd_WebView_LoadURL(wxd_WebView_t* self, const char* url)
 {
     wxWebView* webview = (wxWebView*)self;
     if (webview && url) {
+        auto page = s_generated_pages.find(webview);
+        if (page != s_generated_pages.end())
+            page->second.shown = false;
         webview->LoadURL(wxString::FromUTF8(url));
     }
 }
@@ -106,9 +937,16 @@ WXD_EXPORTED void
 wxd_WebView_Reload(wxd_WebView_t* self, int flags)
 {
     wxWebView* webview = (wxWebView*)self;
-    if (webview) {
-        webview->Reload((wxWebViewReloadFlags)flags);
+    if (!webview)
+        return;
+
+    auto page = s_generated_pages.find(webview);
+    if (page != s_generated_pages.end() && page->second.shown &&
+        (page->second.url.empty() || page->second.url == webview->GetCurrentURL())) {
+        ShowGeneratedPage(webview, page->second);
+        return;
     }
+    webview->Reload((wxWebViewReloadFlags)flags);
 }
 
 WXD_EXPORTED void
@@ -303,6 +1141,85 @@ wxd_WebView_RunScript(wxd_WebView_t* self, const char* javascript, char* output,
     return 0;
 }
 
+// A wxd_WebView_RunScriptAsync call waiting for its wxEVT_WEBVIEW_SCRIPT_RESULT.
+// The request is the event's client data.
+struct ScriptRequest {
+    wxd_WebViewScriptCallback callback;
+    void* userdata;
+};
+
+static std::unordered_map<wxWebView*, std::unordered_set<ScriptRequest*>> s_script_requests;
+
+// Calls the request's callback unless it was already completed.
+static void
+CompleteScriptRequest(wxWebView* webview, ScriptRequest* request, bool success,
+                      const wxString& result)
+{
+    auto it = s_script_requests.find(webview);
+    if (it == s_script_requests.end() || !it->second.erase(request))
+        return;
+    std::unique_ptr<ScriptRequest> owned(request);
+    owned->callback(success, result.utf8_str().data(), owned->userdata);
+}
+
+static void
+TrackScriptRequest(wxWebView* webview, ScriptRequest* request)
+{
+    auto it = s_script_requests.find(webview);
+    if (it == s_script_requests.end()) {
+        it = s_script_requests.emplace(webview, std::unordered_set<ScriptRequest*>()).first;
+        webview->Bind(wxEVT_WEBVIEW_SCRIPT_RESULT, [webview](wxWebViewEvent& event) {
+            event.Skip();
+            CompleteScriptRequest(webview, static_cast<ScriptRequest*>(event.GetClientData()),
+                                  !event.IsError(), event.GetString());
+        });
+        webview->Bind(wxEVT_DESTROY, [webview](wxWindowDestroyEvent& event) {
+            event.Skip();
+            if (event.GetEventObject() != webview)
+                return;
+            auto pending = s_script_requests.find(webview);
+            if (pending == s_script_requests.end())
+                return;
+            std::unordered_set<ScriptRequest*> requests = std::move(pending->second);
+            s_script_requests.erase(pending);
+            for (ScriptRequest* request : requests) {
+                std::unique_ptr<ScriptRequest> owned(request);
+                owned->callback(false, "the webview was destroyed", owned->userdata);
+            }
+        });
+    }
+    it->second.insert(request);
+}
+
+WXD_EXPORTED void
+wxd_WebView_RunScriptAsync(wxd_WebView_t* self, const char* javascript,
+                           wxd_WebViewScriptCallback callback, void* userdata)
+{
+    wxWebView* webview = (wxWebView*)self;
+    if (!callback)
+        return;
+    if (!webview || !javascript) {
+        callback(false, "no webview or script", userdata);
+        return;
+    }
+
+    ScriptRequest* request = new ScriptRequest{ callback, userdata };
+    TrackScriptRequest(webview, request);
+
+    wxString script = wxString::FromUTF8(javascript);
+    if (webview->GetClassInfo()->GetClassName() == wxString("wxWebViewIE")) {
+        // IE has no asynchronous scripts; run it now and report from the event loop
+        // so the callback is never called before this function returns.
+        wxString result;
+        bool success = webview->RunScript(script, &result);
+        webview->CallAfter([webview, request, success, result]() {
+            CompleteScriptRequest(webview, request, success, result);
+        });
+        return;
+    }
+    webview->RunScriptAsync(script, request);
+}
+
 WXD_EXPORTED bool
 wxd_WebView_CanCut(wxd_WebView_t* self)
 {
@@ -450,6 +1367,69 @@ wxd_WebView_Print(wxd_WebView_t* self)
         webview->Print();
 }
 
+WXD_EXPORTED void
+wxd_WebView_CaptureSnapshot(wxd_WebView_t* self, bool full_page,
+                            wxd_WebViewSnapshotCallback callback, void* userdata)
+{
+    wxWebView* webview = (wxWebView*)self;
+    if (!callback)
+        return;
+    if (!webview) {
+        callback(nullptr, 0, "the webview has been destroyed", userdata);
+        return;
+    }
+
+    CaptureSnapshot(webview, full_page,
+                    [callback, userdata](const wxMemoryBuffer& png, const wxString& error) {
+                        if (!error.empty()) {
+                            callback(nullptr, 0, error.utf8_str(), userdata);
+                            return;
+                        }
+                        callback((const unsigned char*)png.GetData(), png.GetDataLen(), nullptr,
+                                 userdata);
+                    });
+}
+
+WXD_EXPORTED void
+wxd_WebView_CaptureSnapshotSync(wxd_WebView_t* self, bool full_page,
+                                wxd_WebViewSnapshotCallback callback, void* userdata)
+{
+    struct Snapshot
+    {
+        bool done = false;
+        wxMemoryBuffer png;
+        wxString error;
+    };
+    // Shared with the capture so a reply arriving after the timeout has somewhere to go
+    auto snapshot = std::make_shared<Snapshot>();
+
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview) {
+        snapshot->done = true;
+        snapshot->error = "the webview has been destroyed";
+    }
+    else {
+        CaptureSnapshot(webview, full_page,
+                        [snapshot](const wxMemoryBuffer& png, const wxString& error) {
+                            snapshot->done = true;
+                            snapshot->png = png;
+                            snapshot->error = error;
+                        });
+    }
+
+    YieldUntil(snapshot->done, 5000);
+
+    if (!callback)
+        return;
+    if (!snapshot->done)
+        callback(nullptr, 0, "timed out waiting for the snapshot", userdata);
+    else if (!snapshot->error.empty())
+        callback(nullptr, 0, snapshot->error.utf8_str(), userdata);
+    else
+        callback((const unsigned char*)snapshot->png.GetData(), snapshot->png.GetDataLen(), nullptr,
+                 userdata);
+}
+
 // Context Menu & Dev Tools
 WXD_EXPORTED void
 wxd_WebView_EnableContextMenu(wxd_WebView_t* self, bool enable)
@@ -553,7 +1533,7 @@ wxd_WebView_SetPage(wxd_WebView_t* self, const char* html, const char* baseUrl)
     if (webview && html) {
         wxString htmlStr = wxString::FromUTF8(html);
         wxString baseUrlStr = baseUrl ? wxString::FromUTF8(baseUrl) : wxString();
-        webview->SetPage(htmlStr, baseUrlStr);
+        SetGeneratedPage(webview, htmlStr, baseUrlStr);
     }
 }
 
@@ -611,6 +1591,38 @@ wxd_WebView_SetProxy(wxd_WebView_t* self, const char* proxy)
 }
 
 // Advanced Scripting
+
+// Script message handlers added before the backend was ready. Edge creates its browser
+// asynchronously and has no native backend until then, so the handlers are added when it sends
+// wxEVT_WEBVIEW_CREATED instead of failing.
+static std::unordered_map<wxWebView*, std::vector<wxString>> s_pending_script_handlers;
+
+static void
+AddPendingScriptHandler(wxWebView* webview, const wxString& name)
+{
+    auto it = s_pending_script_handlers.find(webview);
+    if (it == s_pending_script_handlers.end()) {
+        it = s_pending_script_handlers.emplace(webview, std::vector<wxString>()).first;
+        webview->Bind(wxEVT_WEBVIEW_CREATED, [webview](wxWebViewEvent& event) {
+            event.Skip();
+            auto pending = s_pending_script_handlers.find(webview);
+            if (pending == s_pending_script_handlers.end())
+                return;
+            std::vector<wxString> names = std::move(pending->second);
+            s_pending_script_handlers.erase(pending);
+            for (const wxString& handler : names)
+                webview->AddScriptMessageHandler(handler);
+        });
+        webview->Bind(wxEVT_DESTROY, [webview](wxWindowDestroyEvent& event) {
+            event.Skip();
+            if (event.GetEventObject() == webview)
+                s_pending_script_handlers.erase(webview);
+        });
+    }
+    if (std::find(it->second.begin(), it->second.end(), name) == it->second.end())
+        it->second.push_back(name);
+}
+
 WXD_EXPORTED bool
 wxd_WebView_AddScriptMessageHandler(wxd_WebView_t* self, const char* name)
 {
@@ -619,6 +1631,10 @@ wxd_WebView_AddScriptMessageHandler(wxd_WebView_t* self, const char* name)
         return false;
 
     wxString nameStr = wxString::FromUTF8(name);
+    if (!webview->GetNativeBackend()) {
+        AddPendingScriptHandler(webview, nameStr);
+        return true;
+    }
     return webview->AddScriptMessageHandler(nameStr);
 }
 
@@ -630,9 +1646,145 @@ wxd_WebView_RemoveScriptMessageHandler(wxd_WebView_t* self, const char* name)
         return false;
 
     wxString nameStr = wxString::FromUTF8(name);
+    auto pending = s_pending_script_handlers.find(webview);
+    if (pending != s_pending_script_handlers.end()) {
+        std::vector<wxString>& names = pending->second;
+        auto it = std::find(names.begin(), names.end(), nameStr);
+        if (it != names.end()) {
+            names.erase(it);
+            return true;
+        }
+    }
     return webview->RemoveScriptMessageHandler(nameStr);
 }
 
+WXD_EXPORTED int
+wxd_WebViewEvent_GetMessageHandler(wxd_Event_t* event, char* buffer, int len)
+{
+    wxWebViewEvent* webview_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxWebViewEvent);
+    if (!webview_event)
+        return -1;
+
+    return wxd_cpp_utils::copy_wxstring_to_buffer(webview_event->GetMessageHandler(), buffer, len);
+}
+
+WXD_EXPORTED int
+wxd_WebViewEvent_GetURL(wxd_Event_t* event, char* buffer, int len)
+{
+    wxWebViewEvent* webview_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxWebViewEvent);
+    if (!webview_event)
+        return -1;
+
+    return wxd_cpp_utils::copy_wxstring_to_buffer(webview_event->GetURL(), buffer, len);
+}
+
+WXD_EXPORTED int
+wxd_WebViewEvent_GetTarget(wxd_Event_t* event, char* buffer, int len)
+{
+    wxWebViewEvent* webview_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxWebViewEvent);
+    if (!webview_event)
+        return -1;
+
+    return wxd_cpp_utils::copy_wxstring_to_buffer(webview_event->GetTarget(), buffer, len);
+}
+
+WXD_EXPORTED int
+wxd_WebViewEvent_GetDownloadFilename(wxd_Event_t* event, char* buffer, int len)
+{
+    wxdWebViewDownloadEvent* download = AsDownloadEvent(event);
+    if (!download)
+        return -1;
+
+    wxString name = wxFileName(download->m_path).GetFullName();
+    return wxd_cpp_utils::copy_wxstring_to_buffer(name, buffer, len);
+}
+
+WXD_EXPORTED int
+wxd_WebViewEvent_GetDownloadPath(wxd_Event_t* event, char* buffer, int len)
+{
+    wxdWebViewDownloadEvent* download = AsDownloadEvent(event);
+    if (!download)
+        return -1;
+
+    const wxString& path = download->m_targetPath.empty() ? download->m_path
+                                                          : download->m_targetPath;
+    return wxd_cpp_utils::copy_wxstring_to_buffer(path, buffer, len);
+}
+
+WXD_EXPORTED int
+wxd_WebViewEvent_GetDownloadMimeType(wxd_Event_t* event, char* buffer, int len)
+{
+    wxdWebViewDownloadEvent* download = AsDownloadEvent(event);
+    if (!download)
+        return -1;
+
+    return wxd_cpp_utils::copy_wxstring_to_buffer(download->m_mimeType, buffer, len);
+}
+
+WXD_EXPORTED bool
+wxd_WebViewEvent_SetDownloadPath(wxd_Event_t* event, const char* path)
+{
+    wxdWebViewDownloadEvent* download = AsDownloadEvent(event);
+    if (!download || !path || download->GetEventType() != wxdEVT_WEBVIEW_DOWNLOAD_REQUEST)
+        return false;
+
+    download->m_targetPath = wxString::FromUTF8(path);
+    return true;
+}
+
+WXD_EXPORTED bool
+wxd_WebViewEvent_GetDownloadBytes(wxd_Event_t* event, int64_t* received, int64_t* total)
+{
+    wxdWebViewDownloadEvent* download = AsDownloadEvent(event);
+    if (!download)
+        return false;
+
+    if (received)
+        *received = download->m_bytesReceived;
+    if (total)
+        *total = download->m_totalBytes;
+    return true;
+}
+
+WXD_EXPORTED int
+wxd_WebViewEvent_GetDownloadState(wxd_Event_t* event)
+{
+    wxdWebViewDownloadEvent* download = AsDownloadEvent(event);
+    return download ? (int)download->m_state : -1;
+}
+
+WXD_EXPORTED bool
+wxd_WebView_EnableDownloadEvents(wxd_WebView_t* self)
+{
+    return EnableDownloadEvents((wxWebView*)self);
+}
+
+WXD_EXPORTED bool
+wxd_WebView_SupportsDownloads(wxd_WebView_t* self)
+{
+    wxWebView* webview = (wxWebView*)self;
+    return webview && BackendSupportsDownloads(webview);
+}
+
+WXD_EXPORTED bool
+wxd_WebViewEvent_LoadInCurrent(wxd_Event_t* event)
+{
+    wxWebViewEvent* webview_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxWebViewEvent);
+    if (!webview_event)
+        return false;
+
+    wxWebView* webview = wxDynamicCast(webview_event->GetEventObject(), wxWebView);
+    wxString url = webview_event->GetURL();
+    if (!webview || url.empty())
+        return false;
+
+    // No new window either way; loading is deferred because the backend is still
+    // inside its new-window callback. Pending calls are dropped if the webview dies.
+    webview_event->Veto();
+    webview->CallAfter([webview, url]() { webview->LoadURL(url); });
+    return true;
+}
+
 WXD_EXPORTED bool
 wxd_WebView_AddUserScript(wxd_WebView_t* self, const char* javascript, int injectionTime)
 {
@@ -672,6 +1824,30 @@ wxd_WebView_RegisterHandler(wxd_WebView_t* self, const char* scheme,
         new WxdRustWebViewHandler(schemeStr, callback, free_data, drop_userdata, userdata)));
 }
 
+WXD_EXPORTED void
+wxd_WebView_RegisterArchiveHandler(wxd_WebView_t* self, const char* scheme)
+{
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview || !scheme)
+        return;
+
+    wxd_WebViewHandlerDesc desc = { scheme, WXD_WEBVIEW_HANDLER_ARCHIVE, nullptr, nullptr, nullptr,
+                                    nullptr };
+    RegisterHandlers(webview, &desc, 1);
+}
+
+WXD_EXPORTED void
+wxd_WebView_RegisterFSHandler(wxd_WebView_t* self, const char* scheme)
+{
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview || !scheme)
+        return;
+
+    wxd_WebViewHandlerDesc desc = { scheme, WXD_WEBVIEW_HANDLER_FILESYSTEM, nullptr, nullptr,
+                                    nullptr, nullptr };
+    RegisterHandlers(webview, &desc, 1);
+}
+
 // Native Backend
 WXD_EXPORTED void*
 wxd_WebView_GetNativeBackend(wxd_WebView_t* self)
@@ -699,6 +1875,138 @@ wxd_WebView_IsBackendAvailable(const char* backend)
     return wxWebView::IsBackendAvailable(backendStr);
 }
 
+// Browsing Data
+WXD_EXPORTED bool
+wxd_WebView_SupportsBrowsingDataClearing(wxd_WebView_t* self)
+{
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview)
+        return false;
+
+    // The backends implementing wxWebView::ClearBrowsingData()
+    wxString backend = webview->GetClassInfo()->GetClassName();
+    return backend == "wxWebViewEdge" || backend == "wxWebViewWebKit";
+}
+
+WXD_EXPORTED bool
+wxd_WebView_ClearBrowsingData(wxd_WebView_t* self, int types, int64_t since)
+{
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview || !wxd_WebView_SupportsBrowsingDataClearing(self))
+        return false;
+
+    // A negative `since` clears data of any age
+    wxDateTime from((time_t)(since < 0 ? 0 : since));
+    return webview->ClearBrowsingData(types, from);
+}
+
+// Cookies
+WXD_EXPORTED bool
+wxd_WebView_SupportsCookies(wxd_WebView_t* self)
+{
+#if WXD_WEBVIEW_EDGE_COM
+    wxWebView* webview = (wxWebView*)self;
+    return webview && wxDynamicCast(webview, wxWebViewEdge) != nullptr;
+#else
+    wxUnusedVar(self);
+    return false;
+#endif
+}
+
+WXD_EXPORTED int
+wxd_WebView_GetCookie(wxd_WebView_t* self, const char* name, const char* url, char* buffer,
+                      int len)
+{
+#if WXD_WEBVIEW_EDGE_COM
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview || !name || !url)
+        return -1;
+    ComPtr<ICoreWebView2CookieManager> manager = GetEdgeCookieManager(webview);
+    std::vector<ComPtr<ICoreWebView2Cookie>> cookies;
+    if (!manager || !GetEdgeCookies(manager.Get(), wxString::FromUTF8(url), cookies))
+        return -1;
+
+    wxString nameStr = wxString::FromUTF8(name);
+    for (const ComPtr<ICoreWebView2Cookie>& cookie : cookies) {
+        LPWSTR str = nullptr;
+        if (FAILED(cookie->get_Name(&str)) || TakeCoTaskString(str) != nameStr)
+            continue;
+        str = nullptr;
+        if (FAILED(cookie->get_Value(&str)))
+            return -1;
+        return wxd_cpp_utils::copy_wxstring_to_buffer(TakeCoTaskString(str), buffer, len);
+    }
+    return -1;
+#else
+    wxUnusedVar(self);
+    wxUnusedVar(name);
+    wxUnusedVar(url);
+    wxUnusedVar(buffer);
+    wxUnusedVar(len);
+    return -1;
+#endif
+}
+
+WXD_EXPORTED bool
+wxd_WebView_SetCookie(wxd_WebView_t* self, const char* name, const char* value, const char* domain,
+                      const char* path, double expires, bool http_only, bool secure)
+{
+#if WXD_WEBVIEW_EDGE_COM
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview || !name || !value || !domain)
+        return false;
+    ComPtr<ICoreWebView2CookieManager> manager = GetEdgeCookieManager(webview);
+    if (!manager)
+        return false;
+
+    wxString pathStr = path ? wxString::FromUTF8(path) : wxString("/");
+    ComPtr<ICoreWebView2Cookie> cookie;
+    if (FAILED(manager->CreateCookie(wxString::FromUTF8(name).wc_str(),
+                                     wxString::FromUTF8(value).wc_str(),
+                                     wxString::FromUTF8(domain).wc_str(), pathStr.wc_str(),
+                                     &cookie)))
+        return false;
+    // WebView2 treats -1 as a session cookie, like the caller
+    cookie->put_Expires(expires < 0 ? -1 : expires);
+    cookie->put_IsHttpOnly(http_only ? TRUE : FALSE);
+    cookie->put_IsSecure(secure ? TRUE : FALSE);
+    return SUCCEEDED(manager->AddOrUpdateCookie(cookie.Get()));
+#else
+    wxUnusedVar(self);
+    wxUnusedVar(name);
+    wxUnusedVar(value);
+    wxUnusedVar(domain);
+    wxUnusedVar(path);
+    wxUnusedVar(expires);
+    wxUnusedVar(http_only);
+    wxUnusedVar(secure);
+    return false;
+#endif
+}
+
+WXD_EXPORTED bool
+wxd_WebView_DeleteCookies(wxd_WebView_t* self, const char* url)
+{
+#if WXD_WEBVIEW_EDGE_COM
+    wxWebView* webview = (wxWebView*)self;
+    if (!webview || !url)
+        return false;
+    ComPtr<ICoreWebView2CookieManager> manager = GetEdgeCookieManager(webview);
+    std::vector<ComPtr<ICoreWebView2Cookie>> cookies;
+    if (!manager || !GetEdgeCookies(manager.Get(), wxString::FromUTF8(url), cookies))
+        return false;
+
+    bool ok = true;
+    for (const ComPtr<ICoreWebView2Cookie>& cookie : cookies)
+        ok = SUCCEEDED(manager->DeleteCookie(cookie.Get())) && ok;
+    return ok;
+#else
+    wxUnusedVar(self);
+    wxUnusedVar(url);
+    return false;
+#endif
+}
+
 } // extern "C"
 
 #endif // wxdUSE_WEBVIEW
@@ -0,0 +1,42 @@
+#import <AppKit/AppKit.h>
+#import <WebKit/WebKit.h>
+#include "../include/wxdragon.h"
+#include <wx/buffer.h>
+
+#include <functional>
+
+// Snapshots the visible part of a WKWebView as PNG. `done` receives the PNG bytes,
+// or an empty buffer and the reason it failed.
+void
+wxdOSXCaptureWebViewSnapshot(void* native,
+                             std::function<void(const wxMemoryBuffer&, const wxString&)> done)
+{
+    WKWebView* view = (__bridge WKWebView*)native;
+    if (![view isKindOfClass:[WKWebView class]]) {
+        done(wxMemoryBuffer(), "the WebKit backend has no WKWebView");
+        return;
+    }
+
+    [view takeSnapshotWithConfiguration:nil
+                      completionHandler:^(NSImage* image, NSError* error) {
+                          CGImageRef cgImage =
+                              image ? [image CGImageForProposedRect:NULL context:nil hints:nil]
+                                    : NULL;
+                          if (!cgImage) {
+                              wxString reason = "WKWebView could not capture the page";
+                              if (error)
+                                  reason = wxString::FromUTF8(
+                                      [[error localizedDescription] UTF8String]);
+                              done(wxMemoryBuffer(), reason);
+                              return;
+                          }
+                          NSBitmapImageRep* rep =
+                              [[NSBitmapImageRep alloc] initWithCGImage:cgImage];
+                          NSData* data = [rep representationUsingType:NSBitmapImageFileTypePNG
+                                                           properties:@{}];
+                          wxMemoryBuffer png;
+                          if (data)
+                              png.AppendData([data bytes], [data length]);
+                          done(png, png.IsEmpty() ? "could not encode the snapshot as PNG" : "");
+                      }];
+}
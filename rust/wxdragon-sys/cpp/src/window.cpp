@@ -135,6 +135,17 @@ wxd_Window_Update(wxd_Window_t* window)
     }
 }
 
+WXD_EXPORTED bool
+wxd_Window_SendIdleEvents(wxd_Window_t* window)
+{
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window) {
+        return false;
+    }
+    wxIdleEvent event;
+    return wx_window->SendIdleEvents(event);
+}
+
 WXD_EXPORTED void
 wxd_Window_Fit(wxd_Window_t* window)
 {
@@ -418,6 +429,28 @@ wxd_Window_GetBackgroundStyle(wxd_Window_t* window)
     return static_cast<int>(wxBG_STYLE_SYSTEM); // Default fallback
 }
 
+WXD_EXPORTED void
+wxd_Window_SetDoubleBuffered(wxd_Window_t* window, bool on)
+{
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+#if defined(__WXMSW__) || defined(__WXGTK__)
+    if (wx_window) {
+        wx_window->SetDoubleBuffered(on);
+    }
+#else
+    // macOS windows are always double buffered
+    wxUnusedVar(wx_window);
+    wxUnusedVar(on);
+#endif
+}
+
+WXD_EXPORTED bool
+wxd_Window_IsDoubleBuffered(wxd_Window_t* window)
+{
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    return wx_window && wx_window->IsDoubleBuffered();
+}
+
 WXD_EXPORTED wxd_Point
 wxd_Window_ClientToScreen(wxd_Window_t* window, wxd_Point pt)
 {
@@ -636,6 +669,14 @@ wxd_Window_SetCursor(wxd_Window_t* window, wxd_Cursor_t* cursor)
             // Set to default cursor if null is passed
             wx_window->SetCursor(wxNullCursor);
         }
+#ifdef __WXMSW__
+        // Windows doesn't send WM_SETCURSOR to a window capturing the mouse, so during a
+        // drag the new cursor would only show once the capture is released
+        if (wxWindow::GetCapture() == wx_window) {
+            const wxCursor& current = wx_window->GetCursor();
+            wxSetCursor(current.IsOk() ? current : *wxSTANDARD_CURSOR);
+        }
+#endif
     }
 }
 
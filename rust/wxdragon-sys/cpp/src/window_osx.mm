@@ -40,3 +40,18 @@ wxd_App_ActivateMac(void)
     [[NSRunningApplication currentApplication]
         activateWithOptions:NSApplicationActivateIgnoringOtherApps];
 }
+
+bool
+wxdOSXHasMarkedText(wxWindow* window)
+{
+    NSView* view = window ? window->GetHandle() : nil;
+    // Multi-line text controls are a scroll view around the text view, single-line
+    // ones and combo boxes edit through the window's field editor while focused
+    if ([view isKindOfClass:[NSScrollView class]])
+        view = [(NSScrollView*)view documentView];
+    if ([view isKindOfClass:[NSControl class]])
+        view = [(NSControl*)view currentEditor];
+    if ([view conformsToProtocol:@protocol(NSTextInputClient)])
+        return [(id<NSTextInputClient>)view hasMarkedText];
+    return false;
+}
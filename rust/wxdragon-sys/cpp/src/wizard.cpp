@@ -0,0 +1,223 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "wxdragon.h"
+#include <wx/wizard.h>
+
+// Implemented in Rust: frees the closure passed as user_data to the link callbacks.
+extern "C" void
+wxd_Drop_Rust_WizardPageLink(void* user_data);
+
+namespace {
+
+struct WxdWizardPageLink {
+    wxd_WizardPage_LinkCallback callback = nullptr;
+    void* user_data = nullptr;
+
+    void Reset(wxd_WizardPage_LinkCallback cb, void* data)
+    {
+        if (user_data)
+            wxd_Drop_Rust_WizardPageLink(user_data);
+        callback = cb;
+        user_data = data;
+    }
+};
+
+// wxWizardPageSimple whose next/previous page can be decided at runtime by Rust.
+// Without a callback it behaves exactly like wxWizardPageSimple.
+class WxdWizardPage : public wxWizardPageSimple {
+public:
+    explicit WxdWizardPage(wxWizard* parent) : wxWizardPageSimple(parent) {}
+
+    ~WxdWizardPage() override
+    {
+        m_nextLink.Reset(nullptr, nullptr);
+        m_prevLink.Reset(nullptr, nullptr);
+    }
+
+    wxWizardPage* GetNext() const override
+    {
+        if (m_nextLink.callback)
+            return reinterpret_cast<wxWizardPage*>(m_nextLink.callback(m_nextLink.user_data));
+        return wxWizardPageSimple::GetNext();
+    }
+
+    wxWizardPage* GetPrev() const override
+    {
+        if (m_prevLink.callback)
+            return reinterpret_cast<wxWizardPage*>(m_prevLink.callback(m_prevLink.user_data));
+        return wxWizardPageSimple::GetPrev();
+    }
+
+    WxdWizardPageLink m_nextLink;
+    WxdWizardPageLink m_prevLink;
+};
+
+} // namespace
+
+extern "C" {
+
+wxd_Wizard_t*
+wxd_Wizard_Create(wxd_Window_t* parent, int id, const char* title, const wxd_Bitmap_t* bitmap,
+                  int x, int y, wxd_Style_t style)
+{
+    wxWindow* wx_parent = (wxWindow*)parent;
+    wxPoint pos = (x == -1 && y == -1) ? wxDefaultPosition : wxPoint(x, y);
+
+    wxBitmapBundle bundle;
+    const wxBitmap* bmp = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (bmp && bmp->IsOk())
+        bundle = wxBitmapBundle(*bmp);
+
+    wxWizard* wizard = new wxWizard();
+    if (!wizard->Create(wx_parent, id, WXD_STR_TO_WX_STRING_UTF8_NULL_OK(title), bundle, pos,
+                        style)) {
+        delete wizard;
+        return nullptr;
+    }
+    return (wxd_Wizard_t*)wizard;
+}
+
+void
+wxd_Wizard_AddPageToSizer(wxd_Wizard_t* self, wxd_WizardPage_t* page)
+{
+    if (!self || !page)
+        return;
+    ((wxWizard*)self)->GetPageAreaSizer()->Add((wxWizardPage*)page);
+}
+
+bool
+wxd_Wizard_RunWizard(wxd_Wizard_t* self, wxd_WizardPage_t* firstPage)
+{
+    if (!self || !firstPage)
+        return false;
+    return ((wxWizard*)self)->RunWizard((wxWizardPage*)firstPage);
+}
+
+wxd_WizardPage_t*
+wxd_Wizard_GetCurrentPage(const wxd_Wizard_t* self)
+{
+    if (!self)
+        return nullptr;
+    return (wxd_WizardPage_t*)((const wxWizard*)self)->GetCurrentPage();
+}
+
+bool
+wxd_Wizard_HasNextPage(wxd_Wizard_t* self, wxd_WizardPage_t* page)
+{
+    if (!self || !page)
+        return false;
+    return ((wxWizard*)self)->HasNextPage((wxWizardPage*)page);
+}
+
+bool
+wxd_Wizard_HasPrevPage(wxd_Wizard_t* self, wxd_WizardPage_t* page)
+{
+    if (!self || !page)
+        return false;
+    return ((wxWizard*)self)->HasPrevPage((wxWizardPage*)page);
+}
+
+void
+wxd_Wizard_SetPageSize(wxd_Wizard_t* self, int width, int height)
+{
+    if (!self)
+        return;
+    ((wxWizard*)self)->SetPageSize(wxSize(width, height));
+}
+
+void
+wxd_Wizard_SetBorder(wxd_Wizard_t* self, int border)
+{
+    if (!self)
+        return;
+    ((wxWizard*)self)->SetBorder(border);
+}
+
+wxd_WizardPage_t*
+wxd_WizardPage_Create(wxd_Wizard_t* wizard)
+{
+    if (!wizard)
+        return nullptr;
+    return (wxd_WizardPage_t*)new WxdWizardPage((wxWizard*)wizard);
+}
+
+void
+wxd_WizardPage_SetPrev(wxd_WizardPage_t* self, wxd_WizardPage_t* prev)
+{
+    wxWizardPageSimple* page = wxDynamicCast((wxWizardPage*)self, wxWizardPageSimple);
+    if (!page)
+        return;
+    page->SetPrev((wxWizardPage*)prev);
+}
+
+void
+wxd_WizardPage_SetNext(wxd_WizardPage_t* self, wxd_WizardPage_t* next)
+{
+    wxWizardPageSimple* page = wxDynamicCast((wxWizardPage*)self, wxWizardPageSimple);
+    if (!page)
+        return;
+    page->SetNext((wxWizardPage*)next);
+}
+
+wxd_WizardPage_t*
+wxd_WizardPage_GetPrev(const wxd_WizardPage_t* self)
+{
+    if (!self)
+        return nullptr;
+    return (wxd_WizardPage_t*)((const wxWizardPage*)self)->GetPrev();
+}
+
+wxd_WizardPage_t*
+wxd_WizardPage_GetNext(const wxd_WizardPage_t* self)
+{
+    if (!self)
+        return nullptr;
+    return (wxd_WizardPage_t*)((const wxWizardPage*)self)->GetNext();
+}
+
+void
+wxd_WizardPage_SetNextCallback(wxd_WizardPage_t* self, wxd_WizardPage_LinkCallback callback,
+                               void* user_data)
+{
+    WxdWizardPage* page = dynamic_cast<WxdWizardPage*>((wxWizardPage*)self);
+    if (!page) {
+        // Not one of our pages: nobody else would free the closure.
+        if (user_data)
+            wxd_Drop_Rust_WizardPageLink(user_data);
+        return;
+    }
+    page->m_nextLink.Reset(callback, user_data);
+}
+
+void
+wxd_WizardPage_SetPrevCallback(wxd_WizardPage_t* self, wxd_WizardPage_LinkCallback callback,
+                               void* user_data)
+{
+    WxdWizardPage* page = dynamic_cast<WxdWizardPage*>((wxWizardPage*)self);
+    if (!page) {
+        if (user_data)
+            wxd_Drop_Rust_WizardPageLink(user_data);
+        return;
+    }
+    page->m_prevLink.Reset(callback, user_data);
+}
+
+bool
+wxd_WizardEvent_GetDirection(wxd_Event_t* event)
+{
+    wxWizardEvent* evt = wxDynamicCast((wxEvent*)event, wxWizardEvent);
+    if (!evt)
+        return false;
+    return evt->GetDirection();
+}
+
+wxd_WizardPage_t*
+wxd_WizardEvent_GetPage(wxd_Event_t* event)
+{
+    wxWizardEvent* evt = wxDynamicCast((wxEvent*)event, wxWizardEvent);
+    if (!evt)
+        return nullptr;
+    return (wxd_WizardPage_t*)evt->GetPage();
+}
+
+} // extern "C"
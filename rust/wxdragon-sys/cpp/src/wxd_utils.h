@@ -6,6 +6,8 @@
 #include <wx/window.h>            // For wxWindow and FromDIP/ToDIP functions
 #include "../include/wxd_types.h" // For wxd_Point, wxd_Size (CHANGED from wxdragon.h)
 #include <wx/colour.h>            // For wxColour type
+#include <wx/image.h>             // For wxImage handlers
+#include <wx/dataobj.h>           // For wxDataFormat
 
 #ifdef __cplusplus
 // Helper macro to convert const char* to wxString, handling nulls and UTF-8
@@ -75,6 +77,93 @@ to_wx(const wxd_Size& s)
 size_t
 copy_wxstring_to_buffer(const wxString& str, char* buffer, size_t buffer_len);
 
+// Converts a wxd_BitmapType to the wxWidgets file type
+inline wxBitmapType
+to_wx_bitmap_type(wxd_BitmapType type)
+{
+    switch (type) {
+    case WXD_BITMAP_TYPE_BMP:
+        return wxBITMAP_TYPE_BMP;
+    case WXD_BITMAP_TYPE_ICO:
+        return wxBITMAP_TYPE_ICO;
+    case WXD_BITMAP_TYPE_CUR:
+        return wxBITMAP_TYPE_CUR;
+    case WXD_BITMAP_TYPE_XBM:
+        return wxBITMAP_TYPE_XBM;
+    case WXD_BITMAP_TYPE_XPM:
+        return wxBITMAP_TYPE_XPM;
+    case WXD_BITMAP_TYPE_PNG:
+        return wxBITMAP_TYPE_PNG;
+    case WXD_BITMAP_TYPE_JPEG:
+        return wxBITMAP_TYPE_JPEG;
+    case WXD_BITMAP_TYPE_GIF:
+        return wxBITMAP_TYPE_GIF;
+    case WXD_BITMAP_TYPE_ANI:
+        return wxBITMAP_TYPE_ANI;
+    case WXD_BITMAP_TYPE_ANY:
+        return wxBITMAP_TYPE_ANY;
+    default:
+        return wxBITMAP_TYPE_ANY;
+    }
+}
+
+// Converts a wxd_ShowEffect value to the wxWidgets effect
+inline wxShowEffect
+to_wx_show_effect(int effect)
+{
+    switch (effect) {
+    case WXD_SHOW_EFFECT_ROLL_TO_LEFT:
+        return wxSHOW_EFFECT_ROLL_TO_LEFT;
+    case WXD_SHOW_EFFECT_ROLL_TO_RIGHT:
+        return wxSHOW_EFFECT_ROLL_TO_RIGHT;
+    case WXD_SHOW_EFFECT_ROLL_TO_TOP:
+        return wxSHOW_EFFECT_ROLL_TO_TOP;
+    case WXD_SHOW_EFFECT_ROLL_TO_BOTTOM:
+        return wxSHOW_EFFECT_ROLL_TO_BOTTOM;
+    case WXD_SHOW_EFFECT_SLIDE_TO_LEFT:
+        return wxSHOW_EFFECT_SLIDE_TO_LEFT;
+    case WXD_SHOW_EFFECT_SLIDE_TO_RIGHT:
+        return wxSHOW_EFFECT_SLIDE_TO_RIGHT;
+    case WXD_SHOW_EFFECT_SLIDE_TO_TOP:
+        return wxSHOW_EFFECT_SLIDE_TO_TOP;
+    case WXD_SHOW_EFFECT_SLIDE_TO_BOTTOM:
+        return wxSHOW_EFFECT_SLIDE_TO_BOTTOM;
+    case WXD_SHOW_EFFECT_BLEND:
+        return wxSHOW_EFFECT_BLEND;
+    case WXD_SHOW_EFFECT_EXPAND:
+        return wxSHOW_EFFECT_EXPAND;
+    default:
+        return wxSHOW_EFFECT_NONE;
+    }
+}
+
+// Custom data formats are named like MIME types, e.g. "application/x-myapp-node". wx registers
+// the name as a clipboard format on Windows and an atom on GTK, but macOS pasteboards only take
+// UTIs, so there it becomes "application.x-myapp-node". Drag sources, drop targets and the
+// clipboard all create custom formats here so they agree on the platform format.
+inline wxDataFormat
+custom_data_format(const wxString& name)
+{
+#ifdef __WXOSX__
+    wxString uti;
+    for (wxString::const_iterator it = name.begin(); it != name.end(); ++it) {
+        const wxUniChar c = *it;
+        uti += (c.IsAscii() && (wxIsalnum(c) || c == '-' || c == '.')) ? c : wxUniChar('.');
+    }
+    return wxDataFormat(uti);
+#else
+    return wxDataFormat(name);
+#endif
+}
+
+// Applications register the image handlers at startup; this covers use before (or without) wxApp
+inline void
+ensure_image_handlers()
+{
+    if (!wxImage::FindHandler(wxBITMAP_TYPE_PNG))
+        wxInitAllImageHandlers();
+}
+
 }
 
 // Helper to convert wxd_Colour_t representation (unsigned long RGBA) to wxColour
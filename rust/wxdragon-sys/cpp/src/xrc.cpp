@@ -10,6 +10,151 @@
 #include <wx/panel.h>
 #include <wx/filename.h>
 #include <wx/file.h>
+#include <wx/log.h>
+#include <wx/filesys.h>
+#include <wx/bitmap.h>
+#include <wx/icon.h>
+#if wxdUSE_AUI
+#include <wx/xrc/xh_aui.h>
+#endif
+#include <map>
+#include <set>
+
+// Implemented in Rust: frees the closure passed as user_data to RegisterSubclassFactory.
+extern "C" void
+wxd_Drop_Rust_XrcSubclassFactory(void* user_data);
+
+namespace {
+
+// Errors logged by the most recent load call, see wxd_XmlResource_GetLastError
+wxString g_lastXrcError;
+
+// Collects the errors wxXmlResource logs while loading, so they can be returned to the
+// caller instead of being shown in a log message box.
+class XrcErrorCapture : public wxLog {
+public:
+    XrcErrorCapture()
+    {
+        g_lastXrcError.clear();
+        m_previous = wxLog::SetActiveTarget(this);
+    }
+
+    ~XrcErrorCapture() override
+    {
+        wxLog::SetActiveTarget(m_previous);
+    }
+
+protected:
+    void DoLogTextAtLevel(wxLogLevel level, const wxString& msg) override
+    {
+        if (level > wxLOG_Warning)
+            return;
+        if (!g_lastXrcError.empty())
+            g_lastXrcError += "\n";
+        g_lastXrcError += msg;
+    }
+
+private:
+    wxLog* m_previous;
+};
+
+// Object names found in the documents loaded so far, plus names given an id explicitly.
+// wxXmlResource::GetXRCID() creates an id for any name, so this is what tells known
+// names from typos.
+std::set<wxString> g_knownXrcNames;
+
+void
+RememberObjectNames(const wxXmlNode* node)
+{
+    for (const wxXmlNode* child = node ? node->GetChildren() : nullptr; child;
+         child = child->GetNext()) {
+        if (child->GetType() != wxXML_ELEMENT_NODE)
+            continue;
+        if (child->GetName() == wxT("object") || child->GetName() == wxT("object_ref")) {
+            wxString name = child->GetAttribute(wxT("name"));
+            if (!name.empty())
+                g_knownXrcNames.insert(name);
+        }
+        RememberObjectNames(child);
+    }
+}
+
+// Name under which an in-memory document is registered. wxXmlResource resolves relative
+// paths (e.g. bitmap files) against the location of this name, so pointing it into
+// base_dir makes those paths relative to that directory.
+wxString
+DocumentNameInDir(const char* base_dir, const wxXmlDocument* doc)
+{
+    wxFileName fn(wxString::FromUTF8(base_dir), wxString::Format("embedded_%p.xrc", doc));
+    fn.MakeAbsolute();
+    return wxFileSystem::FileNameToURL(fn);
+}
+
+// Creates objects of one custom XRC class through a factory implemented in Rust.
+class RustSubclassXmlHandler : public wxXmlResourceHandler {
+public:
+    RustSubclassXmlHandler(const wxString& className, wxd_XmlResource_SubclassFactory factory,
+                           void* userData)
+        : m_className(className), m_factory(factory), m_userData(userData)
+    {
+    }
+
+    ~RustSubclassXmlHandler() override
+    {
+        wxd_Drop_Rust_XrcSubclassFactory(m_userData);
+    }
+
+    void SetFactory(wxd_XmlResource_SubclassFactory factory, void* userData)
+    {
+        wxd_Drop_Rust_XrcSubclassFactory(m_userData);
+        m_factory = factory;
+        m_userData = userData;
+    }
+
+    bool CanHandle(wxXmlNode* node) override
+    {
+        return IsOfClass(node, m_className);
+    }
+
+    wxObject* DoCreateResource() override
+    {
+        wxString name = GetName();
+        wxWindow* window = reinterpret_cast<wxWindow*>(
+            m_factory(m_userData, reinterpret_cast<wxd_Window_t*>(m_parentAsWindow),
+                      name.utf8_str()));
+        if (!window) {
+            ReportError(wxString::Format("factory for class \"%s\" did not create a window",
+                                         m_className));
+            return nullptr;
+        }
+
+        // Apply what the standard handlers pass to Create(), then the common attributes
+        window->SetId(GetID());
+        window->SetName(name);
+        if (HasParam(wxT("style")))
+            window->SetWindowStyleFlag(GetStyle());
+        if (HasParam(wxT("pos")) || HasParam(wxT("size"))) {
+            wxPoint pos = GetPosition();
+            wxSize size = GetSize();
+            window->SetSize(pos.x, pos.y, size.x, size.y, wxSIZE_USE_EXISTING);
+            if (size != wxDefaultSize)
+                window->SetInitialSize(size);
+        }
+        SetupWindow(window);
+        CreateChildren(window);
+        return window;
+    }
+
+private:
+    wxString m_className;
+    wxd_XmlResource_SubclassFactory m_factory;
+    void* m_userData;
+};
+
+// Handlers registered per class name, so registering a class again replaces its factory
+std::map<wxString, RustSubclassXmlHandler*> g_subclassHandlers;
+
+} // namespace
 
 // Get the global wxXmlResource instance
 extern "C" WXD_EXPORTED wxd_XmlResource_t*
@@ -25,6 +170,14 @@ wxd_XmlResource_InitAllHandlers(wxd_XmlResource_t* self)
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
     if (resource) {
         resource->InitAllHandlers();
+#if wxdUSE_AUI
+        // The AUI handler (wxAuiNotebook, wxAuiToolBar) is not part of InitAllHandlers
+        static bool auiHandlerAdded = false;
+        if (!auiHandlerAdded) {
+            resource->AddHandler(new wxAuiXmlHandler);
+            auiHandlerAdded = true;
+        }
+#endif
     }
 }
 
@@ -35,9 +188,19 @@ wxd_XmlResource_LoadFromFile(wxd_XmlResource_t* self, const char* filemask)
     if (!self || !filemask)
         return false;
 
+    XrcErrorCapture capture;
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
     wxString filename = wxString::FromUTF8(filemask);
-    return resource->Load(filename);
+    if (!resource->Load(filename))
+        return false;
+
+    // Wildcard masks and virtual file system paths are not scanned for names
+    if (wxFileName::FileExists(filename)) {
+        wxXmlDocument doc;
+        if (doc.Load(filename))
+            RememberObjectNames(doc.GetRoot());
+    }
+    return true;
 }
 
 // Load XRC from string data
@@ -47,6 +210,7 @@ wxd_XmlResource_LoadFromString(wxd_XmlResource_t* self, const char* xrc_data)
     if (!self || !xrc_data)
         return false;
 
+    XrcErrorCapture capture;
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
 
     // Create a memory input stream from the string data
@@ -61,11 +225,90 @@ wxd_XmlResource_LoadFromString(wxd_XmlResource_t* self, const char* xrc_data)
         return false;
     }
 
+    RememberObjectNames(doc->GetRoot());
+
     // Use LoadDocument to load the XML document into the resource
     // This takes ownership of the document and handles proper lifecycle
     return resource->LoadDocument(doc, wxT("embedded_xrc"));
 }
 
+// Load XRC from string data, resolving relative paths against base_dir
+extern "C" WXD_EXPORTED bool
+wxd_XmlResource_LoadFromStringWithBasePath(wxd_XmlResource_t* self, const char* xrc_data,
+                                           const char* base_dir)
+{
+    if (!self || !xrc_data || !base_dir)
+        return false;
+
+    XrcErrorCapture capture;
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxMemoryInputStream stream(xrc_data, strlen(xrc_data));
+
+    wxXmlDocument* doc = new wxXmlDocument();
+    if (!doc->Load(stream)) {
+        delete doc;
+        return false;
+    }
+    RememberObjectNames(doc->GetRoot());
+    return resource->LoadDocument(doc, DocumentNameInDir(base_dir, doc));
+}
+
+// Load XRC from a UTF-8 byte buffer
+extern "C" WXD_EXPORTED bool
+wxd_XmlResource_LoadFromBytes(wxd_XmlResource_t* self, const unsigned char* data, size_t len,
+                              const char* name)
+{
+    if (!self || !data || !name)
+        return false;
+
+    XrcErrorCapture capture;
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxMemoryInputStream stream(data, len);
+
+    wxXmlDocument* doc = new wxXmlDocument();
+    if (!doc->Load(stream)) {
+        delete doc;
+        return false;
+    }
+    RememberObjectNames(doc->GetRoot());
+    return resource->LoadDocument(doc, wxString::FromUTF8(name));
+}
+
+// Load a named wxBitmap resource; returns null if it does not exist or cannot be read
+extern "C" WXD_EXPORTED wxd_Bitmap_t*
+wxd_XmlResource_LoadBitmap(wxd_XmlResource_t* self, const char* name)
+{
+    if (!self || !name)
+        return nullptr;
+
+    XrcErrorCapture capture;
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxBitmap bitmap = resource->LoadBitmap(wxString::FromUTF8(name));
+    if (!bitmap.IsOk())
+        return nullptr;
+    return reinterpret_cast<wxd_Bitmap_t*>(new wxBitmap(bitmap));
+}
+
+// Load a named wxIcon resource as a bitmap; returns null if it does not exist or cannot be read
+extern "C" WXD_EXPORTED wxd_Bitmap_t*
+wxd_XmlResource_LoadIcon(wxd_XmlResource_t* self, const char* name)
+{
+    if (!self || !name)
+        return nullptr;
+
+    XrcErrorCapture capture;
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxIcon icon = resource->LoadIcon(wxString::FromUTF8(name));
+    if (!icon.IsOk())
+        return nullptr;
+    wxBitmap* bitmap = new wxBitmap();
+    if (!bitmap->CopyFromIcon(icon)) {
+        delete bitmap;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
+}
+
 // Load a dialog from XRC
 extern "C" WXD_EXPORTED wxd_Dialog_t*
 wxd_XmlResource_LoadDialog(wxd_XmlResource_t* self, wxd_Window_t* parent, const char* name)
@@ -73,6 +316,7 @@ wxd_XmlResource_LoadDialog(wxd_XmlResource_t* self, wxd_Window_t* parent, const
     if (!self || !name)
         return nullptr;
 
+    XrcErrorCapture capture;
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
     wxWindow* parentWindow = reinterpret_cast<wxWindow*>(parent);
     wxString dialogName = wxString::FromUTF8(name);
@@ -88,6 +332,7 @@ wxd_XmlResource_LoadFrame(wxd_XmlResource_t* self, wxd_Window_t* parent, const c
     if (!self || !name)
         return nullptr;
 
+    XrcErrorCapture capture;
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
     wxWindow* parentWindow = reinterpret_cast<wxWindow*>(parent);
     wxString frameName = wxString::FromUTF8(name);
@@ -103,6 +348,7 @@ wxd_XmlResource_LoadPanel(wxd_XmlResource_t* self, wxd_Window_t* parent, const c
     if (!self || !name)
         return nullptr;
 
+    XrcErrorCapture capture;
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
     wxWindow* parentWindow = reinterpret_cast<wxWindow*>(parent);
     wxString panelName = wxString::FromUTF8(name);
@@ -118,6 +364,7 @@ wxd_XmlResource_LoadMenuBar(wxd_XmlResource_t* self, wxd_Window_t* parent, const
     if (!self || !name)
         return nullptr;
 
+    XrcErrorCapture capture;
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
     wxWindow* parentWindow = reinterpret_cast<wxWindow*>(parent); // parent can be null for menubars
     wxString menubarName = wxString::FromUTF8(name);
@@ -126,6 +373,40 @@ wxd_XmlResource_LoadMenuBar(wxd_XmlResource_t* self, wxd_Window_t* parent, const
     return reinterpret_cast<wxd_MenuBar_t*>(menubar);
 }
 
+// Load menu from XRC
+extern "C" WXD_EXPORTED wxd_Menu_t*
+wxd_XmlResource_LoadMenu(wxd_XmlResource_t* self, const char* name)
+{
+    if (!self || !name)
+        return nullptr;
+
+    XrcErrorCapture capture;
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxMenu* menu = resource->LoadMenu(wxString::FromUTF8(name));
+    return reinterpret_cast<wxd_Menu_t*>(menu);
+}
+
+// Load toolbar from XRC
+extern "C" WXD_EXPORTED wxd_ToolBar_t*
+wxd_XmlResource_LoadToolBar(wxd_XmlResource_t* self, wxd_Window_t* parent, const char* name)
+{
+    if (!self || !parent || !name)
+        return nullptr;
+
+    XrcErrorCapture capture;
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxWindow* parentWindow = reinterpret_cast<wxWindow*>(parent);
+    wxToolBar* toolbar = resource->LoadToolBar(parentWindow, wxString::FromUTF8(name));
+    if (!toolbar)
+        return nullptr;
+
+    // Attach it like wxFrame::CreateToolBar() would, so the frame lays it out
+    wxFrame* frame = wxDynamicCast(parentWindow, wxFrame);
+    if (frame && frame->GetToolBar() != toolbar)
+        frame->SetToolBar(toolbar);
+    return reinterpret_cast<wxd_ToolBar_t*>(toolbar);
+}
+
 // Load a generic object from XRC
 extern "C" WXD_EXPORTED wxd_Window_t*
 wxd_XmlResource_LoadObject(wxd_XmlResource_t* self, wxd_Window_t* parent, const char* name,
@@ -134,6 +415,7 @@ wxd_XmlResource_LoadObject(wxd_XmlResource_t* self, wxd_Window_t* parent, const
     if (!self || !name || !classname)
         return nullptr;
 
+    XrcErrorCapture capture;
     wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
     wxWindow* parentWindow = reinterpret_cast<wxWindow*>(parent); // parent can be null
     wxString wxName = wxString::FromUTF8(name);
@@ -154,9 +436,50 @@ wxd_XmlResource_GetXRCID(const char* str_id)
         return wxID_NONE;
 
     wxString name = wxString::FromUTF8(str_id);
+    g_knownXrcNames.insert(name);
+    return wxXmlResource::GetXRCID(name);
+}
+
+// Get XRC ID for a name from a loaded document or one given an id before, without
+// creating one for unknown names
+extern "C" WXD_EXPORTED int
+wxd_XmlResource_TryGetXRCID(const char* str_id)
+{
+    if (!str_id)
+        return wxID_NONE;
+
+    wxString name = wxString::FromUTF8(str_id);
+    if (g_knownXrcNames.find(name) == g_knownXrcNames.end())
+        return wxID_NONE;
     return wxXmlResource::GetXRCID(name);
 }
 
+// Give a name the XRC ID id unless it already has one
+extern "C" WXD_EXPORTED int
+wxd_XmlResource_AssignXRCID(const char* str_id, int id)
+{
+    if (!str_id || id == wxID_NONE)
+        return wxID_NONE;
+
+    wxString name = wxString::FromUTF8(str_id);
+    wxString holder = wxXmlResource::FindXRCIDById(id);
+    if (!holder.empty() && holder != name)
+        return wxID_NONE;
+
+    g_knownXrcNames.insert(name);
+    return wxXmlResource::GetXRCID(name, id);
+}
+
+// Get the XRC name an ID was created for
+extern "C" WXD_EXPORTED int
+wxd_XmlResource_GetXRCName(int id, char* buffer, size_t buffer_len)
+{
+    wxString name = wxXmlResource::FindXRCIDById(id);
+    if (name.empty())
+        return -1;
+    return static_cast<int>(wxd_cpp_utils::copy_wxstring_to_buffer(name, buffer, buffer_len));
+}
+
 // Find a window by XRC name
 extern "C" WXD_EXPORTED wxd_Window_t*
 wxd_Window_FindWindowByXRCName(wxd_Window_t* self, const char* name)
@@ -170,4 +493,53 @@ wxd_Window_FindWindowByXRCName(wxd_Window_t* self, const char* name)
     // Use FindWindow to find child by name
     wxWindow* child = window->FindWindow(windowName);
     return reinterpret_cast<wxd_Window_t*>(child);
-}
\ No newline at end of file
+}
+
+// Get the class of a top-level XRC resource
+extern "C" WXD_EXPORTED int
+wxd_XmlResource_GetResourceClass(wxd_XmlResource_t* self, const char* name, char* buffer,
+                                 size_t buffer_len)
+{
+    if (!self || !name)
+        return -1;
+
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxXmlNode* node = resource->GetResourceNode(wxString::FromUTF8(name));
+    if (!node)
+        return -1;
+    return static_cast<int>(
+        wxd_cpp_utils::copy_wxstring_to_buffer(node->GetAttribute("class"), buffer, buffer_len));
+}
+
+// Get the errors logged by the last load call
+extern "C" WXD_EXPORTED int
+wxd_XmlResource_GetLastError(char* buffer, size_t buffer_len)
+{
+    return static_cast<int>(
+        wxd_cpp_utils::copy_wxstring_to_buffer(g_lastXrcError, buffer, buffer_len));
+}
+
+// Register a Rust factory creating the objects of a custom XRC class
+extern "C" WXD_EXPORTED bool
+wxd_XmlResource_RegisterSubclassFactory(wxd_XmlResource_t* self, const char* class_name,
+                                        wxd_XmlResource_SubclassFactory factory, void* user_data)
+{
+    if (!self || !class_name || !factory) {
+        wxd_Drop_Rust_XrcSubclassFactory(user_data);
+        return false;
+    }
+
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    wxString className = wxString::FromUTF8(class_name);
+    auto it = g_subclassHandlers.find(className);
+    if (it != g_subclassHandlers.end()) {
+        it->second->SetFactory(factory, user_data);
+        return true;
+    }
+
+    // Inserted first so a custom factory takes precedence over the standard handlers
+    RustSubclassXmlHandler* handler = new RustSubclassXmlHandler(className, factory, user_data);
+    resource->InsertHandler(handler);
+    g_subclassHandlers[className] = handler;
+    return true;
+}
@@ -28,7 +28,11 @@
 #include <wx/slider.h>     // ADDED: Needed for Slider styles
 #include <wx/spinctrl.h>   // ADDED: Needed for SpinCtrl styles
 #include <wx/spinbutt.h>   // ADDED: Needed for SpinButton constants/events
+#include <wx/graphics.h>   // For wxAntialiasMode / wxInterpolationQuality
+#include <wx/image.h>      // For wxImageResizeQuality
+#include <wx/renderer.h>   // For wxCONTROL_* renderer flags
 #include <wx/notebook.h>   // ADDED: Needed for Notebook styles and events
+#include <wx/toolbook.h>   // For wxToolbook styles
 #include <wx/splitter.h>   // ADDED: Needed for SplitterWindow styles and events
 #include <wx/button.h>     // Ensure button styles are included
 #include <wx/bmpbuttn.h>   // ADDED: Needed for wxBitmapButton
@@ -56,6 +60,7 @@
 #include <wx/dataview.h> // ADDED: Needed for wxDataView styles and constants
 #include <wx/taskbar.h>  // ADDED: Needed for TaskBarIcon constants
 #include <wx/sysopt.h>   // For wxSystemOptions
+#include <wx/cmndata.h>  // For wxPrintData orientation and paper constants
 
 // A simple application class that initializes wxWidgets
 class ConstExtractorApp : public wxApp {
@@ -389,6 +394,10 @@ main(int argc, char** argv)
     constants_to_extract.push_back({ "wxYES_DEFAULT", wxYES_DEFAULT });
     constants_to_extract.push_back({ "wxNO_DEFAULT", wxNO_DEFAULT });
     constants_to_extract.push_back({ "wxCANCEL_DEFAULT", wxCANCEL_DEFAULT });
+    // Standard dialog buttons (CreateStdDialogButtonSizer)
+    constants_to_extract.push_back({ "wxAPPLY", wxAPPLY });
+    constants_to_extract.push_back({ "wxCLOSE", wxCLOSE });
+    constants_to_extract.push_back({ "wxHELP", wxHELP });
     constants_to_extract.push_back({ "wxICON_NONE", wxICON_NONE });
     constants_to_extract.push_back({ "wxICON_EXCLAMATION", wxICON_EXCLAMATION });
     constants_to_extract.push_back(
@@ -412,6 +421,7 @@ main(int argc, char** argv)
     constants_to_extract.push_back({ "wxDD_DEFAULT_STYLE", wxDD_DEFAULT_STYLE });
     constants_to_extract.push_back({ "wxDD_DIR_MUST_EXIST", wxDD_DIR_MUST_EXIST });
     constants_to_extract.push_back({ "wxDD_CHANGE_DIR", wxDD_CHANGE_DIR });
+    constants_to_extract.push_back({ "wxDD_SHOW_HIDDEN", wxDD_SHOW_HIDDEN });
     // constants_to_extract.push_back({"wxDD_NEW_DIR_BUTTON", wxDD_NEW_DIR_BUTTON}); // This is often a default
     // FilePickerCtrl Styles
     constants_to_extract.push_back({ "wxFLP_DEFAULT_STYLE", wxFLP_DEFAULT_STYLE });
@@ -522,6 +532,8 @@ main(int argc, char** argv)
     constants_to_extract.push_back({ "wxBK_BOTTOM", wxBK_BOTTOM });
     constants_to_extract.push_back({ "wxBK_LEFT", wxBK_LEFT });
     constants_to_extract.push_back({ "wxBK_RIGHT", wxBK_RIGHT });
+    constants_to_extract.push_back({ "wxTBK_BUTTONBAR", wxTBK_BUTTONBAR });
+    constants_to_extract.push_back({ "wxTBK_HORZ_LAYOUT", wxTBK_HORZ_LAYOUT });
 
     // Dialogs
     constants_to_extract.push_back({ "wxCENTRE", wxCENTRE });
@@ -772,6 +784,53 @@ main(int argc, char** argv)
     constants_to_extract.push_back({ "wxFONTWEIGHT_EXTRABOLD", wxFONTWEIGHT_EXTRABOLD });
     constants_to_extract.push_back({ "wxFONTWEIGHT_HEAVY", wxFONTWEIGHT_HEAVY });
 
+    // Print orientation and paper size constants
+    constants_to_extract.push_back({ "wxPORTRAIT", wxPORTRAIT });
+    constants_to_extract.push_back({ "wxLANDSCAPE", wxLANDSCAPE });
+    constants_to_extract.push_back({ "wxPAPER_NONE", wxPAPER_NONE });
+    constants_to_extract.push_back({ "wxPAPER_LETTER", wxPAPER_LETTER });
+    constants_to_extract.push_back({ "wxPAPER_LEGAL", wxPAPER_LEGAL });
+    constants_to_extract.push_back({ "wxPAPER_A4", wxPAPER_A4 });
+    constants_to_extract.push_back({ "wxPAPER_TABLOID", wxPAPER_TABLOID });
+    constants_to_extract.push_back({ "wxPAPER_LEDGER", wxPAPER_LEDGER });
+    constants_to_extract.push_back({ "wxPAPER_STATEMENT", wxPAPER_STATEMENT });
+    constants_to_extract.push_back({ "wxPAPER_EXECUTIVE", wxPAPER_EXECUTIVE });
+    constants_to_extract.push_back({ "wxPAPER_A3", wxPAPER_A3 });
+    constants_to_extract.push_back({ "wxPAPER_A5", wxPAPER_A5 });
+    constants_to_extract.push_back({ "wxPAPER_B4", wxPAPER_B4 });
+    constants_to_extract.push_back({ "wxPAPER_B5", wxPAPER_B5 });
+
+    // GraphicsContext antialiasing and bitmap interpolation
+    constants_to_extract.push_back({ "wxANTIALIAS_NONE", wxANTIALIAS_NONE });
+    constants_to_extract.push_back({ "wxANTIALIAS_DEFAULT", wxANTIALIAS_DEFAULT });
+    constants_to_extract.push_back({ "wxINTERPOLATION_DEFAULT", wxINTERPOLATION_DEFAULT });
+    constants_to_extract.push_back({ "wxINTERPOLATION_NONE", wxINTERPOLATION_NONE });
+    constants_to_extract.push_back({ "wxINTERPOLATION_FAST", wxINTERPOLATION_FAST });
+    constants_to_extract.push_back({ "wxINTERPOLATION_GOOD", wxINTERPOLATION_GOOD });
+    constants_to_extract.push_back({ "wxINTERPOLATION_BEST", wxINTERPOLATION_BEST });
+
+    // wxImage resize quality
+    constants_to_extract.push_back({ "wxIMAGE_QUALITY_NEAREST", wxIMAGE_QUALITY_NEAREST });
+    constants_to_extract.push_back({ "wxIMAGE_QUALITY_BILINEAR", wxIMAGE_QUALITY_BILINEAR });
+    constants_to_extract.push_back({ "wxIMAGE_QUALITY_BICUBIC", wxIMAGE_QUALITY_BICUBIC });
+    constants_to_extract.push_back({ "wxIMAGE_QUALITY_BOX_AVERAGE", wxIMAGE_QUALITY_BOX_AVERAGE });
+    constants_to_extract.push_back({ "wxIMAGE_QUALITY_HIGH", wxIMAGE_QUALITY_HIGH });
+
+    // wxRendererNative control state flags
+    constants_to_extract.push_back({ "wxCONTROL_NONE", wxCONTROL_NONE });
+    constants_to_extract.push_back({ "wxCONTROL_DISABLED", wxCONTROL_DISABLED });
+    constants_to_extract.push_back({ "wxCONTROL_FOCUSED", wxCONTROL_FOCUSED });
+    constants_to_extract.push_back({ "wxCONTROL_PRESSED", wxCONTROL_PRESSED });
+    constants_to_extract.push_back({ "wxCONTROL_SPECIAL", wxCONTROL_SPECIAL });
+    constants_to_extract.push_back({ "wxCONTROL_CURRENT", wxCONTROL_CURRENT });
+    constants_to_extract.push_back({ "wxCONTROL_SELECTED", wxCONTROL_SELECTED });
+    constants_to_extract.push_back({ "wxCONTROL_CHECKED", wxCONTROL_CHECKED });
+    constants_to_extract.push_back({ "wxCONTROL_UNDETERMINED", wxCONTROL_UNDETERMINED });
+    constants_to_extract.push_back({ "wxELLIPSIZE_NONE", wxELLIPSIZE_NONE });
+    constants_to_extract.push_back({ "wxELLIPSIZE_START", wxELLIPSIZE_START });
+    constants_to_extract.push_back({ "wxELLIPSIZE_MIDDLE", wxELLIPSIZE_MIDDLE });
+    constants_to_extract.push_back({ "wxELLIPSIZE_END", wxELLIPSIZE_END });
+
     // Output the constants in the Rust pub const format
     for (const auto& pair : constants_to_extract) {
         std::string original_name = pair.first;
@@ -253,6 +253,9 @@ pub const WXD_YES_NO: i64 = 10;
 pub const WXD_YES_DEFAULT: i64 = 0;
 pub const WXD_NO_DEFAULT: i64 = 128;
 pub const WXD_CANCEL_DEFAULT: i64 = 2147483648;
+pub const WXD_APPLY: i64 = 32;
+pub const WXD_CLOSE: i64 = 64;
+pub const WXD_HELP: i64 = 4096;
 pub const WXD_ICON_NONE: i64 = 262144;
 pub const WXD_ICON_EXCLAMATION: i64 = 256;
 pub const WXD_ICON_WARNING: i64 = 256;
@@ -273,6 +276,7 @@ pub const WXD_FD_PREVIEW: i64 = 256;
 pub const WXD_DD_DEFAULT_STYLE: i64 = 536877120;
 pub const WXD_DD_DIR_MUST_EXIST: i64 = 512;
 pub const WXD_DD_CHANGE_DIR: i64 = 256;
+pub const WXD_DD_SHOW_HIDDEN: i64 = 1;
 pub const WXD_FLP_DEFAULT_STYLE: i64 = 9216;
 pub const WXD_FLP_USE_TEXTCTRL: i64 = 2;
 pub const WXD_FLP_OPEN: i64 = 1024;
@@ -349,6 +353,8 @@ pub const WXD_BK_TOP: i64 = 16;
 pub const WXD_BK_BOTTOM: i64 = 32;
 pub const WXD_BK_LEFT: i64 = 64;
 pub const WXD_BK_RIGHT: i64 = 128;
+pub const WXD_TBK_BUTTONBAR: i64 = 256;
+pub const WXD_TBK_HORZ_LAYOUT: i64 = 32768;
 pub const WXD_CENTRE: i64 = 1;
 pub const WXD_AUI_NB_DEFAULT_STYLE: i64 = 10545;
 pub const WXD_AUI_NB_TOP: i64 = 1;
@@ -528,6 +534,45 @@ pub const WXD_FONTWEIGHT_SEMIBOLD: i64 = 600;
 pub const WXD_FONTWEIGHT_BOLD: i64 = 700;
 pub const WXD_FONTWEIGHT_EXTRABOLD: i64 = 800;
 pub const WXD_FONTWEIGHT_HEAVY: i64 = 900;
+pub const WXD_PORTRAIT: i64 = 1;
+pub const WXD_LANDSCAPE: i64 = 2;
+pub const WXD_PAPER_NONE: i64 = 0;
+pub const WXD_PAPER_LETTER: i64 = 1;
+pub const WXD_PAPER_LEGAL: i64 = 2;
+pub const WXD_PAPER_A4: i64 = 3;
+pub const WXD_PAPER_TABLOID: i64 = 8;
+pub const WXD_PAPER_LEDGER: i64 = 9;
+pub const WXD_PAPER_STATEMENT: i64 = 10;
+pub const WXD_PAPER_EXECUTIVE: i64 = 11;
+pub const WXD_PAPER_A3: i64 = 12;
+pub const WXD_PAPER_A5: i64 = 14;
+pub const WXD_PAPER_B4: i64 = 15;
+pub const WXD_PAPER_B5: i64 = 16;
+pub const WXD_ANTIALIAS_NONE: i64 = 0;
+pub const WXD_ANTIALIAS_DEFAULT: i64 = 1;
+pub const WXD_INTERPOLATION_DEFAULT: i64 = 0;
+pub const WXD_INTERPOLATION_NONE: i64 = 1;
+pub const WXD_INTERPOLATION_FAST: i64 = 2;
+pub const WXD_INTERPOLATION_GOOD: i64 = 3;
+pub const WXD_INTERPOLATION_BEST: i64 = 4;
+pub const WXD_IMAGE_QUALITY_NEAREST: i64 = 0;
+pub const WXD_IMAGE_QUALITY_BILINEAR: i64 = 1;
+pub const WXD_IMAGE_QUALITY_BICUBIC: i64 = 2;
+pub const WXD_IMAGE_QUALITY_BOX_AVERAGE: i64 = 3;
+pub const WXD_IMAGE_QUALITY_HIGH: i64 = 4;
+pub const WXD_CONTROL_NONE: i64 = 0;
+pub const WXD_CONTROL_DISABLED: i64 = 1;
+pub const WXD_CONTROL_FOCUSED: i64 = 2;
+pub const WXD_CONTROL_PRESSED: i64 = 4;
+pub const WXD_CONTROL_SPECIAL: i64 = 8;
+pub const WXD_CONTROL_CURRENT: i64 = 16;
+pub const WXD_CONTROL_SELECTED: i64 = 32;
+pub const WXD_CONTROL_CHECKED: i64 = 64;
+pub const WXD_CONTROL_UNDETERMINED: i64 = 128;
+pub const WXD_ELLIPSIZE_NONE: i64 = 0;
+pub const WXD_ELLIPSIZE_START: i64 = 1;
+pub const WXD_ELLIPSIZE_MIDDLE: i64 = 2;
+pub const WXD_ELLIPSIZE_END: i64 = 3;
 
 // User attention flags for RequestUserAttention
 pub const WXD_USER_ATTENTION_INFO: i64 = 1;
@@ -6,19 +6,83 @@ use std::collections::VecDeque;
 #[cfg(target_os = "macos")]
 use std::ffi::c_int;
 use std::ffi::{CStr, CString, c_char, c_void};
-use std::sync::{Arc, LazyLock, Mutex};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, RwLock};
+use std::task::{Context, Poll, Waker};
+use std::thread::ThreadId;
 use wxdragon_sys as ffi; // Import Window and WxWidget trait
 
-// Type alias to reduce complexity
-type CallbackQueue = Arc<Mutex<VecDeque<Box<dyn FnOnce() + Send + 'static>>>>;
+type Callback = Box<dyn FnOnce() + Send + 'static>;
+
+/// Where the application is in its lifecycle, as far as queued callbacks are concerned.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum QueueState {
+    /// `wxdragon::main` hasn't initialized the application yet.
+    NotStarted,
+    /// Callbacks are accepted and run by the main loop.
+    Running,
+    /// The main loop has ended; nothing queued would ever run.
+    Finished,
+}
+
+struct CallbackQueue {
+    state: QueueState,
+    callbacks: VecDeque<Callback>,
+}
 
 // Queue for storing callbacks to be executed on the main thread
-static MAIN_THREAD_QUEUE: LazyLock<CallbackQueue> = LazyLock::new(|| Arc::new(Mutex::new(VecDeque::new())));
+static MAIN_THREAD_QUEUE: Mutex<CallbackQueue> = Mutex::new(CallbackQueue {
+    state: QueueState::NotStarted,
+    callbacks: VecDeque::new(),
+});
+
+fn lock_queue() -> MutexGuard<'static, CallbackQueue> {
+    // Callbacks run outside the lock, so a poisoned queue is still consistent.
+    MAIN_THREAD_QUEUE.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Errors from scheduling a callback with [`call_after`] or waiting for it with a
+/// [`CallAfterHandle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallAfterError {
+    /// The application hasn't been started with [`main`] yet.
+    NotStarted,
+    /// The main loop has already ended, so the callback would never run.
+    Finished,
+    /// The main loop ended before the queued callback ran.
+    Dropped,
+    /// The callback panicked.
+    Panicked,
+    /// [`CallAfterHandle::wait`] was called on the main thread, where it would never return.
+    OnMainThread,
+}
+
+impl fmt::Display for CallAfterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallAfterError::NotStarted => write!(f, "The application main loop hasn't started"),
+            CallAfterError::Finished => write!(f, "The application main loop has ended"),
+            CallAfterError::Dropped => write!(f, "The main loop ended before the callback ran"),
+            CallAfterError::Panicked => write!(f, "The callback panicked"),
+            CallAfterError::OnMainThread => write!(f, "Can't block the main thread waiting for its own callback"),
+        }
+    }
+}
+
+impl std::error::Error for CallAfterError {}
 
 /// Schedules a callback to be executed on the main thread.
 ///
 /// This is useful when you need to update UI elements from a background thread.
-/// The callback will be executed during the next event loop iteration.
+/// The callback runs during the next event loop iteration; the event loop is woken up
+/// if it is waiting for events. Use [`call_after_with`] to get a result back or to know
+/// when the callback has run.
+///
+/// Callbacks are accepted from the moment the closure passed to [`main`] is called until
+/// the main loop ends. Outside that window an error is returned and the callback is
+/// dropped without running. Callbacks still queued when the main loop ends are dropped.
 ///
 /// # Example
 /// ```rust,no_run
@@ -29,17 +93,283 @@ static MAIN_THREAD_QUEUE: LazyLock<CallbackQueue> = LazyLock::new(|| Arc::new(Mu
 /// # let my_label = DummyLabel;
 ///
 /// // In a background thread:
-/// wxdragon::call_after(Box::new(move || {
+/// wxdragon::call_after(move || {
 ///     // Update UI elements here
 ///     my_label.set_label("Updated from background thread");
-/// }));
+/// })
+/// .ok();
 /// ```
-pub fn call_after<F>(callback: Box<F>)
+pub fn call_after<F>(callback: F) -> Result<(), CallAfterError>
 where
     F: FnOnce() + Send + 'static,
 {
-    let mut queue = MAIN_THREAD_QUEUE.lock().unwrap();
-    queue.push_back(callback);
+    {
+        let mut queue = lock_queue();
+        match queue.state {
+            QueueState::NotStarted => return Err(CallAfterError::NotStarted),
+            QueueState::Finished => return Err(CallAfterError::Finished),
+            QueueState::Running => queue.callbacks.push_back(Box::new(callback)),
+        }
+    }
+    wake_up_idle();
+    Ok(())
+}
+
+/// Schedules `callback` to be executed on the main thread and returns a handle to its
+/// result.
+///
+/// A worker thread can block on the handle with [`CallAfterHandle::wait`], async code can
+/// `.await` it, and the main thread can poll it with [`CallAfterHandle::try_take`]. If the
+/// callback can't be scheduled (see [`call_after`]), the handle holds that error.
+///
+/// # Example
+/// ```rust,no_run
+/// use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// let label = StaticText::builder(&frame).with_label("0").build();
+///
+/// std::thread::spawn(move || {
+///     // Read the label on the main thread and wait for the answer
+///     let text = wxdragon::call_after_with(move || label.get_label()).wait();
+///     println!("label is {text:?}");
+/// });
+/// ```
+pub fn call_after_with<T, F>(callback: F) -> CallAfterHandle<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let completion = Arc::new(Completion {
+        state: Mutex::new(CompletionState::Pending(None)),
+        ready: Condvar::new(),
+    });
+    let sender = CompletionSender {
+        completion: completion.clone(),
+        sent: false,
+    };
+    if let Err(error) = call_after(move || sender.send(callback())) {
+        completion.complete(Err(error));
+    }
+    CallAfterHandle { completion }
+}
+
+enum CompletionState<T> {
+    /// The callback hasn't run yet; holds the waker of a task awaiting the handle.
+    Pending(Option<Waker>),
+    Done(Result<T, CallAfterError>),
+    /// The result was handed out.
+    Taken,
+}
+
+struct Completion<T> {
+    state: Mutex<CompletionState<T>>,
+    ready: Condvar,
+}
+
+impl<T> Completion<T> {
+    fn lock(&self) -> MutexGuard<'_, CompletionState<T>> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn complete(&self, result: Result<T, CallAfterError>) {
+        let waker = {
+            let mut state = self.lock();
+            match std::mem::replace(&mut *state, CompletionState::Done(result)) {
+                CompletionState::Pending(waker) => waker,
+                previous => {
+                    *state = previous;
+                    return;
+                }
+            }
+        };
+        self.ready.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Completes the handle when the callback returns, or with an error when the callback is
+/// dropped without returning because it panicked or was never run.
+struct CompletionSender<T> {
+    completion: Arc<Completion<T>>,
+    sent: bool,
+}
+
+impl<T> CompletionSender<T> {
+    fn send(mut self, value: T) {
+        self.sent = true;
+        self.completion.complete(Ok(value));
+    }
+}
+
+impl<T> Drop for CompletionSender<T> {
+    fn drop(&mut self) {
+        if !self.sent {
+            let error = if std::thread::panicking() {
+                CallAfterError::Panicked
+            } else {
+                CallAfterError::Dropped
+            };
+            self.completion.complete(Err(error));
+        }
+    }
+}
+
+/// Handle to the result of a callback scheduled with [`call_after_with`].
+///
+/// The result can be taken once, by [`wait`](Self::wait), [`try_take`](Self::try_take) or
+/// by awaiting the handle; dropping the handle doesn't cancel the callback.
+pub struct CallAfterHandle<T> {
+    completion: Arc<Completion<T>>,
+}
+
+impl<T> CallAfterHandle<T> {
+    /// Returns true once the callback has run or failed to run, i.e. when
+    /// [`try_take`](Self::try_take) would return a result.
+    pub fn is_finished(&self) -> bool {
+        matches!(*self.completion.lock(), CompletionState::Done(_))
+    }
+
+    /// Takes the result if the callback has finished, without blocking.
+    ///
+    /// Returns `None` while the callback is pending, and after the result was taken.
+    pub fn try_take(&mut self) -> Option<Result<T, CallAfterError>> {
+        let mut state = self.completion.lock();
+        match std::mem::replace(&mut *state, CompletionState::Taken) {
+            CompletionState::Done(result) => Some(result),
+            pending => {
+                *state = pending;
+                None
+            }
+        }
+    }
+
+    /// Blocks the calling thread until the callback has run and returns its result.
+    ///
+    /// Meant for worker threads. On the main thread the callback could never run while
+    /// waiting, so unless it has already finished this returns
+    /// [`CallAfterError::OnMainThread`].
+    pub fn wait(mut self) -> Result<T, CallAfterError> {
+        if let Some(result) = self.try_take() {
+            return result;
+        }
+        if is_main_thread() {
+            return Err(CallAfterError::OnMainThread);
+        }
+
+        let mut state = self.completion.lock();
+        loop {
+            match std::mem::replace(&mut *state, CompletionState::Taken) {
+                CompletionState::Done(result) => return result,
+                pending => *state = pending,
+            }
+            state = self
+                .completion
+                .ready
+                .wait(state)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+}
+
+impl<T> Future for CallAfterHandle<T> {
+    type Output = Result<T, CallAfterError>;
+
+    /// Resolves to the callback's result. Polling again after it resolved stays pending.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.completion.lock();
+        match std::mem::replace(&mut *state, CompletionState::Taken) {
+            CompletionState::Done(result) => Poll::Ready(result),
+            CompletionState::Pending(_) => {
+                *state = CompletionState::Pending(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+            CompletionState::Taken => Poll::Pending,
+        }
+    }
+}
+
+impl<T> fmt::Debug for CallAfterHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallAfterHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+/// Starts accepting callbacks; called on the main thread when the application initializes.
+fn start_main_thread_queue() {
+    let mut queue = lock_queue();
+    queue.state = QueueState::Running;
+}
+
+/// Stops accepting callbacks once the main loop has ended and drops those never run, which
+/// fails their handles with [`CallAfterError::Dropped`].
+fn finish_main_thread_queue() {
+    let pending = {
+        let mut queue = lock_queue();
+        queue.state = QueueState::Finished;
+        std::mem::take(&mut queue.callbacks)
+    };
+    if !pending.is_empty() {
+        log::debug!("Dropping {} callbacks queued after the main loop ended", pending.len());
+    }
+    drop(pending);
+}
+
+// The thread running the wxWidgets event loop, recorded when the application initializes.
+static MAIN_THREAD_ID: RwLock<Option<ThreadId>> = RwLock::new(None);
+
+fn set_main_thread_id(id: ThreadId) {
+    *MAIN_THREAD_ID.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(id);
+}
+
+/// Returns the id of the main (UI) thread, the one running the event loop started by
+/// [`main`], or `None` if the application hasn't been started yet.
+///
+/// Windows, widgets and most other wxDragon objects may only be used on this thread; other
+/// threads hand work to it with [`call_after`].
+pub fn main_thread_id() -> Option<ThreadId> {
+    *MAIN_THREAD_ID.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Returns true when called on the main (UI) thread, see [`main_thread_id`]. Returns false
+/// before the application has been started.
+pub fn is_main_thread() -> bool {
+    main_thread_id() == Some(std::thread::current().id())
+}
+
+/// Panics with a descriptive message when called from a thread other than the main (UI)
+/// thread.
+///
+/// Widget builders, window handles and event binding call this, so using UI objects from a
+/// worker thread fails right away instead of crashing somewhere inside wxWidgets. The
+/// check only runs in debug builds, or always with the `main-thread-assertions` feature.
+/// Before the application has been started there is no main thread to check against and
+/// nothing is asserted.
+#[inline]
+#[track_caller]
+pub fn assert_main_thread() {
+    if cfg!(any(debug_assertions, feature = "main-thread-assertions")) {
+        check_main_thread();
+    }
+}
+
+#[track_caller]
+fn check_main_thread() {
+    let Some(main_id) = main_thread_id() else {
+        return;
+    };
+    let current = std::thread::current();
+    if current.id() != main_id {
+        panic!(
+            "wxDragon UI object used from thread {:?} ({}), but it may only be used on the main thread; \
+             use wxdragon::call_after to run UI code there",
+            current.id(),
+            current.name().unwrap_or("unnamed"),
+        );
+    }
 }
 
 /// Processes pending callbacks queued via `call_after`.
@@ -53,15 +383,15 @@ pub fn process_main_thread_queue() -> bool {
 
     // Move callbacks from the queue to our local vector to minimize lock time
     {
-        let mut queue = MAIN_THREAD_QUEUE.lock().unwrap();
-        if queue.is_empty() {
+        let mut queue = lock_queue();
+        if queue.callbacks.is_empty() {
             return false;
         }
 
         // Move up to 10 callbacks at a time to prevent UI freezes
         // if there are many callbacks pending
         for _ in 0..10 {
-            if let Some(callback) = queue.pop_front() {
+            if let Some(callback) = queue.callbacks.pop_front() {
                 callbacks.push(callback);
             } else {
                 break;
@@ -69,9 +399,12 @@ pub fn process_main_thread_queue() -> bool {
         }
     }
 
-    // Execute callbacks outside of the lock
+    // Execute callbacks outside of the lock. A panic must not unwind into the C++ event
+    // loop, so it is logged (and reported to the callback's handle) instead.
     for callback in callbacks {
-        callback();
+        if std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)).is_err() {
+            log::error!("Panic caught in a call_after callback");
+        }
     }
 
     true // We processed some callbacks
@@ -314,11 +647,36 @@ pub fn activate_app() {
 /// Wakes up the application's idle event loop.
 ///
 /// This function forces the application to process pending idle events
-/// and wakes up the event loop from a sleep state.
+/// and wakes up the event loop from a sleep state. It can be called from any
+/// thread, e.g. after a worker changed state that an idle handler picks up.
+/// [`call_after`] already wakes the loop itself.
 pub fn wake_up_idle() {
     unsafe { ffi::wxd_WakeUpIdle() };
 }
 
+/// Static methods for querying and driving the active event loop.
+pub struct EventLoop;
+
+impl EventLoop {
+    /// Returns true while an event loop is active and running, including the nested loops
+    /// of modal dialogs.
+    pub fn is_running() -> bool {
+        unsafe { ffi::wxd_EventLoop_IsRunning() }
+    }
+
+    /// Processes pending events, so a long operation on the main thread keeps the screen
+    /// painting and can react to e.g. a "Cancel" button.
+    ///
+    /// While yielding, event handlers can run again, including the one calling this, so
+    /// disable the controls that would start the operation twice. With `only_if_needed` a
+    /// call made while already yielding does nothing and returns false; otherwise such a
+    /// nested call is reported as an error by wxWidgets. Returns false off the main thread
+    /// or without an active event loop.
+    pub fn yield_if_needed(only_if_needed: bool) -> bool {
+        unsafe { ffi::wxd_EventLoop_Yield(only_if_needed) }
+    }
+}
+
 /// Gets the current wxWidgets app instance.
 pub fn get_app_instance() -> Option<App> {
     App::new()
@@ -644,6 +1002,7 @@ where
 
         // Call the C entry point, passing the trampoline and the closure data
         let code = ffi::wxd_Main(argc, argv_ptr, Some(on_init_trampoline), user_data_ptr);
+        finish_main_thread_queue();
 
         // Reclaim and drop the payload Box to free memory in all cases.
         // If the trampoline ran, cb was taken() and executed (now None).
@@ -685,6 +1044,11 @@ unsafe extern "C" fn on_init_trampoline(user_data: *mut c_void) -> bool {
         }
     };
 
+    // OnInit runs on the thread driving the event loop, which on macOS may not be the
+    // thread that called `main`.
+    set_main_thread_id(std::thread::current().id());
+    start_main_thread_queue();
+
     // Call the closure with the App instance, catching potential panics
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| cb(app)));
 
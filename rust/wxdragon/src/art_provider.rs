@@ -5,7 +5,8 @@ use crate::bitmap::Bitmap;
 use crate::bitmap_bundle::BitmapBundle;
 use crate::geometry::Size;
 use crate::window::WxWidget;
-use std::ffi::CString;
+use std::ffi::{CStr, CString, c_char, c_void};
+use std::ptr;
 use wxdragon_sys as ffi;
 
 // --- ArtId Enum ---
@@ -62,7 +63,13 @@ pub enum ArtId {
     Find,
     FindAndReplace,
     Quit,
-    // Add any other specific IDs if they appear in ffi or wxWidgets docs
+    Close,
+    Plus,
+    Minus,
+    FullScreen,
+    Refresh,
+    Stop,
+    WxLogo,
 }
 
 impl ArtId {
@@ -119,8 +126,81 @@ impl ArtId {
             ArtId::Find => "wxART_FIND",
             ArtId::FindAndReplace => "wxART_FIND_AND_REPLACE",
             ArtId::Quit => "wxART_QUIT",
+            ArtId::Close => "wxART_CLOSE",
+            ArtId::Plus => "wxART_PLUS",
+            ArtId::Minus => "wxART_MINUS",
+            ArtId::FullScreen => "wxART_FULL_SCREEN",
+            ArtId::Refresh => "wxART_REFRESH",
+            ArtId::Stop => "wxART_STOP",
+            ArtId::WxLogo => "wxART_WX_LOGO",
         }
     }
+
+    /// Looks up the id for a `wxART_*` string, e.g. as passed to a custom provider.
+    pub fn from_wx_str(id: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|art_id| art_id.as_str() == id)
+    }
+
+    const ALL: [ArtId; 58] = [
+        ArtId::Error,
+        ArtId::Information,
+        ArtId::Warning,
+        ArtId::Question,
+        ArtId::HelpSidePanel,
+        ArtId::HelpSettings,
+        ArtId::HelpBook,
+        ArtId::HelpFolder,
+        ArtId::HelpPage,
+        ArtId::AddBookmark,
+        ArtId::DeleteBookmark,
+        ArtId::GoBack,
+        ArtId::GoForward,
+        ArtId::GoUp,
+        ArtId::GoDown,
+        ArtId::GoToParent,
+        ArtId::GoHome,
+        ArtId::GoToFirst,
+        ArtId::GoToLast,
+        ArtId::FileOpen,
+        ArtId::FileSave,
+        ArtId::FileSaveAs,
+        ArtId::Print,
+        ArtId::Help,
+        ArtId::Tip,
+        ArtId::ReportView,
+        ArtId::ListView,
+        ArtId::NewDir,
+        ArtId::HardDisk,
+        ArtId::Floppy,
+        ArtId::CDRom,
+        ArtId::Removable,
+        ArtId::Folder,
+        ArtId::FolderOpen,
+        ArtId::GoDirUp,
+        ArtId::ExecutableFile,
+        ArtId::NormalFile,
+        ArtId::TickMark,
+        ArtId::CrossMark,
+        ArtId::MissingImage,
+        ArtId::New,
+        ArtId::Edit,
+        ArtId::Undo,
+        ArtId::Redo,
+        ArtId::Delete,
+        ArtId::Copy,
+        ArtId::Cut,
+        ArtId::Paste,
+        ArtId::Find,
+        ArtId::FindAndReplace,
+        ArtId::Quit,
+        ArtId::Close,
+        ArtId::Plus,
+        ArtId::Minus,
+        ArtId::FullScreen,
+        ArtId::Refresh,
+        ArtId::Stop,
+        ArtId::WxLogo,
+    ];
 }
 
 // --- ArtClient Enum ---
@@ -131,7 +211,10 @@ pub enum ArtClient {
     Toolbar,
     FrameIcon,
     MessageBox,
+    /// Common dialogs such as the file dialog (`wxART_CMN_DIALOG`)
     Dialog,
+    HelpBrowser,
+    List,
     Other, // Represents wxART_OTHER, typically an empty string
 }
 
@@ -143,10 +226,27 @@ impl ArtClient {
             ArtClient::Toolbar => "wxART_TOOLBAR_C",
             ArtClient::FrameIcon => "wxART_FRAME_ICON_C",
             ArtClient::MessageBox => "wxART_MESSAGE_BOX_C",
-            ArtClient::Dialog => "wxART_DIALOG_C",
+            ArtClient::Dialog => "wxART_CMN_DIALOG_C",
+            ArtClient::HelpBrowser => "wxART_HELP_BROWSER_C",
+            ArtClient::List => "wxART_LIST_C",
             ArtClient::Other => "", // Empty string for wxART_OTHER
         }
     }
+
+    /// Looks up the client for a `wxART_*_C` string; unknown clients map to `Other`.
+    pub fn from_wx_str(client: &str) -> Self {
+        match client {
+            "wxART_BUTTON_C" => ArtClient::Button,
+            "wxART_MENU_C" => ArtClient::Menu,
+            "wxART_TOOLBAR_C" => ArtClient::Toolbar,
+            "wxART_FRAME_ICON_C" => ArtClient::FrameIcon,
+            "wxART_MESSAGE_BOX_C" => ArtClient::MessageBox,
+            "wxART_CMN_DIALOG_C" => ArtClient::Dialog,
+            "wxART_HELP_BROWSER_C" => ArtClient::HelpBrowser,
+            "wxART_LIST_C" => ArtClient::List,
+            _ => ArtClient::Other,
+        }
+    }
 }
 
 /// Provides static methods to access stock art (bitmaps/icons).
@@ -298,4 +398,96 @@ impl ArtProvider {
     pub fn has_native_provider() -> bool {
         unsafe { ffi::wxd_ArtProvider_HasNativeProvider() }
     }
+
+    /// Registers a custom provider that is asked for stock art before all others, so an
+    /// application can theme the icons used by its own code and by wxWidgets' dialogs.
+    ///
+    /// The closure gets the requested size, which may be `(-1, -1)` if none was given
+    /// (see [`get_size_hint`](Self::get_size_hint)); bitmaps of another size are rescaled.
+    /// Returning `None` lets the next provider, eventually the native one, supply the art.
+    ///
+    /// Must be called after the application has been initialized, e.g. inside
+    /// `wxdragon::main`. The provider stays registered until removed or until the
+    /// application exits.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # fn load_theme_icon(name: &str, size: Size) -> Option<Bitmap> { None }
+    /// ArtProvider::push(|id, _client, size| match id {
+    ///     ArtId::FileOpen => load_theme_icon("open", size),
+    ///     ArtId::FileSave => load_theme_icon("save", size),
+    ///     _ => None,
+    /// });
+    /// ```
+    pub fn push<F>(provider: F) -> CustomArtProvider
+    where
+        F: Fn(ArtId, ArtClient, Size) -> Option<Bitmap> + 'static,
+    {
+        let callback: ArtProviderCallback = Box::new(provider);
+        let user_data = Box::into_raw(Box::new(callback)) as *mut c_void;
+        let ptr = unsafe { ffi::wxd_ArtProvider_Push(Some(create_bitmap_trampoline), user_data) };
+        CustomArtProvider { ptr }
+    }
+}
+
+/// A provider registered with [`ArtProvider::push`].
+///
+/// Dropping this value leaves the provider registered; call [`remove`](Self::remove) to
+/// unregister it.
+pub struct CustomArtProvider {
+    ptr: *mut ffi::wxd_ArtProvider_t,
+}
+
+impl CustomArtProvider {
+    /// Unregisters and destroys the provider. Returns false if it was already removed,
+    /// e.g. because the application has exited.
+    pub fn remove(self) -> bool {
+        unsafe { ffi::wxd_ArtProvider_Delete(self.ptr) }
+    }
+}
+
+type ArtProviderCallback = Box<dyn Fn(ArtId, ArtClient, Size) -> Option<Bitmap> + 'static>;
+
+/// Called by C++ to create a bitmap from a custom provider.
+unsafe extern "C" fn create_bitmap_trampoline(
+    user_data: *mut c_void,
+    id: *const c_char,
+    client: *const c_char,
+    size: ffi::wxd_Size,
+) -> *mut ffi::wxd_Bitmap_t {
+    if user_data.is_null() || id.is_null() {
+        return ptr::null_mut();
+    }
+    let callback = unsafe { &*(user_data as *const ArtProviderCallback) };
+    // Ids without an ArtId (e.g. application defined ones) are left to the other providers
+    let Some(id) = ArtId::from_wx_str(&unsafe { CStr::from_ptr(id) }.to_string_lossy()) else {
+        return ptr::null_mut();
+    };
+    let client = if client.is_null() {
+        ArtClient::Other
+    } else {
+        ArtClient::from_wx_str(&unsafe { CStr::from_ptr(client) }.to_string_lossy())
+    };
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(id, client, Size::from(size)))) {
+        // C++ takes ownership of the result, so borrowed wrappers are copied
+        Ok(Some(bitmap)) if bitmap.is_ok() => bitmap.clone().into_raw_mut(),
+        Ok(_) => ptr::null_mut(),
+        Err(_) => {
+            log::error!("Panic in custom art provider for {id:?}");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Function called by C++ to drop a custom provider's closure.
+///
+/// # Safety
+/// `user_data` must be a pointer created by [`ArtProvider::push`] and must not be used
+/// after this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wxd_Drop_Rust_ArtProviderCallback(user_data: *mut c_void) {
+    if !user_data.is_null() {
+        let _ = unsafe { Box::from_raw(user_data as *mut ArtProviderCallback) };
+    }
 }
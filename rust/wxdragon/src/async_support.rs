@@ -0,0 +1,363 @@
+//! Running futures on the main (UI) thread.
+//!
+//! [`spawn_local`] polls a future on the main thread, driven by the event loop: a task is
+//! polled again only after its waker was woken, which schedules the poll with
+//! [`call_after`](crate::call_after) and so also wakes a sleeping event loop. Futures may
+//! hold widgets and other `!Send` state, and can await values produced elsewhere, e.g. the
+//! receiving end of a channel fed by a tokio runtime on a background thread.
+//!
+//! Wakers handed to spawned futures are [`MainThreadWaker`]s, which can be woken from any
+//! thread. Modal dialogs can be awaited with
+//! [`Dialog::show_modal_async`](crate::dialogs::Dialog::show_modal_async) or [`run_modal`],
+//! which run the modal loop from the event loop instead of inside the task's poll.
+//!
+//! # Example
+//! ```ignore
+//! use wxdragon::prelude::*;
+//!
+//! // A tokio runtime on a background thread does the networking
+//! let runtime = tokio::runtime::Runtime::new().unwrap();
+//! let (sender, receiver) = tokio::sync::oneshot::channel();
+//! runtime.spawn(async move {
+//!     let _ = sender.send(fetch_title().await);
+//! });
+//!
+//! // The UI side awaits the result on the main thread and updates the widget directly
+//! wxdragon::async_support::spawn_local(async move {
+//!     if let Ok(title) = receiver.await {
+//!         status.set_label(&title);
+//!     }
+//! });
+//! ```
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Wake, Waker};
+
+static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(1);
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// A spawned task, or a marker that it is being polled right now.
+enum TaskSlot {
+    Idle(LocalFuture),
+    /// Taken out for polling; records a wake-up or an abort that arrived meanwhile.
+    Polling {
+        woken: bool,
+        aborted: bool,
+    },
+}
+
+thread_local! {
+    /// Tasks spawned on this (the main) thread, by id.
+    static TASKS: RefCell<HashMap<u64, TaskSlot>> = RefCell::new(HashMap::new());
+    /// Id of the task being polled, for `MainThreadWaker::current`.
+    static CURRENT_TASK: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    /// Modal calls waiting to be run from the event loop.
+    static DEFERRED: RefCell<VecDeque<Box<dyn FnOnce()>>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// Ids of tasks that were woken, and whether polling them is already scheduled.
+struct ReadyQueue {
+    ids: Vec<u64>,
+    scheduled: bool,
+}
+
+static READY: Mutex<ReadyQueue> = Mutex::new(ReadyQueue {
+    ids: Vec::new(),
+    scheduled: false,
+});
+
+fn lock_ready() -> MutexGuard<'static, ReadyQueue> {
+    READY.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Wakes a task spawned with [`spawn_local`], from any thread.
+///
+/// This is the waker every spawned future gets through its [`Context`]. Use
+/// [`MainThreadWaker::current`] to get one explicitly, e.g. to hand to a callback-based
+/// API on a background runtime, and call [`wake`](Self::wake) when the result is ready.
+#[derive(Debug, Clone)]
+pub struct MainThreadWaker {
+    task_id: u64,
+}
+
+impl MainThreadWaker {
+    /// Returns the waker of the task being polled on this thread, or `None` outside of a
+    /// task spawned with [`spawn_local`].
+    pub fn current() -> Option<Self> {
+        CURRENT_TASK.with(|current| current.borrow().last().map(|&task_id| Self { task_id }))
+    }
+
+    /// Schedules the task to be polled again on the main thread.
+    ///
+    /// Does nothing if the task has finished or the main loop has ended.
+    pub fn wake(&self) {
+        schedule(self.task_id);
+    }
+
+    /// Converts into a standard [`Waker`].
+    pub fn into_waker(self) -> Waker {
+        Waker::from(Arc::new(self))
+    }
+}
+
+impl Wake for MainThreadWaker {
+    fn wake(self: Arc<Self>) {
+        schedule(self.task_id);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        schedule(self.task_id);
+    }
+}
+
+/// Marks a task as ready and makes sure the ready tasks are polled from the event loop.
+fn schedule(task_id: u64) {
+    let mut ready = lock_ready();
+    if !ready.ids.contains(&task_id) {
+        ready.ids.push(task_id);
+    }
+    if ready.scheduled {
+        return;
+    }
+    ready.scheduled = true;
+    drop(ready);
+
+    if crate::call_after(poll_ready_tasks).is_err() {
+        let mut ready = lock_ready();
+        ready.scheduled = false;
+        ready.ids.clear();
+    }
+}
+
+/// Polls every task woken since the last run. Runs on the main thread.
+fn poll_ready_tasks() {
+    let ids = {
+        let mut ready = lock_ready();
+        ready.scheduled = false;
+        std::mem::take(&mut ready.ids)
+    };
+    for task_id in ids {
+        poll_task(task_id);
+    }
+}
+
+fn poll_task(task_id: u64) {
+    let future = TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        match tasks.get_mut(&task_id) {
+            Some(TaskSlot::Polling { woken, .. }) => {
+                // Woken from inside its own poll, e.g. through a nested event loop
+                *woken = true;
+                None
+            }
+            Some(slot) => match std::mem::replace(
+                slot,
+                TaskSlot::Polling {
+                    woken: false,
+                    aborted: false,
+                },
+            ) {
+                TaskSlot::Idle(future) => Some(future),
+                TaskSlot::Polling { .. } => unreachable!(),
+            },
+            None => None,
+        }
+    });
+    let Some(mut future) = future else {
+        return;
+    };
+
+    let waker = MainThreadWaker { task_id }.into_waker();
+    let mut cx = Context::from_waker(&waker);
+    CURRENT_TASK.with(|current| current.borrow_mut().push(task_id));
+    let poll = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| future.as_mut().poll(&mut cx)));
+    CURRENT_TASK.with(|current| current.borrow_mut().pop());
+
+    // A finished future is dropped after the borrow, as dropping it may touch other tasks.
+    let (woken, finished) = TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        match poll {
+            Ok(Poll::Pending) => match tasks.remove(&task_id) {
+                Some(TaskSlot::Polling { aborted: true, .. }) => (false, Some(future)),
+                slot => {
+                    let woken = matches!(slot, Some(TaskSlot::Polling { woken: true, .. }));
+                    tasks.insert(task_id, TaskSlot::Idle(future));
+                    (woken, None)
+                }
+            },
+            Ok(Poll::Ready(())) => {
+                tasks.remove(&task_id);
+                (false, Some(future))
+            }
+            Err(_) => {
+                log::error!("Panic caught in async task {task_id}");
+                tasks.remove(&task_id);
+                (false, Some(future))
+            }
+        }
+    });
+    drop(finished);
+    if woken {
+        schedule(task_id);
+    }
+}
+
+/// Output slot shared between a running task (or modal call) and the future awaiting it.
+enum JoinState<T> {
+    Running(Option<Waker>),
+    Finished(T),
+    Taken,
+}
+
+/// Stores `output` and wakes whoever awaits it.
+fn finish_join_state<T>(state: &RefCell<JoinState<T>>, output: T) {
+    let waker = match std::mem::replace(&mut *state.borrow_mut(), JoinState::Finished(output)) {
+        JoinState::Running(waker) => waker,
+        _ => None,
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+fn poll_join_state<T>(state: &RefCell<JoinState<T>>, cx: &mut Context<'_>) -> Poll<T> {
+    let mut state = state.borrow_mut();
+    match std::mem::replace(&mut *state, JoinState::Taken) {
+        JoinState::Finished(output) => Poll::Ready(output),
+        JoinState::Running(_) => {
+            *state = JoinState::Running(Some(cx.waker().clone()));
+            Poll::Pending
+        }
+        // Polled again after completing
+        JoinState::Taken => Poll::Pending,
+    }
+}
+
+/// Handle to a task spawned with [`spawn_local`].
+///
+/// Awaiting the handle gives the task's output. Dropping it detaches the task, which keeps
+/// running; use [`abort`](Self::abort) to stop it.
+pub struct JoinHandle<T> {
+    task_id: u64,
+    state: Rc<RefCell<JoinState<T>>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Returns true once the task has produced its output.
+    pub fn is_finished(&self) -> bool {
+        !matches!(*self.state.borrow(), JoinState::Running(_))
+    }
+
+    /// Stops the task by dropping its future. It is never polled again, and awaiting the
+    /// handle afterwards never completes.
+    pub fn abort(&self) {
+        let removed = TASKS.with(|tasks| {
+            let mut tasks = tasks.borrow_mut();
+            match tasks.get_mut(&self.task_id) {
+                Some(TaskSlot::Idle(_)) => tasks.remove(&self.task_id),
+                // Being polled right now (the task aborts itself); dropped when the poll returns
+                Some(TaskSlot::Polling { aborted, .. }) => {
+                    *aborted = true;
+                    None
+                }
+                None => None,
+            }
+        });
+        // Dropped outside the borrow, as dropping the future may touch other tasks
+        drop(removed);
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        poll_join_state(&self.state, cx)
+    }
+}
+
+/// Runs `future` on the main thread, polling it from the event loop whenever it is woken.
+///
+/// Must be called on the main thread once [`main`](crate::main) has started the
+/// application, e.g. from its init closure or an event handler. The future doesn't need to
+/// be `Send`, so it can use widgets directly.
+pub fn spawn_local<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
+    crate::app::assert_main_thread();
+    let task_id = NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed);
+    let state = Rc::new(RefCell::new(JoinState::Running(None)));
+
+    let task_state = state.clone();
+    let task: LocalFuture = Box::pin(async move {
+        finish_join_state(&task_state, future.await);
+    });
+
+    TASKS.with(|tasks| tasks.borrow_mut().insert(task_id, TaskSlot::Idle(task)));
+    schedule(task_id);
+    JoinHandle { task_id, state }
+}
+
+/// Runs `modal` from the event loop and resolves to its result, for awaiting a modal
+/// dialog (or anything else running a nested event loop) from a task.
+///
+/// Calling `show_modal` directly inside a task would run the nested loop inside the task's
+/// poll; this runs it after the poll returned, like an event handler would.
+///
+/// # Example
+/// ```rust,no_run
+/// use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// wxdragon::async_support::spawn_local(async move {
+///     let dialog = FileDialog::builder(&frame).build();
+///     if wxdragon::async_support::run_modal(move || dialog.show_modal()).await == ID_OK {
+///         // ...
+///     }
+/// });
+/// ```
+pub fn run_modal<T, F>(modal: F) -> ModalFuture<T>
+where
+    T: 'static,
+    F: FnOnce() -> T + 'static,
+{
+    let state = Rc::new(RefCell::new(JoinState::Running(None)));
+    let modal_state = state.clone();
+    DEFERRED.with(|deferred| {
+        deferred.borrow_mut().push_back(Box::new(move || {
+            finish_join_state(&modal_state, modal());
+        }))
+    });
+    if crate::call_after(run_deferred).is_err() {
+        log::warn!("run_modal: the main loop isn't running, the modal call never runs");
+    }
+    ModalFuture { state }
+}
+
+fn run_deferred() {
+    let next = DEFERRED.with(|deferred| deferred.borrow_mut().pop_front());
+    if let Some(call) = next {
+        call();
+    }
+}
+
+/// Future returned by [`run_modal`] and the dialogs' `show_modal_async`.
+pub struct ModalFuture<T> {
+    state: Rc<RefCell<JoinState<T>>>,
+}
+
+impl<T> Future for ModalFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        poll_join_state(&self.state, cx)
+    }
+}
@@ -1,11 +1,51 @@
 //!
 //! Safe wrapper for wxBitmap.
 
+use crate::cursor::BitmapType;
+use crate::geometry::Rect;
+use std::ffi::CString;
+use std::fmt;
 use std::marker::PhantomData;
 use std::os::raw::{c_int, c_uchar};
+use std::path::Path;
 use std::rc::Rc;
 use wxdragon_sys as ffi;
 
+/// Error returned when loading, saving or encoding a [`Bitmap`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitmapError {
+    /// The path contained a nul byte.
+    InvalidPath(String),
+    /// The file does not exist.
+    FileNotFound(String),
+    /// No image handler is available for this type.
+    UnsupportedFormat(BitmapType),
+    /// The file or data is not an image in a supported format, or is corrupt.
+    DecodeFailed(String),
+    /// The bitmap is invalid (e.g. the null bitmap) and can't be saved.
+    InvalidBitmap,
+    /// Writing the file failed, e.g. because the directory doesn't exist.
+    SaveFailed(String),
+    /// Encoding to the requested type failed.
+    EncodeFailed(BitmapType),
+}
+
+impl fmt::Display for BitmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitmapError::InvalidPath(path) => write!(f, "Invalid image path: {path:?}"),
+            BitmapError::FileNotFound(path) => write!(f, "Image file not found: {path}"),
+            BitmapError::UnsupportedFormat(kind) => write!(f, "Unsupported image format: {kind:?}"),
+            BitmapError::DecodeFailed(source) => write!(f, "Failed to decode image from {source}"),
+            BitmapError::InvalidBitmap => write!(f, "The bitmap is not valid"),
+            BitmapError::SaveFailed(path) => write!(f, "Failed to save image to {path}"),
+            BitmapError::EncodeFailed(kind) => write!(f, "Failed to encode image as {kind:?}"),
+        }
+    }
+}
+
+impl std::error::Error for BitmapError {}
+
 /// Represents a platform-dependent bitmap image.
 #[derive(Debug)] // Keep Debug if useful, or remove if pointer isn't meaningful for debug
 pub struct Bitmap {
@@ -56,12 +96,25 @@ impl Bitmap {
     /// * `width` - The width of the image in pixels.
     /// * `height` - The height of the image in pixels.
     ///
+    /// The pixel layout is the same on all platforms: rows from top to bottom, pixels
+    /// from left to right, each as the bytes R, G, B, A with straight (not premultiplied)
+    /// alpha, and no padding between rows.
+    ///
     /// Returns `None` if the bitmap creation fails (e.g., invalid dimensions, memory allocation error).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// // A 2x1 bitmap with a red and a half-transparent blue pixel
+    /// let pixels = [255, 0, 0, 255, 0, 0, 255, 128];
+    /// let bitmap = Bitmap::from_rgba(&pixels, 2, 1).unwrap();
+    /// assert!(bitmap.has_alpha());
+    /// ```
     pub fn from_rgba(data: &[u8], width: u32, height: u32) -> Option<Self> {
-        let expected_len = (width * height * 4) as usize;
-        if data.len() != expected_len || width == 0 || height == 0 {
+        let expected_len = Self::buffer_len(width, height, 4);
+        if expected_len != Some(data.len()) || width == 0 || height == 0 {
             log::error!(
-                "Bitmap::from_rgba: Invalid data length or dimensions. Expected {}, got {}, w={}, h={}",
+                "Bitmap::from_rgba: Invalid data length or dimensions. Expected {:?}, got {}, w={}, h={}",
                 expected_len,
                 data.len(),
                 width,
@@ -83,6 +136,139 @@ impl Bitmap {
         }) // We own bitmaps created this way
     }
 
+    /// Creates a new opaque bitmap from raw RGB pixel data (3 bytes per pixel, R, G, B),
+    /// laid out like [`from_rgba`](Self::from_rgba) without the alpha byte.
+    ///
+    /// Returns `None` if the data length doesn't match the dimensions or creation fails.
+    pub fn from_rgb(data: &[u8], width: u32, height: u32) -> Option<Self> {
+        let expected_len = Self::buffer_len(width, height, 3);
+        if expected_len != Some(data.len()) || width == 0 || height == 0 {
+            log::error!(
+                "Bitmap::from_rgb: Invalid data length or dimensions. Expected {:?}, got {}, w={}, h={}",
+                expected_len,
+                data.len(),
+                width,
+                height
+            );
+            return None;
+        }
+
+        let ptr = unsafe { ffi::wxd_Bitmap_CreateFromRGB(data.as_ptr() as *const c_uchar, width as c_int, height as c_int) };
+        if ptr.is_null() {
+            return None;
+        }
+        Some(Bitmap {
+            ptr,
+            owned: true,
+            _nosend_nosync: PhantomData,
+        })
+    }
+
+    /// Length of a pixel buffer, `None` if it overflows or exceeds what wxWidgets accepts
+    fn buffer_len(width: u32, height: u32, bytes_per_pixel: usize) -> Option<usize> {
+        if width > i32::MAX as u32 || height > i32::MAX as u32 {
+            return None;
+        }
+        (width as usize).checked_mul(height as usize)?.checked_mul(bytes_per_pixel)
+    }
+
+    /// Loads an image file, detecting its format (PNG, JPEG, BMP, GIF, ICO, ...).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// match Bitmap::from_file("assets/logo.png") {
+    ///     Ok(bitmap) => println!("{}x{}", bitmap.get_width(), bitmap.get_height()),
+    ///     Err(e) => eprintln!("{e}"),
+    /// }
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, BitmapError> {
+        let path = path.as_ref();
+        let display = path.display().to_string();
+        if !path.is_file() {
+            return Err(BitmapError::FileNotFound(display));
+        }
+        let c_path = CString::new(path.to_string_lossy().as_bytes()).map_err(|_| BitmapError::InvalidPath(display.clone()))?;
+        let ptr = unsafe { ffi::wxd_Bitmap_CreateFromFile(c_path.as_ptr()) };
+        if ptr.is_null() {
+            return Err(BitmapError::DecodeFailed(display));
+        }
+        Ok(Bitmap {
+            ptr,
+            owned: true,
+            _nosend_nosync: PhantomData,
+        })
+    }
+
+    /// Decodes an image held in memory (e.g. from `include_bytes!` or a download),
+    /// detecting its format.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, BitmapError> {
+        let ptr = unsafe { ffi::wxd_Bitmap_CreateFromBytes(data.as_ptr(), data.len()) };
+        if ptr.is_null() {
+            return Err(BitmapError::DecodeFailed(format!("{} bytes of memory", data.len())));
+        }
+        Ok(Bitmap {
+            ptr,
+            owned: true,
+            _nosend_nosync: PhantomData,
+        })
+    }
+
+    /// Saves the bitmap to a file in the given format.
+    pub fn save_file(&self, path: impl AsRef<Path>, kind: BitmapType) -> Result<(), BitmapError> {
+        self.save_file_impl(path.as_ref(), kind, -1)
+    }
+
+    /// Saves the bitmap as JPEG (or another lossy format) with `quality` from 0 to 100.
+    pub fn save_file_with_quality(&self, path: impl AsRef<Path>, kind: BitmapType, quality: u8) -> Result<(), BitmapError> {
+        self.save_file_impl(path.as_ref(), kind, quality.min(100) as c_int)
+    }
+
+    fn save_file_impl(&self, path: &Path, kind: BitmapType, quality: c_int) -> Result<(), BitmapError> {
+        self.check_can_save(kind)?;
+        let display = path.display().to_string();
+        let c_path = CString::new(path.to_string_lossy().as_bytes()).map_err(|_| BitmapError::InvalidPath(display.clone()))?;
+        if unsafe { ffi::wxd_Bitmap_SaveFile(self.as_const_ptr(), c_path.as_ptr(), kind.to_raw(), quality) } {
+            Ok(())
+        } else {
+            Err(BitmapError::SaveFailed(display))
+        }
+    }
+
+    /// Encodes the bitmap in the given format without touching the disk, e.g. for uploading.
+    ///
+    /// `quality` (0-100) applies to JPEG; `None` uses the default.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # let bitmap = Bitmap::new(16, 16).unwrap();
+    /// let png = bitmap.encode(BitmapType::Png, None).unwrap();
+    /// let jpeg = bitmap.encode(BitmapType::Jpeg, Some(85)).unwrap();
+    /// ```
+    pub fn encode(&self, kind: BitmapType, quality: Option<u8>) -> Result<Vec<u8>, BitmapError> {
+        self.check_can_save(kind)?;
+        let quality = quality.map_or(-1, |q| q.min(100) as c_int);
+        let mut len = 0usize;
+        let data = unsafe { ffi::wxd_Bitmap_Encode(self.as_const_ptr(), kind.to_raw(), quality, &mut len) };
+        if data.is_null() {
+            return Err(BitmapError::EncodeFailed(kind));
+        }
+        let bytes = unsafe { std::slice::from_raw_parts(data, len).to_vec() };
+        unsafe { ffi::wxd_Bitmap_FreeEncodedData(data) };
+        Ok(bytes)
+    }
+
+    fn check_can_save(&self, kind: BitmapType) -> Result<(), BitmapError> {
+        if !self.is_ok() {
+            return Err(BitmapError::InvalidBitmap);
+        }
+        if matches!(kind, BitmapType::Any | BitmapType::Invalid) || !unsafe { ffi::wxd_Bitmap_IsTypeSupported(kind.to_raw()) } {
+            return Err(BitmapError::UnsupportedFormat(kind));
+        }
+        Ok(())
+    }
+
     /// Returns `true` if this bitmap is owned by Rust and will be automatically destroyed when dropped.
     ///
     /// Returns `false` if the bitmap is managed elsewhere (e.g., by wxWidgets or another owner)
@@ -119,6 +305,40 @@ impl Bitmap {
         unsafe { ffi::wxd_Bitmap_IsOk(self.as_const_ptr()) }
     }
 
+    /// Returns `true` if the bitmap has an alpha channel.
+    ///
+    /// Bitmaps created by [`from_rgba`](Self::from_rgba) always have one.
+    pub fn has_alpha(&self) -> bool {
+        !self.as_const_ptr().is_null() && unsafe { ffi::wxd_Bitmap_HasAlpha(self.as_const_ptr()) }
+    }
+
+    /// Copies the part of the bitmap inside `rect` into a new bitmap.
+    ///
+    /// Returns `None` if the rectangle is empty or not entirely inside the bitmap.
+    pub fn get_sub_bitmap(&self, rect: Rect) -> Option<Bitmap> {
+        if self.as_const_ptr().is_null() {
+            return None;
+        }
+        let ptr = unsafe { ffi::wxd_Bitmap_GetSubBitmap(self.as_const_ptr(), rect.x, rect.y, rect.width, rect.height) };
+        if ptr.is_null() {
+            return None;
+        }
+        Some(Bitmap {
+            ptr,
+            owned: true,
+            _nosend_nosync: PhantomData,
+        })
+    }
+
+    /// Returns the pixels in the layout accepted by [`from_rgba`](Self::from_rgba);
+    /// empty if the bitmap is invalid.
+    ///
+    /// Bitmaps without alpha read back as fully opaque. On platforms that store
+    /// premultiplied alpha, the colour of partially transparent pixels may be rounded.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.get_rgba_data().unwrap_or_default()
+    }
+
     /// Extracts the raw RGBA pixel data from the bitmap.
     ///
     /// Returns a vector containing RGBA pixel data where each pixel is represented
@@ -295,7 +515,9 @@ impl Drop for Bitmap {
 
 #[cfg(test)]
 mod tests {
-    use super::Bitmap;
+    use super::{Bitmap, BitmapError};
+    use crate::cursor::BitmapType;
+    use crate::geometry::Rect;
     use wxdragon_sys as ffi;
 
     #[test]
@@ -321,4 +543,85 @@ mod tests {
         // When this test ends, `bmp` will be dropped and should destroy its own handle.
         // If ownership transfer or Drop were incorrect, this test would double-free or leak.
     }
+
+    /// Opaque test pattern where every byte differs from its neighbours
+    fn opaque_pattern(w: u32, h: u32) -> Vec<u8> {
+        (0..w * h)
+            .flat_map(|i| [(i * 7) as u8, (i * 13 + 50) as u8, (i * 29 + 100) as u8, 255])
+            .collect()
+    }
+
+    #[test]
+    fn bitmap_rgba_round_trip() {
+        let (w, h) = (5u32, 3u32);
+        let rgba = opaque_pattern(w, h);
+
+        let bmp = Bitmap::from_rgba(&rgba, w, h).expect("failed to create bitmap from rgba");
+        assert_eq!((bmp.get_width(), bmp.get_height()), (w as i32, h as i32));
+        assert!(bmp.has_alpha());
+        assert_eq!(bmp.to_rgba(), rgba);
+    }
+
+    #[test]
+    fn bitmap_rgb_reads_back_opaque() {
+        let (w, h) = (4u32, 2u32);
+        let rgba = opaque_pattern(w, h);
+        let rgb: Vec<u8> = rgba.chunks(4).flat_map(|p| [p[0], p[1], p[2]]).collect();
+
+        let bmp = Bitmap::from_rgb(&rgb, w, h).expect("failed to create bitmap from rgb");
+        assert_eq!(bmp.to_rgba(), rgba);
+    }
+
+    #[test]
+    fn bitmap_rejects_wrong_buffer_length() {
+        assert!(Bitmap::from_rgba(&[0; 15], 2, 2).is_none());
+        assert!(Bitmap::from_rgb(&[0; 13], 2, 2).is_none());
+        assert!(Bitmap::from_rgba(&[], 0, 0).is_none());
+        assert!(Bitmap::from_rgba(&[0; 4], u32::MAX, u32::MAX).is_none());
+    }
+
+    #[test]
+    fn bitmap_png_encode_round_trip() {
+        let (w, h) = (3u32, 2u32);
+        let rgba = opaque_pattern(w, h);
+        let bmp = Bitmap::from_rgba(&rgba, w, h).expect("failed to create bitmap from rgba");
+
+        let png = bmp.encode(BitmapType::Png, None).expect("png encoding");
+        assert_eq!(&png[..4], b"\x89PNG");
+        let decoded = Bitmap::from_bytes(&png).expect("png decoding");
+        assert_eq!(decoded.to_rgba(), rgba);
+    }
+
+    #[test]
+    fn bitmap_load_errors_are_reported() {
+        assert!(matches!(
+            Bitmap::from_file("/nonexistent/image.png"),
+            Err(BitmapError::FileNotFound(_))
+        ));
+        assert!(matches!(
+            Bitmap::from_bytes(b"not an image"),
+            Err(BitmapError::DecodeFailed(_))
+        ));
+        assert_eq!(
+            Bitmap::null_bitmap().encode(BitmapType::Png, None),
+            Err(BitmapError::InvalidBitmap)
+        );
+    }
+
+    #[test]
+    fn bitmap_sub_bitmap_copies_pixels() {
+        let (w, h) = (4u32, 3u32);
+        let rgba = opaque_pattern(w, h);
+        let bmp = Bitmap::from_rgba(&rgba, w, h).expect("failed to create bitmap from rgba");
+
+        let sub = bmp.get_sub_bitmap(Rect::new(1, 1, 2, 2)).expect("sub bitmap inside bounds");
+        let expected: Vec<u8> = [1usize, 2]
+            .iter()
+            .flat_map(|&row| rgba[(row * 4 + 1) * 4..(row * 4 + 3) * 4].to_vec())
+            .collect();
+        assert_eq!(sub.to_rgba(), expected);
+
+        assert!(bmp.get_sub_bitmap(Rect::new(3, 0, 2, 1)).is_none());
+        assert!(bmp.get_sub_bitmap(Rect::new(0, 0, 0, 1)).is_none());
+    }
 }
@@ -7,6 +7,7 @@ use wxdragon_sys as ffi;
 
 use crate::bitmap::Bitmap;
 use crate::geometry::Size;
+use crate::image::{Image, ResizeQuality};
 use crate::window::WxWidget;
 
 /// Represents a collection of bitmaps of the same image in different sizes/resolutions.
@@ -21,6 +22,10 @@ use crate::window::WxWidget;
 /// - An SVG file (which can be rendered at any size)
 /// - SVG data in memory
 /// - SVG string content
+/// - PNG data rendered for specific scale factors
+///
+/// APIs that display an image take `impl Into<BitmapBundleArg>`, so they accept a bundle
+/// as well as a plain [`Bitmap`].
 #[derive(Debug)]
 pub struct BitmapBundle {
     ptr: *mut ffi::wxd_BitmapBundle_t,
@@ -131,15 +136,18 @@ impl BitmapBundle {
         }
     }
 
-    /// Creates a bitmap bundle from raw SVG data.
+    /// Creates a bitmap bundle from SVG bytes, e.g. from `include_bytes!`.
+    ///
+    /// The image is rendered at the exact size needed for each DPI, so it stays crisp at
+    /// any scaling.
     ///
     /// # Arguments
     /// * `data` - Byte slice containing SVG data.
-    /// * `default_size` - The size to use when the exact size needed is not known.
+    /// * `default_size` - The size at 100% scaling, in DIPs.
     ///
     /// # Returns
     /// None if the SVG data is invalid.
-    pub fn from_svg_data(data: &[u8], default_size: Size) -> Option<Self> {
+    pub fn from_svg_bytes(data: &[u8], default_size: Size) -> Option<Self> {
         if data.is_empty() {
             return None;
         }
@@ -155,6 +163,48 @@ impl BitmapBundle {
         }
     }
 
+    /// Creates a bitmap bundle from raw SVG data.
+    #[deprecated(note = "renamed to `from_svg_bytes`")]
+    pub fn from_svg_data(data: &[u8], default_size: Size) -> Option<Self> {
+        Self::from_svg_bytes(data, default_size)
+    }
+
+    /// Creates a bitmap bundle from PNG (or any other supported format) images exported for
+    /// specific scale factors, e.g. `icon.png` and `icon@2x.png`.
+    ///
+    /// The size at 100% scaling is derived from the image with the smallest scale. If no 1x
+    /// image is given, one is produced by scaling that image down.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # fn load(icon_png: &[u8], icon_2x_png: &[u8]) -> Option<BitmapBundle> {
+    /// BitmapBundle::from_png_bytes_for_scales(&[(1.0, icon_png), (2.0, icon_2x_png)])
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    /// None if the slice is empty, a scale is below 1.0 or an image can't be decoded.
+    pub fn from_png_bytes_for_scales(images: &[(f64, &[u8])]) -> Option<Self> {
+        let mut decoded = Vec::with_capacity(images.len());
+        for &(scale, data) in images {
+            if scale.is_nan() || scale < 1.0 {
+                return None;
+            }
+            decoded.push((scale, Image::from_bytes(data).ok()?));
+        }
+
+        // wxBitmapBundle takes its default size from the smallest bitmap
+        let (base_scale, base_image) = decoded.iter().min_by(|a, b| a.0.total_cmp(&b.0))?;
+        let mut bitmaps: Vec<Bitmap> = decoded.iter().map(|(_, image)| image.to_bitmap()).collect();
+        if *base_scale > 1.0 {
+            let width = (base_image.get_width() as f64 / base_scale).round().max(1.0) as i32;
+            let height = (base_image.get_height() as f64 / base_scale).round().max(1.0) as i32;
+            bitmaps.push(base_image.scale(width, height, ResizeQuality::High)?.to_bitmap());
+        }
+        Some(Self::from_bitmaps(&bitmaps))
+    }
+
     /// Retrieves a bitmap of the specified size from the bundle.
     ///
     /// The returned bitmap is the best match for the requested size, possibly scaled
@@ -277,3 +327,55 @@ impl Default for BitmapBundle {
         Self::new()
     }
 }
+
+/// An image argument accepted wherever a bitmap is displayed: a [`BitmapBundle`] (by value or
+/// reference) or a single [`Bitmap`], which is wrapped in a bundle and scaled as needed.
+#[derive(Debug, Clone)]
+pub enum BitmapBundleArg<'a> {
+    /// A bundle owned by the caller
+    Borrowed(&'a BitmapBundle),
+    /// A bundle created for this call
+    Owned(BitmapBundle),
+}
+
+impl BitmapBundleArg<'_> {
+    /// Returns the raw bundle pointer, valid as long as `self`.
+    pub fn as_ptr(&self) -> *mut ffi::wxd_BitmapBundle_t {
+        match self {
+            BitmapBundleArg::Borrowed(bundle) => bundle.as_ptr(),
+            BitmapBundleArg::Owned(bundle) => bundle.as_ptr(),
+        }
+    }
+
+    /// Converts into an owned bundle, cloning a borrowed one.
+    pub fn into_owned(self) -> BitmapBundle {
+        match self {
+            BitmapBundleArg::Borrowed(bundle) => bundle.clone(),
+            BitmapBundleArg::Owned(bundle) => bundle,
+        }
+    }
+}
+
+impl<'a> From<&'a BitmapBundle> for BitmapBundleArg<'a> {
+    fn from(bundle: &'a BitmapBundle) -> Self {
+        BitmapBundleArg::Borrowed(bundle)
+    }
+}
+
+impl From<BitmapBundle> for BitmapBundleArg<'_> {
+    fn from(bundle: BitmapBundle) -> Self {
+        BitmapBundleArg::Owned(bundle)
+    }
+}
+
+impl From<&Bitmap> for BitmapBundleArg<'_> {
+    fn from(bitmap: &Bitmap) -> Self {
+        BitmapBundleArg::Owned(BitmapBundle::from_bitmap(bitmap))
+    }
+}
+
+impl From<Bitmap> for BitmapBundleArg<'_> {
+    fn from(bitmap: Bitmap) -> Self {
+        BitmapBundleArg::Owned(BitmapBundle::from_bitmap(&bitmap))
+    }
+}
@@ -1,8 +1,106 @@
-use crate::data_object::DataObject;
+//! Access to the system clipboard.
+//!
+//! The functions at the module level cover the common text case and open and close the
+//! clipboard around each operation:
+//!
+//! ```rust,no_run
+//! use wxdragon::clipboard;
+//!
+//! if clipboard::set_text("Hello, Clipboard!") {
+//!     // Keep the text available after the application exits
+//!     clipboard::flush();
+//! }
+//! if let Some(text) = clipboard::get_text() {
+//!     println!("Clipboard text: {text}");
+//! }
+//! ```
+//!
+//! Bitmaps and file lists work the same way with [`set_bitmap`], [`get_bitmap`], [`set_files`]
+//! and [`get_files`]. To offer several formats at once, e.g. readable text together with an
+//! application-private format, put a
+//! [`DataObjectComposite`](crate::data_object::DataObjectComposite) on the clipboard with
+//! [`set_data_object`], and read it back in the format the application prefers with
+//! [`get_preferred`]:
+//!
+//! ```rust,no_run
+//! use wxdragon::clipboard::{self, ClipboardData, ClipboardFormat};
+//! use wxdragon::data_object::DataObjectComposite;
+//!
+//! let json = br#"{"host":"example.com"}"#;
+//! clipboard::set_data_object(
+//!     DataObjectComposite::builder()
+//!         .with_data("application/x-myapp-record", json)
+//!         .with_text("example.com")
+//!         .build(),
+//! );
+//!
+//! let formats = [ClipboardFormat::Custom("application/x-myapp-record".into()), ClipboardFormat::Text];
+//! match clipboard::get_preferred(&formats) {
+//!     Some(ClipboardData::Custom(_, record)) => println!("Pasted a record of {} bytes", record.len()),
+//!     Some(ClipboardData::Text(text)) => println!("Pasted text: {text}"),
+//!     _ => {}
+//! }
+//! ```
+//!
+//! The clipboard may only be used from the main thread; elsewhere every operation fails
+//! (use [`call_after`](crate::app::call_after) to get there).
+
+use crate::bitmap::Bitmap;
+use crate::data_object::{CustomDataObject, DataFormat, DataObject, TransferOwnership};
+use crate::utils::ArrayString;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use wxdragon_sys as ffi;
 
+/// The kinds of data the clipboard helpers read and write.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClipboardFormat {
+    /// Plain text, in any encoding
+    Text,
+    /// An image
+    Bitmap,
+    /// A list of file paths, as copied in Explorer or Finder
+    Files,
+    /// An application defined format, named like a MIME type, e.g. `"application/x-myapp"`
+    Custom(String),
+}
+
+impl ClipboardFormat {
+    fn to_raw(&self) -> Option<i32> {
+        match self {
+            ClipboardFormat::Text => Some(DataFormat::TEXT),
+            ClipboardFormat::Bitmap => Some(DataFormat::BITMAP),
+            ClipboardFormat::Files => Some(DataFormat::FILENAME),
+            ClipboardFormat::Custom(_) => None,
+        }
+    }
+}
+
+/// Data read from the clipboard, in one of the formats of [`ClipboardFormat`].
+#[derive(Debug)]
+pub enum ClipboardData {
+    /// Plain text
+    Text(String),
+    /// An image
+    Bitmap(Bitmap),
+    /// A list of file paths
+    Files(Vec<String>),
+    /// The name and bytes of an application defined format
+    Custom(String, Vec<u8>),
+}
+
+impl ClipboardData {
+    /// The format this data was read in
+    pub fn format(&self) -> ClipboardFormat {
+        match self {
+            ClipboardData::Text(_) => ClipboardFormat::Text,
+            ClipboardData::Bitmap(_) => ClipboardFormat::Bitmap,
+            ClipboardData::Files(_) => ClipboardFormat::Files,
+            ClipboardData::Custom(name, _) => ClipboardFormat::Custom(name.clone()),
+        }
+    }
+}
+
 /// A struct representing the system clipboard.
 ///
 /// The clipboard can be used to copy data to or paste data from.
@@ -35,6 +133,22 @@ impl Clipboard {
         Self { ptr }
     }
 
+    /// Runs `f` with the clipboard held open, so several operations act on the same
+    /// clipboard contents, and closes it afterwards.
+    ///
+    /// Returns `None` if the clipboard couldn't be opened, e.g. outside the main thread.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use wxdragon::prelude::*;
+    /// let pasted = Clipboard::with(|clip| if clip.has_text() { clip.get_text() } else { None }).flatten();
+    /// ```
+    pub fn with<R>(f: impl FnOnce(&Clipboard) -> R) -> Option<R> {
+        let clipboard = Self::get();
+        let _locker = clipboard.locker()?;
+        Some(f(&clipboard))
+    }
+
     /// Open the clipboard before accessing data
     pub fn open(&self) -> bool {
         if self.ptr.is_null() {
@@ -184,6 +298,116 @@ impl Clipboard {
         Some(unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().to_string() })
     }
 
+    /// Returns true if the clipboard holds text that [`get_text`](Self::get_text) can read
+    pub fn has_text(&self) -> bool {
+        if self.ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Clipboard_HasText(self.ptr) }
+    }
+
+    /// Returns true if the clipboard holds data of the given kind
+    pub fn is_format_available(&self, format: ClipboardFormat) -> bool {
+        if self.ptr.is_null() {
+            return false;
+        }
+        match (&format, format.to_raw()) {
+            (_, Some(raw)) => unsafe { ffi::wxd_Clipboard_IsFormatAvailable(self.ptr, raw) },
+            (ClipboardFormat::Custom(name), None) => {
+                let Ok(c_name) = CString::new(name.as_str()) else {
+                    return false;
+                };
+                unsafe { ffi::wxd_Clipboard_IsCustomFormatAvailable(self.ptr, c_name.as_ptr()) }
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the formats of `formats` the clipboard holds, in the same order.
+    ///
+    /// Applications can't list every format on the clipboard portably, so this checks the
+    /// ones the caller knows how to handle.
+    pub fn available_formats(&self, formats: &[ClipboardFormat]) -> Vec<ClipboardFormat> {
+        formats
+            .iter()
+            .filter(|format| self.is_format_available((*format).clone()))
+            .cloned()
+            .collect()
+    }
+
+    /// Reads the clipboard in the first format of `formats` it holds, so list the formats
+    /// from most to least preferred. Returns `None` if it holds none of them.
+    pub fn get_preferred(&self, formats: &[ClipboardFormat]) -> Option<ClipboardData> {
+        formats.iter().find_map(|format| match format {
+            ClipboardFormat::Text if self.has_text() => self.get_text().map(ClipboardData::Text),
+            ClipboardFormat::Bitmap => self.get_bitmap().map(ClipboardData::Bitmap),
+            ClipboardFormat::Files => self.get_files().map(ClipboardData::Files),
+            ClipboardFormat::Custom(name) => self
+                .get_custom_data(name)
+                .map(|data| ClipboardData::Custom(name.clone(), data)),
+            _ => None,
+        })
+    }
+
+    /// Get the clipboard's data of the application defined format `format_name`, or `None`
+    /// if it holds none
+    pub fn get_custom_data(&self, format_name: &str) -> Option<Vec<u8>> {
+        if self.ptr.is_null() {
+            return None;
+        }
+        let c_name = CString::new(format_name).ok()?;
+        let ptr = unsafe { ffi::wxd_Clipboard_GetCustomData(self.ptr, c_name.as_ptr()) };
+        if ptr.is_null() {
+            return None;
+        }
+        Some(CustomDataObject::from_ptr(ptr).get_data())
+    }
+
+    /// Put a bitmap on the clipboard, replacing its contents.
+    ///
+    /// The alpha channel is kept on GTK and macOS; on Windows it depends on whether the
+    /// pasting application reads it.
+    pub fn set_bitmap(&self, bitmap: &Bitmap) -> bool {
+        if self.ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Clipboard_SetBitmap(self.ptr, bitmap.as_const_ptr()) }
+    }
+
+    /// Get the bitmap on the clipboard, or `None` if it holds no image
+    pub fn get_bitmap(&self) -> Option<Bitmap> {
+        if self.ptr.is_null() {
+            return None;
+        }
+        let ptr = unsafe { ffi::wxd_Clipboard_GetBitmap(self.ptr) };
+        if ptr.is_null() { None } else { Some(Bitmap::from(ptr)) }
+    }
+
+    /// Put a list of file paths on the clipboard, replacing its contents, so they can be
+    /// pasted in a file manager. Returns false if the list is empty.
+    pub fn set_files(&self, files: &[&str]) -> bool {
+        if self.ptr.is_null() {
+            return false;
+        }
+        let mut array = ArrayString::new();
+        array.add_many(files);
+        unsafe { ffi::wxd_Clipboard_SetFiles(self.ptr, array.as_const_ptr()) }
+    }
+
+    /// Get the file paths on the clipboard, e.g. files copied in Explorer or Finder, or
+    /// `None` if it holds none
+    pub fn get_files(&self) -> Option<Vec<String>> {
+        if self.ptr.is_null() {
+            return None;
+        }
+        let mut array = ArrayString::new();
+        if unsafe { ffi::wxd_Clipboard_GetFiles(self.ptr, array.as_mut_ptr()) } {
+            Some(array.get_strings())
+        } else {
+            None
+        }
+    }
+
     /// Create a ClipboardLocker to safely manage clipboard access
     pub fn locker(&self) -> Option<ClipboardLocker<'_>> {
         ClipboardLocker::new(self)
@@ -218,6 +442,80 @@ impl<'a> Drop for ClipboardLocker<'a> {
     }
 }
 
+/// Copies text to the clipboard, replacing its contents. Returns false on failure.
+pub fn set_text(text: &str) -> bool {
+    Clipboard::get().set_text(text)
+}
+
+/// Gets the text on the clipboard, or `None` if it holds no text.
+pub fn get_text() -> Option<String> {
+    Clipboard::get().get_text()
+}
+
+/// Returns true if the clipboard holds text.
+pub fn has_text() -> bool {
+    Clipboard::get().has_text()
+}
+
+/// Returns true if the clipboard holds data of the given kind.
+pub fn is_format_available(format: ClipboardFormat) -> bool {
+    Clipboard::get().is_format_available(format)
+}
+
+/// Puts a data object, usually a [`DataObjectComposite`](crate::data_object::DataObjectComposite)
+/// offering several formats, on the clipboard, replacing its contents. Returns false on failure.
+pub fn set_data_object<T: DataObject + TransferOwnership>(mut data: T) -> bool {
+    Clipboard::with(|clip| clip.set_data(&mut data)).unwrap_or(false)
+}
+
+/// Returns the formats of `formats` the clipboard holds, in the same order.
+pub fn available_formats(formats: &[ClipboardFormat]) -> Vec<ClipboardFormat> {
+    Clipboard::with(|clip| clip.available_formats(formats)).unwrap_or_default()
+}
+
+/// Reads the clipboard in the first format of `formats` it holds, or `None` if it holds
+/// none of them.
+pub fn get_preferred(formats: &[ClipboardFormat]) -> Option<ClipboardData> {
+    Clipboard::with(|clip| clip.get_preferred(formats)).flatten()
+}
+
+/// Gets the clipboard's data of the application defined format `format_name`, or `None`
+/// if it holds none.
+pub fn get_custom_data(format_name: &str) -> Option<Vec<u8>> {
+    Clipboard::get().get_custom_data(format_name)
+}
+
+/// Copies a bitmap to the clipboard, replacing its contents. Returns false on failure.
+pub fn set_bitmap(bitmap: &Bitmap) -> bool {
+    Clipboard::get().set_bitmap(bitmap)
+}
+
+/// Gets the image on the clipboard, or `None` if it holds none.
+pub fn get_bitmap() -> Option<Bitmap> {
+    Clipboard::get().get_bitmap()
+}
+
+/// Copies a list of file paths to the clipboard, replacing its contents.
+pub fn set_files(files: &[&str]) -> bool {
+    Clipboard::get().set_files(files)
+}
+
+/// Gets the file paths on the clipboard, or `None` if it holds none.
+pub fn get_files() -> Option<Vec<String>> {
+    Clipboard::get().get_files()
+}
+
+/// Empties the clipboard.
+pub fn clear() {
+    Clipboard::with(|clip| clip.clear());
+}
+
+/// Hands the data this application put on the clipboard over to the system, so it stays
+/// available after the application exits. Returns false if that isn't possible.
+pub fn flush() -> bool {
+    Clipboard::get().flush()
+}
+
 // No need for Drop implementation since we're not allocating resources
 // that need to be cleaned up when the Clipboard instance is dropped.
 // The clipboard itself is a global resource.
@@ -1,5 +1,6 @@
 use crate::bitmap::Bitmap;
 use crate::geometry::Point;
+use crate::image::Image;
 use std::ffi::CString;
 use wxdragon_sys as ffi;
 
@@ -38,7 +39,7 @@ pub enum StockCursor {
     ArrowWait,
 }
 
-/// Bitmap file types supported for cursor creation
+/// Image file types, used for cursor creation and for saving and encoding bitmaps
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(C)]
 pub enum BitmapType {
@@ -52,9 +53,28 @@ pub enum BitmapType {
     Jpeg,
     Gif,
     Ani,
+    /// Detect the type when loading
     Any,
 }
 
+impl BitmapType {
+    pub(crate) fn to_raw(self) -> ffi::wxd_BitmapType {
+        match self {
+            BitmapType::Invalid => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_INVALID,
+            BitmapType::Bmp => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_BMP,
+            BitmapType::Ico => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_ICO,
+            BitmapType::Cur => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_CUR,
+            BitmapType::Xbm => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_XBM,
+            BitmapType::Xpm => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_XPM,
+            BitmapType::Png => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_PNG,
+            BitmapType::Jpeg => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_JPEG,
+            BitmapType::Gif => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_GIF,
+            BitmapType::Ani => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_ANI,
+            BitmapType::Any => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_ANY,
+        }
+    }
+}
+
 /// Represents a cursor object.
 ///
 /// A cursor is a small bitmap usually used for denoting where the mouse pointer is,
@@ -151,22 +171,7 @@ impl Cursor {
     /// ```
     pub fn from_file(filename: &str, bitmap_type: BitmapType, hotspot_x: i32, hotspot_y: i32) -> Option<Self> {
         let c_filename = CString::new(filename).ok()?;
-        let ptr = {
-            let ffi_bitmap_type = match bitmap_type {
-                BitmapType::Invalid => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_INVALID,
-                BitmapType::Bmp => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_BMP,
-                BitmapType::Ico => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_ICO,
-                BitmapType::Cur => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_CUR,
-                BitmapType::Xbm => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_XBM,
-                BitmapType::Xpm => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_XPM,
-                BitmapType::Png => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_PNG,
-                BitmapType::Jpeg => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_JPEG,
-                BitmapType::Gif => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_GIF,
-                BitmapType::Ani => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_ANI,
-                BitmapType::Any => ffi::wxd_BitmapType_WXD_BITMAP_TYPE_ANY,
-            };
-            unsafe { ffi::wxd_Cursor_CreateFromFile(c_filename.as_ptr(), ffi_bitmap_type, hotspot_x, hotspot_y) }
-        };
+        let ptr = unsafe { ffi::wxd_Cursor_CreateFromFile(c_filename.as_ptr(), bitmap_type.to_raw(), hotspot_x, hotspot_y) };
         if ptr.is_null() { None } else { Some(Self(ptr)) }
     }
 
@@ -215,15 +220,50 @@ impl Cursor {
         if ptr.is_null() { None } else { Some(Self(ptr)) }
     }
 
-    /// Creates a cursor from a bitmap/image.
+    /// Creates a cursor from an image, e.g. one shaped like the current brush of a drawing tool.
+    ///
+    /// The alpha channel is kept where the platform supports it. Images larger than the platform
+    /// allows (the system cursor size, usually 32x32, on Windows) are scaled down, and the hotspot
+    /// is moved along with them; it is clamped to the image.
+    ///
+    /// # Arguments
+    /// * `image` - The cursor picture
+    /// * `hotspot_x` - X coordinate of the cursor hotspot
+    /// * `hotspot_y` - Y coordinate of the cursor hotspot
+    ///
+    /// # Returns
+    /// A new `Cursor` instance, or `None` if creation failed
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use wxdragon::prelude::*;
+    /// // A round brush with its hotspot in the middle
+    /// let mut brush = Image::new(15, 15).unwrap();
+    /// brush.set_alpha(0);
+    /// for y in 0..15 {
+    ///     for x in 0..15 {
+    ///         if (x - 7) * (x - 7) + (y - 7) * (y - 7) <= 49 {
+    ///             brush.set_pixel(x, y, Colour::new(0, 0, 0, 160));
+    ///         }
+    ///     }
+    /// }
+    /// let _cursor = Cursor::from_image(&brush, 7, 7).unwrap();
+    /// ```
+    pub fn from_image(image: &Image, hotspot_x: i32, hotspot_y: i32) -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_Cursor_CreateFromImage(image.as_const_ptr(), hotspot_x, hotspot_y) };
+        if ptr.is_null() { None } else { Some(Self(ptr)) }
+    }
+
+    /// Creates a cursor from a bitmap, see [`from_image`](Self::from_image).
     ///
     /// # Arguments
-    /// * `bitmap` - The bitmap to convert to a cursor
+    /// * `bitmap` - The cursor picture
+    /// * `hotspot` - The point of the bitmap at which the mouse is considered to be
     ///
     /// # Returns
     /// A new `Cursor` instance, or `None` if creation failed
-    pub fn from_bitmap(bitmap: &Bitmap) -> Option<Self> {
-        let ptr = unsafe { ffi::wxd_Cursor_CreateFromImage(bitmap.as_const_ptr()) };
+    pub fn from_bitmap(bitmap: &Bitmap, hotspot: Point) -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_Cursor_CreateFromBitmap(bitmap.as_const_ptr(), hotspot.x, hotspot.y) };
         if ptr.is_null() { None } else { Some(Self(ptr)) }
     }
 
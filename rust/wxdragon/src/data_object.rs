@@ -39,6 +39,10 @@ pub trait TransferOwnership {
     fn transfer_ownership(&mut self);
 }
 
+/// Marker for the data objects holding a single format, which can be combined in a
+/// [`DataObjectComposite`].
+pub trait SimpleDataObject: DataObject + TransferOwnership {}
+
 /// Base class for data objects.
 pub struct DataObjectBase {
     ptr: *mut ffi::wxd_DataObject_t,
@@ -150,6 +154,8 @@ impl Drop for TextDataObject {
     }
 }
 
+impl SimpleDataObject for TextDataObject {}
+
 impl TransferOwnership for TextDataObject {
     fn transfer_ownership(&mut self) {
         self.data_object.transfer_ownership();
@@ -241,6 +247,8 @@ impl Default for FileDataObject {
     }
 }
 
+impl SimpleDataObject for FileDataObject {}
+
 impl TransferOwnership for FileDataObject {
     fn transfer_ownership(&mut self) {
         self.data_object.transfer_ownership();
@@ -293,20 +301,213 @@ impl DataObject for BitmapDataObject {
 impl Drop for BitmapDataObject {
     fn drop(&mut self) {
         if !self.data_object.as_ptr().is_null() && self.data_object.owned {
-            // Currently, there's no explicit destroy function for BitmapDataObject in the C API,
-            // But the destructor will be called via the normal C++ destruction process
-            // when the DataObject is freed
-            // unsafe {
-            //     ffi::wxd_BitmapDataObject_Destroy(
-            //         self.data_object.as_ptr() as *mut ffi::wxd_BitmapDataObject_t
-            //     );
-            // }
+            unsafe { ffi::wxd_DataObject_Destroy(self.data_object.as_ptr()) };
         }
     }
 }
 
+impl SimpleDataObject for BitmapDataObject {}
+
 impl TransferOwnership for BitmapDataObject {
     fn transfer_ownership(&mut self) {
         self.data_object.transfer_ownership();
     }
 }
+
+/// Data object for the raw bytes of an application defined format.
+///
+/// The format is named like a MIME type, e.g. `"application/x-myapp-node"`; wxWidgets
+/// registers the name with the platform (a registered clipboard format on Windows, an
+/// atom on GTK, a UTI on macOS), so the same name must be used to read the data back.
+pub struct CustomDataObject {
+    data_object: DataObjectBase,
+}
+
+impl CustomDataObject {
+    /// Creates a data object holding `data` in the format `format_name`.
+    pub fn new(format_name: &str, data: &[u8]) -> Self {
+        let c_format = CString::new(format_name).unwrap_or_default();
+        let ptr = unsafe { ffi::wxd_CustomDataObject_Create(c_format.as_ptr(), data.as_ptr(), data.len()) };
+        Self {
+            data_object: DataObjectBase::from_ptr(ptr as *mut ffi::wxd_DataObject_t, true),
+        }
+    }
+
+    /// Wraps a data object created on the C++ side, taking ownership of it.
+    pub(crate) fn from_ptr(ptr: *mut ffi::wxd_CustomDataObject_t) -> Self {
+        Self {
+            data_object: DataObjectBase::from_ptr(ptr as *mut ffi::wxd_DataObject_t, true),
+        }
+    }
+
+    /// Gets a copy of the data.
+    pub fn get_data(&self) -> Vec<u8> {
+        let ptr = self.data_object.as_ptr() as *const ffi::wxd_CustomDataObject_t;
+        let len = unsafe { ffi::wxd_CustomDataObject_GetData(ptr, std::ptr::null_mut(), 0) };
+        let mut data = vec![0u8; len];
+        if len > 0 {
+            unsafe { ffi::wxd_CustomDataObject_GetData(ptr, data.as_mut_ptr(), data.len()) };
+        }
+        data
+    }
+
+    /// Gets the underlying DataObject.
+    pub fn as_data_object(&self) -> &DataObjectBase {
+        &self.data_object
+    }
+
+    /// Gets the underlying DataObject as mutable.
+    pub fn as_data_object_mut(&mut self) -> &mut DataObjectBase {
+        &mut self.data_object
+    }
+}
+
+impl DataObject for CustomDataObject {
+    fn as_data_object_ptr(&self) -> *mut ffi::wxd_DataObject_t {
+        self.data_object.as_ptr()
+    }
+}
+
+impl Drop for CustomDataObject {
+    fn drop(&mut self) {
+        if !self.data_object.as_ptr().is_null() && self.data_object.owned {
+            unsafe { ffi::wxd_DataObject_Destroy(self.data_object.as_ptr()) };
+        }
+    }
+}
+
+impl SimpleDataObject for CustomDataObject {}
+
+impl TransferOwnership for CustomDataObject {
+    fn transfer_ownership(&mut self) {
+        self.data_object.transfer_ownership();
+    }
+}
+
+/// Data object offering several formats at once, e.g. a chart as both a bitmap and its
+/// data as text, so the pasting application can pick the one it understands.
+///
+/// The same object can be put on the clipboard with
+/// [`clipboard::set_data_object`](crate::clipboard::set_data_object) or dragged with
+/// [`DragSource::set_data_object`](crate::dnd::DragSource::set_data_object).
+///
+/// # Example
+/// ```rust,no_run
+/// use wxdragon::prelude::*;
+/// # let chart = Bitmap::new(16, 16).unwrap();
+/// let data = DataObjectComposite::builder()
+///     .with_bitmap(&chart)
+///     .with_text("x,y\n1,2\n")
+///     .with_data("application/x-myapp-chart", b"{\"points\":[[1,2]]}")
+///     .build();
+/// wxdragon::clipboard::set_data_object(data);
+/// ```
+pub struct DataObjectComposite {
+    data_object: DataObjectBase,
+}
+
+impl DataObjectComposite {
+    /// Creates a builder adding the formats in order, the first one being preferred.
+    pub fn builder() -> DataObjectCompositeBuilder {
+        DataObjectCompositeBuilder { composite: Self::new() }
+    }
+
+    /// Creates an empty composite data object.
+    pub fn new() -> Self {
+        let ptr = unsafe { ffi::wxd_DataObjectComposite_Create() };
+        Self {
+            data_object: DataObjectBase::from_ptr(ptr as *mut ffi::wxd_DataObject_t, true),
+        }
+    }
+
+    /// Adds a format, taking ownership of the data object. The `preferred` format is
+    /// offered first; otherwise the first one added is preferred.
+    pub fn add<T: SimpleDataObject>(&mut self, mut data: T, preferred: bool) {
+        unsafe {
+            ffi::wxd_DataObjectComposite_Add(
+                self.data_object.as_ptr() as *mut ffi::wxd_DataObjectComposite_t,
+                data.as_data_object_ptr(),
+                preferred,
+            )
+        };
+        data.transfer_ownership();
+    }
+
+    /// Gets the underlying DataObject.
+    pub fn as_data_object(&self) -> &DataObjectBase {
+        &self.data_object
+    }
+
+    /// Gets the underlying DataObject as mutable.
+    pub fn as_data_object_mut(&mut self) -> &mut DataObjectBase {
+        &mut self.data_object
+    }
+}
+
+impl DataObject for DataObjectComposite {
+    fn as_data_object_ptr(&self) -> *mut ffi::wxd_DataObject_t {
+        self.data_object.as_ptr()
+    }
+}
+
+impl Drop for DataObjectComposite {
+    fn drop(&mut self) {
+        if !self.data_object.as_ptr().is_null() && self.data_object.owned {
+            unsafe { ffi::wxd_DataObject_Destroy(self.data_object.as_ptr()) };
+        }
+    }
+}
+
+impl Default for DataObjectComposite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransferOwnership for DataObjectComposite {
+    fn transfer_ownership(&mut self) {
+        self.data_object.transfer_ownership();
+    }
+}
+
+/// Builder for a [`DataObjectComposite`]. Formats are offered in the order they are added,
+/// so add the richest one first and plain text, which nearly every target reads, last.
+pub struct DataObjectCompositeBuilder {
+    composite: DataObjectComposite,
+}
+
+impl DataObjectCompositeBuilder {
+    /// Offers text.
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.composite.add(TextDataObject::new(text), false);
+        self
+    }
+
+    /// Offers an image.
+    pub fn with_bitmap(mut self, bitmap: &crate::bitmap::Bitmap) -> Self {
+        self.composite.add(BitmapDataObject::new(bitmap), false);
+        self
+    }
+
+    /// Offers a list of file paths.
+    pub fn with_files(mut self, files: &[&str]) -> Self {
+        let mut data = FileDataObject::new();
+        for file in files {
+            data.add_file(file);
+        }
+        self.composite.add(data, false);
+        self
+    }
+
+    /// Offers bytes of the application defined format `format_name`, e.g.
+    /// `"application/x-myapp"`; see [`CustomDataObject`].
+    pub fn with_data(mut self, format_name: &str, data: &[u8]) -> Self {
+        self.composite.add(CustomDataObject::new(format_name, data), false);
+        self
+    }
+
+    /// Builds the composite data object.
+    pub fn build(self) -> DataObjectComposite {
+        self.composite
+    }
+}
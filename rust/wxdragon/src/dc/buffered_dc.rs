@@ -0,0 +1,47 @@
+use crate::dc::DeviceContext;
+use std::marker::PhantomData;
+
+/// Buffers drawing on another DC in an off-screen bitmap and copies it to that DC
+/// in one go when dropped, so partial drawing is never visible.
+///
+/// Use it to draw flicker-free on a [`ClientDC`](crate::dc::ClientDC) outside of paint
+/// events; inside `on_paint` use [`AutoBufferedPaintDC`](crate::dc::AutoBufferedPaintDC)
+/// instead. The buffer has the size of the wrapped DC.
+///
+/// # Example
+/// ```ignore
+/// let client_dc = ClientDC::new(&panel);
+/// let dc = BufferedDC::new(&client_dc);
+/// dc.clear();
+/// draw_scene(&dc);
+/// // The scene appears on the panel when `dc` is dropped
+/// ```
+pub struct BufferedDC<'a> {
+    dc_ptr: *mut wxdragon_sys::wxd_BufferedDC_t,
+    _target: PhantomData<&'a ()>,
+}
+
+impl<'a> BufferedDC<'a> {
+    /// Create a buffer for `dc`, which stays borrowed until the buffer is copied to it
+    pub fn new<D: DeviceContext + ?Sized>(dc: &'a D) -> Self {
+        let dc_ptr = unsafe { wxdragon_sys::wxd_BufferedDC_Create(dc.dc_ptr()) };
+        Self {
+            dc_ptr,
+            _target: PhantomData,
+        }
+    }
+}
+
+impl DeviceContext for BufferedDC<'_> {
+    fn dc_ptr(&self) -> *mut wxdragon_sys::wxd_DC_t {
+        unsafe { wxdragon_sys::wxd_BufferedDC_AsDC(self.dc_ptr) }
+    }
+}
+
+impl Drop for BufferedDC<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            wxdragon_sys::wxd_BufferedDC_Destroy(self.dc_ptr);
+        }
+    }
+}
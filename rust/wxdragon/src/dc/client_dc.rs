@@ -1,33 +1,46 @@
 use crate::dc::DeviceContext;
 use crate::window::WxWidget;
+use std::marker::PhantomData;
 
 /// A device context to draw on the client area of a window.
 ///
-/// ClientDC can be used outside of paint events, but when handling paint events,
-/// you should use PaintDC instead.
-pub struct ClientDC {
+/// ClientDC can be used outside of paint events, e.g. to give immediate feedback while
+/// the mouse moves, but when handling paint events, you should use PaintDC instead.
+/// The DC borrows the window, so it cannot outlive the handler it was created in.
+///
+/// Some platforms (macOS, GTK 3 under Wayland) do not show drawing done outside paint
+/// events, and ignore logical functions such as `LogicalFunction::Invert`. Use an
+/// [`Overlay`](crate::dc::Overlay) for rubber bands and crosshairs instead of erasing
+/// them by inverting.
+pub struct ClientDC<'a> {
     dc_ptr: *mut wxdragon_sys::wxd_ClientDC_t,
+    _window: PhantomData<&'a ()>,
 }
 
-impl ClientDC {
+impl<'a> ClientDC<'a> {
     /// Create a new ClientDC for the specified window
     ///
+    /// If the window has been destroyed the DC is not OK and drawing on it does nothing.
+    ///
     /// # Arguments
     /// * `window` - The window to draw on
-    pub fn new<W: WxWidget>(window: &W) -> Self {
+    pub fn new<W: WxWidget + ?Sized>(window: &'a W) -> Self {
         let ptr = window.handle_ptr();
         let dc_ptr = unsafe { wxdragon_sys::wxd_ClientDC_Create(ptr) };
-        Self { dc_ptr }
+        Self {
+            dc_ptr,
+            _window: PhantomData,
+        }
     }
 }
 
-impl DeviceContext for ClientDC {
+impl DeviceContext for ClientDC<'_> {
     fn dc_ptr(&self) -> *mut wxdragon_sys::wxd_DC_t {
         unsafe { wxdragon_sys::wxd_ClientDC_AsDC(self.dc_ptr) }
     }
 }
 
-impl Drop for ClientDC {
+impl Drop for ClientDC<'_> {
     fn drop(&mut self) {
         unsafe {
             wxdragon_sys::wxd_ClientDC_Destroy(self.dc_ptr);
@@ -0,0 +1,592 @@
+use crate::bitmap::Bitmap;
+use crate::color::Colour;
+use crate::dc::{BrushStyle, DeviceContext, PenStyle, PolygonFillMode};
+use crate::font::Font;
+use crate::window::WxWidget;
+use std::ffi::CString;
+use std::marker::PhantomData;
+
+/// Antialiasing mode of a [`GraphicsContext`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AntialiasMode {
+    /// No antialiasing, e.g. for pixel-exact grid lines
+    None,
+    /// The renderer's default antialiasing
+    #[default]
+    Default,
+}
+
+impl AntialiasMode {
+    /// Convert to the raw FFI value
+    pub fn to_raw(&self) -> i32 {
+        match self {
+            AntialiasMode::None => wxdragon_sys::WXD_ANTIALIAS_NONE as i32,
+            AntialiasMode::Default => wxdragon_sys::WXD_ANTIALIAS_DEFAULT as i32,
+        }
+    }
+
+    /// Convert from raw FFI value
+    pub fn from_raw(value: i32) -> Self {
+        if value == wxdragon_sys::WXD_ANTIALIAS_NONE as i32 {
+            AntialiasMode::None
+        } else {
+            AntialiasMode::Default
+        }
+    }
+}
+
+/// Quality of bitmap scaling in [`GraphicsContext::draw_bitmap`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterpolationQuality {
+    /// The renderer's default
+    #[default]
+    Default,
+    /// Nearest neighbour, keeps hard pixel edges
+    None,
+    Fast,
+    Good,
+    Best,
+}
+
+impl InterpolationQuality {
+    /// Convert to the raw FFI value
+    pub fn to_raw(&self) -> i32 {
+        match self {
+            InterpolationQuality::Default => wxdragon_sys::WXD_INTERPOLATION_DEFAULT as i32,
+            InterpolationQuality::None => wxdragon_sys::WXD_INTERPOLATION_NONE as i32,
+            InterpolationQuality::Fast => wxdragon_sys::WXD_INTERPOLATION_FAST as i32,
+            InterpolationQuality::Good => wxdragon_sys::WXD_INTERPOLATION_GOOD as i32,
+            InterpolationQuality::Best => wxdragon_sys::WXD_INTERPOLATION_BEST as i32,
+        }
+    }
+
+    /// Convert from raw FFI value
+    pub fn from_raw(value: i32) -> Self {
+        match value {
+            _ if value == wxdragon_sys::WXD_INTERPOLATION_NONE as i32 => InterpolationQuality::None,
+            _ if value == wxdragon_sys::WXD_INTERPOLATION_FAST as i32 => InterpolationQuality::Fast,
+            _ if value == wxdragon_sys::WXD_INTERPOLATION_GOOD as i32 => InterpolationQuality::Good,
+            _ if value == wxdragon_sys::WXD_INTERPOLATION_BEST as i32 => InterpolationQuality::Best,
+            _ => InterpolationQuality::Default,
+        }
+    }
+}
+
+/// Colours of a gradient, from `start` at position 0.0 to `end` at position 1.0,
+/// with optional stops in between
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientStops {
+    start: Colour,
+    end: Colour,
+    stops: Vec<(f64, Colour)>,
+}
+
+impl GradientStops {
+    /// A two-colour gradient
+    pub fn new(start: Colour, end: Colour) -> Self {
+        Self {
+            start,
+            end,
+            stops: Vec::new(),
+        }
+    }
+
+    /// Add a colour at `position` (between 0.0 and 1.0)
+    pub fn add(mut self, position: f64, colour: Colour) -> Self {
+        let position = position.clamp(0.0, 1.0);
+        let index = self.stops.partition_point(|(pos, _)| *pos <= position);
+        self.stops.insert(index, (position, colour));
+        self
+    }
+
+    fn to_arrays(&self) -> (Vec<wxdragon_sys::wxd_Colour_t>, Vec<f64>) {
+        let mut colours = Vec::with_capacity(self.stops.len() + 2);
+        let mut positions = Vec::with_capacity(self.stops.len() + 2);
+        colours.push(self.start.into());
+        positions.push(0.0);
+        for (position, colour) in &self.stops {
+            colours.push((*colour).into());
+            positions.push(*position);
+        }
+        colours.push(self.end.into());
+        positions.push(1.0);
+        (colours, positions)
+    }
+}
+
+/// A gradient used by a [`GraphicsBrush`] or [`GraphicsPen`], in the coordinates
+/// of the context at the time the brush or pen is set
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphicsGradient {
+    /// A gradient along the line from (x1, y1) to (x2, y2)
+    Linear {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        stops: GradientStops,
+    },
+    /// A gradient from the focus (start) to the circle around the centre (end)
+    Radial {
+        start_x: f64,
+        start_y: f64,
+        end_x: f64,
+        end_y: f64,
+        radius: f64,
+        stops: GradientStops,
+    },
+}
+
+impl GraphicsGradient {
+    /// Call `f` with the FFI description; the arrays it points to live for the call only
+    fn with_raw<R>(&self, f: impl FnOnce(&wxdragon_sys::wxd_GraphicsGradient_t) -> R) -> R {
+        let (kind, x1, y1, x2, y2, radius, stops) = match self {
+            GraphicsGradient::Linear { x1, y1, x2, y2, stops } => {
+                (wxdragon_sys::WXD_GRAPHICS_GRADIENT_LINEAR, *x1, *y1, *x2, *y2, 0.0, stops)
+            }
+            GraphicsGradient::Radial {
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+                radius,
+                stops,
+            } => {
+                // wxWidgets takes the focus as start and the circle centre as end
+                (
+                    wxdragon_sys::WXD_GRAPHICS_GRADIENT_RADIAL,
+                    *start_x,
+                    *start_y,
+                    *end_x,
+                    *end_y,
+                    *radius,
+                    stops,
+                )
+            }
+        };
+        let (colours, positions) = stops.to_arrays();
+        let raw = wxdragon_sys::wxd_GraphicsGradient_t {
+            kind: kind as i32,
+            x1,
+            y1,
+            x2,
+            y2,
+            radius,
+            colours: colours.as_ptr(),
+            positions: positions.as_ptr(),
+            count: colours.len() as i32,
+        };
+        f(&raw)
+    }
+}
+
+/// How shapes are filled by a [`GraphicsContext`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphicsBrush {
+    /// Don't fill
+    Transparent,
+    /// Fill with a colour; its alpha is respected
+    Solid(Colour),
+    /// Fill with a hatch pattern or other `BrushStyle`
+    Styled(Colour, BrushStyle),
+    /// Fill with a linear or radial gradient
+    Gradient(GraphicsGradient),
+}
+
+impl GraphicsBrush {
+    /// A linear gradient brush from (x1, y1) to (x2, y2)
+    pub fn linear_gradient(x1: f64, y1: f64, x2: f64, y2: f64, stops: GradientStops) -> Self {
+        GraphicsBrush::Gradient(GraphicsGradient::Linear { x1, y1, x2, y2, stops })
+    }
+
+    /// A radial gradient brush with the given focus, centre and radius
+    pub fn radial_gradient(start_x: f64, start_y: f64, end_x: f64, end_y: f64, radius: f64, stops: GradientStops) -> Self {
+        GraphicsBrush::Gradient(GraphicsGradient::Radial {
+            start_x,
+            start_y,
+            end_x,
+            end_y,
+            radius,
+            stops,
+        })
+    }
+}
+
+/// How lines and outlines are stroked by a [`GraphicsContext`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphicsPen {
+    colour: Colour,
+    width: f64,
+    style: PenStyle,
+    gradient: Option<GraphicsGradient>,
+}
+
+impl GraphicsPen {
+    /// A solid pen; widths below 1.0 give hairlines thinner than a pixel
+    pub fn new(colour: Colour, width: f64) -> Self {
+        Self {
+            colour,
+            width,
+            style: PenStyle::Solid,
+            gradient: None,
+        }
+    }
+
+    /// A pen that doesn't draw, e.g. to fill shapes without an outline
+    pub fn transparent() -> Self {
+        Self::new(Colour::new(0, 0, 0, 0), 1.0).with_style(PenStyle::Transparent)
+    }
+
+    /// Set the dash style
+    pub fn with_style(mut self, style: PenStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Stroke with a gradient instead of the pen colour
+    pub fn with_gradient(mut self, gradient: GraphicsGradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+}
+
+/// A path of lines, curves and shapes that can be stroked or filled by a [`GraphicsContext`].
+///
+/// Coordinates are in the context's user space, angles in radians.
+pub struct GraphicsPath {
+    ptr: *mut wxdragon_sys::wxd_GraphicsPath_t,
+}
+
+impl GraphicsPath {
+    /// Begin a new subpath at (x, y)
+    pub fn move_to(&self, x: f64, y: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_MoveToPoint(self.ptr, x, y) };
+        self
+    }
+
+    /// Add a straight line from the current point to (x, y)
+    pub fn line_to(&self, x: f64, y: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddLineToPoint(self.ptr, x, y) };
+        self
+    }
+
+    /// Add a cubic Bézier curve to (x, y) with control points (cx1, cy1) and (cx2, cy2)
+    pub fn add_curve_to_point(&self, cx1: f64, cy1: f64, cx2: f64, cy2: f64, x: f64, y: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddCurveToPoint(self.ptr, cx1, cy1, cx2, cy2, x, y) };
+        self
+    }
+
+    /// Add a quadratic Bézier curve to (x, y) with control point (cx, cy)
+    pub fn add_quad_curve_to_point(&self, cx: f64, cy: f64, x: f64, y: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddQuadCurveToPoint(self.ptr, cx, cy, x, y) };
+        self
+    }
+
+    /// Add an arc of a circle around (x, y), connected to the current point by a line
+    pub fn add_arc(&self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64, clockwise: bool) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddArc(self.ptr, x, y, radius, start_angle, end_angle, clockwise) };
+        self
+    }
+
+    /// Add a closed rectangle subpath
+    pub fn add_rectangle(&self, x: f64, y: f64, width: f64, height: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddRectangle(self.ptr, x, y, width, height) };
+        self
+    }
+
+    /// Add a closed rectangle subpath with rounded corners
+    pub fn add_rounded_rectangle(&self, x: f64, y: f64, width: f64, height: f64, radius: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddRoundedRectangle(self.ptr, x, y, width, height, radius) };
+        self
+    }
+
+    /// Add a closed circle subpath
+    pub fn add_circle(&self, x: f64, y: f64, radius: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddCircle(self.ptr, x, y, radius) };
+        self
+    }
+
+    /// Add a closed ellipse subpath fitting the rectangle
+    pub fn add_ellipse(&self, x: f64, y: f64, width: f64, height: f64) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_AddEllipse(self.ptr, x, y, width, height) };
+        self
+    }
+
+    /// Close the current subpath with a line back to its start
+    pub fn close(&self) -> &Self {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_CloseSubpath(self.ptr) };
+        self
+    }
+
+    /// Bounding box of the path as (x, y, width, height)
+    pub fn get_box(&self) -> (f64, f64, f64, f64) {
+        let (mut x, mut y, mut width, mut height) = (0.0, 0.0, 0.0, 0.0);
+        unsafe { wxdragon_sys::wxd_GraphicsPath_GetBox(self.ptr, &mut x, &mut y, &mut width, &mut height) };
+        (x, y, width, height)
+    }
+
+    /// Whether (x, y) is inside the filled path, e.g. for hit testing
+    pub fn contains(&self, x: f64, y: f64, fill_mode: PolygonFillMode) -> bool {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_Contains(self.ptr, x, y, fill_mode.to_raw()) }
+    }
+}
+
+impl Drop for GraphicsPath {
+    fn drop(&mut self) {
+        unsafe { wxdragon_sys::wxd_GraphicsPath_Destroy(self.ptr) };
+    }
+}
+
+/// An anti-aliased drawing context with alpha blending, paths, gradients and transforms
+/// (wxGraphicsContext).
+///
+/// It is created on top of a DC or a window and borrows it; everything drawn is
+/// flushed to the target when the context is dropped. Coordinates are `f64` in
+/// user space, angles in radians.
+///
+/// # Example
+/// ```ignore
+/// panel.on_paint(move |_| {
+///     let dc = AutoBufferedPaintDC::new(&panel);
+///     dc.clear();
+///     let Some(gc) = GraphicsContext::from_dc(&dc) else { return };
+///
+///     let area = gc.create_path();
+///     area.move_to(0.0, 200.0).line_to(50.0, 120.0).line_to(100.0, 150.0).line_to(150.0, 60.0);
+///     area.line_to(150.0, 200.0).close();
+///     gc.set_brush(&GraphicsBrush::linear_gradient(
+///         0.0, 60.0, 0.0, 200.0,
+///         GradientStops::new(Colour::new(30, 120, 220, 160), Colour::new(30, 120, 220, 0)),
+///     ));
+///     gc.set_pen(&GraphicsPen::new(Colour::rgb(30, 120, 220), 2.0));
+///     gc.draw_path(&area, PolygonFillMode::OddEven);
+/// });
+/// ```
+pub struct GraphicsContext<'a> {
+    ptr: *mut wxdragon_sys::wxd_GraphicsContext_t,
+    _target: PhantomData<&'a ()>,
+}
+
+impl<'a> GraphicsContext<'a> {
+    /// Create a context drawing on `dc`, e.g. a `PaintDC`, `AutoBufferedPaintDC` or
+    /// `MemoryDC` with a bitmap selected. Returns `None` if the DC can't be used.
+    pub fn from_dc<D: DeviceContext + ?Sized>(dc: &'a D) -> Option<Self> {
+        let ptr = unsafe { wxdragon_sys::wxd_GraphicsContext_CreateFromDC(dc.dc_ptr()) };
+        Self::from_raw(ptr)
+    }
+
+    /// Create a context drawing directly on `window`, outside of paint events
+    pub fn from_window<W: WxWidget + ?Sized>(window: &'a W) -> Option<Self> {
+        let ptr = unsafe { wxdragon_sys::wxd_GraphicsContext_CreateFromWindow(window.handle_ptr()) };
+        Self::from_raw(ptr)
+    }
+
+    fn from_raw(ptr: *mut wxdragon_sys::wxd_GraphicsContext_t) -> Option<Self> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self {
+                ptr,
+                _target: PhantomData,
+            })
+        }
+    }
+
+    /// Create an empty path to draw with this context
+    pub fn create_path(&self) -> GraphicsPath {
+        GraphicsPath {
+            ptr: unsafe { wxdragon_sys::wxd_GraphicsContext_CreatePath(self.ptr) },
+        }
+    }
+
+    /// Set the pen used for stroking
+    pub fn set_pen(&self, pen: &GraphicsPen) {
+        let colour = pen.colour.into();
+        let style = pen.style.bits() as i32;
+        match &pen.gradient {
+            Some(gradient) => gradient
+                .with_raw(|raw| unsafe { wxdragon_sys::wxd_GraphicsContext_SetPen(self.ptr, colour, pen.width, style, raw) }),
+            None => unsafe { wxdragon_sys::wxd_GraphicsContext_SetPen(self.ptr, colour, pen.width, style, std::ptr::null()) },
+        }
+    }
+
+    /// Set the brush used for filling
+    pub fn set_brush(&self, brush: &GraphicsBrush) {
+        let (colour, style) = match brush {
+            GraphicsBrush::Transparent => (Colour::new(0, 0, 0, 0), BrushStyle::Transparent),
+            GraphicsBrush::Solid(colour) => (*colour, BrushStyle::Solid),
+            GraphicsBrush::Styled(colour, style) => (*colour, *style),
+            GraphicsBrush::Gradient(gradient) => {
+                return gradient.with_raw(|raw| unsafe {
+                    wxdragon_sys::wxd_GraphicsContext_SetBrush(self.ptr, Colour::new(0, 0, 0, 0).into(), 0, raw)
+                });
+            }
+        };
+        unsafe { wxdragon_sys::wxd_GraphicsContext_SetBrush(self.ptr, colour.into(), style.bits() as i32, std::ptr::null()) };
+    }
+
+    /// Set the font and text colour; the context starts with the font of its DC or window
+    pub fn set_font(&self, font: &Font, colour: Colour) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_SetFont(self.ptr, font.as_ptr(), colour.into()) };
+    }
+
+    /// Set the antialiasing mode; returns false if the renderer doesn't support it
+    pub fn set_antialias_mode(&self, mode: AntialiasMode) -> bool {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_SetAntialiasMode(self.ptr, mode.to_raw()) }
+    }
+
+    /// Get the antialiasing mode
+    pub fn get_antialias_mode(&self) -> AntialiasMode {
+        AntialiasMode::from_raw(unsafe { wxdragon_sys::wxd_GraphicsContext_GetAntialiasMode(self.ptr) })
+    }
+
+    /// Set the quality used when `draw_bitmap` scales a bitmap; returns false if unsupported
+    pub fn set_interpolation_quality(&self, quality: InterpolationQuality) -> bool {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_SetInterpolationQuality(self.ptr, quality.to_raw()) }
+    }
+
+    /// Get the bitmap interpolation quality
+    pub fn get_interpolation_quality(&self) -> InterpolationQuality {
+        InterpolationQuality::from_raw(unsafe { wxdragon_sys::wxd_GraphicsContext_GetInterpolationQuality(self.ptr) })
+    }
+
+    /// Stroke the path with the current pen
+    pub fn stroke_path(&self, path: &GraphicsPath) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_StrokePath(self.ptr, path.ptr) };
+    }
+
+    /// Fill the path with the current brush
+    pub fn fill_path(&self, path: &GraphicsPath, fill_mode: PolygonFillMode) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_FillPath(self.ptr, path.ptr, fill_mode.to_raw()) };
+    }
+
+    /// Fill the path with the current brush, then stroke it with the current pen
+    pub fn draw_path(&self, path: &GraphicsPath, fill_mode: PolygonFillMode) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_DrawPath(self.ptr, path.ptr, fill_mode.to_raw()) };
+    }
+
+    /// Stroke a line from (x1, y1) to (x2, y2)
+    pub fn stroke_line(&self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_StrokeLine(self.ptr, x1, y1, x2, y2) };
+    }
+
+    /// Stroke connected lines through `points`
+    pub fn stroke_lines(&self, points: &[(f64, f64)]) {
+        let xy: Vec<f64> = points.iter().flat_map(|&(x, y)| [x, y]).collect();
+        unsafe { wxdragon_sys::wxd_GraphicsContext_StrokeLines(self.ptr, xy.as_ptr(), points.len() as i32) };
+    }
+
+    /// Fill and stroke a rectangle
+    pub fn draw_rectangle(&self, x: f64, y: f64, width: f64, height: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_DrawRectangle(self.ptr, x, y, width, height) };
+    }
+
+    /// Fill and stroke a rectangle with rounded corners
+    pub fn draw_rounded_rectangle(&self, x: f64, y: f64, width: f64, height: f64, radius: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_DrawRoundedRectangle(self.ptr, x, y, width, height, radius) };
+    }
+
+    /// Fill and stroke an ellipse fitting the rectangle
+    pub fn draw_ellipse(&self, x: f64, y: f64, width: f64, height: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_DrawEllipse(self.ptr, x, y, width, height) };
+    }
+
+    /// Draw `bitmap` scaled to the rectangle, using the current interpolation quality
+    pub fn draw_bitmap(&self, bitmap: &Bitmap, x: f64, y: f64, width: f64, height: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_DrawBitmap(self.ptr, bitmap.as_const_ptr(), x, y, width, height) };
+    }
+
+    /// Draw text with its top left corner at (x, y)
+    pub fn draw_text(&self, text: &str, x: f64, y: f64) {
+        self.draw_rotated_text(text, x, y, 0.0);
+    }
+
+    /// Draw text rotated by `angle` radians (counter-clockwise) around (x, y)
+    pub fn draw_rotated_text(&self, text: &str, x: f64, y: f64, angle: f64) {
+        if let Ok(c_text) = CString::new(text) {
+            unsafe { wxdragon_sys::wxd_GraphicsContext_DrawText(self.ptr, c_text.as_ptr(), x, y, angle) };
+        }
+    }
+
+    /// Size of `text` in the current font as (width, height)
+    pub fn get_text_extent(&self, text: &str) -> (f64, f64) {
+        let (width, height, _, _) = self.get_full_text_extent(text);
+        (width, height)
+    }
+
+    /// Size of `text` in the current font as (width, height, descent, external leading)
+    pub fn get_full_text_extent(&self, text: &str) -> (f64, f64, f64, f64) {
+        let (mut width, mut height, mut descent, mut leading) = (0.0, 0.0, 0.0, 0.0);
+        if let Ok(c_text) = CString::new(text) {
+            unsafe {
+                wxdragon_sys::wxd_GraphicsContext_GetTextExtent(
+                    self.ptr,
+                    c_text.as_ptr(),
+                    &mut width,
+                    &mut height,
+                    &mut descent,
+                    &mut leading,
+                )
+            };
+        }
+        (width, height, descent, leading)
+    }
+
+    /// Move the origin by (dx, dy)
+    pub fn translate(&self, dx: f64, dy: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_Translate(self.ptr, dx, dy) };
+    }
+
+    /// Scale the user space
+    pub fn scale(&self, x_scale: f64, y_scale: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_Scale(self.ptr, x_scale, y_scale) };
+    }
+
+    /// Rotate the user space by `angle` radians (clockwise)
+    pub fn rotate(&self, angle: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_Rotate(self.ptr, angle) };
+    }
+
+    /// Save the transform and clipping, restored by the matching [`pop_state`](Self::pop_state)
+    pub fn push_state(&self) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_PushState(self.ptr) };
+    }
+
+    /// Restore the state saved by the last [`push_state`](Self::push_state)
+    pub fn pop_state(&self) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_PopState(self.ptr) };
+    }
+
+    /// Draw everything until [`end_layer`](Self::end_layer) with the given opacity (0.0 - 1.0)
+    pub fn begin_layer(&self, opacity: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_BeginLayer(self.ptr, opacity) };
+    }
+
+    /// Composite the current layer onto the context
+    pub fn end_layer(&self) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_EndLayer(self.ptr) };
+    }
+
+    /// Restrict drawing to the rectangle, intersected with the current clipping region
+    pub fn clip(&self, x: f64, y: f64, width: f64, height: f64) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_Clip(self.ptr, x, y, width, height) };
+    }
+
+    /// Remove the clipping region
+    pub fn reset_clip(&self) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_ResetClip(self.ptr) };
+    }
+
+    /// Size of the drawing area as (width, height)
+    pub fn get_size(&self) -> (f64, f64) {
+        let (mut width, mut height) = (0.0, 0.0);
+        unsafe { wxdragon_sys::wxd_GraphicsContext_GetSize(self.ptr, &mut width, &mut height) };
+        (width, height)
+    }
+}
+
+impl Drop for GraphicsContext<'_> {
+    fn drop(&mut self) {
+        unsafe { wxdragon_sys::wxd_GraphicsContext_Destroy(self.ptr) };
+    }
+}
@@ -1,26 +1,65 @@
 use crate::bitmap::Bitmap;
 use crate::dc::DeviceContext;
+use std::marker::PhantomData;
+use std::ptr;
 
 /// A device context for drawing to an off-screen bitmap.
 ///
 /// MemoryDC can be used to draw to a bitmap, which can then be drawn
-/// to another device context or saved to a file.
-pub struct MemoryDC {
+/// to another device context or saved to a file. The selected bitmap stays
+/// borrowed until the DC is dropped (or another bitmap is selected), and is
+/// deselected on drop so it can be drawn or selected elsewhere.
+///
+/// # Example
+/// ```ignore
+/// // Render an expensive background once...
+/// let mut cache = Bitmap::new(400, 300).unwrap();
+/// {
+///     let dc = MemoryDC::with_bitmap(&mut cache);
+///     draw_background(&dc);
+/// }
+///
+/// // ...and copy it in each paint event
+/// panel.on_paint(move |_| {
+///     let dc = AutoBufferedPaintDC::new(&panel);
+///     let source = MemoryDC::with_source(&cache);
+///     dc.blit(&source, BlitConfig::new(0, 0, 400, 300, 0, 0));
+/// });
+/// ```
+pub struct MemoryDC<'a> {
     dc_ptr: *mut wxdragon_sys::wxd_MemoryDC_t,
+    _bitmap: PhantomData<&'a mut Bitmap>,
 }
 
-impl MemoryDC {
+impl<'a> MemoryDC<'a> {
     /// Create a new memory device context
     pub fn new() -> Self {
         let dc_ptr = unsafe { wxdragon_sys::wxd_MemoryDC_Create() };
-        Self { dc_ptr }
+        Self {
+            dc_ptr,
+            _bitmap: PhantomData,
+        }
+    }
+
+    /// Create a memory device context drawing on `bitmap`
+    pub fn with_bitmap(bitmap: &'a mut Bitmap) -> Self {
+        let mut dc = Self::new();
+        dc.select_object(bitmap);
+        dc
+    }
+
+    /// Create a memory device context for reading from `bitmap`, e.g. as a blit source
+    pub fn with_source(bitmap: &'a Bitmap) -> Self {
+        let mut dc = Self::new();
+        dc.select_object_as_source(bitmap);
+        dc
     }
 
     /// Select a bitmap to draw on
     ///
     /// # Arguments
     /// * `bitmap` - The bitmap to select into this DC
-    pub fn select_object(&mut self, bitmap: &mut Bitmap) {
+    pub fn select_object(&mut self, bitmap: &'a mut Bitmap) {
         unsafe { wxdragon_sys::wxd_MemoryDC_SelectObject(self.dc_ptr, bitmap.as_const_ptr()) };
     }
 
@@ -28,26 +67,33 @@ impl MemoryDC {
     ///
     /// # Arguments
     /// * `bitmap` - The bitmap to use as source
-    pub fn select_object_as_source(&mut self, bitmap: &Bitmap) {
+    pub fn select_object_as_source(&mut self, bitmap: &'a Bitmap) {
         unsafe { wxdragon_sys::wxd_MemoryDC_SelectObjectAsSource(self.dc_ptr, bitmap.as_const_ptr()) };
     }
+
+    /// Deselect the current bitmap; drawing does nothing until another one is selected
+    pub fn deselect_object(&mut self) {
+        unsafe { wxdragon_sys::wxd_MemoryDC_SelectObject(self.dc_ptr, ptr::null()) };
+    }
 }
 
-impl DeviceContext for MemoryDC {
+impl DeviceContext for MemoryDC<'_> {
     fn dc_ptr(&self) -> *mut wxdragon_sys::wxd_DC_t {
         unsafe { wxdragon_sys::wxd_MemoryDC_AsDC(self.dc_ptr) }
     }
 }
 
-impl Drop for MemoryDC {
+impl Drop for MemoryDC<'_> {
     fn drop(&mut self) {
+        // Deselect explicitly: on Windows a bitmap can't be used elsewhere while selected
+        self.deselect_object();
         unsafe {
             wxdragon_sys::wxd_MemoryDC_Destroy(self.dc_ptr);
         }
     }
 }
 
-impl Default for MemoryDC {
+impl Default for MemoryDC<'_> {
     fn default() -> Self {
         Self::new()
     }
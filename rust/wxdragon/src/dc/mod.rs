@@ -1,3 +1,7 @@
+use crate::geometry::Size;
+use crate::window::WxWidget;
+use std::marker::PhantomData;
+
 /// Background mode constants for device contexts
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BackgroundMode {
@@ -273,80 +277,62 @@ impl GradientDirection {
     }
 }
 
-/// Point structure for drawing operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Point {
-    pub fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-}
-
-impl From<Point> for wxdragon_sys::wxd_Point {
-    fn from(point: Point) -> Self {
-        wxdragon_sys::wxd_Point { x: point.x, y: point.y }
-    }
-}
-
-impl From<wxdragon_sys::wxd_Point> for Point {
-    fn from(point: wxdragon_sys::wxd_Point) -> Self {
-        Point { x: point.x, y: point.y }
-    }
-}
-
-/// Rectangle structure for drawing operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Rect {
-    pub x: i32,
-    pub y: i32,
-    pub width: i32,
+/// Metrics of the font selected into a device context, in logical units
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FontMetrics {
+    /// Total character height (ascent plus descent)
     pub height: i32,
+    /// Height above the baseline
+    pub ascent: i32,
+    /// Depth below the baseline
+    pub descent: i32,
+    /// Leading space included in the ascent
+    pub internal_leading: i32,
+    /// Recommended extra space between lines
+    pub external_leading: i32,
+    /// Average character width
+    pub average_width: i32,
 }
 
-impl Rect {
-    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
-        Self { x, y, width, height }
-    }
-}
-
-impl From<Rect> for wxdragon_sys::wxd_Rect {
-    fn from(rect: Rect) -> Self {
-        wxdragon_sys::wxd_Rect {
-            x: rect.x,
-            y: rect.y,
-            width: rect.width,
-            height: rect.height,
+impl From<wxdragon_sys::wxd_FontMetrics> for FontMetrics {
+    fn from(m: wxdragon_sys::wxd_FontMetrics) -> Self {
+        Self {
+            height: m.height,
+            ascent: m.ascent,
+            descent: m.descent,
+            internal_leading: m.internal_leading,
+            external_leading: m.external_leading,
+            average_width: m.average_width,
         }
     }
 }
 
-impl From<wxdragon_sys::wxd_Rect> for Rect {
-    fn from(rect: wxdragon_sys::wxd_Rect) -> Self {
-        Rect {
-            x: rect.x,
-            y: rect.y,
-            width: rect.width,
-            height: rect.height,
-        }
-    }
-}
+// Drawing uses the same geometry types as windows
+pub use crate::geometry::{Point, Rect};
 
 pub mod auto_buffered_paint_dc;
+pub mod buffered_dc;
 pub mod client_dc;
+pub mod graphics_context;
 pub mod memory_dc;
+pub mod overlay;
 pub mod paint_dc;
+pub mod renderer;
 pub mod screen_dc;
 pub mod window_dc;
 
 pub use auto_buffered_paint_dc::AutoBufferedPaintDC;
+pub use buffered_dc::BufferedDC;
 pub use client_dc::ClientDC;
+pub use graphics_context::{
+    AntialiasMode, GradientStops, GraphicsBrush, GraphicsContext, GraphicsGradient, GraphicsPath, GraphicsPen,
+    InterpolationQuality,
+};
 pub use memory_dc::MemoryDC;
+pub use overlay::{DCOverlay, Overlay};
 pub use paint_dc::PaintDC;
-pub use screen_dc::ScreenDC;
+pub use renderer::RendererFlags;
+pub use screen_dc::{ScreenDC, capture_screen};
 pub use window_dc::WindowDC;
 
 /// A generic device context that can wrap any raw DC pointer.
@@ -391,6 +377,27 @@ impl Drop for GenericDC {
     }
 }
 
+/// Restores the drawing state saved by [`DeviceContext::save_state`] when dropped.
+#[must_use = "the state is restored as soon as the guard is dropped"]
+pub struct DcStateGuard<'a> {
+    dc_ptr: *mut wxdragon_sys::wxd_DC_t,
+    state: *mut wxdragon_sys::wxd_DCState_t,
+    _dc: PhantomData<&'a ()>,
+}
+
+impl DcStateGuard<'_> {
+    /// Restore the saved state now instead of at the end of the scope.
+    pub fn restore(self) {}
+}
+
+impl Drop for DcStateGuard<'_> {
+    fn drop(&mut self) {
+        if !self.state.is_null() {
+            unsafe { wxdragon_sys::wxd_DC_RestoreState(self.dc_ptr, self.state) };
+        }
+    }
+}
+
 // Re-export for convenience
 pub use crate::bitmap::Bitmap;
 pub use crate::color::Colour;
@@ -476,6 +483,11 @@ pub trait DeviceContext {
     /// Get a pointer to the underlying DC
     fn dc_ptr(&self) -> *mut wxdragon_sys::wxd_DC_t;
 
+    /// Returns false if the DC could not be created, e.g. because its window was destroyed
+    fn is_ok(&self) -> bool {
+        unsafe { wxdragon_sys::wxd_DC_IsOk(self.dc_ptr()) }
+    }
+
     /// Clear the device context
     fn clear(&self) {
         unsafe {
@@ -597,23 +609,60 @@ pub trait DeviceContext {
         }
     }
 
-    /// Get the text extent (width and height) for the specified string
-    fn get_text_extent(&self, text: &str) -> (i32, i32) {
+    /// Get the size of a single line of text drawn with the current font
+    fn get_text_extent(&self, text: &str) -> Size {
         use std::ffi::CString;
         if let Ok(c_text) = CString::new(text) {
             let mut width = 0;
             let mut height = 0;
             unsafe { wxdragon_sys::wxd_DC_GetTextExtent(self.dc_ptr(), c_text.as_ptr(), &mut width, &mut height) };
-            (width, height)
+            Size::new(width, height)
         } else {
-            (0, 0)
+            Size::new(0, 0)
+        }
+    }
+
+    /// Get the width of every prefix of `text` with the current font: element `i` is the
+    /// extent of the first `i + 1` characters (not bytes).
+    ///
+    /// This is cheaper than measuring each prefix separately, e.g. for hit testing or
+    /// finding where to cut a label.
+    fn get_partial_text_extents(&self, text: &str) -> Vec<i32> {
+        use std::ffi::CString;
+        let Ok(c_text) = CString::new(text) else {
+            return Vec::new();
+        };
+        let count =
+            unsafe { wxdragon_sys::wxd_DC_GetPartialTextExtents(self.dc_ptr(), c_text.as_ptr(), std::ptr::null_mut(), 0) };
+        if count <= 0 {
+            return Vec::new();
         }
+        let mut widths = vec![0; count as usize];
+        unsafe { wxdragon_sys::wxd_DC_GetPartialTextExtents(self.dc_ptr(), c_text.as_ptr(), widths.as_mut_ptr(), widths.len()) };
+        widths
+    }
+
+    /// Get the metrics of the current font
+    fn get_font_metrics(&self) -> FontMetrics {
+        let mut metrics = wxdragon_sys::wxd_FontMetrics {
+            height: 0,
+            ascent: 0,
+            descent: 0,
+            internal_leading: 0,
+            external_leading: 0,
+            average_width: 0,
+        };
+        unsafe { wxdragon_sys::wxd_DC_GetFontMetrics(self.dc_ptr(), &mut metrics) };
+        metrics.into()
     }
 
-    /// Set a clipping region to restrict drawing operations
-    fn set_clipping_region(&self, x: i32, y: i32, width: i32, height: i32) {
+    /// Restrict drawing to `rect` (in logical coordinates).
+    ///
+    /// If a clipping region is already set, drawing is restricted to the intersection
+    /// of both; use [`clipped`](Self::clipped) to restore the previous region afterwards.
+    fn set_clipping_region(&self, rect: Rect) {
         unsafe {
-            wxdragon_sys::wxd_DC_SetClippingRegion(self.dc_ptr(), x, y, width, height);
+            wxdragon_sys::wxd_DC_SetClippingRegion(self.dc_ptr(), rect.x, rect.y, rect.width, rect.height);
         }
     }
 
@@ -622,6 +671,55 @@ pub trait DeviceContext {
         unsafe { wxdragon_sys::wxd_DC_DestroyClippingRegion(self.dc_ptr()) };
     }
 
+    /// Run `draw` with drawing restricted to `rect`, then restore the previous clipping region.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # fn paint(dc: &PaintDC, viewport: Rect) {
+    /// dc.clipped(viewport, |dc| {
+    ///     dc.draw_rectangle(-50, -50, 1000, 1000);
+    /// });
+    /// # }
+    /// ```
+    fn clipped<R>(&self, rect: Rect, draw: impl FnOnce(&Self) -> R) -> R
+    where
+        Self: Sized,
+    {
+        let mut previous = wxdragon_sys::wxd_Rect::from(Rect::default());
+        let had_clip = unsafe { wxdragon_sys::wxd_DC_GetClippingRect(self.dc_ptr(), &mut previous) };
+        self.set_clipping_region(rect);
+        let result = draw(self);
+        self.destroy_clipping_region();
+        if had_clip {
+            self.set_clipping_region(previous.into());
+        }
+        result
+    }
+
+    /// Save the pen, brush, font, text colours, background, modes, origins, user scale and
+    /// clipping box; they are restored when the returned guard is dropped.
+    ///
+    /// Nested drawing routines can use this to change settings without affecting their caller.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// fn draw_badge(dc: &dyn DeviceContext) {
+    ///     let _state = dc.save_state();
+    ///     dc.set_brush(Colour::new(200, 0, 0, 255), BrushStyle::Solid);
+    ///     dc.set_device_origin(10, 10);
+    ///     dc.draw_circle(0, 0, 8);
+    /// } // the caller's brush and origin are back here
+    /// ```
+    fn save_state(&self) -> DcStateGuard<'_> {
+        DcStateGuard {
+            dc_ptr: self.dc_ptr(),
+            state: unsafe { wxdragon_sys::wxd_DC_SaveState(self.dc_ptr()) },
+            _dc: PhantomData,
+        }
+    }
+
     /// Draw a polygon using the specified points
     fn draw_polygon(&self, points: &[Point], x_offset: i32, y_offset: i32, fill_mode: PolygonFillMode) {
         if points.is_empty() {
@@ -642,6 +740,32 @@ pub trait DeviceContext {
         };
     }
 
+    /// Draw several polygons at once, e.g. a shape with holes.
+    ///
+    /// With [`PolygonFillMode::OddEven`] areas covered by an even number of polygons are not filled.
+    fn draw_poly_polygon(&self, polygons: &[&[Point]], x_offset: i32, y_offset: i32, fill_mode: PolygonFillMode) {
+        let polygons: Vec<&[Point]> = polygons.iter().copied().filter(|p| !p.is_empty()).collect();
+        if polygons.is_empty() {
+            return;
+        }
+
+        let counts: Vec<i32> = polygons.iter().map(|p| p.len() as i32).collect();
+        let mut ffi_points: Vec<wxdragon_sys::wxd_Point> =
+            polygons.iter().flat_map(|p| p.iter().map(|pt| (*pt).into())).collect();
+
+        unsafe {
+            wxdragon_sys::wxd_DC_DrawPolyPolygon(
+                self.dc_ptr(),
+                counts.len() as i32,
+                counts.as_ptr(),
+                ffi_points.as_mut_ptr(),
+                x_offset,
+                y_offset,
+                fill_mode.to_raw(),
+            )
+        };
+    }
+
     /// Draw an elliptic arc
     fn draw_elliptic_arc(&self, x: i32, y: i32, width: i32, height: i32, start_angle: f64, end_angle: f64) {
         unsafe { wxdragon_sys::wxd_DC_DrawEllipticArc(self.dc_ptr(), x, y, width, height, start_angle, end_angle) };
@@ -981,6 +1105,48 @@ pub trait DeviceContext {
         };
     }
 
+    /// Draw a column header button in the platform style, with an optional `label`.
+    ///
+    /// `window` is the window this DC draws on; it supplies the theme and font.
+    fn draw_header_button(&self, window: &dyn WxWidget, rect: Rect, flags: RendererFlags, label: &str) {
+        use std::ffi::CString;
+        let c_label = CString::new(label).unwrap_or_default();
+        unsafe {
+            wxdragon_sys::wxd_DC_DrawHeaderButton(
+                self.dc_ptr(),
+                window.handle_ptr(),
+                rect.into(),
+                flags.bits() as i32,
+                c_label.as_ptr(),
+            )
+        };
+    }
+
+    /// Draw a push button background in the platform style.
+    fn draw_push_button(&self, window: &dyn WxWidget, rect: Rect, flags: RendererFlags) {
+        unsafe { wxdragon_sys::wxd_DC_DrawPushButton(self.dc_ptr(), window.handle_ptr(), rect.into(), flags.bits() as i32) };
+    }
+
+    /// Draw a check box in the platform style; see [`renderer::check_box_size`] for its size.
+    fn draw_check_box(&self, window: &dyn WxWidget, rect: Rect, flags: RendererFlags) {
+        unsafe { wxdragon_sys::wxd_DC_DrawCheckBox(self.dc_ptr(), window.handle_ptr(), rect.into(), flags.bits() as i32) };
+    }
+
+    /// Draw the background of a selected or hovered list item in the platform style.
+    ///
+    /// Pass [`RendererFlags::Selected`] for selection and [`RendererFlags::Focused`] if the
+    /// window has the focus.
+    fn draw_item_selection_rect(&self, window: &dyn WxWidget, rect: Rect, flags: RendererFlags) {
+        unsafe {
+            wxdragon_sys::wxd_DC_DrawItemSelectionRect(self.dc_ptr(), window.handle_ptr(), rect.into(), flags.bits() as i32)
+        };
+    }
+
+    /// Draw the platform's keyboard focus indicator around `rect`.
+    fn draw_focus_rect(&self, window: &dyn WxWidget, rect: Rect, flags: RendererFlags) {
+        unsafe { wxdragon_sys::wxd_DC_DrawFocusRect(self.dc_ptr(), window.handle_ptr(), rect.into(), flags.bits() as i32) };
+    }
+
     /// Flood fill starting from a point
     fn flood_fill(&self, x: i32, y: i32, colour: Colour, style: FloodFillMode) -> bool {
         unsafe { wxdragon_sys::wxd_DC_FloodFill(self.dc_ptr(), x, y, colour.into(), style.to_raw()) }
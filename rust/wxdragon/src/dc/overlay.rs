@@ -0,0 +1,83 @@
+use crate::dc::DeviceContext;
+use std::marker::PhantomData;
+
+/// Keeps temporary drawing, such as a rubber band or crosshair, separate from the
+/// window contents, so it can be erased without repainting the window.
+///
+/// This is the portable replacement for drawing with `LogicalFunction::Invert`, which
+/// macOS and GTK 3 do not support. Keep one `Overlay` per window for the duration of
+/// the interaction and call [`reset`](Self::reset) when it ends.
+///
+/// # Example
+/// ```ignore
+/// // On mouse move while dragging
+/// let dc = ClientDC::new(&panel);
+/// let overlay_dc = DCOverlay::new(&overlay, &dc);
+/// overlay_dc.clear();
+/// dc.draw_rectangle(x, y, width, height);
+///
+/// // On mouse release
+/// {
+///     let dc = ClientDC::new(&panel);
+///     DCOverlay::new(&overlay, &dc).clear();
+/// }
+/// overlay.reset();
+/// ```
+pub struct Overlay {
+    ptr: *mut wxdragon_sys::wxd_Overlay_t,
+}
+
+impl Overlay {
+    /// Create a new, empty overlay
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe { wxdragon_sys::wxd_Overlay_Create() },
+        }
+    }
+
+    /// Remove the overlay, e.g. when a drag ends. The next `DCOverlay` starts a new one.
+    pub fn reset(&self) {
+        unsafe { wxdragon_sys::wxd_Overlay_Reset(self.ptr) };
+    }
+}
+
+impl Default for Overlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Overlay {
+    fn drop(&mut self) {
+        unsafe { wxdragon_sys::wxd_Overlay_Destroy(self.ptr) };
+    }
+}
+
+/// Connects a DC to an [`Overlay`]: while it exists, drawing on the DC goes to the overlay.
+///
+/// It borrows the DC, so it is always dropped before the DC as wxWidgets requires.
+pub struct DCOverlay<'a> {
+    ptr: *mut wxdragon_sys::wxd_DCOverlay_t,
+    _borrows: PhantomData<(&'a Overlay, &'a dyn DeviceContext)>,
+}
+
+impl<'a> DCOverlay<'a> {
+    /// Connect `dc` to `overlay`
+    pub fn new(overlay: &'a Overlay, dc: &'a dyn DeviceContext) -> Self {
+        Self {
+            ptr: unsafe { wxdragon_sys::wxd_DCOverlay_Create(overlay.ptr, dc.dc_ptr()) },
+            _borrows: PhantomData,
+        }
+    }
+
+    /// Erase what was drawn on the overlay before
+    pub fn clear(&self) {
+        unsafe { wxdragon_sys::wxd_DCOverlay_Clear(self.ptr) };
+    }
+}
+
+impl Drop for DCOverlay<'_> {
+    fn drop(&mut self) {
+        unsafe { wxdragon_sys::wxd_DCOverlay_Destroy(self.ptr) };
+    }
+}
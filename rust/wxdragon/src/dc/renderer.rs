@@ -0,0 +1,36 @@
+//! Platform themed drawing of standard control parts (wxRendererNative).
+//!
+//! The drawing itself is done with [`DeviceContext`](crate::dc::DeviceContext) methods such as
+//! [`draw_header_button`](crate::dc::DeviceContext::draw_header_button); this module holds the
+//! state flags and the metrics needed to lay out custom controls.
+
+use crate::geometry::Size;
+use crate::window::WxWidget;
+use wxdragon_sys as ffi;
+
+widget_style_enum!(
+    name: RendererFlags,
+    doc: "State of a control part drawn by the native renderer.",
+    variants: {
+        Normal: ffi::WXD_CONTROL_NONE, "Enabled, not hovered or pressed.",
+        Disabled: ffi::WXD_CONTROL_DISABLED, "The control is disabled.",
+        Focused: ffi::WXD_CONTROL_FOCUSED, "The control has the keyboard focus.",
+        Pressed: ffi::WXD_CONTROL_PRESSED, "The button is pressed.",
+        Special: ffi::WXD_CONTROL_SPECIAL, "Part specific: default push button, or a selection rect of a single cell.",
+        Current: ffi::WXD_CONTROL_CURRENT, "The mouse is over the control.",
+        Selected: ffi::WXD_CONTROL_SELECTED, "The item is selected.",
+        Checked: ffi::WXD_CONTROL_CHECKED, "The check box is checked.",
+        Undetermined: ffi::WXD_CONTROL_UNDETERMINED, "The check box is in the third state."
+    },
+    default_variant: Normal
+);
+
+/// Height of a native header button (e.g. of list or grid columns) for the window's font.
+pub fn header_button_height(window: &dyn WxWidget) -> i32 {
+    unsafe { ffi::wxd_Renderer_GetHeaderButtonHeight(window.handle_ptr()) }
+}
+
+/// Size of a native check box drawn for the window.
+pub fn check_box_size(window: &dyn WxWidget, flags: RendererFlags) -> Size {
+    unsafe { ffi::wxd_Renderer_GetCheckBoxSize(window.handle_ptr(), flags.bits() as i32) }.into()
+}
@@ -1,4 +1,6 @@
+use crate::bitmap::Bitmap;
 use crate::dc::DeviceContext;
+use crate::geometry::Rect;
 
 /// A device context for drawing directly on the screen.
 ///
@@ -34,3 +36,46 @@ impl Default for ScreenDC {
         Self::new()
     }
 }
+
+/// Copies the screen contents into a bitmap.
+///
+/// `area` is in screen coordinates, which are negative for displays left of or above the
+/// primary display; it is clipped to the displays. `None` captures all displays.
+///
+/// Returns `None` and logs the reason if nothing could be captured: the area is off screen,
+/// the Screen Recording permission is missing on macOS (the system asks the user the first
+/// time), or the platform doesn't allow it (Wayland).
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// if let Some(shot) = wxdragon::capture_screen(Some(Rect::new(-200, 0, 400, 300))) {
+///     shot.save_file("screenshot.png", BitmapType::Png).ok();
+/// }
+/// ```
+pub fn capture_screen(area: Option<Rect>) -> Option<Bitmap> {
+    let rect = area.unwrap_or_default();
+    if area.is_some_and(|r| r.width <= 0 || r.height <= 0) {
+        log::warn!("capture_screen: empty area {rect:?}");
+        return None;
+    }
+    let mut error = 0;
+    let ptr = unsafe { wxdragon_sys::wxd_CaptureScreen(rect.x, rect.y, rect.width, rect.height, &mut error) };
+    bitmap_from_capture(ptr, error, "capture_screen")
+}
+
+/// Wraps the result of a capture function, logging why it failed
+pub(crate) fn bitmap_from_capture(ptr: *mut wxdragon_sys::wxd_Bitmap_t, error: i32, what: &str) -> Option<Bitmap> {
+    if !ptr.is_null() {
+        return Some(Bitmap::from(ptr));
+    }
+    let reason = match error as u32 {
+        wxdragon_sys::WXD_CAPTURE_PERMISSION_DENIED => "the Screen Recording permission was not granted",
+        wxdragon_sys::WXD_CAPTURE_OUTSIDE_SCREEN => "the area is outside all displays",
+        wxdragon_sys::WXD_CAPTURE_NOT_VISIBLE => "the window is not shown on screen",
+        wxdragon_sys::WXD_CAPTURE_NOT_SUPPORTED => "the platform doesn't allow reading the screen",
+        _ => "the screen could not be read",
+    };
+    log::warn!("{what}: {reason}");
+    None
+}
@@ -1,33 +1,41 @@
 use crate::dc::DeviceContext;
 use crate::window::WxWidget;
+use std::marker::PhantomData;
 
 /// A device context to draw on a window including both the client and non-client areas.
 ///
 /// WindowDC allows drawing on the entire window area, including borders, title bar, etc.
-/// For drawing only in the client area, use ClientDC instead.
-pub struct WindowDC {
+/// For drawing only in the client area, use ClientDC instead. Like ClientDC, it borrows
+/// the window and can be used outside paint events.
+pub struct WindowDC<'a> {
     dc_ptr: *mut wxdragon_sys::wxd_WindowDC_t,
+    _window: PhantomData<&'a ()>,
 }
 
-impl WindowDC {
+impl<'a> WindowDC<'a> {
     /// Create a new WindowDC for the specified window
     ///
+    /// If the window has been destroyed the DC is not OK and drawing on it does nothing.
+    ///
     /// # Arguments
     /// * `window` - The window to draw on
-    pub fn new<W: WxWidget>(window: &W) -> Self {
+    pub fn new<W: WxWidget + ?Sized>(window: &'a W) -> Self {
         let ptr = window.handle_ptr();
         let dc_ptr = unsafe { wxdragon_sys::wxd_WindowDC_Create(ptr) };
-        Self { dc_ptr }
+        Self {
+            dc_ptr,
+            _window: PhantomData,
+        }
     }
 }
 
-impl DeviceContext for WindowDC {
+impl DeviceContext for WindowDC<'_> {
     fn dc_ptr(&self) -> *mut wxdragon_sys::wxd_DC_t {
         unsafe { wxdragon_sys::wxd_WindowDC_AsDC(self.dc_ptr) }
     }
 }
 
-impl Drop for WindowDC {
+impl Drop for WindowDC<'_> {
     fn drop(&mut self) {
         unsafe {
             wxdragon_sys::wxd_WindowDC_Destroy(self.dc_ptr);
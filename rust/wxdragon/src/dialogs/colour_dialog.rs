@@ -1,10 +1,117 @@
+use std::cell::RefCell;
 use std::ffi::CString;
 
 use crate::{color::Colour, dialogs::Dialog, window::WxWidget};
 use wxdragon_sys as ffi;
 
+/// Number of custom colour slots offered by the colour dialog.
+pub const NUM_CUSTOM_COLOURS: usize = 16;
+
+thread_local! {
+    // Custom colour slots shared by every ColourDialog shown on the GUI thread,
+    // so colours the user saved in one invocation show up again in the next.
+    static CUSTOM_COLOURS: RefCell<[Option<Colour>; NUM_CUSTOM_COLOURS]> =
+        const { RefCell::new([None; NUM_CUSTOM_COLOURS]) };
+}
+
+/// Settings passed to and returned from a [`ColourDialog`] (wxColourData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ColourData {
+    /// The selected (or initially selected) colour.
+    pub colour: Option<Colour>,
+    /// Under Windows, show the full dialog with custom colour controls.
+    pub choose_full: bool,
+    /// The custom colour slots; `None` marks an unset slot.
+    pub custom_colours: [Option<Colour>; NUM_CUSTOM_COLOURS],
+}
+
+impl ColourData {
+    /// Creates empty colour data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the initially selected colour.
+    pub fn with_colour(mut self, colour: Colour) -> Self {
+        self.colour = Some(colour);
+        self
+    }
+
+    /// Sets whether the full dialog is shown (Windows only).
+    pub fn with_choose_full(mut self, choose_full: bool) -> Self {
+        self.choose_full = choose_full;
+        self
+    }
+
+    /// Sets the custom colour at `index` (0 to 15). Out of range indices are ignored.
+    pub fn with_custom_colour(mut self, index: usize, colour: Colour) -> Self {
+        if let Some(slot) = self.custom_colours.get_mut(index) {
+            *slot = Some(colour);
+        }
+        self
+    }
+
+    fn to_ffi(self) -> *mut ffi::wxd_ColourData_t {
+        let data_ptr = unsafe { ffi::wxd_ColourData_Create() };
+        if data_ptr.is_null() {
+            return data_ptr;
+        }
+        unsafe {
+            if let Some(colour) = self.colour {
+                ffi::wxd_ColourData_SetColour(data_ptr, colour.into());
+            }
+            ffi::wxd_ColourData_SetChooseFull(data_ptr, self.choose_full);
+            for (index, colour) in self.custom_colours.iter().enumerate() {
+                if let Some(colour) = colour {
+                    ffi::wxd_ColourData_SetCustomColour(data_ptr, index as i32, (*colour).into());
+                }
+            }
+        }
+        data_ptr
+    }
+
+    /// # Safety
+    /// `ptr` must be null or point to a live wxColourData.
+    unsafe fn from_ffi(ptr: *mut ffi::wxd_ColourData_t) -> Option<Self> {
+        if ptr.is_null() {
+            return None;
+        }
+        let mut data = ColourData {
+            colour: Some(Colour::from(unsafe { ffi::wxd_ColourData_GetColour(ptr) })),
+            choose_full: unsafe { ffi::wxd_ColourData_GetChooseFull(ptr) },
+            ..Default::default()
+        };
+        for (index, slot) in data.custom_colours.iter_mut().enumerate() {
+            if unsafe { ffi::wxd_ColourData_HasCustomColour(ptr, index as i32) } {
+                *slot = Some(Colour::from(unsafe {
+                    ffi::wxd_ColourData_GetCustomColour(ptr, index as i32)
+                }));
+            }
+        }
+        Some(data)
+    }
+}
+
 /// Wrapper for wxColourDialog.
 /// A dialog for choosing a colour.
+///
+/// The custom colour slots are remembered for the lifetime of the process: each
+/// dialog starts with the slots left by the previous one, and [`show_modal`](ColourDialog::show_modal)
+/// stores the user's edits back.
+///
+/// # Example
+///
+/// ```ignore
+/// let dialog = ColourDialog::builder(&frame)
+///     .with_initial_colour(Colour::new(255, 0, 0, 255))
+///     .build();
+/// if dialog.show_modal() == ID_OK {
+///     if let Some(colour) = dialog.get_colour() {
+///         println!("Picked {colour:?}");
+///     }
+/// }
+/// dialog.destroy();
+/// ```
 #[derive(Clone, Copy)]
 pub struct ColourDialog {
     dialog_base: Dialog,
@@ -14,9 +121,7 @@ pub struct ColourDialog {
 pub struct ColourDialogBuilder<'a, W: WxWidget> {
     parent: &'a W,
     title: String,
-    initial_colour: Option<Colour>,
-    choose_full: bool,
-    custom_colours: std::collections::HashMap<i32, Colour>,
+    data: ColourData,
 }
 
 impl ColourDialog {
@@ -25,9 +130,10 @@ impl ColourDialog {
         ColourDialogBuilder {
             parent,
             title: "Choose a colour".to_string(),
-            initial_colour: None,
-            choose_full: false,
-            custom_colours: std::collections::HashMap::new(),
+            data: ColourData {
+                custom_colours: Self::stored_custom_colours(),
+                ..ColourData::default()
+            },
         }
     }
 
@@ -48,8 +154,14 @@ impl ColourDialog {
     /// Returns an integer value which is usually one of the standard dialog return codes
     /// (e.g., ID_OK, ID_CANCEL). If the dialog was accepted (ID_OK), you can call
     /// get_colour() to retrieve the selected colour.
+    ///
+    /// The dialog's custom colours are stored for the next ColourDialog once it closes.
     pub fn show_modal(&self) -> i32 {
-        self.dialog_base.show_modal()
+        let result = self.dialog_base.show_modal();
+        if let Some(data) = self.get_colour_data() {
+            Self::set_stored_custom_colours(data.custom_colours);
+        }
+        result
     }
 
     /// Get the selected colour from the dialog.
@@ -64,6 +176,22 @@ impl ColourDialog {
             Some(Colour::from(colour))
         }
     }
+
+    /// Returns a snapshot of the dialog's colour data, including its custom colours.
+    pub fn get_colour_data(&self) -> Option<ColourData> {
+        unsafe { ColourData::from_ffi(ffi::wxd_ColourDialog_GetColourData(self.as_ptr())) }
+    }
+
+    /// Returns the custom colours that new dialogs will start with.
+    pub fn stored_custom_colours() -> [Option<Colour>; NUM_CUSTOM_COLOURS] {
+        CUSTOM_COLOURS.with(|colours| *colours.borrow())
+    }
+
+    /// Replaces the custom colours that new dialogs will start with,
+    /// e.g. to restore them from saved settings.
+    pub fn set_stored_custom_colours(colours: [Option<Colour>; NUM_CUSTOM_COLOURS]) {
+        CUSTOM_COLOURS.with(|stored| *stored.borrow_mut() = colours);
+    }
 }
 
 impl<'a, W: WxWidget> ColourDialogBuilder<'a, W> {
@@ -75,21 +203,30 @@ impl<'a, W: WxWidget> ColourDialogBuilder<'a, W> {
 
     /// Set the initial colour to use
     pub fn with_initial_colour(mut self, colour: Colour) -> Self {
-        self.initial_colour = Some(colour);
+        self.data.colour = Some(colour);
         self
     }
 
     /// Under Windows, determines whether the Windows colour dialog will display
     /// the full dialog with custom colour selection controls when it is first shown.
     pub fn with_choose_full(mut self, choose_full: bool) -> Self {
-        self.choose_full = choose_full;
+        self.data.choose_full = choose_full;
         self
     }
 
     /// Sets custom colours (index from 0 to 15) to be displayed in the
     /// bottom row of custom colour swatches.
     pub fn with_custom_colour(mut self, index: i32, colour: Colour) -> Self {
-        self.custom_colours.insert(index, colour);
+        if let Ok(index) = usize::try_from(index) {
+            self.data = self.data.with_custom_colour(index, colour);
+        }
+        self
+    }
+
+    /// Uses `data` as is, replacing the initial colour, the choose-full flag
+    /// and the remembered custom colours.
+    pub fn with_colour_data(mut self, data: ColourData) -> Self {
+        self.data = data;
         self
     }
 
@@ -97,35 +234,14 @@ impl<'a, W: WxWidget> ColourDialogBuilder<'a, W> {
     pub fn build(self) -> ColourDialog {
         let c_title = CString::new(self.title).expect("CString::new failed for title");
 
-        // Create a temporary ColourData if we have an initial colour, choose_full flag or custom colours
-        let has_custom_data = self.initial_colour.is_some() || self.choose_full || !self.custom_colours.is_empty();
-
-        let colour_data_ptr = if has_custom_data {
-            let data_ptr = unsafe { ffi::wxd_ColourData_Create() };
-            if !data_ptr.is_null() {
-                if let Some(colour) = self.initial_colour {
-                    unsafe { ffi::wxd_ColourData_SetColour(data_ptr, colour.into()) };
-                }
-
-                if self.choose_full {
-                    unsafe { ffi::wxd_ColourData_SetChooseFull(data_ptr, true) };
-                }
-
-                for (index, colour) in self.custom_colours {
-                    unsafe { ffi::wxd_ColourData_SetCustomColour(data_ptr, index, colour.into()) };
-                }
-            }
-            data_ptr
-        } else {
-            std::ptr::null_mut()
-        };
+        let colour_data_ptr = self.data.to_ffi();
 
         let parent_ptr = self.parent.handle_ptr();
         assert!(!parent_ptr.is_null(), "ColourDialog requires a valid parent window pointer.");
 
         let ptr = unsafe { ffi::wxd_ColourDialog_Create(parent_ptr, c_title.as_ptr(), colour_data_ptr) };
 
-        // Clean up the temporary ColourData if we created one
+        // The dialog copies the data, so the temporary can go
         if !colour_data_ptr.is_null() {
             unsafe { ffi::wxd_ColourData_Destroy(colour_data_ptr) };
         }
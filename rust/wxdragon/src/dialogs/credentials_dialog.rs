@@ -0,0 +1,198 @@
+use crate::dialogs::{Dialog, StdDialogButtons};
+use crate::event::TextEvents;
+use crate::geometry::Size;
+use crate::id::ID_OK;
+use crate::sizers::{BoxSizer, FlexGridSizer, Orientation, SizerFlag};
+use crate::utils::SecretString;
+use crate::widgets::checkbox::CheckBox;
+use crate::widgets::static_text::StaticText;
+use crate::widgets::textctrl::{TextCtrl, TextCtrlStyle};
+use crate::window::WxWidget;
+
+/// User name and password entered in a [`CredentialsDialog`].
+///
+/// `Debug` output never contains the password.
+#[derive(Debug, Clone)]
+pub struct Credentials {
+    pub user: String,
+    pub password: SecretString,
+    /// State of the "remember me" checkbox; `false` if the dialog had none.
+    pub remember: bool,
+}
+
+// --- CredentialsDialog ---
+/// A dialog asking for a user name and a masked password, with an optional
+/// "remember me" checkbox.
+///
+/// Tab moves from the user field to the password field, the checkbox and the
+/// buttons. Enter activates OK from either field; OK stays disabled while the user
+/// name is empty. When a user name is pre-filled, the password field gets the focus.
+///
+/// # Example
+/// ```ignore
+/// let dialog = CredentialsDialog::builder(&frame, "Connect")
+///     .with_message("Log in to example.com")
+///     .with_user("alice")
+///     .with_remember_checkbox("Remember me", false)
+///     .build();
+/// if let Some(credentials) = dialog.show() {
+///     connect(&credentials.user, credentials.password.expose());
+/// }
+/// ```
+pub struct CredentialsDialog {
+    dialog: Dialog,
+    user: TextCtrl,
+    password: TextCtrl,
+    remember: Option<CheckBox>,
+}
+
+impl CredentialsDialog {
+    /// Creates a new builder for a CredentialsDialog.
+    pub fn builder<'a>(parent: &'a dyn WxWidget, caption: &str) -> CredentialsDialogBuilder<'a> {
+        CredentialsDialogBuilder::new(parent, caption)
+    }
+
+    /// Shows the dialog modally.
+    /// Returns `ID_OK` or `ID_CANCEL`.
+    pub fn show_modal(&self) -> i32 {
+        self.dialog.show_modal()
+    }
+
+    /// Shows the dialog modally and returns the credentials if the user pressed OK.
+    pub fn show(&self) -> Option<Credentials> {
+        if self.show_modal() != ID_OK {
+            return None;
+        }
+        Some(self.get_credentials())
+    }
+
+    /// Returns the values currently entered in the dialog.
+    pub fn get_credentials(&self) -> Credentials {
+        Credentials {
+            user: self.user.get_value().trim().to_string(),
+            password: self.password.get_secret_value(),
+            remember: self.remember.is_some_and(|cb| cb.is_checked()),
+        }
+    }
+}
+
+impl WxWidget for CredentialsDialog {
+    fn handle_ptr(&self) -> *mut wxdragon_sys::wxd_Window_t {
+        self.dialog.handle_ptr()
+    }
+}
+
+impl Drop for CredentialsDialog {
+    fn drop(&mut self) {
+        // Don't leave the password in the control any longer than needed
+        self.password.clear();
+        self.dialog.destroy();
+    }
+}
+
+// --- CredentialsDialogBuilder ---
+pub struct CredentialsDialogBuilder<'a> {
+    parent: &'a dyn WxWidget,
+    caption: String,
+    message: String,
+    user: String,
+    remember: Option<(String, bool)>,
+}
+
+impl<'a> CredentialsDialogBuilder<'a> {
+    pub fn new(parent: &'a dyn WxWidget, caption: &str) -> Self {
+        CredentialsDialogBuilder {
+            parent,
+            caption: caption.to_string(),
+            message: String::new(),
+            user: String::new(),
+            remember: None,
+        }
+    }
+
+    /// Sets a message shown above the fields.
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Pre-fills the user name.
+    pub fn with_user(mut self, user: &str) -> Self {
+        self.user = user.to_string();
+        self
+    }
+
+    /// Adds a "remember me" checkbox with the given label and initial state.
+    pub fn with_remember_checkbox(mut self, label: &str, checked: bool) -> Self {
+        self.remember = Some((label.to_string(), checked));
+        self
+    }
+
+    pub fn build(self) -> CredentialsDialog {
+        let dialog = Dialog::builder(self.parent, &self.caption).build();
+        let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+
+        if !self.message.is_empty() {
+            let message = StaticText::builder(&dialog).with_label(&self.message).build();
+            main_sizer.add(
+                &message,
+                0,
+                SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top,
+                10,
+            );
+        }
+
+        // Controls are created in tab order: user, password, checkbox, buttons.
+        let grid = FlexGridSizer::builder(2, 2).with_gap(Size::new(8, 8)).build();
+        grid.add_growable_col(1, 1);
+        let user_label = StaticText::builder(&dialog).with_label("&User name:").build();
+        let user = TextCtrl::builder(&dialog)
+            .with_value(&self.user)
+            .with_size(Size::new(220, -1))
+            .build();
+        let password_label = StaticText::builder(&dialog).with_label("&Password:").build();
+        let password = TextCtrl::builder(&dialog).with_style(TextCtrlStyle::Password).build();
+        grid.add(&user_label, 0, SizerFlag::AlignCenterVertical, 0);
+        grid.add(&user, 1, SizerFlag::Expand, 0);
+        grid.add(&password_label, 0, SizerFlag::AlignCenterVertical, 0);
+        grid.add(&password, 1, SizerFlag::Expand, 0);
+        main_sizer.add_sizer(&grid, 1, SizerFlag::Expand | SizerFlag::All, 10);
+
+        let remember = self.remember.map(|(label, checked)| {
+            let checkbox = CheckBox::builder(&dialog).with_label(&label).build();
+            checkbox.set_value(checked);
+            main_sizer.add(&checkbox, 0, SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom, 10);
+            checkbox
+        });
+
+        // OK is the default button, so Enter in either field accepts the dialog.
+        if let Some(buttons) = dialog.create_std_dialog_button_sizer(StdDialogButtons::Ok | StdDialogButtons::Cancel) {
+            if let Some(ok) = buttons.get_affirmative_button() {
+                ok.enable(!self.user.trim().is_empty());
+                user.on_text_updated(move |_| ok.enable(!user.get_value().trim().is_empty()));
+            }
+            main_sizer.add_sizer(
+                &buttons,
+                0,
+                SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+                10,
+            );
+        }
+
+        dialog.set_sizer_and_fit(main_sizer, true);
+        dialog.centre();
+
+        if self.user.trim().is_empty() {
+            user.set_focus();
+        } else {
+            password.set_focus();
+        }
+
+        CredentialsDialog {
+            dialog,
+            user,
+            password,
+            remember,
+        }
+    }
+}
@@ -10,7 +10,8 @@ crate::widget_style_enum!(
     variants: {
         Default: ffi::WXD_DEFAULT_DIALOG_STYLE, "Default style.",
         MustExist: ffi::WXD_DD_DIR_MUST_EXIST, "The dialog will allow the user to choose only an existing directory.",
-        ChangeDir: ffi::WXD_DD_CHANGE_DIR, "Change the current working directory to the directory chosen by the user."
+        ChangeDir: ffi::WXD_DD_CHANGE_DIR, "Change the current working directory to the directory chosen by the user.",
+        ShowHidden: ffi::WXD_DD_SHOW_HIDDEN, "Show hidden and system folders."
     },
     default_variant: Default
 );
@@ -19,14 +20,28 @@ crate::widget_style_enum!(
 pub type DirDialogPtr = *mut ffi::wxd_DirDialog_t;
 
 /// A dialog that lets the user choose a directory.
+///
+/// # Example
+/// ```ignore
+/// let dialog = DirDialog::builder(&frame)
+///     .with_message("Choose an output folder")
+///     .with_default_path("/tmp")
+///     .with_style(DirDialogStyle::Default | DirDialogStyle::MustExist)
+///     .build();
+/// if dialog.show_modal() == ID_OK {
+///     if let Some(path) = dialog.get_path() {
+///         println!("Exporting to {path}");
+///     }
+/// }
+/// ```
 pub struct DirDialog {
     ptr: DirDialogPtr,
 }
 
 impl DirDialog {
     /// Creates a new builder for a DirDialog.
-    pub fn builder<'a>(parent: &'a dyn WxWidget, message: &str, default_path: &str) -> DirDialogBuilder<'a> {
-        DirDialogBuilder::new(parent, message, default_path)
+    pub fn builder<'a>(parent: &'a dyn WxWidget) -> DirDialogBuilder<'a> {
+        DirDialogBuilder::new(parent)
     }
 
     /// Shows the dialog modally.
@@ -35,9 +50,10 @@ impl DirDialog {
     }
 
     /// Gets the path selected by the user.
+    /// Returns `None` if no directory was chosen.
     pub fn get_path(&self) -> Option<String> {
         let len = unsafe { ffi::wxd_DirDialog_GetPath(self.ptr, std::ptr::null_mut(), 0) };
-        if len < 0 {
+        if len <= 0 {
             return None;
         }
         let mut buf = vec![0; len as usize + 1];
@@ -94,26 +110,38 @@ pub struct DirDialogBuilder<'a> {
     parent: &'a dyn WxWidget,
     message: String,
     default_path: String,
-    style: i64,
+    style: DirDialogStyle,
     pos: Point,
     size: Size,
 }
 
 impl<'a> DirDialogBuilder<'a> {
     /// Creates a new builder with default values.
-    pub fn new(parent: &'a dyn WxWidget, message: &str, default_path: &str) -> Self {
+    pub fn new(parent: &'a dyn WxWidget) -> Self {
         DirDialogBuilder {
             parent,
-            message: message.to_string(),
-            default_path: default_path.to_string(),
-            style: DirDialogStyle::default().bits(),
+            message: "Choose a directory".to_string(),
+            default_path: String::new(),
+            style: DirDialogStyle::default(),
             pos: Point::DEFAULT_POSITION,
             size: Size::DEFAULT_SIZE,
         }
     }
 
+    /// Sets the message shown to the user.
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Sets the directory initially selected in the dialog.
+    pub fn with_default_path(mut self, path: &str) -> Self {
+        self.default_path = path.to_string();
+        self
+    }
+
     /// Sets the style for the dialog.
-    pub fn with_style(mut self, style: i64) -> Self {
+    pub fn with_style(mut self, style: DirDialogStyle) -> Self {
         self.style = style;
         self
     }
@@ -140,7 +168,7 @@ impl<'a> DirDialogBuilder<'a> {
                 self.parent.handle_ptr(),
                 c_message.as_ptr(),
                 c_default_path.as_ptr(),
-                self.style,
+                self.style.bits() as ffi::wxd_Style_t,
                 self.pos.x,
                 self.pos.y,
                 self.size.width,
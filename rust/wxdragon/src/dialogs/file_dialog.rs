@@ -25,6 +25,25 @@ widget_style_enum!(
 pub type FileDialogPtr = *mut ffi::wxd_FileDialog_t;
 
 // --- FileDialog ---
+
+/// Native file open/save dialog.
+///
+/// Paths are exchanged with wxWidgets as UTF-8, so non-ASCII file names
+/// round-trip unchanged on every platform.
+///
+/// # Example
+/// ```ignore
+/// let dialog = FileDialog::builder(&frame)
+///     .with_message("Open image")
+///     .with_wildcard("Images (*.png;*.jpg)|*.png;*.jpg|All files|*.*")
+///     .with_style(FileDialogStyle::Open | FileDialogStyle::FileMustExist | FileDialogStyle::Multiple)
+///     .build();
+/// if dialog.show_modal() == ID_OK {
+///     for path in dialog.get_paths() {
+///         println!("{path} (filter {})", dialog.get_filter_index());
+///     }
+/// }
+/// ```
 #[derive(Clone)] // Cloning FileDialog clones the underlying Dialog pointer
 pub struct FileDialog {
     dialog_base: Dialog,
@@ -62,7 +81,8 @@ impl FileDialog {
         let mut buffer = [0; 2048]; // Larger buffer for paths
         let len = unsafe { ffi::wxd_FileDialog_GetPath(self.as_ptr(), buffer.as_mut_ptr(), buffer.len()) };
 
-        if len < 0 {
+        // wxWidgets reports an empty path when nothing was chosen
+        if len <= 0 {
             return None;
         }
 
@@ -91,7 +111,7 @@ impl FileDialog {
         let mut buffer = [0; 1024];
         let len = unsafe { ffi::wxd_FileDialog_GetFilename(self.as_ptr(), buffer.as_mut_ptr(), buffer.len()) };
 
-        if len < 0 {
+        if len <= 0 {
             return None;
         }
 
@@ -293,11 +313,17 @@ impl<'a> FileDialogBuilder<'a> {
         self
     }
 
-    pub fn with_default_file(mut self, file: &str) -> Self {
-        self.default_file = file.to_string();
+    /// Sets the file name initially shown in the dialog.
+    pub fn with_default_filename(mut self, filename: &str) -> Self {
+        self.default_file = filename.to_string();
         self
     }
 
+    #[deprecated(note = "renamed to `with_default_filename`")]
+    pub fn with_default_file(self, file: &str) -> Self {
+        self.with_default_filename(file)
+    }
+
     pub fn with_wildcard(mut self, wildcard: &str) -> Self {
         self.wildcard = wildcard.to_string();
         self
@@ -18,6 +18,19 @@ pub struct FontDialogBuilder<'a, W: WxWidget> {
 }
 
 impl FontDialog {
+    /// Creates a font dialog with `initial_font` preselected.
+    ///
+    /// After `show_modal()` returns `ID_OK`, [`get_font`](FontDialog::get_font) returns the
+    /// chosen font, which can be passed to e.g. `Grid::set_cell_font` directly.
+    pub fn new<W: WxWidget>(parent: &W, initial_font: Option<&Font>) -> Self {
+        let mut font_data = FontData::new();
+        if let Some(font) = initial_font {
+            font_data.set_initial_font(font);
+        }
+        // wxFontDialog copies the data, so it can be dropped right after creation
+        Self::builder(parent).with_font_data(&font_data).build()
+    }
+
     /// Creates a builder for a font dialog.
     pub fn builder<'a, W: WxWidget>(parent: &'a W) -> FontDialogBuilder<'a, W> {
         FontDialogBuilder {
@@ -69,8 +82,8 @@ impl FontDialog {
         if data_ptr.is_null() {
             None
         } else {
-            // Create a FontData that doesn't take ownership
-            Some(FontData { ptr: data_ptr })
+            // The dialog keeps ownership of its font data
+            Some(unsafe { FontData::from_ptr_unowned(data_ptr) })
         }
     }
 }
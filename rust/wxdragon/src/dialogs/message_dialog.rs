@@ -55,6 +55,13 @@ impl MessageDialog {
         self.dialog_base.show_modal()
     }
 
+    /// Shows the dialog modally from an async task and resolves to the button pressed, see
+    /// [`Dialog::show_modal_async`]. Takes the dialog, which is destroyed once it closed.
+    #[cfg(feature = "async")]
+    pub fn show_modal_async(self) -> crate::async_support::ModalFuture<i32> {
+        crate::async_support::run_modal(move || self.show_modal())
+    }
+
     pub fn as_ptr(&self) -> MessageDialogPtr {
         self.dialog_base.as_ptr() as MessageDialogPtr
     }
@@ -1,5 +1,6 @@
 use crate::Bitmap;
 use crate::event::WxEvtHandler;
+use crate::sizers::StdDialogButtonSizer;
 use crate::window::{WindowHandle, WxWidget};
 use std::ffi::CString;
 use std::marker::PhantomData;
@@ -7,14 +8,19 @@ use wxdragon_sys as ffi;
 
 pub mod about_dialog;
 pub mod colour_dialog;
+pub mod credentials_dialog;
 pub mod dir_dialog;
 pub mod file_dialog;
 pub mod font_dialog;
 pub mod message_dialog;
 pub mod multi_choice_dialog;
+pub mod number_entry_dialog;
+pub mod password_entry_dialog;
 pub mod progress_dialog;
+pub mod rich_message_dialog;
 pub mod single_choice_dialog;
 pub mod text_entry_dialog;
+pub mod wizard;
 
 // Define DialogStyle enum using the widget_style_enum macro
 widget_style_enum!(
@@ -33,6 +39,23 @@ widget_style_enum!(
     default_variant: DefaultDialogStyle
 );
 
+widget_style_enum!(
+    name: StdDialogButtons,
+    doc: "Standard buttons created by [`Dialog::create_std_dialog_button_sizer`].",
+    variants: {
+        Ok: ffi::WXD_OK, "An OK button (the affirmative button).",
+        Cancel: ffi::WXD_CANCEL, "A Cancel button (the escape button).",
+        Yes: ffi::WXD_YES, "A Yes button.",
+        No: ffi::WXD_NO, "A No button.",
+        Apply: ffi::WXD_APPLY, "An Apply button.",
+        Close: ffi::WXD_CLOSE, "A Close button.",
+        Help: ffi::WXD_HELP, "A Help button.",
+        NoDefault: ffi::WXD_NO_DEFAULT, "Make No the default button instead of Yes.",
+        OkCancel: ffi::WXD_OK | ffi::WXD_CANCEL, "OK and Cancel buttons."
+    },
+    default_variant: OkCancel
+);
+
 // --- Dialog --- (Base struct for dialogs)
 /// Represents a wxDialog.
 ///
@@ -115,6 +138,15 @@ impl Dialog {
         unsafe { ffi::wxd_Dialog_ShowModal(ptr) }
     }
 
+    /// Shows the dialog modally from a task spawned with
+    /// [`spawn_local`](crate::async_support::spawn_local) and resolves to its return code.
+    /// The modal loop runs from the event loop, not inside the task's poll.
+    #[cfg(feature = "async")]
+    pub fn show_modal_async(&self) -> crate::async_support::ModalFuture<i32> {
+        let dialog = *self;
+        crate::async_support::run_modal(move || dialog.show_modal())
+    }
+
     /// Ends the modal dialog with the given return code.
     /// This method should be called from event handlers to close the dialog.
     /// The return code is what will be returned by show_modal().
@@ -217,6 +249,31 @@ impl Dialog {
         unsafe { ffi::wxd_Dialog_GetReturnCode(ptr) }
     }
 
+    /// Creates the standard buttons given by `flags` and returns them in a realized
+    /// [`StdDialogButtonSizer`], ordered according to the platform conventions.
+    ///
+    /// The buttons use stock IDs and stock labels, so their text is translated through
+    /// the `wxstd` catalog. Because they carry the affirmative (`ID_OK`/`ID_YES`) and
+    /// escape (`ID_CANCEL`) IDs, Enter and Esc work natively and the buttons end the modal
+    /// loop without any `on_click` handler.
+    ///
+    /// Returns `None` if no button was requested or the dialog has been destroyed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let buttons = dialog
+    ///     .create_std_dialog_button_sizer(StdDialogButtons::Ok | StdDialogButtons::Cancel)
+    ///     .expect("buttons");
+    /// main_sizer.add_sizer(&buttons, 0, SizerFlag::Expand | SizerFlag::All, 10);
+    /// ```
+    pub fn create_std_dialog_button_sizer(&self, flags: StdDialogButtons) -> Option<StdDialogButtonSizer> {
+        let ptr = self.dialog_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        unsafe { StdDialogButtonSizer::from_ptr(ffi::wxd_Dialog_CreateStdDialogButtonSizer(ptr, flags.bits())) }
+    }
+
     /// Returns the raw underlying dialog pointer.
     pub fn as_ptr(&self) -> *mut ffi::wxd_Dialog_t {
         self.dialog_ptr()
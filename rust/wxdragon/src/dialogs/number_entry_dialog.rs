@@ -0,0 +1,140 @@
+use crate::dialogs::Dialog;
+use crate::id::ID_OK;
+use crate::window::WxWidget;
+use std::cell::Cell;
+use std::ffi::CString;
+use wxdragon_sys as ffi;
+
+// --- NumberEntryDialog ---
+/// A dialog asking the user for an integer within a range (wxNumberEntryDialog).
+///
+/// A reversed range is swapped and an out-of-range initial value is clamped when the
+/// dialog is created, so any builder input yields a usable dialog. Values are limited
+/// to the 32-bit range on Windows, where the underlying control uses `long`.
+///
+/// # Example
+/// ```ignore
+/// let dialog = NumberEntryDialog::builder(&frame, "Choose the service port", "Port:", "IPC Demo")
+///     .with_range(1024, 65535)
+///     .with_value(4242)
+///     .build();
+/// dialog.show_modal();
+/// if let Some(port) = dialog.get_value() {
+///     println!("Using port {port}");
+/// }
+/// dialog.destroy();
+/// ```
+pub struct NumberEntryDialog {
+    dialog_base: Dialog,
+    accepted: Cell<bool>,
+}
+
+impl NumberEntryDialog {
+    /// Creates a new builder for a NumberEntryDialog.
+    pub fn builder<'a>(parent: &'a dyn WxWidget, message: &str, prompt: &str, caption: &str) -> NumberEntryDialogBuilder<'a> {
+        NumberEntryDialogBuilder::new(parent, message, prompt, caption)
+    }
+
+    /// Creates a new NumberEntryDialog wrapper from a raw pointer.
+    /// # Safety
+    /// The pointer must be a valid pointer to a wxNumberEntryDialog.
+    pub(crate) unsafe fn from_ptr(ptr: *mut ffi::wxd_NumberEntryDialog_t) -> Self {
+        NumberEntryDialog {
+            dialog_base: unsafe { Dialog::from_ptr(ptr as *mut ffi::wxd_Dialog_t) },
+            accepted: Cell::new(false),
+        }
+    }
+
+    fn as_ptr(&self) -> *mut ffi::wxd_NumberEntryDialog_t {
+        self.dialog_base.as_ptr() as *mut ffi::wxd_NumberEntryDialog_t
+    }
+
+    /// Shows the dialog modally.
+    /// Returns an integer value which is usually one of the standard dialog return codes
+    /// (e.g., ID_OK, ID_CANCEL).
+    pub fn show_modal(&self) -> i32 {
+        let result = self.dialog_base.show_modal();
+        self.accepted.set(result == ID_OK);
+        result
+    }
+
+    /// Gets the number entered by the user.
+    /// Returns `None` unless the dialog was closed with OK.
+    pub fn get_value(&self) -> Option<i64> {
+        if !self.accepted.get() {
+            return None;
+        }
+        Some(unsafe { ffi::wxd_NumberEntryDialog_GetValue(self.as_ptr()) })
+    }
+}
+
+impl WxWidget for NumberEntryDialog {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.dialog_base.handle_ptr()
+    }
+}
+
+// --- NumberEntryDialogBuilder ---
+pub struct NumberEntryDialogBuilder<'a> {
+    parent: &'a dyn WxWidget,
+    message: String,
+    prompt: String,
+    caption: String,
+    value: i64,
+    min: i64,
+    max: i64,
+}
+
+impl<'a> NumberEntryDialogBuilder<'a> {
+    pub fn new(parent: &'a dyn WxWidget, message: &str, prompt: &str, caption: &str) -> Self {
+        NumberEntryDialogBuilder {
+            parent,
+            message: message.to_string(),
+            prompt: prompt.to_string(),
+            caption: caption.to_string(),
+            value: 0,
+            min: 0,
+            max: 100,
+        }
+    }
+
+    /// Sets the accepted range (inclusive). Defaults to 0..=100.
+    pub fn with_range(mut self, min: i64, max: i64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Sets the initially shown value.
+    pub fn with_value(mut self, value: i64) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn build(self) -> NumberEntryDialog {
+        let c_message = CString::new(self.message).expect("CString::new failed for message");
+        let c_prompt = CString::new(self.prompt).expect("CString::new failed for prompt");
+        let c_caption = CString::new(self.caption).expect("CString::new failed for caption");
+        let parent_ptr = self.parent.handle_ptr();
+        assert!(
+            !parent_ptr.is_null(),
+            "NumberEntryDialog requires a valid parent window pointer."
+        );
+
+        let ptr = unsafe {
+            ffi::wxd_NumberEntryDialog_Create(
+                parent_ptr,
+                c_message.as_ptr(),
+                c_prompt.as_ptr(),
+                c_caption.as_ptr(),
+                self.value,
+                self.min,
+                self.max,
+            )
+        };
+        if ptr.is_null() {
+            panic!("Failed to create wxNumberEntryDialog");
+        }
+        unsafe { NumberEntryDialog::from_ptr(ptr) }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::dialogs::Dialog;
+use crate::dialogs::text_entry_dialog::TextEntryDialogStyle;
+use crate::geometry::Point;
+use crate::id::ID_OK;
+use crate::utils::SecretString;
+use crate::window::WxWidget;
+use std::cell::Cell;
+use std::ffi::CString;
+use wxdragon_sys as ffi;
+
+// --- PasswordEntryDialog ---
+/// A dialog asking for a single password in a masked field (wxPasswordEntryDialog).
+///
+/// The value is returned as a [`SecretString`], which is zeroed on drop and never
+/// printed by `Debug`. Enter activates OK and Esc cancels.
+///
+/// # Example
+/// ```ignore
+/// let dialog = PasswordEntryDialog::builder(&frame, "Enter the key passphrase:", "Unlock").build();
+/// dialog.show_modal();
+/// if let Some(passphrase) = dialog.get_password() {
+///     unlock(passphrase.expose());
+/// }
+/// ```
+pub struct PasswordEntryDialog {
+    dialog_base: Dialog,
+    accepted: Cell<bool>,
+}
+
+impl PasswordEntryDialog {
+    /// Creates a new builder for a PasswordEntryDialog.
+    pub fn builder<'a>(parent: &'a dyn WxWidget, message: &str, caption: &str) -> PasswordEntryDialogBuilder<'a> {
+        PasswordEntryDialogBuilder::new(parent, message, caption)
+    }
+
+    fn as_ptr(&self) -> *mut ffi::wxd_TextEntryDialog_t {
+        self.dialog_base.as_ptr() as *mut ffi::wxd_TextEntryDialog_t
+    }
+
+    /// Shows the dialog modally.
+    /// Returns an integer value which is usually one of the standard dialog return codes
+    /// (e.g., ID_OK, ID_CANCEL).
+    pub fn show_modal(&self) -> i32 {
+        let result = self.dialog_base.show_modal();
+        self.accepted.set(result == ID_OK);
+        result
+    }
+
+    /// Gets the password entered by the user.
+    /// Returns `None` unless the dialog was closed with OK.
+    pub fn get_password(&self) -> Option<SecretString> {
+        if !self.accepted.get() {
+            return None;
+        }
+        let ptr = self.as_ptr();
+        Some(SecretString::read_with(|buf, len| unsafe {
+            ffi::wxd_TextEntryDialog_GetValue(ptr, buf, len)
+        }))
+    }
+}
+
+impl WxWidget for PasswordEntryDialog {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.dialog_base.handle_ptr()
+    }
+}
+
+impl Drop for PasswordEntryDialog {
+    fn drop(&mut self) {
+        if !self.handle_ptr().is_null() {
+            unsafe { ffi::wxd_Window_Destroy(self.handle_ptr()) };
+        }
+    }
+}
+
+// --- PasswordEntryDialogBuilder ---
+pub struct PasswordEntryDialogBuilder<'a> {
+    parent: &'a dyn WxWidget,
+    message: String,
+    caption: String,
+    style: TextEntryDialogStyle,
+    pos: Point,
+    max_length: usize,
+}
+
+impl<'a> PasswordEntryDialogBuilder<'a> {
+    pub fn new(parent: &'a dyn WxWidget, message: &str, caption: &str) -> Self {
+        PasswordEntryDialogBuilder {
+            parent,
+            message: message.to_string(),
+            caption: caption.to_string(),
+            style: TextEntryDialogStyle::Default,
+            pos: Point::DEFAULT_POSITION,
+            max_length: 0,
+        }
+    }
+
+    /// Sets the dialog style (OK/Cancel buttons, centring).
+    pub fn with_style(mut self, style: TextEntryDialogStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_position(mut self, pos: Point) -> Self {
+        self.pos = pos;
+        self
+    }
+
+    /// Limits the number of characters that can be entered; 0 means no limit.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    pub fn build(self) -> PasswordEntryDialog {
+        let c_message = CString::new(self.message).expect("CString::new failed for message");
+        let c_caption = CString::new(self.caption).expect("CString::new failed for caption");
+        let parent_ptr = self.parent.handle_ptr();
+
+        let ptr = unsafe {
+            ffi::wxd_PasswordEntryDialog_Create(
+                parent_ptr,
+                c_message.as_ptr(),
+                c_caption.as_ptr(),
+                self.style.bits() as ffi::wxd_Style_t,
+                self.pos.x,
+                self.pos.y,
+            )
+        };
+        if ptr.is_null() {
+            panic!("Failed to create wxPasswordEntryDialog");
+        }
+        if self.max_length > 0 {
+            unsafe { ffi::wxd_TextEntryDialog_SetMaxLength(ptr, self.max_length as _) };
+        }
+        PasswordEntryDialog {
+            dialog_base: unsafe { Dialog::from_ptr(ptr as *mut ffi::wxd_Dialog_t) },
+            accepted: Cell::new(false),
+        }
+    }
+}
@@ -23,7 +23,34 @@ widget_style_enum!(
 
 /// Wrapper for wxProgressDialog.
 /// A dialog that shows a progress bar and optional text.
-#[derive(Clone)]
+///
+/// [`update`](ProgressDialog::update) and [`pulse`](ProgressDialog::pulse) process pending
+/// events internally, as wxWidgets does, so the Cancel/Skip buttons and the rest of the UI
+/// stay responsive while the work runs on the UI thread. For long jobs, drive the updates
+/// from a `Timer` or idle handler instead of a blocking loop.
+///
+/// The dialog is destroyed when the wrapper is dropped.
+///
+/// # Example
+///
+/// ```ignore
+/// let dialog = ProgressDialog::builder(&frame, "Importing", "Reading files...", files.len() as i32)
+///     .can_abort()
+///     .can_skip()
+///     .show_remaining_time()
+///     .build();
+/// for (i, file) in files.iter().enumerate() {
+///     let (keep_going, skipped) = dialog.update(i as i32, Some(file));
+///     if !keep_going {
+///         break; // Cancel was pressed
+///     }
+///     if skipped {
+///         dialog.resume();
+///         continue;
+///     }
+///     import(file);
+/// }
+/// ```
 pub struct ProgressDialog {
     dialog_base: Dialog,
 }
@@ -35,6 +62,7 @@ pub struct ProgressDialogBuilder<'a, W: WxWidget> {
     message: String,
     maximum: i32,
     style: ProgressDialogStyle,
+    generic: bool,
 }
 
 impl ProgressDialog {
@@ -46,6 +74,7 @@ impl ProgressDialog {
             message: message.to_string(),
             maximum,
             style: ProgressDialogStyle::AutoHide | ProgressDialogStyle::AppModal,
+            generic: false,
         }
     }
 
@@ -62,30 +91,16 @@ impl ProgressDialog {
         self.dialog_base.as_ptr() as *mut ffi::wxd_ProgressDialog_t
     }
 
-    /// Updates the dialog, setting the progress bar to the given value
-    /// and, optionally, updating the message shown.
-    /// Returns `true` if the "Cancel" button has not been pressed.
-    ///
-    /// - `value`: The new value of the progress meter (between 0 and the maximum)
-    /// - `newmsg`: The new progress message to display in the dialog, or None to not change it
-    pub fn update(&self, value: i32, newmsg: Option<&str>) -> bool {
-        let c_newmsg = newmsg.map(|s| CString::new(s).expect("CString::new failed"));
-        let newmsg_ptr = c_newmsg.as_ref().map_or(ptr::null(), |cs| cs.as_ptr());
-
-        let mut skip = false;
-
-        unsafe { ffi::wxd_ProgressDialog_Update(self.as_ptr(), value, newmsg_ptr, &mut skip) }
-    }
-
     /// Updates the dialog, setting the progress bar to the given value
     /// and, optionally, updating the message shown.
     /// Returns a tuple with:
     /// - `continue`: `true` if the "Cancel" button has not been pressed
-    /// - `skipped`: `true` if the "Skip" button has been pressed
+    /// - `skipped`: `true` if the "Skip" button has been pressed; call [`resume`](ProgressDialog::resume)
+    ///   to clear it
     ///
     /// - `value`: The new value of the progress meter (between 0 and the maximum)
     /// - `newmsg`: The new progress message to display in the dialog, or None to not change it
-    pub fn update_with_skip(&self, value: i32, newmsg: Option<&str>) -> (bool, bool) {
+    pub fn update(&self, value: i32, newmsg: Option<&str>) -> (bool, bool) {
         let c_newmsg = newmsg.map(|s| CString::new(s).expect("CString::new failed"));
         let newmsg_ptr = c_newmsg.as_ref().map_or(ptr::null(), |cs| cs.as_ptr());
 
@@ -95,17 +110,10 @@ impl ProgressDialog {
         (result, skip)
     }
 
-    /// Switches the progress dialog to indeterminate mode and makes the gauge control
-    /// run back and forth.
-    ///
-    /// - `newmsg`: The new progress message to display in the dialog, or None to not change it
-    pub fn pulse(&self, newmsg: Option<&str>) -> bool {
-        let c_newmsg = newmsg.map(|s| CString::new(s).expect("CString::new failed"));
-        let newmsg_ptr = c_newmsg.as_ref().map_or(ptr::null(), |cs| cs.as_ptr());
-
-        let mut skip = false;
-
-        unsafe { ffi::wxd_ProgressDialog_Pulse(self.as_ptr(), newmsg_ptr, &mut skip) }
+    /// Same as [`update`](ProgressDialog::update).
+    #[deprecated(note = "`update` now returns the skip flag as well")]
+    pub fn update_with_skip(&self, value: i32, newmsg: Option<&str>) -> (bool, bool) {
+        self.update(value, newmsg)
     }
 
     /// Switches the progress dialog to indeterminate mode and makes the gauge control
@@ -115,7 +123,7 @@ impl ProgressDialog {
     /// - `skipped`: `true` if the "Skip" button has been pressed
     ///
     /// - `newmsg`: The new progress message to display in the dialog, or None to not change it
-    pub fn pulse_with_skip(&self, newmsg: Option<&str>) -> (bool, bool) {
+    pub fn pulse(&self, newmsg: Option<&str>) -> (bool, bool) {
         let c_newmsg = newmsg.map(|s| CString::new(s).expect("CString::new failed"));
         let newmsg_ptr = c_newmsg.as_ref().map_or(ptr::null(), |cs| cs.as_ptr());
 
@@ -125,7 +133,14 @@ impl ProgressDialog {
         (result, skip)
     }
 
-    /// Resumes dialog after calling update(value, "", skip) or Pulse("", skip) if `skip` was true.
+    /// Same as [`pulse`](ProgressDialog::pulse).
+    #[deprecated(note = "`pulse` now returns the skip flag as well")]
+    pub fn pulse_with_skip(&self, newmsg: Option<&str>) -> (bool, bool) {
+        self.pulse(newmsg)
+    }
+
+    /// Resumes the dialog after the user cancelled or skipped, re-enabling the
+    /// buttons so the operation can continue.
     pub fn resume(&self) {
         unsafe {
             ffi::wxd_ProgressDialog_Resume(self.as_ptr());
@@ -196,6 +211,15 @@ impl<'a, W: WxWidget> ProgressDialogBuilder<'a, W> {
         self.add_style(ProgressDialogStyle::Smooth)
     }
 
+    /// Use wxGenericProgressDialog instead of the native dialog.
+    ///
+    /// Only makes a difference on Windows, where the native dialog runs in its
+    /// own thread and ignores some styles; elsewhere the generic dialog is always used.
+    pub fn with_generic(mut self, generic: bool) -> Self {
+        self.generic = generic;
+        self
+    }
+
     /// Build the ProgressDialog
     pub fn build(self) -> ProgressDialog {
         let c_title = CString::new(self.title).expect("CString::new failed for title");
@@ -206,8 +230,13 @@ impl<'a, W: WxWidget> ProgressDialogBuilder<'a, W> {
             "ProgressDialog requires a valid parent window pointer."
         );
 
+        let create = if self.generic {
+            ffi::wxd_ProgressDialog_CreateGeneric
+        } else {
+            ffi::wxd_ProgressDialog_Create
+        };
         let ptr = unsafe {
-            ffi::wxd_ProgressDialog_Create(
+            create(
                 parent_ptr,
                 c_title.as_ptr(),
                 c_message.as_ptr(),
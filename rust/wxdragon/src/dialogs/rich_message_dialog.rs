@@ -0,0 +1,148 @@
+use crate::dialogs::Dialog;
+use crate::dialogs::message_dialog::MessageDialogStyle;
+use crate::window::WxWidget;
+use std::ffi::CString;
+use wxdragon_sys as ffi;
+
+// --- RichMessageDialog ---
+/// A message dialog with an optional checkbox (e.g. "Don't show this message again")
+/// and expandable detailed text (wxRichMessageDialog).
+///
+/// It takes the same [`MessageDialogStyle`] flags as [`MessageDialog`](crate::dialogs::message_dialog::MessageDialog).
+/// The native task dialog is used where available (Windows); other platforms get
+/// the generic implementation, which supports the same features.
+///
+/// The dialog is destroyed when the wrapper is dropped.
+///
+/// # Example
+/// ```ignore
+/// let dialog = RichMessageDialog::builder(&frame, "Delete the selected node?", "Confirm")
+///     .with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconQuestion)
+///     .with_checkbox("Don't ask me again", false)
+///     .with_detailed_text("The node is removed from the list and its settings are lost.")
+///     .build();
+/// let answer = dialog.show_modal();
+/// let dont_ask_again = dialog.is_checkbox_checked();
+/// ```
+pub struct RichMessageDialog {
+    dialog_base: Dialog,
+}
+
+impl RichMessageDialog {
+    /// Creates a new RichMessageDialog from a raw pointer.
+    /// # Safety
+    /// The pointer must be a valid pointer to a wxRichMessageDialog.
+    pub(crate) unsafe fn from_ptr(ptr: *mut ffi::wxd_RichMessageDialog_t) -> Self {
+        RichMessageDialog {
+            dialog_base: unsafe { Dialog::from_ptr(ptr as *mut ffi::wxd_Dialog_t) },
+        }
+    }
+
+    pub fn builder<'a>(parent: &'a dyn WxWidget, message: &str, caption: &str) -> RichMessageDialogBuilder<'a> {
+        RichMessageDialogBuilder::new(parent, message, caption)
+    }
+
+    fn as_ptr(&self) -> *mut ffi::wxd_RichMessageDialog_t {
+        self.dialog_base.as_ptr() as *mut ffi::wxd_RichMessageDialog_t
+    }
+
+    /// Shows the dialog modally.
+    /// Returns an integer value which is usually one of the standard dialog return codes
+    /// (e.g., ID_OK, ID_CANCEL, ID_YES, ID_NO).
+    pub fn show_modal(&self) -> i32 {
+        self.dialog_base.show_modal()
+    }
+
+    /// Returns whether the checkbox was checked when the dialog was closed.
+    /// Always `false` if no checkbox was requested.
+    pub fn is_checkbox_checked(&self) -> bool {
+        unsafe { ffi::wxd_RichMessageDialog_IsCheckBoxChecked(self.as_ptr()) }
+    }
+}
+
+impl WxWidget for RichMessageDialog {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.dialog_base.handle_ptr()
+    }
+}
+
+impl Drop for RichMessageDialog {
+    fn drop(&mut self) {
+        if !self.handle_ptr().is_null() {
+            unsafe { ffi::wxd_Window_Destroy(self.handle_ptr()) };
+        }
+    }
+}
+
+// --- RichMessageDialogBuilder ---
+pub struct RichMessageDialogBuilder<'a> {
+    parent: &'a dyn WxWidget,
+    message: String,
+    caption: String,
+    style: MessageDialogStyle,
+    checkbox: Option<(String, bool)>,
+    detailed_text: Option<String>,
+}
+
+impl<'a> RichMessageDialogBuilder<'a> {
+    pub fn new(parent: &'a dyn WxWidget, message: &str, caption: &str) -> Self {
+        RichMessageDialogBuilder {
+            parent,
+            message: message.to_string(),
+            caption: caption.to_string(),
+            style: MessageDialogStyle::OK,
+            checkbox: None,
+            detailed_text: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: MessageDialogStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Shows a checkbox with the given label below the message, initially `checked` or not.
+    pub fn with_checkbox(mut self, label: &str, checked: bool) -> Self {
+        self.checkbox = Some((label.to_string(), checked));
+        self
+    }
+
+    /// Adds text that is hidden behind a "Details" expander.
+    pub fn with_detailed_text(mut self, text: &str) -> Self {
+        self.detailed_text = Some(text.to_string());
+        self
+    }
+
+    pub fn build(self) -> RichMessageDialog {
+        let c_message = CString::new(self.message).expect("CString::new failed for message");
+        let c_caption = CString::new(self.caption).expect("CString::new failed for caption");
+        let parent_ptr = self.parent.handle_ptr();
+        assert!(
+            !parent_ptr.is_null(),
+            "RichMessageDialog requires a valid parent window pointer."
+        );
+
+        let ptr = unsafe {
+            ffi::wxd_RichMessageDialog_Create(
+                parent_ptr,
+                c_message.as_ptr(),
+                c_caption.as_ptr(),
+                self.style.bits() as ffi::wxd_Style_t,
+            )
+        };
+        if ptr.is_null() {
+            panic!("Failed to create wxRichMessageDialog");
+        }
+
+        if let Some((label, checked)) = self.checkbox {
+            let c_label = CString::new(label).expect("CString::new failed for checkbox label");
+            unsafe { ffi::wxd_RichMessageDialog_ShowCheckBox(ptr, c_label.as_ptr(), checked) };
+        }
+        if let Some(text) = self.detailed_text {
+            let c_text = CString::new(text).expect("CString::new failed for detailed text");
+            unsafe { ffi::wxd_RichMessageDialog_ShowDetailedText(ptr, c_text.as_ptr()) };
+        }
+
+        unsafe { RichMessageDialog::from_ptr(ptr) }
+    }
+}
@@ -14,6 +14,7 @@ crate::widget_style_enum!(
         Cancel: ffi::WXD_CANCEL, "Style flag for Cancel button.",
         Centre: ffi::WXD_CENTRE, "Style flag to center the dialog.",
         Password: ffi::WXD_TE_PASSWORD, "Style flag for password text entry.",
+        Multiline: ffi::WXD_TE_MULTILINE, "Style flag for a multi-line text entry.",
         ProcessEnter: ffi::WXD_TE_PROCESS_ENTER, "Style flag to process Enter key in the text control."
     },
     default_variant: Default
@@ -103,6 +104,7 @@ pub struct TextEntryDialogBuilder<'a> {
     style: TextEntryDialogStyle,
     pos: Point,
     size: Size, // Often unused, but kept for consistency
+    max_length: usize,
 }
 
 impl<'a> TextEntryDialogBuilder<'a> {
@@ -115,6 +117,7 @@ impl<'a> TextEntryDialogBuilder<'a> {
             style: TextEntryDialogStyle::Default,
             pos: Point::DEFAULT_POSITION,
             size: Size::DEFAULT_SIZE,
+            max_length: 0,
         }
     }
 
@@ -134,6 +137,23 @@ impl<'a> TextEntryDialogBuilder<'a> {
         self
     }
 
+    /// Masks the entered text, same as [`password`](Self::password).
+    pub fn with_password_style(self) -> Self {
+        self.password()
+    }
+
+    /// Lets the user enter several lines of text.
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.style.set(TextEntryDialogStyle::Multiline, multiline);
+        self
+    }
+
+    /// Limits the text to `max_length` characters; 0 means no limit.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
     pub fn with_pos(mut self, pos: Point) -> Self {
         self.pos = pos;
         self
@@ -170,6 +190,9 @@ impl<'a> TextEntryDialogBuilder<'a> {
         if ptr.is_null() {
             panic!("Failed to create wxTextEntryDialog");
         }
+        if self.max_length > 0 {
+            unsafe { ffi::wxd_TextEntryDialog_SetMaxLength(ptr, self.max_length as _) };
+        }
         unsafe { TextEntryDialog::from_ptr(ptr) }
     }
 }
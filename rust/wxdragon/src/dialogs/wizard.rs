@@ -0,0 +1,471 @@
+//! Safe wrapper for wxWizard and its pages.
+
+use crate::bitmap::Bitmap;
+use crate::dialogs::{Dialog, DialogStyle};
+use crate::event::{Event, EventType, WindowEvents, WxEvtHandler};
+use crate::geometry::{Point, Size};
+use crate::id::{ID_ANY, Id};
+use crate::window::{WindowHandle, WxWidget};
+use std::ffi::{CString, c_void};
+use wxdragon_sys as ffi;
+
+/// Events emitted by [`Wizard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WizardEvent {
+    /// The page is about to change; veto to stay on the current page (e.g. failed validation).
+    PageChanging,
+    /// The page has changed.
+    PageChanged,
+    /// The user is cancelling the wizard; veto to keep it open.
+    Cancel,
+    /// The user pressed Finish on the last page.
+    Finished,
+}
+
+/// Event data for [`Wizard`] events.
+#[derive(Debug)]
+pub struct WizardEventData {
+    event: Event,
+}
+
+crate::impl_event_base!(WizardEventData => event);
+
+impl WizardEventData {
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Returns `true` when moving forward (Next/Finish), `false` when going back.
+    ///
+    /// For `Cancel` and `Finished` this is meaningless.
+    pub fn get_direction(&self) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_WizardEvent_GetDirection(self.event.0) }
+    }
+
+    /// Returns the page the event refers to: the page being left for `PageChanging`,
+    /// the newly shown page for `PageChanged`, and the current page otherwise.
+    pub fn get_page(&self) -> Option<WizardPage> {
+        if self.event.is_null() {
+            return None;
+        }
+        let ptr = unsafe { ffi::wxd_WizardEvent_GetPage(self.event.0) };
+        unsafe { WizardPage::from_ptr_opt(ptr) }
+    }
+
+    /// Skip this event (allowing the default processing to occur)
+    pub fn skip(&self, skip: bool) {
+        self.event.skip(skip);
+    }
+
+    /// Prevents the page change (`PageChanging`) or the cancellation (`Cancel`).
+    pub fn veto(&self) {
+        self.event.veto();
+    }
+
+    /// Checks if the event has been vetoed
+    pub fn is_vetoed(&self) -> bool {
+        self.event.is_vetoed()
+    }
+}
+
+/// Represents a wxWizard, a dialog presenting a sequence of [`WizardPage`]s with
+/// Back/Next/Finish buttons.
+///
+/// Like other dialogs, a wizard is not destroyed automatically: call `destroy()`
+/// once [`run_wizard`](Wizard::run_wizard) has returned.
+///
+/// # Example
+/// ```ignore
+/// let wizard = Wizard::builder(&frame, "First run", None).build();
+/// let welcome = WizardPage::new(&wizard);
+/// let account = WizardPage::new(&wizard);
+/// // ... add controls and sizers to the pages ...
+/// wizard.chain(&[&welcome, &account]);
+///
+/// wizard.on_page_changing(move |event| {
+///     if event.get_direction() && event.get_page() == Some(account) && name.get_value().is_empty() {
+///         event.veto();
+///     }
+/// });
+///
+/// let completed = wizard.run_wizard(&welcome);
+/// wizard.destroy();
+/// ```
+#[derive(Clone, Copy)]
+pub struct Wizard {
+    /// Safe handle to the underlying wxWizard - automatically invalidated on destroy
+    handle: WindowHandle,
+}
+
+impl Wizard {
+    /// Creates a new builder for a Wizard with an optional bitmap shown next to the pages.
+    pub fn builder<'a>(parent: &'a dyn WxWidget, title: &str, bitmap: Option<&'a Bitmap>) -> WizardBuilder<'a> {
+        WizardBuilder::new(parent, title, bitmap)
+    }
+
+    pub(crate) unsafe fn from_ptr(ptr: *mut ffi::wxd_Wizard_t) -> Self {
+        Wizard {
+            handle: WindowHandle::new(ptr as *mut ffi::wxd_Window_t),
+        }
+    }
+
+    /// Helper to get raw wizard pointer, returns null if the wizard has been destroyed
+    #[inline]
+    fn wizard_ptr(&self) -> *mut ffi::wxd_Wizard_t {
+        self.handle
+            .get_ptr()
+            .map(|p| p as *mut ffi::wxd_Wizard_t)
+            .unwrap_or(std::ptr::null_mut())
+    }
+
+    /// Returns the wizard as a plain [`Dialog`], e.g. to call `end_modal`.
+    pub fn as_dialog(&self) -> Dialog {
+        unsafe { Dialog::from_ptr(self.wizard_ptr() as *mut ffi::wxd_Dialog_t) }
+    }
+
+    /// Links the pages in order (each page's next/previous page) and adds them all
+    /// to the page area so the wizard is sized to fit the largest one.
+    ///
+    /// Pages whose successor is decided at runtime can still override the static link
+    /// with [`WizardPage::set_next`].
+    pub fn chain(&self, pages: &[&WizardPage]) {
+        for pair in pages.windows(2) {
+            pair[0].set_next_page(Some(pair[1]));
+            pair[1].set_prev_page(Some(pair[0]));
+        }
+        for page in pages {
+            self.add_page(page);
+        }
+    }
+
+    /// Adds a page to the page area so that the wizard is big enough to show it.
+    ///
+    /// Pages passed to [`chain`](Wizard::chain) are added automatically; use this for
+    /// pages only reachable through [`WizardPage::set_next`].
+    pub fn add_page(&self, page: &WizardPage) {
+        let ptr = self.wizard_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Wizard_AddPageToSizer(ptr, page.as_ptr()) }
+    }
+
+    /// Shows the wizard modally starting at `first_page`.
+    ///
+    /// Returns `true` if the user finished the wizard and `false` if it was cancelled.
+    pub fn run_wizard(&self, first_page: &WizardPage) -> bool {
+        let ptr = self.wizard_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Wizard_RunWizard(ptr, first_page.as_ptr()) }
+    }
+
+    /// Returns the page currently shown, or `None` if the wizard is not running.
+    pub fn get_current_page(&self) -> Option<WizardPage> {
+        let ptr = self.wizard_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        unsafe { WizardPage::from_ptr_opt(ffi::wxd_Wizard_GetCurrentPage(ptr)) }
+    }
+
+    /// Returns `true` if `page` has a next page, i.e. shows "Next" rather than "Finish".
+    pub fn has_next_page(&self, page: &WizardPage) -> bool {
+        let ptr = self.wizard_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Wizard_HasNextPage(ptr, page.as_ptr()) }
+    }
+
+    /// Returns `true` if `page` has a previous page, i.e. "Back" is enabled.
+    pub fn has_prev_page(&self, page: &WizardPage) -> bool {
+        let ptr = self.wizard_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Wizard_HasPrevPage(ptr, page.as_ptr()) }
+    }
+
+    /// Sets the minimal size of the page area.
+    pub fn set_page_size(&self, size: Size) {
+        let ptr = self.wizard_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Wizard_SetPageSize(ptr, size.width, size.height) }
+    }
+
+    /// Sets the border around the page area (5 pixels by default).
+    pub fn set_border(&self, border: i32) {
+        let ptr = self.wizard_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Wizard_SetBorder(ptr, border) }
+    }
+}
+
+impl WxWidget for Wizard {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.handle.is_valid()
+    }
+}
+
+impl WxEvtHandler for Wizard {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut()) as *mut ffi::wxd_EvtHandler_t
+    }
+}
+
+impl WindowEvents for Wizard {}
+
+crate::implement_widget_local_event_handlers!(
+    Wizard,
+    WizardEvent,
+    WizardEventData,
+    PageChanging => page_changing, EventType::WIZARD_PAGE_CHANGING,
+    PageChanged => page_changed, EventType::WIZARD_PAGE_CHANGED,
+    Cancel => cancel, EventType::WIZARD_CANCEL,
+    Finished => finished, EventType::WIZARD_FINISHED
+);
+
+// --- WizardBuilder ---
+/// Builder for [`Wizard`].
+pub struct WizardBuilder<'a> {
+    parent: &'a dyn WxWidget,
+    title: String,
+    bitmap: Option<&'a Bitmap>,
+    id: Id,
+    pos: Point,
+    style: DialogStyle,
+}
+
+impl<'a> WizardBuilder<'a> {
+    pub fn new(parent: &'a dyn WxWidget, title: &str, bitmap: Option<&'a Bitmap>) -> Self {
+        WizardBuilder {
+            parent,
+            title: title.to_string(),
+            bitmap,
+            id: ID_ANY as Id,
+            pos: Point::DEFAULT_POSITION,
+            style: DialogStyle::DefaultDialogStyle,
+        }
+    }
+
+    /// Sets the window identifier.
+    pub fn with_id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the wizard position.
+    pub fn with_pos(mut self, pos: Point) -> Self {
+        self.pos = pos;
+        self
+    }
+
+    /// Sets the dialog style.
+    pub fn with_style(mut self, style: DialogStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn build(self) -> Wizard {
+        let c_title = CString::new(self.title).expect("CString::new failed for title");
+        let bitmap_ptr = self.bitmap.map_or(std::ptr::null(), |b| b.as_const_ptr());
+        let ptr = unsafe {
+            ffi::wxd_Wizard_Create(
+                self.parent.handle_ptr(),
+                self.id,
+                c_title.as_ptr(),
+                bitmap_ptr,
+                self.pos.x,
+                self.pos.y,
+                self.style.bits() as ffi::wxd_Style_t,
+            )
+        };
+        if ptr.is_null() {
+            panic!("Failed to create wxWizard");
+        }
+        unsafe { Wizard::from_ptr(ptr) }
+    }
+}
+
+// --- WizardPage ---
+/// A page of a [`Wizard`] (wxWizardPageSimple).
+///
+/// A page is an ordinary window: create controls with the page as parent and
+/// lay them out with `set_sizer`. The page order is given by
+/// [`Wizard::chain`] or by [`set_next_page`](WizardPage::set_next_page), and can be
+/// decided at runtime with [`set_next`](WizardPage::set_next).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct WizardPage {
+    /// Safe handle to the underlying wxWizardPage - automatically invalidated on destroy
+    handle: WindowHandle,
+}
+
+impl std::fmt::Debug for WizardPage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WizardPage").field("ptr", &self.handle_ptr()).finish()
+    }
+}
+
+/// Callback deciding a page link at runtime.
+type WizardPageLink = Box<dyn Fn() -> Option<WizardPage> + 'static>;
+
+impl WizardPage {
+    /// Creates an empty page belonging to `wizard`.
+    pub fn new(wizard: &Wizard) -> Self {
+        let ptr = unsafe { ffi::wxd_WizardPage_Create(wizard.wizard_ptr()) };
+        if ptr.is_null() {
+            panic!("Failed to create wxWizardPageSimple");
+        }
+        WizardPage {
+            handle: WindowHandle::new(ptr as *mut ffi::wxd_Window_t),
+        }
+    }
+
+    unsafe fn from_ptr_opt(ptr: *mut ffi::wxd_WizardPage_t) -> Option<Self> {
+        if ptr.is_null() {
+            return None;
+        }
+        let window = ptr as *mut ffi::wxd_Window_t;
+        Some(WizardPage {
+            handle: WindowHandle::from_ptr(window).unwrap_or_else(|| WindowHandle::new(window)),
+        })
+    }
+
+    fn as_ptr(&self) -> *mut ffi::wxd_WizardPage_t {
+        self.handle
+            .get_ptr()
+            .map(|p| p as *mut ffi::wxd_WizardPage_t)
+            .unwrap_or(std::ptr::null_mut())
+    }
+
+    /// Sets the page shown when the user presses "Next"; `None` makes this the last page.
+    pub fn set_next_page(&self, next: Option<&WizardPage>) {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let next_ptr = next.map_or(std::ptr::null_mut(), |p| p.as_ptr());
+        unsafe { ffi::wxd_WizardPage_SetNext(ptr, next_ptr) }
+    }
+
+    /// Sets the page shown when the user presses "Back"; `None` makes this the first page.
+    pub fn set_prev_page(&self, prev: Option<&WizardPage>) {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let prev_ptr = prev.map_or(std::ptr::null_mut(), |p| p.as_ptr());
+        unsafe { ffi::wxd_WizardPage_SetPrev(ptr, prev_ptr) }
+    }
+
+    /// Returns the page following this one, taking [`set_next`](WizardPage::set_next) into account.
+    pub fn get_next(&self) -> Option<WizardPage> {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        unsafe { Self::from_ptr_opt(ffi::wxd_WizardPage_GetNext(ptr)) }
+    }
+
+    /// Returns the page preceding this one, taking [`set_prev`](WizardPage::set_prev) into account.
+    pub fn get_prev(&self) -> Option<WizardPage> {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        unsafe { Self::from_ptr_opt(ffi::wxd_WizardPage_GetPrev(ptr)) }
+    }
+
+    /// Decides the next page at runtime, overriding the static link.
+    ///
+    /// wxWidgets queries the callback whenever it needs the next page: to label the
+    /// Next/Finish button, when the user presses Next, and while sizing the wizard.
+    /// It must therefore be cheap and only depend on state (e.g. a checkbox value),
+    /// not on how many times it has been called. Pages only reachable this way should
+    /// be passed to [`Wizard::add_page`] so the wizard is big enough for them.
+    pub fn set_next<F>(&self, callback: F)
+    where
+        F: Fn() -> Option<WizardPage> + 'static,
+    {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let user_data = Box::into_raw(Box::new(Box::new(callback) as WizardPageLink)) as *mut c_void;
+        unsafe { ffi::wxd_WizardPage_SetNextCallback(ptr, Some(wizard_page_link_trampoline), user_data) }
+    }
+
+    /// Decides the previous page at runtime, overriding the static link.
+    ///
+    /// The same rules as for [`set_next`](WizardPage::set_next) apply.
+    pub fn set_prev<F>(&self, callback: F)
+    where
+        F: Fn() -> Option<WizardPage> + 'static,
+    {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let user_data = Box::into_raw(Box::new(Box::new(callback) as WizardPageLink)) as *mut c_void;
+        unsafe { ffi::wxd_WizardPage_SetPrevCallback(ptr, Some(wizard_page_link_trampoline), user_data) }
+    }
+}
+
+impl WxWidget for WizardPage {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.handle.is_valid()
+    }
+}
+
+impl WxEvtHandler for WizardPage {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut()) as *mut ffi::wxd_EvtHandler_t
+    }
+}
+
+impl WindowEvents for WizardPage {}
+
+/// Called by C++ when a page needs its next/previous page.
+unsafe extern "C" fn wizard_page_link_trampoline(user_data: *mut c_void) -> *mut ffi::wxd_WizardPage_t {
+    if user_data.is_null() {
+        return std::ptr::null_mut();
+    }
+    let callback = unsafe { &*(user_data as *const WizardPageLink) };
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback())) {
+        Ok(Some(page)) => page.as_ptr(),
+        Ok(None) => std::ptr::null_mut(),
+        Err(_) => {
+            log::error!("Panic in wizard page link callback");
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Function called by C++ to drop a page link callback.
+///
+/// # Safety
+/// `user_data` must be a pointer created by [`WizardPage::set_next`] or
+/// [`WizardPage::set_prev`] and must not be used after this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wxd_Drop_Rust_WizardPageLink(user_data: *mut c_void) {
+    if !user_data.is_null() {
+        let _ = unsafe { Box::from_raw(user_data as *mut WizardPageLink) };
+    }
+}
@@ -0,0 +1,152 @@
+//! Starting drags from a window with the data to offer.
+
+use super::DragResult;
+use crate::data_object::{DataObject, DataObjectComposite};
+use crate::prelude::WxWidget;
+use wxdragon_sys as ffi;
+
+/// Drags data out of a window, to another place in the application or to another
+/// application.
+///
+/// Set the data to offer with [`set_text`](Self::set_text), [`set_files`](Self::set_files)
+/// and [`set_data`](Self::set_data); each kind set is offered at once, so a drag can carry
+/// an application-private format for in-app drops and text for everything else. The same
+/// [`DataObjectComposite`] put on the clipboard can also be dragged with
+/// [`set_data_object`](Self::set_data_object). Then call
+/// [`do_drag_drop`](Self::do_drag_drop) from the handler of the mouse press or begin-drag
+/// event that started the drag. For a [`DataViewCtrl`](crate::DataViewCtrl), hand the
+/// source to [`DataViewEvent::set_drag_source`](crate::DataViewEvent::set_drag_source)
+/// in its begin-drag handler instead, so the control runs the drag.
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// let list = ListCtrl::builder(&frame).build();
+/// list.on_begin_drag(move |event| {
+///     let index = event.get_item_index() as i64;
+///     let mut source = DragSource::new(&list);
+///     source.set_text(&list.get_item_text(index, 0));
+///     if source.do_drag_drop(true) == DragResult::Move {
+///         list.delete_item(index);
+///     }
+/// });
+/// ```
+pub struct DragSource {
+    window: *mut ffi::wxd_Window_t,
+    text: Option<String>,
+    files: Option<Vec<String>>,
+    data: Vec<(String, Vec<u8>)>,
+    data_object: Option<DataObjectComposite>,
+}
+
+impl DragSource {
+    /// Creates a drag source for `window` offering no data yet.
+    pub fn new(window: &dyn WxWidget) -> Self {
+        Self {
+            window: window.handle_ptr(),
+            text: None,
+            files: None,
+            data: Vec::new(),
+            data_object: None,
+        }
+    }
+
+    /// Offers `text`, replacing text set before.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+
+    /// Offers a list of file paths, replacing files set before.
+    pub fn set_files(&mut self, files: &[&str]) {
+        self.files = Some(files.iter().map(|file| file.to_string()).collect());
+    }
+
+    /// Offers `data` in the custom format `format_name`, e.g. `"application/x-myapp-node"`,
+    /// replacing data set before for that format. Drop targets read it with
+    /// [`set_data_drop_target`](crate::window::WxWidget::set_data_drop_target).
+    pub fn set_data(&mut self, format_name: &str, data: &[u8]) {
+        if format_name.is_empty() {
+            log::warn!("Ignoring drag data with an empty format name");
+            return;
+        }
+        match self.data.iter_mut().find(|(format, _)| format == format_name) {
+            Some((_, existing)) => *existing = data.to_vec(),
+            None => self.data.push((format_name.to_string(), data.to_vec())),
+        }
+    }
+
+    /// Offers the formats of `data`, e.g. the same data a copy command puts on the
+    /// clipboard, instead of anything set with the other setters.
+    pub fn set_data_object(&mut self, data: DataObjectComposite) {
+        self.data_object = Some(data);
+    }
+
+    /// Drags the data until the user drops or cancels it, and returns how the drop ended.
+    ///
+    /// `allow_move` lets the target move the data, in which case the source should remove
+    /// it after a [`DragResult::Move`]. [`DragResult::Copy`] or `Move` mean the target took
+    /// the data, [`DragResult::None`] that it refused it and [`DragResult::Cancel`] that
+    /// the user cancelled the drag, e.g. with Escape. Without any data nothing is dragged
+    /// and `None` is returned.
+    ///
+    /// The drag runs a nested event loop, so the rest of the application stays responsive
+    /// while it lasts. It must be called on the main thread, from the handler of the
+    /// event that started the drag; a mouse capture still held by then is released.
+    pub fn do_drag_drop(&self, allow_move: bool) -> DragResult {
+        if self.window.is_null() {
+            return DragResult::Error;
+        }
+        let built = if self.data_object.is_none() {
+            self.build_data_object()
+        } else {
+            None
+        };
+        let Some(data) = self.data_object.as_ref().or(built.as_ref()) else {
+            return DragResult::None;
+        };
+
+        let source = unsafe { ffi::wxd_DropSource_Create(self.window) };
+        if source.is_null() {
+            return DragResult::Error;
+        }
+        let result = unsafe {
+            ffi::wxd_DropSource_SetData(source, data.as_data_object_ptr());
+            let result = ffi::wxd_DropSource_DoDragDrop(source, allow_move);
+            ffi::wxd_DropSource_Destroy(source);
+            result
+        };
+        DragResult::from(result)
+    }
+
+    /// Converts the source into the data object to drag, or `None` if it offers nothing.
+    pub(crate) fn into_data_object(self) -> Option<DataObjectComposite> {
+        match self.data_object {
+            Some(data) => Some(data),
+            None => self.build_data_object(),
+        }
+    }
+
+    /// Builds a data object offering everything set, or `None` if nothing was.
+    ///
+    /// Custom formats are preferred, as a target understanding one is most likely part of
+    /// this application, then files and then the text nearly every target accepts.
+    fn build_data_object(&self) -> Option<DataObjectComposite> {
+        if self.text.is_none() && self.files.is_none() && self.data.is_empty() {
+            return None;
+        }
+
+        let mut builder = DataObjectComposite::builder();
+        for (format, data) in &self.data {
+            builder = builder.with_data(format, data);
+        }
+        if let Some(files) = &self.files {
+            let files: Vec<&str> = files.iter().map(String::as_str).collect();
+            builder = builder.with_files(&files);
+        }
+        if let Some(text) = &self.text {
+            builder = builder.with_text(text);
+        }
+        Some(builder.build())
+    }
+}
@@ -1,9 +1,10 @@
 //! Target for drop operations.
 
 use crate::dnd::DragResult;
+use crate::geometry::Point;
 use crate::prelude::WxWidget;
 use std::boxed::Box;
-use std::ffi::{CStr, c_void};
+use std::ffi::{CStr, CString, c_void};
 use std::os::raw::c_char;
 use wxdragon_sys as ffi;
 
@@ -503,11 +504,16 @@ extern "C" fn file_on_drop_files_trampoline(
         return false;
     }
 
-    // Extract filenames from wxArrayString
-    let mut filenames = Vec::<String>::new();
+    let filenames = filenames_from_array(filenames_ptr);
+    let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
+
+    (callbacks.on_drop_files)(filenames, x, y)
+}
 
+/// Extracts the file names of a wxArrayString passed to a drop callback.
+fn filenames_from_array(filenames_ptr: *const ffi::wxd_ArrayString_t) -> Vec<String> {
     let count = unsafe { ffi::wxd_ArrayString_GetCount(filenames_ptr) };
-    filenames.reserve(count as usize);
+    let mut filenames = Vec::<String>::with_capacity(count.max(0) as usize);
 
     for i in 0..count {
         let mut buffer = vec![0; 2048]; // Buffer for path
@@ -518,10 +524,146 @@ extern "C" fn file_on_drop_files_trampoline(
             filenames.push(s);
         }
     }
+    filenames
+}
 
-    let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
+// --- Window drop handlers ---
+//
+// Files, text and custom formats registered on a window share one composite drop target
+// on the C++ side, so a window can accept several kinds of data at once. Each handler
+// owns its closure, which the C++ side frees when it is replaced or the window is destroyed.
+
+type WindowDropFilesHandler = Box<dyn Fn(Point, Vec<String>) -> bool + 'static>;
+type WindowDropTextHandler = Box<dyn Fn(Point, String) -> bool + 'static>;
+type WindowDropDataHandler = Box<dyn Fn(Point, Vec<u8>) -> bool + 'static>;
+type WindowDragOverHandler = Box<dyn Fn(Point) -> DragResult + 'static>;
+
+/// Registers `on_drop` for files dropped on `window`.
+pub(crate) fn set_window_drop_files_handler<F>(window: *mut ffi::wxd_Window_t, on_drop: F)
+where
+    F: Fn(Point, Vec<String>) -> bool + 'static,
+{
+    let handler: Box<WindowDropFilesHandler> = Box::new(Box::new(on_drop));
+    let user_data = Box::into_raw(handler) as *mut c_void;
+    unsafe {
+        ffi::wxd_Window_SetDropFilesHandler(
+            window,
+            Some(window_drop_files_trampoline),
+            user_data,
+            Some(free_window_handler::<WindowDropFilesHandler>),
+        );
+    }
+}
 
-    (callbacks.on_drop_files)(filenames, x, y)
+/// Registers `on_drop` for text dropped on `window`.
+pub(crate) fn set_window_drop_text_handler<F>(window: *mut ffi::wxd_Window_t, on_drop: F)
+where
+    F: Fn(Point, String) -> bool + 'static,
+{
+    let handler: Box<WindowDropTextHandler> = Box::new(Box::new(on_drop));
+    let user_data = Box::into_raw(handler) as *mut c_void;
+    unsafe {
+        ffi::wxd_Window_SetDropTextHandler(
+            window,
+            Some(window_drop_text_trampoline),
+            user_data,
+            Some(free_window_handler::<WindowDropTextHandler>),
+        );
+    }
+}
+
+/// Registers `on_drop` for data of the custom format `format_name` dropped on `window`.
+pub(crate) fn set_window_drop_data_handler<F>(window: *mut ffi::wxd_Window_t, format_name: &str, on_drop: F)
+where
+    F: Fn(Point, Vec<u8>) -> bool + 'static,
+{
+    let Ok(c_format) = CString::new(format_name) else {
+        log::warn!("Invalid drop format name {format_name:?}");
+        return;
+    };
+    let handler: Box<WindowDropDataHandler> = Box::new(Box::new(on_drop));
+    let user_data = Box::into_raw(handler) as *mut c_void;
+    unsafe {
+        ffi::wxd_Window_SetDropDataHandler(
+            window,
+            c_format.as_ptr(),
+            Some(window_drop_data_trampoline),
+            user_data,
+            Some(free_window_handler::<WindowDropDataHandler>),
+        );
+    }
+}
+
+/// Registers `on_drag_over` to decide the drag result while data is dragged over `window`.
+pub(crate) fn set_window_drag_over_handler<F>(window: *mut ffi::wxd_Window_t, on_drag_over: F)
+where
+    F: Fn(Point) -> DragResult + 'static,
+{
+    let handler: Box<WindowDragOverHandler> = Box::new(Box::new(on_drag_over));
+    let user_data = Box::into_raw(handler) as *mut c_void;
+    unsafe {
+        ffi::wxd_Window_SetDragOverHandler(
+            window,
+            Some(window_drag_over_trampoline),
+            user_data,
+            Some(free_window_handler::<WindowDragOverHandler>),
+        );
+    }
+}
+
+extern "C" fn window_drop_files_trampoline(
+    filenames_ptr: *const ffi::wxd_ArrayString_t,
+    x: i32,
+    y: i32,
+    data_ptr: *mut c_void,
+) -> bool {
+    if filenames_ptr.is_null() || data_ptr.is_null() {
+        return false;
+    }
+    let handler = unsafe { &*(data_ptr as *const WindowDropFilesHandler) };
+    handler(Point::new(x, y), filenames_from_array(filenames_ptr))
+}
+
+extern "C" fn window_drop_text_trampoline(text: *const c_char, x: i32, y: i32, data_ptr: *mut c_void) -> bool {
+    if text.is_null() || data_ptr.is_null() {
+        return false;
+    }
+    let text = unsafe { CStr::from_ptr(text).to_string_lossy().into_owned() };
+    let handler = unsafe { &*(data_ptr as *const WindowDropTextHandler) };
+    handler(Point::new(x, y), text)
+}
+
+extern "C" fn window_drop_data_trampoline(data: *const u8, len: usize, x: i32, y: i32, data_ptr: *mut c_void) -> bool {
+    if data_ptr.is_null() {
+        return false;
+    }
+    let bytes = if data.is_null() || len == 0 {
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len).to_vec() }
+    };
+    let handler = unsafe { &*(data_ptr as *const WindowDropDataHandler) };
+    handler(Point::new(x, y), bytes)
+}
+
+extern "C" fn window_drag_over_trampoline(
+    x: i32,
+    y: i32,
+    def_result: ffi::wxd_DragResult,
+    data_ptr: *mut c_void,
+) -> ffi::wxd_DragResult {
+    if data_ptr.is_null() {
+        return def_result;
+    }
+    let handler = unsafe { &*(data_ptr as *const WindowDragOverHandler) };
+    handler(Point::new(x, y)).into()
+}
+
+extern "C" fn free_window_handler<T>(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    let _ = unsafe { Box::from_raw(ptr as *mut T) };
 }
 
 // --- Rust-side cleanup functions for boxed user data ---
@@ -3,15 +3,17 @@
 //! This module provides classes for implementing drag and drop operations
 //! in wxDragon applications, following the wxWidgets drag and drop pattern.
 
+mod dragsource;
 mod dropsource;
-mod droptarget;
+pub(crate) mod droptarget;
 // Use the main data_object module instead of our own implementation
 // mod dataobject;
 
+pub use dragsource::DragSource;
 pub use dropsource::DropSource;
 pub use droptarget::{FileDropTarget, TextDropTarget};
 // Re-export data objects from the main module
-pub use crate::data_object::{BitmapDataObject, DataObject, FileDataObject, TextDataObject};
+pub use crate::data_object::{BitmapDataObject, CustomDataObject, DataObject, FileDataObject, TextDataObject};
 
 use std::fmt;
 
@@ -0,0 +1,144 @@
+//! Page change events shared by the book controls.
+
+use crate::event::{Event, EventToken, EventType, WxEvtHandler};
+use wxdragon_sys as ffi;
+
+/// Event data for the page changed and changing events of a book control.
+#[derive(Debug)]
+pub struct BookCtrlEventData {
+    /// The base event.
+    pub event: Event,
+}
+
+crate::impl_event_base!(BookCtrlEventData => event);
+
+impl BookCtrlEventData {
+    /// Creates a new `BookCtrlEventData` from a base `Event`.
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Gets the page that is or has been selected.
+    pub fn get_selection(&self) -> Option<i32> {
+        if self.event.is_null() {
+            return None;
+        }
+        let val = unsafe { ffi::wxd_NotebookEvent_GetSelection(self.event.0) };
+        if val == ffi::WXD_NOT_FOUND as i32 { None } else { Some(val) }
+    }
+
+    /// Gets the page that was selected before the change.
+    pub fn get_old_selection(&self) -> Option<i32> {
+        if self.event.is_null() {
+            return None;
+        }
+        let val = unsafe { ffi::wxd_NotebookEvent_GetOldSelection(self.event.0) };
+        if val == ffi::WXD_NOT_FOUND as i32 { None } else { Some(val) }
+    }
+
+    /// Vetoes a page changing event, so the old page stays selected and no page changed
+    /// event follows.
+    pub fn veto(&self) {
+        self.event.veto();
+    }
+
+    /// Checks if the event has been vetoed
+    pub fn is_vetoed(&self) -> bool {
+        self.event.is_vetoed()
+    }
+}
+
+/// Page change events of the book controls.
+///
+/// Implemented by `Notebook`, `Choicebook`, `Listbook`, `Treebook` and `SimpleBook`, each of
+/// which sends its own event types, so handlers can be written once for any of them. Where a
+/// control has `on_page_changed` and `on_page_changing` methods of its own, those are called
+/// for it by name, while generic code like below gets these.
+///
+/// # Example
+/// ```rust,no_run
+/// use wxdragon::prelude::*;
+///
+/// fn confirm_leaving<B: BookCtrlEvents>(book: &B, is_saved: impl Fn() -> bool + 'static) {
+///     book.on_page_changing(move |event| {
+///         if !is_saved() {
+///             event.veto();
+///         }
+///     });
+/// }
+/// ```
+pub trait BookCtrlEvents: WxEvtHandler {
+    /// The event type sent after the selected page changed.
+    #[doc(hidden)]
+    const PAGE_CHANGED: EventType;
+
+    /// The event type sent before the selected page changes.
+    #[doc(hidden)]
+    const PAGE_CHANGING: EventType;
+
+    /// Binds a handler called after the user or `set_selection` selected another page.
+    /// Returns an EventToken that can be used to unbind the handler later.
+    fn on_page_changed<F>(&self, mut callback: F) -> EventToken
+    where
+        F: FnMut(BookCtrlEventData) + 'static,
+        Self: Sized,
+    {
+        self.bind_internal(Self::PAGE_CHANGED, move |event| callback(BookCtrlEventData::new(event)))
+    }
+
+    /// Binds a handler that runs only the first time the page changed event fires.
+    /// The returned EventToken can still be used to unbind it early.
+    fn on_page_changed_once<F>(&self, callback: F) -> EventToken
+    where
+        F: FnOnce(BookCtrlEventData) + 'static,
+        Self: Sized,
+    {
+        self.bind_once(Self::PAGE_CHANGED, move |event| callback(BookCtrlEventData::new(event)))
+    }
+
+    /// Binds a handler called before another page is selected, which can keep the current
+    /// one with [`BookCtrlEventData::veto`].
+    /// Returns an EventToken that can be used to unbind the handler later.
+    fn on_page_changing<F>(&self, mut callback: F) -> EventToken
+    where
+        F: FnMut(BookCtrlEventData) + 'static,
+        Self: Sized,
+    {
+        self.bind_internal(Self::PAGE_CHANGING, move |event| callback(BookCtrlEventData::new(event)))
+    }
+
+    /// Binds a handler that runs only the first time the page changing event fires.
+    /// The returned EventToken can still be used to unbind it early.
+    fn on_page_changing_once<F>(&self, callback: F) -> EventToken
+    where
+        F: FnOnce(BookCtrlEventData) + 'static,
+        Self: Sized,
+    {
+        self.bind_once(Self::PAGE_CHANGING, move |event| callback(BookCtrlEventData::new(event)))
+    }
+}
+
+impl BookCtrlEvents for crate::widgets::Notebook {
+    const PAGE_CHANGED: EventType = EventType::NOTEBOOK_PAGE_CHANGED;
+    const PAGE_CHANGING: EventType = EventType::NOTEBOOK_PAGE_CHANGING;
+}
+
+impl BookCtrlEvents for crate::widgets::Choicebook {
+    const PAGE_CHANGED: EventType = EventType::CHOICEBOOK_PAGE_CHANGED;
+    const PAGE_CHANGING: EventType = EventType::CHOICEBOOK_PAGE_CHANGING;
+}
+
+impl BookCtrlEvents for crate::widgets::Listbook {
+    const PAGE_CHANGED: EventType = EventType::LISTBOOK_PAGE_CHANGED;
+    const PAGE_CHANGING: EventType = EventType::LISTBOOK_PAGE_CHANGING;
+}
+
+impl BookCtrlEvents for crate::widgets::Treebook {
+    const PAGE_CHANGED: EventType = EventType::TREEBOOK_PAGE_CHANGED;
+    const PAGE_CHANGING: EventType = EventType::TREEBOOK_PAGE_CHANGING;
+}
+
+impl BookCtrlEvents for crate::widgets::SimpleBook {
+    const PAGE_CHANGED: EventType = EventType::BOOKCTRL_PAGE_CHANGED;
+    const PAGE_CHANGING: EventType = EventType::BOOKCTRL_PAGE_CHANGING;
+}
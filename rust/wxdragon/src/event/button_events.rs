@@ -18,6 +18,8 @@ pub struct ButtonEventData {
     pub event: CommandEventData,
 }
 
+crate::impl_event_base!(ButtonEventData => event.event);
+
 impl ButtonEventData {
     pub fn new(event: Event) -> Self {
         Self {
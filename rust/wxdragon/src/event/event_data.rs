@@ -7,6 +7,8 @@ pub struct CommandEventData {
     pub event: Event,
 }
 
+crate::impl_event_base!(CommandEventData => event);
+
 impl CommandEventData {
     pub fn new(event: Event) -> Self {
         CommandEventData { event }
@@ -39,6 +41,8 @@ pub struct MouseEventData {
     pub event: Event,
 }
 
+crate::impl_event_base!(MouseEventData => event);
+
 impl MouseEventData {
     pub fn new(event: Event) -> Self {
         MouseEventData { event }
@@ -73,6 +77,8 @@ pub struct KeyEventData {
     pub event: Event,
 }
 
+crate::impl_event_base!(KeyEventData => event);
+
 impl KeyEventData {
     pub fn new(event: Event) -> Self {
         KeyEventData { event }
@@ -0,0 +1,113 @@
+//! Scoped event bindings that are removed together.
+
+use crate::event::{EventToken, WxEvtHandler};
+use crate::window::{Window, WindowHandle, WxWidget};
+
+/// Collects event bindings across any number of windows and unbinds them all
+/// when dropped or when [`clear`](EventScope::clear) is called.
+///
+/// This is handy for temporary UI modes (e.g. "click a widget to inspect it")
+/// that hook many windows and must undo every binding when the mode ends.
+/// Windows destroyed in the meantime are skipped: their bindings were already
+/// released together with the window.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut scope = EventScope::new();
+///
+/// // Bind through the scope...
+/// scope.bind(&button, |b| b.on_click(|_| println!("inspect button")));
+/// // ...or track a token you already have
+/// let token = panel.on_mouse_left_down(|_| println!("inspect panel"));
+/// scope.track(&panel, token);
+///
+/// // Leaving the mode removes both handlers
+/// scope.clear();
+/// ```
+#[derive(Default)]
+pub struct EventScope {
+    bindings: Vec<(WindowHandle, EventToken)>,
+}
+
+impl EventScope {
+    /// Creates an empty scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `bind` against `widget` and tracks the token it returns.
+    ///
+    /// Returns the token so it can still be unbound individually.
+    pub fn bind<W, F>(&mut self, widget: &W, bind: F) -> EventToken
+    where
+        W: WxWidget,
+        F: FnOnce(&W) -> EventToken,
+    {
+        let token = bind(widget);
+        self.track(widget, token)
+    }
+
+    /// Tracks a token that was bound on `widget`, so that it is removed with the scope.
+    ///
+    /// Invalid tokens and destroyed widgets are ignored.
+    pub fn track<W: WxWidget + ?Sized>(&mut self, widget: &W, token: EventToken) -> EventToken {
+        let ptr = widget.handle_ptr();
+        if ptr.is_null() || !token.is_valid() {
+            return token;
+        }
+        let handle = WindowHandle::from_ptr(ptr).unwrap_or_else(|| WindowHandle::new(ptr));
+        self.bindings.push((handle, token));
+        token
+    }
+
+    /// Unbinds a single tracked token and stops tracking it.
+    ///
+    /// Returns `true` if the handler was found and removed.
+    pub fn unbind(&mut self, token: EventToken) -> bool {
+        let Some(index) = self.bindings.iter().position(|(_, t)| *t == token) else {
+            return false;
+        };
+        let (handle, token) = self.bindings.remove(index);
+        Self::unbind_binding(handle, token)
+    }
+
+    /// Returns the tokens currently tracked by this scope, oldest first.
+    pub fn tokens(&self) -> impl Iterator<Item = EventToken> + '_ {
+        self.bindings.iter().map(|(_, token)| *token)
+    }
+
+    /// Returns the number of tracked bindings.
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns true if no bindings are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Unbinds every tracked handler.
+    ///
+    /// Returns the number of handlers actually removed; bindings whose window has
+    /// been destroyed are dropped silently.
+    pub fn clear(&mut self) -> usize {
+        self.bindings
+            .drain(..)
+            .filter(|(handle, token)| Self::unbind_binding(*handle, *token))
+            .count()
+    }
+
+    fn unbind_binding(handle: WindowHandle, token: EventToken) -> bool {
+        match handle.get_ptr() {
+            Some(ptr) => unsafe { Window::from_ptr(ptr) }.unbind(token),
+            None => false,
+        }
+    }
+}
+
+impl Drop for EventScope {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -54,11 +54,16 @@ macro_rules! implement_window_event_handlers {
 }
 
 /// Generates internal binding method and public on_* methods for category-specific events
+///
+/// Renamed handlers can be kept after a `; deprecated:` list of
+/// `old_name => new_name, "note"` entries, which adds deprecated `on_old_name` methods
+/// forwarding to `on_new_name`.
 #[macro_export]
 macro_rules! implement_category_event_handlers {
     // Generic implementation for category event traits
     ($trait_name:ident, $event_enum:ident, $event_data:ident,
-     $($variant:ident => $method_name:ident, $event_type:expr),+) => {
+     $($variant:ident => $method_name:ident, $event_type:expr),+
+     $(; deprecated: $($old_method:ident => $new_method:ident, $note:literal),+)?) => {
         pub trait $trait_name: $crate::event::WxEvtHandler {
             // Internal binding method
             #[doc(hidden)]
@@ -92,17 +97,44 @@ macro_rules! implement_category_event_handlers {
                     {
                         self.bind_category_event($crate::event::$event_enum::$variant, callback)
                     }
+
+                    /// Binds a handler that runs only the first time the event fires.
+                    /// The returned EventToken can still be used to unbind it early.
+                    fn [<on_ $method_name _once>]<F>(&self, callback: F) -> $crate::event::EventToken
+                    where
+                        F: FnOnce($crate::event::$event_data) + 'static
+                    {
+                        $crate::event::WxEvtHandler::bind_once(self, $event_type, move |event| {
+                            callback($crate::event::$event_data::new(event));
+                        })
+                    }
                 }
             )*
+
+            // Former names of renamed handlers
+            $($(
+                paste::paste! {
+                    #[deprecated(note = $note)]
+                    fn [<on_ $old_method>]<F>(&self, callback: F) -> $crate::event::EventToken
+                    where
+                        F: FnMut($crate::event::$event_data) + 'static
+                    {
+                        self.[<on_ $new_method>](callback)
+                    }
+                }
+            )+)?
         }
     }
 }
 
 /// Generates internal binding method and public on_* methods for widget-specific events defined in the same module
+///
+/// Takes the same optional `; deprecated:` list as `implement_category_event_handlers!`.
 #[macro_export]
 macro_rules! implement_widget_local_event_handlers {
     ($widget:ident, $event_enum:ident, $event_data:ident,
-     $($variant:ident => $method_name:ident, $event_type:expr),+) => {
+     $($variant:ident => $method_name:ident, $event_type:expr),+
+     $(; deprecated: $($old_method:ident => $new_method:ident, $note:literal),+)?) => {
         impl $widget {
             // Internal binding method
             #[doc(hidden)]
@@ -136,8 +168,46 @@ macro_rules! implement_widget_local_event_handlers {
                     {
                         self.bind_widget_event($event_enum::$variant, callback)
                     }
+
+                    /// Binds a handler that runs only the first time the event fires.
+                    /// The returned EventToken can still be used to unbind it early.
+                    pub fn [<on_ $method_name _once>]<F>(&self, callback: F) -> $crate::event::EventToken
+                    where
+                        F: FnOnce($event_data) + 'static
+                    {
+                        $crate::event::WxEvtHandler::bind_once(self, $event_type, move |event| {
+                            callback($event_data::new(event));
+                        })
+                    }
                 }
             )*
+
+            // Former names of renamed handlers
+            $($(
+                paste::paste! {
+                    #[deprecated(note = $note)]
+                    pub fn [<on_ $old_method>]<F>(&self, callback: F) -> $crate::event::EventToken
+                    where
+                        F: FnMut($event_data) + 'static
+                    {
+                        self.[<on_ $new_method>](callback)
+                    }
+                }
+            )+)?
         }
     }
 }
+
+/// Implements [`EventBase`](crate::event::EventBase) for a typed event data struct,
+/// given the field path that leads to the wrapped [`Event`](crate::event::Event).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_event_base {
+    ($ty:ty => $($field:ident).+) => {
+        impl $crate::event::EventBase for $ty {
+            fn base(&self) -> &$crate::event::Event {
+                &self$(.$field)+
+            }
+        }
+    };
+}
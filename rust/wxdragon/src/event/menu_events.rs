@@ -25,6 +25,8 @@ pub struct MenuEventData {
     event: Event,
 }
 
+crate::impl_event_base!(MenuEventData => event);
+
 impl MenuEventData {
     /// Creates a new MenuEventData from a raw event
     pub(crate) fn new(event: Event) -> Self {
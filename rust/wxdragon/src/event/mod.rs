@@ -7,8 +7,10 @@ use std::ffi::CStr;
 use std::ffi::c_void;
 use wxdragon_sys as ffi;
 pub mod app_events;
+pub mod book_ctrl_events;
 pub mod button_events;
 pub mod event_data;
+pub mod event_scope;
 pub mod macros;
 pub mod menu_events;
 pub mod scroll_events;
@@ -21,10 +23,13 @@ pub mod window_events;
 
 // Re-export window events for easier access
 pub use window_events::{
-    IdleEventData, KeyboardEvent, MouseButtonEvent, MouseEnterEvent, MouseLeaveEvent, MouseMotionEvent, WindowEvent,
-    WindowEventData, WindowEvents, WindowSizeEvent,
+    EraseEventData, IdleEventData, KeyboardEvent, MouseButtonEvent, MouseEnterEvent, MouseLeaveEvent, MouseMotionEvent,
+    WindowEvent, WindowEventData, WindowEvents, WindowSizeEvent,
 };
 
+// Re-export book control events for easier access
+pub use book_ctrl_events::{BookCtrlEventData, BookCtrlEvents};
+
 // Re-export button events for easier access
 pub use button_events::{ButtonEvent, ButtonEventData, ButtonEvents};
 
@@ -51,6 +56,9 @@ pub use taskbar_events::{TaskBarIconEvent, TaskBarIconEventData};
 // Re-export app events for easier access
 pub use app_events::AppEvents;
 
+// Re-export scoped bindings
+pub use event_scope::EventScope;
+
 // Re-export the stable C enum for use in the safe wrapper
 pub use ffi::WXDEventTypeCEnum;
 
@@ -106,6 +114,9 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const CHECKBOX = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CHECKBOX;
     const TEXT = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TEXT;
     const TEXT_ENTER = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TEXT_ENTER;
+    const TEXT_MAXLEN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TEXT_MAXLEN;
+    const TEXT_URL = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TEXT_URL;
+    const TEXT_CARET_OR_SELECTION_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TEXT_CARET_OR_SELECTION_CHANGED;
     const SIZE = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_SIZE;
     const MENU = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MENU;
     // NEW: Menu event types
@@ -131,11 +142,15 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const COMMAND_LISTBOX_SELECTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_LISTBOX_SELECTED;
     const COMMAND_CHOICE_SELECTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_CHOICE_SELECTED;
     const COMMAND_COMBOBOX_SELECTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_COMBOBOX_SELECTED;
+    const COMBOBOX_DROPDOWN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMBOBOX_DROPDOWN;
+    const COMBOBOX_CLOSEUP = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMBOBOX_CLOSEUP;
     const COMMAND_CHECKLISTBOX_SELECTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_CHECKLISTBOX_SELECTED;
     const COMMAND_LISTBOX_DOUBLECLICKED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_LISTBOX_DOUBLECLICKED;
+    const EDITABLELISTBOX_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_EDITABLELISTBOX_CHANGED;
     const COMMAND_TOGGLEBUTTON_CLICKED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_TOGGLEBUTTON_CLICKED;
     // ADDED: RearrangeList event type
     const COMMAND_REARRANGE_LIST = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_REARRANGE_LIST;
+    const REARRANGE_LIST_CHECKED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_REARRANGE_LIST_CHECKED;
     // ADDED: CollapsiblePane event type
     const COLLAPSIBLEPANE_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COLLAPSIBLEPANE_CHANGED;
     // ADDED: TreeCtrl event types
@@ -161,6 +176,13 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     // ADDED: Notebook event types
     const NOTEBOOK_PAGE_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NOTEBOOK_PAGE_CHANGED;
     const NOTEBOOK_PAGE_CHANGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NOTEBOOK_PAGE_CHANGING;
+    const NOTEBOOK_PAGES_REORDERED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NOTEBOOK_PAGES_REORDERED;
+    const BOOKCTRL_PAGE_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_BOOKCTRL_PAGE_CHANGED;
+    const BOOKCTRL_PAGE_CHANGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_BOOKCTRL_PAGE_CHANGING;
+    const CHOICEBOOK_PAGE_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CHOICEBOOK_PAGE_CHANGED;
+    const CHOICEBOOK_PAGE_CHANGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CHOICEBOOK_PAGE_CHANGING;
+    const LISTBOOK_PAGE_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_LISTBOOK_PAGE_CHANGED;
+    const LISTBOOK_PAGE_CHANGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_LISTBOOK_PAGE_CHANGING;
     // ADDED: Splitter event types
     const SPLITTER_SASH_POS_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_SPLITTER_SASH_POS_CHANGED;
     const SPLITTER_SASH_POS_CHANGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_SPLITTER_SASH_POS_CHANGING;
@@ -272,6 +294,7 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const DATAVIEW_COLUMN_SORTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_DATAVIEW_COLUMN_SORTED;
     const DATAVIEW_COLUMN_REORDERED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_DATAVIEW_COLUMN_REORDERED;
     const DATAVIEW_ITEM_CONTEXT_MENU = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_DATAVIEW_ITEM_CONTEXT_MENU;
+    const DATAVIEW_ITEM_BEGIN_DRAG = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_DATAVIEW_ITEM_BEGIN_DRAG;
 
     // ADDED: New TreeCtrl Event Types (complementing 22-25)
     const TREE_SEL_CHANGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TREE_SEL_CHANGING;
@@ -292,6 +315,7 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     // ToolBar Events
     const TOOL = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TOOL;
     const TOOL_ENTER = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TOOL_ENTER;
+    const TOOL_DROPDOWN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TOOL_DROPDOWN;
 
     // TreeCtrl Events
     const TREE_ITEM_GETTOOLTIP = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TREE_ITEM_GETTOOLTIP;
@@ -318,6 +342,14 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const AUI_PANE_ACTIVATED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUI_PANE_ACTIVATED;
     #[cfg(feature = "aui")]
     const AUI_RENDER = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUI_RENDER;
+    #[cfg(feature = "aui")]
+    const AUI_PANE_FLOAT = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUI_PANE_FLOAT;
+    #[cfg(feature = "aui")]
+    const AUI_PANE_DOCK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUI_PANE_DOCK;
+    #[cfg(feature = "aui")]
+    const AUITOOLBAR_TOOL_DROPDOWN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUITOOLBAR_TOOL_DROPDOWN;
+    #[cfg(feature = "aui")]
+    const AUITOOLBAR_RIGHT_CLICK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUITOOLBAR_RIGHT_CLICK;
 
     // Timer event
     const TIMER = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TIMER;
@@ -439,6 +471,12 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const WEBVIEW_WINDOW_CLOSE_REQUESTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WEBVIEW_WINDOW_CLOSE_REQUESTED;
     #[cfg(feature = "webview")]
     const WEBVIEW_BROWSING_DATA_CLEARED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WEBVIEW_BROWSING_DATA_CLEARED;
+    #[cfg(feature = "webview")]
+    const WEBVIEW_DOWNLOAD_REQUEST = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_REQUEST;
+    #[cfg(feature = "webview")]
+    const WEBVIEW_DOWNLOAD_PROGRESS = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_PROGRESS;
+    #[cfg(feature = "webview")]
+    const WEBVIEW_DOWNLOAD_COMPLETED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WEBVIEW_DOWNLOAD_COMPLETED;
 
     // TaskBarIcon Event Types - platform-specific support
 
@@ -496,6 +534,12 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const PG_COL_BEGIN_DRAG = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_PG_COL_BEGIN_DRAG;
     const PG_COL_DRAGGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_PG_COL_DRAGGING;
     const PG_COL_END_DRAG = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_PG_COL_END_DRAG;
+
+    // Wizard events
+    const WIZARD_PAGE_CHANGING = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WIZARD_PAGE_CHANGING;
+    const WIZARD_PAGE_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WIZARD_PAGE_CHANGED;
+    const WIZARD_CANCEL = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WIZARD_CANCEL;
+    const WIZARD_FINISHED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WIZARD_FINISHED;
 }
 }
 
@@ -616,6 +660,44 @@ impl Event {
         unsafe { ffi::wxd_Event_Skip(self.0, skip) };
     }
 
+    /// Gets the timestamp of the event in milliseconds, as reported by the platform.
+    /// Returns 0 if the event carries no timestamp.
+    pub fn get_timestamp(&self) -> i64 {
+        if self.0.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_Event_GetTimestamp(self.0) }
+    }
+
+    /// Stops the event from propagating to parent windows.
+    ///
+    /// Returns the previous propagation level, which can be passed to
+    /// [`resume_propagation`](Self::resume_propagation) to restore it.
+    pub fn stop_propagation(&self) -> i32 {
+        if self.0.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_Event_StopPropagation(self.0) }
+    }
+
+    /// Restores the propagation level previously returned by
+    /// [`stop_propagation`](Self::stop_propagation).
+    pub fn resume_propagation(&self, propagation_level: i32) {
+        if self.0.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Event_ResumePropagation(self.0, propagation_level) }
+    }
+
+    /// Returns true if the event will propagate to parent windows when skipped.
+    /// Command events propagate by default, most other events do not.
+    pub fn should_propagate(&self) -> bool {
+        if self.0.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Event_ShouldPropagate(self.0) }
+    }
+
     // --- Common Event Data Accessors ---
 
     /// Gets the string associated with a command event.
@@ -812,6 +894,35 @@ impl Event {
     }
 }
 
+// --- EventBase Trait ---
+
+/// Common access to the underlying [`Event`] of typed event data.
+///
+/// Every typed event wrapper (e.g. `ButtonEventData`, `MenuEventData`,
+/// `GridEventData`) implements this, so generic code such as a single logging
+/// handler shared by many widgets can reach the id, source window, timestamp
+/// and propagation controls without knowing the concrete type.
+///
+/// # Example
+///
+/// ```ignore
+/// fn log_event(data: &impl EventBase) {
+///     let event = data.base();
+///     let source = event.get_event_object().and_then(|w| w.get_label());
+///     println!("id={} source={source:?} at {}ms", event.get_id(), event.get_timestamp());
+/// }
+/// ```
+pub trait EventBase {
+    /// Returns the underlying event.
+    fn base(&self) -> &Event;
+}
+
+impl EventBase for Event {
+    fn base(&self) -> &Event {
+        self
+    }
+}
+
 // --- WxEvtHandler Trait ---
 
 pub trait WxEvtHandler {
@@ -829,6 +940,7 @@ pub trait WxEvtHandler {
     where
         F: FnMut(Event) + 'static,
     {
+        crate::app::assert_main_thread();
         let handler_ptr = unsafe { self.get_event_handler_ptr() };
         if handler_ptr.is_null() {
             /* ... error handling ... */
@@ -853,6 +965,50 @@ pub trait WxEvtHandler {
         token
     }
 
+    /// Bind a handler that runs at most once.
+    ///
+    /// The handler is unbound (and its closure dropped) as soon as it has been
+    /// invoked for the first time. The returned token works like any other
+    /// token, so the binding can still be removed early with [`unbind`](Self::unbind)
+    /// before the event ever fires.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// frame.bind_once(EventType::IDLE, move |_| {
+    ///     println!("first idle after startup");
+    /// });
+    /// ```
+    fn bind_once<F>(&self, event_type: EventType, callback: F) -> EventToken
+    where
+        F: FnOnce(Event) + 'static,
+    {
+        crate::app::assert_main_thread();
+        let handler_ptr = unsafe { self.get_event_handler_ptr() };
+        if handler_ptr.is_null() {
+            return EventToken::INVALID_TOKEN;
+        }
+
+        // The token is only known after binding, so hand it to the closure afterwards
+        let own_token = std::rc::Rc::new(std::cell::Cell::new(EventToken::INVALID_TOKEN));
+        let own_token_inner = own_token.clone();
+        let mut callback = Some(callback);
+
+        let token = self.bind_internal(event_type, move |event| {
+            if let Some(callback) = callback.take() {
+                // Unbind before running the callback, in case it destroys the window.
+                // The C++ side defers dropping this closure until dispatch has finished.
+                let token = own_token_inner.get();
+                if token.is_valid() {
+                    unsafe { ffi::wxd_EvtHandler_Unbind(handler_ptr, token.into()) };
+                }
+                callback(event);
+            }
+        });
+        own_token.set(token);
+        token
+    }
+
     /// Unbind a specific event handler by token.
     ///
     /// Returns `true` if the handler was found and removed, `false` otherwise.
@@ -890,6 +1046,7 @@ pub trait WxEvtHandler {
     where
         F: FnMut(Event) + 'static,
     {
+        crate::app::assert_main_thread();
         let handler_ptr = unsafe { self.get_event_handler_ptr() };
         if handler_ptr.is_null() {
             /* ... error handling ... */
@@ -963,3 +1120,63 @@ pub unsafe extern "C" fn drop_rust_event_closure_box(ptr: *mut c_void) {
         let _ = unsafe { Box::from_raw(ptr as *mut Box<dyn FnMut(Event) + 'static>) };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    // Runs the wxWidgets main loop; skipped on macOS for the same reason as the
+    // window tests (the test harness thread is not the OS main thread there).
+    #[cfg_attr(target_os = "macos", ignore)]
+    #[test]
+    fn bind_once_drops_closure_after_first_event() {
+        use crate::prelude::*;
+        use crate::widgets::Frame;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct DropFlag(Rc<Cell<bool>>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
+        let calls = Rc::new(Cell::new(0));
+        let dropped = Rc::new(Cell::new(false));
+        let calls_clone = calls.clone();
+        let dropped_clone = dropped.clone();
+        let timer_store: Rc<std::cell::RefCell<Option<Timer<Frame>>>> = Rc::new(std::cell::RefCell::new(None));
+        let timer_store_clone = timer_store.clone();
+
+        let res = crate::main(move |app| {
+            let frame = Frame::builder().with_title("test").build();
+
+            let flag = DropFlag(dropped_clone);
+            let token = frame.bind_once(EventType::TIMER, move |_| {
+                let _flag = &flag;
+                calls_clone.set(calls_clone.get() + 1);
+            });
+            assert!(token.is_valid(), "bind_once should return a valid token");
+
+            // Let the repeating timer fire a few times before leaving the loop
+            let ticks = Rc::new(Cell::new(0));
+            let timer = Timer::new(&frame);
+            timer.on_tick(move |_| {
+                ticks.set(ticks.get() + 1);
+                if ticks.get() == 5 {
+                    app.exit_main_loop();
+                }
+            });
+            timer.start(10, false);
+            timer_store_clone.borrow_mut().replace(timer);
+        });
+
+        match res {
+            Ok(()) => {
+                assert_eq!(calls.get(), 1, "one-shot handler should run exactly once");
+                assert!(dropped.get(), "one-shot closure should be dropped after it ran");
+            }
+            Err(e) => log::warn!("Test failed with error: {:?}", e),
+        }
+    }
+}
@@ -4,6 +4,9 @@ use crate::event::{Event, EventType};
 use wxdragon_sys as ffi;
 
 /// Events specific to scrollable controls (ScrollBar, Slider, etc.)
+///
+/// Use `ThumbTrack` for live updates while the thumb is dragged and
+/// `ThumbRelease`/`Changed` to commit once the user is done.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScrollEventType {
     /// Fired when scrolling to the top
@@ -32,6 +35,8 @@ pub struct ScrollEvent {
     pub event: Event,
 }
 
+crate::impl_event_base!(ScrollEvent => event);
+
 impl ScrollEvent {
     pub fn new(event: Event) -> Self {
         Self { event }
@@ -59,17 +64,54 @@ impl ScrollEvent {
         let orient = unsafe { ffi::wxd_ScrollEvent_GetOrientation(self.event.0) };
         if orient == -1 { None } else { Some(orient) }
     }
+
+    /// Returns true if the event comes from a vertical control
+    pub fn is_vertical(&self) -> bool {
+        self.get_orientation() == Some(ffi::WXD_VERTICAL as i32)
+    }
+
+    /// Get which scroll sub-event this is (line up, thumb track, etc.)
+    pub fn get_scroll_type(&self) -> Option<ScrollEventType> {
+        let event_type = self.event.get_event_type()?;
+        let scroll_type = if event_type == EventType::SCROLL_TOP {
+            ScrollEventType::Top
+        } else if event_type == EventType::SCROLL_BOTTOM {
+            ScrollEventType::Bottom
+        } else if event_type == EventType::SCROLL_LINEUP {
+            ScrollEventType::LineUp
+        } else if event_type == EventType::SCROLL_LINEDOWN {
+            ScrollEventType::LineDown
+        } else if event_type == EventType::SCROLL_PAGEUP {
+            ScrollEventType::PageUp
+        } else if event_type == EventType::SCROLL_PAGEDOWN {
+            ScrollEventType::PageDown
+        } else if event_type == EventType::SCROLL_THUMBTRACK {
+            ScrollEventType::ThumbTrack
+        } else if event_type == EventType::SCROLL_THUMBRELEASE {
+            ScrollEventType::ThumbRelease
+        } else if event_type == EventType::SCROLL_CHANGED {
+            ScrollEventType::Changed
+        } else {
+            return None;
+        };
+        Some(scroll_type)
+    }
 }
 
 // Use the macro to implement the trait
 crate::implement_category_event_handlers!(ScrollEvents, ScrollEventType, ScrollEvent,
     Top => scroll_top, EventType::SCROLL_TOP,
     Bottom => scroll_bottom, EventType::SCROLL_BOTTOM,
-    LineUp => scroll_lineup, EventType::SCROLL_LINEUP,
-    LineDown => scroll_linedown, EventType::SCROLL_LINEDOWN,
-    PageUp => scroll_pageup, EventType::SCROLL_PAGEUP,
-    PageDown => scroll_pagedown, EventType::SCROLL_PAGEDOWN,
+    LineUp => line_up, EventType::SCROLL_LINEUP,
+    LineDown => line_down, EventType::SCROLL_LINEDOWN,
+    PageUp => page_up, EventType::SCROLL_PAGEUP,
+    PageDown => page_down, EventType::SCROLL_PAGEDOWN,
     ThumbTrack => thumb_track, EventType::SCROLL_THUMBTRACK,
     ThumbRelease => thumb_release, EventType::SCROLL_THUMBRELEASE,
-    Changed => scroll_changed, EventType::SCROLL_CHANGED
+    Changed => scroll_changed, EventType::SCROLL_CHANGED;
+    deprecated:
+    scroll_lineup => line_up, "renamed to `on_line_up`",
+    scroll_linedown => line_down, "renamed to `on_line_down`",
+    scroll_pageup => page_up, "renamed to `on_page_up`",
+    scroll_pagedown => page_down, "renamed to `on_page_down`"
 );
@@ -103,6 +103,8 @@ pub struct TaskBarIconEventData {
     pub event: MouseEventData,
 }
 
+crate::impl_event_base!(TaskBarIconEventData => event.event);
+
 impl TaskBarIconEventData {
     pub fn new(event: Event) -> Self {
         Self {
@@ -10,6 +10,8 @@ pub enum TextEvent {
     Updated,
     /// Fired when Enter key is pressed in the control
     EnterPressed,
+    /// Fired when the user tries to enter more text than the control's maximum length
+    MaxLengthReached,
 }
 
 /// Event data for text events
@@ -18,6 +20,8 @@ pub struct TextEventData {
     pub event: CommandEventData,
 }
 
+crate::impl_event_base!(TextEventData => event.event);
+
 impl TextEventData {
     pub fn new(event: Event) -> Self {
         Self {
@@ -39,5 +43,6 @@ impl TextEventData {
 // Use the macro to implement the trait
 crate::implement_category_event_handlers!(TextEvents, TextEvent, TextEventData,
     Updated => text_updated, EventType::TEXT,
-    EnterPressed => enter_pressed, EventType::TEXT_ENTER
+    EnterPressed => enter_pressed, EventType::TEXT_ENTER,
+    MaxLengthReached => max_length_reached, EventType::TEXT_MAXLEN
 );
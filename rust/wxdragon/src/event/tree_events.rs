@@ -40,6 +40,8 @@ pub struct TreeEventData {
     pub event: Event,
 }
 
+crate::impl_event_base!(TreeEventData => event);
+
 impl TreeEventData {
     pub fn new(event: Event) -> Self {
         Self { event }
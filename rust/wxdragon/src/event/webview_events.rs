@@ -2,6 +2,11 @@
 
 use crate::event::event_data::CommandEventData;
 use crate::event::{Event, EventType};
+use crate::widgets::webview::{WebViewDownloadState, WebViewNavigationError};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use wxdragon_sys as ffi;
 
 /// Events specific to WebView controls
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,7 +21,10 @@ pub enum WebViewEvent {
     Loaded,
     /// Fired when a navigation error occurs
     Error,
-    /// Fired when a new window is requested
+    /// Fired when the page asks for a new window, through `window.open` or a
+    /// `target="_blank"` link. Veto it to ignore the request, answer it with
+    /// `load_in_current()`, or open a window of your own for `get_url()`. Without a
+    /// handler the request is ignored on every backend and the page stays as it is.
     NewWindow,
     /// Fired with new window features
     NewWindowFeatures,
@@ -32,6 +40,14 @@ pub enum WebViewEvent {
     WindowCloseRequested,
     /// Fired when browsing data has been cleared
     BrowsingDataCleared,
+    /// Fired before a download starts (can be vetoed, or redirected with `set_download_path()`).
+    /// Only sent by backends for which `WebView::supports_downloads()` is true. While a
+    /// handler for it is bound on the webview, Edge doesn't show its own download flyout.
+    DownloadRequest,
+    /// Fired as a download receives data
+    DownloadProgress,
+    /// Fired when a download has completed or been interrupted
+    DownloadCompleted,
 }
 
 /// Event data for WebView events
@@ -40,6 +56,8 @@ pub struct WebViewEventData {
     pub event: CommandEventData,
 }
 
+crate::impl_event_base!(WebViewEventData => event.event);
+
 impl WebViewEventData {
     pub fn new(event: Event) -> Self {
         Self {
@@ -52,15 +70,150 @@ impl WebViewEventData {
         self.event.get_id()
     }
 
-    /// Get the URL associated with the event (if any)
+    /// Get the string associated with the event (if any).
+    ///
+    /// For script messages this is the payload passed to `postMessage`, for error
+    /// events the error description and for title changes the new title.
     pub fn get_string(&self) -> Option<String> {
         self.event.get_string()
     }
 
+    /// Get the URL being navigated to, loaded, or that failed to load.
+    pub fn get_url(&self) -> Option<String> {
+        self.read_string(ffi::wxd_WebViewEvent_GetURL)
+    }
+
+    /// Get the name of the frame the navigation targets. Empty for the main frame
+    /// on most backends.
+    pub fn get_target(&self) -> Option<String> {
+        self.read_string(ffi::wxd_WebViewEvent_GetTarget)
+    }
+
+    /// Get the name of the script message handler a script message was posted to,
+    /// i.e. the `name` of `window.name.postMessage(...)`.
+    pub fn get_message_handler_name(&self) -> Option<String> {
+        self.read_string(ffi::wxd_WebViewEvent_GetMessageHandler)
+    }
+
+    /// Get the new page title of a title changed event.
+    pub fn get_title(&self) -> Option<String> {
+        self.get_string()
+    }
+
+    /// Get the reason a navigation failed, for error events.
+    pub fn get_navigation_error(&self) -> Option<WebViewNavigationError> {
+        if self.event.event.get_event_type() != Some(EventType::WEBVIEW_ERROR) {
+            return None;
+        }
+        self.get_int().and_then(WebViewNavigationError::from_i32)
+    }
+
+    /// Get the backend's description of a navigation error, for error events.
+    pub fn get_error_description(&self) -> Option<String> {
+        if self.event.event.get_event_type() != Some(EventType::WEBVIEW_ERROR) {
+            return None;
+        }
+        self.get_string()
+    }
+
     /// Get the integer value associated with the event (e.g., navigation flags, error code)
     pub fn get_int(&self) -> Option<i32> {
         self.event.get_int()
     }
+
+    /// Cancels a navigating or new window event. The navigation never starts, so the
+    /// view is not left busy and no navigated or loaded event follows.
+    pub fn veto(&self) {
+        self.event.event.veto();
+    }
+
+    /// Checks if the event has been vetoed.
+    pub fn is_vetoed(&self) -> bool {
+        self.event.event.is_vetoed()
+    }
+
+    /// Answers a new window event by loading its URL in the webview that raised it,
+    /// instead of opening a window. Returns `false` if the event carries no URL.
+    pub fn load_in_current(&self) -> bool {
+        let ptr = self.event.event._as_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_WebViewEvent_LoadInCurrent(ptr) }
+    }
+
+    /// Get the file name the backend suggests for a download.
+    pub fn get_suggested_filename(&self) -> Option<String> {
+        self.read_string(ffi::wxd_WebViewEvent_GetDownloadFilename)
+    }
+
+    /// Get the path a download is saved to.
+    pub fn get_download_path(&self) -> Option<PathBuf> {
+        self.read_string(ffi::wxd_WebViewEvent_GetDownloadPath).map(PathBuf::from)
+    }
+
+    /// Get the MIME type of a download, if the server sent one.
+    pub fn get_mime_type(&self) -> Option<String> {
+        self.read_string(ffi::wxd_WebViewEvent_GetDownloadMimeType)
+            .filter(|mime| !mime.is_empty())
+    }
+
+    /// Saves the download to `path` instead of the backend's default location.
+    /// Only has an effect on download request events; returns `false` otherwise.
+    pub fn set_download_path(&self, path: &Path) -> bool {
+        let ptr = self.event.event._as_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let Ok(c_path) = CString::new(path.to_string_lossy().as_ref()) else {
+            return false;
+        };
+        unsafe { ffi::wxd_WebViewEvent_SetDownloadPath(ptr, c_path.as_ptr()) }
+    }
+
+    /// Get the bytes received so far by a download.
+    pub fn get_bytes_received(&self) -> Option<u64> {
+        self.read_download_bytes().map(|(received, _)| received.max(0) as u64)
+    }
+
+    /// Get the expected size of a download, if the server reported one.
+    pub fn get_total_bytes(&self) -> Option<u64> {
+        self.read_download_bytes()
+            .and_then(|(_, total)| (total >= 0).then_some(total as u64))
+    }
+
+    /// Get whether a download is in progress, completed or interrupted.
+    pub fn get_download_state(&self) -> Option<WebViewDownloadState> {
+        let ptr = self.event.event._as_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        WebViewDownloadState::from_i32(unsafe { ffi::wxd_WebViewEvent_GetDownloadState(ptr) })
+    }
+
+    fn read_download_bytes(&self) -> Option<(i64, i64)> {
+        let ptr = self.event.event._as_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let mut received = 0;
+        let mut total = -1;
+        unsafe { ffi::wxd_WebViewEvent_GetDownloadBytes(ptr, &mut received, &mut total) }.then_some((received, total))
+    }
+
+    fn read_string(&self, get: unsafe extern "C" fn(*mut ffi::wxd_Event_t, *mut c_char, i32) -> i32) -> Option<String> {
+        let ptr = self.event.event._as_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let len = unsafe { get(ptr, std::ptr::null_mut(), 0) };
+        if len < 0 {
+            return None;
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { get(ptr, buf.as_mut_ptr(), buf.len() as i32) };
+        Some(unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().to_string() })
+    }
 }
 
 // Use the macro to implement the trait
@@ -78,5 +231,8 @@ crate::implement_category_event_handlers!(WebViewEvents, WebViewEvent, WebViewEv
     ScriptMessageReceived => script_message_received, EventType::WEBVIEW_SCRIPT_MESSAGE_RECEIVED,
     ScriptResult => script_result, EventType::WEBVIEW_SCRIPT_RESULT,
     WindowCloseRequested => window_close_requested, EventType::WEBVIEW_WINDOW_CLOSE_REQUESTED,
-    BrowsingDataCleared => browsing_data_cleared, EventType::WEBVIEW_BROWSING_DATA_CLEARED
+    BrowsingDataCleared => browsing_data_cleared, EventType::WEBVIEW_BROWSING_DATA_CLEARED,
+    DownloadRequest => download_request, EventType::WEBVIEW_DOWNLOAD_REQUEST,
+    DownloadProgress => download_progress, EventType::WEBVIEW_DOWNLOAD_PROGRESS,
+    DownloadCompleted => download_completed, EventType::WEBVIEW_DOWNLOAD_COMPLETED
 );
@@ -52,6 +52,7 @@ pub enum WindowEventData {
     Size(WindowSizeEvent),
     Idle(IdleEventData),
     Activate(ActivateEventData),
+    Erase(EraseEventData),
     General(Event),
 }
 
@@ -91,6 +92,8 @@ impl WindowEventData {
                 return WindowEventData::Idle(IdleEventData::new(event));
             } else if event_type == EventType::ACTIVATE {
                 return WindowEventData::Activate(ActivateEventData::new(event));
+            } else if event_type == EventType::ERASE {
+                return WindowEventData::Erase(EraseEventData::new(event));
             }
         }
 
@@ -109,17 +112,37 @@ impl WindowEventData {
             WindowEventData::Size(event) => event.event.skip(skip),
             WindowEventData::Idle(event) => event.event.skip(skip),
             WindowEventData::Activate(event) => event.event.skip(skip),
+            WindowEventData::Erase(event) => event.event.skip(skip),
             WindowEventData::General(event) => event.skip(skip),
         }
     }
 }
 
+impl crate::event::EventBase for WindowEventData {
+    fn base(&self) -> &Event {
+        match self {
+            WindowEventData::MouseButton(event) => &event.event.event,
+            WindowEventData::MouseMotion(event) => &event.event.event,
+            WindowEventData::MouseEnter(event) => &event.event.event,
+            WindowEventData::MouseLeave(event) => &event.event.event,
+            WindowEventData::Keyboard(event) => &event.event.event,
+            WindowEventData::Size(event) => &event.event,
+            WindowEventData::Idle(event) => &event.event,
+            WindowEventData::Activate(event) => &event.event,
+            WindowEventData::Erase(event) => &event.event,
+            WindowEventData::General(event) => event,
+        }
+    }
+}
+
 /// Mouse button events (left/right/middle click, up/down)
 #[derive(Debug)]
 pub struct MouseButtonEvent {
     pub event: MouseEventData,
 }
 
+crate::impl_event_base!(MouseButtonEvent => event.event);
+
 impl MouseButtonEvent {
     pub fn new(event: Event) -> Self {
         Self {
@@ -138,6 +161,8 @@ pub struct MouseMotionEvent {
     pub event: MouseEventData,
 }
 
+crate::impl_event_base!(MouseMotionEvent => event.event);
+
 impl MouseMotionEvent {
     pub fn new(event: Event) -> Self {
         Self {
@@ -156,6 +181,8 @@ pub struct MouseEnterEvent {
     pub event: MouseEventData,
 }
 
+crate::impl_event_base!(MouseEnterEvent => event.event);
+
 impl MouseEnterEvent {
     pub fn new(event: Event) -> Self {
         Self {
@@ -174,6 +201,8 @@ pub struct MouseLeaveEvent {
     pub event: MouseEventData,
 }
 
+crate::impl_event_base!(MouseLeaveEvent => event.event);
+
 impl MouseLeaveEvent {
     pub fn new(event: Event) -> Self {
         Self {
@@ -192,6 +221,8 @@ pub struct KeyboardEvent {
     pub event: KeyEventData,
 }
 
+crate::impl_event_base!(KeyboardEvent => event.event);
+
 impl KeyboardEvent {
     pub fn new(event: Event) -> Self {
         Self {
@@ -239,6 +270,8 @@ pub struct WindowSizeEvent {
     pub event: Event,
 }
 
+crate::impl_event_base!(WindowSizeEvent => event);
+
 impl WindowSizeEvent {
     pub fn new(event: Event) -> Self {
         Self { event }
@@ -265,6 +298,8 @@ pub struct IdleEventData {
     pub event: Event,
 }
 
+crate::impl_event_base!(IdleEventData => event);
+
 impl IdleEventData {
     pub fn new(event: Event) -> Self {
         Self { event }
@@ -289,6 +324,8 @@ pub struct ActivateEventData {
     pub event: Event,
 }
 
+crate::impl_event_base!(ActivateEventData => event);
+
 impl ActivateEventData {
     pub fn new(event: Event) -> Self {
         Self { event }
@@ -303,6 +340,45 @@ impl ActivateEventData {
     }
 }
 
+/// Erase background events.
+///
+/// Sent before a window is painted so that its background can be cleared.
+/// Handling this event without skipping it (and doing nothing, or drawing the
+/// background yourself on [`get_dc`](Self::get_dc)) suppresses the default
+/// erase, which is the usual cause of flicker in custom-drawn windows. It is
+/// typically combined with
+/// [`BackgroundStyle::Paint`](crate::window::BackgroundStyle::Paint) and a
+/// [`PaintDC`](crate::dc::PaintDC) or
+/// [`AutoBufferedPaintDC`](crate::dc::AutoBufferedPaintDC) that repaints the
+/// whole client area.
+#[derive(Debug)]
+pub struct EraseEventData {
+    pub event: Event,
+}
+
+crate::impl_event_base!(EraseEventData => event);
+
+impl EraseEventData {
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Returns the device context to draw the background on.
+    ///
+    /// The returned DC is owned by wxWidgets and is only valid for the
+    /// duration of the event handler; do not keep it around.
+    pub fn get_dc(&self) -> Option<crate::dc::GenericDC> {
+        if self.event.is_null() {
+            return None;
+        }
+        let dc_ptr = unsafe { ffi::wxd_EraseEvent_GetDC(self.event._as_ptr()) };
+        if dc_ptr.is_null() {
+            return None;
+        }
+        Some(unsafe { crate::dc::GenericDC::from_ffi_ptr_unowned(dc_ptr) })
+    }
+}
+
 // Use the macro to implement the trait
 crate::implement_category_event_handlers!(
     WindowEvents, WindowEvent, WindowEventData,
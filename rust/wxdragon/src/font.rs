@@ -168,6 +168,37 @@ impl Font {
         }
     }
 
+    /// Returns a platform-specific description of the font that can be stored
+    /// (e.g. in `Config`) and turned back into a font with
+    /// [`from_native_description`](Font::from_native_description).
+    ///
+    /// Returns an empty string if the font is invalid.
+    pub fn native_description(&self) -> String {
+        if self.ptr.is_null() {
+            return String::new();
+        }
+        let len = unsafe { ffi::wxd_Font_GetNativeFontInfoDesc(self.ptr, std::ptr::null_mut(), 0) };
+        if len <= 0 {
+            return String::new();
+        }
+        let mut buffer = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_Font_GetNativeFontInfoDesc(self.ptr, buffer.as_mut_ptr(), buffer.len()) };
+        unsafe { CStr::from_ptr(buffer.as_ptr()).to_string_lossy().to_string() }
+    }
+
+    /// Recreates a font from a description returned by [`native_description`](Font::native_description).
+    ///
+    /// Returns `None` if the description can't be parsed on this platform.
+    pub fn from_native_description(desc: &str) -> Option<Self> {
+        let c_desc = std::ffi::CString::new(desc).ok()?;
+        let ptr = unsafe { ffi::wxd_Font_CreateFromNativeFontInfoDesc(c_desc.as_ptr()) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self { ptr, owned: true })
+        }
+    }
+
     /// Check if the font is valid.
     pub fn is_ok(&self) -> bool {
         unsafe { ffi::wxd_Font_IsOk(self.ptr) }
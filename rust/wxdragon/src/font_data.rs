@@ -5,13 +5,21 @@ use wxdragon_sys as ffi;
 /// Wrapper for wxFontData, used primarily with FontDialog
 pub struct FontData {
     pub(crate) ptr: *mut ffi::wxd_FontData_t,
+    owned: bool,
 }
 
 impl FontData {
     /// Create a new FontData instance with default values
     pub fn new() -> Self {
         let ptr = unsafe { ffi::wxd_FontData_Create() };
-        Self { ptr }
+        Self { ptr, owned: true }
+    }
+
+    /// Wraps font data owned by a dialog without taking ownership.
+    /// # Safety
+    /// The pointer must stay valid for as long as the wrapper is used.
+    pub(crate) unsafe fn from_ptr_unowned(ptr: *mut ffi::wxd_FontData_t) -> Self {
+        Self { ptr, owned: false }
     }
 
     /// Enable or disable font effects (underline, strikethrough, etc.)
@@ -84,7 +92,7 @@ impl Default for FontData {
 
 impl Drop for FontData {
     fn drop(&mut self) {
-        if !self.ptr.is_null() {
+        if self.owned && !self.ptr.is_null() {
             unsafe {
                 ffi::wxd_FontData_Destroy(self.ptr);
             }
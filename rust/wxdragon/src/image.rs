@@ -0,0 +1,361 @@
+//!
+//! Safe wrapper for wxImage.
+//!
+//! Unlike [`Bitmap`], an [`Image`] is device independent: it can be loaded, scaled, rotated
+//! and edited pixel by pixel without a running application or any window, and converted to
+//! a bitmap for drawing afterwards.
+
+use crate::bitmap::{Bitmap, BitmapError};
+use crate::color::Colour;
+use crate::geometry::{Point, Size};
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+use std::path::Path;
+use std::rc::Rc;
+use wxdragon_sys as ffi;
+
+/// Algorithm used by [`Image::scale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ResizeQuality {
+    /// Nearest neighbour; fastest, keeps hard pixel edges.
+    Nearest,
+    /// Bilinear interpolation; suited for enlarging.
+    Bilinear,
+    /// Box averaging; suited for shrinking.
+    BoxAverage,
+    /// Picks the best algorithm for the direction of scaling. Use this for icons.
+    #[default]
+    High,
+}
+
+impl ResizeQuality {
+    fn to_raw(self) -> c_int {
+        let raw = match self {
+            ResizeQuality::Nearest => ffi::WXD_IMAGE_QUALITY_NEAREST,
+            ResizeQuality::Bilinear => ffi::WXD_IMAGE_QUALITY_BILINEAR,
+            ResizeQuality::BoxAverage => ffi::WXD_IMAGE_QUALITY_BOX_AVERAGE,
+            ResizeQuality::High => ffi::WXD_IMAGE_QUALITY_HIGH,
+        };
+        raw as c_int
+    }
+}
+
+/// A device independent image with optional alpha channel.
+///
+/// Transformations such as [`scale`](Self::scale) and [`rotate`](Self::rotate) return a new
+/// image and leave `self` untouched. Cloning makes a deep copy.
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// let icon = Image::from_file("assets/icon.png").unwrap();
+/// let small = icon.scale(24, 24, ResizeQuality::High).unwrap();
+/// let bitmap = small.to_bitmap();
+/// ```
+#[derive(Debug)]
+pub struct Image {
+    ptr: *mut ffi::wxd_Image_t,
+    // Prevent Send/Sync: wxImage uses non-atomic reference counting.
+    _nosend_nosync: PhantomData<Rc<()>>,
+}
+
+impl Image {
+    fn from_ptr(ptr: *mut ffi::wxd_Image_t) -> Option<Self> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Image {
+                ptr,
+                _nosend_nosync: PhantomData,
+            })
+        }
+    }
+
+    /// Creates a black image without alpha channel.
+    ///
+    /// Returns `None` if a dimension is not positive.
+    pub fn new(width: i32, height: i32) -> Option<Self> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Create(width, height) })
+    }
+
+    /// Creates an image with alpha channel from RGBA pixel data, laid out as for
+    /// [`Bitmap::from_rgba`].
+    ///
+    /// Returns `None` if the data length doesn't match the dimensions.
+    pub fn from_rgba(data: &[u8], width: u32, height: u32) -> Option<Self> {
+        if width == 0 || height == 0 || width > i32::MAX as u32 || height > i32::MAX as u32 {
+            return None;
+        }
+        let expected_len = (width as usize).checked_mul(height as usize)?.checked_mul(4)?;
+        if data.len() != expected_len {
+            log::error!(
+                "Image::from_rgba: Invalid data length. Expected {}, got {}, w={}, h={}",
+                expected_len,
+                data.len(),
+                width,
+                height
+            );
+            return None;
+        }
+        Self::from_ptr(unsafe { ffi::wxd_Image_CreateFromRGBA(data.as_ptr(), width as c_int, height as c_int) })
+    }
+
+    /// Loads an image file, detecting its format (PNG, JPEG, BMP, GIF, ICO, ...).
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, BitmapError> {
+        let path = path.as_ref();
+        let display = path.display().to_string();
+        if !path.is_file() {
+            return Err(BitmapError::FileNotFound(display));
+        }
+        let c_path = CString::new(path.to_string_lossy().as_bytes()).map_err(|_| BitmapError::InvalidPath(display.clone()))?;
+        Self::from_ptr(unsafe { ffi::wxd_Image_CreateFromFile(c_path.as_ptr()) }).ok_or(BitmapError::DecodeFailed(display))
+    }
+
+    /// Decodes an image held in memory, detecting its format.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, BitmapError> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_CreateFromBytes(data.as_ptr(), data.len()) })
+            .ok_or_else(|| BitmapError::DecodeFailed(format!("{} bytes of memory", data.len())))
+    }
+
+    /// Returns the width of the image in pixels.
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::wxd_Image_GetWidth(self.ptr) }
+    }
+
+    /// Returns the height of the image in pixels.
+    pub fn get_height(&self) -> i32 {
+        unsafe { ffi::wxd_Image_GetHeight(self.ptr) }
+    }
+
+    /// Returns the size of the image in pixels.
+    pub fn get_size(&self) -> Size {
+        Size::new(self.get_width(), self.get_height())
+    }
+
+    /// Returns `true` if the image has an alpha channel.
+    pub fn has_alpha(&self) -> bool {
+        unsafe { ffi::wxd_Image_HasAlpha(self.ptr) }
+    }
+
+    /// Returns the pixels in the layout accepted by [`from_rgba`](Self::from_rgba).
+    ///
+    /// Images without alpha channel read back as fully opaque.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        let len = self.get_width() as usize * self.get_height() as usize * 4;
+        let mut data = vec![0u8; len];
+        if !unsafe { ffi::wxd_Image_GetRGBAData(self.ptr, data.as_mut_ptr(), len) } {
+            data.clear();
+        }
+        data
+    }
+
+    /// Returns the image scaled to `width` x `height`.
+    ///
+    /// [`ResizeQuality::High`] gives smooth results when shrinking icons, where
+    /// [`ResizeQuality::Nearest`] looks jagged.
+    ///
+    /// Returns `None` if a dimension is not positive.
+    pub fn scale(&self, width: i32, height: i32, quality: ResizeQuality) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Scale(self.ptr, width, height, quality.to_raw()) })
+    }
+
+    /// Returns the image placed at `offset` on a canvas of the given size, without scaling.
+    ///
+    /// Parts of the image outside the canvas are cut off; the uncovered area is filled with
+    /// `fill`, or transparent if it is `None`.
+    pub fn resize(&self, size: Size, offset: Point, fill: Option<Colour>) -> Option<Image> {
+        let (r, g, b) = fill.map_or((-1, -1, -1), |c| (c.r as c_int, c.g as c_int, c.b as c_int));
+        Self::from_ptr(unsafe { ffi::wxd_Image_Resize(self.ptr, size.width, size.height, offset.x, offset.y, r, g, b) })
+    }
+
+    /// Returns the image rotated by `angle` radians counter-clockwise around `center`.
+    ///
+    /// The result is large enough to hold the whole rotated image; the corners it
+    /// doesn't cover are transparent.
+    pub fn rotate(&self, angle: f64, center: Point) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Rotate(self.ptr, angle, center.x, center.y) })
+    }
+
+    /// Returns the image rotated by 90 degrees.
+    pub fn rotate90(&self, clockwise: bool) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Rotate90(self.ptr, clockwise) })
+    }
+
+    /// Returns the image rotated by 180 degrees.
+    pub fn rotate180(&self) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Rotate180(self.ptr) })
+    }
+
+    /// Returns the image mirrored left to right if `horizontally`, otherwise top to bottom.
+    pub fn mirror(&self, horizontally: bool) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Mirror(self.ptr, horizontally) })
+    }
+
+    /// Returns a greyscale copy of the image, keeping its alpha channel.
+    pub fn convert_to_greyscale(&self) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_ConvertToGreyscale(self.ptr) })
+    }
+
+    /// Sets the alpha of every pixel, adding an alpha channel if the image has none.
+    pub fn set_alpha(&mut self, alpha: u8) {
+        unsafe { ffi::wxd_Image_SetAlpha(self.ptr, alpha) }
+    }
+
+    /// Returns the colour of a pixel, or `None` if it is outside the image.
+    ///
+    /// Pixels of images without alpha channel are opaque.
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<Colour> {
+        let mut colour = ffi::wxd_Colour_t { r: 0, g: 0, b: 0, a: 0 };
+        unsafe { ffi::wxd_Image_GetPixel(self.ptr, x, y, &mut colour) }.then(|| Colour::from(colour))
+    }
+
+    /// Sets the colour of a pixel; returns `false` if it is outside the image.
+    ///
+    /// Setting a non-opaque colour adds an alpha channel to the image.
+    pub fn set_pixel(&mut self, x: i32, y: i32, colour: Colour) -> bool {
+        unsafe { ffi::wxd_Image_SetPixel(self.ptr, x, y, colour.to_raw()) }
+    }
+
+    /// Converts the image to a bitmap for drawing or showing in controls.
+    ///
+    /// Returns [`Bitmap::null_bitmap`] if the conversion fails.
+    pub fn to_bitmap(&self) -> Bitmap {
+        let ptr = unsafe { ffi::wxd_Image_ToBitmap(self.ptr) };
+        if ptr.is_null() {
+            Bitmap::null_bitmap()
+        } else {
+            Bitmap::from(ptr)
+        }
+    }
+
+    /// Returns a const raw pointer to the underlying wxd_Image_t.
+    pub fn as_const_ptr(&self) -> *const ffi::wxd_Image_t {
+        self.ptr as *const _
+    }
+}
+
+impl Bitmap {
+    /// Converts the bitmap to an [`Image`] for pixel manipulation.
+    ///
+    /// Returns `None` if the bitmap is invalid.
+    pub fn to_image(&self) -> Option<Image> {
+        Image::from_ptr(unsafe { ffi::wxd_Bitmap_ToImage(self.as_const_ptr()) })
+    }
+}
+
+impl Clone for Image {
+    fn clone(&self) -> Self {
+        let ptr = unsafe { ffi::wxd_Image_Copy(self.ptr) };
+        Image::from_ptr(ptr).expect("Failed to copy wxImage")
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_Image_Destroy(self.ptr) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Image, ResizeQuality};
+    use crate::bitmap::{Bitmap, BitmapError};
+    use crate::color::Colour;
+    use crate::geometry::{Point, Size};
+
+    /// 2x2 image: red, green / blue, half-transparent white
+    fn quad() -> Image {
+        let rgba = [255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255, 255, 255, 128];
+        Image::from_rgba(&rgba, 2, 2).expect("image from rgba")
+    }
+
+    #[test]
+    fn image_rgba_round_trip_and_pixels() {
+        let mut image = quad();
+        assert_eq!(image.get_size(), Size::new(2, 2));
+        assert!(image.has_alpha());
+        assert_eq!(image.get_pixel(1, 1), Some(Colour::new(255, 255, 255, 128)));
+        assert_eq!(image.get_pixel(2, 0), None);
+
+        assert!(image.set_pixel(0, 0, Colour::new(1, 2, 3, 4)));
+        assert!(!image.set_pixel(-1, 0, Colour::new(1, 2, 3, 4)));
+        assert_eq!(&image.to_rgba()[..4], &[1, 2, 3, 4]);
+
+        image.set_alpha(200);
+        assert!(image.to_rgba().chunks(4).all(|p| p[3] == 200));
+    }
+
+    #[test]
+    fn image_without_alpha_is_opaque_until_needed() {
+        let mut image = Image::new(3, 1).expect("new image");
+        assert!(!image.has_alpha());
+        assert_eq!(image.to_rgba(), vec![0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255]);
+        assert!(image.set_pixel(1, 0, Colour::new(10, 20, 30, 0)));
+        assert!(image.has_alpha());
+        assert_eq!(image.get_pixel(0, 0), Some(Colour::new(0, 0, 0, 255)));
+        assert!(Image::new(0, 5).is_none());
+    }
+
+    #[test]
+    fn image_scale_and_resize() {
+        let image = quad();
+        for quality in [
+            ResizeQuality::Nearest,
+            ResizeQuality::Bilinear,
+            ResizeQuality::BoxAverage,
+            ResizeQuality::High,
+        ] {
+            let scaled = image.scale(8, 6, quality).expect("scaled image");
+            assert_eq!(scaled.get_size(), Size::new(8, 6));
+        }
+        let nearest = image.scale(4, 4, ResizeQuality::Nearest).unwrap();
+        assert_eq!(nearest.get_pixel(1, 1), Some(Colour::new(255, 0, 0, 255)));
+        assert!(image.scale(0, 4, ResizeQuality::High).is_none());
+
+        let padded = image.resize(Size::new(3, 3), Point::new(1, 1), None).unwrap();
+        assert_eq!(padded.get_pixel(0, 0).unwrap().a, 0);
+        assert_eq!(padded.get_pixel(1, 1), Some(Colour::new(255, 0, 0, 255)));
+        let filled = Image::new(1, 1)
+            .unwrap()
+            .resize(Size::new(2, 1), Point::new(0, 0), Some(Colour::new(9, 8, 7, 255)));
+        assert_eq!(filled.unwrap().get_pixel(1, 0), Some(Colour::new(9, 8, 7, 255)));
+    }
+
+    #[test]
+    fn image_rotate_mirror_and_greyscale() {
+        let image = quad();
+        let cw = image.rotate90(true).unwrap();
+        assert_eq!(cw.get_pixel(1, 0), Some(Colour::new(255, 0, 0, 255)));
+        let flipped = image.rotate180().unwrap();
+        assert_eq!(flipped.get_pixel(1, 1), Some(Colour::new(255, 0, 0, 255)));
+        let mirrored = image.mirror(true).unwrap();
+        assert_eq!(mirrored.get_pixel(0, 0), Some(Colour::new(0, 255, 0, 255)));
+
+        let wide = Image::new(4, 2).unwrap();
+        let rotated = wide.rotate(std::f64::consts::FRAC_PI_4, Point::new(2, 1)).unwrap();
+        assert!(rotated.get_width() > 4 && rotated.get_height() > 2);
+        assert_eq!(rotated.get_pixel(0, 0).unwrap().a, 0);
+
+        let grey = image.convert_to_greyscale().unwrap();
+        assert!(grey.to_rgba().chunks(4).all(|p| p[0] == p[1] && p[1] == p[2]));
+        assert_eq!(grey.get_pixel(1, 1).unwrap().a, 128);
+    }
+
+    #[test]
+    fn image_bitmap_conversion_and_errors() {
+        let image = quad();
+        let bitmap = image.to_bitmap();
+        assert!(bitmap.is_ok());
+        let back = bitmap.to_image().expect("image from bitmap");
+        assert_eq!(back.get_pixel(0, 1), Some(Colour::new(0, 0, 255, 255)));
+        assert!(Bitmap::null_bitmap().to_image().is_none());
+
+        assert!(matches!(
+            Image::from_bytes(b"not an image"),
+            Err(BitmapError::DecodeFailed(_))
+        ));
+        let png = bitmap.encode(crate::cursor::BitmapType::Png, None).unwrap();
+        assert_eq!(Image::from_bytes(&png).unwrap().get_size(), Size::new(2, 2));
+    }
+}
@@ -37,12 +37,36 @@
 //!     conn.execute_string("Hello, server!");
 //! }
 //! ```
-
+//!
+//! # Errors
+//!
+//! The `try_` variants of the operations return an [`IpcError`] telling e.g. a service
+//! already in use from a server that isn't running or a peer that disconnected or did not
+//! answer within [`DEFAULT_TIMEOUT`]. The other methods return `bool` or `Option`.
+//!
+//! # JSON Payloads
+//!
+//! With the `serde` feature, [`IPCConnection::execute_json`] and
+//! [`IPCConnection::request_json`] exchange serializable values, received with
+//! [`IPCConnectionBuilder::on_execute_json`] and [`IPCConnectionBuilder::on_request_json`].
+//! They are sent as [`IPCFormat::Private`] data so text-based peers don't mistake them
+//! for text; payloads that don't deserialize are logged and refused.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::os::raw::c_void;
 use std::ptr;
+use std::rc::Rc;
+use std::time::Duration;
 use wxdragon_sys as ffi;
 
+/// How long clients wait for a TCP server to accept a connection, and client connections
+/// wait for it to answer a blocking operation such as [`IPCConnection::request`].
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// IPC data format for Execute, Request, Poke, and Advise operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(i32)]
@@ -88,6 +112,82 @@ impl From<IPCFormat> for ffi::wxd_IPCFormat {
     }
 }
 
+// =============================================================================
+// Errors
+// =============================================================================
+
+/// Largest payload the transports can send; the socket transport sends sizes as 32 bits.
+const MAX_PAYLOAD_SIZE: usize = u32::MAX as usize;
+
+/// Error returned by the `try_` methods of the IPC types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpcError {
+    /// A host, service, topic or item name contained a nul byte.
+    InvalidName(String),
+    /// Another server already listens on the service.
+    AddressInUse(String),
+    /// No server listens on the service.
+    ConnectionRefused(String),
+    /// The peer did not answer in time.
+    Timeout(String),
+    /// The connection has been closed by either side.
+    PeerDisconnected,
+    /// The data is larger than the transport can send.
+    PayloadTooLarge { size: usize, max: usize },
+    /// Any other failure, described by what wxWidgets logged where available.
+    Failed(String),
+}
+
+impl fmt::Display for IpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpcError::InvalidName(name) => write!(f, "Invalid IPC name: {name:?}"),
+            IpcError::AddressInUse(message) => write!(f, "Address in use: {message}"),
+            IpcError::ConnectionRefused(message) => write!(f, "Connection refused: {message}"),
+            IpcError::Timeout(message) => write!(f, "Timed out: {message}"),
+            IpcError::PeerDisconnected => write!(f, "The IPC connection is closed"),
+            IpcError::PayloadTooLarge { size, max } => {
+                write!(f, "IPC payload of {size} bytes exceeds the maximum of {max} bytes")
+            }
+            IpcError::Failed(message) => write!(f, "IPC operation failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for IpcError {}
+
+/// Returns the error of the last failed IPC call.
+fn last_error() -> IpcError {
+    let len = unsafe { ffi::wxd_IPC_GetLastError(ptr::null_mut(), 0) };
+    let mut buf = vec![0; len + 1];
+    unsafe { ffi::wxd_IPC_GetLastError(buf.as_mut_ptr(), buf.len()) };
+    let mut message = unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() };
+    if message.is_empty() {
+        message = "unknown error".to_string();
+    }
+    match unsafe { ffi::wxd_IPC_GetLastErrorKind() } {
+        ffi::wxd_IPCError_WXD_IPC_ERROR_ADDRESS_IN_USE => IpcError::AddressInUse(message),
+        ffi::wxd_IPCError_WXD_IPC_ERROR_CONNECTION_REFUSED => IpcError::ConnectionRefused(message),
+        ffi::wxd_IPCError_WXD_IPC_ERROR_TIMEOUT => IpcError::Timeout(message),
+        ffi::wxd_IPCError_WXD_IPC_ERROR_DISCONNECTED => IpcError::PeerDisconnected,
+        _ => IpcError::Failed(message),
+    }
+}
+
+fn c_name(name: &str) -> Result<CString, IpcError> {
+    CString::new(name).map_err(|_| IpcError::InvalidName(name.to_string()))
+}
+
+fn check_payload(data: &[u8]) -> Result<(), IpcError> {
+    if data.len() > MAX_PAYLOAD_SIZE {
+        return Err(IpcError::PayloadTooLarge {
+            size: data.len(),
+            max: MAX_PAYLOAD_SIZE,
+        });
+    }
+    Ok(())
+}
+
 // =============================================================================
 // Connection Callbacks - stored in a Box and passed to C++
 // =============================================================================
@@ -101,8 +201,20 @@ type AdviseDataCallback = Box<dyn FnMut(&str, &str, &[u8], IPCFormat) -> bool>;
 type DisconnectCallback = Box<dyn FnMut() -> bool>;
 type AcceptConnectionCallback = Box<dyn FnMut(&str) -> Option<IPCConnection>>;
 
+/// State of a connection shared by its callbacks and all handles to it.
+#[derive(Default)]
+struct ConnectionState {
+    /// Cleared when the C++ connection is destroyed, e.g. after the other side disconnected
+    alive: Cell<bool>,
+    /// Items the other side started an advise loop for
+    advised_items: RefCell<HashSet<String>>,
+    /// Application data attached with `IPCConnection::set_user_data`
+    user_data: RefCell<Option<Box<dyn Any>>>,
+}
+
 /// Internal structure holding all connection callbacks.
 struct ConnectionCallbacks {
+    state: Rc<ConnectionState>,
     on_execute: Option<ExecuteCallback>,
     on_request: Option<RequestCallback>,
     on_poke: Option<PokeCallback>,
@@ -117,6 +229,10 @@ struct ConnectionCallbacks {
 impl ConnectionCallbacks {
     fn new() -> Self {
         Self {
+            state: Rc::new(ConnectionState {
+                alive: Cell::new(true),
+                advised_items: RefCell::default(),
+            }),
             on_execute: None,
             on_request: None,
             on_poke: None,
@@ -129,6 +245,13 @@ impl ConnectionCallbacks {
     }
 }
 
+// Freed by the C++ connection when it is destroyed
+impl Drop for ConnectionCallbacks {
+    fn drop(&mut self) {
+        self.state.alive.set(false);
+    }
+}
+
 // =============================================================================
 // C Callback Trampolines
 // =============================================================================
@@ -255,7 +378,11 @@ unsafe extern "C" fn on_start_advise_trampoline(
         } else {
             CStr::from_ptr(item).to_str().unwrap_or("")
         };
-        return cb(topic_str, item_str);
+        let accepted = cb(topic_str, item_str);
+        if accepted {
+            callbacks.state.advised_items.borrow_mut().insert(item_str.to_string());
+        }
+        return accepted;
     }
     false
 }
@@ -270,20 +397,22 @@ unsafe extern "C" fn on_stop_advise_trampoline(
         return false;
     }
     let callbacks = &mut *(user_data as *mut ConnectionCallbacks);
-    if let Some(ref mut cb) = callbacks.on_stop_advise {
-        let topic_str = if topic.is_null() {
-            ""
-        } else {
-            CStr::from_ptr(topic).to_str().unwrap_or("")
-        };
-        let item_str = if item.is_null() {
-            ""
-        } else {
-            CStr::from_ptr(item).to_str().unwrap_or("")
-        };
-        return cb(topic_str, item_str);
+    let topic_str = if topic.is_null() {
+        ""
+    } else {
+        CStr::from_ptr(topic).to_str().unwrap_or("")
+    };
+    let item_str = if item.is_null() {
+        ""
+    } else {
+        CStr::from_ptr(item).to_str().unwrap_or("")
+    };
+    // The advise loop ends whatever the callback says; it only reports success
+    callbacks.state.advised_items.borrow_mut().remove(item_str);
+    match callbacks.on_stop_advise {
+        Some(ref mut cb) => cb(topic_str, item_str),
+        None => true,
     }
-    false
 }
 
 #[allow(unsafe_op_in_unsafe_fn)]
@@ -348,10 +477,14 @@ unsafe extern "C" fn free_connection_callbacks(user_data: *mut c_void) {
 /// Connections are used to exchange data using Execute, Request, Poke, and Advise
 /// operations. The connection can be created by the server (in OnAcceptConnection)
 /// or returned from a client's MakeConnection call.
+///
+/// A connection is destroyed once the other side disconnects; from then on all methods
+/// return `false` or `None`. Use [`is_alive`](Self::is_alive) to prune kept connections.
 pub struct IPCConnection {
     ptr: *mut ffi::wxd_IPCConnection_t,
     /// Whether we own the pointer and should destroy it
     owned: bool,
+    state: Rc<ConnectionState>,
 }
 
 impl IPCConnection {
@@ -360,23 +493,73 @@ impl IPCConnection {
         IPCConnectionBuilder::new()
     }
 
-    /// Create a connection from a raw pointer (used internally).
-    ///
-    /// # Safety
-    /// The pointer must be valid and the caller transfers ownership.
-    #[allow(dead_code)]
-    pub(crate) unsafe fn from_ptr(ptr: *mut ffi::wxd_IPCConnection_t) -> Option<Self> {
+    /// Get the raw pointer (for internal use).
+    /// Returns null once the connection has been destroyed.
+    pub(crate) fn as_ptr(&self) -> *mut ffi::wxd_IPCConnection_t {
+        if self.state.alive.get() { self.ptr } else { ptr::null_mut() }
+    }
+
+    fn live_ptr(&self) -> Result<*mut ffi::wxd_IPCConnection_t, IpcError> {
+        let ptr = self.as_ptr();
         if ptr.is_null() {
-            None
+            Err(IpcError::PeerDisconnected)
         } else {
-            Some(Self { ptr, owned: false })
+            Ok(ptr)
         }
     }
 
-    /// Get the raw pointer (for internal use).
-    #[allow(dead_code)]
-    pub(crate) fn as_ptr(&self) -> *mut ffi::wxd_IPCConnection_t {
-        self.ptr
+    /// Returns another handle to this connection, which doesn't own it.
+    ///
+    /// A server keeps handles to the connections it returns from OnAcceptConnection to
+    /// [`advise`](Self::advise) them later; [`IPCServer::advise`] does so for all of them.
+    pub fn share(&self) -> IPCConnection {
+        IPCConnection {
+            ptr: self.ptr,
+            owned: false,
+            state: self.state.clone(),
+        }
+    }
+
+    /// Returns false once the connection has been destroyed, e.g. because the other side
+    /// disconnected.
+    pub fn is_alive(&self) -> bool {
+        !self.ptr.is_null() && self.state.alive.get()
+    }
+
+    /// Get the topic the connection was made for.
+    ///
+    /// Returns None if the connection has been destroyed.
+    pub fn topic(&self) -> Option<String> {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let len = unsafe { ffi::wxd_IPCConnection_GetTopic(ptr, ptr::null_mut(), 0) };
+        let mut buf = vec![0; len + 1];
+        unsafe { ffi::wxd_IPCConnection_GetTopic(ptr, buf.as_mut_ptr(), buf.len()) };
+        Some(unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() })
+    }
+
+    /// Attach application data to the connection, replacing any previous data.
+    ///
+    /// The data is shared by all handles to the connection, so a server can tell the
+    /// connections returned by [`IPCServer::connections`] apart.
+    pub fn set_user_data<T: 'static>(&self, data: T) {
+        *self.state.user_data.borrow_mut() = Some(Box::new(data));
+    }
+
+    /// Call `f` with the data attached by [`set_user_data`](Self::set_user_data).
+    ///
+    /// Returns None if no data of type `T` is attached. The data stays available after
+    /// the connection has been destroyed.
+    pub fn with_user_data<T: 'static, R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let mut data = self.state.user_data.borrow_mut();
+        data.as_mut()?.downcast_mut::<T>().map(f)
+    }
+
+    /// Returns true if the other side started an advise loop for `item` (server-side).
+    pub fn is_advising(&self, item: &str) -> bool {
+        self.is_alive() && self.state.advised_items.borrow().contains(item)
     }
 
     /// Execute a command on the remote side.
@@ -384,127 +567,184 @@ impl IPCConnection {
     /// On the server side, this triggers the client's OnExecute callback.
     /// On the client side, this triggers the server's OnExecute callback.
     pub fn execute(&self, data: &[u8], format: IPCFormat) -> bool {
-        if self.ptr.is_null() {
-            return false;
+        self.try_execute(data, format).is_ok()
+    }
+
+    /// Like [`execute`](Self::execute), but returns why it failed.
+    pub fn try_execute(&self, data: &[u8], format: IPCFormat) -> Result<(), IpcError> {
+        let ptr = self.live_ptr()?;
+        check_payload(data)?;
+        if unsafe { ffi::wxd_IPCConnection_Execute(ptr, data.as_ptr() as *const c_void, data.len(), format.into()) } {
+            Ok(())
+        } else {
+            Err(last_error())
         }
-        unsafe { ffi::wxd_IPCConnection_Execute(self.ptr, data.as_ptr() as *const c_void, data.len(), format.into()) }
     }
 
     /// Execute a string command (convenience method for text data).
     pub fn execute_string(&self, data: &str) -> bool {
-        if self.ptr.is_null() {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
             return false;
         }
         let c_str = match CString::new(data) {
             Ok(s) => s,
             Err(_) => return false,
         };
-        unsafe { ffi::wxd_IPCConnection_ExecuteString(self.ptr, c_str.as_ptr()) }
+        unsafe { ffi::wxd_IPCConnection_ExecuteString(ptr, c_str.as_ptr()) }
     }
 
-    /// Request data from the remote side.
+    /// Request the current value of an item from the remote side (client-side).
     ///
-    /// Returns the data if the request was successful, None otherwise.
+    /// Blocks until the server's OnRequest callback answers. Returns None if the server
+    /// has no value for the item, the peer has disconnected or it did not answer within
+    /// the timeout (see [`set_timeout`](Self::set_timeout)).
     pub fn request(&self, item: &str, format: IPCFormat) -> Option<Vec<u8>> {
-        if self.ptr.is_null() {
-            return None;
-        }
-        let c_item = CString::new(item).ok()?;
+        self.try_request(item, format).ok()
+    }
+
+    /// Like [`request`](Self::request), but returns why it failed.
+    pub fn try_request(&self, item: &str, format: IPCFormat) -> Result<Vec<u8>, IpcError> {
+        let ptr = self.live_ptr()?;
+        let c_item = c_name(item)?;
         let mut size: usize = 0;
-        let data_ptr = unsafe { ffi::wxd_IPCConnection_Request(self.ptr, c_item.as_ptr(), &mut size, format.into()) };
-        if data_ptr.is_null() || size == 0 {
-            return None;
+        let data_ptr = unsafe { ffi::wxd_IPCConnection_Request(ptr, c_item.as_ptr(), &mut size, format.into()) };
+        if data_ptr.is_null() {
+            return Err(last_error());
+        }
+        if size == 0 {
+            return Ok(Vec::new());
         }
         let data_slice = unsafe { std::slice::from_raw_parts(data_ptr as *const u8, size) };
-        Some(data_slice.to_vec())
+        Ok(data_slice.to_vec())
     }
 
-    /// Poke data to the remote side.
+    /// Set the value of an item on the remote side (client-side).
+    ///
+    /// What `true` means depends on the transport: with DDE (Windows) it is the result of the
+    /// server's OnPoke callback. With the TCP/Unix socket transport the server sends no
+    /// acknowledgement, so it only means the data was written; a server refusing the poke
+    /// goes unnoticed. Returns false if the peer has disconnected, or with DDE did not answer
+    /// within the timeout.
     pub fn poke(&self, item: &str, data: &[u8], format: IPCFormat) -> bool {
-        if self.ptr.is_null() {
-            return false;
-        }
-        let c_item = match CString::new(item) {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
-        unsafe {
+        self.try_poke(item, data, format).is_ok()
+    }
+
+    /// Like [`poke`](Self::poke), but returns why it failed.
+    pub fn try_poke(&self, item: &str, data: &[u8], format: IPCFormat) -> Result<(), IpcError> {
+        let ptr = self.live_ptr()?;
+        let c_item = c_name(item)?;
+        check_payload(data)?;
+        let poked = unsafe {
             ffi::wxd_IPCConnection_Poke(
-                self.ptr,
+                ptr,
                 c_item.as_ptr(),
                 data.as_ptr() as *const c_void,
                 data.len(),
                 format.into(),
             )
+        };
+        if poked { Ok(()) } else { Err(last_error()) }
+    }
+
+    /// Set how long blocking operations such as [`request`](Self::request) and
+    /// [`poke`](Self::poke) wait for the peer before failing.
+    ///
+    /// Client connections default to [`DEFAULT_TIMEOUT`]. The timeout is rounded up to
+    /// whole seconds. Returns false if the connection is gone or the transport has a fixed
+    /// timeout (DDE on Windows).
+    pub fn set_timeout(&self, timeout: Duration) -> bool {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
+            return false;
         }
+        let seconds = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+        let seconds = seconds.clamp(1, i32::MAX as u64) as std::os::raw::c_long;
+        unsafe { ffi::wxd_IPCConnection_SetTimeout(ptr, seconds) }
     }
 
-    /// Start an advise loop for the given item.
+    /// Start an advise loop for the given item (client-side).
     ///
-    /// The server will send updates via the OnAdvise callback when the item changes.
+    /// The server accepts or refuses it in its OnStartAdvise callback; once accepted, its
+    /// updates of the item arrive in the OnAdvise callback.
     pub fn start_advise(&self, item: &str) -> bool {
-        if self.ptr.is_null() {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
             return false;
         }
         let c_item = match CString::new(item) {
             Ok(s) => s,
             Err(_) => return false,
         };
-        unsafe { ffi::wxd_IPCConnection_StartAdvise(self.ptr, c_item.as_ptr()) }
+        unsafe { ffi::wxd_IPCConnection_StartAdvise(ptr, c_item.as_ptr()) }
     }
 
-    /// Stop an advise loop for the given item.
+    /// Stop an advise loop for the given item (client-side).
     pub fn stop_advise(&self, item: &str) -> bool {
-        if self.ptr.is_null() {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
             return false;
         }
         let c_item = match CString::new(item) {
             Ok(s) => s,
             Err(_) => return false,
         };
-        unsafe { ffi::wxd_IPCConnection_StopAdvise(self.ptr, c_item.as_ptr()) }
+        unsafe { ffi::wxd_IPCConnection_StopAdvise(ptr, c_item.as_ptr()) }
     }
 
     /// Send advised data to the client (server-side only).
+    ///
+    /// Only clients that started an advise loop for `item` are sure to get it, see
+    /// [`is_advising`](Self::is_advising). Returns false if the client is gone.
     pub fn advise(&self, item: &str, data: &[u8], format: IPCFormat) -> bool {
-        if self.ptr.is_null() {
-            return false;
-        }
-        let c_item = match CString::new(item) {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
-        unsafe {
+        self.try_advise(item, data, format).is_ok()
+    }
+
+    /// Like [`advise`](Self::advise), but returns why it failed.
+    pub fn try_advise(&self, item: &str, data: &[u8], format: IPCFormat) -> Result<(), IpcError> {
+        let ptr = self.live_ptr()?;
+        let c_item = c_name(item)?;
+        check_payload(data)?;
+        let advised = unsafe {
             ffi::wxd_IPCConnection_Advise(
-                self.ptr,
+                ptr,
                 c_item.as_ptr(),
                 data.as_ptr() as *const c_void,
                 data.len(),
                 format.into(),
             )
-        }
+        };
+        if advised { Ok(()) } else { Err(last_error()) }
     }
 
     /// Disconnect the connection.
+    ///
+    /// Works from either side, e.g. for a server to drop a client. The OnDisconnect callbacks
+    /// of both sides run, after which the connection is destroyed unless this side's callback
+    /// returned false. Called from one of this connection's own callbacks, e.g. to drop a
+    /// client from `on_execute`, the connection closes right away but this side's
+    /// OnDisconnect callback and the destruction wait until the event loop runs again.
     pub fn disconnect(&self) -> bool {
-        if self.ptr.is_null() {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
             return false;
         }
-        unsafe { ffi::wxd_IPCConnection_Disconnect(self.ptr) }
+        unsafe { ffi::wxd_IPCConnection_Disconnect(ptr) }
     }
 
     /// Check if the connection is still connected.
     pub fn is_connected(&self) -> bool {
-        if self.ptr.is_null() {
+        let ptr = self.as_ptr();
+        if ptr.is_null() {
             return false;
         }
-        unsafe { ffi::wxd_IPCConnection_IsConnected(self.ptr) }
+        unsafe { ffi::wxd_IPCConnection_IsConnected(ptr) }
     }
 }
 
 impl Drop for IPCConnection {
     fn drop(&mut self) {
-        if self.owned && !self.ptr.is_null() {
+        if self.owned && self.is_alive() {
             unsafe { ffi::wxd_IPCConnection_Destroy(self.ptr) };
         }
     }
@@ -533,6 +773,9 @@ impl IPCConnectionBuilder {
     }
 
     /// Set the OnRequest callback (server-side: called when client requests data).
+    ///
+    /// Return the current value of the item, or None if there is none; the client's
+    /// [`IPCConnection::request`] then returns None.
     pub fn on_request<F>(mut self, callback: F) -> Self
     where
         F: FnMut(&str, &str, IPCFormat) -> Option<Vec<u8>> + 'static,
@@ -542,6 +785,9 @@ impl IPCConnectionBuilder {
     }
 
     /// Set the OnPoke callback (server-side: called when client pokes data).
+    ///
+    /// Return true if the item was set; the value is what the client's
+    /// [`IPCConnection::poke`] returns. Without this callback pokes are refused.
     pub fn on_poke<F>(mut self, callback: F) -> Self
     where
         F: FnMut(&str, &str, &[u8], IPCFormat) -> bool + 'static,
@@ -551,6 +797,9 @@ impl IPCConnectionBuilder {
     }
 
     /// Set the OnStartAdvise callback (server-side: called when client starts advise).
+    ///
+    /// Return true to accept the subscription to the item. Without this callback all
+    /// subscriptions are refused.
     pub fn on_start_advise<F>(mut self, callback: F) -> Self
     where
         F: FnMut(&str, &str) -> bool + 'static,
@@ -560,6 +809,8 @@ impl IPCConnectionBuilder {
     }
 
     /// Set the OnStopAdvise callback (server-side: called when client stops advise).
+    ///
+    /// The subscription ends whatever the callback returns; without it stopping succeeds.
     pub fn on_stop_advise<F>(mut self, callback: F) -> Self
     where
         F: FnMut(&str, &str) -> bool + 'static,
@@ -588,6 +839,7 @@ impl IPCConnectionBuilder {
 
     /// Build the connection.
     pub fn build(self) -> IPCConnection {
+        let state = self.callbacks.state.clone();
         let callbacks_box = Box::new(self.callbacks);
         let user_data = Box::into_raw(callbacks_box) as *mut c_void;
 
@@ -605,7 +857,7 @@ impl IPCConnectionBuilder {
             )
         };
 
-        IPCConnection { ptr, owned: true }
+        IPCConnection { ptr, owned: true, state }
     }
 }
 
@@ -622,6 +874,8 @@ impl Default for IPCConnectionBuilder {
 /// Callback data for the server's OnAcceptConnection.
 struct ServerCallbacks {
     on_accept: AcceptConnectionCallback,
+    /// Handles to the accepted connections, shared with the IPCServer
+    connections: Rc<RefCell<Vec<IPCConnection>>>,
 }
 
 #[allow(unsafe_op_in_unsafe_fn)]
@@ -638,10 +892,15 @@ unsafe extern "C" fn on_accept_connection_trampoline(
     } else {
         CStr::from_ptr(topic).to_str().unwrap_or("")
     };
-    if let Some(conn) = (callbacks.on_accept)(topic_str) {
+    if let Some(mut conn) = (callbacks.on_accept)(topic_str) {
         // Transfer ownership to C++ - it will manage the connection
-        let ptr = conn.ptr;
-        std::mem::forget(conn);
+        conn.owned = false;
+        let ptr = conn.as_ptr();
+        if !ptr.is_null() {
+            let mut connections = callbacks.connections.borrow_mut();
+            connections.retain(IPCConnection::is_alive);
+            connections.push(conn);
+        }
         ptr
     } else {
         ptr::null_mut()
@@ -684,6 +943,7 @@ unsafe extern "C" fn free_server_callbacks(user_data: *mut c_void) {
 /// ```
 pub struct IPCServer {
     ptr: *mut ffi::wxd_IPCServer_t,
+    connections: Rc<RefCell<Vec<IPCConnection>>>,
 }
 
 impl IPCServer {
@@ -695,15 +955,17 @@ impl IPCServer {
     where
         F: FnMut(&str) -> Option<IPCConnection> + 'static,
     {
+        let connections = Rc::new(RefCell::new(Vec::new()));
         let callbacks = ServerCallbacks {
             on_accept: Box::new(on_accept_connection),
+            connections: connections.clone(),
         };
         let user_data = Box::into_raw(Box::new(callbacks)) as *mut c_void;
 
         let ptr =
             unsafe { ffi::wxd_IPCServer_Create(user_data, Some(on_accept_connection_trampoline), Some(free_server_callbacks)) };
 
-        Self { ptr }
+        Self { ptr, connections }
     }
 
     /// Start the server listening on the given service.
@@ -711,14 +973,43 @@ impl IPCServer {
     /// The service can be a port number (e.g., "4242") or a Unix socket path.
     /// Returns true if the server started successfully.
     pub fn create(&self, service: &str) -> bool {
+        self.try_create(service).is_ok()
+    }
+
+    /// Like [`create`](Self::create), but returns why it failed, e.g.
+    /// [`IpcError::AddressInUse`] if another server already listens on the service.
+    pub fn try_create(&self, service: &str) -> Result<(), IpcError> {
         if self.ptr.is_null() {
-            return false;
+            return Err(IpcError::Failed("the server was not created".to_string()));
         }
-        let c_service = match CString::new(service) {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
-        unsafe { ffi::wxd_IPCServer_Create_Service(self.ptr, c_service.as_ptr()) }
+        let c_service = c_name(service)?;
+        if unsafe { ffi::wxd_IPCServer_Create_Service(self.ptr, c_service.as_ptr()) } {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Returns handles to the accepted connections that are still alive, oldest first.
+    ///
+    /// The handles don't own their connections; use [`IPCConnection::topic`] and
+    /// [`IPCConnection::with_user_data`] to identify a client and
+    /// [`IPCConnection::disconnect`] to drop it.
+    pub fn connections(&self) -> Vec<IPCConnection> {
+        let mut connections = self.connections.borrow_mut();
+        connections.retain(IPCConnection::is_alive);
+        connections.iter().map(IPCConnection::share).collect()
+    }
+
+    /// Sends advised data to every connected client that started an advise loop for `item`,
+    /// skipping clients that disconnected.
+    ///
+    /// Returns the number of clients the data was sent to.
+    pub fn advise(&self, item: &str, data: &[u8], format: IPCFormat) -> usize {
+        self.connections()
+            .iter()
+            .filter(|conn| conn.is_advising(item) && conn.advise(item, data, format))
+            .count()
     }
 }
 
@@ -769,11 +1060,29 @@ pub struct IPCClient {
 
 impl IPCClient {
     /// Create a new IPC client.
+    ///
+    /// Connecting waits as long as the platform does; see
+    /// [`set_connect_timeout`](Self::set_connect_timeout) to bound it.
     pub fn new() -> Self {
         let ptr = unsafe { ffi::wxd_IPCClient_Create() };
         Self { ptr }
     }
 
+    /// Set how long connecting to a TCP server may take before it fails with
+    /// [`IpcError::Timeout`], or `None` (the default) to wait as long as the platform does,
+    /// which can be minutes for an unreachable host.
+    ///
+    /// With a timeout the server is checked with a separate connection first, which it
+    /// accepts and closes right away, so servers see one extra connection per connect.
+    /// Unix domain sockets and DDE connect without delay and ignore the timeout.
+    pub fn set_connect_timeout(&self, timeout: Option<Duration>) {
+        if self.ptr.is_null() {
+            return;
+        }
+        let millis = timeout.map_or(0, |timeout| timeout.as_millis().clamp(1, i32::MAX as u128));
+        unsafe { ffi::wxd_IPCClient_SetConnectTimeout(self.ptr, millis as std::os::raw::c_long) };
+    }
+
     /// Connect to a server.
     ///
     /// # Arguments
@@ -784,7 +1093,13 @@ impl IPCClient {
     ///
     /// Returns Some(IPCConnection) if the connection was successful, None otherwise.
     pub fn make_connection(&self, host: &str, service: &str, topic: &str) -> Option<IPCConnection> {
-        self.make_connection_with_callbacks(host, service, topic, IPCConnectionBuilder::new())
+        self.try_make_connection(host, service, topic).ok()
+    }
+
+    /// Like [`make_connection`](Self::make_connection), but returns why it failed, e.g.
+    /// [`IpcError::ConnectionRefused`] if no server is running.
+    pub fn try_make_connection(&self, host: &str, service: &str, topic: &str) -> Result<IPCConnection, IpcError> {
+        self.try_make_connection_with_callbacks(host, service, topic, IPCConnectionBuilder::new())
     }
 
     /// Connect to a server with custom callbacks.
@@ -798,14 +1113,27 @@ impl IPCClient {
         topic: &str,
         builder: IPCConnectionBuilder,
     ) -> Option<IPCConnection> {
+        self.try_make_connection_with_callbacks(host, service, topic, builder).ok()
+    }
+
+    /// Like [`make_connection_with_callbacks`](Self::make_connection_with_callbacks), but
+    /// returns why it failed.
+    pub fn try_make_connection_with_callbacks(
+        &self,
+        host: &str,
+        service: &str,
+        topic: &str,
+        builder: IPCConnectionBuilder,
+    ) -> Result<IPCConnection, IpcError> {
         if self.ptr.is_null() {
-            return None;
+            return Err(IpcError::Failed("the client was not created".to_string()));
         }
 
-        let c_host = CString::new(host).ok()?;
-        let c_service = CString::new(service).ok()?;
-        let c_topic = CString::new(topic).ok()?;
+        let c_host = c_name(host)?;
+        let c_service = c_name(service)?;
+        let c_topic = c_name(topic)?;
 
+        let state = builder.callbacks.state.clone();
         let callbacks_box = Box::new(builder.callbacks);
         let user_data = Box::into_raw(callbacks_box) as *mut c_void;
 
@@ -828,12 +1156,16 @@ impl IPCClient {
         };
 
         if conn_ptr.is_null() {
-            None
+            Err(last_error())
         } else {
-            Some(IPCConnection {
+            let conn = IPCConnection {
                 ptr: conn_ptr,
                 owned: false, // Owned by the wxWidgets system
-            })
+                state,
+            };
+            // The transport's own timeout is minutes long, too long to block the UI on
+            conn.set_timeout(DEFAULT_TIMEOUT);
+            Ok(conn)
         }
     }
 }
@@ -851,3 +1183,153 @@ impl Drop for IPCClient {
         }
     }
 }
+
+// =============================================================================
+// JSON Payloads (serde feature)
+// =============================================================================
+
+/// Format JSON payloads are sent with, so peers that expect text don't take them for it.
+#[cfg(feature = "serde")]
+const JSON_FORMAT: IPCFormat = IPCFormat::Private;
+
+#[cfg(feature = "serde")]
+fn encode_json<T: serde::Serialize>(value: &T) -> Option<Vec<u8>> {
+    match serde_json::to_vec(value) {
+        Ok(data) => Some(data),
+        Err(err) => {
+            log::warn!("Failed to serialize IPC JSON payload: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn decode_json<T: serde::de::DeserializeOwned>(data: &[u8]) -> Option<T> {
+    match serde_json::from_slice(data) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            log::warn!("Rejected malformed IPC JSON payload: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl IPCConnection {
+    /// Execute a command on the remote side with `value` serialized as JSON.
+    ///
+    /// The remote side receives it in its [`IPCConnectionBuilder::on_execute_json`]
+    /// callback. Returns false if serialization fails or the execute is refused.
+    pub fn execute_json<T: serde::Serialize>(&self, value: &T) -> bool {
+        encode_json(value).is_some_and(|data| self.execute(&data, JSON_FORMAT))
+    }
+
+    /// Request an item from the remote side and deserialize the JSON answer.
+    ///
+    /// The server answers in its [`IPCConnectionBuilder::on_request_json`] callback.
+    /// Returns None if the request fails or the answer is not a valid `T`; fields of
+    /// the answer that `T` does not know are ignored.
+    pub fn request_json<T: serde::de::DeserializeOwned>(&self, item: &str) -> Option<T> {
+        self.request(item, JSON_FORMAT).and_then(|data| decode_json(&data))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl IPCConnectionBuilder {
+    /// Set the OnExecute callback for JSON commands sent with
+    /// [`IPCConnection::execute_json`].
+    ///
+    /// The payload is deserialized before the callback runs; payloads sent in another
+    /// format or that are not a valid `T` are refused without calling it. Replaces any
+    /// callback set with [`on_execute`](Self::on_execute).
+    pub fn on_execute_json<T, F>(self, mut callback: F) -> Self
+    where
+        T: serde::de::DeserializeOwned,
+        F: FnMut(&str, T) -> bool + 'static,
+    {
+        self.on_execute(move |topic, data, format| {
+            format == JSON_FORMAT && decode_json(data).is_some_and(|value| callback(topic, value))
+        })
+    }
+
+    /// Set the OnRequest callback answering [`IPCConnection::request_json`].
+    ///
+    /// The callback receives the topic and item; the value it returns is sent as JSON.
+    /// Requests for another format are refused without calling it. Replaces any
+    /// callback set with [`on_request`](Self::on_request).
+    pub fn on_request_json<T, F>(self, mut callback: F) -> Self
+    where
+        T: serde::Serialize,
+        F: FnMut(&str, &str) -> Option<T> + 'static,
+    {
+        self.on_request(move |topic, item, format| {
+            if format != JSON_FORMAT {
+                return None;
+            }
+            callback(topic, item).and_then(|value| encode_json(&value))
+        })
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct StatusV1 {
+        version: u32,
+        name: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct StatusV2 {
+        version: u32,
+        name: String,
+        #[serde(default)]
+        clients: Vec<String>,
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let status = StatusV1 {
+            version: 1,
+            name: "server".to_string(),
+        };
+        let data = encode_json(&status).unwrap();
+        assert_eq!(data, br#"{"version":1,"name":"server"}"#);
+        assert_eq!(decode_json::<StatusV1>(&data), Some(status));
+    }
+
+    #[test]
+    fn test_json_ignores_unknown_fields() {
+        let status = StatusV2 {
+            version: 2,
+            name: "server".to_string(),
+            clients: vec!["a".to_string(), "b".to_string()],
+        };
+        let data = encode_json(&status).unwrap();
+        let old = decode_json::<StatusV1>(&data).unwrap();
+        assert_eq!(old.version, 2);
+        assert_eq!(old.name, "server");
+    }
+
+    #[test]
+    fn test_json_accepts_older_payloads() {
+        let data = encode_json(&StatusV1 {
+            version: 1,
+            name: "server".to_string(),
+        })
+        .unwrap();
+        let status = decode_json::<StatusV2>(&data).unwrap();
+        assert!(status.clients.is_empty());
+    }
+
+    #[test]
+    fn test_json_rejects_malformed_payloads() {
+        assert_eq!(decode_json::<StatusV1>(b"not json"), None);
+        assert_eq!(decode_json::<StatusV1>(br#"{"version":"one","name":"server"}"#), None);
+        assert_eq!(decode_json::<StatusV1>(br#"{"version":1}"#), None);
+        assert_eq!(decode_json::<StatusV1>(b""), None);
+    }
+}
@@ -9,6 +9,8 @@ pub mod app;
 pub mod appearance;
 pub mod appprogress;
 pub mod art_provider;
+#[cfg(feature = "async")]
+pub mod async_support;
 pub mod bitmap;
 pub mod bitmap_bundle;
 pub mod clipboard;
@@ -25,6 +27,7 @@ pub mod font;
 pub mod font_data;
 pub mod geometry;
 pub mod id;
+pub mod image;
 pub mod ipc;
 pub mod language;
 pub mod menus;
@@ -34,12 +37,17 @@ pub mod scrollable;
 pub mod single_instance_checker;
 pub mod sizers;
 pub mod sound;
+pub mod stopwatch;
 pub mod sysopt;
+pub mod task;
+pub mod text;
+pub mod text_entry;
 pub mod timer;
 pub mod translations;
 pub mod types;
 pub mod uiactionsimulator;
 pub mod utils;
+pub mod validator;
 pub mod widgets;
 pub mod window;
 #[cfg(feature = "xrc")]
@@ -106,8 +106,9 @@ macro_rules! widget_builder {
                     $crate::__widget_builder_field_method!($field_name: $field_type);
                 )*
 
-                /// Builds the widget.
+                /// Builds the widget. Must be called on the main thread.
                 pub fn build(self) -> $name {
+                    $crate::app::assert_main_thread();
                     let build_fn = |$self_param: [<$name Builder>]<'a>| $build_impl;
                     build_fn(self)
                 }
@@ -511,6 +512,7 @@ macro_rules! custom_widget {
 
                 /// Builds the custom widget
                 pub fn build(self) -> $name {
+                    $crate::app::assert_main_thread();
                     let panel = $crate::widgets::panel::Panel::builder(self.parent)
                         .with_size(self.size)
                         .build();
@@ -532,6 +532,8 @@ pub struct MenuEventData {
     pub event: CommandEventData,
 }
 
+crate::impl_event_base!(MenuEventData => event.event);
+
 impl MenuEventData {
     pub fn new(event: Event) -> Self {
         Self {
@@ -2,6 +2,7 @@
 //! wxMenuItem wrapper and related types
 
 use crate::bitmap::Bitmap;
+use crate::bitmap_bundle::BitmapBundleArg;
 use crate::event::{Event, EventType, WxEvtHandler};
 use crate::menus::Menu;
 use crate::window::{Window, WindowHandle, WxWidget};
@@ -226,13 +227,16 @@ impl MenuItem {
         }
     }
 
-    /// Sets the bitmap for the menu item.
-    pub fn set_bitmap(&self, bitmap: &Bitmap) {
+    /// Sets the bitmap for the menu item, either a single `Bitmap` or a [`BitmapBundle`](crate::bitmap_bundle::BitmapBundle).
+    ///
+    /// On some platforms this must be called before the item is appended to a menu.
+    pub fn set_bitmap<'a>(&self, bitmap: impl Into<BitmapBundleArg<'a>>) {
         if self.ptr.is_null() {
             return;
         }
+        let bitmap = bitmap.into();
         unsafe {
-            ffi::wxd_MenuItem_SetBitmap(self.ptr, bitmap.as_const_ptr());
+            ffi::wxd_MenuItem_SetBitmap(self.ptr, bitmap.as_ptr());
         }
     }
 
@@ -1,30 +1,34 @@
 // --- Core Types & Traits ---
 #[cfg(target_os = "windows")]
 pub use crate::accessible::Accessible;
-pub use crate::app::{App, call_after, get_app, get_app_instance, main, set_appearance, set_top_window, wake_up_idle};
+pub use crate::app::{
+    App, CallAfterError, CallAfterHandle, EventLoop, assert_main_thread, call_after, call_after_with, get_app, get_app_instance,
+    is_main_thread, main, main_thread_id, set_appearance, set_top_window, wake_up_idle,
+};
 pub use crate::appearance::{
     AppAppearance, Appearance, AppearanceResult, SystemAppearance, get_app as get_app_for_appearance, get_system_appearance,
     is_system_dark_mode,
 };
-pub use crate::clipboard::{Clipboard, ClipboardLocker};
+pub use crate::clipboard::{Clipboard, ClipboardData, ClipboardFormat, ClipboardLocker};
 pub use crate::color::{Colour, colours};
 pub use crate::config::{Config, ConfigEntryType, ConfigPathGuard, ConfigStyle};
 pub use crate::cursor::{BitmapType, BusyCursor, Cursor, StockCursor, begin_busy_cursor, end_busy_cursor, is_busy, set_cursor};
 pub use crate::datetime::DateTime;
-pub use crate::event::{Event, EventType, IdleEvent, IdleMode, WindowEventData, WxEvtHandler};
+pub use crate::event::{Event, EventBase, EventScope, EventType, IdleEvent, IdleMode, WindowEventData, WxEvtHandler};
 // ADDED: Event category traits
-pub use crate::event::{AppEvents, ButtonEvents, MenuEvents, ScrollEvents, TextEvents, TreeEvents, WindowEvents};
+pub use crate::event::{AppEvents, BookCtrlEvents, ButtonEvents, MenuEvents, ScrollEvents, TextEvents, TreeEvents, WindowEvents};
 // ADDED: Event Data Structs
 pub use crate::event::event_data::{CommandEventData, KeyEventData, MouseEventData};
-pub use crate::event::{IdleEventData, MenuEventData};
+pub use crate::event::{BookCtrlEventData, IdleEventData, MenuEventData, ScrollEvent, ScrollEventType};
 pub use crate::geometry::{Point, Rect, Size};
 pub use crate::id::{ID_ANY, ID_APPLY, ID_CANCEL, ID_HELP, ID_HIGHEST, ID_NO, ID_OK, ID_YES, Id};
 pub use crate::language::Language;
 pub use crate::sizers::WxSizer;
 pub use crate::sound::{Sound, SoundFlags};
+pub use crate::stopwatch::StopWatch;
 pub use crate::sysopt::SystemOptions;
 pub use crate::types::Style;
-pub use crate::utils::{ArrayString, BrowserLaunchFlags, bell, launch_default_browser};
+pub use crate::utils::{ArrayString, BrowserLaunchFlags, SecretString, bell, launch_default_browser};
 pub use crate::window::{BackgroundStyle, ExtraWindowStyle, Window, WindowStyle, WxWidget, WxWidgetDowncast};
 
 // --- Sizers ---
@@ -44,7 +48,7 @@ pub use crate::sizers::base::{Orientation, SizerFlag};
 pub use crate::widgets::activity_indicator::{ActivityIndicator, ActivityIndicatorBuilder, ActivityIndicatorStyle}; // Added Style
 pub use crate::widgets::animation_ctrl::{AnimationCtrl, AnimationCtrlBuilder, AnimationCtrlStyle}; // Added Style
 #[cfg(feature = "aui")]
-pub use crate::widgets::aui_manager::{AuiManager, AuiPaneInfo, DockDirection};
+pub use crate::widgets::aui_manager::{AuiManager, AuiPane, AuiPaneInfo, DockDirection, persist_perspective};
 #[cfg(feature = "aui")]
 pub use crate::widgets::aui_mdi_child_frame::{AuiMdiChildFrame, AuiMdiChildFrameBuilder};
 #[cfg(feature = "aui")]
@@ -52,7 +56,7 @@ pub use crate::widgets::aui_mdi_parent_frame::{AuiMdiParentFrame, AuiMdiParentFr
 #[cfg(feature = "aui")]
 pub use crate::widgets::aui_notebook::{AuiNotebook, AuiNotebookBuilder, AuiNotebookStyle}; // Added Style
 #[cfg(feature = "aui")]
-pub use crate::widgets::aui_toolbar::{AuiToolBar, AuiToolBarBuilder, AuiToolBarStyle}; // Added Style
+pub use crate::widgets::aui_toolbar::{AuiToolBar, AuiToolBarBuilder, AuiToolBarEventData, AuiToolBarStyle}; // Added Style
 pub use crate::widgets::bitmap_button::{BitmapButton, BitmapButtonBuilder, BitmapButtonStyle}; // Added Style
 pub use crate::widgets::bitmap_combobox::{BitmapComboBox, BitmapComboBoxBuilder}; // Style is ComboBoxStyle
 pub use crate::widgets::bitmaptogglebutton::{BitmapToggleButton, BitmapToggleButtonBuilder, BitmapToggleButtonStyle};
@@ -61,8 +65,10 @@ pub use crate::widgets::calendar_ctrl::{CalendarCtrl, CalendarCtrlBuilder, Calen
 pub use crate::widgets::checkbox::{CheckBox, CheckBoxBuilder, CheckBoxStyle};
 pub use crate::widgets::checklistbox::{CheckListBox, CheckListBoxBuilder, CheckListBoxStyle}; // Added Style
 pub use crate::widgets::choice::{Choice, ChoiceBuilder, ChoiceStyle};
+pub use crate::widgets::choicebook::{Choicebook, ChoicebookBuilder, ChoicebookStyle};
 pub use crate::widgets::collapsible_pane::{CollapsiblePane, CollapsiblePaneBuilder, CollapsiblePaneStyle};
 pub use crate::widgets::colour_picker_ctrl::{ColourPickerCtrl, ColourPickerCtrlBuilder, ColourPickerCtrlStyle};
+pub use crate::widgets::combo_ctrl::{ComboCtrl, ComboCtrlBuilder, ComboCtrlEvent, ComboCtrlEventData, ComboCtrlStyle};
 pub use crate::widgets::combobox::{ComboBox, ComboBoxBuilder, ComboBoxStyle};
 pub use crate::widgets::command_link_button::{CommandLinkButton, CommandLinkButtonBuilder, CommandLinkButtonStyle}; // Added Style
 
@@ -109,6 +115,7 @@ pub use crate::widgets::grid::{
     TabBehaviour,
 };
 pub use crate::widgets::hyperlink_ctrl::{HyperlinkCtrl, HyperlinkCtrlBuilder, HyperlinkCtrlStyle};
+pub use crate::widgets::infobar::{InfoBar, InfoBarIcon};
 // ADDED: ImageList
 pub use crate::widgets::imagelist::ImageList;
 // ADDED: ItemData trait
@@ -118,18 +125,20 @@ pub use crate::widgets::list_ctrl::{
     ListCtrl,
     ListCtrlBuilder,
     ListCtrlStyle,
+    ListItemAttr,
     ListItemState,
     ListNextItemFlag,
     // Events for ListCtrl are now in list_ctrl/event.rs, re-exported from list_ctrl/mod.rs
 }; // Added Events
 
 pub use crate::widgets::list_ctrl::image_list_type;
+pub use crate::widgets::listbook::{Listbook, ListbookBuilder, ListbookStyle};
 pub use crate::widgets::listbox::{ListBox, ListBoxBuilder, ListBoxStyle};
 pub use crate::widgets::mdi_child_frame::{MDIChildFrame, MDIChildFrameBuilder};
 pub use crate::widgets::mdi_parent_frame::{MDIParentFrame, MDIParentFrameBuilder};
 #[cfg(feature = "media-ctrl")]
 pub use crate::widgets::media_ctrl::{MediaCtrl, MediaCtrlBuilder, MediaCtrlPlayerControls, MediaState};
-pub use crate::widgets::notebook::{Notebook, NotebookBuilder, NotebookStyle};
+pub use crate::widgets::notebook::{Notebook, NotebookBuilder, NotebookHitTestFlags, NotebookStyle};
 pub use crate::widgets::notification_message::{
     NotificationMessage,
     NotificationMessageBuilder,
@@ -137,6 +146,7 @@ pub use crate::widgets::notification_message::{
     // Events for NotificationMessage are now in notification_message/event.rs, re-exported from notification_message/mod.rs
     TIMEOUT_AUTO,
     TIMEOUT_NEVER,
+    Timeout,
 }; // Added Events
 pub use crate::widgets::panel::{Panel, PanelBuilder, PanelStyle};
 pub use crate::widgets::property_grid::{
@@ -146,14 +156,18 @@ pub use crate::widgets::property_grid::{
 pub use crate::widgets::radio_button::{RadioButton, RadioButtonBuilder, RadioButtonStyle};
 pub use crate::widgets::radiobox::{RadioBox, RadioBoxBuilder, RadioBoxStyle};
 // Added RearrangeList
-pub use crate::widgets::rearrangelist::{RearrangeList, RearrangeListBuilder, RearrangeListStyle};
+pub use crate::widgets::rearrangelist::{
+    RearrangeCtrl, RearrangeCtrlBuilder, RearrangeCtrlEvent, RearrangeList, RearrangeListBuilder, RearrangeListStyle,
+};
 #[cfg(feature = "richtext")]
 pub use crate::widgets::richtextctrl::{
-    RichTextCtrl, RichTextCtrlBuilder, RichTextCtrlEvent, RichTextCtrlEventData, RichTextCtrlStyle, RichTextFileType,
+    RichTextAlignment, RichTextCtrl, RichTextCtrlBuilder, RichTextCtrlEvent, RichTextCtrlEventData, RichTextCtrlStyle,
+    RichTextFileType,
 };
 pub use crate::widgets::scrollbar::{ScrollBar, ScrollBarBuilder, ScrollBarStyle};
 pub use crate::widgets::scrolled_window::{ScrolledWindow, ScrolledWindowBuilder, ScrolledWindowStyle}; // Added Style
 pub use crate::widgets::search_ctrl::{SearchCtrl, SearchCtrlBuilder, SearchCtrlStyle};
+pub use crate::widgets::simplebook::{ShowEffect, SimpleBook, SimpleBookBuilder, SimpleBookStyle};
 pub use crate::widgets::slider::{Slider, SliderBuilder, SliderStyle};
 pub use crate::widgets::spinbutton::{SpinButton, SpinButtonBuilder, SpinButtonStyle};
 pub use crate::widgets::spinctrl::{SpinCtrl, SpinCtrlBuilder, SpinCtrlStyle};
@@ -168,17 +182,18 @@ pub use crate::widgets::static_bitmap::{ScaleMode, StaticBitmap, StaticBitmapBui
 pub use crate::widgets::static_line::{StaticLine, StaticLineBuilder, StaticLineStyle};
 pub use crate::widgets::static_text::{StaticText, StaticTextBuilder, StaticTextStyle};
 pub use crate::widgets::staticbox::{StaticBox, StaticBoxBuilder, StaticBoxStyle}; // Added Style
-pub use crate::widgets::statusbar::{StatusBar, StatusBarBuilder};
+pub use crate::widgets::statusbar::{StatusBar, StatusBarBuilder, StatusBarFieldStyle};
 #[cfg(feature = "stc")]
 pub use crate::widgets::styledtextctrl::{
     EolMode, FindFlags, Lexer, MarginType, MarkerSymbol, SelectionMode, StyledTextCtrl, StyledTextCtrlBuilder,
     StyledTextCtrlEvent, StyledTextCtrlEventData, StyledTextCtrlStyle, WhiteSpaceView, WrapMode,
 };
 pub use crate::widgets::taskbar_icon::{TaskBarIcon, TaskBarIconBuilder, TaskBarIconStyle, TaskBarIconType};
-pub use crate::widgets::textctrl::{TextCtrl, TextCtrlBuilder, TextCtrlStyle};
+pub use crate::widgets::textctrl::{TextAttr, TextCtrl, TextCtrlBuilder, TextCtrlStyle};
 pub use crate::widgets::time_picker_ctrl::{TimePickerCtrl, TimePickerCtrlBuilder, TimePickerCtrlStyle};
 pub use crate::widgets::togglebutton::{ToggleButton, ToggleButtonBuilder, ToggleButtonStyle};
-pub use crate::widgets::toolbar::{ToolBar, ToolBarStyle}; // Added Style
+pub use crate::widgets::toolbar::{RemovedTool, ToolBar, ToolBarBuilder, ToolBarEventData, ToolBarStyle}; // Added Style
+pub use crate::widgets::toolbook::{Toolbook, ToolbookBuilder, ToolbookStyle};
 pub use crate::widgets::treebook::{Treebook, TreebookBuilder, TreebookStyle}; // Added Style
 pub use crate::widgets::treectrl::{TreeCtrl, TreeCtrlBuilder, TreeCtrlStyle, TreeHitTestFlags, TreeItemIcon, TreeItemId};
 
@@ -191,45 +206,54 @@ pub use crate::menus::{ItemKind, Menu, MenuBar, MenuItem};
 pub use crate::widgets::ItemKind as WidgetItemKind;
 
 // --- Bitmaps & Art ---
-pub use crate::art_provider::{ArtClient, ArtId, ArtProvider};
-pub use crate::bitmap::Bitmap;
-pub use crate::bitmap_bundle::BitmapBundle; // Added BitmapBundle
+pub use crate::art_provider::{ArtClient, ArtId, ArtProvider, CustomArtProvider};
+pub use crate::bitmap::{Bitmap, BitmapError};
+pub use crate::bitmap_bundle::{BitmapBundle, BitmapBundleArg};
+pub use crate::image::{Image, ResizeQuality};
 
 // --- Dialogs ---
 pub use crate::dialogs::about_dialog::{AboutDialogInfo, show_about_box};
-pub use crate::dialogs::colour_dialog::{ColourDialog, ColourDialogBuilder}; // Added Builder
+pub use crate::dialogs::colour_dialog::{ColourData, ColourDialog, ColourDialogBuilder};
+pub use crate::dialogs::credentials_dialog::{Credentials, CredentialsDialog, CredentialsDialogBuilder};
 pub use crate::dialogs::dir_dialog::{DirDialog, DirDialogBuilder, DirDialogStyle}; // Added DirDialog
 pub use crate::dialogs::file_dialog::{FileDialog, FileDialogBuilder, FileDialogStyle}; // Added Builder
 pub use crate::dialogs::font_dialog::{FontDialog, FontDialogBuilder}; // Added Builder
 pub use crate::dialogs::message_dialog::{MessageDialog, MessageDialogBuilder, MessageDialogStyle};
 pub use crate::dialogs::multi_choice_dialog::{MultiChoiceDialog, MultiChoiceDialogBuilder}; // Added MultiChoiceDialog
+pub use crate::dialogs::number_entry_dialog::{NumberEntryDialog, NumberEntryDialogBuilder};
+pub use crate::dialogs::password_entry_dialog::{PasswordEntryDialog, PasswordEntryDialogBuilder};
 pub use crate::dialogs::progress_dialog::{ProgressDialog, ProgressDialogBuilder, ProgressDialogStyle}; // Added Builder
+pub use crate::dialogs::rich_message_dialog::{RichMessageDialog, RichMessageDialogBuilder};
 pub use crate::dialogs::single_choice_dialog::{SingleChoiceDialog, SingleChoiceDialogBuilder}; // Added SingleChoiceDialog
 pub use crate::dialogs::text_entry_dialog::{TextEntryDialog, TextEntryDialogBuilder, TextEntryDialogStyle};
-pub use crate::dialogs::{Dialog, DialogBuilder, DialogStyle}; // Base Dialog struct and builder
+pub use crate::dialogs::wizard::{Wizard, WizardBuilder, WizardEvent, WizardEventData, WizardPage};
+pub use crate::dialogs::{Dialog, DialogBuilder, DialogStyle, StdDialogButtons}; // Base Dialog struct and builder
 
 // --- Fonts ---
 pub use crate::font::{Font, FontBuilder, FontFamily, FontStyle, FontWeight}; // Added FontBuilder
 pub use crate::font_data::FontData;
 
 // --- Drag and Drop ---
-pub use crate::data_object::{BitmapDataObject, DataFormat};
-pub use crate::dnd::{DataObject, DragResult, DropSource, FileDataObject, FileDropTarget, TextDataObject, TextDropTarget};
+pub use crate::data_object::{BitmapDataObject, CustomDataObject, DataFormat, DataObjectComposite, DataObjectCompositeBuilder};
+pub use crate::dnd::{
+    DataObject, DragResult, DragSource, DropSource, FileDataObject, FileDropTarget, TextDataObject, TextDropTarget,
+};
 
 // --- Painting & DeviceContexts ---
 
 pub use crate::dc::{
-    AutoBufferedPaintDC, BackgroundMode, BrushStyle, ClientDC, DeviceContext, GenericDC, MemoryDC, PaintDC, PenStyle, ScreenDC,
-    WindowDC,
+    AutoBufferedPaintDC, BackgroundMode, BrushStyle, BufferedDC, ClientDC, DCOverlay, DcStateGuard, DeviceContext, FontMetrics,
+    GenericDC, GradientStops, GraphicsBrush, GraphicsContext, GraphicsPen, MemoryDC, Overlay, PaintDC, PenStyle, RendererFlags,
+    ScreenDC, WindowDC, capture_screen,
 };
 pub use crate::printing::*;
 
 // --- Application & Misc ---
 // pub use crate::app::App; // Commented out as per previous error, App is in main or app module
 pub use crate::appprogress::AppProgressIndicator;
-pub use crate::ipc::{IPCClient, IPCConnection, IPCConnectionBuilder, IPCFormat, IPCServer};
+pub use crate::ipc::{IPCClient, IPCConnection, IPCConnectionBuilder, IPCFormat, IPCServer, IpcError};
 pub use crate::single_instance_checker::SingleInstanceChecker;
-pub use crate::timer::Timer;
+pub use crate::timer::{CallLaterHandle, Timer, call_later};
 pub use crate::translations::{
     LanguageInfo, Locale, Translations, TranslationsLoader, add_catalog_lookup_path_prefix, translate, translate_plural,
 };
@@ -244,10 +268,12 @@ pub use crate::widgets::combobox::NOT_FOUND as COMBOBOX_NOT_FOUND;
 
 // --- XRC Support ---
 #[cfg(feature = "xrc")]
-pub use crate::xrc::{FromXrcPtr, WindowXrcMethods, XmlResource}; // Added XRC functionality
+pub use crate::xrc::{FromXrcPtr, WindowXrcMethods, XmlResource, XrcCustomWidget, XrcError, try_xrc_id, xrc_id}; // Added XRC functionality
 
 // --- Macros for custom widget development ---
 pub use crate::custom_widget;
 
 // --- Scrolling ---
 pub use crate::scrollable::WxScrollable;
+pub use crate::text_entry::WxTextEntry;
+pub use crate::validator::{CustomValidator, FloatValidator, IntValidator, RegexValidator, Validator, WxValidatable};
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::ffi::CString;
 use std::os::raw::c_void;
 use std::ptr;
@@ -5,6 +6,67 @@ use std::ptr;
 use crate::ffi;
 use crate::prelude::*;
 
+// --- Enums ---
+
+/// Page orientation stored in [`PrintData`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[repr(i32)]
+pub enum PrintOrientation {
+    #[default]
+    Portrait = ffi::WXD_PORTRAIT as i32,
+    Landscape = ffi::WXD_LANDSCAPE as i32,
+}
+
+impl PrintOrientation {
+    fn from_raw(value: i32) -> Self {
+        if value == Self::Landscape as i32 {
+            Self::Landscape
+        } else {
+            Self::Portrait
+        }
+    }
+}
+
+/// Common paper sizes (wxPaperSize).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum PaperId {
+    /// No predefined paper; the size is given by `PageSetupDialogData::set_paper_size`.
+    Custom = ffi::WXD_PAPER_NONE as i32,
+    Letter = ffi::WXD_PAPER_LETTER as i32,
+    Legal = ffi::WXD_PAPER_LEGAL as i32,
+    A4 = ffi::WXD_PAPER_A4 as i32,
+    Tabloid = ffi::WXD_PAPER_TABLOID as i32,
+    Ledger = ffi::WXD_PAPER_LEDGER as i32,
+    Statement = ffi::WXD_PAPER_STATEMENT as i32,
+    Executive = ffi::WXD_PAPER_EXECUTIVE as i32,
+    A3 = ffi::WXD_PAPER_A3 as i32,
+    A5 = ffi::WXD_PAPER_A5 as i32,
+    B4 = ffi::WXD_PAPER_B4 as i32,
+    B5 = ffi::WXD_PAPER_B5 as i32,
+}
+
+impl PaperId {
+    const ALL: [PaperId; 12] = [
+        PaperId::Custom,
+        PaperId::Letter,
+        PaperId::Legal,
+        PaperId::A4,
+        PaperId::Tabloid,
+        PaperId::Ledger,
+        PaperId::Statement,
+        PaperId::Executive,
+        PaperId::A3,
+        PaperId::A5,
+        PaperId::B4,
+        PaperId::B5,
+    ];
+
+    fn from_raw(value: i32) -> Option<Self> {
+        Self::ALL.into_iter().find(|id| *id as i32 == value)
+    }
+}
+
 // --- Traits ---
 
 pub trait Printout {
@@ -22,6 +84,103 @@ pub trait Printout {
     fn get_page_info(&mut self) -> (i32, i32, i32, i32) {
         (1, 32000, 1, 1)
     }
+
+    // The helpers below act on the wxPrintout driving this printout. They only have an
+    // effect while one of the callbacks above is running, and return zero sizes otherwise.
+
+    /// Scales the DC so that an image of `image_size` (in logical units) fits on the
+    /// printable area of the page, keeping the aspect ratio.
+    fn fit_this_size_to_page(&self, image_size: Size) {
+        with_current_printout(|p| unsafe { ffi::wxd_Printout_FitThisSizeToPage(p, image_size.into()) });
+    }
+
+    /// Like [`fit_this_size_to_page`](Printout::fit_this_size_to_page), but fits the image
+    /// inside the margins chosen in the page setup dialog.
+    fn fit_this_size_to_page_margins(&self, image_size: Size, page_setup: &PageSetupDialogData) {
+        with_current_printout(|p| unsafe {
+            ffi::wxd_Printout_FitThisSizeToPageMargins(p, image_size.into(), page_setup.ffi_ptr)
+        });
+    }
+
+    /// Scales the DC so that sizes in screen pixels print at the same physical size,
+    /// whatever the printer resolution.
+    fn map_screen_size_to_page(&self) {
+        with_current_printout(|p| unsafe { ffi::wxd_Printout_MapScreenSizeToPage(p) });
+    }
+
+    /// Returns the size of the page in millimetres.
+    fn get_page_size_mm(&self) -> Size {
+        with_current_printout(|p| {
+            let mut size = Size::new(0, 0);
+            unsafe { ffi::wxd_Printout_GetPageSizeMM(p, &mut size.width, &mut size.height) };
+            size
+        })
+        .unwrap_or(Size::new(0, 0))
+    }
+
+    /// Returns the size of the page in device pixels.
+    fn get_page_size_pixels(&self) -> Size {
+        with_current_printout(|p| {
+            let mut size = Size::new(0, 0);
+            unsafe { ffi::wxd_Printout_GetPageSizePixels(p, &mut size.width, &mut size.height) };
+            size
+        })
+        .unwrap_or(Size::new(0, 0))
+    }
+
+    /// Returns the screen resolution in pixels per inch.
+    fn get_ppi_screen(&self) -> (i32, i32) {
+        with_current_printout(|p| {
+            let (mut x, mut y) = (0, 0);
+            unsafe { ffi::wxd_Printout_GetPPIScreen(p, &mut x, &mut y) };
+            (x, y)
+        })
+        .unwrap_or((0, 0))
+    }
+
+    /// Returns the printer resolution in pixels per inch.
+    fn get_ppi_printer(&self) -> (i32, i32) {
+        with_current_printout(|p| {
+            let (mut x, mut y) = (0, 0);
+            unsafe { ffi::wxd_Printout_GetPPIPrinter(p, &mut x, &mut y) };
+            (x, y)
+        })
+        .unwrap_or((0, 0))
+    }
+
+    /// Returns `true` if the printout is being drawn for a print preview.
+    fn is_preview(&self) -> bool {
+        with_current_printout(|p| unsafe { ffi::wxd_Printout_IsPreview(p) }).unwrap_or(false)
+    }
+}
+
+thread_local! {
+    // wxPrintout whose callback is currently running; used by the `Printout` helpers.
+    static CURRENT_PRINTOUT: Cell<*mut ffi::wxd_Printout_t> = const { Cell::new(ptr::null_mut()) };
+}
+
+fn with_current_printout<R>(f: impl FnOnce(*mut ffi::wxd_Printout_t) -> R) -> Option<R> {
+    let current = CURRENT_PRINTOUT.with(|c| c.get());
+    if current.is_null() { None } else { Some(f(current)) }
+}
+
+/// Makes a printout current for the duration of a callback.
+struct CurrentPrintoutGuard {
+    previous: *mut ffi::wxd_Printout_t,
+}
+
+impl CurrentPrintoutGuard {
+    fn enter(printout: *mut ffi::wxd_Printout_t) -> Self {
+        Self {
+            previous: CURRENT_PRINTOUT.with(|c| c.replace(printout)),
+        }
+    }
+}
+
+impl Drop for CurrentPrintoutGuard {
+    fn drop(&mut self) {
+        CURRENT_PRINTOUT.with(|c| c.set(self.previous));
+    }
 }
 
 // --- Printout Proxy ---
@@ -62,40 +221,47 @@ impl<T: Printout> PrintoutProxy<T> {
 
     unsafe extern "C" fn on_prepare_printing_cb(user_data: *mut c_void) {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         let dc = proxy.get_dc();
         proxy.inner.on_prepare_printing(&dc);
     }
 
     unsafe extern "C" fn on_begin_printing_cb(user_data: *mut c_void) {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         let dc = proxy.get_dc();
         proxy.inner.on_begin_printing(&dc);
     }
 
     unsafe extern "C" fn on_end_printing_cb(user_data: *mut c_void) {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         proxy.inner.on_end_printing();
     }
 
     unsafe extern "C" fn on_begin_document_cb(user_data: *mut c_void, start_page: i32, end_page: i32) {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         let dc = proxy.get_dc();
         proxy.inner.on_begin_document(&dc, start_page, end_page);
     }
 
     unsafe extern "C" fn on_end_document_cb(user_data: *mut c_void) {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         proxy.inner.on_end_document();
     }
 
     unsafe extern "C" fn on_print_page_cb(user_data: *mut c_void, page_num: i32) -> bool {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         let dc = proxy.get_dc();
         proxy.inner.on_print_page(&dc, page_num)
     }
 
     unsafe extern "C" fn has_page_cb(user_data: *mut c_void, page_num: i32) -> bool {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         proxy.inner.has_page(page_num)
     }
 
@@ -107,6 +273,7 @@ impl<T: Printout> PrintoutProxy<T> {
         page_to: *mut i32,
     ) {
         let proxy = unsafe { &mut *(user_data as *mut Self) };
+        let _current = CurrentPrintoutGuard::enter(proxy.ffi_ptr);
         let (min, max, from, to) = proxy.inner.get_page_info();
         unsafe {
             if !min_page.is_null() {
@@ -155,6 +322,23 @@ impl PrintData {
     pub fn is_ok(&self) -> bool {
         unsafe { ffi::wxd_PrintData_IsOk(self.ffi_ptr) }
     }
+
+    pub fn get_orientation(&self) -> PrintOrientation {
+        PrintOrientation::from_raw(unsafe { ffi::wxd_PrintData_GetOrientation(self.ffi_ptr) })
+    }
+
+    pub fn set_orientation(&mut self, orientation: PrintOrientation) {
+        unsafe { ffi::wxd_PrintData_SetOrientation(self.ffi_ptr, orientation as i32) };
+    }
+
+    /// Returns the selected paper, or `None` for a paper not listed in [`PaperId`].
+    pub fn get_paper_id(&self) -> Option<PaperId> {
+        PaperId::from_raw(unsafe { ffi::wxd_PrintData_GetPaperId(self.ffi_ptr) })
+    }
+
+    pub fn set_paper_id(&mut self, paper_id: PaperId) {
+        unsafe { ffi::wxd_PrintData_SetPaperId(self.ffi_ptr, paper_id as i32) };
+    }
 }
 
 impl Drop for PrintData {
@@ -199,6 +383,94 @@ impl PrintDialogData {
             owned: false,
         }
     }
+
+    /// Copies the paper and orientation settings from `data`, e.g. from a page setup.
+    pub fn set_print_data(&mut self, data: &PrintData) {
+        unsafe { ffi::wxd_PrintDialogData_SetPrintData(self.ffi_ptr, data.ffi_ptr) };
+    }
+
+    pub fn get_from_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetFromPage(self.ffi_ptr) }
+    }
+
+    pub fn set_from_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetFromPage(self.ffi_ptr, page) };
+    }
+
+    pub fn get_to_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetToPage(self.ffi_ptr) }
+    }
+
+    pub fn set_to_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetToPage(self.ffi_ptr, page) };
+    }
+
+    pub fn get_min_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetMinPage(self.ffi_ptr) }
+    }
+
+    pub fn set_min_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetMinPage(self.ffi_ptr, page) };
+    }
+
+    pub fn get_max_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetMaxPage(self.ffi_ptr) }
+    }
+
+    pub fn set_max_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetMaxPage(self.ffi_ptr, page) };
+    }
+
+    /// Returns the number of copies to print.
+    pub fn get_copies(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetNoCopies(self.ffi_ptr) }
+    }
+
+    pub fn set_copies(&mut self, copies: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetNoCopies(self.ffi_ptr, copies) };
+    }
+
+    pub fn get_collate(&self) -> bool {
+        unsafe { ffi::wxd_PrintDialogData_GetCollate(self.ffi_ptr) }
+    }
+
+    pub fn set_collate(&mut self, collate: bool) {
+        unsafe { ffi::wxd_PrintDialogData_SetCollate(self.ffi_ptr, collate) };
+    }
+
+    pub fn get_print_to_file(&self) -> bool {
+        unsafe { ffi::wxd_PrintDialogData_GetPrintToFile(self.ffi_ptr) }
+    }
+
+    pub fn set_print_to_file(&mut self, print_to_file: bool) {
+        unsafe { ffi::wxd_PrintDialogData_SetPrintToFile(self.ffi_ptr, print_to_file) };
+    }
+
+    pub fn get_all_pages(&self) -> bool {
+        unsafe { ffi::wxd_PrintDialogData_GetAllPages(self.ffi_ptr) }
+    }
+
+    pub fn set_all_pages(&mut self, all_pages: bool) {
+        unsafe { ffi::wxd_PrintDialogData_SetAllPages(self.ffi_ptr, all_pages) };
+    }
+
+    pub fn get_selection(&self) -> bool {
+        unsafe { ffi::wxd_PrintDialogData_GetSelection(self.ffi_ptr) }
+    }
+
+    pub fn set_selection(&mut self, selection: bool) {
+        unsafe { ffi::wxd_PrintDialogData_SetSelection(self.ffi_ptr, selection) };
+    }
+}
+
+/// Cloning always produces an owned copy, also of data borrowed from a printer or dialog.
+impl Clone for PrintDialogData {
+    fn clone(&self) -> Self {
+        Self {
+            ffi_ptr: unsafe { ffi::wxd_PrintDialogData_Copy(self.ffi_ptr) },
+            owned: true,
+        }
+    }
 }
 
 impl Drop for PrintDialogData {
@@ -243,6 +515,67 @@ impl PageSetupDialogData {
             owned: false,
         }
     }
+
+    /// Copies the paper and orientation settings from `data`, e.g. after a print job.
+    pub fn set_print_data(&mut self, data: &PrintData) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetPrintData(self.ffi_ptr, data.ffi_ptr) };
+    }
+
+    /// Returns the selected paper, or `None` for a paper not listed in [`PaperId`].
+    pub fn get_paper_id(&self) -> Option<PaperId> {
+        self.get_print_data().get_paper_id()
+    }
+
+    /// Selects a paper and updates [`get_paper_size`](Self::get_paper_size) to match it.
+    pub fn set_paper_id(&mut self, paper_id: PaperId) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetPaperId(self.ffi_ptr, paper_id as i32) };
+    }
+
+    pub fn get_orientation(&self) -> PrintOrientation {
+        self.get_print_data().get_orientation()
+    }
+
+    pub fn set_orientation(&mut self, orientation: PrintOrientation) {
+        self.get_print_data().set_orientation(orientation);
+    }
+
+    /// Returns the paper size in millimetres.
+    pub fn get_paper_size(&self) -> Size {
+        unsafe { ffi::wxd_PageSetupDialogData_GetPaperSize(self.ffi_ptr) }.into()
+    }
+
+    /// Sets a custom paper size in millimetres.
+    pub fn set_paper_size(&mut self, size: Size) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetPaperSize(self.ffi_ptr, size.into()) };
+    }
+
+    /// Returns the left and top margins in millimetres.
+    pub fn get_margin_top_left(&self) -> Point {
+        unsafe { ffi::wxd_PageSetupDialogData_GetMarginTopLeft(self.ffi_ptr) }.into()
+    }
+
+    pub fn set_margin_top_left(&mut self, margin: Point) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetMarginTopLeft(self.ffi_ptr, margin.into()) };
+    }
+
+    /// Returns the right and bottom margins in millimetres.
+    pub fn get_margin_bottom_right(&self) -> Point {
+        unsafe { ffi::wxd_PageSetupDialogData_GetMarginBottomRight(self.ffi_ptr) }.into()
+    }
+
+    pub fn set_margin_bottom_right(&mut self, margin: Point) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetMarginBottomRight(self.ffi_ptr, margin.into()) };
+    }
+}
+
+/// Cloning always produces an owned copy, also of data borrowed from a dialog.
+impl Clone for PageSetupDialogData {
+    fn clone(&self) -> Self {
+        Self {
+            ffi_ptr: unsafe { ffi::wxd_PageSetupDialogData_Copy(self.ffi_ptr) },
+            owned: true,
+        }
+    }
 }
 
 impl Drop for PageSetupDialogData {
@@ -260,7 +593,12 @@ pub struct Printer {
 }
 
 impl Printer {
-    pub fn new(data: Option<&PrintDialogData>) -> Self {
+    /// Creates a printer using the given settings (copied), or the defaults for `None`.
+    ///
+    /// After printing, [`get_print_dialog_data`](Printer::get_print_dialog_data) holds the
+    /// settings chosen by the user, which can be cloned and passed to the next printer.
+    pub fn new<'a>(data: impl Into<Option<&'a PrintDialogData>>) -> Self {
+        let data = data.into();
         let ffi_ptr = unsafe { ffi::wxd_Printer_Create(data.map_or(ptr::null_mut(), |d| d.ffi_ptr)) };
         Self { ffi_ptr }
     }
@@ -366,6 +704,18 @@ impl PageSetupDialog {
             owned: false,
         }
     }
+
+    /// Shows a page setup dialog initialised from `data` and, if the user presses OK,
+    /// stores the chosen paper, orientation and margins back into `data`.
+    /// Returns `true` if the settings were changed.
+    pub fn edit<W: WxWidget>(parent: &W, data: &mut PageSetupDialogData) -> bool {
+        let dialog = Self::new(Some(parent), Some(&*data));
+        if dialog.show_modal() != ID_OK {
+            return false;
+        }
+        *data = dialog.get_page_setup_dialog_data().clone();
+        true
+    }
 }
 
 impl Drop for PageSetupDialog {
@@ -380,41 +730,4 @@ impl<T: Printout> PrintoutProxy<T> {
     pub fn get_dc(&self) -> GenericDC {
         unsafe { GenericDC::from_ffi_ptr_unowned(ffi::wxd_Printout_GetDC(self.ffi_ptr)) }
     }
-
-    #[allow(dead_code)]
-    pub fn get_page_size_pixels(&self) -> (i32, i32) {
-        let mut w = 0;
-        let mut h = 0;
-        unsafe { ffi::wxd_Printout_GetPageSizePixels(self.ffi_ptr, &mut w, &mut h) };
-        (w, h)
-    }
-
-    #[allow(dead_code)]
-    pub fn get_page_size_mm(&self) -> (i32, i32) {
-        let mut w = 0;
-        let mut h = 0;
-        unsafe { ffi::wxd_Printout_GetPageSizeMM(self.ffi_ptr, &mut w, &mut h) };
-        (w, h)
-    }
-
-    #[allow(dead_code)]
-    pub fn get_ppi_screen(&self) -> (i32, i32) {
-        let mut x = 0;
-        let mut y = 0;
-        unsafe { ffi::wxd_Printout_GetPPIScreen(self.ffi_ptr, &mut x, &mut y) };
-        (x, y)
-    }
-
-    #[allow(dead_code)]
-    pub fn get_ppi_printer(&self) -> (i32, i32) {
-        let mut x = 0;
-        let mut y = 0;
-        unsafe { ffi::wxd_Printout_GetPPIPrinter(self.ffi_ptr, &mut x, &mut y) };
-        (x, y)
-    }
-
-    #[allow(dead_code)]
-    pub fn is_preview(&self) -> bool {
-        unsafe { ffi::wxd_Printout_IsPreview(self.ffi_ptr) }
-    }
 }
@@ -0,0 +1,99 @@
+//! Measuring elapsed time with wxStopWatch.
+
+use wxdragon_sys as ffi;
+
+/// Measures elapsed time, e.g. to profile a piece of code.
+///
+/// The stopwatch starts running when it is created. It can be paused and resumed; pauses
+/// nest, so it only runs again once every [`pause`](Self::pause) is matched by a
+/// [`resume`](Self::resume).
+///
+/// # Example
+/// ```rust,no_run
+/// use wxdragon::prelude::*;
+///
+/// let watch = StopWatch::new();
+/// // ... do some work ...
+/// println!("took {} ms", watch.time_ms());
+/// ```
+pub struct StopWatch {
+    ptr: *mut ffi::wxd_StopWatch_t,
+    pause_count: u32,
+}
+
+impl StopWatch {
+    /// Creates a stopwatch and starts it.
+    pub fn new() -> Self {
+        let ptr = unsafe { ffi::wxd_StopWatch_Create() };
+        Self { ptr, pause_count: 0 }
+    }
+
+    /// Restarts the stopwatch from zero, clearing any pause.
+    pub fn start(&mut self) {
+        self.start_at(0);
+    }
+
+    /// Restarts the stopwatch as if `milliseconds` had already elapsed, clearing any pause.
+    pub fn start_at(&mut self, milliseconds: i64) {
+        if self.ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_StopWatch_Start(self.ptr, milliseconds) };
+        self.pause_count = 0;
+    }
+
+    /// Pauses the stopwatch; the elapsed time stops growing until [`resume`](Self::resume).
+    pub fn pause(&mut self) {
+        if self.ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_StopWatch_Pause(self.ptr) };
+        self.pause_count += 1;
+    }
+
+    /// Resumes the stopwatch after [`pause`](Self::pause). Does nothing if it isn't paused.
+    pub fn resume(&mut self) {
+        if self.ptr.is_null() || self.pause_count == 0 {
+            return;
+        }
+        unsafe { ffi::wxd_StopWatch_Resume(self.ptr) };
+        self.pause_count -= 1;
+    }
+
+    /// Returns true while the stopwatch is paused.
+    pub fn is_paused(&self) -> bool {
+        self.pause_count > 0
+    }
+
+    /// Returns the elapsed time in milliseconds.
+    pub fn time_ms(&self) -> i64 {
+        if self.ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_StopWatch_Time(self.ptr) }
+    }
+
+    /// Returns the elapsed time in microseconds.
+    pub fn time_micro(&self) -> i64 {
+        if self.ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_StopWatch_TimeInMicro(self.ptr) }
+    }
+}
+
+impl Default for StopWatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for StopWatch {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::wxd_StopWatch_Destroy(self.ptr) };
+        }
+    }
+}
+
+unsafe impl Send for StopWatch {}
@@ -0,0 +1,280 @@
+//! Background work with progress and results delivered on the main thread.
+//!
+//! [`spawn`] runs a closure on a worker thread. Values it reports through its
+//! [`ProgressSink`] and the value it returns are handed to callbacks registered on the
+//! returned [`TaskHandle`], which run on the main thread where they can update widgets.
+//! Delivery goes through [`call_after`](crate::call_after), so updates appear as soon as
+//! the event loop gets to them, without polling from idle handlers.
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use wxdragon::prelude::*;
+//! # let frame = Frame::builder().build();
+//! let gauge = Gauge::builder(&frame).with_range(100).build();
+//! let status = StaticText::builder(&frame).build();
+//!
+//! let task = wxdragon::task::spawn(|progress| {
+//!     for percent in 0..=100 {
+//!         if progress.is_cancelled() {
+//!             return "cancelled";
+//!         }
+//!         std::thread::sleep(Duration::from_millis(20));
+//!         progress.report(percent);
+//!     }
+//!     "done"
+//! })
+//! .on_progress(move |percent| gauge.set_value(percent))
+//! .on_complete(move |outcome| status.set_label(outcome));
+//!
+//! // Later, e.g. from a "Cancel" button:
+//! task.cancel();
+//! ```
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Callbacks of running tasks by task id, only ever touched on the main thread.
+    static TASK_CALLBACKS: RefCell<HashMap<u64, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// State shared between the worker thread and the main thread.
+struct TaskShared<P, T> {
+    progress: Mutex<VecDeque<P>>,
+    result: Mutex<Option<T>>,
+    /// Set when a delivery to the main thread is queued and hasn't started yet, so a burst of
+    /// reports is delivered by a single callback.
+    delivery_queued: AtomicBool,
+    cancelled: AtomicBool,
+    finished: AtomicBool,
+}
+
+impl<P, T> TaskShared<P, T> {
+    fn lock_progress(&self) -> MutexGuard<'_, VecDeque<P>> {
+        self.progress.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn lock_result(&self) -> MutexGuard<'_, Option<T>> {
+        self.result.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Main-thread callbacks of one task.
+struct TaskCallbacks<P, T> {
+    on_progress: Option<Box<dyn FnMut(P)>>,
+    on_complete: Option<Box<dyn FnOnce(T)>>,
+}
+
+type SharedCallbacks<P, T> = Rc<RefCell<TaskCallbacks<P, T>>>;
+
+fn task_callbacks<P: 'static, T: 'static>(id: u64) -> SharedCallbacks<P, T> {
+    TASK_CALLBACKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        let entry = tasks.entry(id).or_insert_with(|| {
+            Box::new(Rc::new(RefCell::new(TaskCallbacks::<P, T> {
+                on_progress: None,
+                on_complete: None,
+            })))
+        });
+        entry
+            .downcast_ref::<SharedCallbacks<P, T>>()
+            .expect("task callbacks registered with another type")
+            .clone()
+    })
+}
+
+/// Queues a delivery of pending progress and the result to the main thread, unless one is
+/// already queued. Returns false if the main loop is no longer running.
+fn queue_delivery<P, T>(id: u64, shared: &Arc<TaskShared<P, T>>) -> bool
+where
+    P: Send + 'static,
+    T: Send + 'static,
+{
+    if shared.delivery_queued.swap(true, Ordering::AcqRel) {
+        return true;
+    }
+    let shared_for_ui = shared.clone();
+    let queued = crate::call_after(move || deliver(id, &shared_for_ui)).is_ok();
+    if !queued {
+        shared.delivery_queued.store(false, Ordering::Release);
+    }
+    queued
+}
+
+/// Runs on the main thread: hands pending progress and, once finished, the result to the
+/// registered callbacks.
+fn deliver<P: 'static, T: 'static>(id: u64, shared: &TaskShared<P, T>) {
+    // Cleared before draining so reports made from now on queue another delivery.
+    shared.delivery_queued.store(false, Ordering::Release);
+
+    let callbacks = task_callbacks::<P, T>(id);
+    let progress: Vec<P> = shared.lock_progress().drain(..).collect();
+    for value in progress {
+        // Take the closure out while it runs, so it may register callbacks itself.
+        let on_progress = callbacks.borrow_mut().on_progress.take();
+        if let Some(mut on_progress) = on_progress {
+            on_progress(value);
+            let mut callbacks = callbacks.borrow_mut();
+            if callbacks.on_progress.is_none() {
+                callbacks.on_progress = Some(on_progress);
+            }
+        }
+    }
+
+    let result = shared.lock_result().take();
+    if let Some(result) = result {
+        TASK_CALLBACKS.with(|tasks| tasks.borrow_mut().remove(&id));
+        let on_complete = callbacks.borrow_mut().on_complete.take();
+        if let Some(on_complete) = on_complete {
+            on_complete(result);
+        }
+    }
+}
+
+/// Lets the worker closure of [`spawn`] report progress and check for cancellation.
+pub struct ProgressSink<P, T> {
+    id: u64,
+    shared: Arc<TaskShared<P, T>>,
+}
+
+impl<P, T> ProgressSink<P, T>
+where
+    P: Send + 'static,
+    T: Send + 'static,
+{
+    /// Reports `value` to the task's [`on_progress`](TaskHandle::on_progress) callback.
+    ///
+    /// Values reported faster than the main thread handles them are queued and delivered
+    /// together, in order. When the main loop has ended the value is dropped and the task
+    /// is marked cancelled.
+    pub fn report(&self, value: P) {
+        self.shared.lock_progress().push_back(value);
+        if !queue_delivery(self.id, &self.shared) {
+            self.shared.cancelled.store(true, Ordering::Release);
+        }
+    }
+
+    /// Returns true once [`TaskHandle::cancel`] was called or the main loop has ended.
+    ///
+    /// Long running work should check this regularly and return early when it is set.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// Handle to a task started with [`spawn`].
+///
+/// Register the main-thread callbacks right after spawning, in the same event handler;
+/// progress reported before a callback is registered is dropped. Dropping the handle
+/// neither cancels the task nor unregisters its callbacks.
+pub struct TaskHandle<P, T> {
+    id: u64,
+    shared: Arc<TaskShared<P, T>>,
+}
+
+impl<P, T> TaskHandle<P, T>
+where
+    P: Send + 'static,
+    T: Send + 'static,
+{
+    /// Calls `callback` on the main thread with each value the worker reports, replacing a
+    /// callback set before. Must be called on the main thread.
+    pub fn on_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(P) + 'static,
+    {
+        crate::app::assert_main_thread();
+        task_callbacks::<P, T>(self.id).borrow_mut().on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Calls `callback` on the main thread with the worker's return value, after all its
+    /// progress was delivered, replacing a callback set before. Must be called on the main
+    /// thread.
+    ///
+    /// The callback also runs for a cancelled task, with whatever the worker returned. It
+    /// doesn't run if the worker panics.
+    pub fn on_complete<F>(self, callback: F) -> Self
+    where
+        F: FnOnce(T) + 'static,
+    {
+        crate::app::assert_main_thread();
+        task_callbacks::<P, T>(self.id).borrow_mut().on_complete = Some(Box::new(callback));
+        self
+    }
+
+    /// Asks the worker to stop; it sees this through [`ProgressSink::is_cancelled`].
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Returns true once [`cancel`](Self::cancel) was called or the main loop has ended.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns true once the worker closure has returned or panicked.
+    pub fn is_finished(&self) -> bool {
+        self.shared.finished.load(Ordering::Acquire)
+    }
+}
+
+/// Runs `work` on a new worker thread and returns a handle for receiving its progress and
+/// result on the main thread.
+///
+/// `work` gets a [`ProgressSink`] to report values of type `P` and check for cancellation;
+/// its return value is passed to [`TaskHandle::on_complete`]. Call this on the main thread
+/// once [`main`](crate::main) has started the application.
+pub fn spawn<P, T, F>(work: F) -> TaskHandle<P, T>
+where
+    P: Send + 'static,
+    T: Send + 'static,
+    F: FnOnce(ProgressSink<P, T>) -> T + Send + 'static,
+{
+    let id = NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed);
+    let shared = Arc::new(TaskShared {
+        progress: Mutex::new(VecDeque::new()),
+        result: Mutex::new(None),
+        delivery_queued: AtomicBool::new(false),
+        cancelled: AtomicBool::new(false),
+        finished: AtomicBool::new(false),
+    });
+
+    let sink = ProgressSink {
+        id,
+        shared: shared.clone(),
+    };
+    let worker_shared = shared.clone();
+    let spawned = std::thread::Builder::new()
+        .name(format!("wxdragon-task-{id}"))
+        .spawn(move || {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| work(sink)));
+            worker_shared.finished.store(true, Ordering::Release);
+            match result {
+                Ok(result) => {
+                    *worker_shared.lock_result() = Some(result);
+                    queue_delivery(id, &worker_shared);
+                }
+                Err(_) => {
+                    log::error!("Panic in background task {id}");
+                    // Free the callbacks on the main thread; nothing more will be delivered.
+                    let _ = crate::call_after(move || {
+                        TASK_CALLBACKS.with(|tasks| tasks.borrow_mut().remove(&id));
+                    });
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        log::error!("Failed to start background task: {e}");
+        shared.finished.store(true, Ordering::Release);
+    }
+
+    TaskHandle { id, shared }
+}
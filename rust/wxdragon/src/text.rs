@@ -0,0 +1,57 @@
+//! Helpers for laying out custom-drawn text.
+
+use crate::dc::DeviceContext;
+use std::ffi::{CStr, CString};
+use wxdragon_sys as ffi;
+
+/// Where [`ellipsize`] removes text when it doesn't fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EllipsizeMode {
+    /// Replace the beginning: `...ong label`
+    Start,
+    /// Replace the middle: `a lo...abel`
+    Middle,
+    /// Replace the end: `a long l...`
+    #[default]
+    End,
+}
+
+impl EllipsizeMode {
+    /// Convert to the raw FFI value
+    pub fn to_raw(&self) -> i32 {
+        match self {
+            EllipsizeMode::Start => ffi::WXD_ELLIPSIZE_START as i32,
+            EllipsizeMode::Middle => ffi::WXD_ELLIPSIZE_MIDDLE as i32,
+            EllipsizeMode::End => ffi::WXD_ELLIPSIZE_END as i32,
+        }
+    }
+}
+
+/// Shorten `text` with an ellipsis so that it is at most `max_width` wide when drawn with
+/// the DC's current font, the same way native controls ellipsize their labels.
+///
+/// Text that already fits is returned unchanged. Tabs are measured as spaces and `&` has no
+/// special meaning. Each line of multi-line text is ellipsized separately.
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// # use wxdragon::text::{EllipsizeMode, ellipsize};
+/// # fn paint(dc: &PaintDC, column: Rect, path: &str) {
+/// let label = ellipsize(dc, path, column.width, EllipsizeMode::Middle);
+/// dc.draw_text(&label, column.x, column.y);
+/// # }
+/// ```
+pub fn ellipsize(dc: &dyn DeviceContext, text: &str, max_width: i32, mode: EllipsizeMode) -> String {
+    let Ok(c_text) = CString::new(text) else {
+        return text.to_string();
+    };
+    let dc_ptr = dc.dc_ptr();
+    let len = unsafe { ffi::wxd_DC_Ellipsize(dc_ptr, c_text.as_ptr(), mode.to_raw(), max_width, std::ptr::null_mut(), 0) };
+    if len < 0 {
+        return text.to_string();
+    }
+    let mut buf = vec![0; len as usize + 1];
+    unsafe { ffi::wxd_DC_Ellipsize(dc_ptr, c_text.as_ptr(), mode.to_raw(), max_width, buf.as_mut_ptr(), buf.len()) };
+    unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() }
+}
@@ -0,0 +1,114 @@
+//! Auto-completion for single-line text entries.
+//!
+//! This module provides the `WxTextEntry` trait for widgets with an editable text field
+//! backed by wxTextEntry, such as `TextCtrl`, `ComboBox` and `SearchCtrl`.
+
+use crate::utils::ArrayString;
+use crate::window::WxWidget;
+use std::ffi::{CStr, c_char, c_void};
+use wxdragon_sys as ffi;
+
+type Completer = Box<dyn Fn(&str) -> Vec<String>>;
+
+/// Trait for widgets with an editable text field that can offer completions.
+///
+/// Completions are shown in a popup below the field while the user types; picking one
+/// replaces the text. They work in single-line entries only, and on macOS they are held
+/// back while an input method is composing text. Each call replaces the completions set
+/// before.
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// let url = TextCtrl::builder(&frame).build();
+/// url.auto_complete(&["https://www.wxwidgets.org", "https://www.rust-lang.org"]);
+///
+/// let words: Vec<String> = (0..10_000).map(|n| format!("word{n}")).collect();
+/// let search = ComboBox::builder(&frame).build();
+/// search.auto_complete_with(move |prefix| {
+///     words.iter().filter(|word| word.starts_with(prefix)).take(50).cloned().collect()
+/// });
+/// ```
+pub trait WxTextEntry: WxWidget {
+    /// Completes the text from a fixed list of candidates.
+    ///
+    /// Returns false if completion isn't supported on this platform or the widget has been
+    /// destroyed.
+    fn auto_complete(&self, choices: &[&str]) -> bool {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return false;
+        }
+        let mut array = ArrayString::new();
+        array.add_many(choices);
+        unsafe { ffi::wxd_TextEntry_AutoComplete(handle, array.as_const_ptr()) }
+    }
+
+    /// Completes the text with the paths of existing files and directories.
+    ///
+    /// Returns false if completion isn't supported on this platform or the widget has been
+    /// destroyed.
+    fn auto_complete_file_names(&self) -> bool {
+        let handle = self.handle_ptr();
+        !handle.is_null() && unsafe { ffi::wxd_TextEntry_AutoCompleteFileNames(handle) }
+    }
+
+    /// Completes the text with the paths of existing directories.
+    ///
+    /// Returns false if completion isn't supported on this platform or the widget has been
+    /// destroyed.
+    fn auto_complete_directories(&self) -> bool {
+        let handle = self.handle_ptr();
+        !handle.is_null() && unsafe { ffi::wxd_TextEntry_AutoCompleteDirectories(handle) }
+    }
+
+    /// Completes the text with the candidates `completer` returns for the text typed so far.
+    ///
+    /// `completer` is called on the main thread whenever the text changes, so it should be
+    /// quick; return a bounded number of candidates when matching against a large list. It
+    /// is dropped when the widget is destroyed or its completions are replaced.
+    ///
+    /// Returns false if completion isn't supported on this platform or the widget has been
+    /// destroyed.
+    fn auto_complete_with<F>(&self, completer: F) -> bool
+    where
+        Self: Sized,
+        F: Fn(&str) -> Vec<String> + 'static,
+    {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return false;
+        }
+        // Double-box: `Box<dyn Fn>` is a fat pointer, so box it again to get a thin
+        // `*mut c_void` for FFI. Freed by `completer_destroy`, also if this fails.
+        let user_data = Box::into_raw(Box::new(Box::new(completer) as Completer)) as *mut c_void;
+        unsafe { ffi::wxd_TextEntry_AutoCompleteCustom(handle, &COMPLETER_VTABLE as *const _, user_data) }
+    }
+}
+
+// The `user_data` handed to the C++ completer is a `*mut Completer`.
+
+unsafe extern "C" fn completer_get_completions(user_data: *mut c_void, prefix: *const c_char, out: *mut ffi::wxd_ArrayString_t) {
+    if user_data.is_null() || prefix.is_null() || out.is_null() {
+        return;
+    }
+    unsafe {
+        let completer = &**(user_data as *mut Completer);
+        let prefix = CStr::from_ptr(prefix).to_string_lossy();
+        // Borrow (non-owning) the C++-owned array
+        ArrayString::from(out as *const ffi::wxd_ArrayString_t).add_many(&completer(&prefix));
+    }
+}
+
+unsafe extern "C" fn completer_destroy(user_data: *mut c_void) {
+    if user_data.is_null() {
+        return;
+    }
+    drop(unsafe { Box::from_raw(user_data as *mut Completer) });
+}
+
+static COMPLETER_VTABLE: ffi::wxd_TextCompleter_vtable = ffi::wxd_TextCompleter_vtable {
+    get_completions: Some(completer_get_completions),
+    destroy: Some(completer_destroy),
+};
@@ -1,10 +1,14 @@
 //! Timer module for wxDragon.
 //!
 //! This module provides a safe wrapper around wxWidgets' wxTimer class.
-//! Timers are used to generate events at regular intervals.
+//! Timers are used to generate events at regular intervals. For a single delayed
+//! call without managing a timer, use [`call_later`].
 
 use crate::event::{Event, EventType, WxEvtHandler};
+use std::cell::Cell;
+use std::ffi::c_void;
 use std::marker::PhantomData;
+use std::rc::Rc;
 use wxdragon_sys as ffi;
 
 /// Represents a timer that triggers events at specified intervals.
@@ -145,3 +149,130 @@ impl WxEvtHandler for TimerOwnerWrapper {
         self.0
     }
 }
+
+/// Whether a delayed call from [`call_later`] is still waiting to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CallLaterState {
+    Pending,
+    Fired,
+    Cancelled,
+    /// The application exited, or the call couldn't be scheduled.
+    Dropped,
+}
+
+/// Handle to a delayed call scheduled with [`call_later`], used to cancel it.
+///
+/// Dropping the handle doesn't cancel the call. Clones refer to the same call.
+#[derive(Clone)]
+pub struct CallLaterHandle {
+    state: Rc<Cell<CallLaterState>>,
+    // The library's timer, only valid while the call is pending
+    call: *mut ffi::wxd_CallLater_t,
+}
+
+impl CallLaterHandle {
+    /// Cancels the call if it hasn't run yet, stopping and freeing its timer along with the
+    /// callback. Returns true if it was still pending.
+    pub fn cancel(&self) -> bool {
+        if !self.is_pending() {
+            return false;
+        }
+        self.state.set(CallLaterState::Cancelled);
+        unsafe { ffi::wxd_CallLater_Cancel(self.call) };
+        true
+    }
+
+    /// Returns true while the call is scheduled and neither ran nor was cancelled.
+    pub fn is_pending(&self) -> bool {
+        self.state.get() == CallLaterState::Pending
+    }
+}
+
+impl std::fmt::Debug for CallLaterHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallLaterHandle").field("state", &self.state.get()).finish()
+    }
+}
+
+struct CallLaterPayload {
+    callback: Option<Box<dyn FnOnce()>>,
+    state: Rc<Cell<CallLaterState>>,
+}
+
+/// Calls `callback` once on the main thread after `milliseconds`, without a [`Timer`] to
+/// keep around.
+///
+/// The returned handle cancels the call. A call still pending when the main loop shuts
+/// down never runs. Must be called on the main thread once the application is running;
+/// otherwise nothing is scheduled and the handle is not pending.
+///
+/// Cancelling the previous call on every change gives a debounce, e.g. to filter a list
+/// only once typing paused:
+///
+/// ```rust,no_run
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// let search = SearchCtrl::builder(&frame).build();
+///
+/// let pending: Rc<RefCell<Option<CallLaterHandle>>> = Rc::new(RefCell::new(None));
+/// search.on_text_updated(move |_| {
+///     if let Some(previous) = pending.borrow_mut().take() {
+///         previous.cancel();
+///     }
+///     let handle = wxdragon::call_later(300, move || println!("filter for {}", search.get_value()));
+///     *pending.borrow_mut() = Some(handle);
+/// });
+/// ```
+pub fn call_later<F>(milliseconds: u64, callback: F) -> CallLaterHandle
+where
+    F: FnOnce() + 'static,
+{
+    let state = Rc::new(Cell::new(CallLaterState::Pending));
+    let payload = Box::new(CallLaterPayload {
+        callback: Some(Box::new(callback)),
+        state: state.clone(),
+    });
+    let milliseconds = milliseconds.min(i32::MAX as u64) as i32;
+    let user_data = Box::into_raw(payload) as *mut c_void;
+    // The C++ side frees the payload in every case, which marks a call that never ran.
+    let call = unsafe {
+        ffi::wxd_CallLater(
+            milliseconds,
+            Some(call_later_trampoline),
+            user_data,
+            Some(free_call_later_payload),
+        )
+    };
+    if call.is_null() {
+        log::warn!("call_later: nothing scheduled, the application isn't running on this thread");
+    }
+    CallLaterHandle { state, call }
+}
+
+unsafe extern "C" fn call_later_trampoline(user_data: *mut c_void) {
+    if user_data.is_null() {
+        return;
+    }
+    let payload = unsafe { &mut *(user_data as *mut CallLaterPayload) };
+    if payload.state.get() != CallLaterState::Pending {
+        return;
+    }
+    payload.state.set(CallLaterState::Fired);
+    if let Some(callback) = payload.callback.take()
+        && std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)).is_err()
+    {
+        log::error!("Panic caught in a call_later callback");
+    }
+}
+
+unsafe extern "C" fn free_call_later_payload(user_data: *mut c_void) {
+    if user_data.is_null() {
+        return;
+    }
+    let payload = unsafe { Box::from_raw(user_data as *mut CallLaterPayload) };
+    if payload.state.get() == CallLaterState::Pending {
+        payload.state.set(CallLaterState::Dropped);
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+use std::os::raw::c_char;
+use std::sync::atomic::{Ordering, compiler_fence};
+
+/// A string holding a secret such as a password.
+///
+/// The bytes are overwritten with zeros when the value is dropped, and the `Debug`
+/// output never shows the content, so secrets do not end up in logs by accident.
+/// Use [`expose`](SecretString::expose) to read the value where it is really needed.
+///
+/// Zeroing is best-effort: copies made by the caller through `expose`, and the
+/// text kept inside the native control until it is destroyed, are not covered.
+#[derive(Clone, Default)]
+pub struct SecretString {
+    inner: String,
+}
+
+impl SecretString {
+    /// Wraps `value`; it is zeroed when the `SecretString` is dropped.
+    pub fn new(value: String) -> Self {
+        Self { inner: value }
+    }
+
+    /// Returns the secret. Avoid storing or logging the returned slice.
+    pub fn expose(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns the length of the secret in bytes.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the secret is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Reads a UTF-8 string from a "copy into buffer" FFI getter returning the full
+    /// length, without leaving intermediate copies behind.
+    pub(crate) fn read_with(mut getter: impl FnMut(*mut c_char, usize) -> i32) -> Self {
+        let mut buffer: Vec<u8> = vec![0; 256];
+        let mut len = getter(buffer.as_mut_ptr() as *mut c_char, buffer.len());
+        if len < 0 {
+            return Self::default();
+        }
+        if len as usize >= buffer.len() {
+            zeroize(&mut buffer);
+            buffer = vec![0; len as usize + 1];
+            len = getter(buffer.as_mut_ptr() as *mut c_char, buffer.len());
+            if len < 0 || len as usize >= buffer.len() {
+                zeroize(&mut buffer);
+                return Self::default();
+            }
+        }
+        // Zero the unused tail (it holds the terminator), then reuse the allocation.
+        zeroize(&mut buffer[len as usize..]);
+        buffer.truncate(len as usize);
+        match String::from_utf8(buffer) {
+            Ok(inner) => Self { inner },
+            Err(err) => {
+                zeroize(&mut err.into_bytes());
+                Self::default()
+            }
+        }
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self::new(value.to_string())
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretString(***)")
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        // SAFETY: the bytes are only overwritten with zeros, which is valid UTF-8.
+        zeroize(unsafe { self.inner.as_mut_vec() });
+    }
+}
+
+fn zeroize(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        // Volatile writes so the compiler cannot drop them as dead stores.
+        unsafe { std::ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_output_hides_the_secret() {
+        let secret = SecretString::from("hunter2");
+        assert_eq!(format!("{secret:?}"), "SecretString(***)");
+        assert_eq!(secret.expose(), "hunter2");
+    }
+
+    #[test]
+    fn read_with_retries_for_long_values() {
+        let value = "p".repeat(600);
+        let secret = SecretString::read_with(|buf, len| {
+            let bytes = value.as_bytes();
+            if !buf.is_null() && len > 0 {
+                let n = bytes.len().min(len - 1);
+                unsafe {
+                    std::ptr::copy_nonoverlapping(bytes.as_ptr(), buf as *mut u8, n);
+                    *buf.add(n) = 0;
+                }
+            }
+            bytes.len() as i32
+        });
+        assert_eq!(secret.expose(), value);
+    }
+}
@@ -0,0 +1,348 @@
+//! Input validation for text and spin controls.
+//!
+//! A [`Validator`] attached to a control with [`WxValidatable::set_validator`] checks its
+//! value when the window holding it calls [`validate`](crate::window::WxWidget::validate),
+//! typically from a dialog's OK button, and can reject unwanted characters as they are
+//! typed. [`IntValidator`], [`FloatValidator`] and [`RegexValidator`] cover common fields;
+//! [`CustomValidator`] takes closures for anything else.
+//!
+//! # Example
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//! # let parent = Frame::builder().build();
+//! # let dialog = Dialog::builder(&parent, "Connect").build();
+//! let port = TextCtrl::builder(&dialog).build();
+//! port.set_validator(IntValidator::new(1, 65535));
+//!
+//! let user = TextCtrl::builder(&dialog).build();
+//! user.set_validator(CustomValidator::new(|value| {
+//!     if value.trim().is_empty() {
+//!         Err("Enter a user name.".to_string())
+//!     } else {
+//!         Ok(())
+//!     }
+//! }));
+//!
+//! let ok = Button::builder(&dialog).with_label("OK").build();
+//! ok.on_click(move |_| {
+//!     if dialog.validate() {
+//!         dialog.end_modal(ID_OK);
+//!     }
+//! });
+//! ```
+
+use crate::window::WxWidget;
+use std::ffi::{CStr, CString, c_char, c_void};
+use wxdragon_sys as ffi;
+
+/// Checks the value of a control.
+///
+/// Implement it for validation the built-in validators don't cover, or use
+/// [`CustomValidator`] with closures.
+pub trait Validator: 'static {
+    /// Returns an error message for the user if `value`, the text of the control, isn't
+    /// acceptable.
+    fn validate(&self, value: &str) -> Result<(), String>;
+
+    /// Returns `false` to reject `ch` when it is typed into the control. Editing and
+    /// navigation keys and shortcuts are never passed here.
+    ///
+    /// Accepts everything by default.
+    fn accepts_char(&self, ch: char) -> bool {
+        let _ = ch;
+        true
+    }
+
+    /// Takes the value of the control when the window holding it calls
+    /// [`transfer_data_from_window`](crate::window::WxWidget::transfer_data_from_window).
+    ///
+    /// Does nothing by default.
+    fn transfer_from_window(&self, value: &str) {
+        let _ = value;
+    }
+}
+
+/// Trait for controls whose value can be checked by a [`Validator`].
+///
+/// Implemented by `TextCtrl`, `SearchCtrl`, `ComboBox`, `SpinCtrl` and `SpinCtrlDouble`.
+/// Keystroke filtering applies to the text controls and combo boxes; spin controls already
+/// reject what isn't a number.
+pub trait WxValidatable: WxWidget {
+    /// Attaches `validator` to the control, replacing the one set before.
+    ///
+    /// The validator is dropped when the control is destroyed or gets another one. Returns
+    /// false if the widget has been destroyed.
+    fn set_validator<V: Validator>(&self, validator: V) -> bool
+    where
+        Self: Sized,
+    {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return false;
+        }
+        // Double-box: `Box<dyn Validator>` is a fat pointer, so box it again to get a thin
+        // `*mut c_void` for FFI. Freed by `validator_destroy`, also if this fails.
+        let user_data = Box::into_raw(Box::new(Box::new(validator) as Box<dyn Validator>)) as *mut c_void;
+        unsafe { ffi::wxd_Window_SetValidator(handle, &VALIDATOR_VTABLE as *const _, user_data) }
+    }
+}
+
+/// Accepts whole numbers from `min` to `max`, inclusive.
+///
+/// Only digits, and a minus sign when `min` is negative, can be typed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntValidator {
+    pub min: i64,
+    pub max: i64,
+}
+
+impl IntValidator {
+    /// Creates a validator accepting whole numbers from `min` to `max`.
+    pub fn new(min: i64, max: i64) -> Self {
+        Self { min, max }
+    }
+}
+
+impl Default for IntValidator {
+    /// Accepts any whole number that fits into an `i64`.
+    fn default() -> Self {
+        Self::new(i64::MIN, i64::MAX)
+    }
+}
+
+impl Validator for IntValidator {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        let number: i64 = value.parse().map_err(|_| format!("'{value}' is not a whole number."))?;
+        if number < self.min || number > self.max {
+            return Err(format!("Enter a number from {} to {}.", self.min, self.max));
+        }
+        Ok(())
+    }
+
+    fn accepts_char(&self, ch: char) -> bool {
+        ch.is_ascii_digit() || (ch == '-' && self.min < 0)
+    }
+}
+
+/// Accepts decimal numbers from `min` to `max`, inclusive, with at most `precision` digits
+/// after the decimal point.
+///
+/// The decimal point is always `.`. Only digits, the decimal point when `precision` isn't
+/// zero and a minus sign when `min` is negative can be typed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatValidator {
+    pub min: f64,
+    pub max: f64,
+    pub precision: u32,
+}
+
+impl FloatValidator {
+    /// Creates a validator accepting numbers from `min` to `max` with at most `precision`
+    /// decimal places.
+    pub fn new(min: f64, max: f64, precision: u32) -> Self {
+        Self { min, max, precision }
+    }
+}
+
+impl Validator for FloatValidator {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        let number = value
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+            .ok_or_else(|| format!("'{value}' is not a number."))?;
+        let decimals = value.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+        if decimals > self.precision as usize {
+            return Err(match self.precision {
+                0 => "Enter a whole number.".to_string(),
+                precision => format!("Enter at most {precision} digits after the decimal point."),
+            });
+        }
+        if number < self.min || number > self.max {
+            return Err(format!("Enter a number from {} to {}.", self.min, self.max));
+        }
+        Ok(())
+    }
+
+    fn accepts_char(&self, ch: char) -> bool {
+        ch.is_ascii_digit() || (ch == '.' && self.precision > 0) || (ch == '-' && self.min < 0.0)
+    }
+}
+
+/// Accepts values that match a regular expression as a whole.
+///
+/// The pattern uses POSIX extended syntax, e.g. `[a-z]+(-[a-z]+)*`. An invalid pattern
+/// rejects every value with a message naming it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegexValidator {
+    pattern: String,
+    message: Option<String>,
+}
+
+impl RegexValidator {
+    /// Creates a validator accepting values matching `pattern`.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            message: None,
+        }
+    }
+
+    /// Shows `message` for a rejected value instead of a generic one, e.g. describing the
+    /// expected format.
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// Returns the pattern values have to match.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl Validator for RegexValidator {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let (Ok(pattern), Ok(text)) = (CString::new(self.pattern.as_str()), CString::new(value)) else {
+            return Err(format!("'{value}' is not in the expected format."));
+        };
+        match unsafe { ffi::wxd_RegEx_MatchesAll(pattern.as_ptr(), text.as_ptr()) } {
+            1 => Ok(()),
+            0 => Err(self
+                .message
+                .clone()
+                .unwrap_or_else(|| format!("'{value}' is not in the expected format."))),
+            _ => Err(format!("Invalid validation pattern '{}'.", self.pattern)),
+        }
+    }
+}
+
+type ValidateFn = Box<dyn Fn(&str) -> Result<(), String>>;
+type CharFilterFn = Box<dyn Fn(char) -> bool>;
+type TransferFn = Box<dyn Fn(&str)>;
+
+/// A validator made of closures.
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// let hex = CustomValidator::new(|value| match u32::from_str_radix(value, 16) {
+///     Ok(_) => Ok(()),
+///     Err(_) => Err(format!("'{value}' is not a hexadecimal number.")),
+/// })
+/// .with_char_filter(|ch| ch.is_ascii_hexdigit());
+/// ```
+pub struct CustomValidator {
+    validate: ValidateFn,
+    char_filter: Option<CharFilterFn>,
+    transfer: Option<TransferFn>,
+}
+
+impl CustomValidator {
+    /// Creates a validator calling `validate` with the value to check.
+    pub fn new<F>(validate: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        Self {
+            validate: Box::new(validate),
+            char_filter: None,
+            transfer: None,
+        }
+    }
+
+    /// Rejects typed characters for which `filter` returns `false`.
+    pub fn with_char_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(char) -> bool + 'static,
+    {
+        self.char_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Calls `transfer` with the value when data is transferred from the window holding
+    /// the control.
+    pub fn with_transfer<F>(mut self, transfer: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.transfer = Some(Box::new(transfer));
+        self
+    }
+}
+
+impl Validator for CustomValidator {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        (self.validate)(value)
+    }
+
+    fn accepts_char(&self, ch: char) -> bool {
+        self.char_filter.as_ref().is_none_or(|filter| filter(ch))
+    }
+
+    fn transfer_from_window(&self, value: &str) {
+        if let Some(transfer) = &self.transfer {
+            transfer(value);
+        }
+    }
+}
+
+// The `user_data` handed to the C++ validator is a `*mut Box<dyn Validator>`.
+
+unsafe fn validator_from<'a>(user_data: *mut c_void) -> &'a dyn Validator {
+    unsafe { &**(user_data as *mut Box<dyn Validator>) }
+}
+
+unsafe extern "C" fn validator_validate(
+    user_data: *mut c_void,
+    value: *const c_char,
+    sink: *mut c_void,
+    emit: ffi::wxd_ValidatorMessageSink,
+) -> bool {
+    if user_data.is_null() || value.is_null() {
+        return true;
+    }
+    let validator = unsafe { validator_from(user_data) };
+    let value = unsafe { CStr::from_ptr(value) }.to_string_lossy();
+    match validator.validate(&value) {
+        Ok(()) => true,
+        Err(message) => {
+            if let (Some(emit), Ok(message)) = (emit, CString::new(message.replace('\0', ""))) {
+                unsafe { emit(sink, message.as_ptr()) };
+            }
+            false
+        }
+    }
+}
+
+unsafe extern "C" fn validator_accepts_char(user_data: *mut c_void, ch: u32) -> bool {
+    if user_data.is_null() {
+        return true;
+    }
+    let validator = unsafe { validator_from(user_data) };
+    char::from_u32(ch).is_none_or(|ch| validator.accepts_char(ch))
+}
+
+unsafe extern "C" fn validator_transfer_from_window(user_data: *mut c_void, value: *const c_char) {
+    if user_data.is_null() || value.is_null() {
+        return;
+    }
+    let validator = unsafe { validator_from(user_data) };
+    validator.transfer_from_window(&unsafe { CStr::from_ptr(value) }.to_string_lossy());
+}
+
+unsafe extern "C" fn validator_destroy(user_data: *mut c_void) {
+    if user_data.is_null() {
+        return;
+    }
+    drop(unsafe { Box::from_raw(user_data as *mut Box<dyn Validator>) });
+}
+
+static VALIDATOR_VTABLE: ffi::wxd_Validator_vtable = ffi::wxd_Validator_vtable {
+    validate: Some(validator_validate),
+    accepts_char: Some(validator_accepts_char),
+    transfer_from_window: Some(validator_transfer_from_window),
+    destroy: Some(validator_destroy),
+};
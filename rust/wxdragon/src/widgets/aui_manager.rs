@@ -1,4 +1,6 @@
+use crate::config::Config;
 use crate::event::{Event, EventType, WxEvtHandler};
+use crate::widgets::frame::Frame;
 use crate::window::{Window, WindowHandle, WxWidget};
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -159,6 +161,17 @@ impl PaneInfo {
         self
     }
 
+    /// Dock this pane on the side given by `direction`
+    pub fn direction(self, direction: DockDirection) -> Self {
+        match direction {
+            DockDirection::Left => self.left(),
+            DockDirection::Right => self.right(),
+            DockDirection::Top => self.top(),
+            DockDirection::Bottom => self.bottom(),
+            DockDirection::Center => self.center(),
+        }
+    }
+
     /// Dock this pane in the center
     pub fn center(self) -> Self {
         unsafe {
@@ -394,10 +407,15 @@ impl<'a> AuiManagerBuilder<'a> {
             ffi::wxd_AuiManager_SetManagedWindow(ptr, self.parent_ptr);
         }
 
-        // Set up a destroy handler on the managed window to invalidate this manager
+        // When the managed window is destroyed, detach the manager from it before the
+        // window goes away (wxWidgets asserts on windows destroyed with the manager still
+        // pushed as an event handler), free it and invalidate this handle.
         let handle_copy = handle;
         let parent = unsafe { Window::from_ptr(self.parent_ptr) };
         parent.bind_internal(EventType::DESTROY, move |_event| {
+            if let Some(ptr) = handle_copy.get_ptr() {
+                unsafe { ffi::wxd_AuiManager_DestroyLater(ptr) };
+            }
             handle_copy.invalidate();
         });
 
@@ -412,24 +430,44 @@ impl<'a> AuiManagerBuilder<'a> {
 /// a draggable, floating interface for rearranging windows.
 ///
 /// AuiManager uses a handle-based pattern for memory safety. When the managed window
-/// is destroyed, the handle becomes invalid and all operations become safe no-ops.
+/// is destroyed, the manager is uninitialized and freed automatically, the handle becomes
+/// invalid and all operations become safe no-ops.
 ///
 /// # Example
 /// ```ignore
 /// let frame = Frame::builder().build();
-/// let manager = AuiManager::builder(&frame).build();
+/// let manager = AuiManager::new(&frame);
+///
+/// let tools = Panel::builder(&frame).build();
+/// manager.add_pane(
+///     &tools,
+///     AuiPaneInfo::new()
+///         .with_name("tools")
+///         .with_caption("Tools")
+///         .direction(DockDirection::Left)
+///         .best_size(200, 300),
+/// );
+/// manager.update();
 ///
 /// // AuiManager is Copy - no clone needed for closures!
-/// manager.on_pane_close(move |_| {
-///     // Safe: if frame was destroyed, this is a no-op
-///     manager.update();
+/// manager.on_pane_close(move |event| {
+///     // Keep the tools pane open
+///     if event.pane_name().as_deref() == Some("tools") {
+///         event.veto();
+///     }
 /// });
 ///
+/// // Panes can be changed later by name
+/// if let Some(pane) = manager.get_pane("tools") {
+///     pane.float();
+///     manager.update();
+/// }
+///
 /// // After frame destruction, manager operations are safe no-ops
 /// frame.destroy();
 /// assert!(!manager.is_valid());
 /// ```
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct AuiManager {
     /// Safe handle to the underlying wxAuiManager - automatically invalidated when managed window is destroyed
     handle: AuiManagerHandle,
@@ -438,6 +476,11 @@ pub struct AuiManager {
 }
 
 impl AuiManager {
+    /// Create an AuiManager managing the layout of `frame`
+    pub fn new(frame: &Frame) -> AuiManager {
+        Self::builder(frame).build()
+    }
+
     /// Create a new AuiManager builder, which requires a parent window to build
     pub fn builder(parent: &impl WxWidget) -> AuiManagerBuilder<'_> {
         let parent_ptr = parent.handle_ptr();
@@ -501,26 +544,39 @@ impl AuiManager {
         }
     }
 
-    /// Add a pane to the manager with a simple direction
+    /// Add a pane described by `pane_info`, e.g. its name, caption and docking direction.
+    /// Call [`update`](Self::update) afterwards to show it.
     /// Returns false if the manager has been destroyed.
-    pub fn add_pane(&self, window: &impl WxWidget, direction: DockDirection, caption: &str) -> bool {
+    pub fn add_pane(&self, window: &dyn WxWidget, pane_info: PaneInfo) -> bool {
         let ptr = self.manager_ptr();
         if ptr.is_null() {
             return false;
         }
-        let c_caption = CString::new(caption).expect("CString::new failed for caption");
-        unsafe { ffi::wxd_AuiManager_AddPane(ptr, window.handle_ptr(), direction as i32, c_caption.as_ptr()) }
+        // The manager copies the pane info; ours is dropped automatically
+        unsafe { ffi::wxd_AuiManager_AddPaneWithInfo(ptr, window.handle_ptr(), pane_info.ptr) }
     }
 
-    /// Add a pane with detailed pane information
+    /// Add a pane with detailed pane information, the same as [`add_pane`](Self::add_pane)
     /// Returns false if the manager has been destroyed.
     pub fn add_pane_with_info(&self, window: &impl WxWidget, pane_info: PaneInfo) -> bool {
+        self.add_pane(window, pane_info)
+    }
+
+    /// Get the pane named `name` (see [`PaneInfo::with_name`]) for changing it at runtime.
+    /// Returns None if there is no such pane or the manager has been destroyed.
+    pub fn get_pane(&self, name: &str) -> Option<AuiPane> {
         let ptr = self.manager_ptr();
         if ptr.is_null() {
-            return false;
+            return None;
         }
-        // The pane_info is still managed by Rust and will be dropped automatically
-        unsafe { ffi::wxd_AuiManager_AddPaneWithInfo(ptr, window.handle_ptr(), pane_info.ptr) }
+        let c_name = CString::new(name).ok()?;
+        if !unsafe { ffi::wxd_AuiManager_HasPane(ptr, c_name.as_ptr()) } {
+            return None;
+        }
+        Some(AuiPane {
+            manager: *self,
+            name: c_name,
+        })
     }
 
     /// Update the manager's layout (must be called after adding/removing panes)
@@ -551,20 +607,57 @@ impl AuiManager {
         unsafe { CStr::from_ptr(b.as_ptr()).to_string_lossy().to_string() }
     }
 
-    /// Load a perspective from a string
-    /// Returns false if the manager has been destroyed.
+    /// Load a perspective from a string saved with [`save_perspective`](Self::save_perspective),
+    /// updating the layout right away if `update` is true.
+    ///
+    /// Panes in the perspective that the manager no longer has are ignored, and panes added
+    /// since it was saved keep the settings they were added with.
+    /// Returns false if the string isn't a perspective or the manager has been destroyed.
     pub fn load_perspective(&self, perspective: &str, update: bool) -> bool {
         let ptr = self.manager_ptr();
         if ptr.is_null() {
             return false;
         }
-        let c_perspective = CString::new(perspective).expect("CString::new failed for perspective");
+        let Ok(c_perspective) = CString::new(perspective) else {
+            return false;
+        };
         unsafe { ffi::wxd_AuiManager_LoadPerspective(ptr, c_perspective.as_ptr(), update) }
     }
 
-    /// Detach a pane from the manager
+    /// Save the settings of the pane named `name` as a string
+    /// Returns None if there is no such pane or the manager has been destroyed.
+    pub fn save_pane_info(&self, name: &str) -> Option<String> {
+        let ptr = self.manager_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let c_name = CString::new(name).ok()?;
+        let len = unsafe { ffi::wxd_AuiManager_SavePaneInfo(ptr, c_name.as_ptr(), std::ptr::null_mut(), 0) };
+        if len < 0 {
+            return None;
+        }
+        let mut b = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_AuiManager_SavePaneInfo(ptr, c_name.as_ptr(), b.as_mut_ptr(), b.len()) };
+        Some(unsafe { CStr::from_ptr(b.as_ptr()).to_string_lossy().to_string() })
+    }
+
+    /// Apply settings saved with [`save_pane_info`](Self::save_pane_info) to the pane named
+    /// `name`, which keeps its name and window. Call [`update`](Self::update) afterwards.
+    /// Returns false if there is no such pane or the manager has been destroyed.
+    pub fn load_pane_info(&self, name: &str, info: &str) -> bool {
+        let ptr = self.manager_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let (Ok(c_name), Ok(c_info)) = (CString::new(name), CString::new(info)) else {
+            return false;
+        };
+        unsafe { ffi::wxd_AuiManager_LoadPaneInfo(ptr, c_name.as_ptr(), c_info.as_ptr()) }
+    }
+
+    /// Detach a pane from the manager, leaving its window alive for reuse
     /// Returns false if the manager has been destroyed.
-    pub fn detach_pane(&self, window: &impl WxWidget) -> bool {
+    pub fn detach_pane(&self, window: &dyn WxWidget) -> bool {
         let ptr = self.manager_ptr();
         if ptr.is_null() {
             return false;
@@ -573,10 +666,130 @@ impl AuiManager {
     }
 }
 
+/// Keeps the layout of `manager` in `config` under `key` across runs.
+///
+/// Call this once all panes are added: a perspective stored under `key` by an earlier run
+/// is loaded right away, and the current one is written back and flushed when the managed
+/// window receives a close event. Windows destroyed without a close event, e.g. with
+/// `destroy()`, aren't saved.
+///
+/// # Example
+/// ```ignore
+/// let config = Config::new("MyIde", None, None, None, ConfigStyle::empty());
+/// persist_perspective(manager, config, "/Layout/Main");
+/// ```
+pub fn persist_perspective(manager: AuiManager, config: Config, key: &str) {
+    let Some(window) = manager.get_managed_window() else {
+        return;
+    };
+
+    if config.has_entry(key) {
+        let perspective = config.read_string(key, "");
+        if !perspective.is_empty() && !manager.load_perspective(&perspective, true) {
+            log::warn!("Ignoring invalid AUI perspective stored under {key}");
+        }
+    }
+
+    let key = key.to_string();
+    window.bind_internal(EventType::CLOSE_WINDOW, move |event| {
+        if manager.is_valid() {
+            config.write_string(&key, &manager.save_perspective());
+            config.flush(false);
+        }
+        event.skip(true);
+    });
+}
+
 // Implement WxEvtHandler for AuiManager to allow event binding
 impl WxEvtHandler for AuiManager {
     unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
-        self.manager_ptr() as *mut ffi::wxd_EvtHandler_t
+        let ptr = self.manager_ptr();
+        if ptr.is_null() {
+            return std::ptr::null_mut();
+        }
+        unsafe { ffi::wxd_AuiManager_GetEvtHandler(ptr) }
+    }
+}
+
+/// A pane of an [`AuiManager`], looked up by name with [`AuiManager::get_pane`].
+///
+/// Changes made through it take effect on the next [`AuiManager::update`]. Once the pane
+/// is detached or the manager destroyed, changes are no-ops and queries return false.
+#[derive(Clone)]
+pub struct AuiPane {
+    manager: AuiManager,
+    name: CString,
+}
+
+impl AuiPane {
+    /// Get the name of this pane
+    pub fn name(&self) -> String {
+        self.name.to_string_lossy().into_owned()
+    }
+
+    /// Get the window shown in this pane
+    pub fn window(&self) -> Option<Window> {
+        let ptr = self.manager.manager_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let window_ptr = unsafe { ffi::wxd_AuiManager_GetPaneWindow(ptr, self.name.as_ptr()) };
+        if window_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Window::from_ptr(window_ptr) })
+        }
+    }
+
+    /// Show or hide this pane
+    pub fn show(&self, show: bool) {
+        let ptr = self.manager.manager_ptr();
+        if !ptr.is_null() {
+            unsafe { ffi::wxd_AuiManager_ShowPane(ptr, self.name.as_ptr(), show) };
+        }
+    }
+
+    /// Hide this pane
+    pub fn hide(&self) {
+        self.show(false);
+    }
+
+    /// Check if this pane is shown
+    pub fn is_shown(&self) -> bool {
+        let ptr = self.manager.manager_ptr();
+        !ptr.is_null() && unsafe { ffi::wxd_AuiManager_IsPaneShown(ptr, self.name.as_ptr()) }
+    }
+
+    /// Float this pane in its own window
+    pub fn float(&self) {
+        let ptr = self.manager.manager_ptr();
+        if !ptr.is_null() {
+            unsafe { ffi::wxd_AuiManager_FloatPane(ptr, self.name.as_ptr()) };
+        }
+    }
+
+    /// Dock this floating pane back into the managed window
+    pub fn dock(&self) {
+        let ptr = self.manager.manager_ptr();
+        if !ptr.is_null() {
+            unsafe { ffi::wxd_AuiManager_DockPane(ptr, self.name.as_ptr()) };
+        }
+    }
+
+    /// Check if this pane is floating
+    pub fn is_floating(&self) -> bool {
+        let ptr = self.manager.manager_ptr();
+        !ptr.is_null() && unsafe { ffi::wxd_AuiManager_IsPaneFloating(ptr, self.name.as_ptr()) }
+    }
+
+    /// Set the caption (title) of this pane
+    pub fn set_caption(&self, caption: &str) {
+        let ptr = self.manager.manager_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_caption = CString::new(caption).expect("CString::new failed for caption");
+        unsafe { ffi::wxd_AuiManager_SetPaneCaption(ptr, self.name.as_ptr(), c_caption.as_ptr()) };
     }
 }
 
@@ -602,6 +815,10 @@ pub enum AuiManagerEvent {
     PaneActivated,
     /// Fired when the AUI manager is rendering
     Render,
+    /// Fired after a pane was floated
+    PaneFloat,
+    /// Fired after a floating pane was docked
+    PaneDock,
 }
 
 /// Event data for AuiManager events
@@ -609,12 +826,16 @@ pub enum AuiManagerEvent {
 pub struct AuiManagerEventData {
     /// The raw event from wxWidgets
     event: Event,
+    /// The manager the handler was bound on, used to look up the event's pane
+    manager: Option<AuiManager>,
 }
 
+crate::impl_event_base!(AuiManagerEventData => event);
+
 impl AuiManagerEventData {
     /// Create a new AuiManagerEventData from an Event
     pub fn new(event: Event) -> Self {
-        Self { event }
+        Self { event, manager: None }
     }
 
     /// Gets the ID associated with this event
@@ -622,16 +843,71 @@ impl AuiManagerEventData {
         self.event.get_id()
     }
 
-    /// Gets the pane affected by this event.
-    /// This will return the Window associated with the pane if available.
+    /// Gets the window of the pane affected by this event, if available.
     pub fn get_pane(&self) -> Option<Window> {
-        self.event.get_event_object()
+        let ptr = unsafe { ffi::wxd_AuiManagerEvent_GetPaneWindow(self.event.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Window::from_ptr(ptr) })
+        }
+    }
+
+    /// Gets the name of the pane affected by this event, if available.
+    pub fn pane_name(&self) -> Option<String> {
+        let len = unsafe { ffi::wxd_AuiManagerEvent_GetPaneName(self.event.0, std::ptr::null_mut(), 0) };
+        if len < 0 {
+            return None;
+        }
+        let mut b = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_AuiManagerEvent_GetPaneName(self.event.0, b.as_mut_ptr(), b.len()) };
+        Some(unsafe { CStr::from_ptr(b.as_ptr()).to_string_lossy().to_string() })
+    }
+
+    /// Gets the pane affected by this event, to read or change its settings.
+    ///
+    /// Returns `None` for events without a named pane, or if the data wasn't created by
+    /// one of the `AuiManager::on_*` handlers.
+    pub fn pane(&self) -> Option<AuiPane> {
+        self.manager?.get_pane(&self.pane_name()?)
+    }
+
+    /// Gets the device context a render event paints the dock area with.
+    ///
+    /// The DC is owned by wxWidgets and only valid while the handler runs. Returns `None`
+    /// for other events.
+    pub fn get_dc(&self) -> Option<crate::dc::GenericDC> {
+        if self.event.is_null() {
+            return None;
+        }
+        let dc_ptr = unsafe { ffi::wxd_AuiManagerEvent_GetDC(self.event.0) };
+        if dc_ptr.is_null() {
+            return None;
+        }
+        Some(unsafe { crate::dc::GenericDC::from_ffi_ptr_unowned(dc_ptr) })
     }
 
     /// Skip this event (allow default processing to occur)
     pub fn skip(&self) {
         self.event.skip(true);
     }
+
+    /// Checks if the event can be vetoed; true for pane button, close, maximize and
+    /// restore events
+    pub fn can_veto(&self) -> bool {
+        self.event.can_veto()
+    }
+
+    /// Vetos the event, so the manager doesn't act on the pane button that was clicked:
+    /// a closed pane stays open, and a maximize or restore is cancelled
+    pub fn veto(&self) {
+        self.event.veto();
+    }
+
+    /// Checks if the event has been vetoed
+    pub fn is_vetoed(&self) -> bool {
+        self.event.is_vetoed()
+    }
 }
 
 // Implement event handling for AuiManager
@@ -644,7 +920,24 @@ impl AuiManager {
         self.bind_aui_event(EventType::AUI_PANE_BUTTON, callback);
     }
 
-    /// Bind a handler for the pane close event
+    /// Bind a handler for the pane close event, fired when the user clicks the close
+    /// button of a pane. Call [`AuiManagerEventData::veto`] to keep the pane open.
+    ///
+    /// To hide a pane instead of closing it, e.g. so it keeps its place in the layout
+    /// for when it is shown again, veto the event and hide the pane yourself:
+    ///
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # let frame = Frame::builder().build();
+    /// let manager = AuiManager::new(&frame);
+    /// manager.on_pane_close(move |event| {
+    ///     if let Some(pane) = event.pane() {
+    ///         event.veto();
+    ///         pane.hide();
+    ///         manager.update();
+    ///     }
+    /// });
+    /// ```
     pub fn on_pane_close<F>(&self, callback: F)
     where
         F: FnMut(AuiManagerEventData) + 'static,
@@ -652,7 +945,8 @@ impl AuiManager {
         self.bind_aui_event(EventType::AUI_PANE_CLOSE, callback);
     }
 
-    /// Bind a handler for the pane maximize event
+    /// Bind a handler for the pane maximize event, fired when the user clicks the
+    /// maximize button of a pane. Call [`AuiManagerEventData::veto`] to cancel it.
     pub fn on_pane_maximize<F>(&self, callback: F)
     where
         F: FnMut(AuiManagerEventData) + 'static,
@@ -660,7 +954,8 @@ impl AuiManager {
         self.bind_aui_event(EventType::AUI_PANE_MAXIMIZE, callback);
     }
 
-    /// Bind a handler for the pane restore event
+    /// Bind a handler for the pane restore event, fired when the user clicks the restore
+    /// button of a maximized pane. Call [`AuiManagerEventData::veto`] to cancel it.
     pub fn on_pane_restore<F>(&self, callback: F)
     where
         F: FnMut(AuiManagerEventData) + 'static,
@@ -676,7 +971,10 @@ impl AuiManager {
         self.bind_aui_event(EventType::AUI_PANE_ACTIVATED, callback);
     }
 
-    /// Bind a handler for the render event
+    /// Bind a handler for the render event, fired when the dock area is painted.
+    ///
+    /// Draw on [`AuiManagerEventData::get_dc`] and don't skip the event to replace the
+    /// default drawing, or skip it to draw the default on top afterwards.
     pub fn on_render<F>(&self, callback: F)
     where
         F: FnMut(AuiManagerEventData) + 'static,
@@ -684,13 +982,34 @@ impl AuiManager {
         self.bind_aui_event(EventType::AUI_RENDER, callback);
     }
 
+    /// Bind a handler fired after a pane was floated, by dragging it out of its dock or
+    /// from code followed by [`update`](Self::update).
+    pub fn on_pane_float<F>(&self, callback: F)
+    where
+        F: FnMut(AuiManagerEventData) + 'static,
+    {
+        self.bind_aui_event(EventType::AUI_PANE_FLOAT, callback);
+    }
+
+    /// Bind a handler fired after a floating pane was docked again.
+    pub fn on_pane_dock<F>(&self, callback: F)
+    where
+        F: FnMut(AuiManagerEventData) + 'static,
+    {
+        self.bind_aui_event(EventType::AUI_PANE_DOCK, callback);
+    }
+
     // Internal helper to bind AUI events
     fn bind_aui_event<F>(&self, event_type: EventType, mut callback: F)
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
+        let manager = *self;
         self.bind_internal(event_type, move |event| {
-            let data = AuiManagerEventData::new(event);
+            let data = AuiManagerEventData {
+                event,
+                manager: Some(manager),
+            };
             callback(data);
         });
     }
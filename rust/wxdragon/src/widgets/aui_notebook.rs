@@ -254,3 +254,26 @@ widget_builder!(
         AuiNotebook::from_ptr(ptr)
     }
 );
+
+// XRC Support - enables AuiNotebook to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for AuiNotebook {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        AuiNotebook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Enable widget casting for AuiNotebook
+impl crate::window::FromWindowWithClassName for AuiNotebook {
+    fn class_name() -> &'static str {
+        "wxAuiNotebook"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        AuiNotebook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
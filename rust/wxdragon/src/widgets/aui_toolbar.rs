@@ -1,6 +1,7 @@
 use std::ffi::CString;
 use std::os::raw::{c_int, c_longlong};
 
+use crate::bitmap_bundle::BitmapBundleArg;
 use crate::event::{Event, EventType, WxEvtHandler};
 use crate::prelude::*;
 use crate::window::{WindowHandle, WxWidget};
@@ -45,7 +46,7 @@ pub enum AuiToolBarEvent {
     ToolEnter,
     /// Tool right-clicked event
     ToolRightClicked,
-    /// Tool dropdown button clicked
+    /// The dropdown arrow of a tool made with `set_tool_drop_down` was clicked
     ToolDropDown,
     /// Menu event
     Menu,
@@ -57,6 +58,8 @@ pub struct AuiToolBarEventData {
     event: Event,
 }
 
+crate::impl_event_base!(AuiToolBarEventData => event);
+
 impl AuiToolBarEventData {
     /// Create a new AuiToolBarEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -82,6 +85,24 @@ impl AuiToolBarEventData {
     pub fn is_checked(&self) -> Option<bool> {
         self.event.is_checked()
     }
+
+    /// For a dropdown event, whether the dropdown arrow rather than the tool itself was clicked
+    pub fn is_drop_down_clicked(&self) -> bool {
+        unsafe { ffi::wxd_AuiToolBarEvent_IsDropDownClicked(self.event.0) }
+    }
+
+    /// The rectangle of the tool in toolbar coordinates, e.g. for placing a dropdown menu
+    /// below it
+    pub fn get_item_rect(&self) -> Rect {
+        let rect = unsafe { ffi::wxd_AuiToolBarEvent_GetItemRect(self.event.0) };
+        Rect::new(rect.x, rect.y, rect.width, rect.height)
+    }
+
+    /// The point where the tool was clicked, in screen coordinates
+    pub fn get_click_point(&self) -> Point {
+        let point = unsafe { ffi::wxd_AuiToolBarEvent_GetClickPoint(self.event.0) };
+        Point::new(point.x, point.y)
+    }
 }
 
 /// Represents a wxAuiToolBar.
@@ -91,14 +112,30 @@ impl AuiToolBarEventData {
 /// its parent is destroyed), the handle becomes invalid and all operations
 /// become safe no-ops.
 ///
+/// Unlike [`ToolBar`](crate::ToolBar), an AuiToolBar can be docked and floated as a pane
+/// of an [`AuiManager`](crate::AuiManager), shows an overflow menu for tools that don't
+/// fit, and supports tools with dropdown menus.
+///
 /// # Example
 /// ```ignore
 /// let toolbar = AuiToolBar::builder(&frame).build();
 ///
 /// // AuiToolBar is Copy - no clone needed for closures!
-/// toolbar.add_tool(1, "New", "Create new file", ItemKind::Normal);
+/// toolbar.add_tool(ID_OPEN, "Open", &open_icon, ItemKind::Normal);
+/// toolbar.set_tool_drop_down(ID_OPEN, true);
 /// toolbar.realize();
 ///
+/// toolbar.on_tool_dropdown(move |event| {
+///     if event.is_drop_down_clicked() {
+///         let rect = event.get_item_rect();
+///         let pos = toolbar.client_to_screen(Point::new(rect.x, rect.y + rect.height));
+///         toolbar.popup_menu(&mut recent_menu, Some(pos));
+///     }
+/// });
+///
+/// // Dock it in the top toolbar row of the manager
+/// manager.add_pane(&toolbar, AuiPaneInfo::new().with_name("file_tools").toolbar_pane().top());
+///
 /// // After parent destruction, toolbar operations are safe no-ops
 /// frame.destroy();
 /// assert!(!toolbar.is_valid());
@@ -132,27 +169,40 @@ impl AuiToolBar {
             .unwrap_or(std::ptr::null_mut())
     }
 
-    /// Adds a tool to the toolbar.
+    /// Adds a tool showing `bitmap`, a [`BitmapBundle`](crate::BitmapBundle) or a single
+    /// `Bitmap`. `label` is shown with the `Text` style and in the overflow menu, and used
+    /// as the tooltip until one is set with [`set_tool_short_help`](Self::set_tool_short_help).
     /// No-op if the toolbar has been destroyed.
-    pub fn add_tool(&self, tool_id: i32, label: &str, short_help_string: &str, kind: ItemKind) {
+    pub fn add_tool<'a>(&self, tool_id: i32, label: &str, bitmap: impl Into<BitmapBundleArg<'a>>, kind: ItemKind) {
         let ptr = self.toolbar_ptr();
         if ptr.is_null() {
             return;
         }
+        let bitmap = bitmap.into();
         let c_label = CString::new(label).unwrap_or_default();
-        let c_short_help = CString::new(short_help_string).unwrap_or_default();
         unsafe {
             ffi::wxd_AuiToolBar_AddTool(
                 ptr,
                 tool_id as c_int,
                 c_label.as_ptr(),
-                // Bitmaps are currently omitted in C API
-                c_short_help.as_ptr(),
+                bitmap.as_ptr(),
+                c_label.as_ptr(),
                 kind as ffi::WXDItemKindCEnum,
             );
         }
     }
 
+    /// Sets the tooltip of a tool.
+    /// No-op if the toolbar has been destroyed.
+    pub fn set_tool_short_help(&self, tool_id: i32, help: &str) {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_help = CString::new(help).unwrap_or_default();
+        unsafe { ffi::wxd_AuiToolBar_SetToolShortHelp(ptr, tool_id as c_int, c_help.as_ptr()) };
+    }
+
     /// Adds a label to the toolbar.
     /// No-op if the toolbar has been destroyed.
     pub fn add_label(&self, tool_id: i32, label: &str, width: i32) {
@@ -164,9 +214,10 @@ impl AuiToolBar {
         unsafe { ffi::wxd_AuiToolBar_AddLabel(ptr, tool_id as c_int, c_label.as_ptr(), width as c_int) };
     }
 
-    /// Adds a control to the toolbar.
+    /// Adds a control, e.g. a search box, created with this toolbar as its parent.
+    /// `label` is used in the overflow menu and with the `Text` style.
     /// No-op if the toolbar has been destroyed.
-    pub fn add_control<C: WxWidget>(&self, control: &C, label: &str) {
+    pub fn add_control(&self, control: &dyn WxWidget, label: &str) {
         let ptr = self.toolbar_ptr();
         if ptr.is_null() {
             return;
@@ -276,7 +327,8 @@ impl AuiToolBar {
         unsafe { ffi::wxd_AuiToolBar_GetGripperVisible(ptr) }
     }
 
-    /// Sets whether a tool has a dropdown.
+    /// Sets whether a tool has a dropdown arrow; clicking it fires
+    /// [`on_tool_dropdown`](Self::on_tool_dropdown), typically to show a menu.
     /// No-op if the toolbar has been destroyed.
     pub fn set_tool_drop_down(&self, tool_id: i32, dropdown: bool) {
         let ptr = self.toolbar_ptr();
@@ -316,6 +368,36 @@ impl AuiToolBar {
         unsafe { ffi::wxd_AuiToolBar_GetToolEnabled(ptr, tool_id as c_int) }
     }
 
+    /// Checks or unchecks a `Check` or `Radio` tool.
+    /// No-op if the toolbar has been destroyed.
+    pub fn toggle_tool(&self, tool_id: i32, state: bool) {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_AuiToolBar_ToggleTool(ptr, tool_id as c_int, state) };
+    }
+
+    /// Gets whether a `Check` or `Radio` tool is checked.
+    /// Returns false if the toolbar has been destroyed.
+    pub fn get_tool_toggled(&self, tool_id: i32) -> bool {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_AuiToolBar_GetToolToggled(ptr, tool_id as c_int) }
+    }
+
+    /// Keeps a tool drawn as pressed, e.g. while its dropdown menu is shown.
+    /// No-op if the toolbar has been destroyed.
+    pub fn set_tool_sticky(&self, tool_id: i32, sticky: bool) {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_AuiToolBar_SetToolSticky(ptr, tool_id as c_int, sticky) };
+    }
+
     /// Gets the number of tools.
     /// Returns 0 if the toolbar has been destroyed.
     pub fn get_tool_count(&self) -> i32 {
@@ -402,9 +484,9 @@ crate::implement_widget_local_event_handlers!(
     AuiToolBar,
     AuiToolBarEvent,
     AuiToolBarEventData,
-    ToolClicked => tool_clicked, EventType::COMMAND_BUTTON_CLICKED,
+    ToolClicked => tool_clicked, EventType::MENU, // wxEVT_TOOL is the same event type as wxEVT_MENU
     ToolEnter => tool_enter, EventType::TOOL_ENTER,
-    ToolRightClicked => tool_right_clicked, EventType::RIGHT_UP,
-    ToolDropDown => tool_dropdown, EventType::COMMAND_BUTTON_CLICKED, // No specific dropdown event, so use button clicked
+    ToolRightClicked => tool_right_clicked, EventType::AUITOOLBAR_RIGHT_CLICK,
+    ToolDropDown => tool_dropdown, EventType::AUITOOLBAR_TOOL_DROPDOWN,
     Menu => menu, EventType::MENU // Add menu event support
 );
@@ -1,7 +1,7 @@
 //!
 //! Safe wrapper for wxBitmapButton.
 
-use crate::bitmap::Bitmap;
+use crate::bitmap_bundle::BitmapBundleArg;
 use crate::event::WxEvtHandler;
 use crate::event::button_events::ButtonEvents;
 use crate::geometry::{Point, Size};
@@ -38,7 +38,7 @@ widget_style_enum!(
 ///
 /// # Example
 /// ```ignore
-/// let button = BitmapButton::builder(&frame).bitmap(&my_bitmap).build();
+/// let button = BitmapButton::builder(&frame).with_bitmap(&my_bitmap).build();
 ///
 /// // BitmapButton is Copy - no clone needed for closures!
 /// button.bind_click(move |_| {
@@ -59,117 +59,138 @@ pub struct BitmapButton {
 // Implement ButtonEvents trait for BitmapButton
 impl ButtonEvents for BitmapButton {}
 
-/// Configuration for creating a BitmapButton
-#[derive(Debug)]
-struct BitmapButtonConfig {
-    pub parent_ptr: *mut ffi::wxd_Window_t,
-    pub id: Id,
-    pub bitmap_ptr: *const ffi::wxd_Bitmap_t,
-    pub pos: Point,
-    pub size: Size,
-    pub style: i64,
-    pub name: String,
-    pub bmp_disabled_ptr: *const ffi::wxd_Bitmap_t,
-    pub bmp_focus_ptr: *const ffi::wxd_Bitmap_t,
-    pub bmp_hover_ptr: *const ffi::wxd_Bitmap_t,
-}
-
 impl BitmapButton {
     /// Creates a new BitmapButton builder.
     pub fn builder(parent: &dyn WxWidget) -> BitmapButtonBuilder<'_> {
         BitmapButtonBuilder::new(parent)
     }
 
-    /// Low-level constructor used by the builder.
-    fn new_impl(config: BitmapButtonConfig) -> Self {
-        let c_name = CString::new(config.name).unwrap_or_default();
-
-        unsafe {
-            let ptr = ffi::wxd_BitmapButton_Create(
-                config.parent_ptr,
-                config.id as c_int,
-                config.bitmap_ptr,
-                config.pos.into(),
-                config.size.into(),
-                config.style as ffi::wxd_Style_t,
-                c_name.as_ptr(),
-                config.bmp_disabled_ptr,
-                config.bmp_focus_ptr,
-                config.bmp_hover_ptr,
-            );
-
-            if ptr.is_null() {
-                panic!("Failed to create BitmapButton widget");
-            } else {
-                BitmapButton {
-                    handle: WindowHandle::new(ptr as *mut ffi::wxd_Window_t),
-                }
-            }
-        }
-    }
-
     /// Returns the underlying WindowHandle for this bitmap button.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
     }
 }
 
-widget_builder!(
-    name: BitmapButton,
-    parent_type: &'a dyn WxWidget,
-    style_type: BitmapButtonStyle,
-    fields: {
-        bitmap: Option<Bitmap> = None,
-        bitmap_disabled: Option<Bitmap> = None,
-        bitmap_focus: Option<Bitmap> = None,
-        bitmap_hover: Option<Bitmap> = None,
-        name: String = "BitmapButton".to_string()
-    },
-    build_impl: |slf| {
-        let parent_ptr = slf.parent.handle_ptr();
-        let bitmap_ptr = match &slf.bitmap {
-            Some(bitmap) => bitmap.as_const_ptr(),
-            None => panic!("BitmapButton requires a bitmap to be set"),
-        };
+/// Builder for [`BitmapButton`].
+///
+/// The images accept a [`BitmapBundle`](crate::bitmap_bundle::BitmapBundle) or a single
+/// `Bitmap`. Unless a size is set, the button is sized to fit the image at the window's DPI.
+#[derive(Clone)]
+pub struct BitmapButtonBuilder<'a> {
+    parent: &'a dyn WxWidget,
+    id: Id,
+    pos: Point,
+    size: Size,
+    style: BitmapButtonStyle,
+    bitmap: Option<BitmapBundleArg<'a>>,
+    bitmap_disabled: Option<BitmapBundleArg<'a>>,
+    bitmap_focus: Option<BitmapBundleArg<'a>>,
+    bitmap_hover: Option<BitmapBundleArg<'a>>,
+    name: String,
+}
 
-        let bmp_disabled_ptr = slf.bitmap_disabled
-            .as_ref()
-            .map_or(std::ptr::null(), |b| b.as_const_ptr());
-        let bmp_focus_ptr = slf.bitmap_focus
-            .as_ref()
-            .map_or(std::ptr::null(), |b| b.as_const_ptr());
-        let bmp_hover_ptr = slf.bitmap_hover
-            .as_ref()
-            .map_or(std::ptr::null(), |b| b.as_const_ptr());
-
-        // For BitmapButton, size is often best derived from the bitmap if not explicitly set
-        // and if a bitmap is provided
-        let final_size = if slf.size.width == -1 && slf.size.height == -1 {
-            if let Some(bmp) = &slf.bitmap {
-                Size::new(bmp.get_width(), bmp.get_height())
-            } else {
-                slf.size
-            }
-        } else {
-            slf.size
-        };
+impl<'a> BitmapButtonBuilder<'a> {
+    /// Creates a builder for a button on `parent`.
+    pub fn new(parent: &'a dyn WxWidget) -> Self {
+        Self {
+            parent,
+            id: crate::id::ID_ANY as Id,
+            pos: Point::DEFAULT_POSITION,
+            size: Size::DEFAULT_SIZE,
+            style: BitmapButtonStyle::default(),
+            bitmap: None,
+            bitmap_disabled: None,
+            bitmap_focus: None,
+            bitmap_hover: None,
+            name: "BitmapButton".to_string(),
+        }
+    }
 
-        let config = BitmapButtonConfig {
-            parent_ptr,
-            id: slf.id,
-            bitmap_ptr,
-            pos: slf.pos,
-            size: final_size,
-            style: slf.style.bits(),
-            name: slf.name,
-            bmp_disabled_ptr,
-            bmp_focus_ptr,
-            bmp_hover_ptr,
-        };
+    /// Sets the window identifier.
+    pub fn with_id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
 
-        BitmapButton::new_impl(config)
+    /// Sets the position.
+    pub fn with_pos(mut self, pos: Point) -> Self {
+        self.pos = pos;
+        self
     }
-);
+
+    /// Sets the size.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the window style flags.
+    pub fn with_style(mut self, style: BitmapButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the image shown in the normal state (required).
+    pub fn with_bitmap(mut self, bitmap: impl Into<BitmapBundleArg<'a>>) -> Self {
+        self.bitmap = Some(bitmap.into());
+        self
+    }
+
+    /// Sets the image shown when the button is disabled.
+    pub fn with_bitmap_disabled(mut self, bitmap: impl Into<BitmapBundleArg<'a>>) -> Self {
+        self.bitmap_disabled = Some(bitmap.into());
+        self
+    }
+
+    /// Sets the image shown when the button has the focus.
+    pub fn with_bitmap_focus(mut self, bitmap: impl Into<BitmapBundleArg<'a>>) -> Self {
+        self.bitmap_focus = Some(bitmap.into());
+        self
+    }
+
+    /// Sets the image shown when the mouse is over the button.
+    pub fn with_bitmap_hover(mut self, bitmap: impl Into<BitmapBundleArg<'a>>) -> Self {
+        self.bitmap_hover = Some(bitmap.into());
+        self
+    }
+
+    /// Sets the window name.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Builds the widget.
+    ///
+    /// # Panics
+    /// If no bitmap was set or the button could not be created.
+    pub fn build(self) -> BitmapButton {
+        let bitmap = self.bitmap.as_ref().expect("BitmapButton requires a bitmap to be set");
+        let optional_ptr = |b: &Option<BitmapBundleArg>| b.as_ref().map_or(std::ptr::null(), |b| b.as_ptr().cast_const());
+        let c_name = CString::new(self.name.as_str()).unwrap_or_default();
+
+        let ptr = unsafe {
+            ffi::wxd_BitmapButton_Create(
+                self.parent.handle_ptr(),
+                self.id as c_int,
+                bitmap.as_ptr(),
+                self.pos.into(),
+                self.size.into(),
+                self.style.bits() as ffi::wxd_Style_t,
+                c_name.as_ptr(),
+                optional_ptr(&self.bitmap_disabled),
+                optional_ptr(&self.bitmap_focus),
+                optional_ptr(&self.bitmap_hover),
+            )
+        };
+        if ptr.is_null() {
+            panic!("Failed to create BitmapButton widget");
+        }
+        BitmapButton {
+            handle: WindowHandle::new(ptr as *mut ffi::wxd_Window_t),
+        }
+    }
+}
 
 // Manual WxWidget implementation for BitmapButton (using WindowHandle)
 impl WxWidget for BitmapButton {
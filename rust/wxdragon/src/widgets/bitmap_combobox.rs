@@ -9,7 +9,10 @@ use crate::window::{WindowHandle, WxWidget};
 use std::ffi::{CStr, CString};
 use wxdragon_sys as ffi;
 
-/// Represents a wxBitmapComboBox widget.
+/// Represents a wxBitmapComboBox widget, a combobox showing a bitmap next to each item.
+///
+/// The first bitmap added fixes the size of all of them: bitmaps of another size are
+/// scaled to it, so items with differently sized images still line up.
 ///
 /// BitmapComboBox uses `WindowHandle` internally for safe memory management.
 /// When the underlying window is destroyed (by calling `destroy()` or when
@@ -37,6 +40,8 @@ impl BitmapComboBox {
 
     /// Appends an item with an optional bitmap to the combobox.
     /// No-op if the combobox has been destroyed.
+    ///
+    /// The bitmap is scaled to [`get_bitmap_size`](Self::get_bitmap_size) if needed.
     pub fn append(&self, item: &str, bitmap: Option<&Bitmap>) {
         let ptr = self.bitmap_combobox_ptr();
         if ptr.is_null() {
@@ -50,7 +55,9 @@ impl BitmapComboBox {
     }
 
     /// Inserts an item with an optional bitmap into the combobox at the specified position.
-    /// No-op if the combobox has been destroyed.
+    /// No-op if the combobox has been destroyed or `pos` is past the end.
+    ///
+    /// The bitmap is scaled to [`get_bitmap_size`](Self::get_bitmap_size) if needed.
     pub fn insert(&self, item: &str, bitmap: Option<&Bitmap>, pos: usize) {
         let ptr = self.bitmap_combobox_ptr();
         if ptr.is_null() {
@@ -63,6 +70,16 @@ impl BitmapComboBox {
         }
     }
 
+    /// Deletes the item at the specified index.
+    /// No-op if the index is invalid or the combobox has been destroyed.
+    pub fn delete(&self, n: u32) {
+        let ptr = self.bitmap_combobox_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_BitmapComboBox_Delete(ptr, n) };
+    }
+
     /// Clears all items from the combobox.
     pub fn clear(&self) {
         let ptr = self.bitmap_combobox_ptr();
@@ -82,6 +99,16 @@ impl BitmapComboBox {
         unsafe { ffi::wxd_BitmapComboBox_GetSelection(ptr) }
     }
 
+    /// Gets the text of the currently selected item.
+    /// Returns `None` if no item is selected or the bitmap combobox has been destroyed.
+    pub fn get_string_selection(&self) -> Option<String> {
+        let selection = self.get_selection();
+        if selection < 0 {
+            return None;
+        }
+        Some(self.get_string(selection as u32))
+    }
+
     /// Sets the selection to the given item index.
     /// No-op if the bitmap combobox has been destroyed.
     pub fn set_selection(&self, index: i32) {
@@ -163,6 +190,8 @@ impl BitmapComboBox {
 
     /// Sets the bitmap for the item at the specified index.
     /// No-op if the bitmap combobox has been destroyed.
+    ///
+    /// The bitmap is scaled to [`get_bitmap_size`](Self::get_bitmap_size) if needed.
     pub fn set_item_bitmap(&self, n: u32, bitmap: &Bitmap) {
         let ptr = self.bitmap_combobox_ptr();
         if ptr.is_null() {
@@ -170,6 +199,17 @@ impl BitmapComboBox {
         }
         unsafe { ffi::wxd_BitmapComboBox_SetItemBitmap(ptr, n, bitmap.as_const_ptr()) };
     }
+
+    /// Gets the size all item bitmaps are shown at, that of the first bitmap added.
+    /// Returns `None` before any bitmap was added or if the bitmap combobox has been destroyed.
+    pub fn get_bitmap_size(&self) -> Option<Size> {
+        let ptr = self.bitmap_combobox_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let size = unsafe { ffi::wxd_BitmapComboBox_GetBitmapSize(ptr) };
+        (size.width > 0 && size.height > 0).then(|| Size::new(size.width, size.height))
+    }
 }
 
 // Use the widget_builder macro for BitmapComboBox
@@ -131,6 +131,8 @@ pub struct CalendarEventData {
     event: Event, // Calendar events are command events
 }
 
+crate::impl_event_base!(CalendarEventData => event);
+
 impl CalendarEventData {
     /// Creates new `CalendarEventData` from base `Event`.
     pub(crate) fn new(event: Event) -> Self {
@@ -105,6 +105,8 @@ pub struct CheckBoxEventData {
     base: CommandEventData,
 }
 
+crate::impl_event_base!(CheckBoxEventData => base.event);
+
 impl CheckBoxEventData {
     /// Creates new `CheckBoxEventData` from base `CommandEventData`.
     pub(crate) fn new(event: Event) -> Self {
@@ -28,8 +28,9 @@ widget_style_enum!(
 pub enum CheckListBoxEvent {
     /// Emitted when an item is selected
     Selected,
-    /// Emitted when a checkbox is toggled
-    Toggled,
+    /// Emitted when the user checks or unchecks an item; changes made with `check`,
+    /// `set_checked_items` or `check_all` don't emit it
+    ItemToggled,
     /// Emitted when an item is double-clicked
     DoubleClicked,
 }
@@ -40,6 +41,8 @@ pub struct CheckListBoxEventData {
     event: Event,
 }
 
+crate::impl_event_base!(CheckListBoxEventData => event);
+
 impl CheckListBoxEventData {
     /// Create a new CheckListBoxEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -57,9 +60,12 @@ impl CheckListBoxEventData {
         self.event.get_string()
     }
 
-    /// Get whether the checkbox was checked or unchecked (for Toggled events)
+    /// Get whether the item is now checked (for ItemToggled events)
     pub fn is_checked(&self) -> Option<bool> {
-        self.event.is_checked()
+        // The event's integer is the item index, so ask the control for the new state
+        let index = self.get_selection()?;
+        let clbox = self.event.get_event_object()?.handle_ptr() as *mut ffi::wxd_CheckListBox_t;
+        Some(unsafe { ffi::wxd_CheckListBox_IsChecked(clbox, index) })
     }
 }
 
@@ -75,7 +81,7 @@ impl CheckListBoxEventData {
 /// let checklist = CheckListBox::builder(&frame).build();
 ///
 /// // CheckListBox is Copy - no clone needed for closures!
-/// checklist.bind_toggled(move |_| {
+/// checklist.on_item_toggled(move |_| {
 ///     // Safe: if checklist was destroyed, this is a no-op
 ///     checklist.append("New item");
 /// });
@@ -227,6 +233,41 @@ impl CheckListBox {
         unsafe { ffi::wxd_CheckListBox_Check(ptr, index, check) }
     }
 
+    /// Gets the indices of all checked items, in ascending order.
+    /// Returns an empty vector if destroyed.
+    pub fn get_checked_items(&self) -> Vec<u32> {
+        let ptr = self.checklistbox_ptr();
+        if ptr.is_null() {
+            return Vec::new();
+        }
+        let count = unsafe { ffi::wxd_CheckListBox_GetCheckedItems(ptr, std::ptr::null_mut(), 0) };
+        let mut items = vec![0; count];
+        let count = unsafe { ffi::wxd_CheckListBox_GetCheckedItems(ptr, items.as_mut_ptr(), items.len()) };
+        items.truncate(count);
+        items
+    }
+
+    /// Checks exactly the items at the given indices and unchecks all others.
+    /// Indices out of bounds are ignored; no `ItemToggled` events are emitted.
+    /// Does nothing if destroyed.
+    pub fn set_checked_items(&self, indices: &[u32]) {
+        let ptr = self.checklistbox_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_CheckListBox_SetCheckedItems(ptr, indices.as_ptr(), indices.len()) }
+    }
+
+    /// Checks or unchecks all items at once, without emitting `ItemToggled` events.
+    /// Does nothing if destroyed.
+    pub fn check_all(&self, check: bool) {
+        let ptr = self.checklistbox_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_CheckListBox_CheckAll(ptr, check) }
+    }
+
     /// Returns the underlying WindowHandle for this checklistbox.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
@@ -260,8 +301,10 @@ crate::implement_widget_local_event_handlers!(
     CheckListBoxEvent,
     CheckListBoxEventData,
     Selected => selected, EventType::COMMAND_LISTBOX_SELECTED,
-    Toggled => toggled, EventType::COMMAND_CHECKLISTBOX_SELECTED,
-    DoubleClicked => double_clicked, EventType::COMMAND_LISTBOX_DOUBLECLICKED
+    ItemToggled => item_toggled, EventType::COMMAND_CHECKLISTBOX_SELECTED,
+    DoubleClicked => double_clicked, EventType::COMMAND_LISTBOX_DOUBLECLICKED;
+    deprecated:
+    toggled => item_toggled, "renamed to `on_item_toggled`"
 );
 
 // XRC Support - enables CheckListBox to be created from XRC-managed pointers
@@ -241,6 +241,8 @@ pub struct ChoiceEventData {
     pub event: CommandEventData,
 }
 
+crate::impl_event_base!(ChoiceEventData => event.event);
+
 impl ChoiceEventData {
     pub fn new(event: Event) -> Self {
         Self {
@@ -0,0 +1,306 @@
+//!
+//! Safe wrapper for wxChoicebook.
+
+use crate::event::WxEvtHandler;
+use crate::geometry::{Point, Size};
+use crate::id::Id;
+use crate::widgets::imagelist::ImageList;
+use crate::window::{Window, WindowHandle, WxWidget};
+use std::ffi::{CStr, CString};
+use wxdragon_sys as ffi;
+
+// --- Choicebook Styles ---
+widget_style_enum!(
+    name: ChoicebookStyle,
+    doc: "Style flags for Choicebook widget.",
+    variants: {
+        Default: ffi::WXD_BK_DEFAULT, "Default style.",
+        Top: ffi::WXD_BK_TOP, "Place the choice control at the top.",
+        Bottom: ffi::WXD_BK_BOTTOM, "Place the choice control at the bottom.",
+        Left: ffi::WXD_BK_LEFT, "Place the choice control at the left.",
+        Right: ffi::WXD_BK_RIGHT, "Place the choice control at the right."
+    },
+    default_variant: Default
+);
+
+/// Represents a wxChoicebook control.
+///
+/// A book control that selects pages with a choice (drop-down) control.
+///
+/// Page changes are reported by the [`BookCtrlEvents`](crate::event::BookCtrlEvents) methods
+/// `on_page_changed` and `on_page_changing`; the latter can veto the change.
+///
+/// Choicebook uses `WindowHandle` internally for safe memory management.
+/// When the underlying window is destroyed (by calling `destroy()` or when
+/// its parent is destroyed), the handle becomes invalid and all operations
+/// become safe no-ops.
+#[derive(Clone, Copy)]
+pub struct Choicebook {
+    /// Safe handle to the underlying wxChoicebook - automatically invalidated on destroy
+    handle: WindowHandle,
+}
+
+impl Choicebook {
+    /// Creates a new Choicebook builder.
+    pub fn builder(parent: &dyn WxWidget) -> ChoicebookBuilder<'_> {
+        ChoicebookBuilder::new(parent)
+    }
+
+    /// Helper to get raw window pointer, returns null if widget has been destroyed
+    #[inline]
+    fn book_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    /// Internal implementation used by the builder.
+    fn new_impl(parent_ptr: *mut ffi::wxd_Window_t, id: Id, pos: Point, size: Size, style: i64) -> Self {
+        assert!(!parent_ptr.is_null(), "Choicebook parent cannot be null");
+
+        let ptr = unsafe {
+            ffi::wxd_Choicebook_new(
+                parent_ptr,
+                id,
+                pos.x,
+                pos.y,
+                size.width,
+                size.height,
+                style as ffi::wxd_Style_t,
+            )
+        };
+
+        if ptr.is_null() {
+            panic!("Failed to create wxChoicebook");
+        }
+
+        Choicebook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+
+    /// Adds a new page to the choicebook.
+    /// `image_id` is an index into the image list, or -1 for no image.
+    /// Returns `false` if the page could not be added or the choicebook has been destroyed.
+    pub fn add_page<W: WxWidget>(&self, page: &W, text: &str, select: bool, image_id: i32) -> bool {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let text_c = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_BookCtrl_AddPage(ptr, page.handle_ptr(), text_c.as_ptr(), select, image_id) }
+    }
+
+    /// Inserts a new page before the page at `index`; `index` equal to the page count adds it
+    /// at the end. `image_id` is an index into the image list, or -1 for no image.
+    /// Returns `false` if the page could not be inserted or the choicebook has been destroyed.
+    pub fn insert_page<W: WxWidget>(&self, index: usize, page: &W, text: &str, select: bool, image_id: i32) -> bool {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let text_c = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_BookCtrl_InsertPage(ptr, index, page.handle_ptr(), text_c.as_ptr(), select, image_id) }
+    }
+
+    /// Removes the page at the given index and destroys its window.
+    /// Returns `false` if the index is out of range or the choicebook has been destroyed.
+    pub fn delete_page(&self, index: usize) -> bool {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_BookCtrl_DeletePage(ptr, index) }
+    }
+
+    /// Removes the page at the given index without destroying its window.
+    /// Returns `false` if the index is out of range or the choicebook has been destroyed.
+    pub fn remove_page(&self, index: usize) -> bool {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_BookCtrl_RemovePage(ptr, index) }
+    }
+
+    /// Gets the number of pages in the choicebook.
+    /// Returns 0 if the choicebook has been destroyed.
+    pub fn get_page_count(&self) -> usize {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_BookCtrl_GetPageCount(ptr) as usize }
+    }
+
+    /// Returns the page at the given index, or `None` if the index is out of range.
+    pub fn get_page(&self, index: usize) -> Option<Window> {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let page_ptr = unsafe { ffi::wxd_BookCtrl_GetPage(ptr, index) };
+        if page_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Window::from_ptr(page_ptr) })
+        }
+    }
+
+    /// Gets the currently selected page, or -1 if none is selected.
+    /// Returns -1 if the choicebook has been destroyed.
+    pub fn get_selection(&self) -> i32 {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_BookCtrl_GetSelection(ptr) }
+    }
+
+    /// Sets the selection to the given page index.
+    /// Returns the index of the previously selected page, or -1 on failure.
+    pub fn set_selection(&self, n: usize) -> i32 {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_BookCtrl_SetSelection(ptr, n) }
+    }
+
+    /// Changes the selection to the given page without sending page changing and changed
+    /// events.
+    /// Returns the index of the previously selected page, or -1 on failure.
+    pub fn change_selection(&self, n: usize) -> i32 {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_BookCtrl_ChangeSelection(ptr, n) }
+    }
+
+    /// Sets the text for the given page.
+    /// No-op if the choicebook has been destroyed.
+    pub fn set_page_text(&self, n: usize, text: &str) {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let text_c = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_BookCtrl_SetPageText(ptr, n, text_c.as_ptr()) };
+    }
+
+    /// Gets the text for the given page.
+    /// Returns an empty string if the index is out of range or the choicebook has been destroyed.
+    pub fn get_page_text(&self, n: usize) -> String {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return String::new();
+        }
+        let len = unsafe { ffi::wxd_BookCtrl_GetPageText(ptr, n, std::ptr::null_mut(), 0) };
+        if len <= 0 {
+            return String::new();
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_BookCtrl_GetPageText(ptr, n, buf.as_mut_ptr(), buf.len()) };
+        unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() }
+    }
+
+    /// Sets the image list used for the page images.
+    /// The choicebook takes ownership of the image list.
+    /// No-op if the choicebook has been destroyed.
+    pub fn set_image_list(&self, image_list: ImageList) {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_BookCtrl_AssignImageList(ptr, image_list.as_ptr()) };
+        // The choicebook now owns the ImageList, so we forget it in Rust
+        // to prevent a double free.
+        std::mem::forget(image_list);
+    }
+
+    /// Sets the image of the given page to `image_id` in the image list, or -1 for none.
+    /// Returns `false` if the index is out of range or the choicebook has been destroyed.
+    pub fn set_page_image(&self, n: usize, image_id: i32) -> bool {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_BookCtrl_SetPageImage(ptr, n, image_id) }
+    }
+
+    /// Gets the index in the image list of the image of the given page.
+    /// Returns -1 if the page has no image, the index is out of range or the choicebook has
+    /// been destroyed.
+    pub fn get_page_image(&self, n: usize) -> i32 {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_BookCtrl_GetPageImage(ptr, n) }
+    }
+
+    /// Returns the underlying WindowHandle for this choicebook.
+    pub fn window_handle(&self) -> WindowHandle {
+        self.handle
+    }
+}
+
+// Manual WxWidget implementation for Choicebook (using WindowHandle)
+impl WxWidget for Choicebook {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.handle.is_valid()
+    }
+}
+
+// Implement WxEvtHandler for event binding
+impl WxEvtHandler for Choicebook {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut()) as *mut ffi::wxd_EvtHandler_t
+    }
+}
+
+// Implement common event traits that all Window-based widgets support
+impl crate::event::WindowEvents for Choicebook {}
+
+// Use the widget_builder macro for Choicebook
+widget_builder!(
+    name: Choicebook,
+    parent_type: &'a dyn WxWidget,
+    style_type: ChoicebookStyle,
+    fields: {},
+    build_impl: |slf| {
+        Choicebook::new_impl(
+            slf.parent.handle_ptr(),
+            slf.id,
+            slf.pos,
+            slf.size,
+            slf.style.bits()
+        )
+    }
+);
+
+// XRC Support - enables Choicebook to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for Choicebook {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        Choicebook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Enable widget casting for Choicebook
+impl crate::window::FromWindowWithClassName for Choicebook {
+    fn class_name() -> &'static str {
+        "wxChoicebook"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        Choicebook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
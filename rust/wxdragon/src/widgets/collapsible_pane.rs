@@ -247,6 +247,8 @@ pub struct CollapsiblePaneEventData {
     event: Event,
 }
 
+crate::impl_event_base!(CollapsiblePaneEventData => event);
+
 impl CollapsiblePaneEventData {
     /// Create a new CollapsiblePaneEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -36,6 +36,8 @@ pub struct ColourPickerCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(ColourPickerCtrlEventData => event);
+
 impl ColourPickerCtrlEventData {
     /// Create a new ColourPickerCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -0,0 +1,339 @@
+//! Safe wrapper for wxComboCtrl.
+
+use crate::event::{Event, EventType, TextEvents, WxEvtHandler};
+use crate::geometry::{Point, Size};
+use crate::id::Id;
+use crate::sizers::{BoxSizer, Orientation, SizerFlag};
+use crate::widgets::panel::Panel;
+use crate::window::{WindowHandle, WxWidget};
+use std::ffi::{CStr, CString, c_void};
+use std::ptr::null_mut;
+use wxdragon_sys as ffi;
+
+type StringValueFn = Box<dyn Fn() -> Option<String>>;
+
+// --- Style enum using macro ---
+widget_style_enum!(
+    name: ComboCtrlStyle,
+    doc: "Style flags for ComboCtrl widget.",
+    variants: {
+        Default: 0, "Default style: the text can be edited and the button opens the popup.",
+        ReadOnly: ffi::WXD_CB_READONLY, "The text can't be edited, it only shows the value picked in the popup.",
+        ProcessEnter: ffi::WXD_TE_PROCESS_ENTER, "Process the Enter key, generating a TEXT_ENTER event."
+    },
+    default_variant: Default
+);
+
+/// Events emitted by ComboCtrl
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComboCtrlEvent {
+    /// Emitted when the popup has been shown
+    PopupShown,
+    /// Emitted when the popup has been dismissed, after the text has been updated
+    PopupDismissed,
+}
+
+/// Event data for a ComboCtrl event
+#[derive(Debug)]
+pub struct ComboCtrlEventData {
+    event: Event,
+}
+
+crate::impl_event_base!(ComboCtrlEventData => event);
+
+impl ComboCtrlEventData {
+    /// Create a new ComboCtrlEventData from a generic Event
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Get the ID of the control that generated the event
+    pub fn get_id(&self) -> i32 {
+        self.event.get_id()
+    }
+
+    /// Skip this event (allow it to be processed by the parent window)
+    pub fn skip(&self, skip: bool) {
+        self.event.skip(skip);
+    }
+}
+
+/// Represents a wxComboCtrl, a text field with a button opening a popup that can hold any
+/// widget, e.g. a tree or a calendar to pick the value from.
+///
+/// The popup content is built by the factory passed to
+/// [`set_popup_content`](Self::set_popup_content), which also says what text the field
+/// shows when the popup is dismissed. The popup is as wide as the control unless
+/// [`set_popup_size`](Self::set_popup_size) says otherwise, and as high as its content
+/// wants to be. The text field has the text events and accessors of a single-line
+/// `TextCtrl`, see [`TextEvents`] and [`WxTextEntry`](crate::text_entry::WxTextEntry).
+///
+/// ComboCtrl uses `WindowHandle` internally for safe memory management.
+/// When the underlying window is destroyed (by calling `destroy()` or when
+/// its parent is destroyed), the handle becomes invalid and all operations
+/// become safe no-ops.
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// let combo = ComboCtrl::builder(&frame).with_style(ComboCtrlStyle::ReadOnly).build();
+/// let tree = combo
+///     .set_popup_content(
+///         |panel| TreeCtrl::builder(panel).with_style(TreeCtrlStyle::HideRoot).build(),
+///         |tree| tree.get_selection().and_then(|item| tree.get_item_text(&item)),
+///     )
+///     .unwrap();
+/// let root = tree.add_root("Root", None, None).unwrap();
+/// tree.append_item(&root, "Apples", None, None);
+/// tree.append_item(&root, "Pears", None, None);
+///
+/// // Pick an item with a double click or Enter
+/// tree.on_item_activated(move |_| combo.dismiss_popup());
+/// combo.on_popup_dismissed(move |_| println!("Picked {}", combo.get_value()));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ComboCtrl {
+    handle: WindowHandle,
+}
+
+impl ComboCtrl {
+    /// Creates a new `ComboCtrlBuilder`.
+    pub fn builder(parent: &dyn WxWidget) -> ComboCtrlBuilder<'_> {
+        ComboCtrlBuilder::new(parent)
+    }
+
+    /// Helper to get raw comboctrl pointer, returns null if widget has been destroyed
+    #[inline]
+    fn comboctrl_ptr(&self) -> *mut ffi::wxd_ComboCtrl_t {
+        self.handle
+            .get_ptr()
+            .map(|p| p as *mut ffi::wxd_ComboCtrl_t)
+            .unwrap_or(null_mut())
+    }
+
+    /// Replaces the popup content with the widget `factory` builds on the panel it is given,
+    /// and returns that widget.
+    ///
+    /// The widget fills the popup. When the popup is dismissed, the text field shows what
+    /// `string_value` returns for the widget, or keeps its text for `None`. The previous
+    /// content is destroyed and its `string_value` dropped. Returns `None` if the control
+    /// has been destroyed.
+    pub fn set_popup_content<W, F, S>(&self, factory: F, string_value: S) -> Option<W>
+    where
+        W: WxWidget + Copy + 'static,
+        F: FnOnce(&Panel) -> W,
+        S: Fn(&W) -> Option<String> + 'static,
+    {
+        let ptr = self.comboctrl_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let panel_ptr = unsafe { ffi::wxd_ComboCtrl_SetPopupContent(ptr) };
+        if panel_ptr.is_null() {
+            return None;
+        }
+        let panel = unsafe { Panel::from_ptr(panel_ptr as *mut ffi::wxd_Panel_t) };
+        let content = factory(&panel);
+        let sizer = BoxSizer::builder(Orientation::Vertical).build();
+        sizer.add(&content, 1, SizerFlag::Expand, 0);
+        panel.set_sizer(sizer, true);
+
+        // Double-box: `Box<dyn Fn>` is a fat pointer, so box it again to get a thin
+        // `*mut c_void` for FFI. Freed by `popup_destroy`, also if this fails.
+        let string_value: StringValueFn = Box::new(move || string_value(&content));
+        let user_data = Box::into_raw(Box::new(string_value)) as *mut c_void;
+        unsafe { ffi::wxd_ComboCtrl_SetPopupCallbacks(ptr, &POPUP_VTABLE as *const _, user_data) };
+        Some(content)
+    }
+
+    /// Sets the size of the popup. A width or height of -1 keeps the default, the width of
+    /// the control and the height the content wants; the height is reduced to what fits on
+    /// the screen.
+    /// No-op if the control has been destroyed.
+    pub fn set_popup_size(&self, size: Size) {
+        let ptr = self.comboctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ComboCtrl_SetPopupSize(ptr, size.into()) };
+    }
+
+    /// Sets the text of the text field.
+    /// No-op if the control has been destroyed.
+    pub fn set_value(&self, value: &str) {
+        let ptr = self.comboctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_value = CString::new(value).unwrap_or_default();
+        unsafe { ffi::wxd_ComboCtrl_SetValue(ptr, c_value.as_ptr()) }
+    }
+
+    /// Gets the text of the text field.
+    /// Returns empty string if the control has been destroyed.
+    pub fn get_value(&self) -> String {
+        let ptr = self.comboctrl_ptr();
+        if ptr.is_null() {
+            return String::new();
+        }
+        let len = unsafe { ffi::wxd_ComboCtrl_GetValue(ptr, null_mut(), 0) };
+        if len <= 0 {
+            return String::new();
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_ComboCtrl_GetValue(ptr, buf.as_mut_ptr(), buf.len()) };
+        unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() }
+    }
+
+    /// Shows the popup, as if the button had been clicked.
+    /// No-op if the control has been destroyed.
+    pub fn show_popup(&self) {
+        let ptr = self.comboctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ComboCtrl_ShowPopup(ptr) }
+    }
+
+    /// Hides the popup and updates the text from the popup content, e.g. once an item has
+    /// been picked in it.
+    /// No-op if the control has been destroyed.
+    pub fn dismiss_popup(&self) {
+        let ptr = self.comboctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ComboCtrl_DismissPopup(ptr) }
+    }
+
+    /// Returns whether the popup is shown.
+    /// Returns false if the control has been destroyed.
+    pub fn is_popup_shown(&self) -> bool {
+        let ptr = self.comboctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_ComboCtrl_IsPopupShown(ptr) }
+    }
+}
+
+// The `user_data` handed to the C++ popup is a `*mut StringValueFn`.
+
+unsafe extern "C" fn popup_get_string_value(user_data: *mut c_void, sink: *mut c_void, emit: ffi::wxd_ComboPopupStringSink) {
+    if user_data.is_null() {
+        return;
+    }
+    let string_value = unsafe { &**(user_data as *mut StringValueFn) };
+    if let (Some(emit), Some(value)) = (emit, string_value())
+        && let Ok(value) = CString::new(value.replace('\0', ""))
+    {
+        unsafe { emit(sink, value.as_ptr()) };
+    }
+}
+
+unsafe extern "C" fn popup_destroy(user_data: *mut c_void) {
+    if user_data.is_null() {
+        return;
+    }
+    drop(unsafe { Box::from_raw(user_data as *mut StringValueFn) });
+}
+
+static POPUP_VTABLE: ffi::wxd_ComboPopup_vtable = ffi::wxd_ComboPopup_vtable {
+    get_string_value: Some(popup_get_string_value),
+    destroy: Some(popup_destroy),
+};
+
+// --- Builder pattern using macro ---
+widget_builder!(
+    name: ComboCtrl,
+    parent_type: &'a dyn WxWidget,
+    style_type: ComboCtrlStyle,
+    fields: {
+        value: String = String::new(),
+        popup_size: Size = Size::new(-1, -1)
+    },
+    build_impl: |slf| {
+        let parent_ptr = slf.parent.handle_ptr();
+        assert!(!parent_ptr.is_null(), "ComboCtrl requires a parent");
+
+        let c_value = CString::new(slf.value.as_str()).unwrap_or_default();
+        let ctrl_ptr = unsafe {
+            ffi::wxd_ComboCtrl_Create(
+                parent_ptr,
+                slf.id,
+                c_value.as_ptr(),
+                slf.pos.into(),
+                slf.size.into(),
+                slf.style.bits() as ffi::wxd_Style_t,
+            )
+        };
+        if ctrl_ptr.is_null() {
+            panic!("Failed to create ComboCtrl widget");
+        }
+
+        let combo = ComboCtrl {
+            handle: WindowHandle::new(ctrl_ptr as *mut ffi::wxd_Window_t),
+        };
+        combo.set_popup_size(slf.popup_size);
+        combo
+    }
+);
+
+// Manual WxWidget implementation for ComboCtrl (using WindowHandle)
+impl WxWidget for ComboCtrl {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(null_mut())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.handle.is_valid()
+    }
+}
+
+// Implement WxEvtHandler for event binding
+impl WxEvtHandler for ComboCtrl {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        self.handle.get_ptr().unwrap_or(null_mut()) as *mut ffi::wxd_EvtHandler_t
+    }
+}
+
+// Implement common event traits that all Window-based widgets support
+impl crate::event::WindowEvents for ComboCtrl {}
+
+// The text field sends the events of a TextCtrl
+impl TextEvents for ComboCtrl {}
+
+// The text field accessors and auto-completion shared with TextCtrl
+impl crate::text_entry::WxTextEntry for ComboCtrl {}
+
+crate::implement_widget_local_event_handlers!(
+    ComboCtrl,
+    ComboCtrlEvent,
+    ComboCtrlEventData,
+    PopupShown => popup_shown, EventType::COMBOBOX_DROPDOWN,
+    PopupDismissed => popup_dismissed, EventType::COMBOBOX_CLOSEUP
+);
+
+// XRC Support - enables ComboCtrl to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for ComboCtrl {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        ComboCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Widget casting support for ComboCtrl
+impl crate::window::FromWindowWithClassName for ComboCtrl {
+    fn class_name() -> &'static str {
+        "wxComboCtrl"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        ComboCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
@@ -362,6 +362,8 @@ pub struct ComboBoxEventData {
     pub event: CommandEventData,
 }
 
+crate::impl_event_base!(ComboBoxEventData => event.event);
+
 impl ComboBoxEventData {
     pub fn new(event: Event) -> Self {
         Self {
@@ -396,6 +398,12 @@ crate::implement_widget_local_event_handlers!(
 // We still implement TextEvents for text entry capabilities
 impl TextEvents for ComboBox {}
 
+// And auto-completion of the text field
+impl crate::text_entry::WxTextEntry for ComboBox {}
+
+// Allow checking the value with a validator
+impl crate::validator::WxValidatable for ComboBox {}
+
 // Add XRC Support - enables ComboBox to be created from XRC-managed pointers
 #[cfg(feature = "xrc")]
 impl crate::xrc::XrcSupport for ComboBox {
@@ -92,4 +92,16 @@ impl DataViewColumn {
     pub fn is_sortable(&self) -> bool {
         unsafe { ffi::wxd_DataViewColumn_IsSortable(self.handle) }
     }
+
+    /// Hides or shows the column.
+    pub fn set_hidden(&self, hidden: bool) {
+        unsafe {
+            ffi::wxd_DataViewColumn_SetHidden(self.handle, hidden);
+        }
+    }
+
+    /// Checks if the column is hidden.
+    pub fn is_hidden(&self) -> bool {
+        unsafe { ffi::wxd_DataViewColumn_IsHidden(self.handle) }
+    }
 }
@@ -16,6 +16,7 @@ use crate::color::Colour;
 use crate::event::WxEvtHandler;
 use crate::geometry::{Point, Size};
 use crate::window::WindowHandle;
+use std::ffi::CString;
 
 // Define style enum for DataViewCtrl using the macro
 widget_style_enum!(
@@ -234,6 +235,18 @@ impl DataViewCtrl {
         unsafe { ffi::wxd_DataViewCtrl_UnselectAll(self.dvc_ptr()) }
     }
 
+    /// Lets the user drag items out of this control.
+    ///
+    /// `format_name` is the custom format the drag offers first, e.g.
+    /// `"application/x-myapp-node"`, or `None` for text. The data itself is set in the
+    /// [`on_item_begin_drag`](crate::DataViewEventHandler::on_item_begin_drag) handler
+    /// with [`DataViewEvent::set_drag_source`](crate::DataViewEvent::set_drag_source).
+    pub fn enable_drag_source(&self, format_name: Option<&str>) -> bool {
+        let c_format = format_name.map(|format| CString::new(format).unwrap_or_default());
+        let format_ptr = c_format.as_ref().map_or(std::ptr::null(), |format| format.as_ptr());
+        unsafe { ffi::wxd_DataViewCtrl_EnableDragSource(self.dvc_ptr(), format_ptr) }
+    }
+
     /// Creates and appends a text column to this control.
     ///
     /// This is a convenience method for creating a text renderer column and appending it.
@@ -814,3 +827,26 @@ impl crate::widgets::dataview::DataViewEventHandler for DataViewCtrl {}
 
 // Implement DataViewTreeEventHandler for DataViewCtrl since it supports tree functionality
 impl crate::widgets::dataview::DataViewTreeEventHandler for DataViewCtrl {}
+
+// XRC Support - enables DataViewCtrl to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for DataViewCtrl {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        DataViewCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Enable widget casting for DataViewCtrl
+impl crate::window::FromWindowWithClassName for DataViewCtrl {
+    fn class_name() -> &'static str {
+        "wxDataViewCtrl"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        DataViewCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
@@ -4,6 +4,7 @@
 //! DataViewCtrl, DataViewListCtrl, and DataViewTreeCtrl.
 
 use super::item::DataViewItem;
+use crate::data_object::{DataObject, TransferOwnership};
 use crate::event::{Event, EventToken, EventType, WxEvtHandler};
 use wxdragon_sys as ffi;
 
@@ -54,6 +55,10 @@ pub enum DataViewEventType {
     /// Use this instead of the generic `on_context_menu` from MenuEvents trait
     /// for better DataView-specific context information.
     ItemContextMenu,
+    /// Emitted when the user starts dragging an item
+    ///
+    /// Only sent after [`DataViewCtrl::enable_drag_source`](crate::DataViewCtrl::enable_drag_source).
+    ItemBeginDrag,
 }
 
 /// Event data for a DataView event
@@ -65,6 +70,8 @@ pub struct DataViewEvent {
     pub event_type: DataViewEventType,
 }
 
+crate::impl_event_base!(DataViewEvent => event);
+
 impl DataViewEvent {
     /// Create a new DataViewEvent from a generic Event
     pub fn new(event: Event, event_type: DataViewEventType) -> Self {
@@ -207,6 +214,25 @@ impl DataViewEvent {
             None
         }
     }
+
+    /// Sets the data dragged for an [`DataViewEventType::ItemBeginDrag`] event, which the
+    /// control drags once the handler returns; without it the drag does not start.
+    ///
+    /// This takes the same [`DragSource`](crate::dnd::DragSource) a drag started with
+    /// [`do_drag_drop`](crate::dnd::DragSource::do_drag_drop) uses, so drags out of the
+    /// control and out of other windows can share the code building their data.
+    /// Returns false for other events or a source without data.
+    pub fn set_drag_source(&self, source: crate::dnd::DragSource, allow_move: bool) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        let Some(mut data) = source.into_data_object() else {
+            return false;
+        };
+        // The control owns the data object from here on, also if the event refuses it
+        data.transfer_ownership();
+        unsafe { ffi::wxd_DataViewEvent_SetDragData(self.event.0, data.as_data_object_ptr(), allow_move) }
+    }
 }
 
 /// Trait for DataView event handling
@@ -233,6 +259,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
             DataViewEventType::ColumnSorted => EventType::DATAVIEW_COLUMN_SORTED,
             DataViewEventType::ColumnReordered => EventType::DATAVIEW_COLUMN_REORDERED,
             DataViewEventType::ItemContextMenu => EventType::DATAVIEW_ITEM_CONTEXT_MENU,
+            DataViewEventType::ItemBeginDrag => EventType::DATAVIEW_ITEM_BEGIN_DRAG,
         };
 
         // Create wrapper with special handling for editing cancelled events
@@ -369,6 +396,34 @@ pub trait DataViewEventHandler: WxEvtHandler {
     {
         self.bind_dataview_event(DataViewEventType::ItemContextMenu, callback)
     }
+
+    /// Binds a handler to the item begin drag event.
+    /// Returns an EventToken that can be used to unbind the handler later.
+    ///
+    /// Call [`DataViewEvent::set_drag_source`] in the handler to drag the item's data.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use wxdragon::dnd::DragSource;
+    /// use wxdragon::{DataViewCtrl, DataViewEventHandler};
+    ///
+    /// # let data_view: DataViewCtrl = todo!();
+    /// data_view.enable_drag_source(None);
+    /// data_view.on_item_begin_drag(move |event| {
+    ///     if let Some(row) = event.get_row() {
+    ///         let mut source = DragSource::new(&data_view);
+    ///         source.set_text(&format!("Row {row}"));
+    ///         event.set_drag_source(source, false);
+    ///     }
+    /// });
+    /// ```
+    fn on_item_begin_drag<F>(&self, callback: F) -> EventToken
+    where
+        F: FnMut(DataViewEvent) + 'static,
+    {
+        self.bind_dataview_event(DataViewEventType::ItemBeginDrag, callback)
+    }
 }
 
 /// Extension trait for TreeView-specific events
@@ -574,3 +574,26 @@ widget_builder!(
 
 // Implement DataViewEventHandler for DataViewListCtrl
 impl crate::widgets::dataview::DataViewEventHandler for DataViewListCtrl {}
+
+// XRC Support - enables DataViewListCtrl to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for DataViewListCtrl {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        DataViewListCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Enable widget casting for DataViewListCtrl
+impl crate::window::FromWindowWithClassName for DataViewListCtrl {
+    fn class_name() -> &'static str {
+        "wxDataViewListCtrl"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        DataViewListCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
@@ -506,3 +506,26 @@ WXD_EXPORTED wxd_Window_t* wxd_DataViewTreeCtrl_new(
 //   an item with invalid state. ToWxDVI handles this by creating an invalid wxDataViewItem.
 // - The icon parameters are integer indices into the ImageList associated with the control.
 //   A value of -1 typically means no icon.
+
+// XRC Support - enables DataViewTreeCtrl to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for DataViewTreeCtrl {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        DataViewTreeCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Enable widget casting for DataViewTreeCtrl
+impl crate::window::FromWindowWithClassName for DataViewTreeCtrl {
+    fn class_name() -> &'static str {
+        "wxDataViewTreeCtrl"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        DataViewTreeCtrl {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
@@ -35,6 +35,8 @@ pub struct DatePickerCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(DatePickerCtrlEventData => event);
+
 impl DatePickerCtrlEventData {
     /// Create a new DatePickerCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
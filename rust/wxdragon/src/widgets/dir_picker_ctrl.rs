@@ -37,6 +37,8 @@ pub struct DirPickerCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(DirPickerCtrlEventData => event);
+
 impl DirPickerCtrlEventData {
     /// Create a new DirPickerCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -13,6 +13,9 @@ use crate::window::Window;
 
 /// An editable listbox is a listbox with buttons to add, remove, and reorder items in the list.
 ///
+/// New items and edits are typed into the list itself. An item the user empties is
+/// removed, and [`on_changed`](Self::on_changed) reports each change the user made.
+///
 /// EditableListBox uses `WindowHandle` internally for safe memory management.
 /// When the underlying window is destroyed (by calling `destroy()` or when
 /// its parent is destroyed), the handle becomes invalid and all operations
@@ -25,6 +28,7 @@ use crate::window::Window;
 /// // EditableListBox is Copy - no clone needed for closures!
 /// elb.add_string("Item 1");
 /// elb.add_string("Item 2");
+/// elb.on_changed(move |_| println!("Items: {:?}", elb.get_strings()));
 ///
 /// // After parent destruction, operations are safe no-ops
 /// frame.destroy();
@@ -62,6 +66,9 @@ pub enum EditableListBoxEvent {
     BeginLabelEdit,
     /// Emitted when an item edit is completed
     EndLabelEdit,
+    /// Emitted after the user added, edited, deleted or moved an item, but not for
+    /// changes made with `set_strings` or `add_string`
+    Changed,
 }
 
 /// Event data for EditableListBox events
@@ -70,6 +77,8 @@ pub struct EditableListBoxEventData {
     event: Event,
 }
 
+crate::impl_event_base!(EditableListBoxEventData => event);
+
 impl EditableListBoxEventData {
     /// Create a new EditableListBoxEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -141,6 +150,7 @@ impl EditableListBox {
     /// This is intended for internal use by other widget wrappers.
     #[allow(dead_code)]
     pub(crate) fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        unsafe { ffi::wxd_EditableListBox_TrackChanges(ptr) };
         Self {
             handle: WindowHandle::new(ptr),
         }
@@ -187,15 +197,15 @@ impl EditableListBox {
         wxd_array_string.get_strings()
     }
 
-    /// Set all strings in the listbox.
+    /// Set all strings in the listbox, e.g. from a `&[&str]` or `&[String]`.
     /// No-op if the widget has been destroyed.
-    pub fn set_strings(&self, strings: &[&str]) {
+    pub fn set_strings<S: AsRef<str>>(&self, strings: &[S]) {
         let ptr = self.elb_ptr();
         if ptr.is_null() {
             return;
         }
 
-        let c_strings: Vec<CString> = strings.iter().map(|s| CString::new(*s).unwrap_or_default()).collect();
+        let c_strings: Vec<CString> = strings.iter().map(|s| CString::new(s.as_ref()).unwrap_or_default()).collect();
 
         let mut c_ptrs: Vec<*const core::ffi::c_char> = c_strings.iter().map(|s| s.as_ptr()).collect();
 
@@ -285,13 +295,16 @@ crate::implement_widget_local_event_handlers!(
     Selected => selection_changed, crate::event::EventType::COMMAND_LISTBOX_SELECTED,
     DoubleClicked => item_double_clicked, crate::event::EventType::COMMAND_LISTBOX_DOUBLECLICKED,
     BeginLabelEdit => begin_label_edit, crate::event::EventType::LIST_BEGIN_LABEL_EDIT,
-    EndLabelEdit => end_label_edit, crate::event::EventType::LIST_END_LABEL_EDIT
+    EndLabelEdit => end_label_edit, crate::event::EventType::LIST_END_LABEL_EDIT,
+    Changed => changed, crate::event::EventType::EDITABLELISTBOX_CHANGED
 );
 
 // XRC Support - enables EditableListBox to be created from XRC-managed pointers
 #[cfg(feature = "xrc")]
 impl crate::xrc::XrcSupport for EditableListBox {
     unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        // Listboxes not made by the builder report changes once wrapped
+        unsafe { ffi::wxd_EditableListBox_TrackChanges(ptr) };
         EditableListBox {
             handle: WindowHandle::new(ptr),
         }
@@ -305,6 +318,7 @@ impl crate::window::FromWindowWithClassName for EditableListBox {
     }
 
     unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        unsafe { ffi::wxd_EditableListBox_TrackChanges(ptr) };
         EditableListBox {
             handle: WindowHandle::new(ptr),
         }
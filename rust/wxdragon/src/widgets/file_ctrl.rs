@@ -40,6 +40,8 @@ pub struct FileCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(FileCtrlEventData => event);
+
 impl FileCtrlEventData {
     /// Create a new FileCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -40,6 +40,8 @@ pub struct FilePickerCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(FilePickerCtrlEventData => event);
+
 impl FilePickerCtrlEventData {
     /// Create a new FilePickerCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -38,6 +38,8 @@ pub struct FontChangedEventData {
     event: Event,
 }
 
+crate::impl_event_base!(FontChangedEventData => event);
+
 impl FontChangedEventData {
     /// Create a new FontChangedEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -123,12 +125,28 @@ impl FontPickerCtrl {
         if ptr.is_null() {
             return;
         }
-        // Create a new font to ensure proper ownership
-        let font_copy = font.to_owned();
-        // The C++ code makes a copy of the font, so we can just pass the pointer
-        unsafe { ffi::wxd_FontPickerCtrl_SetSelectedFont(ptr, font_copy.as_ptr()) };
-        // Intentionally leak the font as the C++ side now owns it
-        std::mem::forget(font_copy);
+        // The C++ side copies the font, so the caller keeps ownership
+        unsafe { ffi::wxd_FontPickerCtrl_SetSelectedFont(ptr, font.as_ptr()) };
+    }
+
+    /// Sets the maximum point size offered by the font dialog.
+    /// No-op if the widget has been destroyed.
+    pub fn set_max_point_size(&self, max: u32) {
+        let ptr = self.font_picker_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_FontPickerCtrl_SetMaxPointSize(ptr, max) };
+    }
+
+    /// Gets the maximum point size offered by the font dialog.
+    /// Returns 0 if the widget has been destroyed.
+    pub fn get_max_point_size(&self) -> u32 {
+        let ptr = self.font_picker_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_FontPickerCtrl_GetMaxPointSize(ptr) }
     }
 
     /// Creates a FontPickerCtrl from a raw pointer.
@@ -1,4 +1,4 @@
-use crate::bitmap::Bitmap; // ADDED: Import Bitmap
+use crate::bitmap_bundle::BitmapBundleArg;
 use crate::geometry::{Point, Size};
 use crate::id::ID_ANY;
 use crate::id::Id;
@@ -366,6 +366,9 @@ impl Frame {
     }
 
     /// Sets the status text in the specified field.
+    ///
+    /// Going through the frame lets the help text shown for highlighted menu items cooperate
+    /// with it: text set while a menu is open isn't overwritten when the menu closes.
     /// No-op if the frame has been destroyed.
     pub fn set_status_text(&self, text: &str, number: i32) {
         let ptr = self.frame_ptr();
@@ -376,6 +379,49 @@ impl Frame {
         unsafe { ffi::wxd_Frame_SetStatusText(ptr, c_text.as_ptr(), number) }
     }
 
+    /// Shows `text` in a field of the status bar, saving the current text to be restored by
+    /// [`pop_status_text`](Self::pop_status_text).
+    /// No-op if the frame has been destroyed.
+    pub fn push_status_text(&self, text: &str, number: i32) {
+        let ptr = self.frame_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_text = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_Frame_PushStatusText(ptr, c_text.as_ptr(), number) }
+    }
+
+    /// Restores the text of a field saved by the last [`push_status_text`](Self::push_status_text).
+    /// No-op if no text was pushed for the field or the frame has been destroyed.
+    pub fn pop_status_text(&self, number: i32) {
+        let ptr = self.frame_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Frame_PopStatusText(ptr, number) }
+    }
+
+    /// Sets the status bar field showing the help text of highlighted menu items and toolbar
+    /// tools, 0 by default. A negative pane turns the automatic help text off.
+    /// No-op if the frame has been destroyed.
+    pub fn set_status_bar_pane(&self, pane: i32) {
+        let ptr = self.frame_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Frame_SetStatusBarPane(ptr, pane) }
+    }
+
+    /// Gets the status bar field showing the help text of highlighted menu items, -1 if none.
+    /// Returns -1 if the frame has been destroyed.
+    pub fn get_status_bar_pane(&self) -> i32 {
+        let ptr = self.frame_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_Frame_GetStatusBarPane(ptr) }
+    }
+
     /// Gets the frame's title.
     /// Returns empty string if the frame has been destroyed.
     pub fn get_title(&self) -> String {
@@ -435,15 +481,17 @@ impl Frame {
         unsafe { ffi::wxd_Frame_IsMaximized(ptr) }
     }
 
-    /// Sets the frame's icon from a bitmap.
-    /// The bitmap will be converted to an icon internally.
+    /// Sets the frame's icon from a bitmap or a [`BitmapBundle`](crate::bitmap_bundle::BitmapBundle).
+    /// The image is converted to icons at the standard sizes, so an SVG bundle stays sharp
+    /// in the title bar as well as in the task switcher.
     /// No-op if the frame has been destroyed.
-    pub fn set_icon(&self, bitmap: &Bitmap) {
+    pub fn set_icon<'a>(&self, icon: impl Into<BitmapBundleArg<'a>>) {
         let ptr = self.frame_ptr();
         if ptr.is_null() {
             return;
         }
-        unsafe { ffi::wxd_Frame_SetIconFromBitmap(ptr, bitmap.as_const_ptr()) };
+        let icon = icon.into();
+        unsafe { ffi::wxd_Frame_SetIcons(ptr, icon.as_ptr()) };
     }
 
     /// Attracts the user's attention to this window if the application is inactive.
@@ -128,6 +128,8 @@ pub struct GridEventData {
     event: Event,
 }
 
+crate::impl_event_base!(GridEventData => event);
+
 impl GridEventData {
     /// Create a new GridEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -38,6 +38,8 @@ pub struct HyperlinkCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(HyperlinkCtrlEventData => event);
+
 impl HyperlinkCtrlEventData {
     /// Create a new HyperlinkCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -0,0 +1,242 @@
+//!
+//! Safe wrapper for wxInfoBar.
+
+use crate::event::event_data::CommandEventData;
+use crate::event::{EventToken, EventType, WxEvtHandler};
+use crate::id::Id;
+use crate::widgets::simplebook::ShowEffect;
+use crate::window::{WindowHandle, WxWidget};
+use std::ffi::CString;
+use std::os::raw::c_int;
+use wxdragon_sys as ffi;
+
+/// Icon shown next to the message of an [`InfoBar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InfoBarIcon {
+    /// No icon.
+    None,
+    /// An information icon.
+    #[default]
+    Info,
+    /// A warning icon.
+    Warning,
+    /// An error icon.
+    Error,
+}
+
+impl InfoBarIcon {
+    fn to_raw(self) -> c_int {
+        let raw = match self {
+            InfoBarIcon::None => 0,
+            InfoBarIcon::Info => ffi::WXD_ICON_INFORMATION,
+            InfoBarIcon::Warning => ffi::WXD_ICON_WARNING,
+            InfoBarIcon::Error => ffi::WXD_ICON_ERROR,
+        };
+        raw as c_int
+    }
+}
+
+/// A strip showing a message above (or below) the window contents, a non-modal alternative to
+/// a message dialog for things like "Settings saved" or "Connection lost".
+///
+/// The info bar starts hidden and must be added to its parent's sizer, typically at the top;
+/// showing or dismissing it lays the parent out again. It has a close button, and buttons
+/// added with [`add_button`](Self::add_button) dismiss it as well when clicked.
+///
+/// InfoBar uses `WindowHandle` internally for safe memory management.
+/// When the underlying window is destroyed (by calling `destroy()` or when
+/// its parent is destroyed), the handle becomes invalid and all operations
+/// become safe no-ops.
+///
+/// # Example
+/// ```ignore
+/// let info_bar = InfoBar::new(&panel);
+/// sizer.add(&info_bar, 0, SizerFlag::Expand, 0);
+///
+/// info_bar.add_button(ID_RETRY, "Retry");
+/// info_bar.on_button(ID_RETRY, move |_| reconnect());
+/// info_bar.show_message("Connection lost", InfoBarIcon::Warning);
+/// ```
+#[derive(Clone, Copy)]
+pub struct InfoBar {
+    /// Safe handle to the underlying wxInfoBar - automatically invalidated on destroy
+    handle: WindowHandle,
+}
+
+impl InfoBar {
+    /// Creates a hidden info bar with `ID_ANY`.
+    pub fn new(parent: &dyn WxWidget) -> Self {
+        Self::new_with_id(parent, crate::id::ID_ANY as Id)
+    }
+
+    /// Creates a hidden info bar with the given id.
+    pub fn new_with_id(parent: &dyn WxWidget, id: Id) -> Self {
+        let ptr = unsafe { ffi::wxd_InfoBar_Create(parent.handle_ptr(), id) };
+        assert!(!ptr.is_null(), "wxd_InfoBar_Create returned null");
+        InfoBar {
+            handle: WindowHandle::new(ptr as *mut ffi::wxd_Window_t),
+        }
+    }
+
+    /// Helper to get raw info bar pointer, returns null if widget has been destroyed
+    #[inline]
+    fn infobar_ptr(&self) -> *mut ffi::wxd_InfoBar_t {
+        self.handle
+            .get_ptr()
+            .map(|p| p as *mut ffi::wxd_InfoBar_t)
+            .unwrap_or(std::ptr::null_mut())
+    }
+
+    /// Returns the underlying WindowHandle for this info bar.
+    pub fn window_handle(&self) -> WindowHandle {
+        self.handle
+    }
+
+    /// Shows the info bar with `text`, replacing the message shown before.
+    /// No-op if the info bar has been destroyed.
+    pub fn show_message(&self, text: &str, icon: InfoBarIcon) {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_text = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_InfoBar_ShowMessage(ptr, c_text.as_ptr(), icon.to_raw()) };
+    }
+
+    /// Hides the info bar.
+    /// No-op if the info bar has been destroyed.
+    pub fn dismiss(&self) {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_InfoBar_Dismiss(ptr) };
+    }
+
+    /// Adds a button, shown to the right of the message; handle its clicks with
+    /// [`on_button`](Self::on_button). An empty label uses the stock label of a stock id
+    /// such as `ID_OK`.
+    /// No-op if the info bar has been destroyed.
+    pub fn add_button(&self, id: Id, label: &str) {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_label = CString::new(label).unwrap_or_default();
+        unsafe { ffi::wxd_InfoBar_AddButton(ptr, id, c_label.as_ptr()) };
+    }
+
+    /// Removes the button with the given id.
+    /// No-op if there is no such button or the info bar has been destroyed.
+    pub fn remove_button(&self, id: Id) {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_InfoBar_RemoveButton(ptr, id) };
+    }
+
+    /// Gets the number of buttons added with [`add_button`](Self::add_button).
+    /// Returns 0 if the info bar has been destroyed.
+    pub fn get_button_count(&self) -> usize {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_InfoBar_GetButtonCount(ptr) }
+    }
+
+    /// Returns true if there is a button with the given id.
+    /// Returns false if the info bar has been destroyed.
+    pub fn has_button_id(&self, id: Id) -> bool {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_InfoBar_HasButtonId(ptr, id) }
+    }
+
+    /// Animates showing and hiding the info bar, taking `duration_ms` milliseconds.
+    ///
+    /// Only the generic info bar is animated; the native one on GTK ignores this.
+    /// No-op if the info bar has been destroyed.
+    pub fn set_show_hide_effects(&self, show_effect: ShowEffect, hide_effect: ShowEffect, duration_ms: u32) {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe {
+            ffi::wxd_InfoBar_SetShowHideEffects(ptr, show_effect.to_raw(), hide_effect.to_raw());
+            ffi::wxd_InfoBar_SetEffectDuration(ptr, duration_ms.min(i32::MAX as u32) as c_int);
+        }
+    }
+
+    /// Gets the duration of the show and hide animations in milliseconds.
+    /// Returns 0 if the info bar has been destroyed.
+    pub fn get_effect_duration(&self) -> u32 {
+        let ptr = self.infobar_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_InfoBar_GetEffectDuration(ptr) }.max(0) as u32
+    }
+
+    /// Handles clicks on the button with the given id.
+    ///
+    /// The info bar is dismissed after the handler unless it calls `event.skip(false)`,
+    /// e.g. to keep a "Retry" message up until retrying succeeds.
+    ///
+    /// Returns an EventToken that can be used to unbind the handler later.
+    pub fn on_button<F>(&self, id: Id, mut callback: F) -> EventToken
+    where
+        F: FnMut(CommandEventData) + 'static,
+    {
+        self.bind_with_id_internal(EventType::COMMAND_BUTTON_CLICKED, id, move |event| {
+            callback(CommandEventData::new(event))
+        })
+    }
+}
+
+// Manual WxWidget implementation for InfoBar (using WindowHandle)
+impl WxWidget for InfoBar {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.handle.is_valid()
+    }
+}
+
+// Implement WxEvtHandler for event binding
+impl WxEvtHandler for InfoBar {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut()) as *mut ffi::wxd_EvtHandler_t
+    }
+}
+
+// Implement common event traits that all Window-based widgets support
+impl crate::event::WindowEvents for InfoBar {}
+
+// XRC Support - enables InfoBar to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for InfoBar {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        InfoBar {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Enable widget casting for InfoBar
+impl crate::window::FromWindowWithClassName for InfoBar {
+    fn class_name() -> &'static str {
+        "wxInfoBar"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        InfoBar {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! wxListCtrl wrapper
 
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::color::Colour;
+use crate::event::{Event, EventToken, EventType, WxEvtHandler};
 use crate::geometry::{Point, Size};
 use crate::id::Id;
 use crate::widgets::imagelist::ImageList;
@@ -10,6 +11,7 @@ use crate::window::{WindowHandle, WxWidget};
 #[allow(unused_imports)]
 use crate::window::Window;
 use std::any::Any;
+use std::cmp::Ordering;
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int, c_longlong, c_void};
 use std::panic::{self, AssertUnwindSafe};
@@ -20,6 +22,52 @@ struct ListCtrlVirtualTextCallback {
     callback: Box<dyn Fn(i64, i32) -> String>,
 }
 
+type VirtualImageFn = Box<dyn Fn(i64) -> i32>;
+type VirtualAttrFn = Box<dyn Fn(i64) -> Option<ListItemAttr>>;
+
+/// Appearance of a row of a virtual list, returned by the callback set with
+/// [`ListCtrl::on_get_item_attr`].
+///
+/// Colours left at `None` and the font keep the list's defaults.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListItemAttr {
+    pub text_colour: Option<Colour>,
+    pub background_colour: Option<Colour>,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+impl ListItemAttr {
+    /// Creates an attribute keeping the default look.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the text colour.
+    pub fn with_text_colour(mut self, colour: Colour) -> Self {
+        self.text_colour = Some(colour);
+        self
+    }
+
+    /// Sets the background colour.
+    pub fn with_background_colour(mut self, colour: Colour) -> Self {
+        self.background_colour = Some(colour);
+        self
+    }
+
+    /// Shows the text in bold.
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    /// Shows the text in italics.
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+}
+
 // --- ListCtrl Styles ---
 widget_style_enum!(
     name: ListCtrlStyle,
@@ -157,6 +205,8 @@ pub struct ListCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(ListCtrlEventData => event);
+
 impl ListCtrlEventData {
     /// Create a new ListCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -203,6 +253,12 @@ impl ListCtrlEventData {
         Some(unsafe { ffi::wxd_ListEvent_IsEditCancelled(self.event.0) })
     }
 
+    /// Vetoes the event: for `BeginLabelEdit` the label isn't edited, for `EndLabelEdit`
+    /// the edited label is discarded and the old one kept.
+    pub fn veto(&self) {
+        self.event.veto();
+    }
+
     /// Get the point where the event occurred (for click events)
     pub fn get_position(&self) -> Option<Point> {
         self.event.get_position()
@@ -424,6 +480,13 @@ impl ListCtrl {
         }
     }
 
+    /// Sets the text of the cell at `row` and `col`, the same as
+    /// [`set_item_text_by_column`](Self::set_item_text_by_column).
+    /// No-op if the list control has been destroyed.
+    pub fn set_item(&self, row: i64, col: i32, text: &str) {
+        self.set_item_text_by_column(row, col, text);
+    }
+
     /// Gets the text of an item in the specified column.
     /// Returns empty string if the list control has been destroyed.
     pub fn get_item_text(&self, index: i64, col: i32) -> String {
@@ -542,6 +605,46 @@ impl ListCtrl {
         self.get_next_item(-1, ListNextItemFlag::All, ListItemState::Selected)
     }
 
+    /// Gets the indices of all selected items, in display order.
+    /// Returns an empty vector if the list control has been destroyed.
+    pub fn get_selected_items(&self) -> Vec<i64> {
+        let mut items = Vec::new();
+        let mut item = self.get_first_selected_item();
+        while item >= 0 {
+            items.push(item as i64);
+            item = self.get_next_item(item as i64, ListNextItemFlag::All, ListItemState::Selected);
+        }
+        items
+    }
+
+    /// Selects or deselects an item. Other items stay selected unless the control has the
+    /// `SingleSel` style.
+    /// No-op (returns false) if the list control has been destroyed.
+    pub fn select_item(&self, item: i64, select: bool) -> bool {
+        let state = if select {
+            ListItemState::Selected
+        } else {
+            ListItemState::default()
+        };
+        self.set_item_state(item, state, ListItemState::Selected)
+    }
+
+    /// Associates a number, e.g. a database key, with an item.
+    ///
+    /// The number is kept with the item when the list is sorted, see
+    /// [`sort_items_by`](Self::sort_items_by). It replaces any data set with
+    /// [`set_custom_data`](HasItemData::set_custom_data), which it is stored as. Returns
+    /// false if the list control has been destroyed or the item doesn't exist.
+    pub fn set_item_data(&self, item: i64, data: i64) -> bool {
+        self.set_custom_data(item as u64, data) != 0
+    }
+
+    /// Gets the number associated with an item by [`set_item_data`](Self::set_item_data).
+    /// Returns `None` if the item has no such number or the list control has been destroyed.
+    pub fn get_item_data(&self, item: i64) -> Option<i64> {
+        self.get_custom_data(item as u64)?.downcast_ref::<i64>().copied()
+    }
+
     /// Sets the image for a specific item.
     /// No-op (returns false) if the list control has been destroyed.
     ///
@@ -844,6 +947,199 @@ impl ListCtrl {
         unsafe { ffi::wxd_ListCtrl_ClearVirtualTextCallback(ptr) }
     }
 
+    /// Provides the text of the cell at `row` and `col` of a virtual list, the same as
+    /// [`set_virtual_text_callback`](Self::set_virtual_text_callback).
+    ///
+    /// Like the image and attribute callbacks it is only called for the rows being shown,
+    /// so its cost doesn't depend on the number of items.
+    pub fn on_get_item_text<F>(&self, callback: F) -> bool
+    where
+        F: Fn(i64, i32) -> String + 'static,
+    {
+        self.set_virtual_text_callback(callback)
+    }
+
+    /// Provides the index in the small image list of the icon shown in front of `row` of a
+    /// virtual list, or -1 for none. Replaces the callback set before.
+    ///
+    /// Returns `false` if the list control has been destroyed or was not created by wxDragon.
+    pub fn on_get_item_image<F>(&self, callback: F) -> bool
+    where
+        F: Fn(i64) -> i32 + 'static,
+    {
+        let ptr = self.listctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        // Double-box: `Box<dyn Fn>` is a fat pointer, so box it again to get a thin
+        // `*mut c_void` for FFI. Freed by `listctrl_drop_virtual_image_callback`.
+        let user_data = Box::into_raw(Box::new(Box::new(callback) as VirtualImageFn));
+        let result = unsafe {
+            ffi::wxd_ListCtrl_SetVirtualImageCallback(
+                ptr,
+                user_data as *mut c_void,
+                Some(listctrl_virtual_image_callback),
+                Some(listctrl_drop_virtual_image_callback),
+            )
+        };
+        if !result {
+            drop(unsafe { Box::from_raw(user_data) });
+        }
+        result
+    }
+
+    /// Provides the colours and font style of `row` of a virtual list, e.g. to show errors in
+    /// red, or `None` for the default look. Replaces the callback set before.
+    ///
+    /// Returns `false` if the list control has been destroyed or was not created by wxDragon.
+    pub fn on_get_item_attr<F>(&self, callback: F) -> bool
+    where
+        F: Fn(i64) -> Option<ListItemAttr> + 'static,
+    {
+        let ptr = self.listctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        // Double-boxed like the image callback; freed by `listctrl_drop_virtual_attr_callback`
+        let user_data = Box::into_raw(Box::new(Box::new(callback) as VirtualAttrFn));
+        let result = unsafe {
+            ffi::wxd_ListCtrl_SetVirtualAttrCallback(
+                ptr,
+                user_data as *mut c_void,
+                Some(listctrl_virtual_attr_callback),
+                Some(listctrl_drop_virtual_attr_callback),
+            )
+        };
+        if !result {
+            drop(unsafe { Box::from_raw(user_data) });
+        }
+        result
+    }
+
+    // --- Sorting ---
+
+    /// Sorts the items with `compare`, which is given the indices of two items as they are
+    /// before sorting, so it can read them with [`get_item_text`](Self::get_item_text).
+    ///
+    /// Item states such as the selection, images, colours and item data move with the items.
+    /// Returns false if the list control has been destroyed or is virtual, in which case the
+    /// application sorts its own data and refreshes the list instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use wxdragon::prelude::*;
+    /// # let parent = Frame::builder().build();
+    /// # let list_ctrl = ListCtrl::builder(&parent).with_style(ListCtrlStyle::Report).build();
+    /// // Sort by the number in the second column
+    /// list_ctrl.sort_items_by(|a, b| {
+    ///     let size = |row| list_ctrl.get_item_text(row, 1).parse::<u64>().unwrap_or(0);
+    ///     size(a).cmp(&size(b))
+    /// });
+    /// ```
+    pub fn sort_items_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(i64, i64) -> Ordering,
+    {
+        let ptr = self.listctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        // Compare up front: while wxWidgets sorts, items move and can't be read by index
+        let count = self.get_item_count().max(0) as i64;
+        let mut order: Vec<i64> = (0..count).collect();
+        order.sort_by(|&a, &b| compare(a, b));
+
+        // The item data, which wxWidgets sorts by, temporarily becomes each item's position
+        // in the new order; afterwards the item at `position` is the one from order[position]
+        let data: Vec<i64> = (0..count)
+            .map(|item| unsafe { ffi::wxd_ListCtrl_GetItemData(ptr, item as c_longlong) })
+            .collect();
+        for (position, &item) in order.iter().enumerate() {
+            unsafe { ffi::wxd_ListCtrl_SetItemData(ptr, item as c_longlong, position as c_longlong) };
+        }
+        let sorted = unsafe { ffi::wxd_ListCtrl_SortItems(ptr, Some(listctrl_compare_positions), std::ptr::null_mut()) };
+        for (position, &item) in order.iter().enumerate() {
+            let (now, original) = if sorted { (position as i64, item) } else { (item, item) };
+            unsafe { ffi::wxd_ListCtrl_SetItemData(ptr, now as c_longlong, data[original as usize] as c_longlong) };
+        }
+        sorted
+    }
+
+    /// Shows an arrow in the header of column `col` saying the list is sorted by it, up in
+    /// `ascending` order, down otherwise. Other columns lose their arrow.
+    /// No-op if the list control has been destroyed.
+    pub fn show_sort_indicator(&self, col: i32, ascending: bool) {
+        let ptr = self.listctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ListCtrl_ShowSortIndicator(ptr, col, ascending) }
+    }
+
+    /// Removes the sort arrow from the header.
+    /// No-op if the list control has been destroyed.
+    pub fn remove_sort_indicator(&self) {
+        let ptr = self.listctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ListCtrl_RemoveSortIndicator(ptr) }
+    }
+
+    /// Gets the column showing the sort arrow and whether it points up, for ascending order.
+    /// Returns `None` if no column shows it or the list control has been destroyed.
+    pub fn get_sort_indicator(&self) -> Option<(i32, bool)> {
+        let ptr = self.listctrl_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let mut ascending = true;
+        let col = unsafe { ffi::wxd_ListCtrl_GetSortIndicator(ptr, &mut ascending) };
+        (col >= 0).then_some((col, ascending))
+    }
+
+    /// Sorts the list when a column header is clicked: the first click on a column sorts
+    /// ascending, clicking it again toggles the direction, and the header shows an arrow for
+    /// the current order.
+    ///
+    /// `compare` is called with the indices of two rows as they are before sorting, the
+    /// column and whether the order is ascending, and returns their order in the sorted list,
+    /// so it reverses its result for descending order itself. That way it can e.g. keep
+    /// folders first in both directions. The sort uses
+    /// [`sort_items_by`](Self::sort_items_by), so selections and item data move with their
+    /// rows. Other column click handlers still run. Unbind the returned token to stop
+    /// sorting.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use wxdragon::prelude::*;
+    /// # let parent = Frame::builder().build();
+    /// # let list_ctrl = ListCtrl::builder(&parent).with_style(ListCtrlStyle::Report).build();
+    /// list_ctrl.enable_auto_sort(move |a, b, col, ascending| {
+    ///     let order = list_ctrl.get_item_text(a, col).cmp(&list_ctrl.get_item_text(b, col));
+    ///     if ascending { order } else { order.reverse() }
+    /// });
+    /// ```
+    pub fn enable_auto_sort<F>(&self, compare: F) -> EventToken
+    where
+        F: Fn(i64, i64, i32, bool) -> Ordering + 'static,
+    {
+        let list_ctrl = *self;
+        self.on_column_click(move |event_data| {
+            event_data.event.skip(true);
+            let Some(col) = event_data.get_column() else {
+                return;
+            };
+            let ascending = match list_ctrl.get_sort_indicator() {
+                Some((sorted_col, ascending)) if sorted_col == col => !ascending,
+                _ => true,
+            };
+            if list_ctrl.sort_items_by(|a, b| compare(a, b, col, ascending)) {
+                list_ctrl.show_sort_indicator(col, ascending);
+            }
+        })
+    }
+
     // --- ImageList Methods ---
 
     /// Sets the image list for the control.
@@ -1035,6 +1331,16 @@ widget_builder!(
     }
 );
 
+impl<'a> ListCtrlBuilder<'a> {
+    /// Creates a virtual list in report view: instead of holding items, it asks the
+    /// callbacks set with [`ListCtrl::on_get_item_text`] and friends for the rows it shows.
+    /// Set the number of rows with [`ListCtrl::set_item_count`].
+    pub fn virtual_mode(mut self) -> Self {
+        self.style = self.style | ListCtrlStyle::Virtual | ListCtrlStyle::Report;
+        self
+    }
+}
+
 // Register for destroy event to clean up custom data
 impl ListCtrl {
     /// Sets up the ListCtrl to clean up all custom data when it's destroyed.
@@ -1088,6 +1394,57 @@ unsafe extern "C" fn listctrl_drop_virtual_text_callback(userdata: *mut c_void)
     }
 }
 
+unsafe extern "C" fn listctrl_virtual_image_callback(userdata: *mut c_void, item: i64) -> i32 {
+    if userdata.is_null() {
+        return -1;
+    }
+    let callback = unsafe { &**(userdata as *const VirtualImageFn) };
+    panic::catch_unwind(AssertUnwindSafe(|| callback(item))).unwrap_or(-1)
+}
+
+unsafe extern "C" fn listctrl_drop_virtual_image_callback(userdata: *mut c_void) {
+    if !userdata.is_null() {
+        drop(unsafe { Box::from_raw(userdata as *mut VirtualImageFn) });
+    }
+}
+
+unsafe extern "C" fn listctrl_virtual_attr_callback(userdata: *mut c_void, item: i64, attr: *mut ffi::wxd_ListItemAttr) -> bool {
+    if userdata.is_null() || attr.is_null() {
+        return false;
+    }
+    let callback = unsafe { &**(userdata as *const VirtualAttrFn) };
+    let Ok(Some(item_attr)) = panic::catch_unwind(AssertUnwindSafe(|| callback(item))) else {
+        return false;
+    };
+    let attr = unsafe { &mut *attr };
+    if let Some(colour) = item_attr.text_colour {
+        attr.has_text_colour = true;
+        attr.text_colour = colour.into();
+    }
+    if let Some(colour) = item_attr.background_colour {
+        attr.has_background_colour = true;
+        attr.background_colour = colour.into();
+    }
+    attr.bold = item_attr.bold;
+    attr.italic = item_attr.italic;
+    true
+}
+
+unsafe extern "C" fn listctrl_drop_virtual_attr_callback(userdata: *mut c_void) {
+    if !userdata.is_null() {
+        drop(unsafe { Box::from_raw(userdata as *mut VirtualAttrFn) });
+    }
+}
+
+// Orders items by their item data, set to their new positions by `sort_items_by`
+unsafe extern "C" fn listctrl_compare_positions(item1: *mut c_void, item2: *mut c_void, _data: *mut c_void) -> c_int {
+    match (item1 as usize).cmp(&(item2 as usize)) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
 fn string_to_c_ptr(text: String) -> *mut c_char {
     match CString::new(text) {
         Ok(c_string) => c_string.into_raw(),
@@ -5,8 +5,11 @@ use crate::event::event_data::CommandEventData;
 use crate::event::{Event, EventType, WxEvtHandler};
 use crate::geometry::{Point, Size};
 use crate::id::Id;
+use crate::widgets::item_data::{get_item_data, remove_item_data, store_item_data};
 use crate::window::{WindowHandle, WxWidget};
+use std::any::Any;
 use std::ffi::{CStr, CString};
+use std::sync::Arc;
 use wxdragon_sys as ffi;
 
 // --- Constants ---
@@ -83,13 +86,14 @@ impl ListBox {
         }
     }
 
-    /// Clears all items from the listbox.
+    /// Clears all items from the listbox, dropping their item data.
     /// No-op if the listbox has been destroyed.
     pub fn clear(&self) {
         let ptr = self.listbox_ptr();
         if ptr.is_null() {
             return;
         }
+        self.cleanup_item_data();
         unsafe {
             ffi::wxd_ListBox_Clear(ptr);
         }
@@ -201,13 +205,14 @@ impl ListBox {
         unsafe { ffi::wxd_ListBox_GetCount(ptr) }
     }
 
-    /// Deletes the item at the specified index.
+    /// Deletes the item at the specified index, dropping its item data.
     /// No-op if the listbox has been destroyed.
     pub fn delete(&self, index: u32) {
         let ptr = self.listbox_ptr();
         if ptr.is_null() {
             return;
         }
+        self.clear_item_data(index);
         unsafe {
             ffi::wxd_ListBox_Delete(ptr, index as i32);
         }
@@ -238,6 +243,108 @@ impl ListBox {
         }
     }
 
+    /// Gets the indices of all selected items, in ascending order. Mostly useful with the
+    /// `Multiple` and `Extended` styles.
+    /// Returns an empty vector if the listbox has been destroyed.
+    pub fn get_selections(&self) -> Vec<u32> {
+        let ptr = self.listbox_ptr();
+        if ptr.is_null() {
+            return Vec::new();
+        }
+        let count = unsafe { ffi::wxd_ListBox_GetSelections(ptr, std::ptr::null_mut(), 0) };
+        let mut selections = vec![0; count];
+        let count = unsafe { ffi::wxd_ListBox_GetSelections(ptr, selections.as_mut_ptr(), selections.len()) };
+        selections.truncate(count);
+        selections.into_iter().map(|index| index as u32).collect()
+    }
+
+    /// Finds the first item with the given label.
+    /// Returns `None` if there is no such item or if the listbox has been destroyed.
+    pub fn find_string(&self, text: &str, case_sensitive: bool) -> Option<u32> {
+        let ptr = self.listbox_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let c_text = CString::new(text).ok()?;
+        let index = unsafe { ffi::wxd_ListBox_FindString(ptr, c_text.as_ptr(), case_sensitive) };
+        if index == NOT_FOUND { None } else { Some(index as u32) }
+    }
+
+    /// Scrolls the listbox so that the item at the given index is the first visible one.
+    /// No-op if the index is out of bounds or the listbox has been destroyed.
+    pub fn set_first_item(&self, index: u32) {
+        let ptr = self.listbox_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ListBox_SetFirstItem(ptr, index as i32) };
+    }
+
+    /// Gets the index of the item at the given point, in client coordinates, e.g. the
+    /// position of a mouse event.
+    /// Returns `None` if there is no item there or if the listbox has been destroyed.
+    pub fn hit_test(&self, point: Point) -> Option<u32> {
+        let ptr = self.listbox_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let index = unsafe { ffi::wxd_ListBox_HitTest(ptr, point.into()) };
+        if index == NOT_FOUND { None } else { Some(index as u32) }
+    }
+
+    /// Attaches data of any type to the item at the given index, replacing and dropping the
+    /// data attached before.
+    ///
+    /// The data moves with the item when a sorted listbox reorders it, and is dropped when
+    /// the item is deleted with [`delete`](Self::delete) or [`clear`](Self::clear), or when
+    /// a listbox created with the builder is destroyed.
+    /// Returns false if the index is out of bounds or the listbox has been destroyed.
+    pub fn set_item_data<T: Any + Send + Sync + 'static>(&self, index: u32, data: T) -> bool {
+        let ptr = self.listbox_ptr();
+        if ptr.is_null() || index >= self.get_count() {
+            return false;
+        }
+        self.clear_item_data(index);
+        let data_id = store_item_data(data);
+        unsafe { ffi::wxd_ListBox_SetClientData(ptr, index, data_id) };
+        true
+    }
+
+    /// Gets the data attached to the item at the given index with
+    /// [`set_item_data`](Self::set_item_data).
+    /// Returns `None` if the item has no data of type `T` or if the listbox has been destroyed.
+    pub fn get_item_data<T: Any + Send + Sync + 'static>(&self, index: u32) -> Option<Arc<T>> {
+        let ptr = self.listbox_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let data_id = unsafe { ffi::wxd_ListBox_GetClientData(ptr, index) };
+        get_item_data(data_id)?.downcast::<T>().ok()
+    }
+
+    /// Drops the data attached to the item at the given index.
+    /// Returns false if the item had no data or if the listbox has been destroyed.
+    pub fn clear_item_data(&self, index: u32) -> bool {
+        let ptr = self.listbox_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let data_id = unsafe { ffi::wxd_ListBox_GetClientData(ptr, index) };
+        if data_id == 0 {
+            return false;
+        }
+        unsafe { ffi::wxd_ListBox_SetClientData(ptr, index, 0) };
+        remove_item_data(data_id).is_some()
+    }
+
+    // Drops the data attached to all items
+    fn cleanup_item_data(&self) {
+        let ptr = self.listbox_ptr();
+        if !ptr.is_null() {
+            unsafe { drop_all_item_data(ptr) };
+        }
+    }
+
     /// Creates a ListBox from a raw pointer.
     /// # Safety
     /// The pointer must be a valid `wxd_ListBox_t`.
@@ -263,6 +370,21 @@ impl ListBox {
     }
 }
 
+/// Drops the data attached to all items of a listbox.
+///
+/// # Safety
+/// `ptr` must point to a live `wxd_ListBox_t`.
+unsafe fn drop_all_item_data(ptr: *mut RawListBox) {
+    let count = unsafe { ffi::wxd_ListBox_GetCount(ptr) };
+    for index in 0..count {
+        let data_id = unsafe { ffi::wxd_ListBox_GetClientData(ptr, index) };
+        if data_id != 0 {
+            unsafe { ffi::wxd_ListBox_SetClientData(ptr, index, 0) };
+            remove_item_data(data_id);
+        }
+    }
+}
+
 // Use the widget_builder macro to generate the ListBoxBuilder implementation
 widget_builder!(
     name: ListBox,
@@ -291,6 +413,10 @@ widget_builder!(
 
         let list_box = unsafe { ListBox::from_ptr(ctrl_ptr) };
 
+        // Drop the item data along with the listbox. The handle is already invalidated when
+        // DESTROY handlers run, so this uses the pointer, which is valid until the event is done.
+        list_box.bind_internal(EventType::DESTROY, move |_| unsafe { drop_all_item_data(ctrl_ptr) });
+
         // Append initial choices if any
         for choice_str in &slf.choices {
             list_box.append(choice_str);
@@ -337,6 +463,8 @@ pub struct ListBoxEventData {
     pub event: CommandEventData,
 }
 
+crate::impl_event_base!(ListBoxEventData => event.event);
+
 impl ListBoxEventData {
     pub fn new(event: Event) -> Self {
         Self {
@@ -64,6 +64,8 @@ pub struct MediaCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(MediaCtrlEventData => event);
+
 impl MediaCtrlEventData {
     /// Create a new MediaCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -18,8 +18,10 @@ pub mod calendar_ctrl;
 pub mod checkbox;
 pub mod checklistbox;
 pub mod choice;
+pub mod choicebook;
 pub mod collapsible_pane;
 pub mod colour_picker_ctrl;
+pub mod combo_ctrl;
 pub mod combobox;
 pub mod command_link_button;
 pub mod dataview;
@@ -34,8 +36,10 @@ pub mod gauge;
 pub mod generic_static_bitmap;
 pub mod grid;
 pub mod hyperlink_ctrl;
+pub mod infobar;
 pub mod item_data;
 pub mod list_ctrl;
+pub mod listbook;
 pub mod listbox;
 pub mod mdi_child_frame;
 pub mod mdi_parent_frame;
@@ -71,6 +75,7 @@ pub mod textctrl;
 pub mod time_picker_ctrl;
 pub mod togglebutton;
 pub mod toolbar;
+pub mod toolbook;
 pub mod treebook;
 pub mod treectrl;
 pub mod treelistctrl;
@@ -116,8 +121,10 @@ pub use calendar_ctrl::{CalendarCtrl, CalendarCtrlBuilder};
 pub use checkbox::{CheckBox, CheckBoxBuilder};
 pub use checklistbox::{CheckListBox, CheckListBoxBuilder};
 pub use choice::{Choice, ChoiceBuilder};
+pub use choicebook::{Choicebook, ChoicebookBuilder, ChoicebookStyle};
 pub use collapsible_pane::{CollapsiblePane, CollapsiblePaneBuilder, CollapsiblePaneStyle};
 pub use colour_picker_ctrl::{ColourPickerCtrl, ColourPickerCtrlBuilder};
+pub use combo_ctrl::{ComboCtrl, ComboCtrlBuilder, ComboCtrlEvent, ComboCtrlEventData, ComboCtrlStyle};
 pub use combobox::{ComboBox, ComboBoxBuilder};
 pub use command_link_button::{CommandLinkButton, CommandLinkButtonBuilder};
 pub use dataview::{
@@ -134,13 +141,15 @@ pub use grid::{
 // GenericStaticBitmap is mainly for internal use by the platform-aware XRC handler
 pub use generic_static_bitmap::{GenericStaticBitmap, GenericStaticBitmapBuilder};
 pub use hyperlink_ctrl::{HyperlinkCtrl, HyperlinkCtrlBuilder};
-pub use list_ctrl::{ListCtrl, ListCtrlBuilder};
+pub use infobar::{InfoBar, InfoBarIcon};
+pub use list_ctrl::{ListCtrl, ListCtrlBuilder, ListItemAttr};
+pub use listbook::{Listbook, ListbookBuilder, ListbookStyle};
 pub use listbox::{ListBox, ListBoxBuilder};
 pub use mdi_child_frame::{MDIChildFrame, MDIChildFrameBuilder};
 pub use mdi_parent_frame::{MDIParentFrame, MDIParentFrameBuilder};
 #[cfg(feature = "media-ctrl")]
 pub use media_ctrl::{MediaCtrl, MediaCtrlBuilder, MediaCtrlPlayerControls, MediaState};
-pub use notebook::{Notebook, NotebookBuilder};
+pub use notebook::{Notebook, NotebookBuilder, NotebookHitTestFlags};
 pub use panel::{Panel, PanelBuilder};
 pub use property_grid::{
     Property, PropertyChoice, PropertyGrid, PropertyGridBuilder, PropertyGridEvent, PropertyGridEventData, PropertyGridStyle,
@@ -148,15 +157,19 @@ pub use property_grid::{
 };
 pub use radio_button::{RadioButton, RadioButtonBuilder, RadioButtonStyle};
 pub use radiobox::RadioBox;
-pub use rearrangelist::{RearrangeList, RearrangeListEvent, RearrangeListEventData, RearrangeListStyle};
+pub use rearrangelist::{
+    RearrangeCtrl, RearrangeCtrlBuilder, RearrangeCtrlEvent, RearrangeList, RearrangeListEvent, RearrangeListEventData,
+    RearrangeListStyle,
+};
 #[cfg(feature = "richtext")]
 pub use richtextctrl::{
-    RichTextCtrl, RichTextCtrlBuilder, RichTextCtrlEvent, RichTextCtrlEventData, RichTextCtrlStyle, RichTextFileType,
+    RichTextAlignment, RichTextCtrl, RichTextCtrlBuilder, RichTextCtrlEvent, RichTextCtrlEventData, RichTextCtrlStyle,
+    RichTextFileType,
 };
 pub use scrollbar::{ScrollBar, ScrollBarBuilder, ScrollBarStyle};
 pub use scrolled_window::{ScrolledWindow, ScrolledWindowBuilder};
 pub use search_ctrl::{SearchCtrl, SearchCtrlBuilder};
-pub use simplebook::{SimpleBook, SimpleBookBuilder};
+pub use simplebook::{ShowEffect, SimpleBook, SimpleBookBuilder};
 pub use slider::{Slider, SliderBuilder};
 pub use spinbutton::{SpinButton, SpinButtonBuilder};
 pub use spinctrl::{SpinCtrl, SpinCtrlBuilder};
@@ -166,7 +179,7 @@ pub use static_bitmap::{ScaleMode, StaticBitmap, StaticBitmapBuilder};
 pub use static_line::{StaticLine, StaticLineBuilder, StaticLineStyle};
 pub use static_text::{StaticText, StaticTextBuilder, StaticTextStyle};
 pub use staticbox::{StaticBox, StaticBoxBuilder};
-pub use statusbar::{StatusBar, StatusBarBuilder};
+pub use statusbar::{StatusBar, StatusBarBuilder, StatusBarFieldStyle};
 #[cfg(feature = "stc")]
 pub use styledtextctrl::{
     EolMode, FindFlags, Lexer, MarginType, MarkerSymbol, SelectionMode, StyledTextCtrl, StyledTextCtrlBuilder,
@@ -175,7 +188,8 @@ pub use styledtextctrl::{
 pub use taskbar_icon::{TaskBarIcon, TaskBarIconBuilder, TaskBarIconStyle, TaskBarIconType};
 pub use textctrl::{TextCtrl, TextCtrlBuilder};
 pub use togglebutton::{ToggleButton, ToggleButtonBuilder};
-pub use toolbar::ToolBar;
+pub use toolbar::{RemovedTool, ToolBar, ToolBarBuilder};
+pub use toolbook::{Toolbook, ToolbookBuilder, ToolbookStyle};
 pub use treebook::Treebook;
 pub use treebook::TreebookBuilder;
 pub use treectrl::{TreeCtrl, TreeCtrlBuilder};
@@ -187,8 +201,9 @@ pub use virtual_list::{VirtualList, VirtualListDataSource, VirtualListItemRender
 // Re-export ImageList
 #[cfg(feature = "webview")]
 pub use webview::{
-    WebView, WebViewBackend, WebViewBrowsingDataTypes, WebViewBuilder, WebViewFindFlags, WebViewHandlerResponse,
-    WebViewNavigationError, WebViewReloadFlags, WebViewUserScriptInjectionTime, WebViewZoom, WebViewZoomType,
+    WebView, WebViewBackend, WebViewBrowsingDataTypes, WebViewBuilder, WebViewCookie, WebViewDownloadState, WebViewFindFlags,
+    WebViewHandlerResponse, WebViewNavigationError, WebViewReloadFlags, WebViewSchemeHandler, WebViewSnapshotArea,
+    WebViewUserScriptInjectionTime, WebViewZoom, WebViewZoomType,
 };
 
 pub use imagelist::ImageList;
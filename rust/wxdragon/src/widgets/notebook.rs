@@ -6,7 +6,7 @@ use crate::geometry::{Point, Size};
 use crate::id::Id;
 use crate::widgets::imagelist::ImageList;
 use crate::window::{Window, WindowHandle, WxWidget};
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_int;
 use wxdragon_sys as ffi;
 
@@ -27,6 +27,18 @@ widget_style_enum!(
     default_variant: Default
 );
 
+bitflags::bitflags! {
+    /// Flags returned by [`Notebook::hit_test`] indicating what part of the notebook was hit.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct NotebookHitTestFlags: u32 {
+        const NOWHERE = 0x0001;
+        const ON_ICON = 0x0002;
+        const ON_LABEL = 0x0004;
+        const ON_PAGE = 0x0008;
+        const ON_ITEM = Self::ON_ICON.bits() | Self::ON_LABEL.bits();
+    }
+}
+
 /// Represents a wxNotebook widget.
 ///
 /// Notebook uses `WindowHandle` internally for safe memory management.
@@ -236,7 +248,8 @@ impl Notebook {
         }
     }
 
-    /// Removes the page at the given index.
+    /// Removes the page at the given index without destroying it, so it can be added
+    /// again or reparented; see [`delete_page`](Self::delete_page) to destroy it too.
     /// Returns `true` if the page was removed successfully.
     /// Returns false if the notebook has been destroyed.
     pub fn remove_page(&self, index: usize) -> bool {
@@ -247,6 +260,127 @@ impl Notebook {
         unsafe { ffi::wxd_Notebook_RemovePage(ptr, index) }
     }
 
+    /// Removes the page at the given index and destroys its window.
+    /// Returns false if the index is out of bounds or if the notebook has been destroyed.
+    pub fn delete_page(&self, index: usize) -> bool {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Notebook_DeletePage(ptr, index) }
+    }
+
+    /// Removes and destroys all pages.
+    /// Returns false if the notebook has been destroyed.
+    pub fn delete_all_pages(&self) -> bool {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Notebook_DeleteAllPages(ptr) }
+    }
+
+    /// Returns the index of the page showing `page`.
+    /// Returns `None` if it isn't a page of this notebook or if the notebook has been destroyed.
+    pub fn find_page(&self, page: &dyn WxWidget) -> Option<usize> {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() || page.handle_ptr().is_null() {
+            return None;
+        }
+        let index = unsafe { ffi::wxd_Notebook_FindPage(ptr, page.handle_ptr()) };
+        usize::try_from(index).ok()
+    }
+
+    /// Sets the text of the page's tab.
+    /// Returns false if the index is out of bounds or if the notebook has been destroyed.
+    pub fn set_page_text(&self, index: usize, text: &str) -> bool {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let c_text = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_Notebook_SetPageText(ptr, index, c_text.as_ptr()) }
+    }
+
+    /// Gets the text of the page's tab.
+    /// Returns `None` if the index is out of bounds or if the notebook has been destroyed.
+    pub fn get_page_text(&self, index: usize) -> Option<String> {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let len = unsafe { ffi::wxd_Notebook_GetPageText(ptr, index, std::ptr::null_mut(), 0) };
+        if len < 0 {
+            return None;
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_Notebook_GetPageText(ptr, index, buf.as_mut_ptr(), buf.len()) };
+        Some(unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().to_string() })
+    }
+
+    /// Sets the icon of the page's tab to the image at `image_id` in the image list, see
+    /// [`set_image_list`](Self::set_image_list), or -1 for none.
+    /// Returns false if the index is out of bounds or if the notebook has been destroyed.
+    pub fn set_page_image(&self, index: usize, image_id: i32) -> bool {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Notebook_SetPageImage(ptr, index, image_id as c_int) }
+    }
+
+    /// Gets the index in the image list of the icon of the page's tab.
+    /// Returns -1 if the page has no icon, the index is out of bounds or if the notebook
+    /// has been destroyed.
+    pub fn get_page_image(&self, index: usize) -> i32 {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_Notebook_GetPageImage(ptr, index) }
+    }
+
+    /// Moves the page at `from` so that it ends up at `to`, e.g. `move_page(0, 2)` makes the
+    /// first page the third one.
+    ///
+    /// The page keeps its window, with its children, sizer and event handlers, its tab text
+    /// and icon; the selected page stays selected and no page change events are sent.
+    /// Returns false if an index is out of bounds or if the notebook has been destroyed.
+    pub fn move_page(&self, from: usize, to: usize) -> bool {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Notebook_MovePage(ptr, from, to) }
+    }
+
+    /// Returns the index of the tab at `point`, in notebook coordinates, along with flags
+    /// telling which part of the notebook is there.
+    /// Returns `(None, NOWHERE)` if the notebook has been destroyed.
+    pub fn hit_test(&self, point: Point) -> (Option<usize>, NotebookHitTestFlags) {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return (None, NotebookHitTestFlags::NOWHERE);
+        }
+        let mut flags: c_int = 0;
+        let index = unsafe { ffi::wxd_Notebook_HitTest(ptr, point.into(), &mut flags) };
+        let flags = NotebookHitTestFlags::from_bits_truncate(flags as u32);
+        (usize::try_from(index).ok(), flags)
+    }
+
+    /// Lets the user drag tabs to other positions with the mouse.
+    ///
+    /// Pages are moved like with [`move_page`](Self::move_page), and
+    /// [`on_pages_reordered`](Self::on_pages_reordered) handlers are called after each move.
+    /// No-op if the notebook has been destroyed.
+    pub fn enable_tab_drag_reorder(&self, enable: bool) {
+        let ptr = self.notebook_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Notebook_EnableTabDragReorder(ptr, enable) }
+    }
+
     /// Returns the underlying WindowHandle for this notebook.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
@@ -302,17 +436,24 @@ widget_builder!(
 pub enum NotebookEvent {
     /// A notebook page has been changed.
     PageChanged,
-    /// A notebook page is about to change. This event can be vetoed.
+    /// A notebook page is about to change. This event can be vetoed with
+    /// [`NotebookPageChangedEvent::veto`], which keeps the old page and its tab selected.
     PageChanging,
+    /// The user dragged a tab to another position, see
+    /// [`Notebook::enable_tab_drag_reorder`]. The event's selection is the page's new
+    /// position and its old selection the one it was dragged from.
+    PagesReordered,
 }
 
-/// Event data for a `Notebook::PageChanged` event.
+/// Event data for `Notebook` events.
 #[derive(Debug)]
 pub struct NotebookPageChangedEvent {
     /// The base event data.
     pub base: Event,
 }
 
+crate::impl_event_base!(NotebookPageChangedEvent => base);
+
 impl NotebookPageChangedEvent {
     /// Creates new `NotebookPageChangedEvent` from a base `Event`.
     pub fn new(base_event: Event) -> Self {
@@ -320,7 +461,8 @@ impl NotebookPageChangedEvent {
     }
 
     /// Gets the page that has been selected.
-    /// For a `PageChanged` event, this is the new page.
+    /// For a `PageChanged` event, this is the new page; for `PagesReordered`, the position
+    /// the dragged page was moved to.
     pub fn get_selection(&self) -> Option<i32> {
         if self.base.is_null() {
             return None;
@@ -330,7 +472,8 @@ impl NotebookPageChangedEvent {
     }
 
     /// Gets the page that was selected before the change.
-    /// For a `PageChanged` event, this is the old page.
+    /// For a `PageChanged` event, this is the old page; for `PagesReordered`, the position
+    /// the dragged page was moved from.
     pub fn get_old_selection(&self) -> Option<i32> {
         if self.base.is_null() {
             return None;
@@ -338,19 +481,32 @@ impl NotebookPageChangedEvent {
         let val = unsafe { ffi::wxd_NotebookEvent_GetOldSelection(self.base.0) };
         if val == ffi::WXD_NOT_FOUND as i32 { None } else { Some(val) }
     }
+
+    /// Vetoes a `PageChanging` event, e.g. to ask the user to save the page being left
+    /// first: the old page stays selected and no `PageChanged` event follows.
+    pub fn veto(&self) {
+        self.base.veto();
+    }
+
+    /// Checks if the event has been vetoed
+    pub fn is_vetoed(&self) -> bool {
+        self.base.is_vetoed()
+    }
 }
 
 // Use the implement_widget_local_event_handlers macro for notebook events
 crate::implement_widget_local_event_handlers!(
     Notebook, NotebookEvent, NotebookPageChangedEvent,
     PageChanged => page_changed, EventType::NOTEBOOK_PAGE_CHANGED,
-    PageChanging => page_changing, EventType::NOTEBOOK_PAGE_CHANGING
+    PageChanging => page_changing, EventType::NOTEBOOK_PAGE_CHANGING,
+    PagesReordered => pages_reordered, EventType::NOTEBOOK_PAGES_REORDERED
 );
 
 // XRC Support - enables Notebook to be created from XRC-managed pointers
 #[cfg(feature = "xrc")]
 impl crate::xrc::XrcSupport for Notebook {
     unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        unsafe { ffi::wxd_Notebook_GuardVetoes(ptr as *mut ffi::wxd_Notebook_t) };
         Notebook {
             handle: WindowHandle::new(ptr),
         }
@@ -364,6 +520,7 @@ impl crate::window::FromWindowWithClassName for Notebook {
     }
 
     unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        unsafe { ffi::wxd_Notebook_GuardVetoes(ptr as *mut ffi::wxd_Notebook_t) };
         Notebook {
             handle: WindowHandle::new(ptr),
         }
@@ -29,6 +29,39 @@ pub type WxResult<T> = Result<T, Error>;
 pub const TIMEOUT_AUTO: i32 = -1; // Automatically determine the timeout
 pub const TIMEOUT_NEVER: i32 = 0; // Never hide the notification automatically (manual Close() needed)
 
+/// How long a notification stays visible, see [`NotificationMessage::show`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timeout {
+    /// Let the system decide.
+    #[default]
+    Auto,
+    /// Keep the notification until it is dismissed or [`NotificationMessage::close`] is called.
+    Never,
+    /// Hide the notification after the given number of seconds.
+    Seconds(u32),
+}
+
+impl Timeout {
+    fn as_raw(self) -> c_int {
+        match self {
+            Timeout::Auto => TIMEOUT_AUTO,
+            Timeout::Never => TIMEOUT_NEVER,
+            Timeout::Seconds(secs) => secs.min(i32::MAX as u32) as c_int,
+        }
+    }
+}
+
+/// Converts the raw `TIMEOUT_AUTO` / `TIMEOUT_NEVER` / seconds values.
+impl From<i32> for Timeout {
+    fn from(raw: i32) -> Self {
+        match raw {
+            TIMEOUT_NEVER => Timeout::Never,
+            secs if secs > 0 => Timeout::Seconds(secs as u32),
+            _ => Timeout::Auto,
+        }
+    }
+}
+
 // Define NotificationStyle using widget_style_enum macro
 widget_style_enum!(
     name: NotificationStyle,
@@ -60,6 +93,8 @@ pub struct NotificationMessageEventData {
     event: Event,
 }
 
+crate::impl_event_base!(NotificationMessageEventData => event);
+
 impl NotificationMessageEventData {
     /// Create a new NotificationMessageEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -75,7 +110,26 @@ impl NotificationMessageEventData {
 /// Represents a `wxNotificationMessage`.
 ///
 /// This struct manages a pointer to the underlying C++ `wxNotificationMessage` object.
-/// It is responsible for calling `wxd_NotificationMessage_Destroy` when it goes out of scope.
+/// It is responsible for calling `wxd_NotificationMessage_Destroy` when it goes out of scope,
+/// so keep it alive for as long as its events should be delivered.
+///
+/// Native notifications are used where available. On Windows they need either toasts
+/// enabled with [`use_toasts`](NotificationMessage::use_toasts) or a tray icon registered
+/// with [`use_taskbar_icon`](NotificationMessage::use_taskbar_icon); otherwise the generic
+/// implementation is used automatically.
+///
+/// # Example
+///
+/// ```ignore
+/// let notification = NotificationMessage::builder()
+///     .with_title("Server down")
+///     .with_message("example.com:443 stopped responding")
+///     .with_flags(NotificationStyle::Warning)
+///     .build()?;
+/// notification.add_action(1, "Restart")?;
+/// notification.on_action(|event| println!("action {}", event.get_id()));
+/// notification.show(Timeout::Seconds(10));
+/// ```
 #[derive(Debug)] // wxNotificationMessage is not Cloneable as it has direct destructor
 pub struct NotificationMessage {
     ptr: *mut ffi::wxd_NotificationMessage_t,
@@ -93,13 +147,43 @@ impl NotificationMessage {
     /// Shows the notification to the user.
     ///
     /// # Arguments
-    /// * `timeout` - How long the notification is shown, in seconds.
-    ///   Use `TIMEOUT_AUTO` to let the system decide, or `TIMEOUT_NEVER` if it shouldn't time out.
-    ///   A positive value specifies the timeout in seconds.
+    /// * `timeout` - How long the notification is shown, e.g. `Timeout::Auto` to let the
+    ///   system decide or `Timeout::Seconds(5)`. The raw `TIMEOUT_AUTO` / `TIMEOUT_NEVER`
+    ///   constants and second counts are accepted too.
     ///
     /// Returns `true` if it was possible to show the notification, `false` if an error occurred.
-    pub fn show(&self, timeout: i32) -> bool {
-        unsafe { ffi::wxd_NotificationMessage_Show(self.ptr, timeout as c_int) }
+    pub fn show(&self, timeout: impl Into<Timeout>) -> bool {
+        unsafe { ffi::wxd_NotificationMessage_Show(self.ptr, timeout.into().as_raw()) }
+    }
+
+    /// Makes native notifications on Windows come from `icon`, so they carry the
+    /// application's tray icon and name. Pass `None` to stop using it.
+    ///
+    /// The icon is forgotten automatically when it is destroyed. Returns `false`
+    /// on other platforms, where this has no effect.
+    pub fn use_taskbar_icon(icon: Option<&crate::widgets::taskbar_icon::TaskBarIcon>) -> bool {
+        let icon_ptr = icon.map_or(std::ptr::null_mut(), |icon| icon.as_ptr());
+        unsafe { ffi::wxd_NotificationMessage_UseTaskBarIcon(icon_ptr) }
+    }
+
+    /// Enables toast notifications on Windows, which need a Start menu shortcut carrying
+    /// the application's AppUserModelID.
+    ///
+    /// This creates or updates the shortcut at `shortcut_path` (relative to the Start menu
+    /// programs folder) and sets `app_id` as the process' AppUserModelID; `None` lets
+    /// wxWidgets derive either from the application name.
+    /// Both persist outside the application, so call it only when toasts are wanted, before
+    /// creating notifications. Returns `false` if toasts are unavailable, and on other
+    /// platforms, where this has no effect.
+    pub fn use_toasts(shortcut_path: Option<&str>, app_id: Option<&str>) -> WxResult<bool> {
+        let c_shortcut = shortcut_path.map(CString::new).transpose()?;
+        let c_app_id = app_id.map(CString::new).transpose()?;
+        Ok(unsafe {
+            ffi::wxd_NotificationMessage_UseToasts(
+                c_shortcut.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()),
+                c_app_id.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()),
+            )
+        })
     }
 
     /// Hides the notification.
@@ -260,6 +344,11 @@ impl NotificationMessageBuilder {
         self
     }
 
+    /// Sets the icon flags (`Information`, `Warning` or `Error`). Same as [`with_style`](Self::with_style).
+    pub fn with_flags(self, flags: NotificationStyle) -> Self {
+        self.with_style(flags)
+    }
+
     /// Builds the `NotificationMessage`.
     ///
     /// Returns `Some(NotificationMessage)` on success, or `None` if creation failed.
@@ -416,6 +416,8 @@ pub struct PropertyGridEventData {
     event: Event,
 }
 
+crate::impl_event_base!(PropertyGridEventData => event);
+
 impl PropertyGridEventData {
     /// Wraps a generic wxDragon event as PropertyGrid event data.
     ///
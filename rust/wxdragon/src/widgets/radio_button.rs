@@ -152,6 +152,8 @@ pub struct RadioButtonEventData {
     base: CommandEventData,
 }
 
+crate::impl_event_base!(RadioButtonEventData => base.event);
+
 impl RadioButtonEventData {
     /// Creates new `RadioButtonEventData` from base `CommandEventData`.
     pub(crate) fn new(event: Event) -> Self {
@@ -20,6 +20,8 @@ pub struct RadioBoxEventData {
     event: Event,
 }
 
+crate::impl_event_base!(RadioBoxEventData => event);
+
 impl RadioBoxEventData {
     /// Create a new RadioBoxEventData from a generic Event
     pub fn new(event: Event) -> Self {
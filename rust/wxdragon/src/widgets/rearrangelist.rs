@@ -3,6 +3,7 @@
 use crate::event::{Event, EventType, WxEvtHandler};
 use crate::geometry::{Point, Size};
 use crate::id::Id;
+use crate::widgets::{DataViewCtrl, ListCtrl};
 use crate::window::{WindowHandle, WxWidget};
 // Window is used by XRC support for backwards compatibility
 #[allow(unused_imports)]
@@ -34,7 +35,7 @@ pub enum RearrangeListEvent {
     Selected,
     /// Emitted when an item is checked/unchecked
     Toggled,
-    /// Emitted when items are rearranged
+    /// Emitted after the user moved an item with the buttons of a [`RearrangeCtrl`]
     Rearranged,
 }
 
@@ -44,6 +45,8 @@ pub struct RearrangeListEventData {
     event: Event,
 }
 
+crate::impl_event_base!(RearrangeListEventData => event);
+
 impl RearrangeListEventData {
     /// Create a new RearrangeListEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -60,9 +63,12 @@ impl RearrangeListEventData {
         self.event.get_string()
     }
 
-    /// Get whether the item was checked or unchecked (for Toggled events)
+    /// Get whether the item is now checked (for Toggled and CheckChanged events)
     pub fn is_checked(&self) -> Option<bool> {
-        self.event.is_checked()
+        // The event's integer is the item position, so ask the list for the new state
+        let index = self.get_selection()?;
+        let list = self.event.get_event_object()?.handle_ptr() as *mut ffi::wxd_CheckListBox_t;
+        Some(unsafe { ffi::wxd_CheckListBox_IsChecked(list, index) })
     }
 }
 
@@ -360,3 +366,202 @@ impl crate::xrc::XrcSupport for RearrangeList {
         }
     }
 }
+
+/// Events emitted by RearrangeCtrl
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RearrangeCtrlEvent {
+    /// Emitted after the user moved an item up or down
+    Reordered,
+    /// Emitted after the user checked or unchecked an item
+    CheckChanged,
+}
+
+/// Represents a wxRearrangeCtrl: a [`RearrangeList`] with buttons to move the selected item
+/// up and down, as used by "choose and order the visible columns" dialogs.
+///
+/// The order and the checked state of the items are both up to the user. The events are
+/// sent once the order has been updated, so [`get_order`](Self::get_order) and
+/// [`get_items`](Self::get_items) already return the new state in the handlers.
+///
+/// The items keep their original index, the one they had in the builder, however they are
+/// ordered. To let the user pick up where they left off, build the control with the items
+/// in their original order again and pass the saved order to `with_order`.
+///
+/// # Example
+/// ```ignore
+/// let columns = RearrangeCtrl::builder(&dialog)
+///     .with_items(vec![(true, "Name".to_string()), (true, "Size".to_string()), (false, "Modified".to_string())])
+///     .build();
+///
+/// columns.on_reordered(move |_| println!("Order: {:?}", columns.get_order()));
+/// columns.on_check_changed(move |_| println!("Items: {:?}", columns.get_items()));
+///
+/// // After OK, show and order the columns of a ListCtrl the same way
+/// columns.apply_to_list_ctrl(&list);
+/// ```
+#[derive(Clone, Copy)]
+pub struct RearrangeCtrl {
+    /// Safe handle to the underlying wxRearrangeCtrl - automatically invalidated on destroy
+    handle: WindowHandle,
+    list: RearrangeList,
+}
+
+impl RearrangeCtrl {
+    /// Creates a new `RearrangeCtrlBuilder` for constructing a rearrange control.
+    pub fn builder(parent: &dyn WxWidget) -> RearrangeCtrlBuilder<'_> {
+        RearrangeCtrlBuilder::new(parent)
+    }
+
+    /// Returns the list of the control, e.g. to select an item.
+    pub fn get_list(&self) -> RearrangeList {
+        self.list
+    }
+
+    /// Gets the current order of the items in wxWidgets' encoding.
+    /// Returns empty vector if the widget has been destroyed.
+    ///
+    /// The n-th value is the original index of the item shown at position n; it is
+    /// negative, the bitwise complement `!index`, if the item is unchecked.
+    pub fn get_order(&self) -> Vec<i32> {
+        self.list.get_current_order()
+    }
+
+    /// Gets the items in their current order together with their checked state.
+    /// Returns empty vector if the widget has been destroyed.
+    pub fn get_items(&self) -> Vec<(bool, String)> {
+        self.get_order()
+            .iter()
+            .enumerate()
+            .map(|(pos, &index)| (index >= 0, self.list.get_string(pos as u32).unwrap_or_default()))
+            .collect()
+    }
+
+    /// Shows, hides and orders the columns of `list` the way the items are, taking item n
+    /// for column n.
+    ///
+    /// Unchecked columns get a width of 0; columns checked again are sized to their header.
+    /// The order is only applied where the platform supports ordering the columns of a
+    /// ListCtrl, see [`ListCtrl::set_columns_order`]. Returns false if either control has
+    /// been destroyed or the column count doesn't match the items.
+    pub fn apply_to_list_ctrl(&self, list: &ListCtrl) -> bool {
+        let order = self.get_order();
+        if order.is_empty() || list.get_column_count() != order.len() as i32 {
+            return false;
+        }
+        for &index in &order {
+            let col = if index < 0 { !index } else { index } as i64;
+            if index < 0 {
+                list.set_column_width(col, 0);
+            } else if list.get_column_width(col) == 0 {
+                // wxLIST_AUTOSIZE_USEHEADER
+                list.set_column_width(col, -2);
+            }
+        }
+        let columns: Vec<i32> = order.iter().map(|&index| if index < 0 { !index } else { index }).collect();
+        list.set_columns_order(&columns);
+        true
+    }
+
+    /// Shows and hides the columns of `ctrl` the way the items are checked, taking item n
+    /// for column n.
+    ///
+    /// A DataViewCtrl can't move its columns, so only their visibility is applied; users
+    /// reorder them by dragging the headers of reorderable columns. Returns false if either
+    /// control has been destroyed or the column count doesn't match the items.
+    pub fn apply_to_dataview_ctrl(&self, ctrl: &DataViewCtrl) -> bool {
+        let order = self.get_order();
+        if order.is_empty() || !ctrl.is_valid() || ctrl.get_column_count() != order.len() {
+            return false;
+        }
+        for &index in &order {
+            let col = if index < 0 { !index } else { index } as usize;
+            if let Some(column) = ctrl.get_column(col) {
+                column.set_hidden(index < 0);
+            }
+        }
+        true
+    }
+}
+
+// Manual WxWidget implementation for RearrangeCtrl (using WindowHandle)
+impl WxWidget for RearrangeCtrl {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.handle.is_valid()
+    }
+}
+
+// Implement WxEvtHandler for event binding
+impl WxEvtHandler for RearrangeCtrl {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut()) as *mut ffi::wxd_EvtHandler_t
+    }
+}
+
+impl crate::event::WindowEvents for RearrangeCtrl {}
+
+// The events come from the list and propagate to the control
+crate::implement_widget_local_event_handlers!(
+    RearrangeCtrl,
+    RearrangeCtrlEvent,
+    RearrangeListEventData,
+    Reordered => reordered, EventType::COMMAND_REARRANGE_LIST,
+    CheckChanged => check_changed, EventType::REARRANGE_LIST_CHECKED
+);
+
+widget_builder!(
+    name: RearrangeCtrl,
+    parent_type: &'a dyn WxWidget,
+    style_type: RearrangeListStyle,
+    fields: {
+        // The items in their original order, each with whether it starts checked
+        items: Vec<(bool, String)> = Vec::new(),
+        // An order from `get_order` to restore, overriding the checked states of the items
+        order: Vec<i32> = Vec::new()
+    },
+    build_impl: |slf| {
+        let c_items: Vec<CString> = slf.items.iter()
+            .map(|(_, s)| CString::new(s.as_str()).expect("Invalid CString for RearrangeCtrl item"))
+            .collect();
+        let c_items_ptrs: Vec<*const c_char> = c_items.iter()
+            .map(|cs| cs.as_ptr())
+            .collect();
+        let order: Vec<i32> = if !slf.order.is_empty() {
+            slf.order.clone()
+        } else {
+            slf.items.iter()
+                .enumerate()
+                .map(|(index, (checked, _))| if *checked { index as i32 } else { !(index as i32) })
+                .collect()
+        };
+
+        let ctrl_ptr = unsafe {
+            ffi::wxd_RearrangeCtrl_Create(
+                slf.parent.handle_ptr(),
+                slf.id,
+                slf.pos.into(),
+                slf.size.into(),
+                order.as_ptr(),
+                order.len() as i32,
+                c_items_ptrs.as_ptr() as *mut *const c_char,
+                c_items.len() as i32,
+                slf.style.bits(),
+            )
+        };
+
+        if ctrl_ptr.is_null() {
+            panic!("Failed to create RearrangeCtrl widget");
+        }
+
+        let list_ptr = unsafe { ffi::wxd_RearrangeCtrl_GetList(ctrl_ptr) };
+        RearrangeCtrl {
+            handle: WindowHandle::new(ctrl_ptr as *mut ffi::wxd_Window_t),
+            list: RearrangeList {
+                handle: WindowHandle::new(list_ptr as *mut ffi::wxd_Window_t),
+            },
+        }
+    }
+);
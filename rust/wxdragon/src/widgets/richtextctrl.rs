@@ -1,6 +1,7 @@
 //!
 //! Safe wrapper for wxRichTextCtrl.
 
+use crate::bitmap::Bitmap;
 use crate::event::TextEvents;
 use crate::event::{Event, EventType, WxEvtHandler};
 use crate::geometry::{Point, Size};
@@ -35,17 +36,17 @@ widget_style_enum!(
 /// File types for loading and saving rich text documents
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RichTextFileType {
-    /// Any file type (let wxWidgets determine)
+    /// Any file type (let wxWidgets determine from the extension)
     Any = 0,
     /// Plain text file
     Text = 1,
-    /// XML format
+    /// RichText XML format, which keeps all formatting and images
     Xml = 2,
-    /// HTML format
+    /// HTML format (save only)
     Html = 3,
-    /// RTF format
+    /// RTF format (no handler is included with wxWidgets)
     Rtf = 4,
-    /// PDF format (if supported)
+    /// PDF format (no handler is included with wxWidgets)
     Pdf = 5,
 }
 
@@ -55,6 +56,19 @@ impl From<RichTextFileType> for i32 {
     }
 }
 
+/// Paragraph alignment in a RichTextCtrl
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RichTextAlignment {
+    /// Aligned to the left margin
+    Left = 1,
+    /// Centred between the margins
+    Centre = 2,
+    /// Aligned to the right margin
+    Right = 3,
+    /// Stretched to both margins
+    Justified = 4,
+}
+
 /// Events emitted by RichTextCtrl
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RichTextCtrlEvent {
@@ -84,6 +98,8 @@ pub enum RichTextCtrlEvent {
     StyleChanged,
     /// Selection changed
     SelectionChanged,
+    /// A link inserted with `write_url` was clicked
+    UrlClicked,
 }
 
 /// Event data for a RichTextCtrl event
@@ -92,6 +108,8 @@ pub struct RichTextCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(RichTextCtrlEventData => event);
+
 impl RichTextCtrlEventData {
     /// Create a new RichTextCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -117,13 +135,19 @@ impl RichTextCtrlEventData {
     pub fn get_position(&self) -> Option<i32> {
         self.event.get_int()
     }
+
+    /// Get the target of the clicked link for `UrlClicked` events
+    pub fn get_url(&self) -> Option<String> {
+        self.event.get_string()
+    }
 }
 
 /// Represents a wxRichTextCtrl widget.
 ///
 /// RichTextCtrl is a rich text editor that supports formatted text with different fonts,
 /// colors, styles, and other formatting options. It provides a comprehensive set of
-/// editing and formatting capabilities.
+/// editing and formatting capabilities, lists, images and links, and loads and saves
+/// documents as RichText XML or saves them as HTML. Available with the `richtext` feature.
 ///
 /// RichTextCtrl uses `WindowHandle` internally for safe memory management.
 /// When the underlying window is destroyed (by calling `destroy()` or when
@@ -131,18 +155,25 @@ impl RichTextCtrlEventData {
 /// become safe no-ops.
 ///
 /// # Example
-/// ```ignore
-/// let rtc = RichTextCtrl::builder(&frame).build();
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// let editor = RichTextCtrl::builder(&frame)
+///     .with_style(RichTextCtrlStyle::MultiLine)
+///     .build();
+/// editor.write_url("https://www.wxwidgets.org", "wxWidgets");
+/// editor.on_url_clicked(|event| {
+///     println!("Open {}", event.get_url().unwrap_or_default());
+/// });
 ///
-/// // RichTextCtrl is Copy - no clone needed for closures!
-/// rtc.bind_text_changed(move |_| {
-///     // Safe: if rtc was destroyed, this is a no-op
-///     rtc.append_text("Changed!");
+/// // Keep a toolbar toggle in sync with the selection
+/// let bold = ToggleButton::builder(&frame).with_label("B").build();
+/// bold.on_toggle(move |_| {
+///     editor.apply_bold_to_selection();
 /// });
+/// editor.on_selection_changed(move |_| bold.set_value(editor.is_selection_bold()));
 ///
-/// // After parent destruction, rtc operations are safe no-ops
-/// frame.destroy();
-/// assert!(!rtc.is_valid());
+/// editor.save_file("notes.xml", RichTextFileType::Xml);
 /// ```
 #[derive(Clone, Copy)]
 pub struct RichTextCtrl {
@@ -496,7 +527,7 @@ impl RichTextCtrl {
     // --- File Operations ---
 
     /// Loads a file into the control.
-    /// Returns false if the control has been destroyed.
+    /// Returns false if the file can't be read as `file_type` or the control has been destroyed.
     pub fn load_file(&self, filename: &str, file_type: RichTextFileType) -> bool {
         let ptr = self.richtextctrl_ptr();
         if ptr.is_null() {
@@ -507,7 +538,7 @@ impl RichTextCtrl {
     }
 
     /// Saves the content to a file.
-    /// Returns false if the control has been destroyed.
+    /// Returns false if the file can't be written as `file_type` or the control has been destroyed.
     pub fn save_file(&self, filename: &str, file_type: RichTextFileType) -> bool {
         let ptr = self.richtextctrl_ptr();
         if ptr.is_null() {
@@ -653,6 +684,74 @@ impl RichTextCtrl {
         unsafe { ffi::wxd_RichTextCtrl_SetBackgroundColorSelection(ptr, color.into()) }
     }
 
+    // --- Paragraph Operations ---
+
+    /// Aligns the selected paragraphs, or the one holding the caret.
+    /// Returns false if the control has been destroyed.
+    pub fn apply_alignment(&self, alignment: RichTextAlignment) -> bool {
+        let ptr = self.richtextctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_RichTextCtrl_ApplyAlignmentToSelection(ptr, alignment as i32) }
+    }
+
+    /// Returns true if the selected paragraphs, or the one holding the caret, have `alignment`.
+    /// Returns false if the control has been destroyed.
+    pub fn is_selection_aligned(&self, alignment: RichTextAlignment) -> bool {
+        let ptr = self.richtextctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_RichTextCtrl_IsSelectionAligned(ptr, alignment as i32) }
+    }
+
+    /// Turns the selected paragraphs, or the one holding the caret, into a bulleted list,
+    /// or back into plain paragraphs when `bullets` is false.
+    /// Returns false if the control has been destroyed.
+    pub fn apply_bullets_to_selection(&self, bullets: bool) -> bool {
+        let ptr = self.richtextctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_RichTextCtrl_ApplyBulletsToSelection(ptr, bullets) }
+    }
+
+    /// Returns true if the first selected paragraph, or the one holding the caret, is a list item.
+    /// Returns false if the control has been destroyed.
+    pub fn is_selection_bulleted(&self) -> bool {
+        let ptr = self.richtextctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_RichTextCtrl_IsSelectionBulleted(ptr) }
+    }
+
+    // --- Content Insertion ---
+
+    /// Inserts an image at the insertion point. It is stored as PNG.
+    /// Returns false if the bitmap is invalid or the control has been destroyed.
+    pub fn write_image(&self, bitmap: &Bitmap) -> bool {
+        let ptr = self.richtextctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_RichTextCtrl_WriteImage(ptr, bitmap.as_const_ptr()) }
+    }
+
+    /// Inserts a link to `url` showing `text`, or the URL itself if `text` is empty, at the
+    /// insertion point. Clicking it sends a `UrlClicked` event.
+    /// Returns false if the control has been destroyed.
+    pub fn write_url(&self, url: &str, text: &str) -> bool {
+        let ptr = self.richtextctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let c_url = CString::new(url).unwrap_or_default();
+        let c_text = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_RichTextCtrl_WriteUrl(ptr, c_url.as_ptr(), c_text.as_ptr()) }
+    }
+
     /// Returns the underlying WindowHandle for this control.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
@@ -723,7 +822,8 @@ crate::implement_widget_local_event_handlers!(
     ContentInserted => content_inserted, EventType::RICHTEXT_CONTENT_INSERTED,
     ContentDeleted => content_deleted, EventType::RICHTEXT_CONTENT_DELETED,
     StyleChanged => style_changed, EventType::RICHTEXT_STYLE_CHANGED,
-    SelectionChanged => selection_changed, EventType::RICHTEXT_SELECTION_CHANGED
+    SelectionChanged => selection_changed, EventType::RICHTEXT_SELECTION_CHANGED,
+    UrlClicked => url_clicked, EventType::TEXT_URL
 );
 
 // XRC Support - enables RichTextCtrl to be created from XRC-managed pointers
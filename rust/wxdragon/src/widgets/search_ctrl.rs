@@ -1,13 +1,14 @@
-use crate::event::{Event, EventType, TextEvents, WxEvtHandler};
+use crate::event::{Event, EventType, MenuEvents, TextEvents, WxEvtHandler};
 use crate::geometry::{Point, Size};
 use crate::id::Id;
+use crate::menus::Menu;
 use crate::window::{WindowHandle, WxWidget};
 // Window is used by new_from_composition for backwards compatibility
 #[allow(unused_imports)]
 use crate::window::Window;
 use wxdragon_sys as ffi;
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_longlong};
 use std::ptr::null_mut;
 
@@ -25,10 +26,12 @@ widget_style_enum!(
 /// Events emitted by SearchCtrl
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SearchCtrlEvent {
-    /// Emitted when the search button is clicked
-    SearchButtonClicked,
+    /// Emitted when the search button is clicked or Enter is pressed
+    Search,
     /// Emitted when the cancel button is clicked
-    CancelButtonClicked,
+    Cancel,
+    /// Emitted whenever the text changes, also when it is set from code
+    TextChanged,
 }
 
 /// Event data for a SearchCtrl event
@@ -37,6 +40,8 @@ pub struct SearchCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(SearchCtrlEventData => event);
+
 impl SearchCtrlEventData {
     /// Create a new SearchCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -61,7 +66,13 @@ impl SearchCtrlEventData {
 
 // --- SearchCtrl --- //
 
-/// Represents a wxSearchCtrl widget.
+/// Represents a wxSearchCtrl widget, a text field with a magnifier and a cancel button
+/// that looks like the platform's native search field.
+///
+/// Besides its own events it has the text events and accessors of a single-line
+/// `TextCtrl`, see [`TextEvents`] and [`WxTextEntry`](crate::text_entry::WxTextEntry).
+/// Items of the menu set with [`set_menu`](Self::set_menu), e.g. recent searches, are
+/// reported by [`on_menu_selected`](MenuEvents::on_menu_selected).
 ///
 /// SearchCtrl uses `WindowHandle` internally for safe memory management.
 /// When the underlying window is destroyed (by calling `destroy()` or when
@@ -70,14 +81,20 @@ impl SearchCtrlEventData {
 ///
 /// # Example
 /// ```ignore
-/// let search = SearchCtrl::builder(&frame).value("Search...").build();
+/// let search = SearchCtrl::builder(&frame)
+///     .with_descriptive_text("Filter…")
+///     .with_cancel_button(true)
+///     .build();
 ///
 /// // SearchCtrl is Copy - no clone needed for closures!
-/// search.bind_search_button_clicked(move |_| {
+/// search.on_search(move |_| {
 ///     // Safe: if search was destroyed, this is a no-op
 ///     let query = search.get_value();
 ///     println!("Searching for: {}", query);
 /// });
+/// search.on_text_changed(move |event| {
+///     println!("Filtering by: {}", event.get_string().unwrap_or_default());
+/// });
 ///
 /// // After parent destruction, search operations are safe no-ops
 /// frame.destroy();
@@ -186,6 +203,63 @@ impl SearchCtrl {
         String::from_utf8(vec_buffer).unwrap_or_default()
     }
 
+    /// Sets the hint shown in the empty control, e.g. "Search" or "Filter…".
+    /// No-op if the control has been destroyed.
+    pub fn set_descriptive_text(&self, text: &str) {
+        let ptr = self.searchctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_text = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_SearchCtrl_SetDescriptiveText(ptr, c_text.as_ptr()) }
+    }
+
+    /// Gets the hint shown in the empty control.
+    /// Returns empty string if the control has been destroyed.
+    pub fn get_descriptive_text(&self) -> String {
+        let ptr = self.searchctrl_ptr();
+        if ptr.is_null() {
+            return String::new();
+        }
+        let len = unsafe { ffi::wxd_SearchCtrl_GetDescriptiveText(ptr, std::ptr::null_mut(), 0) };
+        if len <= 0 {
+            return String::new();
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_SearchCtrl_GetDescriptiveText(ptr, buf.as_mut_ptr(), buf.len()) };
+        unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().to_string() }
+    }
+
+    /// Attaches a drop-down menu to the search button, e.g. with recent searches, or
+    /// removes it with `None`.
+    ///
+    /// The control takes ownership of the menu and destroys the one set before; build a new
+    /// menu to change the items. Selected items are reported by
+    /// [`on_menu_selected`](MenuEvents::on_menu_selected) on the control.
+    pub fn set_menu(&self, menu: Option<Menu>) {
+        let ptr = self.searchctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let menu_ptr = menu.map_or(null_mut(), Menu::into_raw_mut);
+        unsafe { ffi::wxd_SearchCtrl_SetMenu(ptr, menu_ptr) }
+    }
+
+    /// Returns the menu attached with [`set_menu`](Self::set_menu), still owned by the
+    /// control.
+    pub fn get_menu(&self) -> Option<Menu> {
+        let ptr = self.searchctrl_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let menu_ptr = unsafe { ffi::wxd_SearchCtrl_GetMenu(ptr) };
+        if menu_ptr.is_null() {
+            None
+        } else {
+            Some(Menu::from(menu_ptr as *const ffi::wxd_Menu_t))
+        }
+    }
+
     /// Returns the underlying WindowHandle for this searchctrl.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
@@ -195,6 +269,15 @@ impl SearchCtrl {
 // Implement TextEvents trait for SearchCtrl
 impl TextEvents for SearchCtrl {}
 
+// Items of the search menu are reported as menu events
+impl MenuEvents for SearchCtrl {}
+
+// The text field accessors and auto-completion shared with TextCtrl
+impl crate::text_entry::WxTextEntry for SearchCtrl {}
+
+// Allow checking the value with a validator
+impl crate::validator::WxValidatable for SearchCtrl {}
+
 // Manual WxWidget implementation for SearchCtrl (using WindowHandle)
 impl WxWidget for SearchCtrl {
     fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
@@ -222,7 +305,10 @@ widget_builder!(
     parent_type: &'a dyn WxWidget,
     style_type: SearchCtrlStyle,
     fields: {
-        value: String = String::new()
+        value: String = String::new(),
+        descriptive_text: String = String::new(),
+        search_button: bool = true,
+        cancel_button: bool = false
     },
     build_impl: |slf| {
         let c_value = CString::new(slf.value.as_str()).expect("CString::new failed for value");
@@ -243,9 +329,15 @@ widget_builder!(
             panic!("Failed to create wxSearchCtrl");
         }
 
-        SearchCtrl {
+        let search_ctrl = SearchCtrl {
             handle: WindowHandle::new(raw_ptr as *mut ffi::wxd_Window_t),
+        };
+        if !slf.descriptive_text.is_empty() {
+            search_ctrl.set_descriptive_text(&slf.descriptive_text);
         }
+        search_ctrl.show_search_button(slf.search_button);
+        search_ctrl.show_cancel_button(slf.cancel_button);
+        search_ctrl
     }
 );
 
@@ -254,8 +346,12 @@ crate::implement_widget_local_event_handlers!(
     SearchCtrl,
     SearchCtrlEvent,
     SearchCtrlEventData,
-    SearchButtonClicked => search_button_clicked, EventType::COMMAND_SEARCHCTRL_SEARCH_BTN,
-    CancelButtonClicked => cancel_button_clicked, EventType::COMMAND_SEARCHCTRL_CANCEL_BTN
+    Search => search, EventType::COMMAND_SEARCHCTRL_SEARCH_BTN,
+    Cancel => cancel, EventType::COMMAND_SEARCHCTRL_CANCEL_BTN,
+    TextChanged => text_changed, EventType::TEXT;
+    deprecated:
+    search_button_clicked => search, "renamed to `on_search`",
+    cancel_button_clicked => cancel, "renamed to `on_cancel`"
 );
 
 // XRC Support - enables SearchCtrl to be created from XRC-managed pointers
@@ -23,6 +23,55 @@ widget_style_enum!(
     default_variant: Default
 );
 
+/// Animation used when a [`SimpleBook`] shows or hides a page.
+///
+/// Windows supports all effects; other platforms may switch pages without animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShowEffect {
+    /// No animation.
+    #[default]
+    None,
+    /// Roll the page in or out towards the left.
+    RollToLeft,
+    /// Roll the page in or out towards the right.
+    RollToRight,
+    /// Roll the page in or out towards the top.
+    RollToTop,
+    /// Roll the page in or out towards the bottom.
+    RollToBottom,
+    /// Slide the page in or out towards the left.
+    SlideToLeft,
+    /// Slide the page in or out towards the right.
+    SlideToRight,
+    /// Slide the page in or out towards the top.
+    SlideToTop,
+    /// Slide the page in or out towards the bottom.
+    SlideToBottom,
+    /// Fade the page in or out.
+    Blend,
+    /// Expand the page from its centre, or collapse it towards it.
+    Expand,
+}
+
+impl ShowEffect {
+    pub(crate) fn to_raw(self) -> c_int {
+        let raw = match self {
+            ShowEffect::None => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_NONE,
+            ShowEffect::RollToLeft => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_ROLL_TO_LEFT,
+            ShowEffect::RollToRight => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_ROLL_TO_RIGHT,
+            ShowEffect::RollToTop => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_ROLL_TO_TOP,
+            ShowEffect::RollToBottom => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_ROLL_TO_BOTTOM,
+            ShowEffect::SlideToLeft => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_SLIDE_TO_LEFT,
+            ShowEffect::SlideToRight => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_SLIDE_TO_RIGHT,
+            ShowEffect::SlideToTop => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_SLIDE_TO_TOP,
+            ShowEffect::SlideToBottom => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_SLIDE_TO_BOTTOM,
+            ShowEffect::Blend => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_BLEND,
+            ShowEffect::Expand => ffi::wxd_ShowEffect_WXD_SHOW_EFFECT_EXPAND,
+        };
+        raw as c_int
+    }
+}
+
 /// Represents a wxSimpleBook widget.
 ///
 /// wxSimpleBook is a book control without visual tabs. Pages are switched programmatically,
@@ -134,7 +183,7 @@ impl SimpleBook {
     }
 
     /// Changes the selection to the given page, returning the old selection.
-    /// This function does not generate a `EVT_BOOKCTRL_PAGE_CHANGING` event.
+    /// Unlike `set_selection`, this sends neither `PageChanging` nor `PageChanged` events.
     /// Returns -1 if the simplebook has been destroyed.
     pub fn change_selection(&self, page: usize) -> i32 {
         let ptr = self.simplebook_ptr();
@@ -182,6 +231,28 @@ impl SimpleBook {
         unsafe { ffi::wxd_SimpleBook_RemovePage(ptr, index) }
     }
 
+    /// Animates page switches with `effect`, used both for the page being shown and the one
+    /// being hidden, lasting `timeout_ms` milliseconds; 0 uses the platform's default duration.
+    /// No-op if the simplebook has been destroyed.
+    pub fn set_effect(&self, effect: ShowEffect, timeout_ms: u32) {
+        self.set_effects(effect, effect, timeout_ms);
+    }
+
+    /// Animates page switches with `show_effect` for the page being shown and `hide_effect`
+    /// for the one being hidden, e.g. [`ShowEffect::SlideToLeft`] for both when going forward
+    /// and [`ShowEffect::SlideToRight`] when going back.
+    /// No-op if the simplebook has been destroyed.
+    pub fn set_effects(&self, show_effect: ShowEffect, hide_effect: ShowEffect, timeout_ms: u32) {
+        let ptr = self.simplebook_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe {
+            ffi::wxd_SimpleBook_SetEffects(ptr, show_effect.to_raw(), hide_effect.to_raw());
+            ffi::wxd_SimpleBook_SetEffectTimeout(ptr, timeout_ms);
+        }
+    }
+
     /// Returns the underlying WindowHandle for this simplebook.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
@@ -235,8 +306,11 @@ widget_builder!(
 /// Events that can be emitted by a `SimpleBook`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SimpleBookEvent {
-    /// A simplebook page has been changed.
+    /// A simplebook page has been changed by `set_selection`.
     PageChanged,
+    /// A simplebook page is about to be changed by `set_selection`. This event can be vetoed
+    /// with [`SimpleBookPageChangedEvent::veto`] to stay on the current page.
+    PageChanging,
 }
 
 /// Event data for a `SimpleBook::PageChanged` event.
@@ -246,6 +320,8 @@ pub struct SimpleBookPageChangedEvent {
     pub base: Event,
 }
 
+crate::impl_event_base!(SimpleBookPageChangedEvent => base);
+
 impl SimpleBookPageChangedEvent {
     /// Creates new `SimpleBookPageChangedEvent` from a base `Event`.
     pub fn new(base_event: Event) -> Self {
@@ -273,12 +349,24 @@ impl SimpleBookPageChangedEvent {
         let val = unsafe { ffi::wxd_NotebookEvent_GetOldSelection(self.base.0) };
         if val == ffi::WXD_NOT_FOUND as i32 { None } else { Some(val) }
     }
+
+    /// Vetoes a `PageChanging` event, so the current page stays selected and no
+    /// `PageChanged` event follows.
+    pub fn veto(&self) {
+        self.base.veto();
+    }
+
+    /// Checks if the event has been vetoed
+    pub fn is_vetoed(&self) -> bool {
+        self.base.is_vetoed()
+    }
 }
 
 // Use the implement_widget_local_event_handlers macro for simplebook events
 crate::implement_widget_local_event_handlers!(
     SimpleBook, SimpleBookEvent, SimpleBookPageChangedEvent,
-    PageChanged => page_changed, EventType::NOTEBOOK_PAGE_CHANGED
+    PageChanged => page_changed, EventType::BOOKCTRL_PAGE_CHANGED,
+    PageChanging => page_changing, EventType::BOOKCTRL_PAGE_CHANGING
 );
 
 // XRC Support - enables SimpleBook to be created from XRC-managed pointers
@@ -38,11 +38,16 @@ widget_style_enum!(
 /// let slider = Slider::builder(&frame).min_value(0).max_value(100).build();
 ///
 /// // Slider is Copy - no clone needed for closures!
-/// slider.bind_slider(move |_| {
+/// slider.on_slider(move |_| {
 ///     // Safe: if slider was destroyed, this is a no-op
 ///     let value = slider.value();
 /// });
 ///
+/// // Only recompute once the user lets go of the thumb
+/// slider.on_thumb_release(move |event| {
+///     let value = event.get_position().unwrap_or(0);
+/// });
+///
 /// // After parent destruction, slider operations are safe no-ops
 /// frame.destroy();
 /// assert!(!slider.is_valid());
@@ -137,6 +142,69 @@ impl Slider {
         unsafe { ffi::wxd_Slider_GetValue(ptr) }
     }
 
+    /// Sets the tick mark frequency (the value interval between ticks).
+    /// Only has a visible effect when the slider was created with `SliderStyle::AutoTicks`.
+    /// No-op if the slider has been destroyed.
+    pub fn set_tick_freq(&self, freq: i32) {
+        let ptr = self.slider_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Slider_SetTickFreq(ptr, freq as c_int) }
+    }
+
+    /// Gets the tick mark frequency.
+    /// Returns 0 if the slider has been destroyed.
+    pub fn get_tick_freq(&self) -> i32 {
+        let ptr = self.slider_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_Slider_GetTickFreq(ptr) }
+    }
+
+    /// Sets the page size, i.e. the amount the value changes by when the user
+    /// clicks the track or presses Page Up/Page Down.
+    /// No-op if the slider has been destroyed.
+    pub fn set_page_size(&self, page_size: i32) {
+        let ptr = self.slider_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Slider_SetPageSize(ptr, page_size as c_int) }
+    }
+
+    /// Gets the page size.
+    /// Returns 0 if the slider has been destroyed.
+    pub fn get_page_size(&self) -> i32 {
+        let ptr = self.slider_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_Slider_GetPageSize(ptr) }
+    }
+
+    /// Sets the line size, i.e. the amount the value changes by when the user
+    /// presses an arrow key.
+    /// No-op if the slider has been destroyed.
+    pub fn set_line_size(&self, line_size: i32) {
+        let ptr = self.slider_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Slider_SetLineSize(ptr, line_size as c_int) }
+    }
+
+    /// Gets the line size.
+    /// Returns 0 if the slider has been destroyed.
+    pub fn get_line_size(&self) -> i32 {
+        let ptr = self.slider_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_Slider_GetLineSize(ptr) }
+    }
+
     /// Returns the underlying WindowHandle for this slider.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
@@ -164,6 +232,9 @@ impl WxEvtHandler for Slider {
 // Implement common event traits that all Window-based widgets support
 impl crate::event::WindowEvents for Slider {}
 
+// Sliders emit the wxEVT_SCROLL_* family, e.g. thumb track vs. thumb release
+impl crate::event::ScrollEvents for Slider {}
+
 // Use the widget_builder macro to generate the SliderBuilder implementation
 widget_builder!(
     name: Slider,
@@ -234,6 +305,8 @@ pub struct SliderEvent {
     pub base: CommandEventData,
 }
 
+crate::impl_event_base!(SliderEvent => base.event);
+
 impl SliderEvent {
     /// Creates a new [`SliderEvent`] from a generic [`Event`].
     pub fn new(event: Event) -> Self {
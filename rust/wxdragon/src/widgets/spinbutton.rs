@@ -37,6 +37,8 @@ pub struct SpinButtonEventData {
     event: Event,
 }
 
+crate::impl_event_base!(SpinButtonEventData => event);
+
 impl SpinButtonEventData {
     /// Create a new SpinButtonEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -153,6 +153,8 @@ pub struct SpinCtrlEventData {
     pub base: CommandEventData,
 }
 
+crate::impl_event_base!(SpinCtrlEventData => base.event);
+
 impl SpinCtrlEventData {
     /// Creates a new `SpinCtrlEventData`.
     pub fn new(event: Event) -> Self {
@@ -200,6 +202,9 @@ impl WxEvtHandler for SpinCtrl {
 // Implement common event traits that all Window-based widgets support
 impl crate::event::WindowEvents for SpinCtrl {}
 
+// Allow checking the value with a validator
+impl crate::validator::WxValidatable for SpinCtrl {}
+
 // Use the widget_builder macro to generate the SpinCtrlBuilder implementation
 widget_builder!(
     name: SpinCtrl,
@@ -25,7 +25,8 @@ widget_style_enum!(
 /// Events emitted by SpinCtrlDouble
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SpinCtrlDoubleEvent {
-    /// Emitted when the value is changed
+    /// Emitted when the value is changed with the arrows or keys, or when typed text is
+    /// committed by pressing Enter or leaving the control
     ValueChanged,
     /// Emitted when the user presses Enter
     Enter,
@@ -37,6 +38,8 @@ pub struct SpinCtrlDoubleEventData {
     event: Event,
 }
 
+crate::impl_event_base!(SpinCtrlDoubleEventData => event);
+
 impl SpinCtrlDoubleEventData {
     /// Create a new SpinCtrlDoubleEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -47,11 +50,33 @@ impl SpinCtrlDoubleEventData {
     pub fn get_id(&self) -> i32 {
         self.event.get_id()
     }
+
+    /// Gets the new value of a `ValueChanged` event.
+    /// Returns 0.0 for other events.
+    pub fn get_value(&self) -> f64 {
+        unsafe { ffi::wxd_SpinDoubleEvent_GetValue(self.event._as_ptr()) }
+    }
 }
 
 // --- SpinCtrlDouble --- //
 
-/// Represents a wxSpinCtrlDouble.
+/// Represents a wxSpinCtrlDouble, a spin control for floating point values.
+///
+/// Typed text is taken when Enter is pressed or the control loses focus. It may use the
+/// locale's decimal separator or `.`, and a value outside the range is clamped into it.
+/// Unless set with `with_digits`, the number of decimal places shown follows the increment.
+///
+/// # Example
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// # let frame = Frame::builder().build();
+/// let opacity = SpinCtrlDouble::builder(&frame)
+///     .with_range(0.0, 1.0)
+///     .with_initial_value(0.75)
+///     .with_increment(0.05)
+///     .build();
+/// opacity.on_value_changed(|event| println!("Opacity: {}", event.get_value()));
+/// ```
 ///
 /// SpinCtrlDouble uses `WindowHandle` internally for safe memory management.
 /// When the underlying window is destroyed (by calling `destroy()` or when
@@ -215,6 +240,9 @@ impl WxEvtHandler for SpinCtrlDouble {
 // Implement common event traits that all Window-based widgets support
 impl crate::event::WindowEvents for SpinCtrlDouble {}
 
+// Allow checking the value with a validator
+impl crate::validator::WxValidatable for SpinCtrlDouble {}
+
 // Use the widget_builder macro to generate the SpinCtrlDoubleBuilder implementation
 widget_builder!(
     name: SpinCtrlDouble,
@@ -225,15 +253,20 @@ widget_builder!(
         min_value: f64 = 0.0,
         max_value: f64 = 100.0,
         initial_value: f64 = 0.0,
-        increment: f64 = 1.0
+        increment: f64 = 1.0,
+        digits: u32 = 0
     },
     build_impl: |slf| {
-       let initial_value = slf.initial_value.clamp(slf.min_value, slf.max_value);
-       let value_str =  match slf.value_str.parse::<f64>() {
-            Err(_) => initial_value.to_string(),
-            Ok(v) => v.clamp(slf.min_value, slf.max_value).to_string(),
-        };
-        let c_value_str = CString::new(value_str).expect("CString::new failed for value_str");
+        // A value given as text takes precedence; either way it starts within the range
+        let initial_value = slf
+            .value_str
+            .trim()
+            .parse::<f64>()
+            .unwrap_or(slf.initial_value)
+            .clamp(slf.min_value, slf.max_value);
+        let digits = if slf.digits > 0 { slf.digits } else { decimal_places(slf.increment) };
+        // The value is passed as a number, the text is formatted for the locale from it
+        let c_value_str = CString::new("").unwrap();
         let raw_ptr = unsafe {
             ffi::wxd_SpinCtrlDouble_Create(
                 slf.parent.handle_ptr(),
@@ -253,10 +286,19 @@ widget_builder!(
         if raw_ptr.is_null() {
             panic!("Failed to create wxSpinCtrlDouble");
         }
-        unsafe { SpinCtrlDouble::from_ptr(raw_ptr) }
+        let spin_ctrl = unsafe { SpinCtrlDouble::from_ptr(raw_ptr) };
+        spin_ctrl.set_digits(digits);
+        spin_ctrl.set_value(initial_value);
+        spin_ctrl
     }
 );
 
+/// Number of decimal places needed to show multiples of `increment`, e.g. 2 for 0.05.
+fn decimal_places(increment: f64) -> u32 {
+    let text = increment.abs().to_string();
+    text.split_once('.').map_or(0, |(_, fraction)| fraction.len().min(20) as u32)
+}
+
 // Extension to SpinCtrlBuilder to add specialized methods
 impl<'a> SpinCtrlDoubleBuilder<'a> {
     /// Sets the allowed range.
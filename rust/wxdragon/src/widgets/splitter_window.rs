@@ -65,6 +65,8 @@ pub struct SplitterEventData {
     event: Event,
 }
 
+crate::impl_event_base!(SplitterEventData => event);
+
 impl SplitterEventData {
     /// Create a new SplitterEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -2,7 +2,7 @@
 //! Safe wrapper for wxStatusBar.
 
 use crate::event::WxEvtHandler;
-use crate::geometry::{Point, Size};
+use crate::geometry::{Point, Rect, Size};
 use crate::id::Id;
 use crate::widgets::frame::Frame; // Parent must be a Frame
 use crate::window::{WindowHandle, WxWidget};
@@ -27,6 +27,34 @@ widget_style_enum!(
     default_variant: Default
 );
 
+/// Appearance of a status bar field, see [`StatusBar::set_status_styles`].
+///
+/// How the styles look depends on the platform; some show all fields alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusBarFieldStyle {
+    /// The platform's usual field border.
+    #[default]
+    Normal,
+    /// No border.
+    Flat,
+    /// A raised 3D border.
+    Raised,
+    /// A sunken 3D border.
+    Sunken,
+}
+
+impl StatusBarFieldStyle {
+    fn to_raw(self) -> c_int {
+        let raw = match self {
+            StatusBarFieldStyle::Normal => ffi::wxd_StatusBarFieldStyle_WXD_SB_NORMAL,
+            StatusBarFieldStyle::Flat => ffi::wxd_StatusBarFieldStyle_WXD_SB_FLAT,
+            StatusBarFieldStyle::Raised => ffi::wxd_StatusBarFieldStyle_WXD_SB_RAISED,
+            StatusBarFieldStyle::Sunken => ffi::wxd_StatusBarFieldStyle_WXD_SB_SUNKEN,
+        };
+        raw as c_int
+    }
+}
+
 /// Represents a wxStatusBar attached to a Frame.
 ///
 /// StatusBar uses `WindowHandle` internally for safe memory management.
@@ -91,6 +119,8 @@ impl StatusBar {
     }
 
     /// Sets the number of fields in the status bar.
+    /// Widgets set with [`set_field_widget`](Self::set_field_widget) for removed fields are hidden
+    /// and no longer follow the status bar.
     /// No-op if the status bar has been destroyed.
     pub fn set_fields_count(&self, count: usize) {
         let ptr = self.statusbar_ptr();
@@ -133,7 +163,8 @@ impl StatusBar {
         }
     }
 
-    /// Pushes text onto the stack for a field. Reverts on PopStatusText.
+    /// Shows `text` in a field, saving the current text on the field's stack to be restored
+    /// by [`pop_status_text`](Self::pop_status_text).
     /// No-op if the status bar has been destroyed.
     pub fn push_status_text(&self, text: &str, field_index: usize) {
         let ptr = self.statusbar_ptr();
@@ -144,8 +175,8 @@ impl StatusBar {
         unsafe { ffi::wxd_StatusBar_PushStatusText(ptr, c_text.as_ptr(), field_index as c_int) };
     }
 
-    /// Pops the last pushed text from the stack for a field.
-    /// No-op if the status bar has been destroyed.
+    /// Restores the text of a field saved by the last [`push_status_text`](Self::push_status_text).
+    /// No-op if no text was pushed for the field or the status bar has been destroyed.
     pub fn pop_status_text(&self, field_index: usize) {
         let ptr = self.statusbar_ptr();
         if ptr.is_null() {
@@ -153,6 +184,78 @@ impl StatusBar {
         }
         unsafe { ffi::wxd_StatusBar_PopStatusText(ptr, field_index as c_int) };
     }
+
+    /// Gets the rectangle of a field, relative to the status bar.
+    /// Returns `None` if there is no such field or the status bar has been destroyed.
+    pub fn get_field_rect(&self, field_index: usize) -> Option<Rect> {
+        let ptr = self.statusbar_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let mut rect = ffi::wxd_Rect {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        };
+        let found = unsafe { ffi::wxd_StatusBar_GetFieldRect(ptr, field_index as c_int, &mut rect) };
+        found.then(|| Rect::from(rect))
+    }
+
+    /// Sets the style of each field, starting with the first one.
+    /// No-op if the status bar has been destroyed.
+    pub fn set_status_styles(&self, styles: &[StatusBarFieldStyle]) {
+        let ptr = self.statusbar_ptr();
+        if ptr.is_null() || styles.is_empty() {
+            return;
+        }
+        let raw: Vec<c_int> = styles.iter().map(|style| style.to_raw()).collect();
+        unsafe { ffi::wxd_StatusBar_SetStatusStyles(ptr, raw.len() as c_int, raw.as_ptr()) };
+    }
+
+    /// Sets the minimal height of the status bar, e.g. to make room for a widget in a field.
+    /// No-op if the status bar has been destroyed.
+    pub fn set_minimum_height(&self, height: i32) {
+        let ptr = self.statusbar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_StatusBar_SetMinHeight(ptr, height as c_int) };
+    }
+
+    /// Makes `widget` cover a field, following it whenever the status bar is resized or its
+    /// fields change.
+    ///
+    /// The widget must be created with the status bar as its parent. It replaces a widget set
+    /// for the field before, which stays where it is. The field's text is hidden behind the widget.
+    ///
+    /// Returns false if there is no such field, the widget isn't a child of the status bar
+    /// or the status bar has been destroyed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let gauge = Gauge::builder(&status_bar).with_range(100).build();
+    /// status_bar.set_field_widget(2, &gauge);
+    /// ```
+    pub fn set_field_widget<W: WxWidget>(&self, field_index: usize, widget: &W) -> bool {
+        let ptr = self.statusbar_ptr();
+        let widget_ptr = widget.handle_ptr();
+        if ptr.is_null() || widget_ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_StatusBar_SetFieldWidget(ptr, field_index as c_int, widget_ptr) }
+    }
+
+    /// Stops moving the widget set for a field with [`set_field_widget`](Self::set_field_widget).
+    /// The widget itself is left as it is.
+    /// No-op if the status bar has been destroyed.
+    pub fn clear_field_widget(&self, field_index: usize) {
+        let ptr = self.statusbar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_StatusBar_SetFieldWidget(ptr, field_index as c_int, std::ptr::null_mut()) };
+    }
 }
 
 // Manual WxWidget implementation for StatusBar (using WindowHandle)
@@ -353,6 +353,8 @@ pub struct StyledTextCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(StyledTextCtrlEventData => event);
+
 impl StyledTextCtrlEventData {
     /// Create a new StyledTextCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -46,6 +46,8 @@ pub enum TextCtrlEvent {
     TextChanged,
     /// Emitted when the user presses Enter in the control
     TextEnter,
+    /// Emitted when the caret moves or the selection changes
+    CaretOrSelectionChanged,
 }
 
 /// Event data for a TextCtrl event
@@ -54,6 +56,8 @@ pub struct TextCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(TextCtrlEventData => event);
+
 impl TextCtrlEventData {
     /// Create a new TextCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -74,10 +78,22 @@ impl TextCtrlEventData {
     pub fn get_string(&self) -> Option<String> {
         self.event.get_string()
     }
+
+    /// Get the new insertion point for `CaretOrSelectionChanged` events
+    pub fn get_insertion_point(&self) -> Option<i64> {
+        self.event.get_int().map(i64::from)
+    }
 }
 
 /// Represents a wxTextCtrl widget.
 ///
+/// Besides the usual text events, `on_caret_or_selection_changed` reports when the caret
+/// moves or the selection changes, e.g. to update a formatting toolbar. wxWidgets has no
+/// such event, so it is derived by comparing the caret and selection after key and mouse
+/// input, text changes and idle time, and sent only when they differ. While an input
+/// method is composing text the notification may be delayed until the text is committed,
+/// and programmatic changes are only noticed once idle events reach the control.
+///
 /// TextCtrl uses `WindowHandle` internally for safe memory management.
 /// When the underlying window is destroyed (by calling `destroy()` or when
 /// its parent is destroyed), the handle becomes invalid and all operations
@@ -178,9 +194,24 @@ impl TextCtrl {
         String::from_utf8_lossy(byte_slice).to_string()
     }
 
-    /// Sets the text value of the control.
+    /// Sets the text value of the control and marks it as unmodified.
+    ///
+    /// No text changed event is sent, so handlers only see edits made by the user; use
+    /// [`set_value_with_event`](Self::set_value_with_event) if they should run too.
     /// No-op if the control has been destroyed.
     pub fn set_value(&self, value: &str) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_value = CString::new(value).unwrap_or_default();
+        unsafe { ffi::wxd_TextCtrl_ChangeValue(ptr, c_value.as_ptr()) };
+    }
+
+    /// Sets the text value of the control and marks it as unmodified, sending a text changed
+    /// event like a user edit would.
+    /// No-op if the control has been destroyed.
+    pub fn set_value_with_event(&self, value: &str) {
         let ptr = self.textctrl_ptr();
         if ptr.is_null() {
             return;
@@ -199,7 +230,19 @@ impl TextCtrl {
         unsafe { Self::read_string_with_retry(|buf, len| ffi::wxd_TextCtrl_GetValue(ptr, buf, len)) }
     }
 
-    /// Appends text to the end of the control.
+    /// Gets the current value as a [`SecretString`](crate::utils::SecretString),
+    /// for password fields. The value is zeroed when dropped and never shown by `Debug`.
+    /// Returns an empty secret if the control has been destroyed.
+    pub fn get_secret_value(&self) -> crate::utils::SecretString {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return crate::utils::SecretString::default();
+        }
+        crate::utils::SecretString::read_with(|buf, len| unsafe { ffi::wxd_TextCtrl_GetValue(ptr, buf, len as i32) })
+    }
+
+    /// Appends text to the end of the control, in the
+    /// [default style](Self::set_default_style) for rich controls.
     /// No-op if the control has been destroyed.
     pub fn append_text(&self, text: &str) {
         let ptr = self.textctrl_ptr();
@@ -233,7 +276,8 @@ impl TextCtrl {
         unsafe { ffi::wxd_TextCtrl_WriteText(ptr, c_text.as_ptr()) };
     }
 
-    /// Sets the value of the control without generating a TextChanged event.
+    /// Sets the value of the control without generating a TextChanged event, the same as
+    /// [`set_value`](Self::set_value).
     /// No-op if the control has been destroyed.
     pub fn change_value(&self, value: &str) {
         let ptr = self.textctrl_ptr();
@@ -265,8 +309,26 @@ impl TextCtrl {
         unsafe { ffi::wxd_TextCtrl_Replace(ptr, from, to, c_value.as_ptr()) };
     }
 
-    /// Converts given text position to client coordinates in pixels.
-    /// Returns (x, y) if successful, or None if the position is invalid or the control is destroyed.
+    /// Converts a text position to its column and line, both counted from 0.
+    ///
+    /// Together with [`xy_to_position`](Self::xy_to_position) this maps between positions,
+    /// as used by the selection and insertion point, and places in the lines returned by
+    /// [`get_line_text`](Self::get_line_text), e.g. to go to a line:
+    ///
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # let frame = Frame::builder().build();
+    /// # let editor = TextCtrl::builder(&frame).with_style(TextCtrlStyle::MultiLine).build();
+    /// # let line = 42;
+    /// let position = editor.xy_to_position(0, line);
+    /// if position >= 0 {
+    ///     editor.set_insertion_point(position);
+    ///     editor.show_position(position);
+    /// }
+    /// ```
+    ///
+    /// Returns (column, line) if successful, or None if the position is invalid or the
+    /// control is destroyed.
     pub fn position_to_xy(&self, pos: i64) -> Option<(i64, i64)> {
         let ptr = self.textctrl_ptr();
         if ptr.is_null() {
@@ -278,8 +340,23 @@ impl TextCtrl {
         if result { Some((x, y)) } else { None }
     }
 
-    /// Converts given client coordinates in pixels to text position.
-    /// Returns 0 if the coordinates are invalid or the control is destroyed.
+    /// Returns the text position closest to `point`, in client coordinates, e.g. to find
+    /// what a right click was on.
+    ///
+    /// Returns None if the platform can't map points to positions or the control is
+    /// destroyed.
+    pub fn hit_test(&self, point: Point) -> Option<i64> {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let mut pos: i64 = 0;
+        let found = unsafe { ffi::wxd_TextCtrl_HitTest(ptr, point.into(), &mut pos) };
+        if found { Some(pos) } else { None }
+    }
+
+    /// Converts a column and line, both counted from 0, to a text position.
+    /// Returns -1 if there is no such place or the control is destroyed.
     pub fn xy_to_position(&self, x: i64, y: i64) -> i64 {
         let ptr = self.textctrl_ptr();
         if ptr.is_null() {
@@ -290,22 +367,22 @@ impl TextCtrl {
 
     /// Returns the number of lines in the text control.
     /// Returns 0 if the control has been destroyed.
-    pub fn get_number_of_lines(&self) -> i32 {
+    pub fn get_number_of_lines(&self) -> i64 {
         let ptr = self.textctrl_ptr();
         if ptr.is_null() {
             return 0;
         }
-        unsafe { ffi::wxd_TextCtrl_GetNumberOfLines(ptr) }
+        unsafe { ffi::wxd_TextCtrl_GetNumberOfLines(ptr) as i64 }
     }
 
     /// Returns the length of the specified line (not including the trailing newline character).
-    /// Returns 0 if the line number is invalid or the control is destroyed.
-    pub fn get_line_length(&self, line_no: i64) -> i32 {
+    /// Returns -1 if the line number is invalid or the control is destroyed.
+    pub fn get_line_length(&self, line_no: i64) -> i64 {
         let ptr = self.textctrl_ptr();
         if ptr.is_null() {
-            return 0;
+            return -1;
         }
-        unsafe { ffi::wxd_TextCtrl_GetLineLength(ptr, line_no) }
+        unsafe { ffi::wxd_TextCtrl_GetLineLength(ptr, line_no) as i64 }
     }
 
     /// Returns the contents of the given line.
@@ -318,8 +395,8 @@ impl TextCtrl {
         unsafe { Self::read_string_with_retry(|buf, len| ffi::wxd_TextCtrl_GetLineText(ptr, line_no, buf, len)) }
     }
 
-    /// Returns whether the text control has been modified by the user since the last
-    /// time MarkDirty() or DiscardEdits() was called.
+    /// Returns whether the text has been edited since it was set, loaded or saved, or since
+    /// the flag was last changed with [`set_modified`](Self::set_modified).
     /// Returns false if the control has been destroyed.
     pub fn is_modified(&self) -> bool {
         let ptr = self.textctrl_ptr();
@@ -339,6 +416,124 @@ impl TextCtrl {
         unsafe { ffi::wxd_TextCtrl_SetModified(ptr, modified) };
     }
 
+    /// Marks the control as modified, e.g. after changing its text programmatically.
+    /// No-op if the control has been destroyed.
+    pub fn mark_dirty(&self) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_TextCtrl_MarkDirty(ptr) };
+    }
+
+    /// Marks the control as unmodified.
+    /// No-op if the control has been destroyed.
+    pub fn discard_edits(&self) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_TextCtrl_DiscardEdits(ptr) };
+    }
+
+    // --- File Operations ---
+
+    /// Replaces the text with the contents of the file at `path` and marks the control as
+    /// unmodified.
+    /// Returns false if the file can't be read or the control has been destroyed.
+    pub fn load_file(&self, path: &str) -> bool {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let c_path = CString::new(path).unwrap_or_default();
+        unsafe { ffi::wxd_TextCtrl_LoadFile(ptr, c_path.as_ptr()) }
+    }
+
+    /// Writes the text to the file at `path` and, if that succeeds, marks the control as
+    /// unmodified.
+    /// Returns false if the file can't be written or the control has been destroyed.
+    pub fn save_file(&self, path: &str) -> bool {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let c_path = CString::new(path).unwrap_or_default();
+        unsafe { ffi::wxd_TextCtrl_SaveFile(ptr, c_path.as_ptr()) }
+    }
+
+    // --- Editing Operations ---
+
+    /// Returns true if the last edit can be undone.
+    /// Returns false if the control has been destroyed.
+    pub fn can_undo(&self) -> bool {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_TextCtrl_CanUndo(ptr) }
+    }
+
+    /// Undoes the last edit.
+    /// No-op if the control has been destroyed.
+    pub fn undo(&self) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_TextCtrl_Undo(ptr) };
+    }
+
+    /// Returns true if the last undone edit can be redone.
+    /// Returns false if the control has been destroyed.
+    pub fn can_redo(&self) -> bool {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_TextCtrl_CanRedo(ptr) }
+    }
+
+    /// Redoes the last undone edit.
+    /// No-op if the control has been destroyed.
+    pub fn redo(&self) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_TextCtrl_Redo(ptr) };
+    }
+
+    /// Moves the selected text to the clipboard.
+    /// No-op if the control has been destroyed.
+    pub fn cut(&self) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_TextCtrl_Cut(ptr) };
+    }
+
+    /// Copies the selected text to the clipboard.
+    /// No-op if the control has been destroyed.
+    pub fn copy(&self) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_TextCtrl_Copy(ptr) };
+    }
+
+    /// Inserts the text on the clipboard, replacing the selection.
+    /// No-op if the control has been destroyed.
+    pub fn paste(&self) {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_TextCtrl_Paste(ptr) };
+    }
+
     /// Makes the text control editable or read-only, overriding the style setting.
     /// No-op if the control has been destroyed.
     pub fn set_editable(&self, editable: bool) {
@@ -382,8 +577,22 @@ impl TextCtrl {
 
     /// Sets the maximum number of characters that may be entered in the control.
     ///
+    /// Typing or pasting past the limit fires
+    /// [`on_max_length_reached`](crate::event::TextEvents::on_max_length_reached); a
+    /// pasted string longer than the remaining room is truncated to fit on every
+    /// platform. Text set from code isn't limited.
+    ///
     /// If `len` is 0, the maximum length limit is removed.
     /// No-op if the control has been destroyed.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # let frame = Frame::builder().build();
+    /// let port = TextCtrl::builder(&frame).build();
+    /// port.set_max_length(5);
+    /// port.on_max_length_reached(|_| wxdragon::utils::bell());
+    /// ```
     pub fn set_max_length(&self, len: usize) {
         let ptr = self.textctrl_ptr();
         if ptr.is_null() {
@@ -487,6 +696,9 @@ impl TextCtrl {
     }
 
     /// Sets the style for the given text range.
+    ///
+    /// Styling ranges needs a rich control, see [`TextCtrlBuilder::with_rich_text`]; a plain
+    /// multi-line control may ignore it.
     /// No-op if the control has been destroyed.
     pub fn set_style(&self, start: i64, end: i64, style: &TextAttr) {
         let ptr = self.textctrl_ptr();
@@ -496,6 +708,20 @@ impl TextCtrl {
         unsafe { ffi::wxd_TextCtrl_SetStyle(ptr, start, end, style.as_ptr()) };
     }
 
+    /// Returns the style of the character at `position`.
+    /// Returns None if the control can't report styles or has been destroyed.
+    pub fn get_style(&self, position: i64) -> Option<TextAttr> {
+        let ptr = self.textctrl_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let attr_ptr = unsafe { ffi::wxd_TextCtrl_GetStyle(ptr, position) };
+        if attr_ptr.is_null() {
+            return None;
+        }
+        Some(TextAttr { ptr: attr_ptr })
+    }
+
     /// Returns the default style currently used for new text.
     /// Returns None if the control has been destroyed.
     pub fn get_default_style(&self) -> Option<TextAttr> {
@@ -510,7 +736,10 @@ impl TextCtrl {
         Some(TextAttr { ptr: attr_ptr })
     }
 
-    /// Sets the default style used for new text.
+    /// Sets the default style used for new text, e.g. by [`append_text`](Self::append_text).
+    ///
+    /// Attributes not set in `style` keep their previous default; pass `TextAttr::new()` to
+    /// go back to the control's own font and colours.
     /// No-op if the control has been destroyed.
     pub fn set_default_style(&self, style: &TextAttr) {
         let ptr = self.textctrl_ptr();
@@ -760,6 +989,12 @@ impl crate::event::WindowEvents for TextCtrl {}
 // Implement scrolling functionality for TextCtrl (useful for multiline text)
 impl crate::scrollable::WxScrollable for TextCtrl {}
 
+// Implement auto-completion for the single-line text field
+impl crate::text_entry::WxTextEntry for TextCtrl {}
+
+// Allow checking the value with a validator
+impl crate::validator::WxValidatable for TextCtrl {}
+
 // Use the widget_builder macro for TextCtrl
 widget_builder!(
     name: TextCtrl,
@@ -780,19 +1015,33 @@ widget_builder!(
     }
 );
 
+impl<'a> TextCtrlBuilder<'a> {
+    /// Requests a multi-line control that supports per-range styles, as needed by
+    /// [`TextCtrl::set_style`] and [`TextCtrl::set_default_style`].
+    ///
+    /// This adds to the style flags, so call it after [`with_style`](Self::with_style).
+    pub fn with_rich_text(mut self) -> Self {
+        self.style |= TextCtrlStyle::MultiLine | TextCtrlStyle::Rich2;
+        self
+    }
+}
+
 // Implement TextCtrl-specific event handlers using the standard macro
 crate::implement_widget_local_event_handlers!(
     TextCtrl,
     TextCtrlEvent,
     TextCtrlEventData,
     TextChanged => text_changed, EventType::TEXT,
-    TextEnter => text_enter, EventType::TEXT_ENTER
+    TextEnter => text_enter, EventType::TEXT_ENTER,
+    CaretOrSelectionChanged => caret_or_selection_changed, EventType::TEXT_CARET_OR_SELECTION_CHANGED
 );
 
 // XRC Support - enables TextCtrl to be created from XRC-managed pointers
 #[cfg(feature = "xrc")]
 impl crate::xrc::XrcSupport for TextCtrl {
     unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        // Controls not made by the builder report caret moves once wrapped
+        unsafe { ffi::wxd_TextCtrl_TrackCaret(ptr as *mut ffi::wxd_TextCtrl_t) };
         TextCtrl {
             handle: WindowHandle::new(ptr),
         }
@@ -806,6 +1055,7 @@ impl crate::window::FromWindowWithClassName for TextCtrl {
     }
 
     unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        unsafe { ffi::wxd_TextCtrl_TrackCaret(ptr as *mut ffi::wxd_TextCtrl_t) };
         TextCtrl {
             handle: WindowHandle::new(ptr),
         }
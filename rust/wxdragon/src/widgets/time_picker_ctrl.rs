@@ -34,6 +34,8 @@ pub struct TimePickerEventData {
     event: Event,
 }
 
+crate::impl_event_base!(TimePickerEventData => event);
+
 impl TimePickerEventData {
     /// Create a new TimePickerEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -1,11 +1,12 @@
-//! Safe wrapper for individual toolbar tools loaded from XRC.
+//! Safe wrapper for individual toolbar tools.
 
 use crate::event::{Event, EventType, WxEvtHandler};
 use crate::id::Id;
 use crate::window::{Window, WindowHandle, WxWidget};
 use wxdragon_sys as ffi;
 
-/// Represents an individual toolbar tool loaded from XRC.
+/// Represents an individual toolbar tool, loaded from XRC or found with
+/// [`ToolBar::find_tool_by_id`](crate::widgets::ToolBar::find_tool_by_id).
 ///
 /// In wxWidgets, tools are not standalone widgets but are managed by their parent toolbar.
 /// This wrapper provides a convenient way to access XRC-defined tools and bind events to them.
@@ -22,8 +23,7 @@ pub struct Tool {
 
 impl Tool {
     /// Creates a Tool wrapper from a toolbar and tool information.
-    /// This is typically called by the XRC loading system.
-    #[cfg(feature = "xrc")]
+    /// This is called by the XRC loading system and `ToolBar::find_tool_by_id`.
     pub(crate) fn new(toolbar_handle: WindowHandle, tool_id: Id) -> Self {
         Self { toolbar_handle, tool_id }
     }
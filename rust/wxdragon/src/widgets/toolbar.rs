@@ -1,11 +1,11 @@
 //!
 //! Safe wrapper for wxToolBar.
 
-use crate::bitmap::Bitmap;
-use crate::bitmap_bundle::BitmapBundle;
+use crate::bitmap_bundle::{BitmapBundle, BitmapBundleArg};
 use crate::event::{Event, EventType, WxEvtHandler};
+use crate::geometry::{Point, Size};
 use crate::id::Id;
-use crate::menus::ItemKind; // Reuse ItemKind for tool types
+use crate::menus::{ItemKind, Menu}; // Reuse ItemKind for tool types
 use crate::window::{WindowHandle, WxWidget};
 use std::ffi::CString;
 use std::os::raw::c_int;
@@ -19,10 +19,13 @@ widget_style_enum!(
         Default: ffi::WXD_TB_HORIZONTAL, "Default style, horizontal toolbar.",
         Vertical: ffi::WXD_TB_VERTICAL, "Vertical toolbar.",
         Text: ffi::WXD_TB_TEXT, "Show text labels for tools.",
+        HorzLayout: ffi::WXD_TB_HORZ_LAYOUT, "Show the text labels beside the icons instead of below them; only has an effect together with `Text`.",
+        HorzText: ffi::WXD_TB_HORZ_LAYOUT | ffi::WXD_TB_TEXT, "Show text labels beside the icons.",
         NoIcons: ffi::WXD_TB_NOICONS, "Show text only, no icons.",
         NoDivider: ffi::WXD_TB_NODIVIDER, "No divider between tool groups.",
         Flat: ffi::WXD_TB_FLAT, "Flat toolbar look.",
-        Dockable: ffi::WXD_TB_DOCKABLE, "Toolbar can be dragged and docked."
+        Dockable: ffi::WXD_TB_DOCKABLE, "Toolbar can be dragged and docked.",
+        NoAlign: ffi::WXD_TB_NOALIGN, "Don't align the toolbar with the frame window (MSW only)."
     },
     default_variant: Default
 );
@@ -31,8 +34,8 @@ widget_style_enum!(
 pub struct ToolConfig<'a> {
     pub tool_id: Id,
     pub label: &'a str,
-    pub bitmap: &'a Bitmap,
-    pub bitmap_disabled: Option<&'a Bitmap>,
+    pub bitmap: BitmapBundleArg<'a>,
+    pub bitmap_disabled: Option<BitmapBundleArg<'a>>,
     pub kind: ItemKind,
     pub short_help: &'a str,
     pub long_help: &'a str,
@@ -43,6 +46,13 @@ pub struct ToolConfig<'a> {
 pub enum ToolBarEvent {
     /// Menu event (tool clicked)
     Menu,
+    /// A tool was clicked. This is the same event as `Menu`; it then goes on to the
+    /// frame's `on_menu` handlers unless the handler calls `skip(false)`.
+    ToolClicked,
+    /// The dropdown arrow of a tool added with [`ToolBar::add_dropdown_tool`] was clicked.
+    /// The menu set with [`ToolBar::set_dropdown_menu`] is still shown afterwards unless the
+    /// handler calls `skip(false)`, e.g. to pop up a menu of its own instead.
+    ToolDropdown,
 }
 
 /// Event data for a ToolBar event
@@ -51,6 +61,8 @@ pub struct ToolBarEventData {
     event: Event,
 }
 
+crate::impl_event_base!(ToolBarEventData => event);
+
 impl ToolBarEventData {
     /// Create a new ToolBarEventData from a generic Event
     pub fn new(event: Event) -> Self {
@@ -76,6 +88,39 @@ impl ToolBarEventData {
     pub fn is_checked(&self) -> Option<bool> {
         self.event.is_checked()
     }
+
+    /// Returns true if the dropdown arrow of the tool was clicked rather than the tool itself.
+    pub fn is_dropdown_clicked(&self) -> bool {
+        self.event.get_event_type() == Some(EventType::TOOL_DROPDOWN)
+    }
+}
+
+/// A tool taken off a toolbar with [`ToolBar::remove_tool`].
+///
+/// It keeps its bitmaps, state and dropdown menu, and its control with the control's event
+/// handlers, so swapping tool sets doesn't create anything anew. Put it back with
+/// [`ToolBar::insert_removed_tool`]; dropping it destroys the tool. Meanwhile the toolbar
+/// owns it, so it is also destroyed along with the toolbar.
+pub struct RemovedTool {
+    toolbar: WindowHandle,
+    tool_id: Id,
+    ptr: *mut ffi::wxd_ToolBarTool_t,
+}
+
+impl RemovedTool {
+    /// Gets the tool's ID.
+    pub fn get_tool_id(&self) -> Id {
+        self.tool_id
+    }
+}
+
+impl Drop for RemovedTool {
+    fn drop(&mut self) {
+        // A destroyed toolbar has deleted the tool already
+        if let Some(toolbar) = self.toolbar.get_ptr() {
+            unsafe { ffi::wxd_ToolBar_DestroyRemovedTool(toolbar as *mut ffi::wxd_ToolBar_t, self.ptr) };
+        }
+    }
 }
 
 /// Represents a wxToolBar control.
@@ -85,7 +130,14 @@ impl ToolBarEventData {
 /// its parent is destroyed), the handle becomes invalid and all operations
 /// become safe no-ops.
 ///
-/// Toolbars generate `EventType::MENU` events on their parent window when a tool is clicked.
+/// Toolbars generate `EventType::MENU` events on their parent window when a tool is clicked,
+/// so a frame handles its tools and menu items with the same `on_menu` handler when they
+/// share ids. Use [`Frame::create_tool_bar`](crate::widgets::Frame::create_tool_bar) for
+/// the frame's toolbar, or [`ToolBar::builder`] for one placed in a sizer.
+///
+/// Tools can be inserted, removed and deleted at any time, e.g. to switch between tool sets
+/// for different modes; call [`realize`](ToolBar::realize) after changing them. Unlike
+/// `AuiToolBar`, a `ToolBar` has no overflow menu for tools that don't fit.
 #[derive(Clone, Copy)]
 pub struct ToolBar {
     /// Safe handle to the underlying wxToolBar - automatically invalidated on destroy
@@ -93,6 +145,11 @@ pub struct ToolBar {
 }
 
 impl ToolBar {
+    /// Creates a new ToolBar builder.
+    pub fn builder(parent: &dyn WxWidget) -> ToolBarBuilder<'_> {
+        ToolBarBuilder::new(parent)
+    }
+
     /// Creates a `ToolBar` wrapper from a raw pointer.
     /// # Safety
     /// The pointer must be a valid `wxd_ToolBar_t` pointer.
@@ -121,6 +178,12 @@ impl ToolBar {
     /// Returns true if the tool was added successfully (C++ returns non-null ptr).
     /// No-op (returns false) if the toolbar has been destroyed.
     fn add_tool_raw(&self, config: ToolConfig) -> bool {
+        let kind = config.kind as c_int;
+        self.add_tool_of_kind(config, kind)
+    }
+
+    // Adds a tool of a kind `ItemKind` may not have, like dropdown tools
+    fn add_tool_of_kind(&self, config: ToolConfig, kind: c_int) -> bool {
         let ptr = self.toolbar_ptr();
         if ptr.is_null() {
             return false;
@@ -129,16 +192,16 @@ impl ToolBar {
         let c_label = CString::new(config.label).unwrap_or_default();
         let c_short_help = CString::new(config.short_help).unwrap_or_default();
         let c_longlong_help = CString::new(config.long_help).unwrap_or_default();
-        let bmp_disabled_ptr = config.bitmap_disabled.map_or(std::ptr::null(), |bmp| bmp.as_const_ptr());
+        let bmp_disabled_ptr = config.bitmap_disabled.as_ref().map_or(std::ptr::null(), |b| b.as_ptr());
 
         unsafe {
             let tool_ptr = ffi::wxd_ToolBar_AddTool(
                 ptr,
                 config.tool_id,
                 c_label.as_ptr(),
-                config.bitmap.as_const_ptr(),
+                config.bitmap.as_ptr(),
                 bmp_disabled_ptr,
-                config.kind as c_int,
+                kind,
                 c_short_help.as_ptr(),
                 c_longlong_help.as_ptr(),
             );
@@ -151,13 +214,14 @@ impl ToolBar {
     /// # Arguments
     /// * `tool_id` - ID for the tool, used in event handling.
     /// * `label` - Label shown if `TB_TEXT` style is used.
-    /// * `bitmap` - The bitmap for the tool's normal state.
+    /// * `bitmap` - The image for the tool's normal state: a [`BitmapBundle`] for crisp
+    ///   icons at any DPI, or a single `Bitmap`.
     /// * `short_help` - Short help string (tooltip).
-    pub fn add_tool(&self, tool_id: Id, label: &str, bitmap: &Bitmap, short_help: &str) -> bool {
+    pub fn add_tool<'a>(&self, tool_id: Id, label: &str, bitmap: impl Into<BitmapBundleArg<'a>>, short_help: &str) -> bool {
         self.add_tool_raw(ToolConfig {
             tool_id,
             label,
-            bitmap,
+            bitmap: bitmap.into(),
             bitmap_disabled: None,
             kind: ItemKind::Normal,
             short_help,
@@ -166,11 +230,11 @@ impl ToolBar {
     }
 
     /// Adds a check tool (toggle tool) to the toolbar.
-    pub fn add_check_tool(&self, tool_id: Id, label: &str, bitmap: &Bitmap, short_help: &str) -> bool {
+    pub fn add_check_tool<'a>(&self, tool_id: Id, label: &str, bitmap: impl Into<BitmapBundleArg<'a>>, short_help: &str) -> bool {
         self.add_tool_raw(ToolConfig {
             tool_id,
             label,
-            bitmap,
+            bitmap: bitmap.into(),
             bitmap_disabled: None,
             kind: ItemKind::Check,
             short_help,
@@ -180,11 +244,11 @@ impl ToolBar {
 
     /// Adds a radio tool to the toolbar.
     /// Radio tools require grouping with separators or other radio tools.
-    pub fn add_radio_tool(&self, tool_id: Id, label: &str, bitmap: &Bitmap, short_help: &str) -> bool {
+    pub fn add_radio_tool<'a>(&self, tool_id: Id, label: &str, bitmap: impl Into<BitmapBundleArg<'a>>, short_help: &str) -> bool {
         self.add_tool_raw(ToolConfig {
             tool_id,
             label,
-            bitmap,
+            bitmap: bitmap.into(),
             bitmap_disabled: None,
             kind: ItemKind::Radio,
             short_help,
@@ -192,6 +256,175 @@ impl ToolBar {
         })
     }
 
+    /// Adds a tool with a dropdown arrow next to it, like a "Run" button offering run
+    /// configurations.
+    ///
+    /// Clicking the tool itself sends the normal tool click event; clicking the arrow calls the
+    /// [`on_tool_dropdown`](Self::on_tool_dropdown) handlers and shows the menu set with
+    /// [`set_dropdown_menu`](Self::set_dropdown_menu).
+    pub fn add_dropdown_tool<'a>(
+        &self,
+        tool_id: Id,
+        label: &str,
+        bitmap: impl Into<BitmapBundleArg<'a>>,
+        short_help: &str,
+    ) -> bool {
+        let config = ToolConfig {
+            tool_id,
+            label,
+            bitmap: bitmap.into(),
+            bitmap_disabled: None,
+            kind: ItemKind::Normal,
+            short_help,
+            long_help: "",
+        };
+        self.add_tool_of_kind(config, ffi::WXDItemKindCEnum_WXD_ITEM_DROPDOWN as c_int)
+    }
+
+    /// Sets the menu shown by the dropdown arrow of a tool added with
+    /// [`add_dropdown_tool`](Self::add_dropdown_tool), replacing the one set before.
+    ///
+    /// The toolbar takes ownership of the menu, so handlers bound to it with `on_selected`
+    /// live as long as the toolbar. Its items' clicks also reach the frame's `on_menu`
+    /// handlers. Returns false, dropping the menu, if the tool isn't a dropdown tool or if the
+    /// toolbar has been destroyed. Also returns false for a menu that isn't owned, such as one
+    /// borrowed from a menu bar, since the toolbar can't take it over.
+    pub fn set_dropdown_menu(&self, tool_id: Id, menu: Menu) -> bool {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() || !menu.is_owned() || menu.as_const_ptr().is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_ToolBar_SetDropdownMenu(ptr, tool_id, menu.into_raw_mut()) }
+    }
+
+    /// Inserts a tool at `pos`, which may be the number of tools to append it.
+    ///
+    /// `kind` is `ItemKind::Normal`, `Check` or `Radio`. Returns false if `pos` is out of
+    /// bounds or if the toolbar has been destroyed.
+    pub fn insert_tool<'a>(
+        &self,
+        pos: usize,
+        tool_id: Id,
+        label: &str,
+        bitmap: impl Into<BitmapBundleArg<'a>>,
+        short_help: &str,
+        kind: ItemKind,
+    ) -> bool {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let bitmap = bitmap.into();
+        let c_label = CString::new(label).unwrap_or_default();
+        let c_short_help = CString::new(short_help).unwrap_or_default();
+        unsafe {
+            ffi::wxd_ToolBar_InsertTool(
+                ptr,
+                pos,
+                tool_id,
+                c_label.as_ptr(),
+                bitmap.as_ptr(),
+                std::ptr::null(),
+                kind as c_int,
+                c_short_help.as_ptr(),
+                std::ptr::null(),
+            )
+        }
+    }
+
+    /// Inserts a separator at `pos`.
+    /// Returns false if `pos` is out of bounds or if the toolbar has been destroyed.
+    pub fn insert_separator(&self, pos: usize) -> bool {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_ToolBar_InsertSeparator(ptr, pos) }
+    }
+
+    /// Removes a tool without destroying it, so it can be inserted again with
+    /// [`insert_removed_tool`](Self::insert_removed_tool).
+    /// Returns `None` if there is no such tool or if the toolbar has been destroyed.
+    pub fn remove_tool(&self, tool_id: Id) -> Option<RemovedTool> {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let tool_ptr = unsafe { ffi::wxd_ToolBar_RemoveTool(ptr, tool_id) };
+        if tool_ptr.is_null() {
+            return None;
+        }
+        Some(RemovedTool {
+            toolbar: self.handle,
+            tool_id,
+            ptr: tool_ptr,
+        })
+    }
+
+    /// Inserts a tool removed from this toolbar at `pos` again.
+    ///
+    /// Gives the tool back if `pos` is out of bounds, it was removed from another toolbar or
+    /// if the toolbar has been destroyed.
+    pub fn insert_removed_tool(&self, pos: usize, tool: RemovedTool) -> Result<(), RemovedTool> {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() || unsafe { !ffi::wxd_ToolBar_InsertRemovedTool(ptr, pos, tool.ptr) } {
+            return Err(tool);
+        }
+        // The toolbar owns the tool again
+        std::mem::forget(tool);
+        Ok(())
+    }
+
+    /// Deletes a tool, destroying its control if it has one.
+    /// Returns false if there is no such tool or if the toolbar has been destroyed.
+    pub fn delete_tool(&self, tool_id: Id) -> bool {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_ToolBar_DeleteTool(ptr, tool_id) }
+    }
+
+    /// Deletes the tool or separator at `pos`, destroying its control if it has one.
+    /// Returns false if `pos` is out of bounds or if the toolbar has been destroyed.
+    pub fn delete_tool_by_pos(&self, pos: usize) -> bool {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_ToolBar_DeleteToolByPos(ptr, pos) }
+    }
+
+    /// Gets the number of tools, separators and controls.
+    /// Returns 0 if the toolbar has been destroyed.
+    pub fn get_tools_count(&self) -> usize {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_ToolBar_GetToolsCount(ptr) }
+    }
+
+    /// Returns the tool with the given ID.
+    /// Returns `None` if there is no such tool or if the toolbar has been destroyed.
+    pub fn find_tool_by_id(&self, tool_id: Id) -> Option<crate::widgets::Tool> {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() || unsafe { !ffi::wxd_ToolBar_HasTool(ptr, tool_id) } {
+            return None;
+        }
+        Some(crate::widgets::Tool::new(self.handle, tool_id))
+    }
+
+    /// Gets the position of the tool with the given ID.
+    /// Returns `None` if there is no such tool or if the toolbar has been destroyed.
+    pub fn get_tool_pos(&self, tool_id: Id) -> Option<usize> {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        usize::try_from(unsafe { ffi::wxd_ToolBar_GetToolPos(ptr, tool_id) }).ok()
+    }
+
     /// Adds a separator.
     /// No-op if the toolbar has been destroyed.
     pub fn add_separator(&self) {
@@ -204,6 +437,17 @@ impl ToolBar {
         }
     }
 
+    /// Adds a space that takes up what is left of the toolbar, pushing the tools added after it
+    /// to its end.
+    /// No-op if the toolbar has been destroyed.
+    pub fn add_stretchable_space(&self) {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ToolBar_AddStretchableSpace(ptr) };
+    }
+
     /// Adds an arbitrary control (like a `Choice` or `TextCtrl`) to the toolbar.
     /// The control should have the toolbar as its parent.
     /// No-op if the toolbar has been destroyed.
@@ -217,7 +461,8 @@ impl ToolBar {
         }
     }
 
-    /// Must be called after adding tools to finalize the toolbar layout.
+    /// Must be called after adding, inserting, removing or deleting tools to update the
+    /// toolbar layout; it can be called any number of times.
     /// Returns true if successful, false if toolbar has been destroyed.
     pub fn realize(&self) -> bool {
         let ptr = self.toolbar_ptr();
@@ -282,6 +527,27 @@ impl ToolBar {
         unsafe { ffi::wxd_ToolBar_SetToolShortHelp(ptr, tool_id, c_help.as_ptr()) };
     }
 
+    /// Sets the size of the tool bitmaps in DIPs. Call it before adding tools; bitmaps of
+    /// another size are scaled.
+    /// No-op if the toolbar has been destroyed.
+    pub fn set_tool_bitmap_size(&self, size: Size) {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_ToolBar_SetToolBitmapSize(ptr, size.into()) };
+    }
+
+    /// Gets the size of the tool bitmaps.
+    /// Returns default Size if the toolbar has been destroyed.
+    pub fn get_tool_bitmap_size(&self) -> Size {
+        let ptr = self.toolbar_ptr();
+        if ptr.is_null() {
+            return Size::default();
+        }
+        Size::from(unsafe { ffi::wxd_ToolBar_GetToolBitmapSize(ptr) })
+    }
+
     /// Adds a normal tool to the toolbar using a BitmapBundle instead of a Bitmap.
     /// This is preferred for high-DPI support.
     ///
@@ -292,6 +558,7 @@ impl ToolBar {
     /// * `short_help` - Short help string (tooltip).
     ///
     /// Returns false if the toolbar has been destroyed.
+    #[deprecated(note = "`add_tool` accepts a `BitmapBundle` directly")]
     pub fn add_tool_bundle(&self, tool_id: Id, label: &str, bundle: &BitmapBundle, short_help: &str) -> bool {
         let ptr = self.toolbar_ptr();
         if ptr.is_null() {
@@ -410,7 +677,32 @@ crate::implement_widget_local_event_handlers!(
     ToolBar,
     ToolBarEvent,
     ToolBarEventData,
-    Menu => menu, EventType::MENU
+    Menu => menu, EventType::MENU,
+    ToolClicked => tool_clicked, EventType::MENU,
+    ToolDropdown => tool_dropdown, EventType::TOOL_DROPDOWN
+);
+
+// Use the widget_builder macro to generate the ToolBarBuilder implementation
+widget_builder!(
+    name: ToolBar,
+    parent_type: &'a dyn WxWidget,
+    style_type: ToolBarStyle,
+    fields: {},
+    build_impl: |slf| {
+        let toolbar_ptr = unsafe {
+            ffi::wxd_ToolBar_Create(
+                slf.parent.handle_ptr(),
+                slf.id,
+                slf.pos.into(),
+                slf.size.into(),
+                slf.style.bits() as ffi::wxd_Style_t,
+            )
+        };
+        if toolbar_ptr.is_null() {
+            panic!("Failed to create ToolBar");
+        }
+        unsafe { ToolBar::from_ptr(toolbar_ptr) }
+    }
 );
 
 // XRC Support - enables ToolBar to be created from XRC-managed pointers
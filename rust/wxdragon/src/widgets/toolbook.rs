@@ -0,0 +1,247 @@
+//!
+//! Safe wrapper for wxToolbook.
+
+use crate::event::WxEvtHandler;
+use crate::geometry::{Point, Size};
+use crate::id::Id;
+use crate::widgets::imagelist::ImageList;
+use crate::window::{Window, WindowHandle, WxWidget};
+use std::ffi::{CStr, CString};
+use wxdragon_sys as ffi;
+
+// --- Toolbook Styles ---
+widget_style_enum!(
+    name: ToolbookStyle,
+    doc: "Style flags for Toolbook widget.",
+    variants: {
+        Default: ffi::WXD_BK_DEFAULT, "Default style.",
+        Top: ffi::WXD_BK_TOP, "Place the toolbar at the top.",
+        Bottom: ffi::WXD_BK_BOTTOM, "Place the toolbar at the bottom.",
+        Left: ffi::WXD_BK_LEFT, "Place the toolbar at the left.",
+        Right: ffi::WXD_BK_RIGHT, "Place the toolbar at the right.",
+        ButtonBar: ffi::WXD_TBK_BUTTONBAR, "Use a button bar instead of a toolbar (macOS only).",
+        HorzLayout: ffi::WXD_TBK_HORZ_LAYOUT, "Show the tool labels beside the images rather than below them."
+    },
+    default_variant: Default
+);
+
+/// Represents a wxToolbook control.
+///
+/// A book control that selects pages with a toolbar.
+///
+/// Every page needs an image: set an image list with [`set_image_list`](Toolbook::set_image_list)
+/// before adding pages, and pass a valid `image_id` to [`add_page`](Toolbook::add_page).
+///
+/// Page change events are not wrapped yet; use [`get_selection`](Toolbook::get_selection) to
+/// query the current page.
+///
+/// Toolbook uses `WindowHandle` internally for safe memory management.
+/// When the underlying window is destroyed (by calling `destroy()` or when
+/// its parent is destroyed), the handle becomes invalid and all operations
+/// become safe no-ops.
+#[derive(Clone, Copy)]
+pub struct Toolbook {
+    /// Safe handle to the underlying wxToolbook - automatically invalidated on destroy
+    handle: WindowHandle,
+}
+
+impl Toolbook {
+    /// Creates a new Toolbook builder.
+    pub fn builder(parent: &dyn WxWidget) -> ToolbookBuilder<'_> {
+        ToolbookBuilder::new(parent)
+    }
+
+    /// Helper to get raw window pointer, returns null if widget has been destroyed
+    #[inline]
+    fn book_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    /// Internal implementation used by the builder.
+    fn new_impl(parent_ptr: *mut ffi::wxd_Window_t, id: Id, pos: Point, size: Size, style: i64) -> Self {
+        assert!(!parent_ptr.is_null(), "Toolbook parent cannot be null");
+
+        let ptr = unsafe {
+            ffi::wxd_Toolbook_new(
+                parent_ptr,
+                id,
+                pos.x,
+                pos.y,
+                size.width,
+                size.height,
+                style as ffi::wxd_Style_t,
+            )
+        };
+
+        if ptr.is_null() {
+            panic!("Failed to create wxToolbook");
+        }
+
+        Toolbook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+
+    /// Adds a new page to the toolbook.
+    /// `image_id` is an index into the image list, or -1 for no image.
+    /// Returns `false` if the page could not be added or the toolbook has been destroyed.
+    pub fn add_page<W: WxWidget>(&self, page: &W, text: &str, select: bool, image_id: i32) -> bool {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let text_c = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_BookCtrl_AddPage(ptr, page.handle_ptr(), text_c.as_ptr(), select, image_id) }
+    }
+
+    /// Gets the number of pages in the toolbook.
+    /// Returns 0 if the toolbook has been destroyed.
+    pub fn get_page_count(&self) -> usize {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_BookCtrl_GetPageCount(ptr) as usize }
+    }
+
+    /// Returns the page at the given index, or `None` if the index is out of range.
+    pub fn get_page(&self, index: usize) -> Option<Window> {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let page_ptr = unsafe { ffi::wxd_BookCtrl_GetPage(ptr, index) };
+        if page_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Window::from_ptr(page_ptr) })
+        }
+    }
+
+    /// Gets the currently selected page, or -1 if none is selected.
+    /// Returns -1 if the toolbook has been destroyed.
+    pub fn get_selection(&self) -> i32 {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_BookCtrl_GetSelection(ptr) }
+    }
+
+    /// Sets the selection to the given page index.
+    /// Returns the index of the previously selected page, or -1 on failure.
+    pub fn set_selection(&self, n: usize) -> i32 {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_BookCtrl_SetSelection(ptr, n) }
+    }
+
+    /// Sets the text for the given page.
+    /// No-op if the toolbook has been destroyed.
+    pub fn set_page_text(&self, n: usize, text: &str) {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let text_c = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_BookCtrl_SetPageText(ptr, n, text_c.as_ptr()) };
+    }
+
+    /// Gets the text for the given page.
+    /// Returns an empty string if the index is out of range or the toolbook has been destroyed.
+    pub fn get_page_text(&self, n: usize) -> String {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return String::new();
+        }
+        let len = unsafe { ffi::wxd_BookCtrl_GetPageText(ptr, n, std::ptr::null_mut(), 0) };
+        if len <= 0 {
+            return String::new();
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_BookCtrl_GetPageText(ptr, n, buf.as_mut_ptr(), buf.len()) };
+        unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() }
+    }
+
+    /// Sets the image list used for the page images.
+    /// The toolbook takes ownership of the image list.
+    /// No-op if the toolbook has been destroyed.
+    pub fn set_image_list(&self, image_list: ImageList) {
+        let ptr = self.book_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_BookCtrl_AssignImageList(ptr, image_list.as_ptr()) };
+        // The toolbook now owns the ImageList, so we forget it in Rust
+        // to prevent a double free.
+        std::mem::forget(image_list);
+    }
+
+    /// Returns the underlying WindowHandle for this toolbook.
+    pub fn window_handle(&self) -> WindowHandle {
+        self.handle
+    }
+}
+
+// Manual WxWidget implementation for Toolbook (using WindowHandle)
+impl WxWidget for Toolbook {
+    fn handle_ptr(&self) -> *mut ffi::wxd_Window_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.handle.is_valid()
+    }
+}
+
+// Implement WxEvtHandler for event binding
+impl WxEvtHandler for Toolbook {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        self.handle.get_ptr().unwrap_or(std::ptr::null_mut()) as *mut ffi::wxd_EvtHandler_t
+    }
+}
+
+// Implement common event traits that all Window-based widgets support
+impl crate::event::WindowEvents for Toolbook {}
+
+// Use the widget_builder macro for Toolbook
+widget_builder!(
+    name: Toolbook,
+    parent_type: &'a dyn WxWidget,
+    style_type: ToolbookStyle,
+    fields: {},
+    build_impl: |slf| {
+        Toolbook::new_impl(
+            slf.parent.handle_ptr(),
+            slf.id,
+            slf.pos,
+            slf.size,
+            slf.style.bits()
+        )
+    }
+);
+
+// XRC Support - enables Toolbook to be created from XRC-managed pointers
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for Toolbook {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        Toolbook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
+
+// Enable widget casting for Toolbook
+impl crate::window::FromWindowWithClassName for Toolbook {
+    fn class_name() -> &'static str {
+        "wxToolbook"
+    }
+
+    unsafe fn from_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        Toolbook {
+            handle: WindowHandle::new(ptr),
+        }
+    }
+}
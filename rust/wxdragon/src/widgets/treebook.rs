@@ -4,7 +4,8 @@
 use crate::event::{Event, EventType, WxEvtHandler};
 use crate::geometry::{Point, Size};
 use crate::id::Id;
-use crate::window::{WindowHandle, WxWidget};
+use crate::widgets::imagelist::ImageList;
+use crate::window::{Window, WindowHandle, WxWidget};
 use std::ffi::CString;
 use wxdragon_sys as ffi;
 
@@ -27,7 +28,8 @@ widget_style_enum!(
 pub enum TreebookEvent {
     /// Emitted when the selected page changes
     PageChanged,
-    /// Emitted when the selected page is about to change
+    /// Emitted when the selected page is about to change. Can be vetoed with
+    /// [`TreebookEventData::veto`].
     PageChanging,
     /// Emitted when a tree node is expanded
     NodeExpanded,
@@ -41,6 +43,8 @@ pub struct TreebookEventData {
     base: Event,
 }
 
+crate::impl_event_base!(TreebookEventData => base);
+
 impl TreebookEventData {
     /// Create a new NotebookEventData with the provided Event
     pub fn new(event: Event) -> Self {
@@ -76,6 +80,17 @@ impl TreebookEventData {
         let val = unsafe { ffi::wxd_NotebookEvent_GetOldSelection(self.base.0) };
         if val == ffi::WXD_NOT_FOUND as i32 { None } else { Some(val) }
     }
+
+    /// Vetoes a `PageChanging` event, so the old page stays selected in the tree and
+    /// no `PageChanged` event follows.
+    pub fn veto(&self) {
+        self.base.veto();
+    }
+
+    /// Checks if the event has been vetoed
+    pub fn is_vetoed(&self) -> bool {
+        self.base.is_vetoed()
+    }
 }
 
 /// Represents a wxTreebook control.
@@ -176,6 +191,87 @@ impl Treebook {
         unsafe { ffi::wxd_Treebook_AddSubPage(ptr, page_ptr, text_c.as_ptr(), select as i32, image_id) }
     }
 
+    /// Inserts a new page before the page at `pos`, at the same level; `pos` equal to the
+    /// page count adds a top-level page at the end. `image_id` is -1 for no image.
+    /// Returns false if `pos` is out of bounds or if the treebook has been destroyed.
+    pub fn insert_page<W: WxWidget>(&self, pos: usize, page: &W, text: &str, select: bool, image_id: i32) -> bool {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let text_c = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_Treebook_InsertPage(ptr, pos, page.handle_ptr(), text_c.as_ptr(), select as i32, image_id) != 0 }
+    }
+
+    /// Inserts a new page as the last sub-page of the page at `pos`. `image_id` is -1 for
+    /// no image.
+    /// Returns false if `pos` is out of bounds or if the treebook has been destroyed.
+    pub fn insert_sub_page<W: WxWidget>(&self, pos: usize, page: &W, text: &str, select: bool, image_id: i32) -> bool {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let text_c = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_Treebook_InsertSubPage(ptr, pos, page.handle_ptr(), text_c.as_ptr(), select as i32, image_id) != 0 }
+    }
+
+    /// Deletes the page at `pos` together with its sub-pages, destroying their windows.
+    /// Returns false if `pos` is out of bounds or if the treebook has been destroyed.
+    pub fn delete_page(&self, pos: usize) -> bool {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Treebook_DeletePage(ptr, pos) }
+    }
+
+    /// Returns the window of the page at `pos`.
+    /// Returns `None` if `pos` is out of bounds or if the treebook has been destroyed.
+    pub fn get_page(&self, pos: usize) -> Option<Window> {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let page_ptr = unsafe { ffi::wxd_Treebook_GetPage(ptr, pos) };
+        if page_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Window::from_ptr(page_ptr) })
+        }
+    }
+
+    /// Returns the index of the page the page at `pos` is a sub-page of.
+    /// Returns `None` for top-level pages, if `pos` is out of bounds or if the treebook has
+    /// been destroyed.
+    pub fn get_page_parent(&self, pos: usize) -> Option<usize> {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        usize::try_from(unsafe { ffi::wxd_Treebook_GetPageParent(ptr, pos) }).ok()
+    }
+
+    /// Expands or collapses the sub-pages of the page at `pos` in the tree.
+    /// Returns the previous state, or false if `pos` is out of bounds or if the treebook
+    /// has been destroyed.
+    pub fn expand_node(&self, pos: usize, expand: bool) -> bool {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Treebook_ExpandNode(ptr, pos, expand) }
+    }
+
+    /// Returns true if the sub-pages of the page at `pos` are shown in the tree.
+    /// Returns false if the treebook has been destroyed.
+    pub fn is_node_expanded(&self, pos: usize) -> bool {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Treebook_IsNodeExpanded(ptr, pos) }
+    }
+
     /// Gets the number of pages in the treebook.
     /// Returns 0 if the treebook has been destroyed.
     pub fn get_page_count(&self) -> i32 {
@@ -207,6 +303,57 @@ impl Treebook {
         unsafe { ffi::wxd_Treebook_SetSelection(ptr, n) }
     }
 
+    /// Changes the selection to the given page, returning the old selection.
+    /// Unlike `set_selection`, this sends neither `PageChanging` nor `PageChanged` events.
+    /// Returns -1 if the treebook has been destroyed.
+    pub fn change_selection(&self, n: usize) -> i32 {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return -1;
+        }
+        unsafe { ffi::wxd_Treebook_ChangeSelection(ptr, n) }
+    }
+
+    /// Sets the images of the pages, referred to by the `image_id`s.
+    /// The treebook takes ownership of the image list.
+    /// No-op if the treebook has been destroyed.
+    pub fn set_image_list(&self, image_list: ImageList) {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_Treebook_AssignImageList(ptr, image_list.as_ptr()) };
+        // The treebook deletes the list, so it must not be freed in Rust too
+        std::mem::forget(image_list);
+    }
+
+    /// Sets the image of the given page to `image_id` in the image list, -1 for none.
+    /// Returns false if the index is out of bounds or if the treebook has been destroyed.
+    pub fn set_page_image(&self, n: usize, image_id: i32) -> bool {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Treebook_SetPageImage(ptr, n, image_id) }
+    }
+
+    /// Returns the tree showing the pages, e.g. to give it a minimum width with
+    /// `set_min_size` and re-layout the treebook. Its items belong to the treebook and must
+    /// only be changed through it, so it is exposed as a plain `Window`.
+    /// Returns `None` if the treebook has been destroyed.
+    pub fn get_tree_ctrl(&self) -> Option<Window> {
+        let ptr = self.treebook_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let tree_ptr = unsafe { ffi::wxd_Treebook_GetTreeCtrl(ptr) };
+        if tree_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Window::from_ptr(tree_ptr) })
+        }
+    }
+
     /// Sets the text for the given page.
     /// No-op if the treebook has been destroyed.
     pub fn set_page_text(&self, n: usize, text: &str) {
@@ -163,6 +163,8 @@ pub struct TreeListCtrlEventData {
     event: Event,
 }
 
+crate::impl_event_base!(TreeListCtrlEventData => event);
+
 impl TreeListCtrlEventData {
     /// Create a new TreeListCtrlEventData from a generic Event
     pub fn new(event: Event) -> Self {
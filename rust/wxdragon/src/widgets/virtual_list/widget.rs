@@ -1912,7 +1912,7 @@ custom_widget!(
                 // LineUp: small negative delta
                 let apply = apply_vertical_delta.clone();
                 let state_for_step = config.state.clone();
-                vscrollbar.on_scroll_lineup(move |_e| {
+                vscrollbar.on_line_up(move |_e| {
                     let step = { let b = state_for_step.borrow(); (b.viewport_size.height / 10).max(b.internal_params.estimated_item_height).max(1) };
                     apply(-step);
                 });
@@ -1922,7 +1922,7 @@ custom_widget!(
                 // LineDown: small positive delta
                 let apply = apply_vertical_delta.clone();
                 let state_for_step = config.state.clone();
-                vscrollbar.on_scroll_linedown(move |_e| {
+                vscrollbar.on_line_down(move |_e| {
                     let step = { let b = state_for_step.borrow(); (b.viewport_size.height / 10).max(b.internal_params.estimated_item_height).max(1) };
                     apply(step);
                 });
@@ -1932,7 +1932,7 @@ custom_widget!(
                 // PageUp: negative viewport height
                 let apply = apply_vertical_delta.clone();
                 let state_for_step = config.state.clone();
-                vscrollbar.on_scroll_pageup(move |_e| {
+                vscrollbar.on_page_up(move |_e| {
                     let page = { let b = state_for_step.borrow(); b.viewport_size.height.max(1) };
                     apply(-page);
                 });
@@ -1942,7 +1942,7 @@ custom_widget!(
                 // PageDown: positive viewport height
                 let apply = apply_vertical_delta.clone();
                 let state_for_step = config.state.clone();
-                vscrollbar.on_scroll_pagedown(move |_e| {
+                vscrollbar.on_page_down(move |_e| {
                     let page = { let b = state_for_step.borrow(); b.viewport_size.height.max(1) };
                     apply(page);
                 });
@@ -2173,26 +2173,26 @@ custom_widget!(
             // Use relative deltas for lineup/linedown/pageup/pagedown
             let handler_lineup = apply_horizontal_delta.clone();
             let state_for_lineup = state_hscroll_click.clone();
-            hscrollbar.on_scroll_lineup(move |_| {
+            hscrollbar.on_line_up(move |_| {
                 // Move left by one "line" (estimated item width)
                 let estimated = { state_for_lineup.borrow().internal_params.estimated_item_width.max(1) };
                 handler_lineup(-estimated);
             });
             let handler_linedown = apply_horizontal_delta.clone();
             let state_for_linedown = state_hscroll_click.clone();
-            hscrollbar.on_scroll_linedown(move |_| {
+            hscrollbar.on_line_down(move |_| {
                 let estimated = { state_for_linedown.borrow().internal_params.estimated_item_width.max(1) };
                 handler_linedown(estimated);
             });
             let handler_pageup = apply_horizontal_delta.clone();
             let state_for_pageup = state_hscroll_click.clone();
-            hscrollbar.on_scroll_pageup(move |_| {
+            hscrollbar.on_page_up(move |_| {
                 let viewport_w = { state_for_pageup.borrow().viewport_size.width.max(1) };
                 handler_pageup(-viewport_w);
             });
             let handler_pagedown = apply_horizontal_delta.clone();
             let state_for_pagedown = state_hscroll_click.clone();
-            hscrollbar.on_scroll_pagedown(move |_| {
+            hscrollbar.on_page_down(move |_| {
                 let viewport_w = { state_for_pagedown.borrow().viewport_size.width.max(1) };
                 handler_pagedown(viewport_w);
             });
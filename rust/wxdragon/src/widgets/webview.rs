@@ -1,5 +1,6 @@
 //! Safe wrapper for wxWebView.
 
+use crate::bitmap::Bitmap;
 use crate::event::WxEvtHandler;
 use crate::geometry::{Point, Size};
 use crate::id::Id;
@@ -7,8 +8,11 @@ use crate::window::{WindowHandle, WxWidget};
 // Window is used by new_from_composition for backwards compatibility
 #[allow(unused_imports)]
 use crate::window::Window;
-use std::ffi::CString;
+use std::cell::Cell;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 use wxdragon_sys as ffi;
 
 // WebView Zoom Types
@@ -97,6 +101,75 @@ pub enum WebViewNavigationError {
     Other = 7,
 }
 
+impl WebViewNavigationError {
+    /// Converts the error code carried by a webview error event.
+    pub fn from_i32(val: i32) -> Option<Self> {
+        match val {
+            0 => Some(Self::Connection),
+            1 => Some(Self::Certificate),
+            2 => Some(Self::Auth),
+            3 => Some(Self::Security),
+            4 => Some(Self::NotFound),
+            5 => Some(Self::Request),
+            6 => Some(Self::UserCancelled),
+            7 => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+/// A cookie stored with [`WebView::set_cookie`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebViewCookie {
+    pub name: String,
+    pub value: String,
+    /// Domain the cookie is sent to, e.g. `"example.com"`.
+    pub domain: String,
+    /// Path below which the cookie is sent. Defaults to `"/"`.
+    pub path: String,
+    /// When the cookie expires; `None` for a session cookie.
+    pub expires: Option<SystemTime>,
+    /// Hides the cookie from scripts.
+    pub http_only: bool,
+    /// Only sends the cookie over HTTPS.
+    pub secure: bool,
+}
+
+impl WebViewCookie {
+    /// A session cookie for the whole of `domain`.
+    pub fn new(name: &str, value: &str, domain: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+            domain: domain.to_string(),
+            path: "/".to_string(),
+            expires: None,
+            http_only: false,
+            secure: false,
+        }
+    }
+}
+
+/// State of a download reported by the WebView download events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebViewDownloadState {
+    InProgress,
+    Completed,
+    Interrupted,
+}
+
+impl WebViewDownloadState {
+    /// Converts the state carried by a webview download event.
+    pub fn from_i32(val: i32) -> Option<Self> {
+        match val {
+            0 => Some(Self::InProgress),
+            1 => Some(Self::Completed),
+            2 => Some(Self::Interrupted),
+            _ => None,
+        }
+    }
+}
+
 // WebView Browsing Data Types
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -222,6 +295,7 @@ impl WebView {
         style: i64,
         name: Option<&str>,
         backend: Option<&str>,
+        handlers: &[WebViewSchemeHandler],
     ) -> Self {
         assert!(!parent_ptr.is_null(), "WebView requires a parent");
         let c_url = url.map(|s| CString::new(s).unwrap_or_default());
@@ -233,6 +307,14 @@ impl WebView {
         let name_ptr = c_name.as_ref().map(|c| c.as_ptr()).unwrap_or(std::ptr::null());
         let backend_ptr = c_backend.as_ref().map(|c| c.as_ptr()).unwrap_or(std::ptr::null());
 
+        // The schemes must outlive the descriptors; C++ takes ownership of the closures.
+        let c_schemes: Vec<CString> = handlers
+            .iter()
+            .map(|h| CString::new(h.scheme.as_str()).unwrap_or_default())
+            .collect();
+        let descs: Vec<ffi::wxd_WebViewHandlerDesc> =
+            handlers.iter().zip(&c_schemes).map(|(h, scheme)| h.to_desc(scheme)).collect();
+
         let ptr = unsafe {
             ffi::wxd_WebView_Create(
                 parent_ptr,
@@ -243,6 +325,8 @@ impl WebView {
                 style as _,
                 name_ptr,
                 backend_ptr,
+                descs.as_ptr(),
+                descs.len() as i32,
             )
         };
 
@@ -299,6 +383,8 @@ impl WebView {
     }
 
     /// Reloads the current page.
+    /// A page shown with [`set_page`](Self::set_page) is set again rather than reloaded,
+    /// so the generated content is kept on every backend.
     /// No-op if the webview has been destroyed.
     pub fn reload(&self, flags: WebViewReloadFlags) {
         let ptr = self.webview_ptr();
@@ -400,7 +486,7 @@ impl WebView {
         unsafe { Self::read_string_with_retry(1024, |buf, len| ffi::wxd_WebView_GetCurrentTitle(ptr, buf, len)) }
     }
 
-    /// Returns the page source (HTML).
+    /// Returns the page source (HTML), including pages shown with [`set_page`](Self::set_page).
     /// Returns empty string if the webview has been destroyed.
     pub fn get_page_source(&self) -> String {
         let ptr = self.webview_ptr();
@@ -433,7 +519,7 @@ impl WebView {
         }
     }
 
-    /// Returns the page text content (without HTML tags).
+    /// Returns the page text content (without HTML tags), e.g. for scraping the current document.
     /// Returns empty string if the webview has been destroyed.
     pub fn get_page_text(&self) -> String {
         let ptr = self.webview_ptr();
@@ -565,6 +651,37 @@ impl WebView {
         }
     }
 
+    /// Runs JavaScript code without waiting for it and calls `callback` on the UI thread
+    /// with the result: `Ok` with the value (strings as they are, other values serialized
+    /// as JSON) or `Err` with the message of the exception the script threw.
+    ///
+    /// Unlike [`run_script`](Self::run_script) this never blocks, so long-running scripts
+    /// don't freeze the UI. `callback` is called exactly once, with an `Err` if the webview
+    /// has been destroyed or is destroyed before the script finishes.
+    pub fn run_script_async<F>(&self, javascript: &str, callback: F)
+    where
+        F: FnOnce(Result<String, String>) + 'static,
+    {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            callback(Err("the webview has been destroyed".to_string()));
+            return;
+        }
+        let Ok(c_script) = CString::new(javascript) else {
+            callback(Err("the script contains a NUL byte".to_string()));
+            return;
+        };
+        let callback: Box<ScriptCallback> = Box::new(Box::new(callback));
+        unsafe {
+            ffi::wxd_WebView_RunScriptAsync(
+                ptr,
+                c_script.as_ptr(),
+                Some(script_result_trampoline),
+                Box::into_raw(callback) as *mut std::os::raw::c_void,
+            )
+        };
+    }
+
     // --- Clipboard ---
 
     /// Returns whether the webview can cut.
@@ -753,7 +870,7 @@ impl WebView {
 
     // --- Printing ---
 
-    /// Opens the print dialog.
+    /// Opens the platform's print dialog for the current page.
     /// No-op if the webview has been destroyed.
     pub fn print(&self) {
         let ptr = self.webview_ptr();
@@ -763,6 +880,52 @@ impl WebView {
         unsafe { ffi::wxd_WebView_Print(ptr) };
     }
 
+    // --- Snapshots ---
+
+    /// Captures the visible part of the page, waiting for the backend while
+    /// dispatching events.
+    ///
+    /// Snapshots are taken by the Edge (WebView2) backend in MSVC builds and by the WebKit
+    /// backends on macOS and, when built with the WebKitGTK headers, on Linux.
+    /// Returns `None` and logs the reason if the backend cannot take snapshots, capturing
+    /// failed, or the webview has been destroyed.
+    pub fn capture_snapshot(&self) -> Option<Bitmap> {
+        let result = Rc::new(Cell::new(None));
+        let slot = Rc::clone(&result);
+        let callback: Box<SnapshotCallback> = Box::new(Box::new(move |bitmap| slot.set(bitmap)));
+        unsafe {
+            ffi::wxd_WebView_CaptureSnapshotSync(
+                self.webview_ptr(),
+                false,
+                Some(snapshot_trampoline),
+                Box::into_raw(callback) as *mut std::os::raw::c_void,
+            )
+        };
+        result.take()
+    }
+
+    /// Captures the page and calls `callback` with the result once the backend is done,
+    /// without blocking. [`WebViewSnapshotArea::FullPage`] captures the whole document
+    /// rather than the visible part.
+    ///
+    /// Has the same backend support as [`capture_snapshot`](Self::capture_snapshot), except
+    /// that WKWebView on macOS can only capture the viewport; otherwise `callback` receives
+    /// `None` (possibly right away) and the reason is logged.
+    pub fn capture_snapshot_async<F>(&self, area: WebViewSnapshotArea, callback: F)
+    where
+        F: FnOnce(Option<Bitmap>) + 'static,
+    {
+        let callback: Box<SnapshotCallback> = Box::new(Box::new(callback));
+        unsafe {
+            ffi::wxd_WebView_CaptureSnapshot(
+                self.webview_ptr(),
+                area == WebViewSnapshotArea::FullPage,
+                Some(snapshot_trampoline),
+                Box::into_raw(callback) as *mut std::os::raw::c_void,
+            )
+        };
+    }
+
     // --- Context Menu & Dev Tools ---
 
     /// Enables or disables the context menu.
@@ -860,6 +1023,12 @@ impl WebView {
     // --- Page Loading ---
 
     /// Sets the page content from HTML string.
+    ///
+    /// Relative links and assets resolve against `base_url`, which can use a scheme
+    /// registered with [`WebViewBuilder::with_handler`] to build pages that work offline.
+    /// Backends other than WebKit ignore the base URL of generated pages, so for them a
+    /// `<base>` element is added to documents that don't have one. Pass an empty
+    /// `base_url` if nothing needs to resolve.
     /// No-op if the webview has been destroyed.
     pub fn set_page(&self, html: &str, base_url: &str) {
         let ptr = self.webview_ptr();
@@ -953,8 +1122,12 @@ impl WebView {
 
     // --- Advanced Scripting ---
 
-    /// Adds a script message handler.
-    /// Returns false if the webview has been destroyed.
+    /// Adds a script message handler, letting the page call `window.<name>.postMessage(message)`
+    /// to send a string to [`on_script_message`](Self::on_script_message) handlers.
+    ///
+    /// Add handlers before loading the page so messages it posts while loading arrive; if the
+    /// backend is still being created (Edge), they are added once it is ready.
+    /// Returns false if the webview has been destroyed or the backend refused the name.
     pub fn add_script_message_handler(&self, name: &str) -> bool {
         let ptr = self.webview_ptr();
         if ptr.is_null() {
@@ -975,6 +1148,17 @@ impl WebView {
         unsafe { ffi::wxd_WebView_RemoveScriptMessageHandler(ptr, c_name.as_ptr()) }
     }
 
+    /// Binds a handler for messages the page posts to script message handlers.
+    ///
+    /// The event's `get_message_handler_name()` tells which handler the message was posted to
+    /// and `get_string()` returns the message. Same as `on_script_message_received`.
+    pub fn on_script_message<F>(&self, handler: F) -> crate::event::EventToken
+    where
+        F: FnMut(crate::event::WebViewEventData) + 'static,
+    {
+        self.on_script_message_received(handler)
+    }
+
     /// Adds a user script to be injected into pages.
     /// Returns false if the webview has been destroyed.
     pub fn add_user_script(&self, javascript: &str, injection_time: WebViewUserScriptInjectionTime) -> bool {
@@ -1018,6 +1202,146 @@ impl WebView {
         unsafe { Self::read_string_with_retry(256, |buf, len| ffi::wxd_WebView_GetBackend(ptr, buf, len)) }
     }
 
+    /// Returns whether this webview reports downloads through the
+    /// `on_download_request`, `on_download_progress` and `on_download_completed` events.
+    ///
+    /// The Edge (WebView2) backend in MSVC builds and WebKitGTK on Linux, when built with
+    /// its headers, do. WKWebView on macOS doesn't: its downloads go through the navigation
+    /// delegate, which wxWidgets owns. Elsewhere the events never fire and the backend
+    /// handles downloads itself, if at all. The portable fallback is to veto the navigation
+    /// in `on_navigating` when its URL is a file to download and fetch that URL yourself.
+    ///
+    /// Returns false if the webview has been destroyed.
+    pub fn supports_downloads(&self) -> bool {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_WebView_SupportsDownloads(ptr) }
+    }
+
+    // --- Browsing Data and Cookies ---
+
+    /// Returns whether [`clear_browsing_data`](Self::clear_browsing_data) is
+    /// implemented by this webview's backend (Edge and WebKit).
+    ///
+    /// Returns false if the webview has been destroyed.
+    pub fn supports_browsing_data_clearing(&self) -> bool {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_WebView_SupportsBrowsingDataClearing(ptr) }
+    }
+
+    /// Clears the given kinds of browsing data stored since `since`, or of any age if
+    /// `since` is `None`.
+    ///
+    /// Clearing finishes asynchronously and is reported by `on_browsing_data_cleared`.
+    /// Returns false if the backend cannot clear browsing data, the request failed or
+    /// the webview has been destroyed.
+    pub fn clear_browsing_data(&self, types: WebViewBrowsingDataTypes, since: Option<SystemTime>) -> bool {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let since = since
+            .map(|t| t.duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or(0))
+            .unwrap_or(-1);
+        unsafe { ffi::wxd_WebView_ClearBrowsingData(ptr, types.bits(), since) }
+    }
+
+    /// Returns whether this webview can read and write cookies with
+    /// [`get_cookie`](Self::get_cookie), [`set_cookie`](Self::set_cookie) and
+    /// [`delete_cookies`](Self::delete_cookies). Currently only the Edge (WebView2)
+    /// backend in MSVC builds can; elsewhere those methods return `None`/`false`.
+    ///
+    /// Returns false if the webview has been destroyed.
+    pub fn supports_cookies(&self) -> bool {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_WebView_SupportsCookies(ptr) }
+    }
+
+    /// Returns the value of the cookie called `name` that would be sent to `url`.
+    ///
+    /// Returns `None` if there is no such cookie, cookies are not supported, or the
+    /// webview has been destroyed.
+    pub fn get_cookie(&self, name: &str, url: &str) -> Option<String> {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+        let c_name = CString::new(name).ok()?;
+        let c_url = CString::new(url).ok()?;
+        // Cookies are at most 4096 bytes, so one call normally suffices
+        let mut buffer: Vec<c_char> = vec![0; 4096];
+        let mut len =
+            unsafe { ffi::wxd_WebView_GetCookie(ptr, c_name.as_ptr(), c_url.as_ptr(), buffer.as_mut_ptr(), buffer.len() as i32) };
+        if len >= 0 && len as usize >= buffer.len() {
+            buffer = vec![0; len as usize + 1];
+            len = unsafe {
+                ffi::wxd_WebView_GetCookie(ptr, c_name.as_ptr(), c_url.as_ptr(), buffer.as_mut_ptr(), buffer.len() as i32)
+            };
+        }
+        if len < 0 {
+            return None;
+        }
+        Some(unsafe { CStr::from_ptr(buffer.as_ptr()).to_string_lossy().to_string() })
+    }
+
+    /// Stores `cookie`, replacing any cookie with the same name, domain and path.
+    ///
+    /// Returns false if cookies are not supported, the cookie was rejected, or the
+    /// webview has been destroyed.
+    pub fn set_cookie(&self, cookie: &WebViewCookie) -> bool {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let (Ok(name), Ok(value), Ok(domain), Ok(path)) = (
+            CString::new(cookie.name.as_str()),
+            CString::new(cookie.value.as_str()),
+            CString::new(cookie.domain.as_str()),
+            CString::new(cookie.path.as_str()),
+        ) else {
+            return false;
+        };
+        let expires = cookie
+            .expires
+            .map(|t| t.duration_since(UNIX_EPOCH).map(|d| d.as_secs_f64()).unwrap_or(0.0))
+            .unwrap_or(-1.0);
+        unsafe {
+            ffi::wxd_WebView_SetCookie(
+                ptr,
+                name.as_ptr(),
+                value.as_ptr(),
+                domain.as_ptr(),
+                path.as_ptr(),
+                expires,
+                cookie.http_only,
+                cookie.secure,
+            )
+        }
+    }
+
+    /// Deletes every cookie that would be sent to `url`, e.g. to log out of a site.
+    ///
+    /// Returns false if cookies are not supported, deleting failed, or the webview
+    /// has been destroyed.
+    pub fn delete_cookies(&self, url: &str) -> bool {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return false;
+        }
+        let Ok(c_url) = CString::new(url) else {
+            return false;
+        };
+        unsafe { ffi::wxd_WebView_DeleteCookies(ptr, c_url.as_ptr()) }
+    }
+
     /// Checks if a specific WebView backend is available on the current system.
     ///
     /// # Arguments
@@ -1051,13 +1375,19 @@ impl WebView {
     /// images, or other assets to pages loaded via [`set_page`](Self::set_page),
     /// which would otherwise be blocked or require large base64 data URIs.
     ///
+    /// Pages usually need their handler before the first load; register it with
+    /// [`WebViewBuilder::with_handler`] instead so that is guaranteed.
+    ///
     /// # Platform limitations
-    /// - **Windows (Edge/WebView2)**: fully supported. The handler can be registered
-    ///   at any time after the webview is built.
-    /// - **macOS (WebKit)**: the underlying WebKit backend only reads registered
-    ///   handlers when the native control is created, which happens during
-    ///   [`WebView::builder`]`.build()`. Because this method runs afterward, custom
-    ///   handlers currently have no effect on macOS.
+    /// - **Windows (Edge/WebView2)**: supported at any time after the webview is
+    ///   built. WebView2 cannot navigate to custom schemes, so wxWidgets serves the
+    ///   handler from the virtual host `https://<scheme>.wxsite/` and the closure
+    ///   receives those URLs.
+    /// - **Linux (WebKit2GTK)**: supported at any time after the webview is built.
+    /// - **macOS (WebKit)**: WebKit only reads registered handlers when the native
+    ///   control is created, so handlers registered by this method have no effect.
+    ///   Use [`WebViewBuilder::with_handler`].
+    /// - **Windows (IE)**: not supported.
     ///
     /// No-op if the webview has been destroyed.
     ///
@@ -1099,12 +1429,94 @@ impl WebView {
         }
     }
 
+    /// Registers wxWidgets' archive handler for `scheme`, serving files from inside
+    /// zip archives with URLs like `scheme:///path/to/site.zip;protocol=zip/index.html`.
+    ///
+    /// Has the same platform limitations as [`register_handler`](Self::register_handler);
+    /// use [`WebViewBuilder::with_archive_handler`] to register it before the first load.
+    pub fn register_archive_handler(&self, scheme: &str) {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_scheme = CString::new(scheme).unwrap_or_default();
+        unsafe { ffi::wxd_WebView_RegisterArchiveHandler(ptr, c_scheme.as_ptr()) }
+    }
+
+    /// Registers wxWidgets' filesystem handler for `scheme`, serving `scheme:` URLs
+    /// through wxFileSystem. The scheme must be a protocol wxFileSystem understands,
+    /// such as `memory` for in-memory files.
+    ///
+    /// Has the same platform limitations as [`register_handler`](Self::register_handler);
+    /// use [`WebViewBuilder::with_filesystem_handler`] to register it before the first load.
+    pub fn register_filesystem_handler(&self, scheme: &str) {
+        let ptr = self.webview_ptr();
+        if ptr.is_null() {
+            return;
+        }
+        let c_scheme = CString::new(scheme).unwrap_or_default();
+        unsafe { ffi::wxd_WebView_RegisterFSHandler(ptr, c_scheme.as_ptr()) }
+    }
+
     /// Returns the underlying WindowHandle for this webview.
     pub fn window_handle(&self) -> WindowHandle {
         self.handle
     }
 }
 
+/// The part of the page captured by [`WebView::capture_snapshot_async`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WebViewSnapshotArea {
+    /// The visible viewport.
+    #[default]
+    Viewport,
+    /// The whole document, including what is scrolled out of view. Not supported by
+    /// WKWebView on macOS.
+    FullPage,
+}
+
+type ScriptCallback = Box<dyn FnOnce(Result<String, String>)>;
+
+extern "C" fn script_result_trampoline(success: bool, result: *const c_char, userdata: *mut std::os::raw::c_void) {
+    if userdata.is_null() {
+        return;
+    }
+    let callback = unsafe { Box::from_raw(userdata as *mut ScriptCallback) };
+    let text = if result.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(result) }.to_string_lossy().into_owned()
+    };
+    callback(if success { Ok(text) } else { Err(text) });
+}
+
+type SnapshotCallback = Box<dyn FnOnce(Option<Bitmap>)>;
+
+extern "C" fn snapshot_trampoline(png: *const u8, len: usize, error: *const c_char, userdata: *mut std::os::raw::c_void) {
+    if userdata.is_null() {
+        return;
+    }
+    let callback = unsafe { Box::from_raw(userdata as *mut SnapshotCallback) };
+    let bitmap = if !error.is_null() {
+        let reason = unsafe { CStr::from_ptr(error) }.to_string_lossy();
+        log::warn!("WebView snapshot failed: {reason}");
+        None
+    } else if png.is_null() || len == 0 {
+        log::warn!("WebView snapshot failed: no image data");
+        None
+    } else {
+        let data = unsafe { std::slice::from_raw_parts(png, len) };
+        match Bitmap::from_bytes(data) {
+            Ok(bitmap) => Some(bitmap),
+            Err(err) => {
+                log::warn!("WebView snapshot failed: {err}");
+                None
+            }
+        }
+    };
+    callback(bitmap);
+}
+
 /// The resource returned by a [`WebView::register_handler`] closure.
 pub struct WebViewHandlerResponse {
     /// The raw bytes of the resource to serve.
@@ -1115,6 +1527,83 @@ pub struct WebViewHandlerResponse {
 
 type HandlerClosure = Box<dyn Fn(&str) -> Option<WebViewHandlerResponse>>;
 
+#[derive(Clone)]
+enum SchemeHandlerKind {
+    Callback(Rc<dyn Fn(&str) -> Option<WebViewHandlerResponse>>),
+    Archive,
+    FileSystem,
+}
+
+/// A scheme handler registered while the webview is created, before its first page loads.
+///
+/// Usually added through [`WebViewBuilder::with_handler`],
+/// [`WebViewBuilder::with_archive_handler`] or [`WebViewBuilder::with_filesystem_handler`].
+#[derive(Clone)]
+pub struct WebViewSchemeHandler {
+    scheme: String,
+    kind: SchemeHandlerKind,
+}
+
+impl WebViewSchemeHandler {
+    /// A handler serving `scheme` from a closure, as in [`WebView::register_handler`].
+    pub fn new<F>(scheme: &str, handler: F) -> Self
+    where
+        F: Fn(&str) -> Option<WebViewHandlerResponse> + 'static,
+    {
+        Self {
+            scheme: scheme.to_string(),
+            kind: SchemeHandlerKind::Callback(Rc::new(handler)),
+        }
+    }
+
+    /// wxWidgets' archive handler, as in [`WebView::register_archive_handler`].
+    pub fn archive(scheme: &str) -> Self {
+        Self {
+            scheme: scheme.to_string(),
+            kind: SchemeHandlerKind::Archive,
+        }
+    }
+
+    /// wxWidgets' filesystem handler, as in [`WebView::register_filesystem_handler`].
+    pub fn filesystem(scheme: &str) -> Self {
+        Self {
+            scheme: scheme.to_string(),
+            kind: SchemeHandlerKind::FileSystem,
+        }
+    }
+
+    /// Returns the scheme this handler serves.
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// Describes the handler for `wxd_WebView_Create`, which takes ownership of the
+    /// boxed closure whether or not the webview is created.
+    fn to_desc(&self, scheme: &CString) -> ffi::wxd_WebViewHandlerDesc {
+        let mut desc = ffi::wxd_WebViewHandlerDesc {
+            scheme: scheme.as_ptr(),
+            kind: ffi::wxd_WebViewHandlerKind_WXD_WEBVIEW_HANDLER_CALLBACK,
+            callback: None,
+            free_data: None,
+            drop_userdata: None,
+            userdata: std::ptr::null_mut(),
+        };
+        match &self.kind {
+            SchemeHandlerKind::Callback(handler) => {
+                let handler = Rc::clone(handler);
+                let boxed: Box<HandlerClosure> = Box::new(Box::new(move |uri: &str| handler(uri)));
+                desc.callback = Some(handler_callback_trampoline);
+                desc.free_data = Some(handler_free_data_trampoline);
+                desc.drop_userdata = Some(handler_drop_userdata_trampoline);
+                desc.userdata = Box::into_raw(boxed) as *mut std::os::raw::c_void;
+            }
+            SchemeHandlerKind::Archive => desc.kind = ffi::wxd_WebViewHandlerKind_WXD_WEBVIEW_HANDLER_ARCHIVE,
+            SchemeHandlerKind::FileSystem => desc.kind = ffi::wxd_WebViewHandlerKind_WXD_WEBVIEW_HANDLER_FILESYSTEM,
+        }
+        desc
+    }
+}
+
 extern "C" fn handler_callback_trampoline(
     uri: *const c_char,
     userdata: *mut std::os::raw::c_void,
@@ -1213,7 +1702,8 @@ widget_builder!(
     fields: {
         url: Option<String> = None,
         name: String = "webView".to_string(),
-        backend: WebViewBackend = WebViewBackend::Default
+        backend: WebViewBackend = WebViewBackend::Default,
+        scheme_handlers: Vec<WebViewSchemeHandler> = Vec::new()
     },
     build_impl: |slf| {
         let parent_ptr = slf.parent.handle_ptr();
@@ -1226,14 +1716,60 @@ widget_builder!(
             slf.style.bits(),
             Some(slf.name.as_str()),
             Some(slf.backend.as_str()),
+            &slf.scheme_handlers,
         )
     }
 );
 
+impl<'a> WebViewBuilder<'a> {
+    /// Serves `scheme` from `handler`, registered before the initial URL loads.
+    ///
+    /// Unlike [`WebView::register_handler`] this also works on macOS, because the
+    /// handler is in place when WebKit creates the native control.
+    ///
+    /// # Example
+    /// ```ignore
+    /// const INDEX: &[u8] = include_bytes!("../assets/index.html");
+    ///
+    /// let webview = WebView::builder(&panel)
+    ///     .with_handler("app", |uri| {
+    ///         uri.ends_with("/index.html").then(|| WebViewHandlerResponse {
+    ///             data: INDEX.to_vec(),
+    ///             mime_type: Some("text/html".to_string()),
+    ///         })
+    ///     })
+    ///     .with_url(Some("app://index.html".to_string()))
+    ///     .build();
+    /// ```
+    pub fn with_handler<F>(mut self, scheme: &str, handler: F) -> Self
+    where
+        F: Fn(&str) -> Option<WebViewHandlerResponse> + 'static,
+    {
+        self.scheme_handlers.push(WebViewSchemeHandler::new(scheme, handler));
+        self
+    }
+
+    /// Registers wxWidgets' archive handler for `scheme` before the initial URL loads.
+    /// See [`WebView::register_archive_handler`].
+    pub fn with_archive_handler(mut self, scheme: &str) -> Self {
+        self.scheme_handlers.push(WebViewSchemeHandler::archive(scheme));
+        self
+    }
+
+    /// Registers wxWidgets' filesystem handler for `scheme` before the initial URL loads.
+    /// See [`WebView::register_filesystem_handler`].
+    pub fn with_filesystem_handler(mut self, scheme: &str) -> Self {
+        self.scheme_handlers.push(WebViewSchemeHandler::filesystem(scheme));
+        self
+    }
+}
+
 // XRC Support - enables WebView to be created from XRC-managed pointers
 #[cfg(feature = "xrc")]
 impl crate::xrc::XrcSupport for WebView {
     unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        // Webviews created by XRC did not go through wxd_WebView_Create
+        unsafe { ffi::wxd_WebView_EnableDownloadEvents(ptr as *mut ffi::wxd_WebView_t) };
         WebView {
             handle: WindowHandle::new(ptr),
         }
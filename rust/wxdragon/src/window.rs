@@ -54,6 +54,7 @@ impl WindowHandle {
     /// # Safety
     /// The caller must ensure `ptr` points to a valid wxWindow that hasn't been destroyed.
     pub(crate) fn new(ptr: *mut ffi::wxd_Window_t) -> Self {
+        crate::app::assert_main_thread();
         if ptr.is_null() {
             return WindowHandle(0); // Invalid handle for null pointers
         }
@@ -87,8 +88,14 @@ impl WindowHandle {
     }
 
     /// Get the raw pointer if the window is still valid, `None` if destroyed.
+    ///
+    /// # Panics
+    /// In debug builds, when called from a thread other than the main thread (see
+    /// [`assert_main_thread`](crate::app::assert_main_thread)).
     #[inline]
+    #[track_caller]
     pub fn get_ptr(&self) -> Option<*mut ffi::wxd_Window_t> {
+        crate::app::assert_main_thread();
         if self.0 == 0 {
             return None;
         }
@@ -415,7 +422,11 @@ pub trait WxWidget: std::any::Any {
     /// - `BackgroundStyle::Paint`: No automatic background erasing, app handles it
     /// - `BackgroundStyle::Colour`: Background filled with background color before paint
     ///
-    /// For smooth custom drawing and animations, use `BackgroundStyle::Paint`.
+    /// For smooth custom drawing and animations, use `BackgroundStyle::Paint` and
+    /// paint the whole client area from the paint handler. On platforms that still
+    /// send erase events, an `on_erase_background` handler that does not skip the
+    /// event (see [`EraseEventData`](crate::event::EraseEventData)) suppresses the
+    /// default erase as well.
     fn set_background_style(&self, style: BackgroundStyle) {
         let window_ptr = self.handle_ptr();
         if !window_ptr.is_null() {
@@ -436,6 +447,40 @@ pub trait WxWidget: std::any::Any {
         }
     }
 
+    /// Turns native double buffering of the window on or off.
+    ///
+    /// This is mostly useful on Windows, where it removes flicker from windows with
+    /// many children. macOS and GTK 3 windows are always double buffered, and the call
+    /// does nothing there. For custom drawing prefer
+    /// [`AutoBufferedPaintDC`](crate::dc::AutoBufferedPaintDC), which only buffers when needed.
+    fn set_double_buffered(&self, on: bool) {
+        let window_ptr = self.handle_ptr();
+        if !window_ptr.is_null() {
+            unsafe { ffi::wxd_Window_SetDoubleBuffered(window_ptr, on) };
+        }
+    }
+
+    /// Returns true if the system already double buffers the window.
+    fn is_double_buffered(&self) -> bool {
+        let window_ptr = self.handle_ptr();
+        !window_ptr.is_null() && unsafe { ffi::wxd_Window_IsDoubleBuffered(window_ptr) }
+    }
+
+    /// Copies the client area of the window, as it currently appears on screen, into a bitmap.
+    ///
+    /// The window must be shown; parts covered by other windows or outside the displays are
+    /// not captured correctly. Returns `None` and logs the reason on failure, see
+    /// [`capture_screen`](crate::dc::screen_dc::capture_screen).
+    fn capture(&self) -> Option<crate::bitmap::Bitmap> {
+        let window_ptr = self.handle_ptr();
+        if window_ptr.is_null() {
+            return None;
+        }
+        let mut error = 0;
+        let ptr = unsafe { ffi::wxd_Window_CaptureClientArea(window_ptr, &mut error) };
+        crate::dc::screen_dc::bitmap_from_capture(ptr, error, "Window::capture")
+    }
+
     /// Sets the window's minimum size.
     fn set_min_size(&self, size: crate::geometry::Size) {
         let window_ptr = self.handle_ptr();
@@ -494,6 +539,20 @@ pub trait WxWidget: std::any::Any {
         }
     }
 
+    /// Sends an idle event to this window and its children right away, without waiting
+    /// for the event loop to become idle.
+    ///
+    /// With [`IdleMode::ProcessSpecified`](crate::event::IdleMode::ProcessSpecified) only
+    /// windows with [`ExtraWindowStyle::ProcessIdle`] receive it. Returns true if a handler
+    /// requested more idle events.
+    fn send_idle_events(&self) -> bool {
+        let window_ptr = self.handle_ptr();
+        if window_ptr.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Window_SendIdleEvents(window_ptr) }
+    }
+
     /// Sets the tooltip string for this widget.
     fn set_tooltip(&self, tip: &str) {
         let handle = self.handle_ptr();
@@ -1022,6 +1081,38 @@ pub trait WxWidget: std::any::Any {
         }
     }
 
+    // --- Validation ---
+
+    /// Checks the values of all controls inside this window that have a
+    /// [`Validator`](crate::validator::Validator), stopping at the first one rejected.
+    ///
+    /// The rejected control is brought into view, selecting the notebook pages holding it,
+    /// and focused, and its validator's message is shown. Disabled controls are skipped.
+    /// Returns `true` if every value was accepted.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ok_button.on_click(move |_| {
+    ///     if dialog.validate() && dialog.transfer_data_from_window() {
+    ///         dialog.end_modal(ID_OK);
+    ///     }
+    /// });
+    /// ```
+    fn validate(&self) -> bool {
+        let handle = self.handle_ptr();
+        !handle.is_null() && unsafe { ffi::wxd_Window_Validate(handle) }
+    }
+
+    /// Hands the value of each control inside this window to its validator, see
+    /// [`Validator::transfer_from_window`](crate::validator::Validator::transfer_from_window).
+    ///
+    /// Call it after [`validate`](Self::validate) succeeded. Returns `false` if a
+    /// validator failed to take its value or the window has been destroyed.
+    fn transfer_data_from_window(&self) -> bool {
+        let handle = self.handle_ptr();
+        !handle.is_null() && unsafe { ffi::wxd_Window_TransferDataFromWindow(handle) }
+    }
+
     // --- Visibility ---
 
     /// Returns `true` if the window is currently shown.
@@ -1214,7 +1305,8 @@ pub trait WxWidget: std::any::Any {
 
     /// Sets the cursor for this window.
     ///
-    /// The cursor will be displayed when the mouse pointer is over this window.
+    /// The cursor will be displayed when the mouse pointer is over this window, and takes
+    /// effect immediately, also while the window captures the mouse during a drag.
     /// Pass `None` to reset to the default cursor.
     ///
     /// # Arguments
@@ -1320,6 +1412,117 @@ pub trait WxWidget: std::any::Any {
         }
     }
 
+    // --- Drag and Drop ---
+
+    /// Accepts files dropped on this window, e.g. from a file manager.
+    ///
+    /// `on_drop` gets the drop position in client coordinates and the dropped paths, and
+    /// returns false to reject the drop. It can be combined with
+    /// [`set_text_drop_target`](Self::set_text_drop_target) and
+    /// [`set_data_drop_target`](Self::set_data_drop_target) on the same window; calling it
+    /// again replaces the previous file handler. The handlers are destroyed together with the
+    /// window. For the full set of callbacks use
+    /// [`FileDropTarget::builder`](crate::dnd::FileDropTarget::builder) instead.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # let frame = Frame::builder().build();
+    /// let panel = Panel::builder(&frame).build();
+    /// panel.set_file_drop_target(|_pos, files| {
+    ///     let images: Vec<_> = files.iter().filter(|f| f.ends_with(".png")).collect();
+    ///     println!("Dropped images: {images:?}");
+    ///     !images.is_empty()
+    /// });
+    /// ```
+    fn set_file_drop_target<F>(&self, on_drop: F)
+    where
+        F: Fn(Point, Vec<String>) -> bool + 'static,
+        Self: Sized,
+    {
+        let handle = self.handle_ptr();
+        if !handle.is_null() {
+            crate::dnd::droptarget::set_window_drop_files_handler(handle, on_drop);
+        }
+    }
+
+    /// Like [`set_file_drop_target`](Self::set_file_drop_target), with `on_drag_over` giving
+    /// feedback while data is dragged over the window.
+    ///
+    /// `on_drag_over` gets the mouse position in client coordinates. Returning
+    /// [`DragResult::None`](crate::dnd::DragResult::None) shows the "no entry" cursor and
+    /// refuses a drop at that position; `Copy`, `Move` or `Link` show the matching cursor.
+    /// The feedback applies to everything the window accepts, including text and custom data.
+    fn set_file_drop_target_with_feedback<F, G>(&self, on_drop: F, on_drag_over: G)
+    where
+        F: Fn(Point, Vec<String>) -> bool + 'static,
+        G: Fn(Point) -> crate::dnd::DragResult + 'static,
+        Self: Sized,
+    {
+        let handle = self.handle_ptr();
+        if !handle.is_null() {
+            crate::dnd::droptarget::set_window_drop_files_handler(handle, on_drop);
+            crate::dnd::droptarget::set_window_drag_over_handler(handle, on_drag_over);
+        }
+    }
+
+    /// Accepts text dropped on this window, e.g. a URL dragged from a browser.
+    ///
+    /// `on_drop` gets the drop position in client coordinates and the text, and returns
+    /// false to reject the drop. When files or custom data are accepted too, they are
+    /// preferred over text, which most sources offer alongside them.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # let frame = Frame::builder().build();
+    /// let list = ListBox::builder(&frame).build();
+    /// list.set_text_drop_target(move |_pos, text| {
+    ///     let is_url = text.starts_with("https://");
+    ///     if is_url {
+    ///         list.append(text.trim());
+    ///     }
+    ///     is_url
+    /// });
+    /// ```
+    fn set_text_drop_target<F>(&self, on_drop: F)
+    where
+        F: Fn(Point, String) -> bool + 'static,
+        Self: Sized,
+    {
+        let handle = self.handle_ptr();
+        if !handle.is_null() {
+            crate::dnd::droptarget::set_window_drop_text_handler(handle, on_drop);
+        }
+    }
+
+    /// Accepts data of a custom format dropped on this window.
+    ///
+    /// `format_name` names the format the drag source offers, e.g.
+    /// `"application/x-myapp-node"`; wxWidgets registers it with the platform (a registered
+    /// clipboard format on Windows, an atom on GTK, a UTI on macOS). `on_drop` gets the
+    /// drop position in client coordinates and the raw bytes, and returns false to reject
+    /// the drop. Each format has its own handler; calling this again with the same name
+    /// replaces it.
+    fn set_data_drop_target<F>(&self, format_name: &str, on_drop: F)
+    where
+        F: Fn(Point, Vec<u8>) -> bool + 'static,
+        Self: Sized,
+    {
+        let handle = self.handle_ptr();
+        if !handle.is_null() && !format_name.is_empty() {
+            crate::dnd::droptarget::set_window_drop_data_handler(handle, format_name, on_drop);
+        }
+    }
+
+    /// Removes the drop target of this window, so nothing can be dropped on it any more.
+    fn remove_drop_target(&self) {
+        let handle = self.handle_ptr();
+        if !handle.is_null() {
+            unsafe { ffi::wxd_Window_SetDropTarget(handle, std::ptr::null_mut()) }
+        }
+    }
+
     // --- Painting The Window ---
 
     /// Freeze the window: don't redraw it until it is thawed
@@ -2067,6 +2270,14 @@ impl WxEvtHandler for Window {
     }
 }
 
+// XRC Support - lets include_xrc! expose objects of classes without a dedicated wrapper
+#[cfg(feature = "xrc")]
+impl crate::xrc::XrcSupport for Window {
+    unsafe fn from_xrc_ptr(ptr: *mut ffi::wxd_Window_t) -> Self {
+        Window(ptr)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Unit tests for WindowHandle behavior
 // ---------------------------------------------------------------------------
@@ -2121,4 +2332,54 @@ mod tests {
             log::warn!("Test failed with error: {:?}", e);
         }
     }
+
+    // Needs the main loop, which records the main thread, see the note above
+    #[cfg_attr(target_os = "macos", ignore)]
+    #[test]
+    fn window_use_off_main_thread_panics() {
+        use crate::prelude::*;
+        use crate::widgets::{Frame, Panel};
+
+        if !cfg!(any(debug_assertions, feature = "main-thread-assertions")) {
+            return;
+        }
+        SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
+        let timer_store: std::rc::Rc<std::cell::RefCell<Option<Timer<Frame>>>> = std::rc::Rc::new(std::cell::RefCell::new(None));
+        let timer_store_clone = timer_store.clone();
+        // Checked after the main loop returns, as panics inside it don't fail the test
+        let outcome = std::rc::Rc::new(std::cell::RefCell::new(None));
+        let outcome_clone = outcome.clone();
+
+        let res = crate::main(move |app| {
+            let frame = Frame::builder().with_title("test").build();
+            let panel = Panel::builder(&frame).build();
+
+            // The handle every widget method goes through, used from a worker thread
+            let handle = panel.window_handle();
+            let worker = std::thread::spawn(move || handle.get_ptr().is_some());
+            outcome_clone.borrow_mut().replace(worker.join());
+
+            let timer = Timer::new(&frame);
+            timer.on_tick(move |_evt| {
+                frame.destroy();
+                app.exit_main_loop();
+            });
+            timer.start(100, true);
+            timer_store_clone.borrow_mut().replace(timer);
+        });
+        if let Err(e) = res {
+            panic!("main loop failed: {e:?}");
+        }
+
+        let payload = outcome
+            .borrow_mut()
+            .take()
+            .expect("the worker thread did not run")
+            .expect_err("using a window off the main thread should panic");
+        let message = payload.downcast_ref::<String>().cloned().unwrap_or_default();
+        assert!(
+            message.contains("may only be used on the main thread"),
+            "unexpected panic: {message}"
+        );
+    }
 }
@@ -1,11 +1,103 @@
+use crate::bitmap::Bitmap;
 use crate::dialogs::Dialog;
-use crate::widgets::{Frame, Panel};
-use crate::window::WxWidget;
-use std::ffi::CString;
+use crate::id::ID_NONE;
+use crate::menus::{Menu, MenuBar};
+use crate::widgets::{Frame, Panel, ToolBar};
+use crate::window::{Window, WxWidget};
+use std::ffi::{CStr, CString, c_char, c_void};
+use std::fmt;
 use std::marker::PhantomData;
+use std::path::Path;
 use std::ptr;
 use wxdragon_sys as ffi;
 
+/// Error returned when loading XRC resources fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XrcError {
+    /// A file or resource name contained a nul byte.
+    InvalidName(String),
+    /// The XRC file does not exist.
+    FileNotFound(String),
+    /// The XRC document could not be parsed or loaded.
+    LoadFailed { source: String, message: String },
+    /// No loaded resource has this name.
+    ResourceNotFound(String),
+    /// The resource exists but has a different class than requested.
+    WrongClass { name: String, expected: String, found: String },
+    /// wxWidgets could not create the object, e.g. because no XRC handler exists for
+    /// its class or the class of one of its children.
+    CreationFailed { name: String, class: String, message: String },
+    /// The id requested for an XRC name is already used by another name.
+    IdInUse { name: String, id: i32, used_by: String },
+    /// The XRC name already has a different id.
+    NameHasId { name: String, id: i32, existing: i32 },
+}
+
+impl XrcError {
+    fn creation_failed(name: &str, class: &str) -> Self {
+        XrcError::CreationFailed {
+            name: name.to_string(),
+            class: class.to_string(),
+            message: last_error(),
+        }
+    }
+}
+
+impl fmt::Display for XrcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XrcError::InvalidName(name) => write!(f, "Invalid XRC name: {name:?}"),
+            XrcError::FileNotFound(path) => write!(f, "XRC file not found: {path}"),
+            XrcError::LoadFailed { source, message } => write!(f, "Failed to load XRC from {source}: {message}"),
+            XrcError::ResourceNotFound(name) => write!(f, "XRC resource '{name}' not found"),
+            XrcError::WrongClass { name, expected, found } => {
+                write!(f, "XRC resource '{name}' is a {found}, not a {expected}")
+            }
+            XrcError::CreationFailed { name, class, message } => {
+                write!(f, "Failed to create XRC resource '{name}' ({class}): {message}")
+            }
+            XrcError::IdInUse { name, id, used_by } => {
+                write!(
+                    f,
+                    "Cannot give XRC name '{name}' the id {id}: it is already used by '{used_by}'"
+                )
+            }
+            XrcError::NameHasId { name, id, existing } => {
+                write!(
+                    f,
+                    "Cannot give XRC name '{name}' the id {id}: it already has the id {existing}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for XrcError {}
+
+/// Returns the errors wxWidgets logged during the last XRC load call.
+fn last_error() -> String {
+    let len = unsafe { ffi::wxd_XmlResource_GetLastError(ptr::null_mut(), 0) };
+    if len <= 0 {
+        return "unknown error".to_string();
+    }
+    let mut buf = vec![0; len as usize + 1];
+    unsafe { ffi::wxd_XmlResource_GetLastError(buf.as_mut_ptr(), buf.len()) };
+    unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() }
+}
+
+/// Whether `filename` names a file on disk rather than a wildcard mask or a virtual file
+/// system location (`memory:`, `file:` URLs, or `#`-separated archive paths). A single
+/// letter before the colon is a Windows drive, not a protocol.
+fn is_plain_path(filename: &str) -> bool {
+    if filename.contains(['*', '?', '#']) {
+        return false;
+    }
+    match filename.split_once(':') {
+        Some((protocol, _)) => protocol.len() < 2 || !protocol.chars().all(|c| c.is_ascii_alphanumeric()),
+        None => true,
+    }
+}
+
 /// Represents the global wxXmlResource object.
 #[derive(Clone)] // Cloning just copies the pointer to the singleton.
 pub struct XmlResource {
@@ -54,75 +146,323 @@ impl XmlResource {
     }
 
     /// Load XRC from file
-    pub fn load_from_file(&self, filename: &str) -> Result<(), String> {
-        let c_filename = CString::new(filename).map_err(|_| "Invalid filename")?;
+    ///
+    /// `filename` may also be a wildcard mask or a virtual file system path such as
+    /// `memory:dialogs.xrc` or `archive.zip#zip:dialogs.xrc`; the existence check is
+    /// only done for plain paths.
+    pub fn load_from_file(&self, filename: &str) -> Result<(), XrcError> {
+        let c_filename = CString::new(filename).map_err(|_| XrcError::InvalidName(filename.to_string()))?;
+        if is_plain_path(filename) && !std::path::Path::new(filename).exists() {
+            return Err(XrcError::FileNotFound(filename.to_string()));
+        }
         let success = unsafe { ffi::wxd_XmlResource_LoadFromFile(self.ptr, c_filename.as_ptr()) };
         if success {
             Ok(())
         } else {
-            Err(format!("Failed to load XRC file: {filename}"))
+            Err(XrcError::LoadFailed {
+                source: filename.to_string(),
+                message: last_error(),
+            })
         }
     }
 
     /// Load XRC from string data
-    pub fn load_from_string(&self, xrc_data: &str) -> Result<(), String> {
-        let c_data = CString::new(xrc_data).map_err(|_| "Invalid XRC data")?;
+    pub fn load_from_string(&self, xrc_data: &str) -> Result<(), XrcError> {
+        let c_data = CString::new(xrc_data).map_err(|_| XrcError::LoadFailed {
+            source: "string".to_string(),
+            message: "XRC data contains a nul byte".to_string(),
+        })?;
         let success = unsafe { ffi::wxd_XmlResource_LoadFromString(self.ptr, c_data.as_ptr()) };
         if success {
             Ok(())
         } else {
-            Err("Failed to load XRC from string".to_string())
+            Err(XrcError::LoadFailed {
+                source: "string".to_string(),
+                message: last_error(),
+            })
         }
     }
 
-    /// Load a dialog from XRC
-    pub fn load_dialog(&self, parent: Option<&dyn WxWidget>, name: &str) -> Option<Dialog> {
+    /// Load XRC from string data, resolving relative paths in it (such as bitmap files)
+    /// against `base_dir` instead of the current directory.
+    ///
+    /// `include_xrc!` uses this with the directory of the XRC file.
+    pub fn load_from_string_with_base_path(&self, xrc_data: &str, base_dir: impl AsRef<Path>) -> Result<(), XrcError> {
+        let base_dir = base_dir.as_ref();
+        let c_data = CString::new(xrc_data).map_err(|_| XrcError::LoadFailed {
+            source: "string".to_string(),
+            message: "XRC data contains a nul byte".to_string(),
+        })?;
+        let c_base_dir = CString::new(base_dir.to_string_lossy().as_bytes())
+            .map_err(|_| XrcError::InvalidName(base_dir.display().to_string()))?;
+        let success = unsafe { ffi::wxd_XmlResource_LoadFromStringWithBasePath(self.ptr, c_data.as_ptr(), c_base_dir.as_ptr()) };
+        if success {
+            Ok(())
+        } else {
+            Err(XrcError::LoadFailed {
+                source: "string".to_string(),
+                message: last_error(),
+            })
+        }
+    }
+
+    /// Load XRC from UTF-8 encoded bytes, e.g. a theme file read by the application.
+    pub fn load_from_bytes(&self, xrc_data: &[u8]) -> Result<(), XrcError> {
+        let success =
+            unsafe { ffi::wxd_XmlResource_LoadFromBytes(self.ptr, xrc_data.as_ptr(), xrc_data.len(), c"memory_xrc".as_ptr()) };
+        if success {
+            Ok(())
+        } else {
+            Err(XrcError::LoadFailed {
+                source: "bytes".to_string(),
+                message: last_error(),
+            })
+        }
+    }
+
+    /// Returns the class (e.g. `"wxFrame"`) of the loaded top-level resource `name`,
+    /// or `None` if no such resource has been loaded.
+    pub fn get_resource_class(&self, name: &str) -> Option<String> {
+        let c_name = CString::new(name).ok()?;
+        let len = unsafe { ffi::wxd_XmlResource_GetResourceClass(self.ptr, c_name.as_ptr(), ptr::null_mut(), 0) };
+        if len < 0 {
+            return None;
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_XmlResource_GetResourceClass(self.ptr, c_name.as_ptr(), buf.as_mut_ptr(), buf.len()) };
+        Some(unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() })
+    }
+
+    /// Loads the bitmap defined by `<object class="wxBitmap" name="...">`.
+    /// Returns `None` if there is no such resource or its image cannot be read.
+    pub fn load_bitmap(&self, name: &str) -> Option<Bitmap> {
         let c_name = CString::new(name).ok()?;
+        let ptr = unsafe { ffi::wxd_XmlResource_LoadBitmap(self.ptr, c_name.as_ptr()) };
+        if ptr.is_null() { None } else { Some(Bitmap::from(ptr)) }
+    }
+
+    /// Loads the icon defined by `<object class="wxIcon" name="...">` as a bitmap,
+    /// ready for `Frame::set_icon`.
+    /// Returns `None` if there is no such resource or its image cannot be read.
+    pub fn load_icon(&self, name: &str) -> Option<Bitmap> {
+        let c_name = CString::new(name).ok()?;
+        let ptr = unsafe { ffi::wxd_XmlResource_LoadIcon(self.ptr, c_name.as_ptr()) };
+        if ptr.is_null() { None } else { Some(Bitmap::from(ptr)) }
+    }
+
+    /// Checks that `name` is a loaded resource of class `expected` before creating it.
+    fn check_resource_class(&self, name: &str, expected: &str) -> Result<CString, XrcError> {
+        let c_name = CString::new(name).map_err(|_| XrcError::InvalidName(name.to_string()))?;
+        match self.get_resource_class(name) {
+            None => Err(XrcError::ResourceNotFound(name.to_string())),
+            Some(found) if found != expected => Err(XrcError::WrongClass {
+                name: name.to_string(),
+                expected: expected.to_string(),
+                found,
+            }),
+            Some(_) => Ok(c_name),
+        }
+    }
+
+    /// Load a dialog from XRC
+    pub fn load_dialog(&self, parent: Option<&dyn WxWidget>, name: &str) -> Result<Dialog, XrcError> {
+        let c_name = self.check_resource_class(name, "wxDialog")?;
         let parent_ptr = parent.map_or(ptr::null_mut(), |p| p.handle_ptr());
 
         let dialog_ptr = unsafe { ffi::wxd_XmlResource_LoadDialog(self.ptr, parent_ptr, c_name.as_ptr()) };
 
         if dialog_ptr.is_null() {
-            None
+            Err(XrcError::creation_failed(name, "wxDialog"))
         } else {
-            Some(unsafe { Dialog::from_xrc_ptr(dialog_ptr) })
+            Ok(unsafe { Dialog::from_xrc_ptr(dialog_ptr) })
         }
     }
 
     /// Load a frame from XRC
-    pub fn load_frame(&self, parent: Option<&dyn WxWidget>, name: &str) -> Option<Frame> {
-        let c_name = CString::new(name).ok()?;
+    pub fn load_frame(&self, parent: Option<&dyn WxWidget>, name: &str) -> Result<Frame, XrcError> {
+        let c_name = self.check_resource_class(name, "wxFrame")?;
         let parent_ptr = parent.map_or(ptr::null_mut(), |p| p.handle_ptr());
 
         let frame_ptr = unsafe { ffi::wxd_XmlResource_LoadFrame(self.ptr, parent_ptr, c_name.as_ptr()) };
 
         if frame_ptr.is_null() {
-            None
+            Err(XrcError::creation_failed(name, "wxFrame"))
         } else {
-            Some(unsafe { <Frame as FromXrcPtr>::from_xrc_ptr(frame_ptr as *mut ffi::wxd_Window_t) })
+            Ok(unsafe { <Frame as FromXrcPtr>::from_xrc_ptr(frame_ptr as *mut ffi::wxd_Window_t) })
         }
     }
 
     /// Load a panel from XRC
-    pub fn load_panel(&self, parent: Option<&dyn WxWidget>, name: &str) -> Option<Panel> {
-        let c_name = CString::new(name).ok()?;
+    pub fn load_panel(&self, parent: Option<&dyn WxWidget>, name: &str) -> Result<Panel, XrcError> {
+        let c_name = self.check_resource_class(name, "wxPanel")?;
         let parent_ptr = parent.map_or(ptr::null_mut(), |p| p.handle_ptr());
 
         let panel_ptr = unsafe { ffi::wxd_XmlResource_LoadPanel(self.ptr, parent_ptr, c_name.as_ptr()) };
 
         if panel_ptr.is_null() {
-            None
+            Err(XrcError::creation_failed(name, "wxPanel"))
+        } else {
+            Ok(unsafe { <Panel as FromXrcPtr>::from_xrc_ptr(panel_ptr as *mut ffi::wxd_Window_t) })
+        }
+    }
+
+    /// Load a menu bar from XRC
+    ///
+    /// Pass it to `Frame::set_menu_bar` to use it with a frame built in code. The ids of
+    /// named menu items are their [`xrc_id`], so handlers in `on_menu` can compare against it.
+    pub fn load_menu_bar(&self, name: &str) -> Result<MenuBar, XrcError> {
+        let c_name = self.check_resource_class(name, "wxMenuBar")?;
+
+        let menubar_ptr = unsafe { ffi::wxd_XmlResource_LoadMenuBar(self.ptr, ptr::null_mut(), c_name.as_ptr()) };
+
+        if menubar_ptr.is_null() {
+            Err(XrcError::creation_failed(name, "wxMenuBar"))
         } else {
-            Some(unsafe { <Panel as FromXrcPtr>::from_xrc_ptr(panel_ptr as *mut ffi::wxd_Window_t) })
+            Ok(unsafe { MenuBar::from_ptr(menubar_ptr) })
+        }
+    }
+
+    /// Load a top-level menu from XRC, e.g. for `popup_menu`
+    ///
+    /// The returned menu is owned by the caller. Item ids are their [`xrc_id`].
+    pub fn load_menu(&self, name: &str) -> Result<Menu, XrcError> {
+        let c_name = self.check_resource_class(name, "wxMenu")?;
+
+        let menu_ptr = unsafe { ffi::wxd_XmlResource_LoadMenu(self.ptr, c_name.as_ptr()) };
+
+        if menu_ptr.is_null() {
+            Err(XrcError::creation_failed(name, "wxMenu"))
+        } else {
+            Ok(Menu::from(menu_ptr))
+        }
+    }
+
+    /// Load a toolbar from XRC as a child of `parent`
+    ///
+    /// If `parent` is a frame, the toolbar becomes the frame's toolbar, as with
+    /// `Frame::create_tool_bar`. Tool clicks arrive as menu events with the tool's [`xrc_id`].
+    pub fn load_tool_bar(&self, parent: &dyn WxWidget, name: &str) -> Result<ToolBar, XrcError> {
+        let c_name = self.check_resource_class(name, "wxToolBar")?;
+
+        let toolbar_ptr = unsafe { ffi::wxd_XmlResource_LoadToolBar(self.ptr, parent.handle_ptr(), c_name.as_ptr()) };
+
+        if toolbar_ptr.is_null() {
+            Err(XrcError::creation_failed(name, "wxToolBar"))
+        } else {
+            Ok(unsafe { ToolBar::from_ptr(toolbar_ptr) })
+        }
+    }
+
+    /// Load a top-level window resource of any class.
+    ///
+    /// Use [`Window::as_widget`] to get the typed wrapper for a known class.
+    pub fn load_object(&self, parent: Option<&dyn WxWidget>, name: &str) -> Result<Window, XrcError> {
+        let class = self
+            .get_resource_class(name)
+            .ok_or_else(|| XrcError::ResourceNotFound(name.to_string()))?;
+        let c_name = CString::new(name).map_err(|_| XrcError::InvalidName(name.to_string()))?;
+        let c_class = CString::new(class.as_str()).map_err(|_| XrcError::InvalidName(class.clone()))?;
+        let parent_ptr = parent.map_or(ptr::null_mut(), |p| p.handle_ptr());
+
+        let window_ptr = unsafe { ffi::wxd_XmlResource_LoadObject(self.ptr, parent_ptr, c_name.as_ptr(), c_class.as_ptr()) };
+
+        if window_ptr.is_null() {
+            Err(XrcError::creation_failed(name, &class))
+        } else {
+            Ok(unsafe { Window::from_ptr(window_ptr) })
+        }
+    }
+
+    /// Registers a factory creating the objects of a custom XRC class, such as
+    /// `<object class="MyCanvas" name="plot"/>`.
+    ///
+    /// The factory gets the parent window and the XRC object name and returns the new
+    /// widget. The id, name, style, `pos`, `size` and common window attributes (colours,
+    /// font, tooltip, ...) declared in the XRC are then applied to it, and child objects
+    /// are created inside it. Registering the same class again replaces the factory.
+    ///
+    /// `include_xrc!` registers these itself for the classes given in its `custom` option.
+    ///
+    /// # Example
+    /// ```ignore
+    /// XmlResource::get().register_subclass_factory("MyCanvas", |parent, _name| {
+    ///     let canvas = Panel::builder(parent).build();
+    ///     canvas.on_paint(move |_| { /* custom drawing */ });
+    ///     canvas
+    /// });
+    /// ```
+    pub fn register_subclass_factory<W, F>(&self, class_name: &str, factory: F) -> bool
+    where
+        W: WxWidget,
+        F: Fn(&Window, &str) -> W + 'static,
+    {
+        let Ok(c_class) = CString::new(class_name) else {
+            return false;
+        };
+        let factory: SubclassFactory = Box::new(move |parent, name| factory(parent, name).handle_ptr());
+        // Double-box to get a thin pointer; freed by wxd_Drop_Rust_XrcSubclassFactory.
+        let user_data = Box::into_raw(Box::new(factory)) as *mut c_void;
+        unsafe {
+            ffi::wxd_XmlResource_RegisterSubclassFactory(self.ptr, c_class.as_ptr(), Some(subclass_factory_trampoline), user_data)
         }
     }
 
     /// Get XRC ID for a control name
+    ///
+    /// Like wxWidgets' `XRCID()`, this creates a new id for a name seen for the first time.
+    /// Use [`try_get_xrc_id`](Self::try_get_xrc_id) to look up names without doing so.
     pub fn get_xrc_id(name: &str) -> i32 {
         let c_name = CString::new(name).unwrap_or_default();
         unsafe { ffi::wxd_XmlResource_GetXRCID(c_name.as_ptr()) }
     }
 
+    /// Get XRC ID for a name without creating one.
+    ///
+    /// Returns `None` unless `name` is the name of an object in a loaded XRC document or
+    /// already got an id from [`get_xrc_id`](Self::get_xrc_id) or
+    /// [`assign_xrc_id`](Self::assign_xrc_id). Documents loaded from a wildcard mask or a
+    /// virtual file system path are not searched for names.
+    pub fn try_get_xrc_id(name: &str) -> Option<i32> {
+        let c_name = CString::new(name).ok()?;
+        let id = unsafe { ffi::wxd_XmlResource_TryGetXRCID(c_name.as_ptr()) };
+        (id != ID_NONE).then_some(id)
+    }
+
+    /// Gives an XRC name a fixed id, to be used when objects with this name are created.
+    ///
+    /// Succeeds if the name already has this id. Fails if the name already has another id,
+    /// or the id belongs to another name.
+    pub fn assign_xrc_id(name: &str, id: i32) -> Result<(), XrcError> {
+        let c_name = CString::new(name).map_err(|_| XrcError::InvalidName(name.to_string()))?;
+        let assigned = unsafe { ffi::wxd_XmlResource_AssignXRCID(c_name.as_ptr(), id) };
+        if assigned == id {
+            Ok(())
+        } else if assigned == ID_NONE {
+            Err(XrcError::IdInUse {
+                name: name.to_string(),
+                id,
+                used_by: Self::get_xrc_name(id).unwrap_or_default(),
+            })
+        } else {
+            Err(XrcError::NameHasId {
+                name: name.to_string(),
+                id,
+                existing: assigned,
+            })
+        }
+    }
+
+    /// Get the XRC name an id was created for, e.g. to log which menu item sent an event.
+    pub fn get_xrc_name(id: i32) -> Option<String> {
+        let len = unsafe { ffi::wxd_XmlResource_GetXRCName(id, ptr::null_mut(), 0) };
+        if len < 0 {
+            return None;
+        }
+        let mut buf = vec![0; len as usize + 1];
+        unsafe { ffi::wxd_XmlResource_GetXRCName(id, buf.as_mut_ptr(), buf.len()) };
+        Some(unsafe { CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned() })
+    }
+
     /// Returns the raw pointer.
     /// # Safety
     /// The caller must ensure the pointer is used correctly.
@@ -131,6 +471,81 @@ impl XmlResource {
     }
 }
 
+/// Get the id of a named XRC object, e.g. to compare with `event.get_id()` in a menu
+/// handler. Shorthand for [`XmlResource::get_xrc_id`], so unknown names get a new id.
+pub fn xrc_id(name: &str) -> i32 {
+    XmlResource::get_xrc_id(name)
+}
+
+/// Get the id of a named XRC object, or `None` if no loaded XRC document uses the name.
+/// Shorthand for [`XmlResource::try_get_xrc_id`].
+pub fn try_xrc_id(name: &str) -> Option<i32> {
+    XmlResource::try_get_xrc_id(name)
+}
+
+type SubclassFactory = Box<dyn Fn(&Window, &str) -> *mut ffi::wxd_Window_t + 'static>;
+
+/// Called by C++ to create an object of a custom XRC class.
+unsafe extern "C" fn subclass_factory_trampoline(
+    user_data: *mut c_void,
+    parent: *mut ffi::wxd_Window_t,
+    name: *const c_char,
+) -> *mut ffi::wxd_Window_t {
+    if user_data.is_null() {
+        return ptr::null_mut();
+    }
+    let factory = unsafe { &*(user_data as *const SubclassFactory) };
+    let parent = unsafe { Window::from_ptr(parent) };
+    let name = if name.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(name).to_string_lossy().into_owned() }
+    };
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| factory(&parent, &name))) {
+        Ok(window) => window,
+        Err(_) => {
+            log::error!("Panic in XRC subclass factory for '{name}'");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Function called by C++ to drop a subclass factory.
+///
+/// # Safety
+/// `user_data` must be a pointer created by [`XmlResource::register_subclass_factory`]
+/// and must not be used after this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wxd_Drop_Rust_XrcSubclassFactory(user_data: *mut c_void) {
+    if !user_data.is_null() {
+        let _ = unsafe { Box::from_raw(user_data as *mut SubclassFactory) };
+    }
+}
+
+/// A custom widget that `include_xrc!` creates for objects of a custom XRC class.
+///
+/// List the type in the macro's `custom` option to get a field of this type:
+///
+/// ```ignore
+/// #[derive(Clone, Copy)]
+/// struct MyCanvas { panel: Panel }
+///
+/// impl XrcCustomWidget for MyCanvas {
+///     fn create_from_xrc(parent: &Window, _name: &str) -> Self {
+///         let panel = Panel::builder(parent).build();
+///         panel.on_paint(move |_| { /* custom drawing */ });
+///         MyCanvas { panel }
+///     }
+/// }
+///
+/// include_xrc!("ui.xrc", MyUI, custom = { "MyCanvas" => MyCanvas });
+/// ```
+pub trait XrcCustomWidget: WxWidget + Clone + 'static {
+    /// Creates the widget as a child of `parent`. XRC attributes such as the size are
+    /// applied afterwards.
+    fn create_from_xrc(parent: &Window, name: &str) -> Self;
+}
+
 /// Trait for creating widgets from XRC-managed pointers
 pub trait FromXrcPtr {
     type RawFfiType;
@@ -0,0 +1,72 @@
+//! A virtual list only asks for the rows it shows, however many items it has.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use wxdragon::prelude::*;
+
+const ITEM_COUNT: i64 = 1_000_000;
+const COLUMNS: i32 = 3;
+
+// Generous bound on the calls per callback: a few screens of rows, nowhere near ITEM_COUNT
+const MAX_CALLS: usize = 2_000;
+
+// Needs the wxWidgets main loop on the main thread, see the note in window.rs
+#[cfg_attr(target_os = "macos", ignore)]
+#[test]
+fn virtual_list_callbacks_only_run_for_visible_rows() {
+    SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
+    let timer_store: Rc<RefCell<Option<Timer<Frame>>>> = Rc::new(RefCell::new(None));
+    let timer_store_clone = timer_store.clone();
+
+    // Checked after the main loop returns, as panics in event handlers don't fail the test
+    let text_calls = Rc::new(Cell::new(0usize));
+    let image_calls = Rc::new(Cell::new(0usize));
+    let attr_calls = Rc::new(Cell::new(0usize));
+    let (text, image, attr) = (text_calls.clone(), image_calls.clone(), attr_calls.clone());
+
+    let res = wxdragon::main(move |app| {
+        let frame = Frame::builder().with_size(Size::new(400, 300)).build();
+        let list = ListCtrl::builder(&frame).virtual_mode().build();
+        for col in 0..COLUMNS {
+            list.insert_column(col as i64, &format!("Column {col}"), ListColumnFormat::Left, 100);
+        }
+
+        assert!(list.on_get_item_text(move |row, col| {
+            text.set(text.get() + 1);
+            format!("{row}:{col}")
+        }));
+        assert!(list.on_get_item_image(move |_row| {
+            image.set(image.get() + 1);
+            -1
+        }));
+        assert!(list.on_get_item_attr(move |row| {
+            attr.set(attr.get() + 1);
+            (row % 10 == 0).then(|| ListItemAttr::new().with_bold(true))
+        }));
+        list.set_item_count(ITEM_COUNT);
+        frame.show(true);
+
+        let timer = Timer::new(&frame);
+        timer.on_tick(move |_evt| {
+            // Scroll to the middle and then the end, painting each time
+            list.ensure_visible(ITEM_COUNT / 2);
+            list.update();
+            list.ensure_visible(ITEM_COUNT - 1);
+            list.update();
+
+            frame.destroy();
+            app.exit_main_loop();
+        });
+        timer.start(200, true);
+        timer_store_clone.borrow_mut().replace(timer);
+    });
+    if let Err(e) = res {
+        panic!("main loop failed: {e:?}");
+    }
+
+    assert!(text_calls.get() > 0, "the list never asked for any text");
+    for (name, calls) in [("text", &text_calls), ("image", &image_calls), ("attr", &attr_calls)] {
+        assert!(calls.get() < MAX_CALLS, "{name} callback ran {} times", calls.get());
+    }
+}
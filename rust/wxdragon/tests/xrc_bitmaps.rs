@@ -0,0 +1,37 @@
+//! Bitmaps referenced by relative paths in an XRC file load from the XRC's directory.
+#![cfg(feature = "xrc")]
+
+use wxdragon::prelude::*;
+
+wxdragon::include_xrc!("../tests/xrc/bitmaps.xrc", BitmapsUI);
+
+// Needs the wxWidgets main loop on the main thread, see the note in window.rs
+#[cfg_attr(target_os = "macos", ignore)]
+#[test]
+fn bitmap_resources_resolve_relative_to_the_xrc_file() {
+    SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
+    let timer_store: std::rc::Rc<std::cell::RefCell<Option<Timer<Frame>>>> = std::rc::Rc::new(std::cell::RefCell::new(None));
+    let timer_store_clone = timer_store.clone();
+
+    let res = wxdragon::main(move |app| {
+        let ui = BitmapsUI::new(None, false);
+
+        let logo = ui.logo().expect("logo bitmap should load from images/logo.png");
+        assert_eq!((logo.get_width(), logo.get_height()), (16, 8));
+        let icon = ui.app_icon().expect("app_icon should load from images/logo.png");
+        assert_eq!((icon.get_width(), icon.get_height()), (16, 8));
+        assert!(XmlResource::get().load_bitmap("no_such_bitmap").is_none());
+
+        let frame = ui.bitmap_frame;
+        let timer = Timer::new(&frame);
+        timer.on_tick(move |_evt| {
+            frame.destroy();
+            app.exit_main_loop();
+        });
+        timer.start(100, true);
+        timer_store_clone.borrow_mut().replace(timer);
+    });
+    if let Err(e) = res {
+        panic!("main loop failed: {e:?}");
+    }
+}
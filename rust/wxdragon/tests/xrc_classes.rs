@@ -0,0 +1,60 @@
+//! Compile-tests for `include_xrc!` with book controls, data view controls and the `Window` fallback.
+//!
+//! The generated structs are only type-checked; creating them needs a running app.
+#![cfg(feature = "xrc")]
+
+use wxdragon::prelude::*;
+use wxdragon::widgets::SimpleBook;
+
+wxdragon::include_xrc!("../tests/xrc/book_controls.xrc", BookControlsUI);
+wxdragon::include_xrc!("../tests/xrc/dataview_controls.xrc", DataViewControlsUI);
+
+#[cfg(feature = "aui")]
+wxdragon::include_xrc!("../tests/xrc/aui_notebook.xrc", AuiNotebookUI);
+
+#[test]
+fn book_controls_have_typed_fields() {
+    let _: fn(&BookControlsUI) -> (Choicebook, Listbook, Treebook, Toolbook, SimpleBook) =
+        |ui| (ui.choice_book, ui.list_book, ui.tree_book, ui.tool_book, ui.simple_book);
+    let _: fn(Option<&dyn WxWidget>, bool) -> BookControlsUI = BookControlsUI::new;
+}
+
+#[test]
+fn loaded_books_share_page_change_events() {
+    fn bind_page_changes<B: BookCtrlEvents>(book: &B) {
+        book.on_page_changed(|_event| {});
+    }
+    let _: fn(&BookControlsUI) = |ui| {
+        bind_page_changes(&ui.choice_book);
+        bind_page_changes(&ui.list_book);
+        bind_page_changes(&ui.tree_book);
+        bind_page_changes(&ui.simple_book);
+    };
+}
+
+#[test]
+fn dataview_controls_have_typed_fields() {
+    let _: fn(&DataViewControlsUI) -> (&DataViewCtrl, &DataViewListCtrl, &DataViewTreeCtrl) =
+        |ui| (&ui.data_view, &ui.data_list, &ui.data_tree);
+    let _: fn(Option<&dyn WxWidget>, bool) -> DataViewControlsUI = DataViewControlsUI::new;
+}
+
+#[cfg(feature = "aui")]
+#[test]
+fn aui_notebook_has_typed_field() {
+    let _: fn(&AuiNotebookUI) -> &AuiNotebook = |ui| &ui.aui_book;
+    let _: fn(Option<&dyn WxWidget>, bool) -> AuiNotebookUI = AuiNotebookUI::new;
+}
+
+// The fallback warning is expected here; it lists help_view (wxHtmlWindow)
+#[allow(deprecated)]
+mod fallback {
+    use wxdragon::prelude::*;
+
+    wxdragon::include_xrc!("../tests/xrc/unsupported_class.xrc", HelpPanelUI, fallback = Window);
+}
+
+#[test]
+fn fallback_exposes_unsupported_objects_as_window() {
+    let _: fn(&fallback::HelpPanelUI) -> (Window, Button) = |ui| (ui.help_view, ui.close_button);
+}
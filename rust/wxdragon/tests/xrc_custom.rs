@@ -0,0 +1,67 @@
+//! Objects of custom XRC classes are created by an `XrcCustomWidget` and keep their XRC attributes.
+#![cfg(feature = "xrc")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+use wxdragon::prelude::*;
+
+/// A panel with its own state, standing in for a custom-painted control.
+#[derive(Clone)]
+struct MyCanvas {
+    panel: Panel,
+    xrc_name: Rc<str>,
+    repaints: Rc<Cell<u32>>,
+}
+
+impl WxWidget for MyCanvas {
+    fn handle_ptr(&self) -> *mut wxdragon::ffi::wxd_Window_t {
+        self.panel.handle_ptr()
+    }
+}
+
+impl XrcCustomWidget for MyCanvas {
+    fn create_from_xrc(parent: &Window, name: &str) -> Self {
+        let panel = Panel::builder(parent).build();
+        let repaints = Rc::new(Cell::new(0));
+        let counter = repaints.clone();
+        panel.on_paint(move |_| counter.set(counter.get() + 1));
+        MyCanvas {
+            panel,
+            xrc_name: name.into(),
+            repaints,
+        }
+    }
+}
+
+wxdragon::include_xrc!("../tests/xrc/custom_widget.xrc", PlotUI, custom = { "MyCanvas" => MyCanvas });
+
+// Needs the wxWidgets main loop on the main thread, see the note in window.rs
+#[cfg_attr(target_os = "macos", ignore)]
+#[test]
+fn custom_class_is_created_by_its_rust_type() {
+    SystemOptions::set_option_by_int("msw.no-manifest-check", 1);
+    let timer_store: Rc<std::cell::RefCell<Option<Timer<Frame>>>> = Rc::new(std::cell::RefCell::new(None));
+    let timer_store_clone = timer_store.clone();
+
+    let res = wxdragon::main(move |app| {
+        let ui = PlotUI::new(None, false);
+
+        let plot: &MyCanvas = &ui.plot;
+        assert_eq!(&*plot.xrc_name, "plot");
+        assert_eq!(plot.repaints.get(), 0);
+        assert_eq!(plot.get_size(), Size::new(120, 80));
+        assert_eq!(plot.get_parent().map(|p| p.handle_ptr()), Some(ui.plot_frame.handle_ptr()));
+
+        let frame = ui.plot_frame;
+        let timer = Timer::new(&frame);
+        timer.on_tick(move |_evt| {
+            frame.destroy();
+            app.exit_main_loop();
+        });
+        timer.start(100, true);
+        timer_store_clone.borrow_mut().replace(timer);
+    });
+    if let Err(e) = res {
+        panic!("main loop failed: {e:?}");
+    }
+}
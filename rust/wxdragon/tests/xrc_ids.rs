@@ -0,0 +1,23 @@
+//! Tests for the fixed menu and tool ids generated by `include_xrc!`.
+#![cfg(feature = "xrc")]
+
+use wxdragon::prelude::*;
+
+wxdragon::include_xrc!("../tests/xrc/menu_ids.xrc", EditorUI);
+
+#[test]
+fn menu_items_and_tools_get_id_constants() {
+    assert_eq!(
+        EditorUI::XRC_IDS,
+        &[("menu_open", EditorUI::ID_MENU_OPEN), ("tool_save", EditorUI::ID_TOOL_SAVE)]
+    );
+    assert_ne!(EditorUI::ID_MENU_OPEN, EditorUI::ID_TOOL_SAVE);
+
+    // The constants can be used as patterns in event handlers
+    let action = |id: i32| match id {
+        EditorUI::ID_MENU_OPEN => "open",
+        EditorUI::ID_TOOL_SAVE => "save",
+        _ => "other",
+    };
+    assert_eq!(action(EditorUI::ID_TOOL_SAVE), "save");
+}
@@ -0,0 +1,27 @@
+//! Compile-tests for `include_xrc!` with several XRC files and `object_ref`.
+#![cfg(feature = "xrc")]
+
+use wxdragon::prelude::*;
+
+// The dialog uses <object_ref ref="common_buttons"/> from common.xrc
+wxdragon::include_xrc!(
+    ["../tests/xrc/settings_dialog.xrc", "../tests/xrc/common.xrc"],
+    SettingsUI,
+    root = "SettingsDialog"
+);
+
+#[test]
+fn object_ref_children_become_fields() {
+    let _: fn(&SettingsUI) -> (Dialog, CheckBox, Panel, Button, Button) = |ui| {
+        (
+            ui.SettingsDialog,
+            ui.autosave_check,
+            ui.settings_buttons,
+            ui.ok_button,
+            ui.cancel_button,
+        )
+    };
+    let _: fn(Option<&dyn WxWidget>, bool) -> SettingsUI = SettingsUI::new;
+    assert_eq!(SettingsUI::XRC_FILES.len(), 2);
+    assert_eq!(SettingsUI::XRC_DATA, SettingsUI::XRC_FILES[0].0);
+}